@@ -23,9 +23,9 @@
 
 fn main() {
     // 📝 TODO: Add your println! statement below
-    // 
-    // HINT: The syntax is:
-    //   println!("Your text here");
+    // HINTS `main`
+    // 1: The syntax is:
+    //      println!("Your text here");
     //
     // Make sure to:
     //   • Use println! (with the exclamation mark - it's a macro!)
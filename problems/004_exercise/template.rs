@@ -3,11 +3,13 @@
 
 fn main() {
     // TODO: Create a String (owned, growable) with value "Hello"
-    // Hint: Use String::from() or "text".to_string()
+    // HINTS `main`
+    // 1: Use String::from() or "text".to_string()
     let owned_string = // ...
     
     // TODO: Create a string slice (&str) - a borrowed reference to string data
-    // Hint: Use a string literal or borrow from the owned String with &
+    // HINTS `main`
+    // 2: Use a string literal or borrow from the owned String with &
     let string_slice = // ...
     
     println!("Owned String: {}", owned_string);
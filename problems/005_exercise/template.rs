@@ -15,7 +15,8 @@ fn main() {
     println!("Message: {}", WELCOME_MESSAGE);
     
     // TODO: Calculate what percentage 75 is of MAX_SCORE and print it
-    // Hint: percentage = (75.0 / MAX_SCORE as f64) * 100.0
+    // HINTS `main`
+    // 1: percentage = (75.0 / MAX_SCORE as f64) * 100.0
     let percentage = // ...
     println!("75 is {:.1}% of {}", percentage, MAX_SCORE);
 }
@@ -7,7 +7,8 @@ fn main() {
     // TODO: Check if number is greater than 0
     // If it is, print "Positive number"
     // Otherwise, print "Not positive"
-    // Hint: 
+    // HINTS `main`
+    // 1: 
     // if condition {
     //     // code
     // } else {
@@ -10,7 +10,8 @@ fn main() {
     // 70-79: "C"
     // 60-69: "D"
     // Below 60: "F"
-    // Hint: Use if/else if/else chain
+    // HINTS `main`
+    // 1: Use if/else if/else chain
     
     // if score >= 90 {
     //     // ...
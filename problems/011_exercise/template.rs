@@ -9,7 +9,8 @@ fn main() {
     // 2 -> "Two"
     // 3 -> "Three"
     // Any other number -> "Other"
-    // Hint: 
+    // HINTS `main`
+    // 1: 
     // match value {
     //     pattern1 => expression,
     //     pattern2 => expression,
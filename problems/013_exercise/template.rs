@@ -16,7 +16,8 @@ fn main() {
     println!("---");
     
     // TODO: Print even numbers from 2 to 10 using a range
-    // Hint: Use (2..=10).step_by(2)
+    // HINTS `main`
+    // 1: Use (2..=10).step_by(2)
     
     // for number in // ... {
     //     // TODO: Print the number
@@ -25,7 +26,8 @@ fn main() {
     println!("---");
     
     // TODO: Calculate the sum of numbers from 1 to 100 using a for loop
-    // Hint: Start with let mut sum = 0; then add each number
+    // HINTS `main`
+    // 2: Start with let mut sum = 0; then add each number
     let mut sum = 0;
     // for ... {
     //     // TODO: Add to sum
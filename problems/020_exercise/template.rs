@@ -33,7 +33,8 @@ fn main() {
     let z = "10";
     println!("z as string: {}", z);
     // TODO: Shadow z, parsing it as an i32
-    // Hint: use .parse::<i32>().unwrap() on the string
+    // HINTS `main`
+    // 1: use .parse::<i32>().unwrap() on the string
     // let z = z.parse::<i32>().unwrap();
     // println!("z as number: {}", z);
 }
@@ -14,7 +14,8 @@ fn main() {
     let weekdays = // ...
     
     // TODO: Declare an array of 10 zeros using the shorthand syntax
-    // Hint: [0; 10] creates an array with ten zeros
+    // HINTS `main`
+    // 1: [0; 10] creates an array with ten zeros
     let zeros = // ...
     
     // Print array information
@@ -32,7 +32,8 @@ fn main() {
     println!("Sum of scores: {}", sum);
     
     // TODO: Calculate the average
-    // Hint: Convert to f64 for floating point division
+    // HINTS `main`
+    // 1: Convert to f64 for floating point division
     let average = sum as f64 / scores.len() as f64;
     println!("Average score: {:.1}", average);
     
@@ -87,7 +87,8 @@ fn main() {
     }
     
     // 📝 TODO: Implement this function to return the value in cents
-    // Hint: Quarter has a String value you can access
+    // HINTS `main`
+    // 1: Quarter has a String value you can access
     fn value_in_cents(coin: Coin) -> u32 {
         match coin {
             Coin::Penny => {
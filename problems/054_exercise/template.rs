@@ -33,7 +33,8 @@ impl std::error::Error for MathError {}
 /// Parses a string to i32, returning a MathError.
 fn parse_number(input: &str) -> Result<i32, MathError> {
     // TODO: Parse input as i32, map_err to MathError::InvalidNumber if it fails
-    // Hint: input.parse::<i32>().map_err(|_| ...)
+    // HINTS `parse_number`
+    // 1: input.parse::<i32>().map_err(|_| ...)
     todo!()
 }
 
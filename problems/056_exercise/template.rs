@@ -14,7 +14,8 @@
 /// Returns Some(doubled) if parsing succeeds, None otherwise.
 fn parse_and_double(input: &str) -> Option<i32> {
     // TODO: Parse input as i32, then use map() to double the value
-    // Hint: input.parse::<i32>().ok().map(|n| ...)
+    // HINTS `parse_and_double`
+    // 1: input.parse::<i32>().ok().map(|n| ...)
     todo!()
 }
 
@@ -31,7 +31,8 @@ impl<T> Container<T> {
     /// Replaces the contained value and returns the old one.
     fn set(&mut self, value: T) -> T {
         // TODO: Replace self.value with the new value and return the old one
-        // Hint: Use std::mem::replace
+        // HINTS `set`
+        // 1: Use std::mem::replace
         todo!()
     }
 }
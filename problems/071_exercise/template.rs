@@ -52,7 +52,8 @@ fn make_concatenator(separator: &str) -> impl Fn(&str, &str) -> String + '_ {
 /// Returns a closure that counts how many times it's been called.
 fn make_counter() -> impl FnMut() -> i32 {
     // TODO: Return a closure that increments and returns a counter on each call
-    // Hint: use move and a mutable variable
+    // HINTS `make_counter`
+    // 1: use move and a mutable variable
     todo!()
 }
 
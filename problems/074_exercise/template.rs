@@ -62,7 +62,8 @@ fn char_frequency(text: &str) -> HashMap<char, usize> {
 /// Groups numbers by even/odd into a HashMap.
 fn group_by_parity(numbers: &[i32]) -> HashMap<bool, Vec<i32>> {
     // TODO: Group numbers: true -> even numbers, false -> odd numbers
-    // Hint: Use fold to build the map
+    // HINTS `group_by_parity`
+    // 1: Use fold to build the map
     todo!()
 }
 
@@ -11,7 +11,8 @@
 
 fn main() {
     // TODO: Create a Box that holds an i32 with value 42
-    // Hint: Use Box::new()
+    // HINTS `main`
+    // 1: Use Box::new()
     let boxed_value: Box<i32> = // YOUR CODE HERE
 
     // TODO: Print the boxed value using dereferencing
@@ -30,7 +30,8 @@ impl List {
     // TODO: Implement a len() method that returns the length of the list
     fn len(&self) -> usize {
         // YOUR CODE HERE
-        // Hint: Use pattern matching and recursion
+        // HINTS `len`
+        // 1: Use pattern matching and recursion
     }
 
     // TODO: Implement a stringify() method that converts the list to a string
@@ -26,7 +26,8 @@ fn main() {
         let handle = thread::spawn(move || {
             for _ in 0..10 {
                 // TODO: Lock the mutex, increment the value, and unlock
-                // Hint: The lock returns a Result containing a MutexGuard
+                // HINTS `main`
+                // 1: The lock returns a Result containing a MutexGuard
                 let mut num = counter_clone.lock().unwrap();
                 *num += 1;
                 // MutexGuard automatically unlocks when dropped
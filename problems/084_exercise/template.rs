@@ -40,7 +40,8 @@ fn main() {
     });
 
     // TODO: Receive all messages
-    // Hint: recv() blocks until a message is available
+    // HINTS `main`
+    // 1: recv() blocks until a message is available
     for _ in 1..=5 {
         let received = // YOUR CODE HERE
         println!("Received: {}", received);
@@ -0,0 +1,127 @@
+// Exercise 095: Modules - Visibility Modifiers
+//
+// Learning objective: Understand Rust's visibility modifiers:
+// pub, pub(crate), pub(super), and private (default).
+//
+// Visibility controls which items can be accessed from where.
+// Default is private to the module and its children.
+//
+// This exercise is a Cargo crate. src/lib.rs is the root of the library
+// and every `mod name;` below loads src/name.rs, whose own submodules live
+// in src/name/. src/main.rs is a second crate that uses the library as
+// `exercise_095`, so it only sees what is `pub` all the way down.
+// Run it with `cargo run` and check it with `cargo test`.
+
+// TODO: Declare the modules that live in their own files
+mod outer; // src/outer.rs, private to this crate
+pub mod utils; // src/utils.rs, part of the public API
+
+// TODO: Create a private function (default visibility)
+fn private_function() {
+    println!("I'm private to this module");
+}
+
+// TODO: Create a public function
+pub fn public_function() {
+    println!("I'm public");
+    private_function(); // Can call private functions from same module
+}
+
+// TODO: Call the items only code inside this crate can reach
+pub fn crate_tour() {
+    outer::outer_public();
+    outer::access_inner();
+    outer::inner::inner_public();
+    outer::inner::crate_visible(); // pub(crate) crosses files, not crates
+    println!("Internal util: {}", utils::internal_util());
+}
+
+// TODO: Create a struct with mixed visibility fields
+pub struct MyStruct {
+    pub public_field: i32,
+    private_field: String, // Default: private
+    pub(crate) crate_visible_field: bool,
+}
+
+impl MyStruct {
+    // TODO: Create a public constructor
+    pub fn new(value: i32, name: &str) -> Self {
+        MyStruct {
+            public_field: value,
+            private_field: name.to_string(),
+            crate_visible_field: true,
+        }
+    }
+
+    // Public method
+    pub fn get_private(&self) -> &str {
+        &self.private_field
+    }
+
+    // Private method
+    fn internal_helper(&self) -> i32 {
+        self.public_field * 2
+    }
+
+    // Public method using private helper
+    pub fn calculate(&self) -> i32 {
+        self.internal_helper() + 10
+    }
+}
+
+// TODO: Create an enum with visibility considerations
+pub enum PublicEnum {
+    VariantA,
+    VariantB(i32),
+    VariantC { name: String },
+}
+
+// Enum variants are public if the enum is public
+// Private enum - variants are private to the module
+enum PrivateEnum {
+    Secret,
+    Hidden,
+}
+
+// TODO: Create a trait with visibility
+pub trait PublicTrait {
+    fn public_method(&self);
+
+    // Trait methods are public if trait is public
+    fn default_implementation(&self) {
+        println!("Default implementation");
+    }
+}
+
+impl PublicTrait for MyStruct {
+    fn public_method(&self) {
+        println!("MyStruct implements PublicTrait: {}", self.public_field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests are part of the library crate, so they can access
+    // crate-visible items
+    #[test]
+    fn test_struct() {
+        let s = MyStruct::new(10, "test");
+        assert_eq!(s.public_field, 10);
+        assert_eq!(s.calculate(), 30);
+        assert!(s.crate_visible_field);
+    }
+
+    #[test]
+    fn test_utils() {
+        assert_eq!(utils::get_answer(), 42);
+        assert_eq!(utils::internal_util(), "internal");
+    }
+
+    #[test]
+    fn test_visibility_chain() {
+        outer::access_inner();
+        outer::inner::crate_visible();
+    }
+}
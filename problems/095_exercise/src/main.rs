@@ -0,0 +1,40 @@
+// The binary crate of exercise 095. It reaches the library through its
+// crate name, like any other crate would, so pub(crate) and private items
+// are out of reach here.
+
+use exercise_095::{MyStruct, PublicEnum, PublicTrait};
+
+fn main() {
+    // TODO: Call public functions
+    exercise_095::public_function();
+    // exercise_095::private_function(); // Error: private_function is private
+    // exercise_095::outer::outer_public(); // Error: module `outer` is private
+
+    // TODO: Let the library reach its crate-visible items for you
+    exercise_095::crate_tour();
+
+    // TODO: Use the struct
+    let s = MyStruct::new(42, "test");
+    println!("Public field: {}", s.public_field);
+    println!("Private field via getter: {}", s.get_private());
+    println!("Calculated: {}", s.calculate());
+    // println!("{}", s.private_field); // Error: field is private
+    // println!("{}", s.crate_visible_field); // Error: pub(crate) stops at the library
+
+    // TODO: Use the enum
+    let e1 = PublicEnum::VariantA;
+    let e2 = PublicEnum::VariantB(10);
+
+    match e2 {
+        PublicEnum::VariantA => println!("A"),
+        PublicEnum::VariantB(n) => println!("B with {}", n),
+        PublicEnum::VariantC { name } => println!("C with {}", name),
+    }
+
+    // TODO: Use the trait (it must be in scope for its methods)
+    s.public_method();
+    s.default_implementation();
+
+    // TODO: Use the public utils module
+    println!("Answer: {}", exercise_095::utils::get_answer());
+}
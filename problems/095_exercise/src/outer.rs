@@ -0,0 +1,22 @@
+// The `outer` module, loaded by `mod outer;` in lib.rs. Its nested
+// module `inner` lives in outer/inner.rs.
+
+// Private to outer module
+fn outer_private() {
+    println!("outer_private");
+}
+
+// Public within outer
+pub fn outer_public() {
+    println!("outer_public");
+    outer_private(); // Can access private items
+}
+
+// TODO: Declare the nested module from its own file
+pub mod inner;
+
+pub fn access_inner() {
+    inner::inner_public();
+    inner::super_visible(); // Can access pub(super)
+    inner::crate_visible(); // Can access pub(crate)
+}
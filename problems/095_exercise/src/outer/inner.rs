@@ -0,0 +1,28 @@
+// The `outer::inner` module, loaded by `pub mod inner;` in outer.rs.
+
+// Private to inner
+fn inner_private() {
+    println!("inner_private");
+}
+
+// Public
+pub fn inner_public() {
+    println!("inner_public");
+    inner_private();
+}
+
+// Visible to parent module only
+pub(super) fn super_visible() {
+    println!("super_visible - visible to outer");
+}
+
+// Visible to the entire crate
+pub(crate) fn crate_visible() {
+    println!("crate_visible - visible everywhere in crate");
+}
+
+// Can access parent's pub items
+pub fn access_parent() {
+    // super::outer_public(); // Can call parent's public items
+    println!("Accessed parent");
+}
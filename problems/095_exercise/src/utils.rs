@@ -0,0 +1,19 @@
+// TODO: Complete this module that demonstrates visibility
+
+// Private helper
+fn helper() -> i32 {
+    42
+}
+
+// Public function
+pub fn get_answer() -> i32 {
+    helper()
+}
+
+// Public to crate
+pub(crate) fn internal_util() -> String {
+    String::from("internal")
+}
+
+// Module for internal implementation details, in utils/internal.rs
+pub mod internal;
@@ -0,0 +1,10 @@
+pub fn save() {
+    println!("Saved to storage");
+}
+
+pub fn load() {
+    println!("Loaded from storage");
+}
+
+// TODO: Declare the formats submodule (src/data/storage/formats.rs)
+pub mod formats;
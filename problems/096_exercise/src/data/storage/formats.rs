@@ -0,0 +1,11 @@
+pub fn json() {
+    println!("JSON format");
+}
+
+pub fn yaml() {
+    println!("YAML format");
+}
+
+pub fn xml() {
+    println!("XML format");
+}
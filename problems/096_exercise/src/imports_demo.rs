@@ -0,0 +1,13 @@
+// TODO: Complete this module with multiple use patterns
+
+// Import multiple items from std::vec
+pub use std::vec::Vec;
+
+// Import with deep nesting
+pub use std::collections::hash_map::{Entry, HashMap};
+
+// Import enum variants
+pub use std::option::Option::{None, Some};
+
+// Type alias via use
+pub type StringVec = Vec<String>;
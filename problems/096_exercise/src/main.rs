@@ -0,0 +1,15 @@
+// The binary crate of exercise 096. Its `use` paths start at the
+// library's crate name, `exercise_096`.
+
+// TODO: Import through the library's re-exports
+use exercise_096::{formats, TcpConnection};
+
+fn main() {
+    exercise_096::tour();
+
+    // TODO: Use the re-exported items
+    println!("\n=== Re-exports ===");
+    let tcp = TcpConnection::new();
+    tcp.connect();
+    formats::json();
+}
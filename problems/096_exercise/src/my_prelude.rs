@@ -0,0 +1,4 @@
+// TODO: Create a prelude-like module re-exporting the common items.
+// From another file, paths into this crate start at `crate::`.
+pub use crate::data::Record;
+pub use crate::network::tcp::Connection;
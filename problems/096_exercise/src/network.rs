@@ -0,0 +1,3 @@
+// TODO: Declare the submodules in src/network/
+pub mod tcp;
+pub mod udp;
@@ -0,0 +1,3 @@
+pub fn action() {
+    println!("Action from shadowing module");
+}
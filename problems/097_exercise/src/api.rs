@@ -0,0 +1,12 @@
+// Module with re-exports for cleaner API
+
+// TODO: Re-export commonly used items. Paths into other files of this
+// crate start at `crate::`.
+pub use crate::garden::flowers::Rose;
+pub use crate::garden::vegetables::{Carrot, Tomato};
+pub use crate::garden::Plant;
+
+// New item in this module
+pub fn garden_info() {
+    println!("Garden API v1.0");
+}
@@ -0,0 +1,12 @@
+// TODO: Organize related functionality: configuration and logging live
+// in src/app/, the application logic here
+
+pub mod config;
+pub mod logging;
+
+// Main application logic
+pub fn run() {
+    logging::info("Starting application");
+    let _settings = config::Settings::default();
+    logging::info("Application started successfully");
+}
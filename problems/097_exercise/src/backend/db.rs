@@ -0,0 +1,14 @@
+use super::types::{User, UserId};
+
+pub fn find_user(id: UserId) -> Option<User> {
+    // Simulated database lookup
+    if id == 1 {
+        Some(User { id, name: String::from("Alice") })
+    } else {
+        None
+    }
+}
+
+pub fn save_user(user: &User) {
+    println!("Saved user: {:?}", user);
+}
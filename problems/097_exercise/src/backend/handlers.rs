@@ -0,0 +1,15 @@
+use super::db;
+use super::types::{User, UserId};
+
+pub fn get_user(id: UserId) -> Option<User> {
+    db::find_user(id)
+}
+
+pub fn create_user(name: &str) -> User {
+    let user = User {
+        id: 999, // Would be generated by DB
+        name: name.to_string(),
+    };
+    db::save_user(&user);
+    user
+}
@@ -0,0 +1,45 @@
+// The `garden` module. Its submodules live in src/garden/.
+
+// Public struct
+pub struct Plant {
+    pub name: String,
+    height: f64, // private field
+}
+
+impl Plant {
+    pub fn new(name: &str, height: f64) -> Self {
+        Plant {
+            name: name.to_string(),
+            height,
+        }
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn grow(&mut self, amount: f64) {
+        self.height += amount;
+    }
+}
+
+// TODO: Declare the nested modules from src/garden/
+pub mod vegetables;
+pub mod flowers;
+
+// Module with private submodules, kept inline
+mod maintenance {
+    pub fn water_plants() {
+        println!("Watering all plants");
+    }
+
+    pub fn prune_plants() {
+        println!("Pruning plants");
+    }
+}
+
+// Public function that uses private module
+pub fn maintain_garden() {
+    maintenance::water_plants();
+    maintenance::prune_plants();
+}
@@ -0,0 +1,21 @@
+#[derive(Debug)]
+pub struct Rose {
+    pub color: String,
+    thorns: bool,
+}
+
+impl Rose {
+    pub fn new(color: &str) -> Self {
+        Rose {
+            color: color.to_string(),
+            thorns: true,
+        }
+    }
+
+    pub fn has_thorns(&self) -> bool {
+        self.thorns
+    }
+}
+
+// Even deeper nesting, in src/garden/flowers/wildflowers.rs
+pub mod wildflowers;
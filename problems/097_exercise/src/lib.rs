@@ -0,0 +1,71 @@
+// Exercise 097: Modules - Nested Modules
+//
+// Learning objective: Understand how to structure code using
+// nested modules and the module file hierarchy.
+//
+// Modules can be nested arbitrarily and organized inline,
+// in separate files, or in directories with mod.rs.
+//
+// This exercise is a Cargo crate that uses both file layouts:
+//
+//   src/garden.rs                      declared in lib.rs (name.rs + name/)
+//   src/garden/vegetables.rs           declared in garden.rs
+//   src/garden/flowers.rs              declared in garden.rs
+//   src/garden/flowers/wildflowers.rs  declared in flowers.rs
+//   src/backend/mod.rs                 declared in lib.rs (older mod.rs)
+//   src/backend/types.rs               declared in backend/mod.rs
+//
+// src/main.rs is a separate crate that uses this library as
+// `exercise_097`. Run it with `cargo run` and check it with `cargo test`.
+
+// TODO: Declare the top-level modules; each lives in its own file
+pub mod garden;
+pub mod api;
+pub mod backend;
+pub mod app;
+
+// TODO: Complete this function using deeply nested modules
+pub fn create_garden_report() {
+    use garden::flowers::Rose;
+    use garden::vegetables::Tomato;
+
+    let tomato = Tomato::new("Beefsteak");
+    let rose = Rose::new("White");
+
+    println!("Garden contains: {:?} tomatoes and {:?} roses",
+             tomato.variety, rose.color);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plant_growth() {
+        let mut plant = garden::Plant::new("Test", 10.0);
+        assert_eq!(plant.height(), 10.0);
+        plant.grow(5.0);
+        assert_eq!(plant.height(), 15.0);
+    }
+
+    #[test]
+    fn test_tomato() {
+        let mut tomato = garden::vegetables::Tomato::new("Test");
+        assert!(!tomato.is_ripe());
+        tomato.ripen();
+        assert!(tomato.is_ripe());
+    }
+
+    #[test]
+    fn test_backend() {
+        let user = backend::handlers::create_user("Test");
+        assert_eq!(user.name, "Test");
+    }
+
+    #[test]
+    fn test_app() {
+        app::logging::info("Test message");
+        let settings = app::config::Settings::default();
+        assert_eq!(settings.port, 8080);
+    }
+}
@@ -0,0 +1,56 @@
+// The binary crate of exercise 097. Every path into the library starts
+// at its crate name, `exercise_097`.
+
+use exercise_097::{api, backend, garden};
+
+fn main() {
+    // TODO: Use items from nested garden module
+    println!("=== Garden Module ===");
+    let mut plant = garden::Plant::new("Fern", 30.0);
+    println!("Plant: {} is {}cm tall", plant.name, plant.height());
+    plant.grow(5.0);
+    println!("After growing: {}cm", plant.height());
+
+    // TODO: Use vegetables submodule
+    println!("\n=== Vegetables ===");
+    let mut tomato = garden::vegetables::Tomato::new("Roma");
+    println!("Tomato: {:?}, ripe: {}", tomato.variety, tomato.is_ripe());
+    tomato.ripen();
+    println!("After ripening: {}", tomato.is_ripe());
+
+    let carrot = garden::vegetables::Carrot { length: 15.0 };
+    println!("Carrot: {:?}", carrot);
+
+    // TODO: Use flowers submodule
+    println!("\n=== Flowers ===");
+    let rose = garden::flowers::Rose::new("Red");
+    println!("Rose: {:?}, has thorns: {}", rose.color, rose.has_thorns());
+
+    // TODO: Use deeply nested wildflowers
+    let daisy = garden::flowers::wildflowers::Daisy;
+    let sunflower = garden::flowers::wildflowers::Sunflower { height: 200.0 };
+    println!("Daisy: {:?}, Sunflower: {:?}", daisy, sunflower);
+
+    // TODO: Use garden maintenance (via public function)
+    println!("\n=== Maintenance ===");
+    garden::maintain_garden();
+
+    // TODO: Use the simplified API module
+    println!("\n=== API Module ===");
+    api::garden_info();
+    let plant2 = api::Plant::new("Cactus", 10.0);
+    let tomato2 = api::Tomato::new("Cherry");
+    println!("Created: {} and {:?}", plant2.name, tomato2.variety);
+
+    // TODO: Use backend module
+    println!("\n=== Backend Module ===");
+    use backend::types::UserId;
+
+    let user_id: UserId = 1;
+    if let Some(user) = backend::handlers::get_user(user_id) {
+        println!("Found user: {:?}", user);
+    }
+
+    let new_user = backend::handlers::create_user("Bob");
+    println!("Created user: {:?}", new_user);
+}
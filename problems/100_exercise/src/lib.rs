@@ -5,6 +5,13 @@
 //
 // Doctests are code examples in documentation comments (/// or //!)
 // that are compiled and run as tests with `cargo test`.
+//
+// This exercise is a library crate: src/lib.rs and no main.rs. Doc
+// examples use it the way another crate would, as `exercise_100::add`.
+// It is graded with `cargo test --doc`, and each example is its own test.
+// Every public item needs at least one example that runs: a block marked
+// `ignore`, `no_run` or `compile_fail` does not count, and an item marked
+// `#[doc(hidden)]` needs none. Check it with `cargo test`.
 
 /// A simple add function.
 ///
@@ -13,14 +20,14 @@
 /// Basic usage:
 ///
 /// ```
-/// let result = template::add(2, 3);
+/// let result = exercise_100::add(2, 3);
 /// assert_eq!(result, 5);
 /// ```
 ///
 /// Negative numbers:
 ///
 /// ```
-/// let result = template::add(-5, 3);
+/// let result = exercise_100::add(-5, 3);
 /// assert_eq!(result, -2);
 /// ```
 pub fn add(a: i32, b: i32) -> i32 {
@@ -32,8 +39,8 @@ pub fn add(a: i32, b: i32) -> i32 {
 /// # Examples
 ///
 /// ```
-/// assert_eq!(template::double(5), 10);
-/// assert_eq!(template::double(0), 0);
+/// assert_eq!(exercise_100::double(5), 10);
+/// assert_eq!(exercise_100::double(0), 0);
 /// ```
 pub fn double(n: i32) -> i32 {
     n * 2
@@ -48,8 +55,8 @@ pub fn double(n: i32) -> i32 {
 /// # Examples
 ///
 /// ```
-/// assert_eq!(template::factorial(0), 1);
-/// assert_eq!(template::factorial(5), 120);
+/// assert_eq!(exercise_100::factorial(0), 1);
+/// assert_eq!(exercise_100::factorial(5), 120);
 /// ```
 pub fn factorial(n: u32) -> u64 {
     if n > 20 {
@@ -65,7 +72,7 @@ pub fn factorial(n: u32) -> u64 {
 /// Creating a new point:
 ///
 /// ```
-/// use template::Point;
+/// use exercise_100::Point;
 ///
 /// let p = Point::new(3.0, 4.0);
 /// assert_eq!(p.x(), 3.0);
@@ -75,7 +82,7 @@ pub fn factorial(n: u32) -> u64 {
 /// Calculating distance from origin:
 ///
 /// ```
-/// use template::Point;
+/// use exercise_100::Point;
 ///
 /// let p = Point::new(3.0, 4.0);
 /// assert_eq!(p.distance_from_origin(), 5.0);
@@ -91,7 +98,7 @@ impl Point {
     /// # Examples
     ///
     /// ```
-    /// use template::Point;
+    /// use exercise_100::Point;
     ///
     /// let p = Point::new(1.0, 2.0);
     /// ```
@@ -99,16 +106,19 @@ impl Point {
         Point { x, y }
     }
     
+    // TODO: Add an `# Examples` section with a runnable example
     /// Returns the x coordinate.
     pub fn x(&self) -> f64 {
         self.x
     }
     
+    // TODO: Add an `# Examples` section with a runnable example
     /// Returns the y coordinate.
     pub fn y(&self) -> f64 {
         self.y
     }
     
+    // TODO: Add an `# Examples` section with a runnable example
     /// Calculates the distance from the origin (0, 0).
     pub fn distance_from_origin(&self) -> f64 {
         (self.x * self.x + self.y * self.y).sqrt()
@@ -119,7 +129,7 @@ impl Point {
     /// # Examples
     ///
     /// ```
-    /// use template::Point;
+    /// use exercise_100::Point;
     ///
     /// let mut p = Point::new(1.0, 2.0);
     /// p.translate(3.0, 4.0);
@@ -137,7 +147,7 @@ impl Point {
 /// # Examples
 ///
 /// ```
-/// use template::UniqueCollection;
+/// use exercise_100::UniqueCollection;
 ///
 /// let mut coll = UniqueCollection::new();
 /// coll.add(1);
@@ -154,6 +164,7 @@ pub struct UniqueCollection<T: PartialEq> {
 }
 
 impl<T: PartialEq> UniqueCollection<T> {
+    // TODO: Add an `# Examples` section with a runnable example
     /// Creates a new empty collection.
     pub fn new() -> Self {
         UniqueCollection { items: Vec::new() }
@@ -164,7 +175,7 @@ impl<T: PartialEq> UniqueCollection<T> {
     /// # Examples
     ///
     /// ```
-    /// use template::UniqueCollection;
+    /// use exercise_100::UniqueCollection;
     ///
     /// let mut coll = UniqueCollection::new();
     /// assert!(coll.add(42));
@@ -179,11 +190,13 @@ impl<T: PartialEq> UniqueCollection<T> {
         }
     }
     
+    // TODO: Add an `# Examples` section with a runnable example
     /// Checks if the collection contains the item.
     pub fn contains(&self, item: &T) -> bool {
         self.items.contains(item)
     }
     
+    // TODO: Add an `# Examples` section with a runnable example
     /// Returns the number of items in the collection.
     pub fn len(&self) -> usize {
         self.items.len()
@@ -194,7 +207,7 @@ impl<T: PartialEq> UniqueCollection<T> {
     /// # Examples
     ///
     /// ```
-    /// use template::UniqueCollection;
+    /// use exercise_100::UniqueCollection;
     ///
     /// let mut coll: UniqueCollection<i32> = UniqueCollection::new();
     /// assert!(coll.is_empty());
@@ -212,7 +225,7 @@ impl<T: PartialEq> UniqueCollection<T> {
 /// # Examples
 ///
 /// ```
-/// use template::Serializable;
+/// use exercise_100::Serializable;
 ///
 /// let s = "hello".to_serialized();
 /// assert_eq!(s, "hello");
@@ -251,7 +264,7 @@ impl Serializable for Point {
 /// # Examples
 ///
 /// ```
-/// use template::safe_divide;
+/// use exercise_100::safe_divide;
 ///
 /// let result = safe_divide(10.0, 2.0);
 /// assert!(result.is_ok());
@@ -261,7 +274,7 @@ impl Serializable for Point {
 /// Division by zero:
 ///
 /// ```
-/// use template::safe_divide;
+/// use exercise_100::safe_divide;
 ///
 /// let result = safe_divide(10.0, 0.0);
 /// assert!(result.is_err());
@@ -280,12 +293,6 @@ pub fn internal_helper() -> i32 {
     42
 }
 
-fn main() {
-    println!("Documentation tests are run with: cargo test --doc");
-    println!("Run all tests with: cargo test");
-    println!("\nView documentation with: cargo doc --open");
-}
-
 // Unit tests are still useful alongside doctests
 #[cfg(test)]
 mod tests {
@@ -344,7 +351,7 @@ pub mod string_utils {
     /// # Examples
     ///
     /// ```
-    /// use template::string_utils::reverse;
+    /// use exercise_100::string_utils::reverse;
     ///
     /// assert_eq!(reverse("hello"), "olleh");
     /// assert_eq!(reverse(""), "");
@@ -359,7 +366,7 @@ pub mod string_utils {
     /// # Examples
     ///
     /// ```
-    /// use template::string_utils::is_palindrome;
+    /// use exercise_100::string_utils::is_palindrome;
     ///
     /// assert!(is_palindrome("racecar"));
     /// assert!(is_palindrome(""));
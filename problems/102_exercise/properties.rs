@@ -0,0 +1,28 @@
+//! Property tests for exercise 102: Selection Sort Algorithm.
+//!
+//! For any input, `selection_sort` must leave a sorted permutation of it.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+#[test]
+fn output_is_sorted() {
+    check(vecs(ints(i32::MIN..=i32::MAX), 0..=64), |mut arr| {
+        selection_sort(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]), "not sorted: {arr:?}");
+    });
+}
+
+#[test]
+fn output_is_a_permutation_of_the_input() {
+    // A narrow range, so that inputs are full of duplicates
+    check(vecs(ints(-5..=5), 0..=64), |input| {
+        let mut arr = input.clone();
+        selection_sort(&mut arr);
+        let mut expected = input;
+        expected.sort();
+        let mut actual = arr.clone();
+        actual.sort();
+        assert_eq!(actual, expected, "elements changed: {arr:?}");
+    });
+}
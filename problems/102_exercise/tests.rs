@@ -0,0 +1,57 @@
+//! Hidden tests for exercise 102: Selection Sort Algorithm.
+//!
+//! Large, duplicate-heavy and extreme-valued inputs checked against `sort`.
+
+use super::*;
+
+/// Deterministic pseudo-random values, so a failure always replays the same way.
+fn pseudo_random(len: usize, seed: u64) -> Vec<i32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 32) as u32 as i32
+        })
+        .collect()
+}
+
+fn check(mut arr: Vec<i32>) {
+    let mut expected = arr.clone();
+    expected.sort();
+    selection_sort(&mut arr);
+    assert_eq!(arr, expected);
+}
+
+#[test]
+fn two_elements() {
+    check(vec![2, 1]);
+    check(vec![1, 2]);
+}
+
+#[test]
+fn duplicates() {
+    check(vec![3, 1, 3, 2, 1, 3, 2]);
+    check(vec![7; 20]);
+}
+
+#[test]
+fn negatives_and_extremes() {
+    check(vec![0, -1, i32::MAX, -5, i32::MIN, 5, i32::MIN, i32::MAX]);
+}
+
+#[test]
+fn large_reverse_sorted() {
+    check((0..2_000).rev().collect());
+}
+
+#[test]
+fn large_random() {
+    for seed in 1..=5 {
+        check(pseudo_random(500, seed));
+    }
+}
+
+#[test]
+fn few_distinct_values() {
+    check(pseudo_random(1_000, 42).into_iter().map(|x| x.rem_euclid(4)).collect());
+}
@@ -0,0 +1,13 @@
+//! Property tests for exercise 104: Linear Search Algorithm.
+//!
+//! `linear_search` must find the first occurrence of any target, or none.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+#[test]
+fn finds_the_first_occurrence() {
+    check((vecs(ints(-10..=10), 0..=64), ints(-12..=12)), |(arr, target)| {
+        assert_eq!(linear_search(&arr, target), arr.iter().position(|&x| x == target));
+    });
+}
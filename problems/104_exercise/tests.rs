@@ -0,0 +1,43 @@
+//! Hidden tests for exercise 104: Linear Search Algorithm.
+//!
+//! Duplicates, extreme values and a large input.
+
+use super::*;
+
+#[test]
+fn returns_first_of_duplicates() {
+    let arr = [5, 3, 7, 3, 7, 3];
+    assert_eq!(linear_search(&arr, 3), Some(1));
+    assert_eq!(linear_search(&arr, 7), Some(2));
+}
+
+#[test]
+fn single_element_missing() {
+    assert_eq!(linear_search(&[42], 41), None);
+}
+
+#[test]
+fn negatives_and_extremes() {
+    let arr = [0, -1, i32::MIN, i32::MAX, -7];
+    assert_eq!(linear_search(&arr, i32::MIN), Some(2));
+    assert_eq!(linear_search(&arr, i32::MAX), Some(3));
+    assert_eq!(linear_search(&arr, -7), Some(4));
+    assert_eq!(linear_search(&arr, 7), None);
+}
+
+#[test]
+fn unsorted_input() {
+    let arr = [9, 2, 8, 1, 7];
+    for (i, &x) in arr.iter().enumerate() {
+        assert_eq!(linear_search(&arr, x), Some(i));
+    }
+}
+
+#[test]
+fn large_input() {
+    let arr: Vec<i32> = (0..100_000).map(|x| x * 3).collect();
+    assert_eq!(linear_search(&arr, 0), Some(0));
+    assert_eq!(linear_search(&arr, 299_997), Some(99_999));
+    assert_eq!(linear_search(&arr, 150_000), Some(50_000));
+    assert_eq!(linear_search(&arr, 150_001), None);
+}
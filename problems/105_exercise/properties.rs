@@ -0,0 +1,41 @@
+//! Property tests for exercise 105: Binary Search Algorithm.
+//!
+//! On sorted input, binary search must agree with a linear scan about
+//! whether the target is there. With duplicates any matching index will do.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+fn agrees_with_linear_search(arr: &[i32], target: i32, found: Option<usize>) {
+    match found {
+        Some(i) => assert_eq!(arr.get(i), Some(&target), "index {i} of {arr:?}"),
+        None => assert!(!arr.contains(&target), "missed {target} in {arr:?}"),
+    }
+}
+
+#[test]
+fn agrees_with_linear_search_on_sorted_input() {
+    check((vecs(ints(-20..=20), 0..=64), ints(-25..=25)), |(mut arr, target)| {
+        arr.sort();
+        agrees_with_linear_search(&arr, target, binary_search(&arr, target));
+    });
+}
+
+#[test]
+fn recursive_version_agrees_with_linear_search() {
+    check((vecs(ints(-20..=20), 0..=64), ints(-25..=25)), |(mut arr, target)| {
+        arr.sort();
+        let found = binary_search_recursive(&arr, target, 0, arr.len() as isize - 1);
+        agrees_with_linear_search(&arr, target, found);
+    });
+}
+
+#[test]
+fn finds_every_element_at_the_extremes() {
+    check(vecs(ints(i32::MIN..=i32::MAX), 1..=64), |mut arr| {
+        arr.sort();
+        for &target in &arr {
+            agrees_with_linear_search(&arr, target, binary_search(&arr, target));
+        }
+    });
+}
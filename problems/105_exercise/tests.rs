@@ -0,0 +1,69 @@
+//! Hidden tests for exercise 105: Binary Search Algorithm.
+//!
+//! Every position in arrays of each small length, out-of-range targets and a
+//! million-element input.
+
+use super::*;
+
+fn recursive(arr: &[i32], target: i32) -> Option<usize> {
+    binary_search_recursive(arr, target, 0, arr.len() as isize - 1)
+}
+
+#[test]
+fn every_element_of_odd_and_even_lengths() {
+    for len in 1..=16 {
+        let arr: Vec<i32> = (0..len).map(|x| x * 2).collect();
+        for (i, &x) in arr.iter().enumerate() {
+            assert_eq!(binary_search(&arr, x), Some(i), "len {len}, target {x}");
+            assert_eq!(recursive(&arr, x), Some(i), "len {len}, target {x}");
+        }
+    }
+}
+
+#[test]
+fn gaps_and_out_of_range_targets() {
+    // Targets below the first element must not underflow the right bound
+    let arr = [10, 20, 30, 40, 50];
+    for target in [i32::MIN, 0, 9, 15, 25, 35, 45, 51, i32::MAX] {
+        assert_eq!(binary_search(&arr, target), None, "target {target}");
+        assert_eq!(recursive(&arr, target), None, "target {target}");
+    }
+}
+
+#[test]
+fn single_element() {
+    assert_eq!(binary_search(&[7], 7), Some(0));
+    assert_eq!(binary_search(&[7], 6), None);
+    assert_eq!(binary_search(&[7], 8), None);
+    assert_eq!(recursive(&[7], 6), None);
+}
+
+#[test]
+fn extremes_and_negatives() {
+    let arr = [i32::MIN, -100, -1, 0, 1, 100, i32::MAX];
+    for (i, &x) in arr.iter().enumerate() {
+        assert_eq!(binary_search(&arr, x), Some(i));
+        assert_eq!(recursive(&arr, x), Some(i));
+    }
+}
+
+#[test]
+fn duplicates_find_a_matching_index() {
+    let arr = [1, 2, 2, 2, 2, 3, 3, 9];
+    for target in [2, 3] {
+        let i = binary_search(&arr, target).unwrap();
+        assert_eq!(arr[i], target);
+        let i = recursive(&arr, target).unwrap();
+        assert_eq!(arr[i], target);
+    }
+}
+
+#[test]
+fn large_input() {
+    let arr: Vec<i32> = (0..1_000_000).map(|x| x * 2).collect();
+    for i in (0..arr.len()).step_by(9_973).chain([arr.len() - 1]) {
+        assert_eq!(binary_search(&arr, arr[i]), Some(i));
+        assert_eq!(binary_search(&arr, arr[i] + 1), None);
+        assert_eq!(recursive(&arr, arr[i]), Some(i));
+    }
+}
@@ -0,0 +1,26 @@
+//! Hidden tests for exercise 106: Factorial (Recursive).
+//!
+//! The u64 limit at 20! and agreement between both versions.
+
+use super::*;
+
+#[test]
+fn largest_factorial_that_fits_in_u64() {
+    assert_eq!(factorial(20), 2_432_902_008_176_640_000);
+    assert_eq!(factorial_iterative(20), 2_432_902_008_176_640_000);
+}
+
+#[test]
+fn recursive_and_iterative_agree() {
+    for n in 0..=20 {
+        assert_eq!(factorial(n), factorial_iterative(n), "n = {n}");
+    }
+}
+
+#[test]
+fn each_step_multiplies_by_n() {
+    for n in 1..=20 {
+        assert_eq!(factorial(n), n * factorial(n - 1), "n = {n}");
+    }
+    assert_eq!(factorial(15), 1_307_674_368_000);
+}
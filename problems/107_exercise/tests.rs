@@ -0,0 +1,26 @@
+//! Hidden tests for exercise 107: Fibonacci (Recursive).
+//!
+//! The recurrence itself and inputs large enough to be slow without care.
+
+use super::*;
+
+#[test]
+fn recurrence_holds() {
+    for n in 2..=25 {
+        assert_eq!(fibonacci(n), fibonacci(n - 1) + fibonacci(n - 2), "n = {n}");
+    }
+}
+
+#[test]
+fn larger_values() {
+    assert_eq!(fibonacci(25), 75_025);
+    assert_eq!(fibonacci(30), 832_040);
+}
+
+#[test]
+fn small_values() {
+    let expected = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34];
+    for (n, &f) in expected.iter().enumerate() {
+        assert_eq!(fibonacci(n as u64), f, "n = {n}");
+    }
+}
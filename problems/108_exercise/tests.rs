@@ -0,0 +1,36 @@
+//! Hidden tests for exercise 108: Fibonacci (Iterative with Memoization).
+//!
+//! The u64 limit at F(93) and inputs that only finish with a working cache.
+
+use super::*;
+
+#[test]
+fn largest_fibonacci_that_fits_in_u64() {
+    assert_eq!(fibonacci_iterative(93), 12_200_160_415_121_876_738);
+    assert_eq!(fibonacci_memoized(93), 12_200_160_415_121_876_738);
+}
+
+#[test]
+fn all_versions_agree() {
+    let mut cache = std::collections::HashMap::new();
+    for n in 0..=93 {
+        let expected = fibonacci_iterative(n);
+        assert_eq!(fibonacci_memoized(n), expected, "n = {n}");
+        assert_eq!(fibonacci_with_cache(n, &mut cache), expected, "n = {n}");
+    }
+}
+
+#[test]
+fn cache_makes_large_inputs_fast() {
+    // Plain recursion would need ~10^18 calls here and time out
+    let mut cache = std::collections::HashMap::new();
+    assert_eq!(fibonacci_with_cache(90, &mut cache), 2_880_067_194_370_816_120);
+}
+
+#[test]
+fn fresh_cache_each_call() {
+    for n in [0, 1, 2, 50, 1, 0] {
+        let mut cache = std::collections::HashMap::new();
+        assert_eq!(fibonacci_with_cache(n, &mut cache), fibonacci_iterative(n), "n = {n}");
+    }
+}
@@ -0,0 +1,26 @@
+//! Property tests for exercise 109: Greatest Common Divisor (Euclidean Algorithm).
+//!
+//! `gcd(a, b) * lcm(a, b) == a * b` for any `a` and `b` whose product fits,
+//! and the GCD divides both numbers whatever their order.
+
+use super::*;
+use prop::{check, ints};
+
+#[test]
+fn gcd_times_lcm_is_the_product() {
+    check((ints(0..=1_000_000u64), ints(0..=1_000_000u64)), |(a, b)| {
+        assert_eq!(gcd(a, b) * lcm(a, b), a * b, "gcd({a}, {b}) = {}, lcm = {}", gcd(a, b), lcm(a, b));
+    });
+}
+
+#[test]
+fn gcd_divides_both_and_ignores_order() {
+    check((ints(0..=u64::MAX), ints(0..=u64::MAX)), |(a, b)| {
+        let g = gcd(a, b);
+        assert_eq!(g, gcd(b, a));
+        assert_eq!(g, gcd_recursive(a, b));
+        if g != 0 {
+            assert_eq!((a % g, b % g), (0, 0), "gcd({a}, {b}) = {g}");
+        }
+    });
+}
@@ -0,0 +1,53 @@
+//! Hidden tests for exercise 109: Greatest Common Divisor (Euclidean Algorithm).
+//!
+//! Euclid's worst case, u64 extremes and lcm overflow.
+
+use super::*;
+
+#[test]
+fn large_values() {
+    assert_eq!(gcd(1 << 63, 1 << 40), 1 << 40);
+    assert_eq!(gcd(u64::MAX, u64::MAX - 1), 1);
+    assert_eq!(gcd(u64::MAX, u64::MAX), u64::MAX);
+    assert_eq!(gcd_recursive(1 << 63, 3 << 40), 1 << 40);
+}
+
+#[test]
+fn consecutive_fibonacci_numbers_are_coprime() {
+    // The worst case for Euclid: one step per Fibonacci index
+    let (a, b) = (7_540_113_804_746_346_429, 4_660_046_610_375_530_309);
+    assert_eq!(gcd(a, b), 1);
+    assert_eq!(gcd_recursive(a, b), 1);
+}
+
+#[test]
+fn argument_order_does_not_matter() {
+    for a in 0..40 {
+        for b in 0..40 {
+            assert_eq!(gcd(a, b), gcd(b, a), "gcd({a}, {b})");
+            assert_eq!(gcd(a, b), gcd_recursive(a, b), "gcd({a}, {b})");
+        }
+    }
+}
+
+#[test]
+fn gcd_times_lcm_is_the_product() {
+    for a in 1..60u64 {
+        for b in 1..60u64 {
+            assert_eq!(gcd(a, b) * lcm(a, b), a * b, "a = {a}, b = {b}");
+        }
+    }
+}
+
+#[test]
+fn lcm_avoids_intermediate_overflow() {
+    assert_eq!(lcm(u64::MAX, 1), u64::MAX);
+    assert_eq!(lcm(u64::MAX, u64::MAX), u64::MAX);
+    assert_eq!(lcm(1 << 32, 3 << 31), 3 << 32);
+}
+
+#[test]
+fn lcm_with_zero() {
+    assert_eq!(lcm(0, 7), 0);
+    assert_eq!(lcm(7, 0), 0);
+}
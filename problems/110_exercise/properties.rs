@@ -0,0 +1,22 @@
+//! Property tests for exercise 110: Prime Number Check.
+//!
+//! `is_prime` must agree with trial division by every smaller number.
+
+use super::*;
+use prop::{check, ints};
+
+#[test]
+fn agrees_with_trial_division() {
+    check(ints(0..=20_000u64), |n| {
+        let expected = n >= 2 && (2..n).all(|d| n % d != 0);
+        assert_eq!(is_prime(n), expected, "is_prime({n})");
+    });
+}
+
+#[test]
+fn primes_up_to_lists_exactly_the_primes() {
+    check(ints(0..=2_000u64), |n| {
+        let expected: Vec<u64> = (2..=n).filter(|&x| (2..x).all(|d| x % d != 0)).collect();
+        assert_eq!(primes_up_to(n), expected, "primes_up_to({n})");
+    });
+}
@@ -0,0 +1,44 @@
+//! Hidden tests for exercise 110: Prime Number Check.
+//!
+//! Large primes, prime squares and Carmichael numbers.
+
+use super::*;
+
+#[test]
+fn large_primes() {
+    for p in [2_147_483_647, 998_244_353, 1_000_000_007, 999_999_999_989] {
+        assert!(is_prime(p), "{p} is prime");
+    }
+}
+
+#[test]
+fn squares_of_primes_are_composite() {
+    // A loop bound of `i * i < n` instead of `<=` misses these
+    for p in [3u64, 5, 7, 11, 101, 10_007, 46_337] {
+        assert!(!is_prime(p * p), "{} = {p}^2", p * p);
+    }
+}
+
+#[test]
+fn semiprimes_and_carmichael_numbers() {
+    assert!(!is_prime(1_000_003 * 1_000_033));
+    for n in [561, 1105, 1729, 2465, 41_041] {
+        assert!(!is_prime(n), "{n} is a Carmichael number");
+    }
+}
+
+#[test]
+fn agrees_with_naive_check() {
+    for n in 0..2_000 {
+        assert_eq!(is_prime(n), is_prime_naive(n), "n = {n}");
+    }
+}
+
+#[test]
+fn primes_up_to_edges() {
+    assert!(primes_up_to(0).is_empty());
+    assert!(primes_up_to(1).is_empty());
+    assert_eq!(primes_up_to(2), vec![2]);
+    assert_eq!(primes_up_to(3), vec![2, 3]);
+    assert_eq!(primes_up_to(100_000).len(), 9_592);
+}
@@ -0,0 +1,20 @@
+//! Property tests for exercise 111: Sieve of Eratosthenes.
+//!
+//! Both sieves must list exactly the primes up to and including the bound.
+
+use super::*;
+use prop::{check, ints};
+
+fn primes(n: usize) -> Vec<usize> {
+    (2..=n).filter(|&x| (2..x).all(|d| x % d != 0)).collect()
+}
+
+#[test]
+fn sieves_list_exactly_the_primes() {
+    check(ints(0..=3_000usize), |n| {
+        let expected = primes(n);
+        assert_eq!(sieve_of_eratosthenes(n), expected, "sieve_of_eratosthenes({n})");
+        assert_eq!(sieve_optimized(n), expected, "sieve_optimized({n})");
+        assert_eq!(count_primes(n), expected.len(), "count_primes({n})");
+    });
+}
@@ -0,0 +1,46 @@
+//! Hidden tests for exercise 111: Sieve of Eratosthenes.
+//!
+//! Small bounds, perfect squares and a sieve up to one million.
+
+use super::*;
+
+#[test]
+fn tiny_bounds() {
+    assert!(sieve_of_eratosthenes(0).is_empty());
+    assert!(sieve_optimized(0).is_empty());
+    assert!(sieve_optimized(1).is_empty());
+    assert_eq!(sieve_optimized(2), vec![2]);
+    assert_eq!(sieve_of_eratosthenes(3), vec![2, 3]);
+    assert_eq!(count_primes(0), 0);
+    assert_eq!(count_primes(2), 1);
+}
+
+#[test]
+fn prime_squares_as_the_bound() {
+    // The outer loop must reach sqrt(n) itself
+    assert_eq!(sieve_of_eratosthenes(25).last(), Some(&23));
+    assert_eq!(sieve_of_eratosthenes(49).last(), Some(&47));
+    assert_eq!(count_primes(121), 30);
+    assert_eq!(count_primes(169), 39);
+}
+
+#[test]
+fn bound_is_inclusive() {
+    assert_eq!(sieve_of_eratosthenes(29).last(), Some(&29));
+    assert_eq!(sieve_optimized(97).last(), Some(&97));
+}
+
+#[test]
+fn optimized_agrees_with_basic() {
+    for n in 0..500 {
+        assert_eq!(sieve_optimized(n), sieve_of_eratosthenes(n), "n = {n}");
+    }
+}
+
+#[test]
+fn one_million() {
+    let primes = sieve_of_eratosthenes(1_000_000);
+    assert_eq!(primes.len(), 78_498);
+    assert_eq!(primes.last(), Some(&999_983));
+    assert_eq!(count_primes(1_000_000), 78_498);
+}
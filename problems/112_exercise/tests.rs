@@ -0,0 +1,54 @@
+//! Hidden tests for exercise 112: Palindrome Check.
+//!
+//! Non-ASCII text, punctuation and numbers whose reversal overflows i32.
+
+use super::*;
+
+#[test]
+fn punctuation_and_case_are_ignored() {
+    assert!(is_palindrome_string("No 'x' in Nixon"));
+    assert!(is_palindrome_string("Madam, I'm Adam."));
+    assert!(is_palindrome_string("!!"));
+    assert!(!is_palindrome_string("ab!"));
+    assert!(is_palindrome_recursive("Never odd or even"));
+}
+
+#[test]
+fn non_ascii_text() {
+    assert!(is_palindrome_string("été"));
+    assert!(is_palindrome_string("Été, été"));
+    assert!(!is_palindrome_string("ça"));
+    assert!(is_palindrome_recursive("été"));
+    assert!(is_palindrome_recursive("é"));
+    assert!(is_palindrome_recursive("Été, été"));
+}
+
+#[test]
+fn two_characters() {
+    assert!(is_palindrome_string("aa"));
+    assert!(!is_palindrome_string("ab"));
+    assert!(is_palindrome_recursive("aA"));
+    assert!(!is_palindrome_recursive("ab"));
+}
+
+#[test]
+fn trailing_zeros() {
+    assert!(!is_palindrome_number(10));
+    assert!(!is_palindrome_number(1_000));
+    assert!(is_palindrome_number(1_000_000_001));
+}
+
+#[test]
+fn reversal_would_overflow() {
+    assert!(!is_palindrome_number(i32::MAX));
+    assert!(!is_palindrome_number(1_999_999_999));
+    assert!(is_palindrome_number(2_147_447_412));
+    assert!(!is_palindrome_number(i32::MIN));
+}
+
+#[test]
+fn number_versions_agree() {
+    for n in (0..20_000).chain([123_454_321, 123_456_789]) {
+        assert_eq!(is_palindrome_number(n), is_palindrome_number_string(n), "n = {n}");
+    }
+}
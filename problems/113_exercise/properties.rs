@@ -0,0 +1,25 @@
+//! Property tests for exercise 113: Reverse a String.
+//!
+//! Reversing reverses the characters, multi-byte ones included, so doing it
+//! twice gives back the original.
+
+use super::*;
+use prop::{check, strings};
+
+#[test]
+fn reverses_the_characters() {
+    check(strings("ab é🦀", 0..=32), |s| {
+        let reversed = reverse_string(&s);
+        assert!(reversed.chars().eq(s.chars().rev()), "reverse_string({s:?}) = {reversed:?}");
+        assert_eq!(reverse_string(&reversed), s);
+    });
+}
+
+#[test]
+fn in_place_matches_the_copy() {
+    check(strings("ab é🦀", 0..=32), |s| {
+        let mut in_place = s.clone();
+        reverse_string_in_place(&mut in_place);
+        assert_eq!(in_place, reverse_string(&s), "input {s:?}");
+    });
+}
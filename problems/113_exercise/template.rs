@@ -49,7 +49,8 @@ fn main() {
 /// 3. Collect back into a String
 fn reverse_string(s: &str) -> String {
     // TODO: Implement string reversal using chars
-    // Hint: Use .chars().rev().collect()
+    // HINTS `reverse_string`
+    // 1: Use .chars().rev().collect()
     s.chars().rev().collect()
 }
 
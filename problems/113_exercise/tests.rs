@@ -0,0 +1,50 @@
+//! Hidden tests for exercise 113: Reverse a String.
+//!
+//! Multi-byte characters and irregular whitespace.
+
+use super::*;
+
+#[test]
+fn multi_byte_characters() {
+    assert_eq!(reverse_string("こんにちは"), "はちにんこ");
+    assert_eq!(reverse_string("👋🌍"), "🌍👋");
+    assert_eq!(reverse_string("añb"), "bña");
+}
+
+#[test]
+fn in_place_matches_copy() {
+    for s in ["", "a", "hello", "ünïcödé", "a b  c"] {
+        let mut owned = s.to_string();
+        reverse_string_in_place(&mut owned);
+        assert_eq!(owned, reverse_string(s));
+    }
+}
+
+#[test]
+fn reversing_twice_is_identity() {
+    for s in ["Hello, World!", "👋🌍 ok", "   "] {
+        assert_eq!(reverse_string(&reverse_string(s)), s);
+    }
+}
+
+#[test]
+fn words_with_irregular_whitespace() {
+    assert_eq!(reverse_words("  one   two\tthree  "), "three two one");
+    assert_eq!(reverse_words(""), "");
+    assert_eq!(reverse_words("   "), "");
+    assert_eq!(reverse_words("single"), "single");
+}
+
+#[test]
+fn each_word_keeps_order() {
+    assert_eq!(reverse_each_word("  ab   cd "), "ba dc");
+    assert_eq!(reverse_each_word("añb ü"), "bña ü");
+}
+
+#[test]
+fn long_input() {
+    let s: String = (0..100_000).map(|i| char::from(b'a' + (i % 26) as u8)).collect();
+    let reversed = reverse_string(&s);
+    assert_eq!(reversed.len(), s.len());
+    assert!(reversed.chars().eq(s.chars().rev()));
+}
@@ -0,0 +1,33 @@
+//! Property tests for exercise 114: Anagram Check.
+//!
+//! Any shuffle of a word is an anagram of it, and the three checks agree on
+//! every pair of lowercase words.
+
+use super::*;
+use prop::{check, ints, strings};
+
+#[test]
+fn a_rotation_is_an_anagram() {
+    check((strings("abcd", 0..=24), ints(0..=24usize)), |(word, shift)| {
+        let chars: Vec<char> = word.chars().collect();
+        let shift = if chars.is_empty() { 0 } else { shift % chars.len() };
+        let rotated: String = chars[shift..].iter().chain(&chars[..shift]).collect();
+        assert!(is_anagram_sort(&word, &rotated), "{word:?} and {rotated:?}");
+        assert!(is_anagram_count(&word, &rotated), "{word:?} and {rotated:?}");
+        assert!(is_anagram_ascii(&word, &rotated), "{word:?} and {rotated:?}");
+    });
+}
+
+#[test]
+fn the_checks_agree() {
+    check((strings("abc", 0..=8), strings("abc", 0..=8)), |(s1, s2)| {
+        let mut a: Vec<char> = s1.chars().collect();
+        let mut b: Vec<char> = s2.chars().collect();
+        a.sort();
+        b.sort();
+        let expected = a == b;
+        assert_eq!(is_anagram_sort(&s1, &s2), expected, "is_anagram_sort({s1:?}, {s2:?})");
+        assert_eq!(is_anagram_count(&s1, &s2), expected, "is_anagram_count({s1:?}, {s2:?})");
+        assert_eq!(is_anagram_ascii(&s1, &s2), expected, "is_anagram_ascii({s1:?}, {s2:?})");
+    });
+}
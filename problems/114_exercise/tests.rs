@@ -0,0 +1,49 @@
+//! Hidden tests for exercise 114: Anagram Check.
+//!
+//! Repeated letters, phrases and non-ASCII words.
+
+use super::*;
+
+#[test]
+fn same_letters_different_counts() {
+    assert!(!is_anagram_sort("aab", "abb"));
+    assert!(!is_anagram_count("aab", "abb"));
+    assert!(!is_anagram_ascii("aab", "abb"));
+}
+
+#[test]
+fn phrases_with_spaces_and_case() {
+    assert!(is_anagram_sort("Astronomer", "Moon starer"));
+    assert!(is_anagram_count("Astronomer", "Moon starer"));
+    assert!(is_anagram_sort("The eyes", "They see"));
+    assert!(is_anagram_count("The eyes", "They see"));
+    assert!(is_anagram_ascii("Listen", "SILENT"));
+}
+
+#[test]
+fn non_ascii_words() {
+    assert!(is_anagram_sort("über", "rebü"));
+    assert!(is_anagram_count("über", "rebü"));
+    assert!(!is_anagram_count("über", "uber"));
+}
+
+#[test]
+fn one_side_empty() {
+    assert!(!is_anagram_sort("", "a"));
+    assert!(!is_anagram_count("a", ""));
+    assert!(!is_anagram_ascii("", "a"));
+}
+
+#[test]
+fn long_strings() {
+    let a: String = (0..50_000).map(|i| char::from(b'a' + (i % 26) as u8)).collect();
+    let b: String = a.chars().rev().collect();
+    assert!(is_anagram_sort(&a, &b));
+    assert!(is_anagram_count(&a, &b));
+    assert!(is_anagram_ascii(&a, &b));
+
+    let mut c = b.clone();
+    c.pop();
+    c.push('#');
+    assert!(!is_anagram_count(&a, &c));
+}
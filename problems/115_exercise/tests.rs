@@ -0,0 +1,47 @@
+//! Hidden tests for exercise 115: FizzBuzz Algorithm.
+//!
+//! Zero, u32::MAX, counts over a full sequence and the custom rules.
+
+use super::*;
+
+#[test]
+fn zero_and_max() {
+    // 0 is a multiple of everything; u32::MAX = 3 * 5 * 17 * 257 * 65537
+    assert_eq!(fizzbuzz(0), "FizzBuzz");
+    assert_eq!(fizzbuzz(u32::MAX), "FizzBuzz");
+    assert_eq!(fizzbuzz(u32::MAX - 1), "4294967294");
+    assert_eq!(fizzbuzz_match(u32::MAX), "FizzBuzz");
+}
+
+#[test]
+fn sequence_counts() {
+    let seq = fizzbuzz_sequence(100);
+    assert_eq!(seq.len(), 100);
+    let count = |w: &str| seq.iter().filter(|s| *s == w).count();
+    assert_eq!(count("FizzBuzz"), 6);
+    assert_eq!(count("Fizz"), 27);
+    assert_eq!(count("Buzz"), 14);
+    assert_eq!(seq[99], "Buzz");
+    assert!(fizzbuzz_sequence(0).is_empty());
+}
+
+#[test]
+fn custom_rules() {
+    assert_eq!(custom_fizzbuzz(4, 4, 4, "X", "Y"), "XY");
+    assert_eq!(custom_fizzbuzz(7, 2, 3, "A", "B"), "7");
+    assert_eq!(custom_fizzbuzz(15, 3, 5, "Fizz", "Buzz"), fizzbuzz(15));
+    for n in 1..200 {
+        assert_eq!(custom_fizzbuzz(n, 3, 5, "Fizz", "Buzz"), fizzbuzz(n), "n = {n}");
+    }
+}
+
+#[test]
+fn extended_rules() {
+    assert_eq!(fizzbuzz_extended(7), "Jazz");
+    assert_eq!(fizzbuzz_extended(70), "BuzzJazz");
+    assert_eq!(fizzbuzz_extended(15), "FizzBuzz");
+    assert_eq!(fizzbuzz_extended(8), "8");
+    for n in (1..1_000).filter(|n| n % 7 != 0) {
+        assert_eq!(fizzbuzz_extended(n), fizzbuzz(n), "n = {n}");
+    }
+}
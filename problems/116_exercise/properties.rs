@@ -0,0 +1,27 @@
+//! Property tests for exercise 116: Sum of Digits.
+//!
+//! Every version agrees with summing the decimal digits, and the digital
+//! root is `1 + (n - 1) % 9` for any positive `n`.
+
+use super::*;
+use prop::{check, ints};
+
+#[test]
+fn versions_agree_with_the_decimal_digits() {
+    check(ints(0..=u64::MAX), |n| {
+        let expected: u64 = n.to_string().bytes().map(|b| (b - b'0') as u64).sum();
+        assert_eq!(sum_of_digits(n), expected, "sum_of_digits({n})");
+        assert_eq!(sum_of_digits_recursive(n), expected, "sum_of_digits_recursive({n})");
+        assert_eq!(sum_of_digits_string(n), expected, "sum_of_digits_string({n})");
+        assert_eq!(sum_of_digits_base(n, 10), expected, "sum_of_digits_base({n}, 10)");
+    });
+}
+
+#[test]
+fn digital_root_matches_the_formula() {
+    check(ints(0..=u64::MAX), |n| {
+        let expected = if n == 0 { 0 } else { 1 + (n - 1) % 9 };
+        assert_eq!(digital_root(n), expected, "digital_root({n})");
+        assert_eq!(digital_root_formula(n), expected, "digital_root_formula({n})");
+    });
+}
@@ -0,0 +1,43 @@
+//! Hidden tests for exercise 116: Sum of Digits.
+//!
+//! u64::MAX, other bases and agreement between every version.
+
+use super::*;
+
+#[test]
+fn u64_max() {
+    // 18446744073709551615
+    assert_eq!(sum_of_digits(u64::MAX), 87);
+    assert_eq!(sum_of_digits_recursive(u64::MAX), 87);
+    assert_eq!(sum_of_digits_string(u64::MAX), 87);
+    assert_eq!(digital_root(u64::MAX), 6);
+    assert_eq!(digital_root_formula(u64::MAX), 6);
+}
+
+#[test]
+fn versions_agree() {
+    for n in (0..10_000).chain([u64::MAX / 3, 1 << 40, 10u64.pow(19)]) {
+        let expected = sum_of_digits(n);
+        assert_eq!(sum_of_digits_recursive(n), expected, "n = {n}");
+        assert_eq!(sum_of_digits_string(n), expected, "n = {n}");
+        assert_eq!(digital_root(n), digital_root_formula(n), "n = {n}");
+    }
+}
+
+#[test]
+fn digital_root_of_multiples_of_nine() {
+    assert_eq!(digital_root(0), 0);
+    for n in [9, 18, 99, 999_999_999] {
+        assert_eq!(digital_root(n), 9, "n = {n}");
+        assert_eq!(digital_root_formula(n), 9, "n = {n}");
+    }
+}
+
+#[test]
+fn other_bases() {
+    assert_eq!(sum_of_digits_base(u64::MAX, 2), 64);
+    assert_eq!(sum_of_digits_base(u64::MAX, 16), 240);
+    assert_eq!(sum_of_digits_base(0, 7), 0);
+    assert_eq!(sum_of_digits_base(123_456, 10), sum_of_digits(123_456));
+    assert_eq!(sum_of_digits_base(8, 8), 1);
+}
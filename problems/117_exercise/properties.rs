@@ -0,0 +1,26 @@
+//! Property tests for exercise 117: Power Calculation (Fast Exponentiation).
+//!
+//! Fast exponentiation agrees with `pow` whenever the result fits in a
+//! `u64`, and `modular_power` with repeated multiplication modulo `m`.
+
+use super::*;
+use prop::{check, ints};
+
+#[test]
+fn agrees_with_pow_when_the_result_fits() {
+    check((ints(0..=u64::MAX), ints(0..=64u32)), |(base, exp)| {
+        if let Some(expected) = base.checked_pow(exp) {
+            assert_eq!(fast_power(base, exp), expected, "fast_power({base}, {exp})");
+            assert_eq!(fast_power_recursive(base, exp), expected, "fast_power_recursive({base}, {exp})");
+        }
+    });
+}
+
+#[test]
+fn modular_power_agrees_with_repeated_multiplication() {
+    check((ints(0..=u64::MAX), ints(0..=300u32), ints(1..=u64::MAX)), |(base, exp, modulus)| {
+        let m = modulus as u128;
+        let expected = (0..exp).fold(1 % m, |acc, _| acc * (base as u128 % m) % m) as u64;
+        assert_eq!(modular_power(base, exp, modulus), expected, "modular_power({base}, {exp}, {modulus})");
+    });
+}
@@ -0,0 +1,54 @@
+//! Hidden tests for exercise 117: Power Calculation (Fast Exponentiation).
+//!
+//! Huge exponents that only finish in O(log n) and moduli above 2^32.
+
+use super::*;
+
+#[test]
+fn huge_exponent_is_logarithmic() {
+    // Repeated multiplication would need four billion steps here
+    assert_eq!(fast_power(1, u32::MAX), 1);
+    assert_eq!(fast_power_recursive(1, u32::MAX), 1);
+    assert_eq!(fast_power(0, u32::MAX), 0);
+    assert_eq!(modular_power(2, u32::MAX, 1_000_000_007), 987_337_205);
+}
+
+#[test]
+fn largest_powers_that_fit() {
+    assert_eq!(fast_power(2, 63), 1 << 63);
+    assert_eq!(fast_power(3, 40), 12_157_665_459_056_928_801);
+    assert_eq!(fast_power_recursive(3, 40), 12_157_665_459_056_928_801);
+    assert_eq!(fast_power(10, 19), 10_000_000_000_000_000_000);
+}
+
+#[test]
+fn zero_cases() {
+    assert_eq!(fast_power(0, 0), 1);
+    assert_eq!(fast_power(0, 3), 0);
+    assert_eq!(fast_power_recursive(0, 0), 1);
+    assert_eq!(modular_power(0, 0, 7), 1);
+    assert_eq!(modular_power(5, 3, 1), 0);
+}
+
+#[test]
+fn all_versions_agree() {
+    for base in 0..12 {
+        for exp in 0..30 {
+            let expected = naive_power(base, exp);
+            assert_eq!(fast_power(base, exp), expected, "{base}^{exp}");
+            assert_eq!(fast_power_recursive(base, exp), expected, "{base}^{exp}");
+            if let Some(exact) = base.checked_pow(exp) {
+                assert_eq!(modular_power(base, exp, 1_000_003), exact % 1_000_003, "{base}^{exp}");
+            }
+        }
+    }
+}
+
+#[test]
+fn modulus_above_u32() {
+    // (m - 1)^2 overflows u64 unless the product is widened
+    let m = (1 << 61) - 1;
+    assert_eq!(modular_power(m - 1, 2, m), 1);
+    assert_eq!(modular_power(2, 61, m), 1);
+    assert_eq!(modular_power(u64::MAX, 2, u64::MAX - 58), 3_364);
+}
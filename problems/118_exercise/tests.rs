@@ -0,0 +1,38 @@
+//! Hidden tests for exercise 118: Tower of Hanoi.
+//!
+//! Zero disks, the u64 limit of min_moves and the recursive move count.
+
+use super::*;
+
+#[test]
+fn zero_disks() {
+    assert_eq!(min_moves(0), 0);
+    assert_eq!(solve_hanoi(0, 'A', 'C', 'B'), 0);
+}
+
+#[test]
+fn recursive_count_matches_formula() {
+    for n in 1..=10 {
+        assert_eq!(solve_hanoi(n, 'A', 'C', 'B') as u64, min_moves(n), "n = {n}");
+    }
+}
+
+#[test]
+fn rod_names_do_not_matter() {
+    assert_eq!(solve_hanoi(5, 'X', 'Y', 'Z'), 31);
+    assert_eq!(solve_hanoi(5, 'C', 'A', 'B'), 31);
+}
+
+#[test]
+fn large_disk_counts() {
+    assert_eq!(min_moves(32), 4_294_967_295);
+    assert_eq!(min_moves(63), (1 << 63) - 1);
+    assert_eq!(min_moves(64), u64::MAX);
+}
+
+#[test]
+fn iterative_version_runs() {
+    hanoi_iterative(0);
+    hanoi_iterative(1);
+    hanoi_iterative(6);
+}
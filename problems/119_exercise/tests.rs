@@ -0,0 +1,65 @@
+//! Hidden tests for exercise 119: Linked List - Singly Linked (Manual).
+//!
+//! Long lists that overflow the stack under a recursive Drop, and reuse after
+//! emptying.
+
+use super::*;
+
+#[test]
+fn reverse_edge_cases() {
+    let mut empty: LinkedList<i32> = LinkedList::new();
+    empty.reverse();
+    assert!(empty.is_empty());
+
+    let mut one = LinkedList::new();
+    one.push(1);
+    one.reverse();
+    assert_eq!(one.to_vec(), vec![1]);
+    assert_eq!(one.len(), 1);
+}
+
+#[test]
+fn reverse_twice_restores_order() {
+    let mut list = LinkedList::new();
+    for i in 0..100 {
+        list.push(i);
+    }
+    let before = list.to_vec();
+    list.reverse();
+    assert_eq!(list.to_vec(), before.iter().rev().cloned().collect::<Vec<_>>());
+    list.reverse();
+    assert_eq!(list.to_vec(), before);
+}
+
+#[test]
+fn reuse_after_emptying() {
+    let mut list = LinkedList::new();
+    list.push("a");
+    assert_eq!(list.pop(), Some("a"));
+    assert_eq!(list.pop(), None);
+    assert_eq!(list.len(), 0);
+    list.push("b");
+    assert_eq!(list.peek(), Some(&"b"));
+    assert_eq!(list.len(), 1);
+}
+
+#[test]
+fn owned_values() {
+    let mut list = LinkedList::new();
+    list.push(String::from("x"));
+    list.push(String::from("y"));
+    assert_eq!(list.to_vec(), vec!["y".to_string(), "x".to_string()]);
+    assert_eq!(list.pop().as_deref(), Some("y"));
+}
+
+#[test]
+fn long_list_drops_without_stack_overflow() {
+    let mut list = LinkedList::new();
+    for i in 0..1_000_000 {
+        list.push(i);
+    }
+    assert_eq!(list.len(), 1_000_000);
+    list.reverse();
+    assert_eq!(list.peek(), Some(&0));
+    drop(list);
+}
@@ -0,0 +1,80 @@
+//! Hidden tests for exercise 120: Stack Implementation using Vec.
+//!
+//! Malformed and overflowing postfix input, and deep bracket nesting.
+
+use super::*;
+
+#[test]
+fn malformed_postfix_is_rejected() {
+    for expr in ["", "   ", "+", "1 +", "1 2", "1 2 + +", "3 x +", "1 2 %", "1.5 2 +"] {
+        assert_eq!(evaluate_postfix(expr), None, "{expr:?}");
+    }
+}
+
+#[test]
+fn division_edge_cases() {
+    assert_eq!(evaluate_postfix("4 0 /"), None);
+    assert_eq!(evaluate_postfix("7 2 /"), Some(3));
+    assert_eq!(evaluate_postfix("7 -2 /"), Some(-3));
+    assert_eq!(evaluate_postfix("-2147483648 -1 /"), None);
+}
+
+#[test]
+fn overflow_is_rejected() {
+    assert_eq!(evaluate_postfix("2147483647 1 +"), None);
+    assert_eq!(evaluate_postfix("-2147483648 1 -"), None);
+    assert_eq!(evaluate_postfix("65536 65536 *"), None);
+    assert_eq!(evaluate_postfix("2147483647 0 +"), Some(i32::MAX));
+}
+
+#[test]
+fn negative_operands_and_extra_spaces() {
+    assert_eq!(evaluate_postfix("-3 4 *"), Some(-12));
+    assert_eq!(evaluate_postfix("  10   3  -  "), Some(7));
+    assert_eq!(evaluate_postfix("42"), Some(42));
+}
+
+#[test]
+fn long_expression() {
+    let mut expr = "1".to_string();
+    for _ in 1..100_000 {
+        expr.push_str(" 1 +");
+    }
+    assert_eq!(evaluate_postfix(&expr), Some(100_000));
+}
+
+#[test]
+fn balance_edge_cases() {
+    assert!(is_balanced(""));
+    assert!(is_balanced("no brackets at all"));
+    assert!(is_balanced("fn f(x: [u8; 2]) { g(x[0]) }"));
+    assert!(!is_balanced("}{"));
+    assert!(!is_balanced("("));
+    assert!(!is_balanced(")"));
+}
+
+#[test]
+fn deep_nesting() {
+    let deep = "(".repeat(100_000) + &")".repeat(100_000);
+    assert!(is_balanced(&deep));
+    assert!(!is_balanced(&deep[1..]));
+}
+
+#[test]
+fn stack_reuse_and_clear() {
+    let mut stack = Stack::new();
+    for i in 0..1_000 {
+        stack.push(i);
+    }
+    assert_eq!(stack.size(), 1_000);
+    stack.clear();
+    assert!(stack.is_empty());
+    assert_eq!(stack.pop(), None);
+    stack.push(7);
+    assert_eq!(stack.peek(), Some(&7));
+}
+
+#[test]
+fn reverse_multi_byte() {
+    assert_eq!(reverse_with_stack("añ👋"), "👋ña");
+}
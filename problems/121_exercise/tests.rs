@@ -0,0 +1,80 @@
+//! Hidden tests for exercise 121: Queue Implementation.
+//!
+//! Interleaved operations, wrap-around in the circular queue and a queue large
+//! enough that an O(n) dequeue times out.
+
+use super::*;
+
+#[test]
+fn interleaved_operations_keep_fifo_order() {
+    let mut queue = Queue::new();
+    let mut expected = std::collections::VecDeque::new();
+    for i in 0..1_000 {
+        queue.enqueue(i);
+        expected.push_back(i);
+        if i % 3 == 0 {
+            assert_eq!(queue.dequeue(), expected.pop_front());
+        }
+        assert_eq!(queue.size(), expected.len());
+        assert_eq!(queue.peek(), expected.front());
+    }
+}
+
+#[test]
+fn large_queue_dequeues_in_constant_time() {
+    let mut queue = Queue::new();
+    for i in 0..200_000 {
+        queue.enqueue(i);
+    }
+    for i in 0..200_000 {
+        assert_eq!(queue.dequeue(), Some(i));
+    }
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn circular_queue_wraps_many_times() {
+    let mut cq = CircularQueue::new(3);
+    for i in 0..100 {
+        assert!(cq.enqueue(i));
+        assert!(cq.enqueue(i + 1000));
+        assert_eq!(cq.dequeue(), Some(i));
+        assert_eq!(cq.dequeue(), Some(i + 1000));
+        assert!(cq.is_empty());
+    }
+}
+
+#[test]
+fn circular_queue_of_one() {
+    let mut cq = CircularQueue::new(1);
+    assert!(cq.enqueue('a'));
+    assert!(cq.is_full());
+    assert!(!cq.enqueue('b'));
+    assert_eq!(cq.dequeue(), Some('a'));
+    assert!(cq.enqueue('c'));
+    assert_eq!(cq.dequeue(), Some('c'));
+    assert_eq!(cq.dequeue(), None);
+}
+
+#[test]
+fn circular_queue_of_zero() {
+    let mut cq = CircularQueue::new(0);
+    assert!(cq.is_empty());
+    assert!(cq.is_full());
+    assert!(!cq.enqueue(1));
+    assert_eq!(cq.dequeue(), None);
+}
+
+#[test]
+fn full_then_drain_then_refill() {
+    let mut cq = CircularQueue::new(4);
+    for round in 0..3 {
+        for i in 0..4 {
+            assert!(cq.enqueue(round * 10 + i));
+        }
+        assert!(!cq.enqueue(99));
+        for i in 0..4 {
+            assert_eq!(cq.dequeue(), Some(round * 10 + i));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Hidden tests for exercise 122: Binary Tree - Basic Structure.
+//!
+//! Sorted insertions that degenerate the tree, duplicates and many values.
+
+use super::*;
+
+/// Deterministic pseudo-random values, so a failure always replays the same way.
+fn pseudo_random(len: usize, seed: u64) -> Vec<i32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 48) as i32
+        })
+        .collect()
+}
+
+#[test]
+fn sorted_insertions() {
+    let mut tree = BinaryTree::new();
+    for i in 0..2_000 {
+        tree.insert(i);
+    }
+    assert_eq!(tree.size(), 2_000);
+    assert!(tree.contains(0));
+    assert!(tree.contains(1_999));
+    assert!(!tree.contains(2_000));
+    assert!(!tree.contains(-1));
+}
+
+#[test]
+fn reverse_sorted_insertions() {
+    let mut tree = BinaryTree::new();
+    for i in (0..2_000).rev() {
+        tree.insert(i);
+    }
+    assert_eq!(tree.size(), 2_000);
+    assert!((0..2_000).all(|i| tree.contains(i)));
+}
+
+#[test]
+fn duplicates_everywhere() {
+    let mut tree = BinaryTree::new();
+    for value in [5, 3, 8, 3, 5, 8, 1, 1, 9, 5] {
+        tree.insert(value);
+    }
+    assert_eq!(tree.size(), 5);
+}
+
+#[test]
+fn random_values_match_a_set() {
+    let values = pseudo_random(5_000, 7);
+    let mut tree = BinaryTree::new();
+    let mut set = std::collections::BTreeSet::new();
+    for &v in &values {
+        tree.insert(v);
+        set.insert(v);
+    }
+    assert_eq!(tree.size(), set.len());
+    for probe in -100..100 {
+        assert_eq!(tree.contains(probe), set.contains(&probe), "probe {probe}");
+    }
+    assert!(values.iter().all(|&v| tree.contains(v)));
+}
+
+#[test]
+fn extreme_values() {
+    let mut tree = BinaryTree::new();
+    for v in [0, i32::MIN, i32::MAX, -1, 1] {
+        tree.insert(v);
+    }
+    assert!(tree.contains(i32::MIN));
+    assert!(tree.contains(i32::MAX));
+    assert_eq!(tree.size(), 5);
+}
@@ -0,0 +1,92 @@
+//! Hidden tests for exercise 123: Binary Tree - Inorder Traversal.
+//!
+//! Degenerate trees, duplicates and traversals checked against a sorted set.
+
+use super::*;
+
+#[test]
+fn single_node() {
+    let mut tree = BinaryTree::new();
+    tree.insert(1);
+    assert_eq!(tree.inorder(), vec![1]);
+    assert_eq!(tree.preorder(), vec![1]);
+    assert_eq!(tree.postorder(), vec![1]);
+    assert_eq!(tree.level_order(), vec![1]);
+}
+
+#[test]
+fn right_leaning_chain() {
+    let mut tree = BinaryTree::new();
+    for i in 0..500 {
+        tree.insert(i);
+    }
+    let ascending: Vec<i32> = (0..500).collect();
+    let descending: Vec<i32> = (0..500).rev().collect();
+    assert_eq!(tree.inorder(), ascending);
+    assert_eq!(tree.preorder(), ascending);
+    assert_eq!(tree.postorder(), descending);
+    assert_eq!(tree.level_order(), ascending);
+}
+
+#[test]
+fn left_leaning_chain() {
+    let mut tree = BinaryTree::new();
+    for i in (0..500).rev() {
+        tree.insert(i);
+    }
+    let ascending: Vec<i32> = (0..500).collect();
+    let descending: Vec<i32> = (0..500).rev().collect();
+    assert_eq!(tree.inorder(), ascending);
+    assert_eq!(tree.preorder(), descending);
+    assert_eq!(tree.postorder(), ascending);
+}
+
+#[test]
+fn duplicates_are_ignored() {
+    let mut tree = BinaryTree::new();
+    for v in [4, 2, 6, 2, 4, 6, 4] {
+        tree.insert(v);
+    }
+    assert_eq!(tree.inorder(), vec![2, 4, 6]);
+    assert_eq!(tree.level_order(), vec![4, 2, 6]);
+}
+
+#[test]
+fn full_tree_orders() {
+    //            8
+    //       4         12
+    //     2   6    10    14
+    //    1 3 5 7  9 11 13 15
+    let mut tree = BinaryTree::new();
+    for v in [8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15] {
+        tree.insert(v);
+    }
+    assert_eq!(tree.inorder(), (1..=15).collect::<Vec<_>>());
+    assert_eq!(tree.preorder(), vec![8, 4, 2, 1, 3, 6, 5, 7, 12, 10, 9, 11, 14, 13, 15]);
+    assert_eq!(tree.postorder(), vec![1, 3, 2, 5, 7, 6, 4, 9, 11, 10, 13, 15, 14, 12, 8]);
+    assert_eq!(tree.level_order(), vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15]);
+}
+
+#[test]
+fn inorder_is_sorted_and_deduplicated() {
+    let mut tree = BinaryTree::new();
+    let mut set = std::collections::BTreeSet::new();
+    let mut x: u32 = 12_345;
+    for _ in 0..3_000 {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let v = (x >> 16) % 1_000;
+        tree.insert(v);
+        set.insert(v);
+    }
+    assert_eq!(tree.inorder(), set.into_iter().collect::<Vec<_>>());
+    assert_eq!(tree.level_order().len(), tree.inorder().len());
+}
+
+#[test]
+fn string_values() {
+    let mut tree = BinaryTree::new();
+    for w in ["pear", "apple", "zucchini", "fig"] {
+        tree.insert(w.to_string());
+    }
+    assert_eq!(tree.inorder(), vec!["apple", "fig", "pear", "zucchini"]);
+}
@@ -0,0 +1,87 @@
+//! Hidden tests for exercise 124: Binary Tree - Height Calculation.
+//!
+//! Skewed and perfectly balanced trees, subtrees that are unbalanced below a
+//! balanced root, and diameters that do not pass through the root.
+
+use super::*;
+
+fn tree_of(values: &[i32]) -> BinaryTree<i32> {
+    let mut tree = BinaryTree::new();
+    for &v in values {
+        tree.insert(v);
+    }
+    tree
+}
+
+/// Insertion order that builds a perfect tree over lo..=hi.
+fn balanced_order(lo: i32, hi: i32, out: &mut Vec<i32>) {
+    if lo > hi {
+        return;
+    }
+    let mid = lo + (hi - lo) / 2;
+    out.push(mid);
+    balanced_order(lo, mid - 1, out);
+    balanced_order(mid + 1, hi, out);
+}
+
+#[test]
+fn long_chain_height() {
+    let values: Vec<i32> = (0..1_000).collect();
+    let tree = tree_of(&values);
+    assert_eq!(tree.height(), 1_000);
+    assert!(!tree.is_balanced());
+    assert_eq!(tree.check_balanced(), None);
+    assert_eq!(tree.diameter(), 1_000);
+}
+
+#[test]
+fn perfect_tree() {
+    let mut order = Vec::new();
+    balanced_order(1, 1_023, &mut order);
+    let tree = tree_of(&order);
+    assert_eq!(tree.height(), 10);
+    assert!(tree.is_balanced());
+    assert_eq!(tree.check_balanced(), Some(10));
+    assert_eq!(tree.diameter(), 19);
+}
+
+#[test]
+fn empty_tree_is_balanced() {
+    let tree: BinaryTree<i32> = BinaryTree::new();
+    assert!(tree.is_balanced());
+    assert_eq!(tree.check_balanced(), Some(0));
+    assert_eq!(tree.diameter(), 0);
+}
+
+#[test]
+fn unbalanced_below_a_balanced_root() {
+    //          50
+    //       30    70
+    //     20        80
+    //   10            90
+    // Root heights are equal, but 30 and 70 each lean by two
+    let tree = tree_of(&[50, 30, 70, 20, 80, 10, 90]);
+    assert_eq!(tree.height(), 4);
+    assert!(!tree.is_balanced());
+    assert_eq!(tree.check_balanced(), None);
+}
+
+#[test]
+fn lean_of_one_is_still_balanced() {
+    let tree = tree_of(&[50, 30, 70, 20, 40, 60, 10]);
+    assert!(tree.is_balanced());
+    assert_eq!(tree.check_balanced(), Some(4));
+}
+
+#[test]
+fn diameter_away_from_root() {
+    //     100
+    //    50
+    //  30   70
+    // 20 40 60 80
+    // 10        90
+    // The longest path, 10 .. 90, never visits the root
+    let tree = tree_of(&[100, 50, 30, 70, 20, 40, 60, 80, 10, 90]);
+    assert_eq!(tree.diameter(), 7);
+    assert_eq!(tree.height(), 5);
+}
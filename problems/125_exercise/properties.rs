@@ -0,0 +1,30 @@
+//! Property tests for exercise 125: Min/Max Finding in Array.
+//!
+//! Every method finds the same minimum and maximum as `Iterator::min` and
+//! `Iterator::max`, on any non-empty input.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+#[test]
+fn all_methods_agree_with_the_iterator() {
+    check(vecs(ints(i32::MIN..=i32::MAX), 1..=64), |arr| {
+        let expected = (*arr.iter().min().unwrap(), *arr.iter().max().unwrap());
+        assert_eq!(find_min_max_simple(&arr), expected, "find_min_max_simple({arr:?})");
+        assert_eq!(find_min_max_single_pass(&arr), expected, "find_min_max_single_pass({arr:?})");
+        assert_eq!(find_min_max_tournament(&arr), expected, "find_min_max_tournament({arr:?})");
+        assert_eq!(find_min_max_iter(&arr), expected, "find_min_max_iter({arr:?})");
+        assert_eq!(find_min_max_generic(&arr), Some(expected), "find_min_max_generic({arr:?})");
+    });
+}
+
+#[test]
+fn indices_point_at_the_extremes() {
+    check(vecs(ints(-5..=5), 0..=64), |arr| match find_min_max_indices(&arr) {
+        Some((min, max)) => {
+            assert_eq!(arr[min], *arr.iter().min().unwrap(), "min index {min} of {arr:?}");
+            assert_eq!(arr[max], *arr.iter().max().unwrap(), "max index {max} of {arr:?}");
+        }
+        None => assert!(arr.is_empty(), "no indices for {arr:?}"),
+    });
+}
@@ -0,0 +1,91 @@
+//! Hidden tests for exercise 125: Min/Max Finding in Array.
+//!
+//! Odd lengths for the pairwise method, extreme values, ties and sentinel
+//! values appearing as real data.
+
+use super::*;
+
+fn all_methods(arr: &[i32]) -> [(i32, i32); 4] {
+    [
+        find_min_max_simple(arr),
+        find_min_max_single_pass(arr),
+        find_min_max_tournament(arr),
+        find_min_max_iter(arr),
+    ]
+}
+
+#[test]
+fn odd_and_even_lengths() {
+    for len in 1..=9 {
+        let arr: Vec<i32> = (0..len).map(|i| (i * 7) % 10 - 4).collect();
+        let expected = (*arr.iter().min().unwrap(), *arr.iter().max().unwrap());
+        for got in all_methods(&arr) {
+            assert_eq!(got, expected, "arr = {arr:?}");
+        }
+    }
+}
+
+#[test]
+fn extremes_at_either_end() {
+    let arr = [i32::MAX, 0, 5, -5, i32::MIN];
+    for got in all_methods(&arr) {
+        assert_eq!(got, (i32::MIN, i32::MAX));
+    }
+    let arr = [i32::MIN, 3, i32::MAX];
+    for got in all_methods(&arr) {
+        assert_eq!(got, (i32::MIN, i32::MAX));
+    }
+}
+
+#[test]
+fn all_equal() {
+    let arr = [7; 11];
+    for got in all_methods(&arr) {
+        assert_eq!(got, (7, 7));
+    }
+    assert_eq!(find_second_min_max(&arr), None);
+}
+
+#[test]
+fn indices() {
+    assert_eq!(find_min_max_indices(&[]), None);
+    assert_eq!(find_min_max_indices(&[4]), Some((0, 0)));
+    let arr = [3, i32::MIN, 8, i32::MAX, 0];
+    assert_eq!(find_min_max_indices(&arr), Some((1, 3)));
+}
+
+#[test]
+fn second_min_max_with_sentinel_values() {
+    // i32::MAX and i32::MIN are real data here, not "not found" markers
+    assert_eq!(find_second_min_max(&[1, i32::MAX]), Some((i32::MAX, 1)));
+    assert_eq!(find_second_min_max(&[i32::MIN, 5, 9]), Some((5, 5)));
+    assert_eq!(find_second_min_max(&[i32::MIN, i32::MAX]), Some((i32::MAX, i32::MIN)));
+}
+
+#[test]
+fn second_min_max_skips_duplicates() {
+    assert_eq!(find_second_min_max(&[1, 1, 2, 9, 9]), Some((2, 2)));
+    assert_eq!(find_second_min_max(&[2, 1]), Some((2, 1)));
+    assert_eq!(find_second_min_max(&[3]), None);
+    assert_eq!(find_second_min_max(&[]), None);
+}
+
+#[test]
+fn generic_version() {
+    assert_eq!(find_min_max_generic::<i32>(&[]), None);
+    assert_eq!(find_min_max_generic(&['q', 'a', 'z']), Some(('a', 'z')));
+    let words = ["delta".to_string(), "alpha".to_string(), "echo".to_string()];
+    assert_eq!(
+        find_min_max_generic(&words),
+        Some(("alpha".to_string(), "echo".to_string()))
+    );
+}
+
+#[test]
+fn large_input() {
+    let arr: Vec<i32> = (0..1_000_001).map(|i: i32| i.wrapping_mul(7_919) % 1_000_003).collect();
+    let expected = (*arr.iter().min().unwrap(), *arr.iter().max().unwrap());
+    for got in all_methods(&arr) {
+        assert_eq!(got, expected);
+    }
+}
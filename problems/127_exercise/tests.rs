@@ -0,0 +1,75 @@
+//! Hidden tests for exercise 127: Quick Sort Algorithm.
+//!
+//! Sorted, reverse-sorted and all-equal inputs that make a naive pivot choice
+//! quadratic, plus duplicates and extreme values checked against `sort`.
+
+use super::*;
+
+/// Deterministic pseudo-random values, so a failure always replays the same way.
+fn pseudo_random(len: usize, seed: u64) -> Vec<i32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 32) as u32 as i32
+        })
+        .collect()
+}
+
+fn check(mut arr: Vec<i32>) {
+    let mut expected = arr.clone();
+    expected.sort();
+    quick_sort(&mut arr);
+    assert_eq!(arr, expected);
+}
+
+#[test]
+fn two_elements() {
+    check(vec![2, 1]);
+    check(vec![1, 2]);
+}
+
+#[test]
+fn odd_lengths() {
+    for len in [3, 5, 7, 9, 31] {
+        check((0..len).rev().collect());
+        check(pseudo_random(len as usize, len as u64));
+    }
+}
+
+#[test]
+fn negatives_and_extremes() {
+    check(vec![0, -1, i32::MAX, -5, i32::MIN, 5, i32::MIN, i32::MAX]);
+}
+
+#[test]
+fn all_equal() {
+    check(vec![7; 2_000]);
+}
+
+#[test]
+fn few_distinct_values() {
+    check(pseudo_random(2_000, 42).into_iter().map(|x| x.rem_euclid(4)).collect());
+}
+
+#[test]
+fn already_sorted() {
+    check((0..2_000).collect());
+}
+
+#[test]
+fn reverse_sorted() {
+    check((0..2_000).rev().collect());
+}
+
+#[test]
+fn organ_pipe() {
+    let mut arr: Vec<i32> = (0..1_000).collect();
+    arr.extend((0..1_000).rev());
+    check(arr);
+}
+
+#[test]
+fn large_random() {
+    check(pseudo_random(100_000, 7));
+}
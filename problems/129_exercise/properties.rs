@@ -0,0 +1,29 @@
+//! Property tests for exercise 129: Counting Sort Algorithm.
+//!
+//! Both versions must agree with `sort` on any input. Values stay within a
+//! few thousand of each other, as counting sort allocates a count for each.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+#[test]
+fn agrees_with_sort() {
+    check(vecs(ints(-2000..=2000), 0..=64), |input| {
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        counting_sort(&mut arr);
+        assert_eq!(arr, expected);
+    });
+}
+
+#[test]
+fn in_place_version_agrees_with_sort() {
+    check(vecs(ints(-2000..=2000), 0..=64), |input| {
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        counting_sort_in_place(&mut arr);
+        assert_eq!(arr, expected);
+    });
+}
@@ -47,7 +47,8 @@ fn counting_sort(arr: &mut [i32]) {
 /// This version modifies the array in-place without extra output array
 fn counting_sort_in_place(arr: &mut [i32]) {
     // TODO: Implement an in-place version
-    // Hint: Use the count array to determine how many times each element appears,
+    // HINTS `counting_sort_in_place`
+    // 1: Use the count array to determine how many times each element appears,
     // then overwrite the original array with sorted values
 }
 
@@ -0,0 +1,57 @@
+//! Hidden tests for exercise 129: Counting Sort Algorithm.
+//!
+//! Wide value ranges, values that all sit far from zero, and both versions
+//! checked against `sort`.
+
+use super::*;
+
+fn check(arr: Vec<i32>) {
+    let mut expected = arr.clone();
+    expected.sort();
+    let mut a = arr.clone();
+    counting_sort(&mut a);
+    assert_eq!(a, expected, "counting_sort");
+    let mut b = arr;
+    counting_sort_in_place(&mut b);
+    assert_eq!(b, expected, "counting_sort_in_place");
+}
+
+#[test]
+fn two_elements() {
+    check(vec![2, 1]);
+    check(vec![0, 0]);
+}
+
+#[test]
+fn in_place_basics() {
+    check(vec![]);
+    check(vec![9]);
+    check(vec![4, 2, 2, 8, 3, 3, 1]);
+}
+
+#[test]
+fn all_equal() {
+    check(vec![5; 1_000]);
+}
+
+#[test]
+fn wide_range() {
+    check(vec![1_000_000, 0, 999_999, 1, 500_000, 0, 1_000_000]);
+}
+
+#[test]
+fn values_far_from_zero() {
+    check((0..10_000).map(|i| 2_000_000_000 - (i * 37) % 1_000).collect());
+}
+
+#[test]
+fn many_values() {
+    let mut x: u32 = 1;
+    let arr: Vec<i32> = (0..200_000)
+        .map(|_| {
+            x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            ((x >> 16) % 5_000) as i32
+        })
+        .collect();
+    check(arr);
+}
@@ -0,0 +1,131 @@
+//! Hidden tests for exercise 130: Depth First Search (DFS) on Graph.
+//!
+//! Cycles, self-loops, parallel edges and out-of-range edges, with every
+//! recursive visit order checked against the definition of depth-first.
+
+use super::*;
+
+use std::collections::HashSet as Set;
+
+/// Every vertex reachable from `start`, found without DFS.
+fn reachable(graph: &Graph, start: usize) -> Set<usize> {
+    let mut seen = Set::from([start]);
+    let mut frontier = vec![start];
+    while let Some(v) = frontier.pop() {
+        for &w in &graph.adj[v] {
+            if seen.insert(w) {
+                frontier.push(w);
+            }
+        }
+    }
+    seen
+}
+
+/// Checks that `order` is a depth-first order: each new vertex is a neighbour
+/// of the deepest vertex on the current path that still has unvisited
+/// neighbours, and a vertex is only left once all its neighbours are visited.
+fn assert_depth_first(graph: &Graph, start: usize, order: &[usize]) {
+    assert_eq!(order.first(), Some(&start));
+    assert_eq!(order.iter().copied().collect::<Set<_>>(), reachable(graph, start));
+    assert_eq!(order.iter().collect::<Set<_>>().len(), order.len(), "repeated vertex");
+
+    let mut visited = Set::from([start]);
+    let mut path = vec![start];
+    for &v in &order[1..] {
+        while let Some(&top) = path.last() {
+            if graph.adj[top].contains(&v) {
+                break;
+            }
+            let pending: Vec<_> = graph.adj[top].iter().filter(|w| !visited.contains(w)).collect();
+            assert!(pending.is_empty(), "left {top} before visiting {pending:?}; order {order:?}");
+            path.pop();
+        }
+        assert!(!path.is_empty(), "{v} is not adjacent to the current path; order {order:?}");
+        visited.insert(v);
+        path.push(v);
+    }
+}
+
+fn graph_of(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+    let mut graph = Graph::new(vertices);
+    for &(u, v) in edges {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+#[test]
+fn recursive_order_is_depth_first() {
+    let graph = graph_of(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6), (3, 4), (5, 6)]);
+    for start in 0..7 {
+        assert_depth_first(&graph, start, &graph.dfs_recursive(start));
+    }
+}
+
+#[test]
+fn cycle() {
+    let graph = graph_of(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    let order = graph.dfs_recursive(0);
+    assert_depth_first(&graph, 0, &order);
+    assert_eq!(order.len(), 5);
+    assert_eq!(graph.dfs_iterative(0).len(), 5);
+}
+
+#[test]
+fn self_loops_and_parallel_edges() {
+    let graph = graph_of(3, &[(0, 0), (0, 1), (0, 1), (1, 1), (1, 2), (2, 1)]);
+    let order = graph.dfs_recursive(0);
+    assert_depth_first(&graph, 0, &order);
+    let mut iterative = graph.dfs_iterative(0);
+    assert_eq!(iterative[0], 0);
+    iterative.sort();
+    assert_eq!(iterative, vec![0, 1, 2]);
+}
+
+#[test]
+fn out_of_range_edges_are_ignored() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 3);
+    graph.add_edge(7, 2);
+    assert_eq!(graph.dfs_recursive(0), vec![0, 1]);
+    assert_eq!(graph.dfs_iterative(0), vec![0, 1]);
+}
+
+#[test]
+fn isolated_start() {
+    let graph = graph_of(4, &[(1, 2), (2, 3)]);
+    assert_eq!(graph.dfs_recursive(0), vec![0]);
+    assert_eq!(graph.dfs_iterative(0), vec![0]);
+}
+
+#[test]
+fn iterative_reaches_the_same_vertices() {
+    let mut x: u32 = 99;
+    let mut edges = Vec::new();
+    for _ in 0..300 {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let u = (x >> 16) as usize % 200;
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let v = (x >> 16) as usize % 200;
+        edges.push((u, v));
+    }
+    let graph = graph_of(200, &edges);
+    for start in [0, 17, 199] {
+        let recursive = graph.dfs_recursive(start);
+        assert_depth_first(&graph, start, &recursive);
+        let iterative = graph.dfs_iterative(start);
+        assert_eq!(iterative[0], start);
+        assert_eq!(iterative.len(), recursive.len());
+        assert_eq!(iterative.iter().collect::<Set<_>>(), recursive.iter().collect::<Set<_>>());
+    }
+}
+
+#[test]
+fn long_path() {
+    let edges: Vec<_> = (0..2_999).map(|i| (i, i + 1)).collect();
+    let graph = graph_of(3_000, &edges);
+    assert_eq!(graph.dfs_recursive(0), (0..3_000).collect::<Vec<_>>());
+    assert_eq!(graph.dfs_iterative(0), (0..3_000).collect::<Vec<_>>());
+    assert_eq!(graph.dfs_recursive(1_500).len(), 3_000);
+}
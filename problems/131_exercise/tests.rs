@@ -0,0 +1,97 @@
+//! Hidden tests for exercise 131: Breadth First Search (BFS) on Graph.
+//!
+//! Cycles, disconnected parts and long paths, with BFS order checked level by
+//! level and every shortest path checked against the distances.
+
+use super::*;
+
+fn graph_of(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+    let mut graph = Graph::new(vertices);
+    for &(u, v) in edges {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+/// A small grid-with-chords graph that has many equal-length paths.
+fn sample() -> Graph {
+    graph_of(
+        9,
+        &[(0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8), (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)],
+    )
+}
+
+#[test]
+fn bfs_visits_in_distance_order() {
+    let graph = sample();
+    for start in 0..9 {
+        let order = graph.bfs(start);
+        let distances = graph.bfs_with_distance(start);
+        assert_eq!(order.len(), 9);
+        assert_eq!(order[0], start);
+        assert!(order.windows(2).all(|w| distances[w[0]] <= distances[w[1]]), "{order:?}");
+    }
+}
+
+#[test]
+fn grid_distances() {
+    let distances = sample().bfs_with_distance(0);
+    assert_eq!(distances, vec![0, 1, 2, 1, 2, 3, 2, 3, 4]);
+}
+
+#[test]
+fn unreachable_distances() {
+    let graph = graph_of(5, &[(0, 1), (2, 3)]);
+    let distances = graph.bfs_with_distance(0);
+    assert_eq!(distances, vec![0, 1, usize::MAX, usize::MAX, usize::MAX]);
+    assert_eq!(graph.bfs(4), vec![4]);
+}
+
+#[test]
+fn path_to_itself() {
+    let graph = sample();
+    assert_eq!(graph.shortest_path(4, 4), Some(vec![4]));
+}
+
+#[test]
+fn shortest_paths_are_valid_and_minimal() {
+    let graph = sample();
+    for start in 0..9 {
+        let distances = graph.bfs_with_distance(start);
+        for end in 0..9 {
+            let path = graph.shortest_path(start, end).unwrap();
+            assert_eq!(path.first(), Some(&start));
+            assert_eq!(path.last(), Some(&end));
+            assert_eq!(path.len(), distances[end] + 1, "{start} -> {end}: {path:?}");
+            for hop in path.windows(2) {
+                assert!(graph.adj[hop[0]].contains(&hop[1]), "{path:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn cycle_takes_the_short_way_round() {
+    let edges: Vec<_> = (0..10).map(|i| (i, (i + 1) % 10)).collect();
+    let graph = graph_of(10, &edges);
+    assert_eq!(graph.shortest_path(0, 8), Some(vec![0, 9, 8]));
+    assert_eq!(graph.bfs_with_distance(0)[5], 5);
+}
+
+#[test]
+fn self_loops_and_parallel_edges() {
+    let graph = graph_of(3, &[(0, 0), (0, 1), (0, 1), (1, 2)]);
+    assert_eq!(graph.bfs(0), vec![0, 1, 2]);
+    assert_eq!(graph.bfs_with_distance(0), vec![0, 1, 2]);
+    assert_eq!(graph.shortest_path(2, 0), Some(vec![2, 1, 0]));
+}
+
+#[test]
+fn long_path() {
+    let edges: Vec<_> = (0..99_999).map(|i| (i, i + 1)).collect();
+    let graph = graph_of(100_000, &edges);
+    assert_eq!(graph.bfs(0).len(), 100_000);
+    assert_eq!(graph.bfs_with_distance(0)[99_999], 99_999);
+    assert_eq!(graph.shortest_path(0, 99_999).unwrap().len(), 100_000);
+    assert_eq!(graph.shortest_path(50_000, 0).unwrap().len(), 50_001);
+}
@@ -2,32 +2,39 @@
 //
 // Learning Objective:
 // Implement Dijkstra's algorithm to find the shortest path in a weighted graph
-// with non-negative edge weights.
+// with non-negative edge weights, then the algorithms that follow from it.
 //
 // Key Concepts:
 // - Greedy algorithm
 // - Priority queue (min-heap)
 // - Relaxation of edges
 // - Time complexity: O((V + E) log V) with binary heap
+//
+// Stages:
+// 1. Dijkstra, with path reconstruction
+// 2. Bellman-Ford: negative edges, and reporting negative cycles - O(V * E)
+// 3. A*: Dijkstra guided by a heuristic, on a grid map read from text
+// 4. Bidirectional Dijkstra: searching from both ends until they meet
 
 use std::collections::{BinaryHeap, HashMap};
 use std::cmp::Ordering;
 
 /// Represents a weighted edge in the graph
 #[derive(Debug, Clone, Copy)]
-struct Edge {
+struct Edge<W = u32> {
     to: usize,
-    weight: u32,
+    weight: W,
 }
 
 /// Graph represented using adjacency list
+/// Weights are u32 unless said otherwise; Bellman-Ford takes a Graph<i64>
 #[derive(Debug, Clone)]
-struct Graph {
+struct Graph<W = u32> {
     vertices: usize,
-    adj: Vec<Vec<Edge>>,
+    adj: Vec<Vec<Edge<W>>>,
 }
 
-impl Graph {
+impl<W: Copy> Graph<W> {
     fn new(vertices: usize) -> Self {
         Graph {
             vertices,
@@ -36,15 +43,26 @@ impl Graph {
     }
     
     /// Adds a directed edge from u to v with given weight
-    fn add_edge(&mut self, u: usize, v: usize, weight: u32) {
+    fn add_edge(&mut self, u: usize, v: usize, weight: W) {
         self.adj[u].push(Edge { to: v, weight });
     }
     
     /// Adds an undirected edge between u and v
-    fn add_undirected_edge(&mut self, u: usize, v: usize, weight: u32) {
+    fn add_undirected_edge(&mut self, u: usize, v: usize, weight: W) {
         self.add_edge(u, v, weight);
         self.add_edge(v, u, weight);
     }
+
+    /// The same graph with every edge pointing the other way
+    fn reversed(&self) -> Graph<W> {
+        let mut reversed = Graph::new(self.vertices);
+        for (u, edges) in self.adj.iter().enumerate() {
+            for edge in edges {
+                reversed.add_edge(edge.to, u, edge.weight);
+            }
+        }
+        reversed
+    }
 }
 
 /// State for priority queue: (distance, vertex)
@@ -107,6 +125,160 @@ fn reconstruct_path(parents: &[Option<usize>], start: usize, end: usize) -> Opti
     None
 }
 
+// Stage 2: Bellman-Ford, for graphs with negative edges
+
+/// A cycle of negative total weight reachable from the start, listed in
+/// edge order: cycle[i] -> cycle[i + 1], and the last vertex back to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NegativeCycle {
+    cycle: Vec<usize>,
+}
+
+/// TODO: Implement the Bellman-Ford algorithm
+/// Returns the shortest distances from start (None where unreachable), or
+/// the negative cycle that leaves some of them without a shortest path
+fn bellman_ford(graph: &Graph<i64>, start: usize) -> Result<Vec<Option<i64>>, NegativeCycle> {
+    let mut distances: Vec<Option<i64>> = vec![None; graph.vertices];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.vertices];
+    
+    // TODO: Relax every edge, V - 1 times over (stop early once nothing changes)
+    // TODO: If an edge still relaxes in round V, a negative cycle is behind it:
+    //   Walk V parents back from the vertex it relaxed - that lands on the cycle
+    //   Follow parents from there until you are back, then reverse the list
+    
+    Ok(distances)
+}
+
+// Stage 3: A* on a grid map
+
+/// A map read from text, one row per line: '.' is open ground costing 1
+/// to enter, '1' to '9' rough ground costing that much, '#' a wall, and
+/// 'S' and 'G' the start and the goal on open ground
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Grid {
+    width: usize,
+    height: usize,
+    /// Cost of entering each cell, row by row; None for walls
+    cells: Vec<Option<u32>>,
+    start: usize,
+    goal: usize,
+}
+
+impl Grid {
+    /// Parses a map; every row must be as wide as the first
+    fn parse(text: &str) -> Result<Grid, String> {
+        let rows: Vec<&str> = text.lines().map(str::trim).filter(|row| !row.is_empty()).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let (mut start, mut goal) = (None, None);
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (r, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(format!("row {} is not {width} cells wide", r + 1));
+            }
+            for c in row.chars() {
+                cells.push(match c {
+                    '.' => Some(1),
+                    '#' => None,
+                    'S' | 'G' => {
+                        let marker = if c == 'S' { &mut start } else { &mut goal };
+                        if marker.replace(cells.len()).is_some() {
+                            return Err(format!("more than one '{c}'"));
+                        }
+                        Some(1)
+                    }
+                    '1'..='9' => c.to_digit(10),
+                    _ => return Err(format!("unknown cell '{c}' in row {}", r + 1)),
+                });
+            }
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+            start: start.ok_or("no start 'S'")?,
+            goal: goal.ok_or("no goal 'G'")?,
+        })
+    }
+
+    /// The map as a graph: every open cell has an edge to each open cell
+    /// above, below, left and right of it, weighted with that cell's cost
+    fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new(self.cells.len());
+        for v in 0..self.cells.len() {
+            if self.cells[v].is_none() {
+                continue;
+            }
+            let (row, col) = (v / self.width, v % self.width);
+            let mut neighbours = Vec::new();
+            if row > 0 {
+                neighbours.push(v - self.width);
+            }
+            if row + 1 < self.height {
+                neighbours.push(v + self.width);
+            }
+            if col > 0 {
+                neighbours.push(v - 1);
+            }
+            if col + 1 < self.width {
+                neighbours.push(v + 1);
+            }
+            for n in neighbours {
+                if let Some(cost) = self.cells[n] {
+                    graph.add_edge(v, n, cost);
+                }
+            }
+        }
+        graph
+    }
+
+    /// TODO: Implement the Manhattan distance between two cells
+    /// Every step costs at least 1, so it never overestimates
+    fn manhattan(&self, from: usize, to: usize) -> u32 {
+        // TODO: Cell v is at row v / width, column v % width
+        
+        0
+    }
+}
+
+/// TODO: Implement A* search
+/// Returns the distance and a shortest path from start to goal, or None
+/// if goal is unreachable. heuristic(v) estimates the distance from v to
+/// goal and must never overestimate it
+fn astar(
+    graph: &Graph,
+    start: usize,
+    goal: usize,
+    heuristic: impl Fn(usize) -> u32,
+) -> Option<(u32, Vec<usize>)> {
+    let mut distances = vec![u32::MAX; graph.vertices];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.vertices];
+    let mut heap: BinaryHeap<State> = BinaryHeap::new();
+    
+    // TODO: Like dijkstra_with_path, but order the heap by
+    //   distance so far + heuristic(vertex), an estimate of the whole path
+    // TODO: Return as soon as goal is popped - its distance is final then
+    
+    None
+}
+
+// Stage 4: Bidirectional Dijkstra
+
+/// TODO: Implement bidirectional Dijkstra
+/// Returns the shortest distance from start to goal, or None if goal is
+/// unreachable
+fn bidirectional_dijkstra(graph: &Graph, start: usize, goal: usize) -> Option<u32> {
+    let reversed = graph.reversed();
+    
+    // TODO: Search forward from start over graph and backward from goal
+    //   over reversed, settling a vertex on whichever side is nearer
+    // TODO: Each edge relaxed towards a vertex the other side has reached
+    //   completes a start -> goal path; remember the shortest one
+    // TODO: Stop when the two heaps' smallest distances add up to at least
+    //   that path - nothing left to settle can beat it
+    
+    None
+}
+
 fn main() {
     // Create a sample weighted graph
     //       4
@@ -190,4 +362,67 @@ mod tests {
         let path = reconstruct_path(&parents, 0, 3).unwrap();
         assert_eq!(path, vec![0, 2, 3]);
     }
+    
+    #[test]
+    fn test_bellman_ford_negative_edges() {
+        let mut graph = Graph::new(4);
+        graph.add_edge(0, 1, 4);
+        graph.add_edge(0, 2, 5);
+        graph.add_edge(2, 1, -3);
+        graph.add_edge(1, 3, 1);
+        
+        let distances = bellman_ford(&graph, 0).unwrap();
+        assert_eq!(distances, vec![Some(0), Some(2), Some(5), Some(3)]);
+    }
+    
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        let mut graph = Graph::new(4);
+        graph.add_edge(0, 1, 1);
+        graph.add_edge(1, 2, -2);
+        graph.add_edge(2, 3, 1);
+        graph.add_edge(3, 1, -1);
+        
+        let cycle = bellman_ford(&graph, 0).unwrap_err().cycle;
+        // The same cycle, whichever vertex it starts from
+        let at = cycle.iter().position(|&v| v == 1).unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert_eq!([cycle[at], cycle[(at + 1) % 3], cycle[(at + 2) % 3]], [1, 2, 3]);
+    }
+    
+    #[test]
+    fn test_astar_on_grid() {
+        let grid = Grid::parse(
+            "S..#....
+             .#.#.##.
+             .#...#G.
+             .####.#.
+             ........",
+        )
+        .unwrap();
+        let graph = grid.to_graph();
+        assert_eq!(grid.manhattan(grid.start, grid.goal), 8);
+        
+        let heuristic = |v| grid.manhattan(v, grid.goal);
+        let (distance, path) = astar(&graph, grid.start, grid.goal, heuristic).unwrap();
+        // The walls make it a longer way round than the heuristic guesses
+        assert_eq!(distance, 14);
+        assert_eq!(path.len(), 15);
+        assert_eq!((path[0], path[14]), (grid.start, grid.goal));
+        assert_eq!(dijkstra(&graph, grid.start)[grid.goal], 14);
+    }
+    
+    #[test]
+    fn test_bidirectional_dijkstra() {
+        let mut graph = Graph::new(5);
+        graph.add_edge(0, 1, 2);
+        graph.add_edge(1, 2, 2);
+        graph.add_edge(0, 3, 1);
+        graph.add_edge(3, 2, 5);
+        graph.add_edge(2, 4, 1);
+        
+        assert_eq!(bidirectional_dijkstra(&graph, 0, 4), Some(5));
+        assert_eq!(bidirectional_dijkstra(&graph, 4, 0), None);
+        assert_eq!(bidirectional_dijkstra(&graph, 2, 2), Some(0));
+    }
 }
@@ -0,0 +1,417 @@
+//! Hidden tests for exercise 132: Dijkstra's Algorithm (Shortest Path).
+//!
+//! Zero and very large weights, parallel edges, directed reachability and
+//! random graphs checked against a brute-force relaxation. The later stages
+//! are checked against Dijkstra and each other on random graphs and grids:
+//! Bellman-Ford also against a DP over negative-weight DAGs and on planted
+//! negative cycles.
+
+use super::*;
+
+fn graph_of(vertices: usize, edges: &[(usize, usize, u32)]) -> Graph {
+    let mut graph = Graph::new(vertices);
+    for &(u, v, w) in edges {
+        graph.add_edge(u, v, w);
+    }
+    graph
+}
+
+/// Bellman-Ford style relaxation, in u64 so nothing can overflow.
+fn brute_force(vertices: usize, edges: &[(usize, usize, u32)], start: usize) -> Vec<u32> {
+    let mut dist = vec![u64::MAX; vertices];
+    dist[start] = 0;
+    for _ in 0..vertices {
+        for &(u, v, w) in edges {
+            if dist[u] != u64::MAX && dist[u] + u64::from(w) < dist[v] {
+                dist[v] = dist[u] + u64::from(w);
+            }
+        }
+    }
+    dist.into_iter().map(|d| if d == u64::MAX { u32::MAX } else { d as u32 }).collect()
+}
+
+/// A linear congruential generator; `next(bound)` is below `bound`.
+fn lcg(seed: u64) -> impl FnMut(u64) -> u64 {
+    let mut x = seed;
+    move |bound| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    }
+}
+
+fn signed_graph_of(vertices: usize, edges: &[(usize, usize, i64)]) -> Graph<i64> {
+    let mut graph = Graph::new(vertices);
+    for &(u, v, w) in edges {
+        graph.add_edge(u, v, w);
+    }
+    graph
+}
+
+/// The lightest of the parallel edges u -> v.
+fn lightest<W: Copy + Ord>(graph: &Graph<W>, u: usize, v: usize) -> Option<W> {
+    graph.adj[u].iter().filter(|e| e.to == v).map(|e| e.weight).min()
+}
+
+/// Total weight of a path, taking the lightest edge for each hop; panics
+/// on a hop that is not an edge.
+fn path_weight(graph: &Graph, path: &[usize]) -> u64 {
+    path.windows(2)
+        .map(|hop| u64::from(lightest(graph, hop[0], hop[1]).unwrap_or_else(|| panic!("{path:?} is not a path"))))
+        .sum()
+}
+
+/// A random map with the given share of walls in percent and some rough
+/// ground.
+fn random_grid(next: &mut impl FnMut(u64) -> u64, width: usize, height: usize, walls: u64) -> String {
+    let cells = width * height;
+    let start = next(cells as u64) as usize;
+    let goal = next(cells as u64) as usize;
+    let mut text = String::new();
+    for v in 0..cells {
+        text.push(match v {
+            _ if v == start => 'S',
+            _ if v == goal && goal != start => 'G',
+            _ if next(100) < walls => '#',
+            _ if next(4) == 0 => char::from(b'1' + next(9) as u8),
+            _ => '.',
+        });
+        if v % width == width - 1 {
+            text.push('\n');
+        }
+    }
+    text
+}
+
+#[test]
+fn zero_weight_edges() {
+    let graph = graph_of(4, &[(0, 1, 0), (1, 2, 0), (0, 2, 5), (2, 3, 1)]);
+    assert_eq!(dijkstra(&graph, 0), vec![0, 0, 0, 1]);
+}
+
+#[test]
+fn parallel_edges_and_self_loops() {
+    let graph = graph_of(3, &[(0, 1, 9), (0, 1, 2), (0, 1, 5), (1, 1, 1), (1, 2, 3)]);
+    assert_eq!(dijkstra(&graph, 0), vec![0, 2, 5]);
+}
+
+#[test]
+fn large_weights_do_not_wrap() {
+    // Adding the two long hops in u32 wraps around to a small number
+    let graph = graph_of(3, &[(0, 1, 3_000_000_000), (1, 2, 3_000_000_000), (0, 2, 4_000_000_000)]);
+    assert_eq!(dijkstra(&graph, 0), vec![0, 3_000_000_000, 4_000_000_000]);
+}
+
+#[test]
+fn longer_path_with_fewer_weight() {
+    let graph = graph_of(6, &[(0, 5, 100), (0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 5, 1)]);
+    let (distances, parents) = dijkstra_with_path(&graph, 0);
+    assert_eq!(distances[5], 5);
+    assert_eq!(reconstruct_path(&parents, 0, 5), Some(vec![0, 1, 2, 3, 4, 5]));
+}
+
+#[test]
+fn directed_edges_are_one_way() {
+    let graph = graph_of(3, &[(0, 1, 1), (2, 1, 1)]);
+    assert_eq!(dijkstra(&graph, 1), vec![u32::MAX, 0, u32::MAX]);
+    assert_eq!(dijkstra(&graph, 2), vec![u32::MAX, 1, 0]);
+}
+
+#[test]
+fn path_edge_cases() {
+    let graph = graph_of(3, &[(0, 1, 4)]);
+    let (_, parents) = dijkstra_with_path(&graph, 0);
+    assert_eq!(reconstruct_path(&parents, 0, 0), Some(vec![0]));
+    assert_eq!(reconstruct_path(&parents, 0, 1), Some(vec![0, 1]));
+    assert_eq!(reconstruct_path(&parents, 0, 2), None);
+}
+
+#[test]
+fn undirected_edges() {
+    let mut graph = Graph::new(3);
+    graph.add_undirected_edge(0, 1, 7);
+    graph.add_undirected_edge(1, 2, 2);
+    assert_eq!(dijkstra(&graph, 2), vec![9, 2, 0]);
+}
+
+#[test]
+fn random_graphs_match_brute_force() {
+    let mut x: u64 = 2024;
+    let mut next = |bound: u64| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    };
+    for _ in 0..20 {
+        let vertices = 30;
+        let edges: Vec<_> = (0..90)
+            .map(|_| (next(30) as usize, next(30) as usize, next(50) as u32))
+            .collect();
+        let graph = graph_of(vertices, &edges);
+        for start in [0, 13, 29] {
+            let expected = brute_force(vertices, &edges, start);
+            let (distances, parents) = dijkstra_with_path(&graph, start);
+            assert_eq!(distances, expected);
+            assert_eq!(dijkstra(&graph, start), expected);
+            for end in 0..vertices {
+                let Some(path) = reconstruct_path(&parents, start, end) else {
+                    assert_eq!(expected[end], u32::MAX);
+                    continue;
+                };
+                let weight: u32 = path
+                    .windows(2)
+                    .map(|hop| edges.iter().filter(|e| e.0 == hop[0] && e.1 == hop[1]).map(|e| e.2).min().unwrap())
+                    .sum();
+                assert_eq!(weight, expected[end], "{path:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn large_grid() {
+    let side = 200;
+    let mut graph = Graph::new(side * side);
+    for r in 0..side {
+        for c in 0..side {
+            let v = r * side + c;
+            if c + 1 < side {
+                graph.add_undirected_edge(v, v + 1, 1);
+            }
+            if r + 1 < side {
+                graph.add_undirected_edge(v, v + side, 1);
+            }
+        }
+    }
+    let distances = dijkstra(&graph, 0);
+    assert_eq!(distances[side * side - 1], 2 * (side as u32 - 1));
+}
+
+#[test]
+fn bellman_ford_matches_dijkstra_on_non_negative_graphs() {
+    let mut next = lcg(132);
+    for _ in 0..20 {
+        let edges: Vec<_> = (0..80)
+            .map(|_| (next(25) as usize, next(25) as usize, next(40) as u32))
+            .collect();
+        let graph = graph_of(25, &edges);
+        let signed: Vec<_> = edges.iter().map(|&(u, v, w)| (u, v, i64::from(w))).collect();
+        let signed = signed_graph_of(25, &signed);
+        for start in [0, 7, 24] {
+            let expected: Vec<_> = dijkstra(&graph, start)
+                .into_iter()
+                .map(|d| (d != u32::MAX).then_some(i64::from(d)))
+                .collect();
+            assert_eq!(bellman_ford(&signed, start), Ok(expected));
+        }
+    }
+}
+
+#[test]
+fn bellman_ford_on_negative_dags() {
+    // Edges only go from lower to higher vertices, so there is no cycle
+    // and a DP in vertex order gives the answer
+    let mut next = lcg(7);
+    for _ in 0..30 {
+        let vertices = 20;
+        let edges: Vec<_> = (0..60)
+            .filter_map(|_| {
+                let (u, v) = (next(20) as usize, next(20) as usize);
+                (u < v).then(|| (u, v, next(60) as i64 - 40))
+            })
+            .collect();
+        let graph = signed_graph_of(vertices, &edges);
+        for start in [0, 5] {
+            let mut expected: Vec<Option<i64>> = vec![None; vertices];
+            expected[start] = Some(0);
+            for u in 0..vertices {
+                let Some(du) = expected[u] else { continue };
+                for &(_, v, w) in edges.iter().filter(|e| e.0 == u) {
+                    if expected[v].is_none_or(|dv| du + w < dv) {
+                        expected[v] = Some(du + w);
+                    }
+                }
+            }
+            assert_eq!(bellman_ford(&graph, start), Ok(expected));
+        }
+    }
+}
+
+#[test]
+fn bellman_ford_reports_a_negative_cycle() {
+    let mut next = lcg(99);
+    for round in 0..30 {
+        let vertices = 20;
+        // A random non-negative graph, plus a planted cycle that costs -1
+        // going round, entered from vertex 0 in half the rounds
+        let mut edges: Vec<_> = (0..50)
+            .map(|_| (next(20) as usize, next(20) as usize, next(30) as i64))
+            .collect();
+        let planted: Vec<usize> = (0..3 + next(4) as usize).map(|i| 10 + i).collect();
+        for (i, &u) in planted.iter().enumerate() {
+            let v = planted[(i + 1) % planted.len()];
+            edges.push((u, v, if i == 0 { -(planted.len() as i64) } else { 1 }));
+        }
+        let reachable = round % 2 == 0;
+        // Keep the cycle's vertices out of reach unless it is wanted
+        edges.retain(|&(u, v, _)| !(planted.contains(&v) && !planted.contains(&u)));
+        if reachable {
+            edges.push((0, planted[0], 5));
+        }
+        let graph = signed_graph_of(vertices, &edges);
+
+        match bellman_ford(&graph, 0) {
+            Err(NegativeCycle { cycle }) => {
+                assert!(reachable, "round {round}: no cycle is reachable, found {cycle:?}");
+                assert!(!cycle.is_empty());
+                let mut total = 0;
+                for (i, &u) in cycle.iter().enumerate() {
+                    let v = cycle[(i + 1) % cycle.len()];
+                    total += lightest(&graph, u, v).unwrap_or_else(|| panic!("{cycle:?}: no edge {u} -> {v}"));
+                }
+                assert!(total < 0, "round {round}: {cycle:?} weighs {total}");
+            }
+            Ok(distances) => {
+                assert!(!reachable, "round {round}: missed the cycle");
+                assert!(planted.iter().all(|&v| distances[v].is_none()));
+            }
+        }
+    }
+}
+
+#[test]
+fn grid_parsing() {
+    let grid = Grid::parse("S.#\n.9G\n").unwrap();
+    assert_eq!((grid.width, grid.height, grid.start, grid.goal), (3, 2, 0, 5));
+    assert_eq!(grid.cells, vec![Some(1), Some(1), None, Some(1), Some(9), Some(1)]);
+    assert_eq!(grid.manhattan(0, 5), 3);
+    assert_eq!(grid.manhattan(5, 0), 3);
+    assert_eq!(grid.manhattan(2, 3), 3);
+    assert!(Grid::parse("S.\n.\nG.").is_err());
+    assert!(Grid::parse("S.x\n..G").is_err());
+    assert!(Grid::parse("S..\n..S\nG..").is_err());
+    assert!(Grid::parse("...\n..G").is_err());
+}
+
+#[test]
+fn astar_walls_and_rough_ground() {
+    // Going round the 9s is cheaper than going through them
+    let grid = Grid::parse(
+        "S.....
+         ####9.
+         G.....",
+    )
+    .unwrap();
+    let graph = grid.to_graph();
+    let heuristic = |v| grid.manhattan(v, grid.goal);
+    let (distance, path) = astar(&graph, grid.start, grid.goal, heuristic).unwrap();
+    assert_eq!(distance, 12);
+    assert_eq!(path_weight(&graph, &path), 12);
+
+    let walled = Grid::parse("S#.\n##.\n..G").unwrap();
+    let graph = walled.to_graph();
+    assert_eq!(astar(&graph, walled.start, walled.goal, |_| 0), None);
+    assert_eq!(astar(&graph, walled.start, walled.start, |_| 0), Some((0, vec![walled.start])));
+}
+
+#[test]
+fn astar_matches_dijkstra_on_random_grids() {
+    let mut next = lcg(42);
+    for round in 0..40 {
+        let text = random_grid(&mut next, 12 + round % 7, 9 + round % 5, 25);
+        let Ok(grid) = Grid::parse(&text) else {
+            // Start and goal landed on the same cell
+            continue;
+        };
+        let graph = grid.to_graph();
+        let expected = dijkstra(&graph, grid.start)[grid.goal];
+        let manhattan = |v| grid.manhattan(v, grid.goal);
+        for found in [
+            astar(&graph, grid.start, grid.goal, |_| 0),
+            astar(&graph, grid.start, grid.goal, manhattan),
+        ] {
+            let Some((distance, path)) = found else {
+                assert_eq!(expected, u32::MAX, "\n{text}");
+                continue;
+            };
+            assert_eq!(distance, expected, "\n{text}");
+            assert_eq!((path[0], path[path.len() - 1]), (grid.start, grid.goal));
+            assert_eq!(path_weight(&graph, &path), u64::from(expected), "\n{text}");
+        }
+    }
+}
+
+#[test]
+fn astar_with_a_heuristic_on_random_graphs() {
+    // Half the true distance to the goal never overestimates it
+    let mut next = lcg(5);
+    for _ in 0..20 {
+        let edges: Vec<_> = (0..100)
+            .map(|_| (next(30) as usize, next(30) as usize, next(50) as u32))
+            .collect();
+        let graph = graph_of(30, &edges);
+        for (start, goal) in [(0, 29), (3, 17), (12, 12)] {
+            let expected = dijkstra(&graph, start)[goal];
+            let to_goal = dijkstra(&graph.reversed(), goal);
+            let heuristic = |v: usize| if to_goal[v] == u32::MAX { 0 } else { to_goal[v] / 2 };
+            match astar(&graph, start, goal, heuristic) {
+                Some((distance, path)) => {
+                    assert_eq!(distance, expected);
+                    assert_eq!(path_weight(&graph, &path), u64::from(expected));
+                }
+                None => assert_eq!(expected, u32::MAX),
+            }
+        }
+    }
+}
+
+#[test]
+fn bidirectional_matches_dijkstra() {
+    let mut next = lcg(2025);
+    for round in 0..30 {
+        let vertices = 40;
+        // Sparse rounds leave some pairs unconnected
+        let edges: Vec<_> = (0..if round % 3 == 0 { 45 } else { 120 })
+            .map(|_| (next(40) as usize, next(40) as usize, next(30) as u32))
+            .collect();
+        let graph = graph_of(vertices, &edges);
+        for _ in 0..10 {
+            let (start, goal) = (next(40) as usize, next(40) as usize);
+            let expected = dijkstra(&graph, start)[goal];
+            let expected = (expected != u32::MAX).then_some(expected);
+            assert_eq!(bidirectional_dijkstra(&graph, start, goal), expected, "{start} -> {goal}");
+        }
+    }
+}
+
+#[test]
+fn every_stage_agrees_on_a_large_grid() {
+    let side = 60;
+    let mut next = lcg(11);
+    let mut text = String::new();
+    for r in 0..side {
+        for c in 0..side {
+            text.push(match (r, c) {
+                (0, 0) => 'S',
+                _ if (r, c) == (side - 1, side - 1) => 'G',
+                _ if next(5) == 0 => '#',
+                _ => char::from(b'1' + next(9) as u8),
+            });
+        }
+        text.push('\n');
+    }
+    let grid = Grid::parse(&text).unwrap();
+    let graph = grid.to_graph();
+    let expected = dijkstra(&graph, grid.start)[grid.goal];
+    let heuristic = |v| grid.manhattan(v, grid.goal);
+    assert_eq!(astar(&graph, grid.start, grid.goal, heuristic).map(|(d, _)| d), (expected != u32::MAX).then_some(expected));
+    let expected = (expected != u32::MAX).then_some(expected);
+    assert_eq!(bidirectional_dijkstra(&graph, grid.start, grid.goal), expected);
+
+    let mut signed = Graph::new(graph.vertices);
+    for (u, edges) in graph.adj.iter().enumerate() {
+        for edge in edges {
+            signed.add_edge(u, edge.to, i64::from(edge.weight));
+        }
+    }
+    let distances = bellman_ford(&signed, grid.start).unwrap();
+    assert_eq!(distances[grid.goal], expected.map(i64::from));
+}
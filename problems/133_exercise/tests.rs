@@ -0,0 +1,98 @@
+//! Hidden tests for exercise 133: 0/1 Knapsack Problem.
+//!
+//! Zero-weight and oversized items, consistent selections and random item sets
+//! checked against exhaustive search.
+
+use super::*;
+
+fn items_of(pairs: &[(usize, usize)]) -> Vec<Item> {
+    pairs.iter().map(|&(w, v)| Item::new(w, v)).collect()
+}
+
+/// Tries every subset; only for small item counts.
+fn exhaustive(items: &[Item], capacity: usize) -> usize {
+    (0u32..1 << items.len())
+        .filter_map(|mask| {
+            let chosen = items.iter().enumerate().filter(|(i, _)| mask & (1 << i) != 0);
+            let (w, v) = chosen.fold((0, 0), |(w, v), (_, item)| (w + item.weight, v + item.value));
+            (w <= capacity).then_some(v)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn all_versions(items: &[Item], capacity: usize) -> [usize; 4] {
+    [
+        knapsack_01(items, capacity),
+        knapsack_01_optimized(items, capacity),
+        knapsack_with_selection(items, capacity).0,
+        knapsack_recursive(items, capacity),
+    ]
+}
+
+fn check_selection(items: &[Item], capacity: usize) {
+    let (value, selected) = knapsack_with_selection(items, capacity);
+    let mut unique = selected.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), selected.len(), "item picked twice: {selected:?}");
+    let weight: usize = selected.iter().map(|&i| items[i].weight).sum();
+    let total: usize = selected.iter().map(|&i| items[i].value).sum();
+    assert!(weight <= capacity, "selection {selected:?} weighs {weight}");
+    assert_eq!(total, value, "selection {selected:?}");
+}
+
+#[test]
+fn each_item_used_once() {
+    // Reusing the light item would give 30
+    let items = items_of(&[(1, 10), (5, 12)]);
+    assert_eq!(all_versions(&items, 3), [10; 4]);
+    check_selection(&items, 3);
+}
+
+#[test]
+fn oversized_items() {
+    let items = items_of(&[(11, 100), (20, 500), (3, 4)]);
+    assert_eq!(all_versions(&items, 10), [4; 4]);
+    check_selection(&items, 10);
+}
+
+#[test]
+fn zero_weight_items() {
+    let items = items_of(&[(0, 5), (0, 7), (4, 1)]);
+    assert_eq!(all_versions(&items, 0), [12; 4]);
+    assert_eq!(all_versions(&items, 4), [13; 4]);
+    check_selection(&items, 0);
+}
+
+#[test]
+fn greedy_by_ratio_is_wrong() {
+    let items = items_of(&[(6, 30), (5, 20), (5, 20)]);
+    assert_eq!(all_versions(&items, 10), [40; 4]);
+    check_selection(&items, 10);
+}
+
+#[test]
+fn random_sets_match_exhaustive_search() {
+    let mut x: u64 = 133;
+    let mut next = |bound: u64| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    };
+    for _ in 0..50 {
+        let n = next(12) as usize + 1;
+        let items: Vec<Item> = (0..n).map(|_| Item::new(next(15) as usize, next(40) as usize)).collect();
+        let capacity = next(40) as usize;
+        let expected = exhaustive(&items, capacity);
+        assert_eq!(all_versions(&items, capacity), [expected; 4], "{items:?} cap {capacity}");
+        check_selection(&items, capacity);
+    }
+}
+
+#[test]
+fn large_instance() {
+    let items: Vec<Item> = (1..=200).map(|i| Item::new(i * 7 % 97 + 1, i * 13 % 101)).collect();
+    let [a, b, c, d] = all_versions(&items, 5_000);
+    assert_eq!((a, b, c), (d, d, d));
+    check_selection(&items, 5_000);
+}
@@ -0,0 +1,31 @@
+//! Property tests for exercise 134: Longest Common Subsequence (LCS).
+//!
+//! The LCS is a subsequence of both strings, as long as `lcs_length` says,
+//! and its length does not depend on the order of the strings.
+
+use super::*;
+use prop::{check, strings};
+
+fn is_subsequence(sub: &str, of: &str) -> bool {
+    let mut rest = of.chars();
+    sub.chars().all(|c| rest.any(|x| x == c))
+}
+
+#[test]
+fn lcs_is_a_common_subsequence_of_the_right_length() {
+    check((strings("abc", 0..=16), strings("abc", 0..=16)), |(s1, s2)| {
+        let common = lcs(&s1, &s2);
+        assert!(is_subsequence(&common, &s1) && is_subsequence(&common, &s2), "lcs = {common:?}");
+        assert_eq!(common.chars().count(), lcs_length(&s1, &s2), "lcs = {common:?}");
+    });
+}
+
+#[test]
+fn length_is_symmetric_and_bounded() {
+    check((strings("abc", 0..=16), strings("abc", 0..=16)), |(s1, s2)| {
+        let length = lcs_length(&s1, &s2);
+        assert_eq!(length, lcs_length(&s2, &s1));
+        assert_eq!(length, lcs_length_optimized(&s1, &s2));
+        assert!(length <= s1.chars().count().min(s2.chars().count()));
+    });
+}
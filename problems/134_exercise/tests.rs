@@ -0,0 +1,67 @@
+//! Hidden tests for exercise 134: Longest Common Subsequence (LCS).
+//!
+//! Multi-byte characters, repeated letters and long inputs, with every returned
+//! subsequence checked against both strings.
+
+use super::*;
+
+fn is_subsequence(sub: &str, of: &str) -> bool {
+    let mut rest = of.chars();
+    sub.chars().all(|c| rest.any(|d| d == c))
+}
+
+fn check(s1: &str, s2: &str, expected_len: usize) {
+    assert_eq!(lcs_length(s1, s2), expected_len, "{s1:?} / {s2:?}");
+    assert_eq!(lcs_length_optimized(s1, s2), expected_len, "{s1:?} / {s2:?}");
+    assert_eq!(lcs_length_optimized(s2, s1), expected_len, "{s2:?} / {s1:?}");
+    let found = lcs(s1, s2);
+    assert_eq!(found.chars().count(), expected_len, "{found:?}");
+    assert!(is_subsequence(&found, s1) && is_subsequence(&found, s2), "{found:?}");
+}
+
+#[test]
+fn multibyte_characters() {
+    check("naïve café", "naive cafe", 8);
+    check("日本語のテキスト", "日本のテスト", 6);
+    check("🦀🐍🦀", "🦀🦀", 2);
+}
+
+#[test]
+fn repeated_letters() {
+    check("AAAA", "AA", 2);
+    check("ABABAB", "BABABA", 5);
+    check("XMJYAUZ", "MZJAWXU", 4);
+}
+
+#[test]
+fn shorter_string_first() {
+    check("ACE", "ABCDE", 3);
+    check("", "ABC", 0);
+}
+
+#[test]
+fn all_lcs_finds_every_sequence() {
+    let mut found = all_lcs("ABC", "ACB");
+    found.sort();
+    assert_eq!(found, vec!["AB", "AC"]);
+
+    let mut found = all_lcs("ABCBDAB", "BDCABA");
+    found.sort();
+    assert_eq!(found, vec!["BCAB", "BCBA", "BDAB"]);
+}
+
+#[test]
+fn all_lcs_without_duplicates() {
+    let found = all_lcs("AAB", "ABA");
+    assert!(found.len() == 2 && found.contains(&"AA".to_string()) && found.contains(&"AB".to_string()), "{found:?}");
+}
+
+#[test]
+fn long_strings() {
+    let a: String = (0..1_500).map(|i| (b'a' + (i * 7 % 26) as u8) as char).collect();
+    let b: String = (0..1_500).map(|i| (b'a' + (i * 11 % 26) as u8) as char).collect();
+    let expected = lcs_length(&a, &b);
+    check(&a, &b, expected);
+    let doubled = a.repeat(2);
+    check(&a, &doubled, 1_500);
+}
@@ -0,0 +1,36 @@
+//! Property tests for exercise 135: Levenshtein Distance (Edit Distance).
+//!
+//! The distance is a metric: zero exactly between equal strings, symmetric,
+//! and it obeys the triangle inequality.
+
+use super::*;
+use prop::{check, strings};
+
+#[test]
+fn triangle_inequality() {
+    check((strings("abc", 0..=12), strings("abc", 0..=12), strings("abc", 0..=12)), |(a, b, c)| {
+        let (ab, bc, ac) = (levenshtein_distance(&a, &b), levenshtein_distance(&b, &c), levenshtein_distance(&a, &c));
+        assert!(ac <= ab + bc, "d(a, c) = {ac} but d(a, b) = {ab} and d(b, c) = {bc}");
+    });
+}
+
+#[test]
+fn symmetric_and_zero_only_between_equal_strings() {
+    check((strings("abc", 0..=16), strings("abc", 0..=16)), |(a, b)| {
+        let d = levenshtein_distance(&a, &b);
+        assert_eq!(d, levenshtein_distance(&b, &a));
+        assert_eq!(d == 0, a == b, "distance {d}");
+    });
+}
+
+#[test]
+fn bounded_by_the_lengths_and_versions_agree() {
+    check((strings("abc", 0..=16), strings("abc", 0..=16)), |(a, b)| {
+        let (la, lb) = (a.chars().count(), b.chars().count());
+        let d = levenshtein_distance(&a, &b);
+        assert!(la.abs_diff(lb) <= d && d <= la.max(lb), "distance {d}");
+        assert_eq!(levenshtein_distance_optimized(&a, &b), d);
+        assert_eq!(levenshtein_with_operations(&a, &b).0, d);
+        assert!(damerau_levenshtein(&a, &b) <= d);
+    });
+}
@@ -0,0 +1,93 @@
+//! Hidden tests for exercise 135: Levenshtein Distance (Edit Distance).
+//!
+//! Multi-byte characters, symmetry and the triangle inequality on random words,
+//! edit scripts whose length matches the distance, and transpositions.
+
+use super::*;
+
+fn words() -> Vec<String> {
+    let mut x: u32 = 135;
+    (0..40)
+        .map(|_| {
+            x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let len = (x >> 16) % 8;
+            (0..len)
+                .map(|_| {
+                    x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    ['a', 'b', 'c', 'é'][((x >> 16) % 4) as usize]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn multibyte_characters_count_once() {
+    assert_eq!(levenshtein_distance("café", "cafe"), 1);
+    assert_eq!(levenshtein_distance_optimized("café", "cafe"), 1);
+    assert_eq!(levenshtein_distance("日本", "日本語"), 1);
+    assert_eq!(levenshtein_distance("🦀", ""), 1);
+    assert_eq!(damerau_levenshtein("ñá", "áñ"), 1);
+}
+
+#[test]
+fn metric_properties() {
+    let words = words();
+    for a in &words {
+        assert_eq!(levenshtein_distance(a, a), 0);
+        for b in &words {
+            let ab = levenshtein_distance(a, b);
+            assert_eq!(ab, levenshtein_distance(b, a), "{a:?} / {b:?}");
+            assert_eq!(ab, levenshtein_distance_optimized(a, b), "{a:?} / {b:?}");
+            assert!(ab >= a.chars().count().abs_diff(b.chars().count()));
+            assert!(ab <= a.chars().count().max(b.chars().count()));
+            for c in words.iter().step_by(5) {
+                assert!(ab <= levenshtein_distance(a, c) + levenshtein_distance(c, b));
+            }
+        }
+    }
+}
+
+#[test]
+fn operations_match_distance() {
+    let words = words();
+    for a in &words {
+        for b in words.iter().step_by(3) {
+            let (distance, ops) = levenshtein_with_operations(a, b);
+            assert_eq!(distance, levenshtein_distance(a, b), "{a:?} / {b:?}");
+            assert_eq!(ops.len(), distance, "{a:?} / {b:?}: {ops:?}");
+        }
+    }
+    assert_eq!(levenshtein_with_operations("same", "same"), (0, vec![]));
+}
+
+#[test]
+fn transpositions() {
+    assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+    assert_eq!(damerau_levenshtein("abcdef", "badcfe"), 3);
+    assert_eq!(damerau_levenshtein("", "ab"), 2);
+    assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+    for a in &words() {
+        assert!(damerau_levenshtein(a, "abc") <= levenshtein_distance(a, "abc"));
+    }
+}
+
+#[test]
+fn similarity_bounds() {
+    assert_eq!(similarity("abc", ""), 0.0);
+    assert_eq!(similarity("ab", "ba"), 0.0);
+    assert!((similarity("café", "cafe") - 0.75).abs() < 1e-9);
+    for a in &words() {
+        let s = similarity(a, "abcé");
+        assert!((0.0..=1.0).contains(&s), "{s}");
+    }
+}
+
+#[test]
+fn long_strings() {
+    let a = "ab".repeat(1_000);
+    let b = "ba".repeat(1_000);
+    assert_eq!(levenshtein_distance(&a, &b), 2);
+    assert_eq!(levenshtein_distance_optimized(&a, &b), 2);
+    assert_eq!(levenshtein_distance(&a, ""), 2_000);
+}
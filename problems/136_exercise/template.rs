@@ -151,6 +151,6 @@ mod tests {
     #[test]
     fn test_two_sum_sorted() {
         let nums = vec![1, 3, 4, 8, 10];
-        assert_eq!(two_sum_sorted(&nums, 11), Some((0, 3)));
+        assert_eq!(two_sum_sorted(&nums, 11), Some((0, 4))); // 1 + 10 = 11
     }
 }
@@ -0,0 +1,134 @@
+//! Hidden tests for exercise 136: Two Sum Problem.
+//!
+//! Values near the i32 limits, repeated values, pairs that must not reuse an
+//! index, and random inputs checked against brute force.
+
+use super::*;
+
+fn pair_sums(nums: &[i32], target: i32) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..nums.len() {
+        for j in i + 1..nums.len() {
+            if nums[i] as i64 + nums[j] as i64 == target as i64 {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+fn pseudo_random(len: usize, seed: u64, range: i32) -> Vec<i32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 33) % (2 * range as u64 + 1)) as i32 - range
+        })
+        .collect()
+}
+
+#[test]
+fn does_not_reuse_an_index() {
+    assert_eq!(two_sum(&[3], 6), None);
+    assert_eq!(two_sum(&[3, 4], 6), None);
+    assert_eq!(two_sum(&[3, 4, 3], 6), Some((0, 2)));
+    assert_eq!(two_sum_sorted(&[3], 6), None);
+    assert_eq!(two_sum_sorted(&[], 0), None);
+}
+
+#[test]
+fn values_near_the_limits() {
+    assert_eq!(two_sum(&[i32::MAX, -1, 0], i32::MAX - 1), Some((0, 1)));
+    assert_eq!(two_sum(&[i32::MIN, 1, i32::MAX], -1), Some((0, 2)));
+    assert_eq!(two_sum(&[i32::MIN, -1, 5], i32::MAX), None);
+    assert_eq!(two_sum(&[i32::MAX, i32::MAX], -2), None);
+    let (i, j) = two_sum_sorted(&[i32::MAX, 7, i32::MIN, 3], -1).unwrap();
+    let mut sorted = vec![i32::MAX, 7, i32::MIN, 3];
+    sorted.sort();
+    assert_eq!(sorted[i] + sorted[j], -1);
+}
+
+#[test]
+fn first_pair_is_reported() {
+    // (0, 3) and (1, 2) both work; the earliest completed pair ends at index 2
+    assert_eq!(two_sum(&[1, 4, 5, 8], 9), Some((1, 2)));
+    assert_eq!(two_sum(&[0, 0, 0], 0), Some((0, 1)));
+}
+
+#[test]
+fn all_pairs_with_repeats() {
+    assert_eq!(two_sum_all(&[2, 2, 2], 4).len(), 3);
+    assert_eq!(two_sum_all(&[1, 2, 3], 100), vec![]);
+    let mut pairs = two_sum_all(&[1, 5, 3, 3, 5, 1], 6);
+    pairs.sort();
+    assert_eq!(pairs, vec![(0, 1), (0, 4), (1, 5), (2, 3), (4, 5)]);
+}
+
+#[test]
+fn random_inputs_match_brute_force() {
+    for seed in 0..30 {
+        let nums = pseudo_random(40, seed, 20);
+        for target in [-25, -3, 0, 1, 17, 39] {
+            let expected = pair_sums(&nums, target);
+            match two_sum(&nums, target) {
+                Some((i, j)) => {
+                    assert!(i < j && nums[i] + nums[j] == target, "{nums:?} {target}: ({i}, {j})");
+                }
+                None => assert!(expected.is_empty(), "{nums:?} {target}"),
+            }
+            let mut all = two_sum_all(&nums, target);
+            all.sort();
+            assert_eq!(all, expected, "{nums:?} {target}");
+            assert_eq!(two_sum_sorted(&nums, target).is_some(), !expected.is_empty());
+        }
+    }
+}
+
+#[test]
+fn three_sum_matches_brute_force() {
+    for seed in 0..10 {
+        let nums = pseudo_random(25, seed, 10);
+        for target in [-7, 0, 12] {
+            let mut expected = Vec::new();
+            for i in 0..nums.len() {
+                for j in i + 1..nums.len() {
+                    for k in j + 1..nums.len() {
+                        if nums[i] + nums[j] + nums[k] == target {
+                            expected.push((i, j, k));
+                        }
+                    }
+                }
+            }
+            let mut found: Vec<_> = three_sum(&nums, target)
+                .into_iter()
+                .map(|(i, j, k)| {
+                    let mut t = [i, j, k];
+                    t.sort();
+                    (t[0], t[1], t[2])
+                })
+                .collect();
+            found.sort();
+            assert_eq!(found, expected, "{nums:?} {target}");
+        }
+    }
+}
+
+#[test]
+fn subarray_sums() {
+    assert!(has_subarray_sum(&[1, 2, 3], 5));
+    assert!(has_subarray_sum(&[1, -1, 4], 0));
+    assert!(!has_subarray_sum(&[1, 2], 0));
+    assert!(!has_subarray_sum(&[], 0));
+    assert!(!has_subarray_sum(&[1, 3, 5], 2));
+    assert!(has_subarray_sum(&[-5, 2, -3], -6));
+    // Running sums overflow i32 along the way
+    assert!(has_subarray_sum(&[i32::MAX, i32::MAX, i32::MIN, 3], i32::MAX - 1));
+    assert!(!has_subarray_sum(&[i32::MAX, i32::MAX], i32::MAX - 1));
+}
+
+#[test]
+fn large_input() {
+    let nums: Vec<i32> = (0..200_000).map(|i| i * 2).collect();
+    assert_eq!(two_sum(&nums, 399_996), Some((99_998, 100_000)));
+    assert_eq!(two_sum(&nums, 1), None);
+}
@@ -0,0 +1,26 @@
+//! Property tests for exercise 137: Maximum Subarray (Kadane's Algorithm).
+//!
+//! Kadane's algorithm finds the same maximum as trying every non-empty
+//! subarray.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+fn brute_force(nums: &[i32]) -> i32 {
+    (0..nums.len())
+        .flat_map(|i| (i + 1..=nums.len()).map(move |j| nums[i..j].iter().sum::<i32>()))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn agrees_with_every_subarray() {
+    check(vecs(ints(-1000..=1000), 1..=48), |nums| {
+        let expected = brute_force(&nums);
+        assert_eq!(max_subarray_sum(&nums), expected, "max_subarray_sum({nums:?})");
+        let (sum, start, end) = max_subarray_with_indices(&nums);
+        assert_eq!(sum, expected);
+        assert_eq!(nums[start..=end].iter().sum::<i32>(), sum, "indices {start}..={end}");
+        assert_eq!(max_subarray(&nums).iter().sum::<i32>(), expected);
+    });
+}
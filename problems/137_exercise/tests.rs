@@ -0,0 +1,100 @@
+//! Hidden tests for exercise 137: Maximum Subarray (Kadane's Algorithm).
+//!
+//! Random arrays checked against brute force for every variant, zeros and
+//! negatives in products, and wrap-around cases for the circular sum.
+
+use super::*;
+
+fn pseudo_random(len: usize, seed: u64, range: i32) -> Vec<i32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 33) % (2 * range as u64 + 1)) as i32 - range
+        })
+        .collect()
+}
+
+fn best_over<F: Fn(&[i32]) -> i64>(nums: &[i32], value: F) -> i64 {
+    let mut best = i64::MIN;
+    for i in 0..nums.len() {
+        for j in i..nums.len() {
+            best = best.max(value(&nums[i..=j]));
+        }
+    }
+    best
+}
+
+fn sum(slice: &[i32]) -> i64 {
+    slice.iter().map(|&x| x as i64).sum()
+}
+
+#[test]
+fn random_sums_match_brute_force() {
+    for seed in 0..200 {
+        let nums = pseudo_random(1 + seed as usize % 12, seed, 10);
+        let expected = best_over(&nums, sum);
+        assert_eq!(max_subarray_sum(&nums) as i64, expected, "{nums:?}");
+
+        let (best, start, end) = max_subarray_with_indices(&nums);
+        assert_eq!(best as i64, expected, "{nums:?}");
+        assert!(start <= end && end < nums.len());
+        assert_eq!(sum(&nums[start..=end]), expected, "{nums:?}: {start}..={end}");
+        assert_eq!(sum(&max_subarray(&nums)), expected, "{nums:?}");
+    }
+}
+
+#[test]
+fn random_circular_sums_match_brute_force() {
+    for seed in 0..200 {
+        let nums = pseudo_random(1 + seed as usize % 10, seed, 10);
+        let doubled: Vec<i32> = nums.iter().chain(&nums).copied().collect();
+        let mut expected = i64::MIN;
+        for i in 0..nums.len() {
+            for len in 1..=nums.len() {
+                expected = expected.max(sum(&doubled[i..i + len]));
+            }
+        }
+        assert_eq!(max_circular_subarray_sum(&nums) as i64, expected, "{nums:?}");
+    }
+}
+
+#[test]
+fn random_products_match_brute_force() {
+    for seed in 0..200 {
+        let nums = pseudo_random(1 + seed as usize % 9, seed, 3);
+        let expected = best_over(&nums, |s| s.iter().map(|&x| x as i64).product());
+        assert_eq!(max_product_subarray(&nums) as i64, expected, "{nums:?}");
+    }
+}
+
+#[test]
+fn circular_edge_cases() {
+    assert_eq!(max_circular_subarray_sum(&[-3, -2, -5]), -2);
+    assert_eq!(max_circular_subarray_sum(&[8, -1, -1, 8]), 16);
+    assert_eq!(max_circular_subarray_sum(&[-1, 8, -1]), 8);
+    assert_eq!(max_circular_subarray_sum(&[0]), 0);
+}
+
+#[test]
+fn product_edge_cases() {
+    assert_eq!(max_product_subarray(&[-3]), -3);
+    assert_eq!(max_product_subarray(&[-2, 3, -4]), 24);
+    assert_eq!(max_product_subarray(&[0, -2, 0]), 0);
+    assert_eq!(max_product_subarray(&[-1, -1, -1]), 1);
+    assert_eq!(max_product_subarray(&[2, -5, -2, -4, 3]), 24);
+}
+
+#[test]
+fn extreme_single_values() {
+    assert_eq!(max_subarray_sum(&[i32::MIN]), i32::MIN);
+    assert_eq!(max_subarray_sum(&[i32::MIN, -1, i32::MIN]), -1);
+    assert_eq!(max_subarray_sum(&[i32::MAX]), i32::MAX);
+}
+
+#[test]
+fn large_input() {
+    let mut nums = vec![-1; 500_000];
+    nums[200_000..200_010].fill(100);
+    assert_eq!(max_subarray_with_indices(&nums), (1_000, 200_000, 200_009));
+}
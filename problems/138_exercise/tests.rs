@@ -0,0 +1,132 @@
+//! Hidden tests for exercise 138: LRU Cache Implementation.
+//!
+//! Long random operation sequences checked against a simple model, string keys,
+//! repeated updates, and a capacity of zero.
+
+use super::*;
+
+/// Keys ordered from most to least recently used.
+struct Model {
+    capacity: usize,
+    items: Vec<(u32, u32)>,
+}
+
+impl Model {
+    fn get(&mut self, key: u32) -> Option<u32> {
+        let pos = self.items.iter().position(|&(k, _)| k == key)?;
+        let item = self.items.remove(pos);
+        self.items.insert(0, item);
+        Some(item.1)
+    }
+
+    fn put(&mut self, key: u32, value: u32) {
+        if let Some(pos) = self.items.iter().position(|&(k, _)| k == key) {
+            self.items.remove(pos);
+        }
+        self.items.insert(0, (key, value));
+        self.items.truncate(self.capacity);
+    }
+}
+
+#[test]
+fn get_refreshes_recency() {
+    let mut cache = LRUCache::new(2);
+    cache.put(1, "one");
+    cache.put(2, "two");
+    assert_eq!(cache.get(&1), Some("one"));
+    cache.put(3, "three");
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some("one"));
+    assert_eq!(cache.get(&3), Some("three"));
+}
+
+#[test]
+fn update_refreshes_recency_without_growing() {
+    let mut cache = LRUCache::new(2);
+    cache.put(1, 1);
+    cache.put(2, 2);
+    cache.put(1, 10);
+    cache.put(1, 100);
+    assert_eq!(cache.len(), 2);
+    cache.put(3, 3);
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some(100));
+}
+
+#[test]
+fn miss_does_not_change_order() {
+    let mut cache = LRUCache::new(2);
+    cache.put(1, 1);
+    cache.put(2, 2);
+    assert_eq!(cache.get(&9), None);
+    cache.put(3, 3);
+    assert_eq!(cache.get(&1), None);
+    assert_eq!(cache.get(&2), Some(2));
+}
+
+#[test]
+fn zero_capacity() {
+    let mut cache = LRUCache::new(0);
+    cache.put(1, 1);
+    assert_eq!(cache.get(&1), None);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn string_keys() {
+    let mut cache = LRUCache::new(2);
+    cache.put("alpha".to_string(), vec![1]);
+    cache.put("beta".to_string(), vec![2]);
+    cache.put("gamma".to_string(), vec![3]);
+    assert_eq!(cache.get(&"alpha".to_string()), None);
+    assert_eq!(cache.get(&"gamma".to_string()), Some(vec![3]));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn random_operations_match_model() {
+    for capacity in [1, 2, 3, 7, 20] {
+        let mut cache = LRUCache::new(capacity);
+        let mut model = Model { capacity, items: Vec::new() };
+        let mut x: u32 = capacity as u32;
+        for step in 0..5_000 {
+            x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let key = (x >> 16) % 30;
+            if (x >> 8) % 3 == 0 {
+                cache.put(key, step);
+                model.put(key, step);
+            } else {
+                assert_eq!(cache.get(&key), model.get(key), "step {step}, capacity {capacity}");
+            }
+            assert_eq!(cache.len(), model.items.len());
+        }
+    }
+}
+
+#[test]
+fn many_entries() {
+    let mut cache = LRUCache::new(10_000);
+    for i in 0..100_000u32 {
+        cache.put(i, i);
+    }
+    assert_eq!(cache.len(), 10_000);
+    assert_eq!(cache.get(&89_999), None);
+    assert_eq!(cache.get(&90_000), Some(90_000));
+}
+
+#[test]
+fn simple_cache_matches_model() {
+    let mut cache = SimpleLRUCache::new(3);
+    let mut model = Model { capacity: 3, items: Vec::new() };
+    let mut x: u32 = 7;
+    for step in 0..2_000 {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let key = (x >> 16) % 8;
+        if step % 2 == 0 {
+            cache.put(key, step);
+            model.put(key, step);
+        } else {
+            assert_eq!(cache.get(&key), model.get(key), "step {step}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Hidden tests for exercise 139: Trie (Prefix Tree).
+//!
+//! Multi-byte words, the empty word, duplicate inserts, and deletes that must
+//! leave longer and shorter words intact.
+
+use super::*;
+
+fn trie_of(words: &[&str]) -> Trie {
+    let mut trie = Trie::new();
+    for word in words {
+        trie.insert(word);
+    }
+    trie
+}
+
+fn sorted(mut words: Vec<String>) -> Vec<String> {
+    words.sort();
+    words
+}
+
+#[test]
+fn multibyte_words() {
+    let trie = trie_of(&["café", "cafétéria", "日本", "日本語"]);
+    assert!(trie.search("café"));
+    assert!(!trie.search("cafe"));
+    assert!(trie.starts_with("caf"));
+    assert!(trie.starts_with("日"));
+    assert_eq!(sorted(trie.words_with_prefix("日")), vec!["日本", "日本語"]);
+    assert_eq!(trie.count(), 4);
+}
+
+#[test]
+fn duplicate_inserts_count_once() {
+    let trie = trie_of(&["go", "go", "go"]);
+    assert_eq!(trie.count(), 1);
+    assert_eq!(trie.words_with_prefix("g"), vec!["go"]);
+}
+
+#[test]
+fn empty_prefix_and_empty_word() {
+    let mut trie = trie_of(&["a", "b"]);
+    assert!(trie.starts_with(""));
+    assert!(!trie.search(""));
+    trie.insert("");
+    assert!(trie.search(""));
+    assert_eq!(trie.count(), 3);
+}
+
+#[test]
+fn missing_prefix() {
+    let trie = trie_of(&["apple"]);
+    assert!(trie.words_with_prefix("b").is_empty());
+    assert!(trie.words_with_prefix("applesauce").is_empty());
+    assert_eq!(trie.words_with_prefix("apple"), vec!["apple"]);
+}
+
+#[test]
+fn delete_keeps_longer_words() {
+    let mut trie = trie_of(&["app", "apple"]);
+    assert!(trie.delete("app"));
+    assert!(!trie.search("app"));
+    assert!(trie.search("apple"));
+    assert!(trie.starts_with("app"));
+    assert_eq!(trie.count(), 1);
+}
+
+#[test]
+fn delete_keeps_shorter_words() {
+    let mut trie = trie_of(&["app", "apple"]);
+    assert!(trie.delete("apple"));
+    assert!(trie.search("app"));
+    assert!(!trie.starts_with("appl"));
+    assert_eq!(trie.words_with_prefix("a"), vec!["app"]);
+}
+
+#[test]
+fn delete_missing_words() {
+    let mut trie = trie_of(&["apple"]);
+    assert!(!trie.delete("app"));
+    assert!(!trie.delete("apples"));
+    assert!(!trie.delete("banana"));
+    assert!(trie.search("apple"));
+    assert!(trie.delete("apple"));
+    assert!(!trie.delete("apple"));
+    assert_eq!(trie.count(), 0);
+    assert!(!trie.starts_with("a"));
+}
+
+#[test]
+fn many_words() {
+    let mut trie = Trie::new();
+    let mut words = Vec::new();
+    for i in 0..5_000u32 {
+        let word = format!("w{}", i.wrapping_mul(2_654_435_761) % 100_000);
+        trie.insert(&word);
+        words.push(word);
+    }
+    words.sort();
+    words.dedup();
+    assert_eq!(trie.count(), words.len());
+    assert_eq!(sorted(trie.words_with_prefix("")), words);
+    let with_w1: Vec<String> = words.iter().filter(|w| w.starts_with("w1")).cloned().collect();
+    assert_eq!(sorted(trie.words_with_prefix("w1")), with_w1);
+}
@@ -0,0 +1,131 @@
+//! Hidden tests for exercise 140: Graph Representation - Adjacency List.
+//!
+//! Self-loops, parallel edges, in- and out-degrees, transposes and traversals of
+//! directed graphs.
+
+use super::*;
+
+#[test]
+fn undirected_self_loop() {
+    let mut g = Graph::new(2, false);
+    g.add_edge(0, 0);
+    assert!(g.has_edge(0, 0));
+    assert_eq!(g.edge_count(), 1);
+    assert!(g.remove_edge(0, 0));
+    assert!(!g.has_edge(0, 0));
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn directed_self_loop() {
+    let mut g = Graph::new(2, true);
+    g.add_edge(1, 1);
+    assert_eq!(g.degree(1), 1);
+    assert_eq!(g.in_degree(1), 1);
+    assert_eq!(g.edge_count(), 1);
+    assert!(g.remove_edge(1, 1));
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn parallel_edges_are_removed_one_at_a_time() {
+    let mut g = Graph::new(2, false);
+    g.add_edge(0, 1);
+    g.add_edge(0, 1);
+    assert_eq!(g.edge_count(), 2);
+    assert!(g.remove_edge(1, 0));
+    assert!(g.has_edge(0, 1) && g.has_edge(1, 0));
+    assert!(g.remove_edge(0, 1));
+    assert!(!g.has_edge(0, 1) && !g.has_edge(1, 0));
+}
+
+#[test]
+fn directed_removal_is_one_way() {
+    let mut g = Graph::new(2, true);
+    g.add_edge(0, 1);
+    assert!(!g.remove_edge(1, 0));
+    assert!(g.has_edge(0, 1));
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_edge_panics() {
+    let mut g = Graph::new(2, false);
+    g.add_edge(0, 2);
+}
+
+#[test]
+fn out_of_range_queries() {
+    let g = Graph::new(2, true);
+    assert!(!g.has_edge(5, 0));
+    assert!(g.neighbors(5).is_empty());
+    assert_eq!(g.degree(5), 0);
+}
+
+#[test]
+fn degrees_in_a_directed_star() {
+    let mut g = Graph::new(5, true);
+    for v in 1..5 {
+        g.add_edge(0, v);
+        g.add_edge(v, 0);
+    }
+    g.add_edge(1, 2);
+    assert_eq!(g.degree(0), 4);
+    assert_eq!(g.in_degree(0), 4);
+    assert_eq!(g.in_degree(2), 2);
+    assert_eq!(g.degree(2), 1);
+    assert_eq!(g.edge_count(), 9);
+}
+
+#[test]
+fn transpose_reverses_every_edge() {
+    let mut g = Graph::new(4, true);
+    let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 3)];
+    for &(u, v) in &edges {
+        g.add_edge(u, v);
+    }
+    let t = g.transpose();
+    assert_eq!(t.edge_count(), edges.len());
+    for u in 0..4 {
+        for v in 0..4 {
+            assert_eq!(t.has_edge(v, u), g.has_edge(u, v), "{u} -> {v}");
+        }
+        assert_eq!(t.in_degree(u), g.degree(u));
+    }
+    let back = t.transpose();
+    for &(u, v) in &edges {
+        assert!(back.has_edge(u, v));
+    }
+}
+
+#[test]
+fn directed_traversals_follow_edge_direction() {
+    let mut g = Graph::new(4, true);
+    g.add_edge(0, 1);
+    g.add_edge(1, 2);
+    g.add_edge(3, 0);
+    let mut bfs = g.bfs(0);
+    bfs.sort();
+    assert_eq!(bfs, vec![0, 1, 2]);
+    let mut dfs = g.dfs(0);
+    dfs.sort();
+    assert_eq!(dfs, vec![0, 1, 2]);
+    assert_eq!(g.bfs(2), vec![2]);
+    assert_eq!(g.dfs(3), vec![3, 0, 1, 2]);
+}
+
+#[test]
+fn bfs_order_on_a_tree() {
+    let mut g = Graph::new(7, false);
+    for (u, v) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)] {
+        g.add_edge(u, v);
+    }
+    let order = g.bfs(0);
+    let level = |v: usize| match v {
+        0 => 0,
+        1 | 2 => 1,
+        _ => 2,
+    };
+    assert_eq!(order.len(), 7);
+    assert!(order.windows(2).all(|w| level(w[0]) <= level(w[1])), "{order:?}");
+}
@@ -0,0 +1,109 @@
+//! Hidden tests for exercise 141: Graph Representation - Adjacency Matrix.
+//!
+//! Self-loops, directed edge lists, weights after removal, out-of-range queries
+//! and completeness in both directions.
+
+use super::*;
+
+#[test]
+fn undirected_edges_are_listed_once() {
+    let mut g = Graph::new(4, false);
+    g.add_edge(0, 1);
+    g.add_edge(2, 1);
+    g.add_edge(3, 3);
+    let mut edges: Vec<_> = g.get_edges().into_iter().map(|(u, v)| (u.min(v), u.max(v))).collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1), (1, 2), (3, 3)]);
+    assert_eq!(g.edge_count(), 3);
+}
+
+#[test]
+fn directed_edges_are_listed_once_each_way() {
+    let mut g = Graph::new(3, true);
+    g.add_edge(0, 1);
+    g.add_edge(1, 0);
+    g.add_edge(2, 2);
+    let mut edges = g.get_edges();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1), (1, 0), (2, 2)]);
+    assert_eq!(g.in_degree(0), 1);
+    assert_eq!(g.in_degree(2), 1);
+}
+
+#[test]
+fn adding_twice_is_one_edge() {
+    let mut g = Graph::new(2, false);
+    g.add_edge(0, 1);
+    g.add_edge(1, 0);
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.degree(0), 1);
+}
+
+#[test]
+fn removal_clears_weights() {
+    let mut g = Graph::new_weighted(3, false);
+    g.add_weighted_edge(0, 2, -7);
+    assert_eq!(g.get_weight(2, 0), Some(-7));
+    g.remove_edge(2, 0);
+    assert_eq!(g.get_weight(0, 2), None);
+    assert!(!g.has_edge(0, 2));
+}
+
+#[test]
+fn directed_weights_are_one_way() {
+    let mut g = Graph::new_weighted(2, true);
+    g.add_weighted_edge(0, 1, 3);
+    assert_eq!(g.get_weight(0, 1), Some(3));
+    assert_eq!(g.get_weight(1, 0), None);
+}
+
+#[test]
+fn out_of_range_queries() {
+    let mut g = Graph::new(2, false);
+    assert!(!g.has_edge(0, 9));
+    assert!(!g.has_edge(9, 0));
+    assert!(g.neighbors(9).is_empty());
+    assert_eq!(g.get_weight(9, 9), None);
+    g.remove_edge(0, 9);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range_edge_panics() {
+    let mut g = Graph::new(2, true);
+    g.add_edge(2, 0);
+}
+
+#[test]
+fn adjacency_list_matches_matrix() {
+    let mut g = Graph::new(5, true);
+    for (u, v) in [(0, 4), (0, 1), (3, 0), (4, 4), (2, 3)] {
+        g.add_edge(u, v);
+    }
+    let list = g.to_adjacency_list();
+    assert_eq!(list.len(), 5);
+    for (u, neighbors) in list.iter().enumerate() {
+        let mut sorted = neighbors.clone();
+        sorted.sort();
+        assert_eq!(sorted, g.neighbors(u).into_iter().collect::<Vec<_>>());
+        assert!(neighbors.iter().all(|&v| g.has_edge(u, v)));
+    }
+    let mut zero = list[0].clone();
+    zero.sort();
+    assert_eq!(zero, vec![1, 4]);
+}
+
+#[test]
+fn completeness() {
+    assert!(Graph::new(1, false).is_complete());
+    let mut g = Graph::new(3, true);
+    for (u, v) in [(0, 1), (0, 2), (1, 2)] {
+        g.add_edge(u, v);
+    }
+    assert!(!g.is_complete());
+    for (u, v) in [(1, 0), (2, 0), (2, 1)] {
+        g.add_edge(u, v);
+    }
+    assert!(g.is_complete());
+    assert_eq!(g.edge_count(), 6);
+}
@@ -0,0 +1,123 @@
+//! Hidden tests for exercise 142: Cycle Detection in Undirected Graph.
+//!
+//! Self-loops, long paths and cycles, several components, and random graphs
+//! whose answer is known from their edge count.
+
+use super::*;
+
+fn graph_of(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+    let mut graph = Graph::new(vertices);
+    for &(u, v) in edges {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+fn all_methods(graph: &Graph) -> [bool; 3] {
+    [has_cycle_dfs(graph), has_cycle_bfs(graph), has_cycle_union_find(graph)]
+}
+
+fn assert_valid_cycle(graph: &Graph, cycle: &[usize]) {
+    assert!(cycle.len() >= 3, "{cycle:?}");
+    let mut distinct = cycle.to_vec();
+    distinct.sort();
+    distinct.dedup();
+    assert_eq!(distinct.len(), cycle.len(), "{cycle:?}");
+    for i in 0..cycle.len() {
+        let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+        assert!(graph.adj[u].contains(&v), "{u} - {v} missing from {cycle:?}");
+    }
+}
+
+#[test]
+fn self_loop_is_a_cycle() {
+    let graph = graph_of(2, &[(0, 1), (1, 1)]);
+    assert_eq!(all_methods(&graph), [true; 3]);
+}
+
+#[test]
+fn forest_has_no_cycle() {
+    let graph = graph_of(7, &[(0, 1), (1, 2), (3, 4), (3, 5)]);
+    assert_eq!(all_methods(&graph), [false; 3]);
+    assert_eq!(find_cycle(&graph), None);
+    assert!(!is_tree(&graph));
+}
+
+#[test]
+fn cycle_in_a_later_component() {
+    let graph = graph_of(8, &[(0, 1), (2, 3), (5, 6), (6, 7), (7, 5)]);
+    assert_eq!(all_methods(&graph), [true; 3]);
+    let cycle = find_cycle(&graph).unwrap();
+    assert_valid_cycle(&graph, &cycle);
+    let mut sorted = cycle.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![5, 6, 7]);
+}
+
+#[test]
+fn cycle_found_below_a_tail() {
+    // 0 - 1 - 2 - 3 - 4 - 2
+    let graph = graph_of(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 2)]);
+    let cycle = find_cycle(&graph).unwrap();
+    assert_valid_cycle(&graph, &cycle);
+    assert_eq!(cycle.len(), 3);
+}
+
+#[test]
+fn trees() {
+    assert!(is_tree(&graph_of(1, &[])));
+    assert!(is_tree(&graph_of(5, &[(0, 1), (0, 2), (0, 3), (3, 4)])));
+    // Right edge count, but a cycle plus an isolated vertex
+    assert!(!is_tree(&graph_of(4, &[(0, 1), (1, 2), (2, 0)])));
+    assert!(!is_tree(&graph_of(5, &[(0, 1), (1, 2), (2, 0), (3, 4)])));
+}
+
+#[test]
+fn long_path_and_long_cycle() {
+    let mut edges: Vec<_> = (0..2_999).map(|i| (i, i + 1)).collect();
+    let path = graph_of(3_000, &edges);
+    assert_eq!(all_methods(&path), [false; 3]);
+    assert!(is_tree(&path));
+
+    edges.push((2_999, 0));
+    let ring = graph_of(3_000, &edges);
+    assert_eq!(all_methods(&ring), [true; 3]);
+    assert_eq!(find_cycle(&ring).unwrap().len(), 3_000);
+}
+
+#[test]
+fn random_simple_graphs() {
+    let mut x: u64 = 142;
+    let mut next = |bound: u64| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    };
+    for round in 0..200 {
+        let vertices = 2 + round % 12;
+        let mut edges = Vec::new();
+        for _ in 0..next(vertices as u64 + 2) {
+            let (u, v) = (next(vertices as u64) as usize, next(vertices as u64) as usize);
+            if u != v && !edges.contains(&(u, v)) && !edges.contains(&(v, u)) {
+                edges.push((u, v));
+            }
+        }
+        // A simple graph is a forest exactly when |E| = |V| - components
+        let mut labels: Vec<usize> = (0..vertices).collect();
+        for &(u, v) in &edges {
+            let (a, b) = (labels[u], labels[v]);
+            labels.iter_mut().filter(|l| **l == b).for_each(|l| *l = a);
+        }
+        let mut components = labels.clone();
+        components.sort();
+        components.dedup();
+        let cyclic = edges.len() != vertices - components.len();
+
+        let graph = graph_of(vertices, &edges);
+        assert_eq!(all_methods(&graph), [cyclic; 3], "{vertices} vertices, {edges:?}");
+        match find_cycle(&graph) {
+            Some(cycle) => assert_valid_cycle(&graph, &cycle),
+            None => assert!(!cyclic, "{edges:?}"),
+        }
+        assert_eq!(is_tree(&graph), !cyclic && components.len() == 1);
+    }
+}
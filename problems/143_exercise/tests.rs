@@ -0,0 +1,114 @@
+//! Hidden tests for exercise 143: Topological Sort.
+//!
+//! Self-loops, parallel edges, cycles hidden behind acyclic parts, counts of
+//! all orderings, and random DAGs.
+
+use super::*;
+
+fn graph_of(vertices: usize, edges: &[(usize, usize)]) -> Graph {
+    let mut graph = Graph::new(vertices);
+    for &(u, v) in edges {
+        graph.add_edge(u, v);
+    }
+    graph
+}
+
+#[test]
+fn self_loop_is_a_cycle() {
+    let graph = graph_of(3, &[(0, 1), (1, 1)]);
+    assert_eq!(topological_sort_kahn(&graph), None);
+    assert_eq!(topological_sort_dfs(&graph), None);
+    assert!(!is_dag(&graph));
+}
+
+#[test]
+fn parallel_edges() {
+    let graph = graph_of(3, &[(0, 1), (0, 1), (1, 2), (1, 2)]);
+    assert_eq!(topological_sort_kahn(&graph), Some(vec![0, 1, 2]));
+    assert_eq!(topological_sort_dfs(&graph), Some(vec![0, 1, 2]));
+    assert_eq!(all_topological_sorts(&graph), vec![vec![0, 1, 2]]);
+}
+
+#[test]
+fn cycle_reached_late() {
+    // The acyclic front part sorts fine before the cycle 4 -> 5 -> 6 -> 4
+    let graph = graph_of(7, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 4)]);
+    assert_eq!(topological_sort_kahn(&graph), None);
+    assert_eq!(topological_sort_dfs(&graph), None);
+    assert!(all_topological_sorts(&graph).is_empty());
+}
+
+#[test]
+fn cross_edges_are_not_cycles() {
+    // DFS from 0 finishes 2 before reaching it again from 1
+    let graph = graph_of(3, &[(0, 2), (1, 2), (0, 1)]);
+    assert_eq!(topological_sort_dfs(&graph), Some(vec![0, 1, 2]));
+    assert!(is_dag(&graph));
+}
+
+#[test]
+fn validity_check() {
+    let graph = graph_of(3, &[(0, 1), (1, 2)]);
+    assert!(is_valid_topological_order(&graph, &[0, 1, 2]));
+    assert!(!is_valid_topological_order(&graph, &[1, 0, 2]));
+    assert!(!is_valid_topological_order(&graph, &[0, 1]));
+    assert!(!is_valid_topological_order(&graph, &[0, 1, 1]));
+}
+
+#[test]
+fn counts_of_all_orderings() {
+    assert_eq!(all_topological_sorts(&graph_of(4, &[])).len(), 24);
+    let diamond = graph_of(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+    let mut all = all_topological_sorts(&diamond);
+    all.sort();
+    assert_eq!(all, vec![vec![0, 1, 2, 3], vec![0, 2, 1, 3]]);
+    let graph = graph_of(6, &[(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)]);
+    let all = all_topological_sorts(&graph);
+    assert_eq!(all.len(), 13);
+    assert!(all.iter().all(|order| is_valid_topological_order(&graph, order)));
+}
+
+#[test]
+fn long_chain() {
+    let edges: Vec<_> = (0..2_999).map(|i| (i + 1, i)).collect();
+    let graph = graph_of(3_000, &edges);
+    let expected: Vec<usize> = (0..3_000).rev().collect();
+    assert_eq!(topological_sort_kahn(&graph), Some(expected.clone()));
+    assert_eq!(topological_sort_dfs(&graph), Some(expected));
+}
+
+#[test]
+fn random_dags() {
+    let mut x: u64 = 143;
+    let mut next = |bound: u64| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    };
+    for round in 0..100 {
+        let vertices = 2 + round % 30;
+        // Edges only go from a smaller to a larger label, then labels are shuffled
+        let mut label: Vec<usize> = (0..vertices).collect();
+        for i in (1..vertices).rev() {
+            label.swap(i, next(i as u64 + 1) as usize);
+        }
+        let mut edges = Vec::new();
+        for _ in 0..next(3 * vertices as u64) {
+            let (a, b) = (next(vertices as u64) as usize, next(vertices as u64) as usize);
+            if a < b {
+                edges.push((label[a], label[b]));
+            }
+        }
+        let graph = graph_of(vertices, &edges);
+        let kahn = topological_sort_kahn(&graph).expect("acyclic");
+        let dfs = topological_sort_dfs(&graph).expect("acyclic");
+        assert!(is_valid_topological_order(&graph, &kahn), "{edges:?}: {kahn:?}");
+        assert!(is_valid_topological_order(&graph, &dfs), "{edges:?}: {dfs:?}");
+
+        if let Some(&(u, v)) = edges.first() {
+            let mut cyclic = graph.clone();
+            cyclic.add_edge(v, u);
+            assert_eq!(topological_sort_kahn(&cyclic), None);
+            assert_eq!(topological_sort_dfs(&cyclic), None);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Hidden tests for exercise 144: Union-Find (Disjoint Set Union - DSU).
+//!
+//! Self-unions, set sizes, long union chains and random unions checked against
+//! a naive labelling.
+
+use super::*;
+
+#[test]
+fn union_with_itself() {
+    let mut uf = UnionFind::new(3);
+    assert!(!uf.union(1, 1));
+    assert_eq!(uf.set_count(), 3);
+    assert!(uf.connected(2, 2));
+}
+
+#[test]
+fn set_sizes() {
+    let mut uf = UnionFind::new(6);
+    uf.union(0, 1);
+    uf.union(2, 3);
+    uf.union(1, 3);
+    assert_eq!(uf.set_size(0), 4);
+    assert_eq!(uf.set_size(3), 4);
+    assert_eq!(uf.set_size(4), 1);
+    assert!(!uf.union(0, 2));
+    assert_eq!(uf.set_size(2), 4);
+    assert_eq!(uf.set_count(), 3);
+}
+
+#[test]
+fn connectivity_is_transitive() {
+    let mut uf = UnionFind::new(5);
+    uf.union(0, 1);
+    uf.union(3, 4);
+    assert!(!uf.connected(0, 4));
+    uf.union(1, 3);
+    assert!(uf.connected(0, 4));
+    assert_eq!(uf.set_count(), 2);
+}
+
+#[test]
+fn empty_and_single() {
+    assert_eq!(UnionFind::new(0).set_count(), 0);
+    assert_eq!(count_components(0, &[]), 0);
+    assert_eq!(count_components(1, &[(0, 0)]), 1);
+}
+
+#[test]
+fn components_with_repeated_edges() {
+    let edges = [(0, 1), (1, 0), (0, 1), (2, 2), (3, 4), (4, 3)];
+    assert_eq!(count_components(6, &edges), 4);
+}
+
+#[test]
+fn long_chains() {
+    let n = 200_000;
+    let mut uf = UnionFind::new(n);
+    for i in 1..n {
+        assert!(uf.union(i - 1, i));
+    }
+    assert_eq!(uf.set_count(), 1);
+    assert_eq!(uf.set_size(n / 2), n);
+    assert!(uf.connected(0, n - 1));
+
+    // Joining each new element's root under the next keeps a naive tree deep
+    let mut uf = UnionFind::new(n);
+    for i in (1..n).rev() {
+        uf.union(i, i - 1);
+    }
+    assert!(uf.connected(n - 1, 0));
+}
+
+#[test]
+fn random_unions_match_naive_labels() {
+    let n = 300;
+    let mut uf = UnionFind::new(n);
+    let mut labels: Vec<usize> = (0..n).collect();
+    let mut x: u64 = 144;
+    let mut next = || {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) as usize % n
+    };
+    for step in 0..600 {
+        let (a, b) = (next(), next());
+        let joined = labels[a] != labels[b];
+        assert_eq!(uf.union(a, b), joined, "step {step}");
+        let (la, lb) = (labels[a], labels[b]);
+        labels.iter_mut().filter(|l| **l == lb).for_each(|l| *l = la);
+
+        let (c, d) = (next(), next());
+        assert_eq!(uf.connected(c, d), labels[c] == labels[d], "step {step}");
+        assert_eq!(uf.set_size(c), labels.iter().filter(|&&l| l == labels[c]).count());
+    }
+    let mut distinct = labels.clone();
+    distinct.sort();
+    distinct.dedup();
+    assert_eq!(uf.set_count(), distinct.len());
+}
@@ -0,0 +1,25 @@
+//! Property tests for exercise 145: KMP String Matching Algorithm.
+//!
+//! KMP finds exactly the matches that checking every position does. A
+//! two-letter alphabet makes overlapping matches common.
+
+use super::*;
+use prop::{check, strings};
+
+fn naive(text: &str, pattern: &str) -> Vec<usize> {
+    let (text, pattern): (Vec<char>, Vec<char>) = (text.chars().collect(), pattern.chars().collect());
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+    text.windows(pattern.len()).enumerate().filter(|(_, w)| *w == pattern.as_slice()).map(|(i, _)| i).collect()
+}
+
+#[test]
+fn agrees_with_naive_search() {
+    check((strings("ab", 0..=40), strings("ab", 0..=6)), |(text, pattern)| {
+        let expected = naive(&text, &pattern);
+        assert_eq!(kmp_search(&text, &pattern), expected, "kmp_search({text:?}, {pattern:?})");
+        assert_eq!(kmp_find_first(&text, &pattern), expected.first().copied());
+        assert_eq!(kmp_count(&text, &pattern), expected.len());
+    });
+}
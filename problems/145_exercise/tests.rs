@@ -0,0 +1,97 @@
+//! Hidden tests for exercise 145: KMP String Matching Algorithm.
+//!
+//! Overlapping and periodic patterns, multi-byte text, patterns at the very end,
+//! and long inputs checked against a naive search.
+
+use super::*;
+
+fn naive(text: &str, pattern: &str) -> Vec<usize> {
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return vec![];
+    }
+    (0..=text.len() - pattern.len()).filter(|&i| text[i..i + pattern.len()] == pattern[..]).collect()
+}
+
+fn naive_lps(pattern: &str) -> Vec<usize> {
+    let p: Vec<char> = pattern.chars().collect();
+    (0..p.len())
+        .map(|i| (1..=i).rev().find(|&len| p[..len] == p[i + 1 - len..=i]).unwrap_or(0))
+        .collect()
+}
+
+fn check(text: &str, pattern: &str) {
+    let expected = naive(text, pattern);
+    assert_eq!(kmp_search(text, pattern), expected, "{text:?} / {pattern:?}");
+    assert_eq!(kmp_count(text, pattern), expected.len());
+    assert_eq!(kmp_find_first(text, pattern), expected.first().copied());
+    assert_eq!(contains(text, pattern), !expected.is_empty());
+}
+
+#[test]
+fn lps_tricky_fallbacks() {
+    for pattern in ["AABAAAB", "ABABCABAB", "AAACAAAA", "ABACABAB", "abcabd", "aabaabaaa"] {
+        assert_eq!(build_lps(pattern), naive_lps(pattern), "{pattern}");
+    }
+    assert_eq!(build_lps(""), Vec::<usize>::new());
+    assert_eq!(build_lps("x"), vec![0]);
+}
+
+#[test]
+fn periodic_patterns() {
+    check("abababababab", "abab");
+    check("aaaaaaaaab", "aaab");
+    check("aabaabaabaab", "aabaab");
+    check("abcabcabd", "abcabd");
+}
+
+#[test]
+fn match_at_the_edges() {
+    check("needle in a haystack", "needle");
+    check("haystack with a needle", "needle");
+    check("same", "same");
+    check("abc", "abcd");
+}
+
+#[test]
+fn empty_inputs() {
+    check("", "a");
+    assert_eq!(kmp_search("", ""), Vec::<usize>::new());
+    assert_eq!(kmp_search("abc", ""), Vec::<usize>::new());
+}
+
+#[test]
+fn multibyte_text_uses_char_positions() {
+    assert_eq!(kmp_search("ééé", "éé"), vec![0, 1]);
+    assert_eq!(kmp_search("naïve naïve", "ïve"), vec![2, 8]);
+    assert_eq!(kmp_find_first("🦀🦀🐍🦀", "🐍"), Some(2));
+    check("日本語の日本語", "日本");
+}
+
+#[test]
+fn random_texts_match_naive_search() {
+    let mut x: u64 = 145;
+    let mut word = |len: u64| -> String {
+        (0..len)
+            .map(|_| {
+                x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                ['a', 'b', 'c'][((x >> 33) % 3) as usize]
+            })
+            .collect()
+    };
+    for round in 0..300 {
+        let text = word(20 + round % 40);
+        let pattern = word(1 + round % 5);
+        check(&text, &pattern);
+        assert_eq!(build_lps(&pattern), naive_lps(&pattern), "{pattern}");
+    }
+}
+
+#[test]
+fn long_text() {
+    let text = "a".repeat(200_000) + "b";
+    let pattern = "a".repeat(1_000) + "b";
+    assert_eq!(kmp_search(&text, &pattern), vec![199_000]);
+    assert_eq!(kmp_count(&text, &"a".repeat(1_000)), 199_001);
+}
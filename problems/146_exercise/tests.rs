@@ -0,0 +1,78 @@
+//! Hidden tests for exercise 146: atoi Implementation (String to Integer).
+//!
+//! Boundaries one past the i32 limits, very long digit runs, whitespace and sign
+//! edge cases, and both versions checked against each other.
+
+use super::*;
+
+fn both(s: &str) -> i32 {
+    let value = my_atoi(s);
+    assert_eq!(my_atoi_i64(s), value, "versions disagree on {s:?}");
+    value
+}
+
+#[test]
+fn one_past_the_limits() {
+    assert_eq!(both("2147483648"), i32::MAX);
+    assert_eq!(both("-2147483649"), i32::MIN);
+    assert_eq!(both("2147483646"), 2_147_483_646);
+    assert_eq!(both("-2147483647"), -2_147_483_647);
+}
+
+#[test]
+fn very_long_digit_runs() {
+    assert_eq!(both(&"9".repeat(100)), i32::MAX);
+    assert_eq!(both(&format!("-{}", "9".repeat(100))), i32::MIN);
+    assert_eq!(both(&format!("{}1", "0".repeat(1_000))), 1);
+    assert_eq!(both(&format!("+{}", "1".repeat(40))), i32::MAX);
+}
+
+#[test]
+fn zeros_and_signs() {
+    assert_eq!(both("0"), 0);
+    assert_eq!(both("-0"), 0);
+    assert_eq!(both("+0"), 0);
+    assert_eq!(both("- 42"), 0);
+    assert_eq!(both("+ 42"), 0);
+    assert_eq!(both("--42"), 0);
+    assert_eq!(both("-a42"), 0);
+}
+
+#[test]
+fn whitespace_kinds() {
+    assert_eq!(both("\t\n\r 42"), 42);
+    assert_eq!(both("  \t -7x"), -7);
+    assert_eq!(both("4 2"), 4);
+}
+
+#[test]
+fn stops_at_first_non_digit() {
+    assert_eq!(both("12-34"), 12);
+    assert_eq!(both("1e5"), 1);
+    assert_eq!(both("-12abc34"), -12);
+    assert_eq!(both("0x1F"), 0);
+    assert_eq!(both("١٢٣"), 0);
+    assert_eq!(both("42é"), 42);
+}
+
+#[test]
+fn every_value_round_trips() {
+    let mut x: u32 = 146;
+    for _ in 0..2_000 {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let value = x as i32;
+        assert_eq!(both(&value.to_string()), value);
+        assert_eq!(both(&format!("  {value}xyz")), value);
+    }
+    assert_eq!(both(&i32::MIN.to_string()), i32::MIN);
+    assert_eq!(both(&i32::MAX.to_string()), i32::MAX);
+}
+
+#[test]
+fn simple_floats() {
+    assert_eq!(parse_float("123.456"), Some((123, 456)));
+    assert_eq!(parse_float("-7.25"), Some((-7, 25)));
+    assert_eq!(parse_float("42"), Some((42, 0)));
+    assert_eq!(parse_float("abc"), None);
+    assert_eq!(parse_float("1.2.3"), None);
+}
@@ -0,0 +1,28 @@
+//! Property tests for exercise 147: Reverse Integer.
+//!
+//! `reverse` agrees with reversing the decimal digits, and returns 0
+//! exactly when the result would not fit in an `i32`.
+
+use super::*;
+use prop::{check, ints};
+
+#[test]
+fn agrees_with_reversing_the_digits() {
+    check(ints(i32::MIN..=i32::MAX), |x| {
+        let digits: String = x.unsigned_abs().to_string().chars().rev().collect();
+        let magnitude: i64 = digits.parse().unwrap();
+        let expected = i32::try_from(if x < 0 { -magnitude } else { magnitude }).unwrap_or(0);
+        assert_eq!(reverse(x), expected, "reverse({x})");
+        assert_eq!(reverse_string(x), expected, "reverse_string({x})");
+    });
+}
+
+#[test]
+fn reversing_twice_restores_numbers_without_trailing_zeros() {
+    check(ints(i32::MIN..=i32::MAX), |x| {
+        let reversed = reverse(x);
+        if x % 10 != 0 && reversed != 0 {
+            assert_eq!(reverse(reversed), x, "reverse({x}) = {reversed}");
+        }
+    });
+}
@@ -0,0 +1,81 @@
+//! Hidden tests for exercise 147: Reverse Integer.
+//!
+//! Results right at the i32 limits, negative overflow, trailing zeros, and every
+//! version checked against a reversal in i64.
+
+use super::*;
+
+/// Reverses in i64 and reports None where the result leaves i32.
+fn expected(x: i32) -> Option<i32> {
+    let negative = x < 0;
+    let digits: String = (x as i64).abs().to_string().chars().rev().collect();
+    let value: i64 = digits.parse().unwrap();
+    i32::try_from(if negative { -value } else { value }).ok()
+}
+
+fn check(x: i32) {
+    let want = expected(x);
+    assert_eq!(reverse(x), want.unwrap_or(0), "reverse({x})");
+    assert_eq!(reverse_string(x), want.unwrap_or(0), "reverse_string({x})");
+    assert_eq!(would_overflow(x), want.is_none(), "would_overflow({x})");
+    if x >= 0 {
+        assert_eq!(reverse_positive(x), want.unwrap_or(0), "reverse_positive({x})");
+    }
+}
+
+#[test]
+fn results_at_the_limits() {
+    check(1_463_847_412);
+    check(-1_463_847_412);
+    check(-2_147_483_412);
+    check(2_147_483_412);
+    check(1_563_847_412);
+    check(-1_563_847_412);
+    check(1_000_000_003);
+    check(i32::MAX);
+    check(i32::MIN);
+}
+
+#[test]
+fn small_and_trailing_zero_values() {
+    for x in [0, 1, -1, 9, -9, 10, -10, 100, 1_000_000_000, -1_000_000_000, 2_000_000_000] {
+        check(x);
+    }
+}
+
+#[test]
+fn random_values() {
+    let mut x: u32 = 147;
+    for _ in 0..5_000 {
+        x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        check(x as i32);
+        check((x >> 8) as i32);
+    }
+}
+
+#[test]
+fn palindromes() {
+    assert!(is_palindrome(1_234_554_321));
+    assert!(is_palindrome(2_147_447_412));
+    assert!(!is_palindrome(i32::MAX));
+    assert!(!is_palindrome(999_999_991));
+    assert!(is_palindrome(1_000_000_001));
+    assert!(is_palindrome(11));
+    assert!(!is_palindrome(i32::MIN));
+    for x in 0..10_000 {
+        let s = x.to_string();
+        assert_eq!(is_palindrome(x), s.chars().rev().collect::<String>() == s, "{x}");
+    }
+}
+
+#[test]
+fn other_bases() {
+    assert_eq!(reverse_in_base(13, 2), 11);
+    assert_eq!(reverse_in_base(1, 2), 1);
+    assert_eq!(reverse_in_base(0, 16), 0);
+    assert_eq!(reverse_in_base(0x12, 16), 0x21);
+    assert_eq!(reverse_in_base(123, 10), 321);
+    // 0x7FFF_FFFF reversed in binary is itself; 0x4000_0000 becomes 1
+    assert_eq!(reverse_in_base(i32::MAX, 2), i32::MAX);
+    assert_eq!(reverse_in_base(0x4000_0000, 2), 1);
+}
@@ -116,7 +116,7 @@ fn main() {
     }
     
     println!("\nLongest valid substring:");
-    for s in vec!["(()", ")()())", "") {
+    for s in vec!["(()", ")()())", ""] {
         println!("'{}' longest valid: {}", s, longest_valid_parentheses(s));
     }
 }
@@ -188,6 +188,6 @@ mod tests {
         assert_eq!(longest_valid_parentheses("(()"), 2);
         assert_eq!(longest_valid_parentheses(")()())"), 4);
         assert_eq!(longest_valid_parentheses(""), 0);
-        assert_eq!(longest_valid_parentheses("()(()"), 4);
+        assert_eq!(longest_valid_parentheses("()(()"), 2); // "()" twice, separated by an unmatched "("
     }
 }
@@ -0,0 +1,99 @@
+//! Hidden tests for exercise 148: Valid Parentheses (Stack).
+//!
+//! Bracket types mixed with other text, long and deeply nested inputs, positions
+//! of the first error, and brute-force checks for the bonus functions.
+
+use super::*;
+
+fn all_strings(len: usize, alphabet: &[char]) -> Vec<String> {
+    let mut out = vec![String::new()];
+    for _ in 0..len {
+        out = out
+            .iter()
+            .flat_map(|s| alphabet.iter().map(move |&c| format!("{s}{c}")))
+            .collect();
+    }
+    out
+}
+
+#[test]
+fn other_characters_are_ignored() {
+    assert!(is_valid("fn main() { let v = [1, 2]; }"));
+    assert!(is_valid("abc"));
+    assert!(!is_valid("if (x] {"));
+    assert!(is_valid("é(ü)ß"));
+}
+
+#[test]
+fn close_before_open() {
+    assert!(!is_valid(")("));
+    assert!(!is_valid("}{"));
+    assert!(!is_valid("][][]"));
+}
+
+#[test]
+fn first_error_positions() {
+    assert_eq!(find_invalid_position(")"), Some(0));
+    assert_eq!(find_invalid_position("(()]"), Some(3));
+    assert_eq!(find_invalid_position("([)]"), Some(2));
+    assert_eq!(find_invalid_position("{[]}"), None);
+    assert_eq!(find_invalid_position(""), None);
+    assert_eq!(find_invalid_position("é)"), Some(1));
+}
+
+#[test]
+fn deep_nesting() {
+    let depth = 100_000;
+    let nested = "(".repeat(depth) + &")".repeat(depth);
+    assert!(is_valid(&nested));
+    assert!(!is_valid(&nested[1..]));
+    assert_eq!(longest_valid_parentheses(&nested), 2 * depth);
+    assert_eq!(min_add_to_make_valid(&nested[..depth]), depth);
+}
+
+#[test]
+fn long_flat_input() {
+    let flat = "()[]{}".repeat(50_000);
+    assert!(is_valid(&flat));
+    assert_eq!(find_invalid_position(&(flat.clone() + "(")), Some(300_001));
+}
+
+#[test]
+fn min_add_matches_brute_force() {
+    for s in all_strings(8, &['(', ')']) {
+        let mut open = 0i32;
+        let mut lowest = 0i32;
+        for c in s.chars() {
+            open += if c == '(' { 1 } else { -1 };
+            lowest = lowest.min(open);
+        }
+        let expected = (-lowest + (open - lowest)) as usize;
+        assert_eq!(min_add_to_make_valid(&s), expected, "{s}");
+        assert_eq!(expected == 0, is_valid(&s), "{s}");
+    }
+}
+
+#[test]
+fn removing_one_matches_brute_force() {
+    for s in all_strings(5, &['(', ')', '[', ']']) {
+        let chars: Vec<char> = s.chars().collect();
+        let expected = (0..chars.len()).any(|i| {
+            let rest: String = chars[..i].iter().chain(&chars[i + 1..]).collect();
+            is_valid(&rest)
+        });
+        assert_eq!(can_be_valid_by_removing_one(&s), expected, "{s}");
+    }
+}
+
+#[test]
+fn longest_valid_matches_brute_force() {
+    for s in all_strings(10, &['(', ')']) {
+        let expected = (0..s.len())
+            .flat_map(|i| (i..=s.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| is_valid(&s[i..j]))
+            .map(|(i, j)| j - i)
+            .max()
+            .unwrap_or(0);
+        assert_eq!(longest_valid_parentheses(&s), expected, "{s}");
+    }
+}
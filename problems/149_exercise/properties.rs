@@ -0,0 +1,35 @@
+//! Property tests for exercise 149: Merge k Sorted Lists Concept.
+//!
+//! Merging any sorted lists gives their concatenation, sorted, whichever
+//! method does it.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+#[test]
+fn every_method_sorts_the_concatenation() {
+    check(vecs(vecs(ints(-20..=20), 0..=8), 0..=8), |mut lists| {
+        for list in &mut lists {
+            list.sort();
+        }
+        let mut expected = lists.concat();
+        expected.sort();
+        assert_eq!(merge_k_lists_brute_force(lists.clone()), expected, "brute force");
+        assert_eq!(merge_k_lists_heap(lists.clone()), expected, "heap");
+        assert_eq!(merge_k_lists_divide_conquer(lists.clone()), expected, "divide and conquer");
+        assert_eq!(merge_k_lists_sequential(lists), expected, "sequential");
+    });
+}
+
+#[test]
+fn kth_smallest_is_the_kth_of_the_merge() {
+    check((vecs(vecs(ints(-20..=20), 0..=8), 0..=8), ints(0..=70usize)), |(mut lists, k)| {
+        for list in &mut lists {
+            list.sort();
+        }
+        let mut merged = lists.concat();
+        merged.sort();
+        let expected = k.checked_sub(1).and_then(|i| merged.get(i)).copied();
+        assert_eq!(find_kth_smallest(&lists, k), expected, "k = {k}");
+    });
+}
@@ -0,0 +1,90 @@
+//! Hidden tests for exercise 149: Merge k Sorted Lists Concept.
+//!
+//! Duplicates across lists, many empty lists, extreme values, a large k, and
+//! k-th smallest queries checked against a full merge.
+
+use super::*;
+
+fn all_methods(lists: &[Vec<i32>]) -> [Vec<i32>; 4] {
+    [
+        merge_k_lists_brute_force(lists.to_vec()),
+        merge_k_lists_heap(lists.to_vec()),
+        merge_k_lists_divide_conquer(lists.to_vec()),
+        merge_k_lists_sequential(lists.to_vec()),
+    ]
+}
+
+fn check(lists: &[Vec<i32>]) {
+    let mut expected: Vec<i32> = lists.concat();
+    expected.sort();
+    for (method, merged) in all_methods(lists).iter().enumerate() {
+        assert_eq!(merged, &expected, "method {method} on {lists:?}");
+    }
+    for k in 0..=expected.len() + 1 {
+        let want = k.checked_sub(1).and_then(|i| expected.get(i)).copied();
+        assert_eq!(find_kth_smallest(lists, k), want, "k = {k} on {lists:?}");
+    }
+}
+
+#[test]
+fn duplicates_across_lists() {
+    check(&[vec![1, 1, 2], vec![1, 2, 2], vec![2, 2, 2]]);
+    check(&[vec![5; 4], vec![5; 3]]);
+}
+
+#[test]
+fn many_empty_lists() {
+    let mut lists = vec![vec![]; 50];
+    lists[17] = vec![3, 4];
+    lists[42] = vec![1];
+    check(&lists);
+    check(&[vec![], vec![]]);
+    check(&[]);
+}
+
+#[test]
+fn extreme_values() {
+    check(&[vec![i32::MIN, 0, i32::MAX], vec![i32::MIN, i32::MIN], vec![i32::MAX]]);
+    check(&[vec![-5, -3], vec![-4, -4, -1], vec![-10]]);
+}
+
+#[test]
+fn uneven_lengths() {
+    let long: Vec<i32> = (0..1_000).collect();
+    check(&[long, vec![500], vec![-1, 1_001]]);
+}
+
+#[test]
+fn two_list_merge_keeps_everything() {
+    assert_eq!(merge_two_lists(&[1, 1, 3], &[1, 2]), vec![1, 1, 1, 2, 3]);
+    assert_eq!(merge_two_lists(&[i32::MIN], &[i32::MAX]), vec![i32::MIN, i32::MAX]);
+}
+
+#[test]
+fn random_lists() {
+    let mut x: u64 = 149;
+    let mut next = |bound: u64| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    };
+    for _ in 0..50 {
+        let k = next(8) as usize + 1;
+        let lists: Vec<Vec<i32>> = (0..k)
+            .map(|_| {
+                let mut list: Vec<i32> = (0..next(12)).map(|_| next(41) as i32 - 20).collect();
+                list.sort();
+                list
+            })
+            .collect();
+        check(&lists);
+    }
+}
+
+#[test]
+fn many_lists() {
+    let lists: Vec<Vec<i32>> = (0..1_000).map(|i| (0..50).map(|j| j * 1_000 + i).collect()).collect();
+    let expected: Vec<i32> = (0..50_000).collect();
+    assert_eq!(merge_k_lists_heap(lists.clone()), expected);
+    assert_eq!(merge_k_lists_divide_conquer(lists.clone()), expected);
+    assert_eq!(find_kth_smallest(&lists, 12_345), Some(12_344));
+}
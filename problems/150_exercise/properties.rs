@@ -0,0 +1,45 @@
+//! Property tests for exercise 150: Matrix Rotation (90 degrees).
+//!
+//! Four quarter turns give back any square matrix, and each way of turning
+//! it agrees with the others.
+
+use super::*;
+use prop::{check, ints, vecs};
+
+/// The `n` by `n` matrix filled row by row from `values`.
+fn square(n: usize, values: &[i32]) -> Vec<Vec<i32>> {
+    (0..n).map(|i| values[i * n..(i + 1) * n].to_vec()).collect()
+}
+
+#[test]
+fn four_quarter_turns_are_the_identity() {
+    check((ints(0..=7usize), vecs(ints(-99..=99), 49..=49)), |(n, values)| {
+        let original = square(n, &values);
+        let mut matrix = original.clone();
+        for _ in 0..4 {
+            rotate_clockwise(&mut matrix);
+        }
+        assert_eq!(matrix, original);
+    });
+}
+
+#[test]
+fn every_method_agrees() {
+    check((ints(0..=7usize), vecs(ints(-99..=99), 49..=49)), |(n, values)| {
+        let original = square(n, &values);
+        let expected = rotate_clockwise_new(&original);
+        let mut matrix = original.clone();
+        rotate_clockwise(&mut matrix);
+        assert_eq!(matrix, expected, "rotate_clockwise");
+        let mut layered = original.clone();
+        rotate_layer_by_layer(&mut layered);
+        assert_eq!(layered, expected, "rotate_layer_by_layer");
+        assert_eq!(rotate_rectangle(&original), expected, "rotate_rectangle");
+
+        rotate_counter_clockwise(&mut matrix);
+        assert_eq!(matrix, original, "rotate_counter_clockwise undoes rotate_clockwise");
+        let mut half = original.clone();
+        rotate_180(&mut half);
+        assert_eq!(half, rotate_clockwise_new(&expected), "rotate_180");
+    });
+}
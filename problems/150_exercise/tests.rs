@@ -0,0 +1,106 @@
+//! Hidden tests for exercise 150: Matrix Rotation (90 degrees).
+//!
+//! Odd and even sizes up to 9x9, the empty matrix, rotations undoing each other,
+//! every method agreeing, and thin rectangles.
+
+use super::*;
+
+fn numbered(n: usize) -> Vec<Vec<i32>> {
+    (0..n).map(|r| (0..n).map(|c| (r * n + c) as i32 - 7).collect()).collect()
+}
+
+/// Clockwise rotation straight from the definition: new[r][c] = old[n-1-c][r].
+fn rotated(matrix: &[Vec<i32>]) -> Vec<Vec<i32>> {
+    let rows = matrix.len();
+    let cols = matrix.first().map_or(0, Vec::len);
+    (0..cols).map(|r| (0..rows).map(|c| matrix[rows - 1 - c][r]).collect()).collect()
+}
+
+#[test]
+fn every_method_agrees_for_many_sizes() {
+    for n in 1..=9 {
+        let original = numbered(n);
+        let expected = rotated(&original);
+
+        let mut m = original.clone();
+        rotate_clockwise(&mut m);
+        assert_eq!(m, expected, "clockwise {n}x{n}");
+
+        let mut m = original.clone();
+        rotate_layer_by_layer(&mut m);
+        assert_eq!(m, expected, "layer by layer {n}x{n}");
+
+        assert_eq!(rotate_clockwise_new(&original), expected, "new {n}x{n}");
+        assert_eq!(rotate_rectangle(&original), expected, "rectangle {n}x{n}");
+    }
+}
+
+#[test]
+fn rotations_undo_each_other() {
+    for n in 1..=9 {
+        let original = numbered(n);
+
+        let mut m = original.clone();
+        rotate_clockwise(&mut m);
+        rotate_counter_clockwise(&mut m);
+        assert_eq!(m, original, "{n}x{n}");
+
+        let mut m = original.clone();
+        rotate_180(&mut m);
+        let mut twice = original.clone();
+        rotate_clockwise(&mut twice);
+        rotate_clockwise(&mut twice);
+        assert_eq!(m, twice, "{n}x{n}");
+
+        let mut m = original.clone();
+        rotate_counter_clockwise(&mut m);
+        for _ in 0..3 {
+            rotate_counter_clockwise(&mut m);
+        }
+        assert_eq!(m, original, "{n}x{n}");
+    }
+}
+
+#[test]
+fn empty_matrix() {
+    let mut m: Vec<Vec<i32>> = vec![];
+    rotate_clockwise(&mut m);
+    rotate_counter_clockwise(&mut m);
+    rotate_180(&mut m);
+    rotate_layer_by_layer(&mut m);
+    assert!(m.is_empty());
+    assert!(rotate_clockwise_new(&m).is_empty());
+    assert!(rotate_rectangle(&m).is_empty());
+    assert!(is_square(&m));
+}
+
+#[test]
+fn thin_rectangles() {
+    assert_eq!(rotate_rectangle(&[vec![1, 2, 3]]), vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(rotate_rectangle(&[vec![1], vec![2], vec![3]]), vec![vec![3, 2, 1]]);
+    let wide: Vec<Vec<i32>> = (0..3).map(|r| (0..7).map(|c| r * 7 + c).collect()).collect();
+    let once = rotate_rectangle(&wide);
+    assert_eq!((once.len(), once[0].len()), (7, 3));
+    assert_eq!(once, rotated(&wide));
+    let back = rotate_rectangle(&rotate_rectangle(&rotate_rectangle(&once)));
+    assert_eq!(back, wide);
+}
+
+#[test]
+fn squareness() {
+    assert!(!is_square(&[vec![1, 2], vec![3]]));
+    assert!(!is_square(&[vec![1], vec![2]]));
+    assert!(is_square(&numbered(6)));
+}
+
+#[test]
+fn large_matrix() {
+    let original = numbered(300);
+    let mut m = original.clone();
+    rotate_clockwise(&mut m);
+    assert_eq!(m[0][0], original[299][0]);
+    assert_eq!(m[0][299], original[0][0]);
+    rotate_layer_by_layer(&mut m);
+    rotate_180(&mut m);
+    assert_eq!(m, original);
+}
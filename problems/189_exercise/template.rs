@@ -30,8 +30,9 @@ fn main() {
 
 // 📝 TODO: Implement the required functions below
 
-// HINT: This is an advanced exercise - research the concepts first.
-// HINT: Consider the trade-offs between different approaches.
+// HINTS `main`
+// 1: This is an advanced exercise - research the concepts first.
+// 2: Consider the trade-offs between different approaches.
 
 #[cfg(test)]
 mod tests {
@@ -30,8 +30,9 @@ fn main() {
 
 // 📝 TODO: Implement the required functions below
 
-// HINT: Read the documentation for the mentioned concepts.
-// HINT: Start with a simple implementation and test it.
+// HINTS `main`
+// 1: Read the documentation for the mentioned concepts.
+// 2: Start with a simple implementation and test it.
 
 #[cfg(test)]
 mod tests {
@@ -30,8 +30,9 @@ fn main() {
 
 // 📝 TODO: Implement the required functions below
 
-// HINT: Break the problem down into smaller functions.
-// HINT: Consider using appropriate data structures from std::collections.
+// HINTS `main`
+// 1: Break the problem down into smaller functions.
+// 2: Consider using appropriate data structures from std::collections.
 
 #[cfg(test)]
 mod tests {
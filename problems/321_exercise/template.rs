@@ -0,0 +1,295 @@
+// Exercise 321: Binary Tree - AVL Tree
+// ====================================
+//
+// Learning Objective:
+// Keep a binary search tree balanced as it changes. The trees of exercises
+// 122-124 turn into a linked list when values arrive in sorted order, and
+// is_balanced can only report it. An AVL tree rotates after every insertion
+// and removal so that no node's subtrees differ in height by more than one,
+// which keeps height() - and every search - O(log n).
+//
+// This exercise covers:
+// - Storing each subtree's height in its node
+// - Left and right rotations on Box-owned nodes
+// - The four unbalanced shapes: left-left, left-right, right-right, right-left
+// - Removal by swapping in the in-order successor
+
+use std::cmp::Ordering;
+
+fn main() {
+    println!("=== Binary Tree - AVL Tree ===\n");
+
+    // Sorted input is the worst case for a plain BST: every node becomes
+    // the right child of the one before, and the tree is a list
+    let mut tree = AvlTree::new();
+    println!("Inserting 1..=1000 in order");
+    for value in 1..=1000 {
+        tree.insert(value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("(A plain BST would have height 1000)");
+
+    println!("\nRemoving the even numbers");
+    for value in (2..=1000).step_by(2) {
+        tree.remove(&value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Contains 500: {}", tree.contains(&500));
+    println!("Contains 501: {}", tree.contains(&501));
+
+    println!("\n✓ AVL tree completed successfully!");
+}
+
+type Link<T> = Option<Box<TreeNode<T>>>;
+
+/// AVL Tree Node
+///
+/// Like the nodes of exercises 122-124, plus the height of the subtree
+/// rooted here, kept up to date so balancing never has to walk the tree.
+#[derive(Debug)]
+struct TreeNode<T> {
+    value: T,
+    /// Nodes on the longest path from here down to a leaf; 1 for a leaf.
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Height of a subtree that may be empty.
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+impl<T: Ord> TreeNode<T> {
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recomputes the height from the children's.
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// Left height minus right height; AVL keeps it within -1..=1.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+
+    /// TODO: Lift the left child into this node's place
+    ///
+    /// ```text
+    ///       self           pivot
+    ///      /    \         /     \
+    ///   pivot    C  ->   A      self
+    ///   /   \                  /    \
+    ///  A     B                B      C
+    /// ```
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        // TODO: Take the left child out as the pivot
+        // TODO: B moves across to become self's left child
+        // TODO: self becomes the pivot's right child
+        // Update heights bottom-up: self first, then the pivot
+        todo!()
+    }
+
+    /// TODO: The mirror image of rotate_right
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        todo!()
+    }
+
+    /// TODO: Restore the balance of a node whose children are balanced and
+    /// differ in height by at most 2, and return the subtree's new root
+    fn rebalance(mut self: Box<Self>) -> Box<Self> {
+        self.update_height();
+
+        // TODO: Balance factor 2 (left-heavy):
+        //   If the left child leans right (left-right), rotate it left first
+        //   Then rotate self right
+        // TODO: Balance factor -2 (right-heavy): the mirror image
+        // Anything else is already balanced
+
+        self
+    }
+
+    /// TODO: Insert into the subtree rooted at link, returning its new root.
+    /// Duplicates are ignored.
+    fn insert(link: Link<T>, value: T) -> Box<Self> {
+        // TODO: An empty subtree becomes a new leaf
+        // TODO: Otherwise insert into the left or right child, as in 122,
+        //   then rebalance on the way back up
+        // HINTS `insert`
+        // 1: Take the child out with `node.left.take()`, insert into it and
+        //    put the result back: the child's root may change.
+        // 2: Every node on the path gets `rebalance()`d as the recursion
+        //    returns - that is what keeps its height up to date.
+        todo!()
+    }
+
+    /// TODO: Remove value from the subtree rooted at link, returning its
+    /// new root; set removed to true if value was there
+    fn remove(link: Link<T>, value: &T, removed: &mut bool) -> Link<T> {
+        // TODO: Search for value as in insert
+        // TODO: A node with at most one child is replaced by that child
+        // TODO: A node with two children takes the smallest value of its
+        //   right subtree (take_min), which is then removed from there
+        // TODO: Rebalance every node on the way back up
+        todo!()
+    }
+
+    /// TODO: Remove the smallest value, returning what is left and the value
+    fn take_min(mut self: Box<Self>) -> (Link<T>, T) {
+        // TODO: With no left child this node is the smallest; its right
+        //   subtree takes its place
+        // TODO: Otherwise take the minimum of the left child and rebalance
+        todo!()
+    }
+
+    fn size(&self) -> usize {
+        1 + self.left.as_ref().map_or(0, |n| n.size()) + self.right.as_ref().map_or(0, |n| n.size())
+    }
+
+    fn inorder<'a>(&'a self, result: &mut Vec<&'a T>) {
+        if let Some(left) = &self.left {
+            left.inorder(result);
+        }
+        result.push(&self.value);
+        if let Some(right) = &self.right {
+            right.inorder(result);
+        }
+    }
+}
+
+/// AVL Tree
+///
+/// A binary search tree that rotates after every insertion and removal so
+/// that no node's subtrees differ in height by more than one.
+#[derive(Debug)]
+struct AvlTree<T> {
+    root: Link<T>,
+}
+
+impl<T: Ord> AvlTree<T> {
+    fn new() -> Self {
+        AvlTree { root: None }
+    }
+
+    /// Insert a value; duplicates are ignored
+    fn insert(&mut self, value: T) {
+        self.root = Some(TreeNode::insert(self.root.take(), value));
+    }
+
+    /// Remove a value, returning whether it was in the tree
+    fn remove(&mut self, value: &T) -> bool {
+        let mut removed = false;
+        self.root = TreeNode::remove(self.root.take(), value, &mut removed);
+        removed
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    fn size(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.size())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Height of the tree; 0 when empty. Always O(log n).
+    fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// The values in sorted order
+    fn inorder(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        if let Some(root) = &self.root {
+            root.inorder(&mut result);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_of(values: &[i32]) -> AvlTree<i32> {
+        let mut tree = AvlTree::new();
+        for &v in values {
+            tree.insert(v);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree: AvlTree<i32> = AvlTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+        assert!(!tree.contains(&1));
+    }
+
+    #[test]
+    fn test_single_rotations() {
+        // Left-left and right-right: 2 ends up on top either way
+        for order in [[3, 2, 1], [1, 2, 3]] {
+            let tree = tree_of(&order);
+            assert_eq!(tree.root.as_ref().unwrap().value, 2);
+            assert_eq!(tree.height(), 2);
+        }
+    }
+
+    #[test]
+    fn test_double_rotations() {
+        // Left-right and right-left
+        for order in [[3, 1, 2], [1, 3, 2]] {
+            let tree = tree_of(&order);
+            assert_eq!(tree.root.as_ref().unwrap().value, 2);
+            assert_eq!(tree.inorder(), vec![&1, &2, &3]);
+        }
+    }
+
+    #[test]
+    fn test_sorted_insertions_stay_short() {
+        let values: Vec<i32> = (1..=127).collect();
+        let tree = tree_of(&values);
+        assert_eq!(tree.size(), 127);
+        assert_eq!(tree.height(), 7);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree = tree_of(&[50, 30, 70, 20, 40, 60, 80]);
+        assert!(tree.remove(&30));
+        assert!(!tree.remove(&30));
+        assert!(tree.remove(&50));
+        assert_eq!(tree.inorder(), vec![&20, &40, &60, &70, &80]);
+        assert!(!tree.contains(&50));
+        assert_eq!(tree.height(), 3);
+    }
+
+    #[test]
+    fn test_remove_rebalances() {
+        let mut tree = tree_of(&[2, 1, 3, 4]);
+        // Removing 1 leaves 2 with a right subtree of height 2
+        tree.remove(&1);
+        assert_eq!(tree.root.as_ref().unwrap().value, 3);
+        assert_eq!(tree.height(), 2);
+    }
+}
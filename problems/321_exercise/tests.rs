@@ -0,0 +1,160 @@
+//! Hidden tests for exercise 321: Binary Tree - AVL Tree.
+//!
+//! Every node's order, stored height and balance are checked after each
+//! insertion and removal, against a BTreeSet doing the same operations.
+//! Sorted insertions, which turn the trees of 122-124 into lists, must keep
+//! the height logarithmic.
+
+use super::*;
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+/// Checks the subtree and returns its height; every value must lie
+/// strictly between `lo` and `hi`.
+fn check<T: Ord + Debug>(link: &Option<Box<TreeNode<T>>>, lo: Option<&T>, hi: Option<&T>) -> usize {
+    let Some(node) = link else {
+        return 0;
+    };
+    assert!(lo.is_none_or(|lo| *lo < node.value), "{:?} is out of order", node.value);
+    assert!(hi.is_none_or(|hi| node.value < *hi), "{:?} is out of order", node.value);
+    let left = check(&node.left, lo, Some(&node.value));
+    let right = check(&node.right, Some(&node.value), hi);
+    assert!(left.abs_diff(right) <= 1, "{:?} is unbalanced: {left} against {right}", node.value);
+    assert_eq!(node.height, 1 + left.max(right), "stored height of {:?}", node.value);
+    node.height
+}
+
+fn check_tree<T: Ord + Debug>(tree: &AvlTree<T>, model: &BTreeSet<T>) {
+    check(&tree.root, None, None);
+    assert_eq!(tree.inorder(), model.iter().collect::<Vec<_>>());
+    assert_eq!(tree.size(), model.len());
+    assert_eq!(tree.is_empty(), model.is_empty());
+}
+
+/// The tallest an AVL tree of `len` nodes can be: 1.44 log2(len + 2).
+fn max_height(len: usize) -> usize {
+    (1.4405 * ((len + 2) as f64).log2() - 0.3277) as usize
+}
+
+/// Deterministic pseudo-random numbers below `bound`.
+fn lcg(seed: u64) -> impl FnMut(u64) -> u64 {
+    let mut x = seed;
+    move |bound| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    }
+}
+
+#[test]
+fn invariants_hold_after_every_operation() {
+    let mut next = lcg(321);
+    let mut tree = AvlTree::new();
+    let mut model = BTreeSet::new();
+    for step in 0..3_000 {
+        let value = next(500) as i32;
+        // Insert more often than remove early on, so the tree grows first
+        if next(10) < if step < 1_500 { 7 } else { 3 } {
+            tree.insert(value);
+            model.insert(value);
+        } else {
+            assert_eq!(tree.remove(&value), model.remove(&value), "remove {value}");
+        }
+        check_tree(&tree, &model);
+        assert!(tree.height() <= max_height(model.len()), "{} nodes, height {}", model.len(), tree.height());
+    }
+}
+
+#[test]
+fn every_rotation_case_on_removal() {
+    // Remove each value from every tree built from a permutation of 1..=7
+    let mut order: Vec<i32> = (1..=7).collect();
+    let mut permutations = 0;
+    loop {
+        for gone in 1..=7 {
+            let mut tree = AvlTree::new();
+            let mut model = BTreeSet::new();
+            for &v in &order {
+                tree.insert(v);
+                model.insert(v);
+                check_tree(&tree, &model);
+            }
+            assert!(tree.remove(&gone));
+            model.remove(&gone);
+            check_tree(&tree, &model);
+        }
+        permutations += 1;
+        // Next permutation in lexicographic order
+        let Some(i) = (0..order.len() - 1).rev().find(|&i| order[i] < order[i + 1]) else {
+            break;
+        };
+        let j = (i + 1..order.len()).rev().find(|&j| order[i] < order[j]).unwrap();
+        order.swap(i, j);
+        order[i + 1..].reverse();
+    }
+    assert_eq!(permutations, 5_040);
+}
+
+#[test]
+fn sorted_insertion_stress() {
+    // Each of these builds a 100 000 node list in exercise 122
+    let n = 100_000;
+    let mut ascending = AvlTree::new();
+    let mut descending = AvlTree::new();
+    for v in 0..n {
+        ascending.insert(v);
+        descending.insert(n - 1 - v);
+    }
+    for tree in [&ascending, &descending] {
+        assert_eq!(tree.size(), n as usize);
+        assert!(tree.height() <= max_height(n as usize), "height {}", tree.height());
+        check(&tree.root, None, None);
+        assert!(tree.contains(&0) && tree.contains(&(n - 1)) && !tree.contains(&n));
+    }
+
+    // A power of two less one, inserted in order, fills every level
+    let mut perfect = AvlTree::new();
+    for v in 0..1_023 {
+        perfect.insert(v);
+    }
+    assert_eq!(perfect.height(), 10);
+}
+
+#[test]
+fn removing_in_order_until_empty() {
+    let mut tree = AvlTree::new();
+    let mut model = BTreeSet::new();
+    for v in 0..600 {
+        tree.insert(v);
+        model.insert(v);
+    }
+    // From the smallest end, which keeps making the left side shorter
+    for v in 0..300 {
+        assert!(tree.remove(&v));
+        model.remove(&v);
+        check_tree(&tree, &model);
+    }
+    // Then from the largest end
+    for v in (300..600).rev() {
+        assert!(tree.remove(&v));
+        model.remove(&v);
+        check_tree(&tree, &model);
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+    assert!(!tree.remove(&0));
+}
+
+#[test]
+fn duplicates_and_owned_values() {
+    let mut tree = AvlTree::new();
+    let mut model = BTreeSet::new();
+    for word in "the quick brown fox jumps over the lazy dog the end".split(' ') {
+        tree.insert(word.to_string());
+        model.insert(word.to_string());
+        check_tree(&tree, &model);
+    }
+    assert_eq!(tree.size(), 9);
+    assert!(tree.remove(&"the".to_string()));
+    assert!(!tree.contains(&"the".to_string()));
+    assert!(tree.contains(&"fox".to_string()));
+}
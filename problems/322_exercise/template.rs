@@ -0,0 +1,358 @@
+// Exercise 322: Binary Tree - Red-Black Tree
+// ==========================================
+//
+// Learning Objective:
+// Keep a binary search tree balanced with colors instead of heights. Every
+// node is red or black; the root is black, no red node has a red child,
+// and every path from the root down to an empty subtree passes the same
+// number of black nodes. No path can then be more than twice as long as
+// another, so the height stays below 2 log2(n + 1).
+//
+// This exercise builds the left-leaning variant, where red nodes are only
+// ever left children. A red node is glued to its parent: the two stand for
+// one node of a 2-3 tree, and every rotation and color flip below is a
+// step of inserting into or removing from that 2-3 tree.
+//
+// This exercise covers:
+// - Rotations that carry the color along
+// - Color flips that split and merge 2-3 nodes
+// - Fixing the tree up on the way back out of the recursion
+// - Removal, which pushes a red node down ahead of it
+
+use std::cmp::Ordering;
+
+fn main() {
+    println!("=== Binary Tree - Red-Black Tree ===\n");
+
+    let mut tree = RedBlackTree::new();
+    println!("Inserting 1..=1000 in order");
+    for value in 1..=1000 {
+        tree.insert(value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Black height: {}", tree.black_height());
+    println!("(A plain BST would have height 1000)");
+
+    println!("\nRemoving the multiples of 3");
+    for value in (3..=1000).step_by(3) {
+        tree.remove(&value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Contains 300: {}", tree.contains(&300));
+    println!("Contains 301: {}", tree.contains(&301));
+
+    println!("\n✓ Red-black tree completed successfully!");
+}
+
+type Link<T> = Option<Box<TreeNode<T>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Black,
+}
+
+impl Color {
+    fn flip(self) -> Color {
+        match self {
+            Color::Red => Color::Black,
+            Color::Black => Color::Red,
+        }
+    }
+}
+
+/// Red-Black Tree Node
+///
+/// Like the nodes of exercises 122-124, plus the color of the link from
+/// the parent. A red node is glued to its parent: together they stand for
+/// one node of a 2-3 tree, whose leaves are all at the same depth.
+#[derive(Debug)]
+struct TreeNode<T> {
+    value: T,
+    color: Color,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Whether a subtree's root is red; empty subtrees count as black.
+fn is_red<T>(link: &Link<T>) -> bool {
+    link.as_ref().is_some_and(|node| node.color == Color::Red)
+}
+
+/// Whether the left child of a subtree's root is red.
+fn is_left_red<T>(link: &Link<T>) -> bool {
+    link.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+impl<T: Ord> TreeNode<T> {
+    /// New nodes are red: they join the 2-3 node above them.
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            color: Color::Red,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// TODO: Turn a red right child into a red left child
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        // TODO: Rotate as in an AVL tree (exercise 321)
+        // TODO: The pivot takes self's color, and self becomes red
+        todo!()
+    }
+
+    /// TODO: Turn a red left child into a red right child
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        todo!()
+    }
+
+    /// TODO: Flip this node's color and its children's
+    /// This splits a 4-node in two and passes the middle up, or the reverse.
+    fn flip_colors(&mut self) {
+        todo!()
+    }
+
+    /// TODO: Restore the left-leaning shape on the way back up
+    fn fix_up(mut self: Box<Self>) -> Box<Self> {
+        // TODO: A red right child and a black left child: rotate left
+        // TODO: A red left child with a red left child: rotate right
+        // TODO: Both children red: flip colors
+        // The order matters - each step can set up the next one
+        todo!()
+    }
+
+    /// TODO: Insert into the subtree rooted at link, returning its new root.
+    /// Duplicates are ignored.
+    fn insert(link: Link<T>, value: T) -> Box<Self> {
+        // TODO: An empty subtree becomes a new (red) leaf
+        // TODO: Otherwise insert into the left or right child, then fix_up
+        todo!()
+    }
+
+    /// TODO: Make the left child or one of its children red, so that a
+    /// removal on the left never takes out a lone black node
+    fn move_red_left(mut self: Box<Self>) -> Box<Self> {
+        // TODO: Flip colors, borrowing from the parent
+        // TODO: If the right child's left child is red, borrow from the
+        //   sibling instead: rotate the right child right, self left, and
+        //   flip colors back
+        todo!()
+    }
+
+    /// TODO: The mirror image of move_red_left
+    fn move_red_right(mut self: Box<Self>) -> Box<Self> {
+        // TODO: Flip colors
+        // TODO: If the left child's left child is red, rotate right and
+        //   flip colors back
+        todo!()
+    }
+
+    /// TODO: Remove the smallest value, returning what is left and the
+    /// value. The node or its left child must be red.
+    fn take_min(mut self: Box<Self>) -> (Link<T>, T) {
+        // TODO: In a left-leaning tree a node without a left child is a
+        //   leaf, and can simply go
+        // TODO: If neither the left child nor its left child is red,
+        //   move_red_left first
+        // TODO: Take the minimum of the left child, then fix_up
+        todo!()
+    }
+
+    /// TODO: Remove value, which must be in the subtree, returning its new
+    /// root. The node or its left child must be red.
+    fn remove(mut self: Box<Self>, value: &T) -> Link<T> {
+        // TODO: Going left: move_red_left if needed, as in take_min
+        // TODO: Otherwise:
+        //   Rotate a red left child to the right
+        //   If this is the node and it has no right child, it is a leaf
+        //   move_red_right if neither the right child nor its left child is red
+        //   If this is the node, take the minimum of the right subtree
+        //   into its place; otherwise remove from the right
+        // TODO: fix_up on the way back up
+        // HINTS `remove`
+        // 1: Every step down must land on a node that is red or has a red
+        //    left child - that is what the move_red_* calls guarantee, and
+        //    why a leaf can be dropped without changing any black height.
+        // 2: Compare value with self.value again after rotating: the
+        //    rotation changes which node self is.
+        todo!()
+    }
+
+    fn size(&self) -> usize {
+        1 + self.left.as_ref().map_or(0, |n| n.size())
+            + self.right.as_ref().map_or(0, |n| n.size())
+    }
+
+    fn height(&self) -> usize {
+        let left = self.left.as_ref().map_or(0, |n| n.height());
+        let right = self.right.as_ref().map_or(0, |n| n.height());
+        1 + left.max(right)
+    }
+
+    fn inorder<'a>(&'a self, result: &mut Vec<&'a T>) {
+        if let Some(left) = &self.left {
+            left.inorder(result);
+        }
+        result.push(&self.value);
+        if let Some(right) = &self.right {
+            right.inorder(result);
+        }
+    }
+}
+
+/// Red-Black Tree
+///
+/// A left-leaning red-black tree: a binary search tree in which no red
+/// node has a red child and every path from the root down to an empty
+/// subtree passes the same number of black nodes. So no path is more than
+/// twice as long as another, and the height stays below 2 log2(n + 1).
+#[derive(Debug)]
+struct RedBlackTree<T> {
+    root: Link<T>,
+}
+
+impl<T: Ord> RedBlackTree<T> {
+    fn new() -> Self {
+        RedBlackTree { root: None }
+    }
+
+    /// Insert a value; duplicates are ignored
+    fn insert(&mut self, value: T) {
+        let mut root = TreeNode::insert(self.root.take(), value);
+        root.color = Color::Black;
+        self.root = Some(root);
+    }
+
+    /// Remove a value, returning whether it was in the tree
+    fn remove(&mut self, value: &T) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+        let Some(mut root) = self.root.take() else {
+            return false;
+        };
+        // Removal needs the root or one of its children to be red
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = Color::Red;
+        }
+        self.root = root.remove(value);
+        if let Some(root) = &mut self.root {
+            root.color = Color::Black;
+        }
+        true
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    fn size(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.size())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Height of the tree; 0 when empty.
+    fn height(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.height())
+    }
+
+    /// Black nodes on the path down the left spine, the same on every path
+    fn black_height(&self) -> usize {
+        let mut count = 0;
+        let mut current = &self.root;
+        while let Some(node) = current {
+            count += usize::from(node.color == Color::Black);
+            current = &node.left;
+        }
+        count
+    }
+
+    /// The values in sorted order
+    fn inorder(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        if let Some(root) = &self.root {
+            root.inorder(&mut result);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_of(values: &[i32]) -> RedBlackTree<i32> {
+        let mut tree = RedBlackTree::new();
+        for &v in values {
+            tree.insert(v);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree: RedBlackTree<i32> = RedBlackTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.black_height(), 0);
+    }
+
+    #[test]
+    fn test_three_values_split_into_a_black_root() {
+        for order in [[1, 2, 3], [3, 2, 1], [2, 1, 3], [1, 3, 2]] {
+            let tree = tree_of(&order);
+            let root = tree.root.as_ref().unwrap();
+            assert_eq!((root.value, root.color), (2, Color::Black));
+            assert_eq!(tree.black_height(), 2);
+        }
+    }
+
+    #[test]
+    fn test_red_links_lean_left() {
+        let tree = tree_of(&[1, 2]);
+        let root = tree.root.as_ref().unwrap();
+        assert_eq!(root.value, 2);
+        assert!(is_red(&root.left));
+        assert!(root.right.is_none());
+    }
+
+    #[test]
+    fn test_sorted_insertions_stay_short() {
+        let values: Vec<i32> = (1..=1000).collect();
+        let tree = tree_of(&values);
+        assert_eq!(tree.size(), 1000);
+        assert!(tree.height() <= 19, "height {}", tree.height());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree = tree_of(&[50, 30, 70, 20, 40, 60, 80]);
+        assert!(tree.remove(&30));
+        assert!(!tree.remove(&30));
+        assert!(tree.remove(&50));
+        assert_eq!(tree.inorder(), vec![&20, &40, &60, &70, &80]);
+        assert!(!tree.contains(&50));
+        assert_eq!(tree.root.as_ref().unwrap().color, Color::Black);
+    }
+
+    #[test]
+    fn test_remove_everything() {
+        let mut tree = tree_of(&[5, 3, 8, 1, 4, 7, 9, 2, 6]);
+        for v in 1..=9 {
+            assert!(tree.remove(&v));
+        }
+        assert!(tree.is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+//! Hidden tests for exercise 322: Binary Tree - Red-Black Tree.
+//!
+//! The red-black rules are checked after each insertion and removal,
+//! against a BTreeSet doing the same operations: values in order, a black
+//! root, no red node with a red child, and the same number of black nodes
+//! on every path. Sorted insertions, which turn the trees of 122-124 into
+//! lists, must keep the height logarithmic.
+
+use super::*;
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+
+/// Checks the subtree and returns the black nodes on each of its paths;
+/// every value must lie strictly between `lo` and `hi`.
+fn check<T: Ord + Debug>(link: &Option<Box<TreeNode<T>>>, lo: Option<&T>, hi: Option<&T>) -> usize {
+    let Some(node) = link else {
+        return 0;
+    };
+    assert!(lo.is_none_or(|lo| *lo < node.value), "{:?} is out of order", node.value);
+    assert!(hi.is_none_or(|hi| node.value < *hi), "{:?} is out of order", node.value);
+    if node.color == Color::Red {
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            assert_eq!(child.color, Color::Black, "red {:?} has a red child {:?}", node.value, child.value);
+        }
+    }
+    let left = check(&node.left, lo, Some(&node.value));
+    let right = check(&node.right, Some(&node.value), hi);
+    assert_eq!(left, right, "paths below {:?} pass different numbers of black nodes", node.value);
+    left + usize::from(node.color == Color::Black)
+}
+
+fn check_tree<T: Ord + Debug>(tree: &RedBlackTree<T>, model: &BTreeSet<T>) {
+    if let Some(root) = &tree.root {
+        assert_eq!(root.color, Color::Black, "the root is red");
+    }
+    let black_height = check(&tree.root, None, None);
+    assert_eq!(tree.black_height(), black_height);
+    assert_eq!(tree.inorder(), model.iter().collect::<Vec<_>>());
+    assert_eq!(tree.size(), model.len());
+    assert_eq!(tree.is_empty(), model.is_empty());
+    assert!(tree.height() <= max_height(model.len()), "{} nodes, height {}", model.len(), tree.height());
+}
+
+/// The tallest a red-black tree of `len` nodes can be: 2 log2(len + 1).
+fn max_height(len: usize) -> usize {
+    (2.0 * ((len + 1) as f64).log2()) as usize
+}
+
+/// Deterministic pseudo-random numbers below `bound`.
+fn lcg(seed: u64) -> impl FnMut(u64) -> u64 {
+    let mut x = seed;
+    move |bound| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (x >> 33) % bound
+    }
+}
+
+#[test]
+fn invariants_hold_after_every_operation() {
+    let mut next = lcg(322);
+    let mut tree = RedBlackTree::new();
+    let mut model = BTreeSet::new();
+    for step in 0..3_000 {
+        let value = next(500) as i32;
+        // Insert more often than remove early on, so the tree grows first
+        if next(10) < if step < 1_500 { 7 } else { 3 } {
+            tree.insert(value);
+            model.insert(value);
+        } else {
+            assert_eq!(tree.remove(&value), model.remove(&value), "remove {value}");
+        }
+        check_tree(&tree, &model);
+    }
+}
+
+#[test]
+fn removing_every_value_of_small_trees() {
+    // Every shape the first few sizes can take, and every node removed
+    for len in 1..=12 {
+        for gone in 0..len {
+            let mut tree = RedBlackTree::new();
+            let mut model = BTreeSet::new();
+            for v in 0..len {
+                tree.insert(v);
+                model.insert(v);
+            }
+            assert!(tree.remove(&gone));
+            model.remove(&gone);
+            check_tree(&tree, &model);
+            assert!(!tree.remove(&gone));
+            assert!(!tree.remove(&len));
+            check_tree(&tree, &model);
+        }
+    }
+}
+
+#[test]
+fn sorted_insertion_stress() {
+    // Each of these builds a 100 000 node list in exercise 122
+    let n = 100_000;
+    let mut ascending = RedBlackTree::new();
+    let mut descending = RedBlackTree::new();
+    for v in 0..n {
+        ascending.insert(v);
+        descending.insert(n - 1 - v);
+    }
+    for tree in [&ascending, &descending] {
+        assert_eq!(tree.size(), n as usize);
+        assert!(tree.height() <= max_height(n as usize), "height {}", tree.height());
+        assert_eq!(tree.black_height(), check(&tree.root, None, None));
+        assert!(tree.contains(&0) && tree.contains(&(n - 1)) && !tree.contains(&n));
+    }
+}
+
+#[test]
+fn removing_in_order_until_empty() {
+    let mut tree = RedBlackTree::new();
+    let mut model = BTreeSet::new();
+    for v in 0..600 {
+        tree.insert(v);
+        model.insert(v);
+    }
+    // Alternately from both ends, then the rest from the middle out
+    for i in 0..150 {
+        for v in [i, 599 - i] {
+            assert!(tree.remove(&v));
+            model.remove(&v);
+            check_tree(&tree, &model);
+        }
+    }
+    for i in 0..150 {
+        for v in [299 - i, 300 + i] {
+            assert!(tree.remove(&v));
+            model.remove(&v);
+            check_tree(&tree, &model);
+        }
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.black_height(), 0);
+}
+
+#[test]
+fn duplicates_and_owned_values() {
+    let mut tree = RedBlackTree::new();
+    let mut model = BTreeSet::new();
+    for word in "the quick brown fox jumps over the lazy dog the end".split(' ') {
+        tree.insert(word.to_string());
+        model.insert(word.to_string());
+        check_tree(&tree, &model);
+    }
+    assert_eq!(tree.size(), 9);
+    assert!(tree.remove(&"the".to_string()));
+    model.remove("the");
+    check_tree(&tree, &model);
+    assert!(tree.contains(&"fox".to_string()));
+}
@@ -0,0 +1,857 @@
+// Exercise 323: Linked List - Doubly Linked
+// =========================================
+//
+// Learning Objective:
+// Build the list that Rust's ownership rules make hard. The singly linked
+// list of exercise 119 has one owner per node: the node before it. In a
+// doubly linked list every node is pointed to from both sides, so it has
+// no single owner, and Box alone cannot express it.
+//
+// The list is built twice, with the same operations:
+// - rc: each node owns the next through an Rc and points back through a
+//   Weak, with RefCell for mutation. Safe, but borrows are checked at run
+//   time and cannot escape a node.
+// - raw: the list owns every node and the nodes point at each other with
+//   NonNull raw pointers, as std::collections::LinkedList does. Fast and
+//   ergonomic, but every pointer dereference is an unsafe promise.
+//
+// This exercise covers:
+// - Rc, Weak and RefCell, and why a strong back pointer would leak
+// - NonNull, Box::leak and Box::from_raw
+// - A cursor that walks both ways and edits the list where it stands
+// - O(1) append, split_off, and a double-ended iterator
+// - Checking unsafe code with Miri: `cargo +nightly miri test`
+
+fn main() {
+    println!("=== Doubly Linked List ===\n");
+
+    println!("--- Rc<RefCell<Node>> and Weak ---");
+    let mut list: rc::DoublyLinkedList<i32> = (1..=5).collect();
+    list.push_front(0);
+    println!("List: {:?}", list);
+    println!("Backwards: {:?}", list.iter().rev().collect::<Vec<_>>());
+    let tail = list.split_off(3);
+    println!("split_off(3): {:?} and {:?}", list, tail);
+
+    println!("\n--- NonNull raw pointers ---");
+    let mut list: raw::DoublyLinkedList<i32> = (1..=5).collect();
+    let mut cursor = list.cursor_front_mut();
+    while let Some(value) = cursor.current() {
+        if *value % 2 == 0 {
+            cursor.remove_current();
+        } else {
+            *value *= 10;
+            cursor.move_next();
+        }
+    }
+    println!("Odd values, times ten: {:?}", list);
+    let mut more: raw::DoublyLinkedList<i32> = (6..=8).collect();
+    list.append(&mut more);
+    println!("After append: {:?} (other list: {:?})", list, more);
+    println!("Back to front: {:?}", list.iter().rev().collect::<Vec<_>>());
+
+    println!("\n✓ Doubly linked list completed successfully!");
+}
+
+mod rc {
+    //! The list in safe Rust: each node owns the next one through an `Rc` and
+    //! points back at the previous one through a `Weak`, so the two directions
+    //! never form a cycle of strong references that could not be freed.
+    //!
+    //! `RefCell` moves the borrow checks to run time. The price is that a
+    //! borrow of a value cannot outlive the guard that `RefCell::borrow`
+    //! returns, so `front` hands out a `Ref` and `iter` has to clone.
+
+    #![forbid(unsafe_code)]
+
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::rc::{Rc, Weak};
+
+    type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+    struct Node<T> {
+        value: T,
+        next: Link<T>,
+        prev: Option<Weak<RefCell<Node<T>>>>,
+    }
+
+    impl<T> Node<T> {
+        fn new(value: T) -> Rc<RefCell<Node<T>>> {
+            Rc::new(RefCell::new(Node {
+                value,
+                next: None,
+                prev: None,
+            }))
+        }
+    }
+
+    /// Takes the value out of a node nothing else points to any more.
+    fn into_value<T>(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(cell) => cell.into_inner().value,
+            Err(_) => panic!("an unlinked node is still shared"),
+        }
+    }
+
+    pub struct DoublyLinkedList<T> {
+        head: Link<T>,
+        tail: Link<T>,
+        len: usize,
+    }
+
+    impl<T> DoublyLinkedList<T> {
+        pub fn new() -> Self {
+            DoublyLinkedList {
+                head: None,
+                tail: None,
+                len: 0,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// TODO: Add a value at the front
+        pub fn push_front(&mut self, value: T) {
+            // TODO: Create the node with Node::new
+            // TODO: If there was a head, point its prev at the new node
+            //   (Rc::downgrade) and make it the new node's next
+            // TODO: If the list was empty, the new node is also the tail
+            //   (Rc::clone - the list holds two strong references to it)
+            todo!()
+        }
+
+        /// TODO: Add a value at the back; the mirror image of push_front
+        pub fn push_back(&mut self, value: T) {
+            todo!()
+        }
+
+        /// TODO: Remove and return the front value
+        pub fn pop_front(&mut self) -> Option<T> {
+            // TODO: Take the head; its next becomes the head, with no prev
+            // TODO: If there was no next, the list is now empty: clear tail
+            // TODO: into_value the old head - the list must hold no other
+            //   strong reference to it by now
+            todo!()
+        }
+
+        /// TODO: Remove and return the back value
+        pub fn pop_back(&mut self) -> Option<T> {
+            // TODO: Take the tail; its prev becomes the tail, with no next
+            // HINTS `pop_back`
+            // 1: `prev` is a `Weak`: `upgrade()` it to get an `Rc` you can
+            //    borrow, or None if the node is gone.
+            // 2: Set the new tail's `next` to None before calling into_value,
+            //    or the old tail is still shared and try_unwrap fails.
+            todo!()
+        }
+
+        pub fn front(&self) -> Option<Ref<'_, T>> {
+            self.head
+                .as_ref()
+                .map(|node| Ref::map(node.borrow(), |node| &node.value))
+        }
+
+        pub fn back(&self) -> Option<Ref<'_, T>> {
+            self.tail
+                .as_ref()
+                .map(|node| Ref::map(node.borrow(), |node| &node.value))
+        }
+
+        pub fn front_mut(&mut self) -> Option<RefMut<'_, T>> {
+            self.head
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        pub fn back_mut(&mut self) -> Option<RefMut<'_, T>> {
+            self.tail
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        /// TODO: Move every element of `other` to the end of this list, in
+        /// O(1), leaving `other` empty
+        pub fn append(&mut self, other: &mut Self) {
+            // TODO: Nothing to do if other is empty
+            // TODO: Link this list's tail and other's head both ways
+            // TODO: Take other's tail and len
+            todo!()
+        }
+
+        /// TODO: Split the list in two at `at`: this list keeps the first
+        /// `at` elements and the rest are returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `at > len`.
+        pub fn split_off(&mut self, at: usize) -> Self {
+            // TODO: at == 0 hands over the whole list (std::mem::take),
+            //   at == len an empty one
+            // TODO: Otherwise walk to the last node that stays and cut
+            //   both links between it and the next one
+            todo!()
+        }
+
+        pub fn clear(&mut self) {
+            while self.pop_front().is_some() {}
+        }
+
+        /// A cursor on the first element, or on the ghost if the list is empty.
+        pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.head.clone(),
+                index: 0,
+                list: self,
+            }
+        }
+
+        /// A cursor on the last element, or on the ghost if the list is empty.
+        pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.tail.clone(),
+                index: self.len.saturating_sub(1),
+                list: self,
+            }
+        }
+    }
+
+    impl<T: Clone> DoublyLinkedList<T> {
+        /// Clones of the values, front to back. A `Ref` cannot outlive the
+        /// `RefCell` guard of the node it came from, so they cannot be borrowed.
+        pub fn iter(&self) -> Iter<'_, T> {
+            Iter {
+                front: self.head.clone(),
+                back: self.tail.clone(),
+                len: self.len,
+                list: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Default for DoublyLinkedList<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for DoublyLinkedList<T> {
+        /// Frees the nodes one at a time; dropping the head would free the
+        /// rest recursively and overflow the stack on a long list.
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T> FromIterator<T> for DoublyLinkedList<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            let mut list = Self::new();
+            list.extend(iter);
+            list
+        }
+    }
+
+    impl<T> Extend<T> for DoublyLinkedList<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for value in iter {
+                self.push_back(value);
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut list = f.debug_list();
+            let mut current = self.head.clone();
+            while let Some(node) = current {
+                list.entry(&node.borrow().value);
+                current = node.borrow().next.clone();
+            }
+            list.finish()
+        }
+    }
+
+    /// Iterator over clones of the values, from either end.
+    pub struct Iter<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end.
+        len: usize,
+        /// The nodes it holds on to must not be unlinked under it.
+        list: PhantomData<&'a DoublyLinkedList<T>>,
+    }
+
+    impl<T: Clone> Iterator for Iter<'_, T> {
+        type Item = T;
+
+        /// TODO: Yield a clone of the front value and step forwards
+        fn next(&mut self) -> Option<T> {
+            // TODO: Stop once len reaches 0 - front and back have met
+            todo!()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T: Clone> DoubleEndedIterator for Iter<'_, T> {
+        /// TODO: Yield a clone of the back value and step backwards
+        fn next_back(&mut self) -> Option<T> {
+            todo!()
+        }
+    }
+
+    impl<T: Clone> ExactSizeIterator for Iter<'_, T> {}
+
+    /// Iterator that takes the values out of the list, from either end.
+    pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.0.pop_front()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len, Some(self.0.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            self.0.pop_back()
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> IntoIterator for DoublyLinkedList<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            IntoIter(self)
+        }
+    }
+
+    /// A cursor that can move both ways and edit the list where it stands.
+    ///
+    /// Between the last element and the first sits a "ghost" position that is
+    /// not an element: moving past either end lands there, and moving on from
+    /// it wraps around to the other end.
+    pub struct CursorMut<'a, T> {
+        list: &'a mut DoublyLinkedList<T>,
+        /// None on the ghost.
+        current: Link<T>,
+        /// Position of `current`; meaningless on the ghost.
+        index: usize,
+    }
+
+    impl<T> CursorMut<'_, T> {
+        /// The position of the current element, or None on the ghost.
+        pub fn index(&self) -> Option<usize> {
+            self.current.as_ref().map(|_| self.index)
+        }
+
+        pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+            self.current
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        /// TODO: Move to the next element; from the ghost, to the front
+        pub fn move_next(&mut self) {
+            todo!()
+        }
+
+        /// TODO: Move to the previous element; from the ghost, to the back
+        pub fn move_prev(&mut self) {
+            todo!()
+        }
+
+        /// TODO: Insert after the current element; on the ghost, at the front
+        pub fn insert_after(&mut self, value: T) {
+            // TODO: At either end of the list, push_front or push_back
+            //   already do the work
+            // TODO: Otherwise link the new node between current and next
+            todo!()
+        }
+
+        /// TODO: Insert before the current element; on the ghost, at the back
+        pub fn insert_before(&mut self, value: T) {
+            // TODO: As insert_after; the current element's index goes up
+            todo!()
+        }
+
+        /// TODO: Remove the current element and move on to the next one; do
+        /// nothing on the ghost
+        pub fn remove_current(&mut self) -> Option<T> {
+            // TODO: Link the neighbours to each other, or update head and
+            //   tail where there is none
+            todo!()
+        }
+    }
+}
+
+mod raw {
+    //! The list with raw pointers, as the standard library writes it: the list
+    //! owns every node, allocated with `Box` and turned into a `NonNull`, and
+    //! the nodes point at each other without owning anything.
+    //!
+    //! The compiler no longer checks that a pointer is valid or unaliased, so
+    //! each `unsafe` block says why it is. Run the tests under Miri,
+    //! `cargo +nightly miri test`, to have those claims checked: it reports
+    //! use after free, double frees, leaks and aliasing violations.
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+
+    type Link<T> = Option<NonNull<Node<T>>>;
+
+    struct Node<T> {
+        value: T,
+        next: Link<T>,
+        prev: Link<T>,
+    }
+
+    pub struct DoublyLinkedList<T> {
+        head: Link<T>,
+        tail: Link<T>,
+        len: usize,
+        /// The list owns its nodes and their values, as a `Box<Node<T>>`
+        /// would: this tells the drop checker so.
+        marker: PhantomData<Box<Node<T>>>,
+    }
+
+    // SAFETY: the list owns its values and hands out `&T` only through `&self`
+    // and `&mut T` only through `&mut self`, like a `Vec<T>`.
+    unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+    // SAFETY: as above; `&DoublyLinkedList<T>` only gives out `&T`.
+    unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+    impl<T> DoublyLinkedList<T> {
+        pub fn new() -> Self {
+            DoublyLinkedList {
+                head: None,
+                tail: None,
+                len: 0,
+                marker: PhantomData,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Allocates a node that the list has to free again.
+        fn allocate(value: T, prev: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+            NonNull::from(Box::leak(Box::new(Node { value, next, prev })))
+        }
+
+        /// TODO: Add a value at the front
+        pub fn push_front(&mut self, value: T) {
+            // TODO: allocate the node with the old head as its next
+            // TODO: Point the old head's prev at it, or set the tail
+            //   Writing through a NonNull: unsafe { (*old.as_ptr()).prev = ... }
+            //   with a // SAFETY: comment saying why old is a live node
+            todo!()
+        }
+
+        /// TODO: Add a value at the back; the mirror image of push_front
+        pub fn push_back(&mut self, value: T) {
+            todo!()
+        }
+
+        /// TODO: Remove and return the front value
+        pub fn pop_front(&mut self) -> Option<T> {
+            // TODO: Box::from_raw the head to take ownership back - the Box
+            //   frees the node when it goes out of scope
+            // TODO: Its next becomes the head, with no prev
+            todo!()
+        }
+
+        /// TODO: Remove and return the back value
+        pub fn pop_back(&mut self) -> Option<T> {
+            todo!()
+        }
+
+        pub fn front(&self) -> Option<&T> {
+            // SAFETY: the node lives as long as the list, which is borrowed
+            self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+        }
+
+        pub fn back(&self) -> Option<&T> {
+            // SAFETY: as in front
+            self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+        }
+
+        pub fn front_mut(&mut self) -> Option<&mut T> {
+            // SAFETY: the list is borrowed mutably, so this is the only reference
+            self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        pub fn back_mut(&mut self) -> Option<&mut T> {
+            // SAFETY: as in front_mut
+            self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        /// TODO: Move every element of `other` to the end of this list, in
+        /// O(1), leaving `other` empty
+        pub fn append(&mut self, other: &mut Self) {
+            // TODO: As in the rc list, but the links are plain pointers
+            todo!()
+        }
+
+        /// TODO: Split the list in two at `at`: this list keeps the first
+        /// `at` elements and the rest are returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `at > len`.
+        pub fn split_off(&mut self, at: usize) -> Self {
+            // TODO: As in the rc list
+            todo!()
+        }
+
+        pub fn clear(&mut self) {
+            while self.pop_front().is_some() {}
+        }
+
+        pub fn iter(&self) -> Iter<'_, T> {
+            Iter {
+                front: self.head,
+                back: self.tail,
+                len: self.len,
+                marker: PhantomData,
+            }
+        }
+
+        pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+            IterMut {
+                front: self.head,
+                back: self.tail,
+                len: self.len,
+                marker: PhantomData,
+            }
+        }
+
+        /// A cursor on the first element, or on the ghost if the list is empty.
+        pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.head,
+                index: 0,
+                list: self,
+            }
+        }
+
+        /// A cursor on the last element, or on the ghost if the list is empty.
+        pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.tail,
+                index: self.len.saturating_sub(1),
+                list: self,
+            }
+        }
+    }
+
+    impl<T> Default for DoublyLinkedList<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for DoublyLinkedList<T> {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T> FromIterator<T> for DoublyLinkedList<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            let mut list = Self::new();
+            list.extend(iter);
+            list
+        }
+    }
+
+    impl<T> Extend<T> for DoublyLinkedList<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for value in iter {
+                self.push_back(value);
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    /// Iterator over references to the values, from either end.
+    pub struct Iter<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end.
+        len: usize,
+        marker: PhantomData<&'a T>,
+    }
+
+    impl<'a, T> Iterator for Iter<'a, T> {
+        type Item = &'a T;
+
+        /// TODO: Yield the front value and step forwards
+        fn next(&mut self) -> Option<&'a T> {
+            // TODO: Stop once len reaches 0 - front and back have met
+            // TODO: The reference may live for 'a: the list is borrowed
+            //   that long, so its nodes are not freed
+            todo!()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for Iter<'_, T> {
+        /// TODO: Yield the back value and step backwards
+        fn next_back(&mut self) -> Option<Self::Item> {
+            todo!()
+        }
+    }
+
+    impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+    /// Iterator over mutable references to the values, from either end.
+    pub struct IterMut<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end; the two ends never meet,
+        /// so no value is handed out twice.
+        len: usize,
+        marker: PhantomData<&'a mut T>,
+    }
+
+    impl<'a, T> Iterator for IterMut<'a, T> {
+        type Item = &'a mut T;
+
+        /// TODO: As Iter::next, with &mut
+        fn next(&mut self) -> Option<&'a mut T> {
+            todo!()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IterMut<'_, T> {
+        /// TODO: As Iter::next_back, with &mut
+        fn next_back(&mut self) -> Option<Self::Item> {
+            todo!()
+        }
+    }
+
+    impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+    /// Iterator that takes the values out of the list, from either end.
+    pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.0.pop_front()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len, Some(self.0.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            self.0.pop_back()
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> IntoIterator for DoublyLinkedList<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            IntoIter(self)
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+
+        fn into_iter(self) -> Iter<'a, T> {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+        type Item = &'a mut T;
+        type IntoIter = IterMut<'a, T>;
+
+        fn into_iter(self) -> IterMut<'a, T> {
+            self.iter_mut()
+        }
+    }
+
+    /// A cursor that can move both ways and edit the list where it stands.
+    ///
+    /// Between the last element and the first sits a "ghost" position that is
+    /// not an element: moving past either end lands there, and moving on from
+    /// it wraps around to the other end.
+    pub struct CursorMut<'a, T> {
+        list: &'a mut DoublyLinkedList<T>,
+        /// None on the ghost.
+        current: Link<T>,
+        /// Position of `current`; meaningless on the ghost.
+        index: usize,
+    }
+
+    impl<T> CursorMut<'_, T> {
+        /// The position of the current element, or None on the ghost.
+        pub fn index(&self) -> Option<usize> {
+            self.current.map(|_| self.index)
+        }
+
+        pub fn current(&mut self) -> Option<&mut T> {
+            // SAFETY: the cursor borrows the list mutably, and the reference
+            // borrows the cursor
+            self.current
+                .map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        /// TODO: Move to the next element; from the ghost, to the front
+        pub fn move_next(&mut self) {
+            todo!()
+        }
+
+        /// TODO: Move to the previous element; from the ghost, to the back
+        pub fn move_prev(&mut self) {
+            todo!()
+        }
+
+        /// TODO: Insert after the current element; on the ghost, at the front
+        pub fn insert_after(&mut self, value: T) {
+            todo!()
+        }
+
+        /// TODO: Insert before the current element; on the ghost, at the back
+        pub fn insert_before(&mut self, value: T) {
+            todo!()
+        }
+
+        /// TODO: Remove the current element and move on to the next one; do
+        /// nothing on the ghost
+        pub fn remove_current(&mut self) -> Option<T> {
+            // TODO: Take the node back with Box::from_raw and relink its
+            //   neighbours, or update head and tail where there is none
+            // HINTS `remove_current`
+            // 1: Copy `prev` and `next` out of the node before touching the
+            //    neighbours; a NonNull is Copy.
+            // 2: Free the node only once: after Box::from_raw, the Box owns
+            //    it, and nothing may dereference the old pointer again.
+            todo!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rc_push_and_pop_at_both_ends() {
+        let mut list = rc::DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_rc_iterates_both_ways() {
+        let list: rc::DoublyLinkedList<i32> = (1..=4).collect();
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_rc_split_off_and_append() {
+        let mut list: rc::DoublyLinkedList<i32> = (1..=5).collect();
+        let mut rest = list.split_off(2);
+        assert_eq!(format!("{:?} {:?}", list, rest), "[1, 2] [3, 4, 5]");
+        rest.append(&mut list);
+        assert_eq!(format!("{:?} {:?}", rest, list), "[3, 4, 5, 1, 2] []");
+        assert_eq!(rest.back().as_deref(), Some(&2));
+    }
+
+    #[test]
+    fn test_raw_push_and_pop_at_both_ends() {
+        let mut list = raw::DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_raw_iterates_both_ways() {
+        let mut list: raw::DoublyLinkedList<i32> = (1..=4).collect();
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![40, 30, 20, 10]);
+    }
+
+    #[test]
+    fn test_raw_cursor_edits() {
+        let mut list: raw::DoublyLinkedList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_before(5);
+        cursor.insert_after(7);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(format!("{:?}", list), "[1, 5, 3, 7]");
+    }
+}
@@ -0,0 +1,395 @@
+//! Hidden tests for exercise 323: Linked List - Doubly Linked.
+//!
+//! Both lists run the same tests: random operations and cursor walks
+//! against a VecDeque and a Vec, split_off and append at every position,
+//! double-ended iteration meeting in the middle, and values that record
+//! their own drops, so a leak or a double free fails even outside Miri.
+//! The lists are short under Miri (`cargo +nightly miri test`), which
+//! checks every pointer the raw list follows.
+
+use super::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Operations per random test; Miri runs about a thousand times slower.
+const STEPS: usize = if cfg!(miri) { 300 } else { 5_000 };
+
+/// Deterministic pseudo-random numbers below `bound`.
+fn lcg(seed: u64) -> impl FnMut(usize) -> usize {
+    let mut x = seed;
+    move |bound| {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((x >> 33) % bound as u64) as usize
+    }
+}
+
+/// A value that records its id in a shared log when dropped.
+#[derive(Debug)]
+struct Tracked {
+    id: usize,
+    drops: Rc<RefCell<Vec<usize>>>,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.borrow_mut().push(self.id);
+    }
+}
+
+macro_rules! list_tests {
+    ($name:ident, $list:ident) => {
+        mod $name {
+            use super::*;
+            use crate::$list::DoublyLinkedList;
+
+            fn contents(list: &DoublyLinkedList<i32>) -> (Vec<i32>, Vec<i32>) {
+                let forward: Vec<i32> = list.iter().map(|v| v.clone()).collect();
+                let backward: Vec<i32> = list.iter().rev().map(|v| v.clone()).collect();
+                (forward, backward)
+            }
+
+            /// Compares both directions, so every prev link is checked too.
+            fn assert_matches(list: &DoublyLinkedList<i32>, model: &VecDeque<i32>) {
+                let (forward, backward) = contents(list);
+                let expected: Vec<i32> = model.iter().copied().collect();
+                assert_eq!(forward, expected, "front to back");
+                assert_eq!(backward, expected.iter().rev().copied().collect::<Vec<_>>(), "back to front");
+                assert_eq!(list.len(), model.len());
+                assert_eq!(list.is_empty(), model.is_empty());
+                assert_eq!(list.front().map(|v| *v), model.front().copied());
+                assert_eq!(list.back().map(|v| *v), model.back().copied());
+            }
+
+            #[test]
+            fn matches_a_vecdeque_under_random_operations() {
+                let mut next = lcg(323);
+                let mut list = DoublyLinkedList::new();
+                let mut model = VecDeque::new();
+                for step in 0..STEPS {
+                    let value = step as i32;
+                    match next(9) {
+                        0 | 1 => {
+                            list.push_front(value);
+                            model.push_front(value);
+                        }
+                        2 | 3 => {
+                            list.push_back(value);
+                            model.push_back(value);
+                        }
+                        4 => assert_eq!(list.pop_front(), model.pop_front()),
+                        5 => assert_eq!(list.pop_back(), model.pop_back()),
+                        6 => {
+                            if let Some(mut front) = list.front_mut() {
+                                *front += 1;
+                            }
+                            if let Some(front) = model.front_mut() {
+                                *front += 1;
+                            }
+                            if let Some(mut back) = list.back_mut() {
+                                *back -= 1;
+                            }
+                            if let Some(back) = model.back_mut() {
+                                *back -= 1;
+                            }
+                        }
+                        7 => {
+                            // Split somewhere and put it back together
+                            let at = next(list.len() + 1);
+                            let mut rest = list.split_off(at);
+                            let mut model_rest = model.split_off(at);
+                            assert_matches(&list, &model);
+                            assert_matches(&rest, &model_rest);
+                            list.append(&mut rest);
+                            model.append(&mut model_rest);
+                            assert!(rest.is_empty());
+                            assert_eq!(rest.pop_front(), None);
+                        }
+                        _ => {
+                            let mut rest = list.split_off(next(list.len() + 1));
+                            drop(model.split_off(list.len()));
+                            // The split-off part on its own, then dropped
+                            let len = rest.len();
+                            rest.push_back(-1);
+                            assert_eq!(rest.len(), len + 1);
+                        }
+                    }
+                    assert_matches(&list, &model);
+                }
+            }
+
+            #[test]
+            fn cursor_walks_and_edits_like_a_model() {
+                let mut next = lcg(7);
+                let mut list: DoublyLinkedList<i32> = (0..8).collect();
+                let mut model: Vec<i32> = (0..8).collect();
+                let mut fresh = 100;
+                for _ in 0..STEPS / 20 {
+                    // A new cursor from one end; the model tracks its position
+                    let from_back = next(2) == 0;
+                    let mut cursor = if from_back { list.cursor_back_mut() } else { list.cursor_front_mut() };
+                    let mut at = if model.is_empty() {
+                        None
+                    } else if from_back {
+                        Some(model.len() - 1)
+                    } else {
+                        Some(0)
+                    };
+                    for _ in 0..20 {
+                        match next(7) {
+                            0 => {
+                                cursor.move_next();
+                                at = match at {
+                                    Some(i) if i + 1 < model.len() => Some(i + 1),
+                                    Some(_) => None,
+                                    None => (!model.is_empty()).then_some(0),
+                                };
+                            }
+                            1 => {
+                                cursor.move_prev();
+                                at = match at {
+                                    Some(0) => None,
+                                    Some(i) => Some(i - 1),
+                                    None => model.len().checked_sub(1),
+                                };
+                            }
+                            2 => {
+                                cursor.insert_after(fresh);
+                                model.insert(at.map_or(0, |i| i + 1), fresh);
+                                fresh += 1;
+                            }
+                            3 => {
+                                cursor.insert_before(fresh);
+                                match at {
+                                    Some(i) => {
+                                        model.insert(i, fresh);
+                                        at = Some(i + 1);
+                                    }
+                                    None => model.push(fresh),
+                                }
+                                fresh += 1;
+                            }
+                            4 => {
+                                let removed = at.map(|i| model.remove(i));
+                                assert_eq!(cursor.remove_current(), removed);
+                                at = at.filter(|&i| i < model.len());
+                            }
+                            5 => {
+                                if let Some(mut value) = cursor.current() {
+                                    *value *= -1;
+                                }
+                                if let Some(i) = at {
+                                    model[i] *= -1;
+                                }
+                            }
+                            _ => {}
+                        }
+                        assert_eq!(cursor.index(), at);
+                        assert_eq!(cursor.current().map(|v| *v), at.map(|i| model[i]));
+                    }
+                    drop(cursor);
+                    assert_matches(&list, &model.iter().copied().collect());
+                }
+            }
+
+            #[test]
+            fn cursor_on_an_empty_list() {
+                let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+                let mut cursor = list.cursor_front_mut();
+                assert_eq!(cursor.index(), None);
+                assert!(cursor.current().is_none());
+                assert_eq!(cursor.remove_current(), None);
+                cursor.move_next();
+                cursor.move_prev();
+                assert_eq!(cursor.index(), None);
+                // On the ghost, after means the front and before the back
+                cursor.insert_after(2);
+                cursor.insert_before(3);
+                cursor.insert_after(1);
+                drop(cursor);
+                assert_matches(&list, &VecDeque::from([1, 2, 3]));
+            }
+
+            #[test]
+            fn split_off_and_append_at_every_position() {
+                for len in 0..8 {
+                    for at in 0..=len {
+                        let mut list: DoublyLinkedList<i32> = (0..len).collect();
+                        let mut model: VecDeque<i32> = (0..len).collect();
+                        let mut rest = list.split_off(at as usize);
+                        let mut model_rest = model.split_off(at as usize);
+                        assert_matches(&list, &model);
+                        assert_matches(&rest, &model_rest);
+
+                        // Both halves must still take new elements at both ends
+                        list.push_back(-1);
+                        model.push_back(-1);
+                        rest.push_front(-2);
+                        model_rest.push_front(-2);
+                        list.append(&mut rest);
+                        model.append(&mut model_rest);
+                        assert_matches(&list, &model);
+                        assert_matches(&rest, &VecDeque::new());
+                    }
+                }
+                let mut empty = DoublyLinkedList::new();
+                let mut list: DoublyLinkedList<i32> = (0..3).collect();
+                empty.append(&mut list);
+                assert_matches(&empty, &VecDeque::from([0, 1, 2]));
+                assert_matches(&list, &VecDeque::new());
+            }
+
+            #[test]
+            #[should_panic]
+            fn split_off_past_the_end_panics() {
+                let mut list: DoublyLinkedList<i32> = (0..3).collect();
+                list.split_off(4);
+            }
+
+            #[test]
+            fn double_ended_iterators_meet_in_the_middle() {
+                for len in 0..10 {
+                    let list: DoublyLinkedList<i32> = (0..len).collect();
+                    for pattern in 0..4 {
+                        let mut iter = list.iter();
+                        let mut seen = Vec::new();
+                        let mut turn = pattern;
+                        loop {
+                            assert_eq!(iter.size_hint(), (len as usize - seen.len(), Some(len as usize - seen.len())));
+                            let item = if turn % 3 == 0 { iter.next_back() } else { iter.next() };
+                            let Some(item) = item else { break };
+                            seen.push(item.clone());
+                            turn += 1;
+                        }
+                        assert_eq!(iter.next(), None);
+                        assert_eq!(iter.next_back(), None);
+                        seen.sort();
+                        assert_eq!(seen, (0..len).collect::<Vec<_>>());
+                    }
+
+                    let mut into = list.into_iter();
+                    let mut front = Vec::new();
+                    let mut back = Vec::new();
+                    while let Some(v) = into.next() {
+                        front.push(v);
+                        if let Some(v) = into.next_back() {
+                            back.push(v);
+                        }
+                    }
+                    back.reverse();
+                    front.extend(back);
+                    assert_eq!(front, (0..len).collect::<Vec<_>>());
+                }
+            }
+
+            #[test]
+            fn every_value_is_dropped_once() {
+                let drops = Rc::new(RefCell::new(Vec::new()));
+                let tracked = |id| Tracked { id, drops: Rc::clone(&drops) };
+                let mut created = 0;
+                {
+                    let mut list = DoublyLinkedList::new();
+                    for _ in 0..40 {
+                        list.push_back(tracked(created));
+                        created += 1;
+                        list.push_front(tracked(created));
+                        created += 1;
+                    }
+                    drop(list.pop_front());
+                    drop(list.pop_back());
+                    let mut rest = list.split_off(30);
+                    let mut cursor = rest.cursor_front_mut();
+                    cursor.move_next();
+                    drop(cursor.remove_current());
+                    cursor.insert_before(tracked(created));
+                    created += 1;
+                    drop(cursor);
+                    let mut other: DoublyLinkedList<Tracked> = DoublyLinkedList::new();
+                    other.push_back(tracked(created));
+                    created += 1;
+                    list.append(&mut other);
+
+                    // An iterator dropped half way drops what it had left
+                    let mut into = rest.into_iter();
+                    drop(into.next());
+                    drop(into.next_back());
+                    drop(into);
+                    assert_eq!(drops.borrow().len(), created - list.len() - other.len());
+                }
+                let mut dropped = drops.borrow().clone();
+                dropped.sort();
+                assert_eq!(dropped, (0..created).collect::<Vec<_>>(), "each value dropped exactly once");
+            }
+
+            #[test]
+            fn long_lists_drop_without_recursing() {
+                let len = if cfg!(miri) { 1_000 } else { 200_000 };
+                let list: DoublyLinkedList<i32> = (0..len).collect();
+                assert_eq!(list.len(), len as usize);
+                assert_eq!(list.back().map(|v| *v), Some(len - 1));
+            }
+
+            #[test]
+            fn collects_and_extends() {
+                let mut list: DoublyLinkedList<i32> = DoublyLinkedList::default();
+                list.extend([1, 2]);
+                list.extend(vec![3]);
+                assert_matches(&list, &VecDeque::from([1, 2, 3]));
+                assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+                list.clear();
+                assert_matches(&list, &VecDeque::new());
+                list.push_back(4);
+                assert_matches(&list, &VecDeque::from([4]));
+            }
+        }
+    };
+}
+
+list_tests!(rc_list, rc);
+list_tests!(raw_list, raw);
+
+#[test]
+fn raw_iter_mut_from_both_ends() {
+    let mut list: raw::DoublyLinkedList<i32> = (0..6).collect();
+    let mut iter = list.iter_mut();
+    while let (Some(front), Some(back)) = (iter.next(), iter.next_back()) {
+        std::mem::swap(front, back);
+    }
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1, 0]);
+    for value in &mut list {
+        *value *= 2;
+    }
+    assert_eq!((&list).into_iter().rev().copied().collect::<Vec<_>>(), [0, 2, 4, 6, 8, 10]);
+}
+
+#[test]
+fn raw_list_moves_between_threads() {
+    let list: raw::DoublyLinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    let joined = std::thread::spawn(move || list.into_iter().rev().collect::<String>()).join();
+    assert_eq!(joined.ok().as_deref(), Some("cba"));
+}
+
+#[test]
+fn raw_list_of_zero_sized_values() {
+    let mut list = raw::DoublyLinkedList::new();
+    for _ in 0..10 {
+        list.push_back(());
+    }
+    let rest = list.split_off(4);
+    assert_eq!((list.len(), rest.len()), (4, 6));
+    assert_eq!(rest.iter().count(), 6);
+}
+
+#[test]
+fn rc_borrows_are_checked_at_run_time() {
+    let mut list: rc::DoublyLinkedList<i32> = (1..=3).collect();
+    {
+        let front = list.front();
+        let back = list.back();
+        assert_eq!((front.as_deref(), back.as_deref()), (Some(&1), Some(&3)));
+    }
+    if let Some(mut front) = list.front_mut() {
+        *front = 10;
+    }
+    assert_eq!(list.iter().collect::<Vec<_>>(), [10, 2, 3]);
+}
@@ -0,0 +1,306 @@
+// Exercise 324: Big Integers - Arbitrary Precision
+// ================================================
+//
+// Learning Objective:
+// Compute with integers that do not fit in a machine word. factorial (106)
+// and fibonacci (107, 108) return u64 and overflow at 21! and F(94), and
+// fast_power (117) silently wraps. A BigUint stores a number as a vector
+// of 32-bit "limbs", digits in base 2^32, and does on them what you would
+// do on paper with decimal digits - with a u64 to hold each carry.
+//
+// This exercise covers:
+// - A normalized limb-vector representation, least significant first
+// - Addition and subtraction with carries and borrows
+// - Schoolbook multiplication, O(n^2)
+// - Karatsuba multiplication, O(n^1.585), above a size threshold
+// - Division by a small value, and decimal Display built on it
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+fn main() {
+    println!("=== Arbitrary-Precision Integers ===\n");
+
+    let big = BigUint::from_u64(u64::MAX);
+    println!("u64::MAX + 1 = {}", &big + &BigUint::from_u64(1));
+    println!("u64::MAX^2 = {}", &big * &big);
+
+    println!("\n25! = {} (u64 stops at 20!)", factorial_big(25));
+    println!("F(100) = {} (u64 stops at F(93))", fibonacci_big(100));
+    println!("3^100 = {}", fast_power_big(&BigUint::from_u64(3), 100));
+
+    let digits = factorial_big(1000).to_string();
+    println!("\n1000! has {} digits, starting {}...", digits.len(), &digits[..20]);
+    let digits = fibonacci_big(10_000).to_string();
+    println!("F(10000) has {} digits, starting {}...", digits.len(), &digits[..20]);
+
+    println!("\n✓ Arbitrary-precision integers completed successfully!");
+}
+
+/// Products of operands this many limbs long or longer use Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// An unsigned integer of any size.
+///
+/// Stored like a number written in base 2^32: `limbs[0]` is the least
+/// significant digit. There are never zero limbs at the end, so every value
+/// has exactly one representation and zero is the empty vector.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct BigUint {
+    limbs: Vec<u32>,
+}
+
+/// Drops the zero limbs at the end.
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+/// TODO: a + b, limb by limb with a carry
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // TODO: Walk the longer operand; a missing limb of the shorter is 0
+    // TODO: Add in u64: the low 32 bits are the limb, the rest the carry
+    // TODO: A carry left at the end becomes one more limb
+    todo!()
+}
+
+/// TODO: a - b, limb by limb with a borrow.
+///
+/// # Panics
+///
+/// Panics if b > a.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // TODO: As add_limbs, but borrow 2^32 from the next limb when a limb
+    //   would go negative (i64 has room for it)
+    // TODO: A borrow left at the end means b > a: panic
+    // TODO: trim the result - the top limbs may have cancelled out
+    todo!()
+}
+
+/// Adds `x` into `result` starting at limb `shift`, growing it as needed.
+fn add_shifted(result: &mut Vec<u32>, x: &[u32], shift: usize) {
+    if result.len() < shift + x.len() {
+        result.resize(shift + x.len(), 0);
+    }
+    let mut carry = 0u64;
+    let mut i = shift;
+    for &limb in x {
+        let sum = result[i] as u64 + limb as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    while carry > 0 {
+        if i == result.len() {
+            result.push(0);
+        }
+        let sum = result[i] as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+/// TODO: a * b the way it is done on paper: every limb of a times every
+/// limb of b, in O(len(a) * len(b))
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // TODO: The product has at most len(a) + len(b) limbs
+    // TODO: Limb i of a times limb j of b lands in limb i + j
+    // HINTS `mul_schoolbook`
+    // 1: x * y + result[i + j] + carry never overflows a u64, even with
+    //    every limb at u32::MAX: (2^32 - 1)^2 + 2 (2^32 - 1) = 2^64 - 1.
+    // 2: After the inner loop, the carry is the whole of limb i + len(b).
+    todo!()
+}
+
+/// TODO: a * b with three half-size products instead of four, in
+/// O(n^log2(3)) = O(n^1.585).
+///
+/// Split each operand at `half` limbs: a = a1 * B + a0 and b = b1 * B + b0
+/// with B = 2^(32 * half). Then
+/// a * b = z2 * B^2 + z1 * B + z0, where z0 = a0 * b0, z2 = a1 * b1 and
+/// z1 = (a0 + a1) * (b0 + b1) - z0 - z2.
+fn mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    // TODO: Below KARATSUBA_THRESHOLD limbs, mul_schoolbook is faster
+    // TODO: Split both at half of the longer length (split_at)
+    // TODO: Compute z0, z2 and z1 recursively
+    // TODO: Assemble with add_shifted
+    // HINTS `mul_karatsuba`
+    // 1: A shorter operand may have fewer than `half` limbs; split it at
+    //    `half.min(len)`, which leaves an empty high half.
+    // 2: The low halves may end in zero limbs. sub_limbs and add_shifted
+    //    cope with that; trim the final result.
+    todo!()
+}
+
+impl BigUint {
+    fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    fn from_u64(n: u64) -> Self {
+        BigUint {
+            limbs: trim(vec![n as u32, (n >> 32) as u32]),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// TODO: self * m for a single limb m
+    fn mul_small(&self, m: u32) -> BigUint {
+        todo!()
+    }
+
+    /// TODO: The quotient and remainder of self / d, for a single limb d.
+    ///
+    /// # Panics
+    ///
+    /// Panics if d is 0.
+    fn divmod_small(&self, d: u32) -> (BigUint, u32) {
+        // TODO: Long division: go from the most significant limb down,
+        //   dividing (remainder << 32 | limb) by d at each step
+        todo!()
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: add_limbs(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// # Panics
+    ///
+    /// Panics if other > self.
+    fn sub(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: sub_limbs(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: mul_karatsuba(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Ord for BigUint {
+    /// TODO: Compare two values
+    fn cmp(&self, other: &Self) -> Ordering {
+        // TODO: Without trailing zero limbs, the longer number is the
+        //   larger one; equal lengths compare from the top limb down
+        todo!()
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    /// TODO: Print the value in decimal
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // TODO: Divide by 10^9 repeatedly: each remainder is the next nine
+        //   decimal digits from the right
+        // TODO: Every chunk but the leading one is padded to nine digits
+        // TODO: Write the digits with f.pad_integral(true, "", &digits) so
+        //   width and alignment work as for the built-in integers
+        todo!()
+    }
+}
+
+/// TODO: n! with no upper limit on n (exercise 106 overflows at 21!)
+fn factorial_big(n: u32) -> BigUint {
+    todo!()
+}
+
+/// TODO: The nth Fibonacci number with no upper limit on n (exercise 108
+/// overflows at F(94))
+fn fibonacci_big(n: u64) -> BigUint {
+    todo!()
+}
+
+/// TODO: base^exp by squaring, as in exercise 117, but without wrapping
+fn fast_power_big(base: &BigUint, exp: u32) -> BigUint {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u64_is_trimmed() {
+        assert_eq!(BigUint::from_u64(0).limbs, Vec::<u32>::new());
+        assert_eq!(BigUint::from_u64(5).limbs, vec![5]);
+        assert_eq!(BigUint::from_u64(1 << 32).limbs, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_add_carries() {
+        let a = BigUint::from_u64(u32::MAX as u64);
+        assert_eq!((&a + &BigUint::from_u64(1)).limbs, vec![0, 1]);
+        let max = BigUint::from_u64(u64::MAX);
+        assert_eq!((&max + &max).limbs, vec![u32::MAX - 1, u32::MAX, 1]);
+    }
+
+    #[test]
+    fn test_sub_borrows() {
+        let a = BigUint::from_u64(1 << 32);
+        assert_eq!(&a - &BigUint::from_u64(1), BigUint::from_u64(u32::MAX as u64));
+        assert!((&a - &a).is_zero());
+    }
+
+    #[test]
+    fn test_mul() {
+        let max = BigUint::from_u64(u64::MAX);
+        assert_eq!((&max * &max).to_string(), "340282366920938463426481119284349108225");
+        assert!((&max * &BigUint::zero()).is_zero());
+        assert_eq!(BigUint::from_u64(7).mul_small(6), BigUint::from_u64(42));
+    }
+
+    #[test]
+    fn test_divmod_small() {
+        let (q, r) = BigUint::from_u64(1_000_000_007).divmod_small(10);
+        assert_eq!((q, r), (BigUint::from_u64(100_000_000), 7));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from_u64(1_000_000_000).to_string(), "1000000000");
+        assert_eq!(BigUint::from_u64(u64::MAX).to_string(), "18446744073709551615");
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(BigUint::from_u64(1 << 32) > BigUint::from_u64(u32::MAX as u64));
+        assert!(BigUint::from_u64(3) < BigUint::from_u64(4));
+    }
+
+    #[test]
+    fn test_past_u64() {
+        assert_eq!(factorial_big(25).to_string(), "15511210043330985984000000");
+        assert_eq!(fibonacci_big(100).to_string(), "354224848179261915075");
+        assert_eq!(fast_power_big(&BigUint::from_u64(2), 100).to_string(), "1267650600228229401496703205376");
+    }
+}
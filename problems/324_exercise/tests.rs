@@ -0,0 +1,192 @@
+//! Hidden tests for exercise 324: Big Integers - Arbitrary Precision.
+//!
+//! Results far past u64 are checked against their known digits: the
+//! length, the leading and trailing digits and the digit sum. Karatsuba
+//! must agree with the schoolbook product on random operands of every
+//! shape around the threshold, and everything that fits in a u128 is
+//! checked against u128 arithmetic.
+
+use super::*;
+
+/// Deterministic pseudo-random numbers.
+fn lcg(seed: u64) -> impl FnMut() -> u64 {
+    let mut x = seed;
+    move || {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        x >> 16
+    }
+}
+
+/// `len` random limbs; the top one is never zero.
+fn random(next: &mut impl FnMut() -> u64, len: usize) -> BigUint {
+    let mut limbs: Vec<u32> = (0..len).map(|_| next() as u32).collect();
+    if let Some(top) = limbs.last_mut() {
+        *top |= 1;
+    }
+    BigUint { limbs }
+}
+
+fn from_u128(n: u128) -> BigUint {
+    let high = BigUint::from_u64((n >> 64) as u64);
+    let shift = BigUint::from_u64(1 << 32);
+    &(&(&high * &shift) * &shift) + &BigUint::from_u64(n as u64)
+}
+
+fn digit_sum(digits: &str) -> u32 {
+    digits.bytes().map(|b| (b - b'0') as u32).sum()
+}
+
+#[test]
+fn factorial_of_1000() {
+    let digits = factorial_big(1000).to_string();
+    assert_eq!(digits.len(), 2568);
+    assert!(digits.starts_with("4023872600770937735437024339230039857193"), "{}", &digits[..40]);
+    assert_eq!(digits.len() - digits.trim_end_matches('0').len(), 249, "trailing zeros");
+    assert_eq!(digit_sum(&digits), 10539);
+}
+
+#[test]
+fn fibonacci_of_10000() {
+    let digits = fibonacci_big(10_000).to_string();
+    assert_eq!(digits.len(), 2090);
+    assert!(digits.starts_with("3364476487643178326662161200510754331030"), "{}", &digits[..40]);
+    assert!(digits.ends_with("0701794976171121233066073310059947366875"));
+    assert_eq!(digit_sum(&digits), 9123);
+}
+
+#[test]
+fn powers_past_u64() {
+    let digits = fast_power_big(&BigUint::from_u64(3), 5000).to_string();
+    assert_eq!(digits.len(), 2386);
+    assert!(digits.starts_with("4038997629787155339700863409815084778394"));
+    assert!(digits.ends_with("3468711359732633600493563136998276100001"));
+    assert_eq!(digit_sum(&digits), 11097);
+
+    let digits = fast_power_big(&BigUint::from_u64(7), 777).to_string();
+    assert_eq!(digits.len(), 657);
+    assert!(digits.ends_with("4585097884685751563283203545236625979207"));
+    assert_eq!(digit_sum(&digits), 2989);
+
+    // A power of two is a single bit in the right limb
+    for exp in [0, 31, 32, 63, 64, 1000, 4095] {
+        let power = fast_power_big(&BigUint::from_u64(2), exp);
+        let mut limbs = vec![0; exp as usize / 32];
+        limbs.push(1 << (exp % 32));
+        assert_eq!(power.limbs, limbs, "2^{exp}");
+    }
+}
+
+#[test]
+fn small_results_match_u64_and_u128() {
+    let mut factorial = 1u128;
+    for n in 0..=34 {
+        if n > 0 {
+            factorial *= n as u128;
+        }
+        assert_eq!(factorial_big(n).to_string(), factorial.to_string(), "{n}!");
+    }
+    let (mut a, mut b) = (0u128, 1u128);
+    for n in 0..=184 {
+        assert_eq!(fibonacci_big(n).to_string(), a.to_string(), "F({n})");
+        (a, b) = (b, a + b);
+    }
+    assert_eq!(fast_power_big(&BigUint::from_u64(10), 38).to_string(), 10u128.pow(38).to_string());
+    assert_eq!(fast_power_big(&BigUint::zero(), 0).to_string(), "1");
+    assert_eq!(fast_power_big(&BigUint::zero(), 5).to_string(), "0");
+}
+
+#[test]
+fn arithmetic_matches_u128() {
+    let mut next = lcg(324);
+    let values = [0, 1, u32::MAX as u64, 1 << 32, u64::MAX];
+    for _ in 0..2_000 {
+        let x = match next() % 3 {
+            0 => values[(next() % 5) as usize],
+            1 => next() % 1000,
+            _ => next() << 16 | next(),
+        };
+        let y = match next() % 3 {
+            0 => values[(next() % 5) as usize],
+            1 => next() % 1000,
+            _ => next() << 16 | next(),
+        };
+        let (big_x, big_y) = (BigUint::from_u64(x), BigUint::from_u64(y));
+        assert_eq!(&big_x + &big_y, from_u128(x as u128 + y as u128), "{x} + {y}");
+        assert_eq!(&big_x * &big_y, from_u128(x as u128 * y as u128), "{x} * {y}");
+        assert_eq!(big_x.cmp(&big_y), x.cmp(&y), "{x} vs {y}");
+        if x >= y {
+            assert_eq!(&big_x - &big_y, BigUint::from_u64(x - y), "{x} - {y}");
+        }
+        let product = x as u128 * y as u128;
+        assert_eq!(from_u128(product).to_string(), product.to_string());
+
+        let d = (next() as u32).max(1);
+        let (q, r) = from_u128(product).divmod_small(d);
+        assert_eq!(q, from_u128(product / d as u128), "{product} / {d}");
+        assert_eq!(r as u128, product % d as u128, "{product} % {d}");
+        assert_eq!(big_x.mul_small(d), from_u128(x as u128 * d as u128));
+    }
+}
+
+#[test]
+fn karatsuba_matches_schoolbook() {
+    let mut next = lcg(1);
+    let t = KARATSUBA_THRESHOLD;
+    let lengths = [0, 1, 2, t - 1, t, t + 1, 2 * t - 1, 2 * t, 2 * t + 1, 5 * t + 3];
+    for &m in &lengths {
+        for &n in &lengths {
+            let a = random(&mut next, m);
+            let b = random(&mut next, n);
+            let expected = mul_schoolbook(&a.limbs, &b.limbs);
+            assert_eq!(mul_karatsuba(&a.limbs, &b.limbs), expected, "{m} x {n} limbs");
+            assert_eq!((&a * &b).limbs, expected, "{m} x {n} limbs");
+        }
+    }
+    // All-ones limbs carry the whole way along in every partial sum
+    let ones = BigUint { limbs: vec![u32::MAX; 4 * t] };
+    let square = &ones * &ones;
+    assert_eq!(square.limbs, mul_schoolbook(&ones.limbs, &ones.limbs));
+    let one = BigUint::from_u64(1);
+    let up = &ones + &one;
+    assert_eq!(&up * &up, &(&square + &ones.mul_small(2)) + &one);
+}
+
+#[test]
+fn values_stay_trimmed() {
+    let mut next = lcg(99);
+    for _ in 0..200 {
+        let (m, n) = ((next() % 80) as usize, (next() % 80) as usize);
+        let a = random(&mut next, m);
+        let b = random(&mut next, n);
+        let (big, small) = if a >= b { (&a, &b) } else { (&b, &a) };
+        for value in [&a + &b, big - small, big - big, &a * &b, a.mul_small(0), a.divmod_small(u32::MAX).0] {
+            assert_ne!(value.limbs.last(), Some(&0), "trailing zero limb in {:?}", value.limbs);
+        }
+        // (a + b) - b == a, and (a * d + r) / d == (a, r)
+        assert_eq!(&(&a + &b) - &b, a);
+        let d = (next() as u32).max(2);
+        let r = next() as u32 % d;
+        let (q, rem) = (&a.mul_small(d) + &BigUint::from_u64(r as u64)).divmod_small(d);
+        assert_eq!((q, rem), (a.clone(), r));
+    }
+}
+
+#[test]
+fn zero_and_display() {
+    assert!(BigUint::zero().is_zero());
+    assert_eq!(BigUint::from_u64(0), BigUint::zero());
+    assert!(BigUint::zero().limbs.is_empty());
+    assert_eq!(BigUint::zero().to_string(), "0");
+    assert_eq!(BigUint::from_u64(1_000_000_000).to_string(), "1000000000");
+    assert_eq!(BigUint::from_u64(1_000_000_007).to_string(), "1000000007");
+    assert_eq!(BigUint::from_u64(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(format!("{:>8}", BigUint::from_u64(42)), "      42");
+    assert!(BigUint::zero() < BigUint::from_u64(1));
+    assert!(BigUint::from_u64(1 << 32) > BigUint::from_u64(u32::MAX as u64));
+}
+
+#[test]
+#[should_panic]
+fn subtraction_below_zero_panics() {
+    let _ = &BigUint::from_u64(1 << 32) - &BigUint::from_u64((1 << 32) + 1);
+}
@@ -0,0 +1,222 @@
+// Exercise 325: Primes - Miller-Rabin and Pollard's Rho
+// =====================================================
+//
+// Learning Objective:
+// Take the prime exercises to the whole u64 range. Trial division (110)
+// needs 2^32 steps for a large prime, and the sieve of 111 needs a byte
+// per number: ten gigabytes to count the primes up to 10^10. This
+// exercise replaces each with the algorithm used in practice.
+//
+// - Miller-Rabin decides primality with a dozen modular powers. With a
+//   fixed set of witness bases it is exact, not probabilistic, for u64.
+// - A segmented sieve sieves one window of numbers at a time, with only
+//   the primes up to sqrt(n) kept in memory.
+// - Pollard's rho finds a factor p of n in about sqrt(p) steps, which
+//   factors any u64 in milliseconds.
+//
+// This exercise covers:
+// - Overflow-safe modular multiplication with u128 intermediates
+// - Strong pseudoprimes, and why one base is not enough
+// - Sieving a window lo..=hi in bounded memory
+// - Cycle detection with Floyd's tortoise and hare
+
+fn main() {
+    println!("=== Primes at 64-bit Scale ===\n");
+
+    println!("--- Miller-Rabin ---");
+    for n in [1_000_000_007, 3_215_031_751, 18_446_744_073_709_551_557, u64::MAX] {
+        println!("is_prime({}) = {}", n, is_prime(n));
+    }
+
+    println!("\n--- Segmented sieve ---");
+    println!("Primes up to 10^7: {}", count_primes_up_to(10_000_000));
+    let mut window = Vec::new();
+    segmented_sieve(10_000_000_000, 10_000_000_100, |p| window.push(p));
+    println!("Primes in [10^10, 10^10 + 100]: {:?}", window);
+
+    println!("\n--- Pollard's rho ---");
+    for n in [600_851_475_143, 998_244_359_987_710_471, u64::MAX] {
+        println!("{} = {:?}", n, factorize(n));
+    }
+
+    println!("\n✓ Primes at 64-bit scale completed successfully!");
+}
+
+/// Largest segment the sieve works on at once, in numbers.
+const SEGMENT_SIZE: usize = 1 << 16;
+
+/// The primes up to and including n, with the sieve of exercise 111.
+fn simple_sieve(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return vec![];
+    }
+    let mut is_prime = vec![true; n + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    let mut p = 2;
+    while p * p <= n {
+        if is_prime[p] {
+            for multiple in (p * p..=n).step_by(p) {
+                is_prime[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    (0..=n).filter(|&i| is_prime[i]).collect()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// TODO: a * b % m without overflow
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    // TODO: The product of two u64 always fits a u128
+    todo!()
+}
+
+/// TODO: base^exp % modulus by squaring, as in exercise 117, for any u64
+/// modulus
+fn modular_power(base: u64, exp: u64, modulus: u64) -> u64 {
+    // TODO: As in 117, but every product goes through mul_mod - with a
+    //   modulus above 2^32, result * base overflows a u64
+    // TODO: Anything mod 1 is 0
+    todo!()
+}
+
+/// Bases that make Miller-Rabin exact for every n below 3.3 * 10^24, so
+/// for every u64.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// TODO: Whether n is prime, by Miller-Rabin with a fixed set of witnesses.
+///
+/// Write n - 1 = d * 2^s with d odd. For a prime n, every a has a^d = 1 or
+/// a^(d * 2^r) = n - 1 for some r < s, mod n. A base for which neither
+/// holds proves n composite.
+fn is_prime(n: u64) -> bool {
+    // TODO: Numbers below 2 are not prime; a witness dividing n decides
+    //   it directly (n is prime only if it is that witness)
+    // TODO: Split n - 1 into d * 2^s
+    // TODO: For each witness a: compute x = a^d, then square it up to
+    //   s - 1 times, looking for 1 at the start or n - 1 anywhere
+    // HINTS `is_prime`
+    // 1: `(n - 1).trailing_zeros()` is s, and `(n - 1) >> s` is d.
+    // 2: A labeled loop, `'witness: for &a in &WITNESSES`, lets the inner
+    //    squaring loop `continue 'witness` as soon as it sees n - 1.
+    todo!()
+}
+
+/// TODO: Call `visit` on every prime in lo..=hi, in order.
+///
+/// Only the primes up to sqrt(hi) and one segment of SEGMENT_SIZE flags
+/// are held at a time, so counting to 10^10 needs well under a megabyte
+/// where the plain sieve would need ten gigabytes.
+fn segmented_sieve(lo: u64, hi: u64, mut visit: impl FnMut(u64)) {
+    // TODO: Sieve the base primes up to hi.isqrt() with simple_sieve
+    // TODO: Allocate one segment of SEGMENT_SIZE flags and reuse it for
+    //   each window start..=end, clearing it first
+    // TODO: For each base prime p with p * p <= end, mark its multiples
+    //   in the window, starting from the first one at or after start -
+    //   but never p itself
+    // TODO: visit the unmarked numbers; 0 and 1 are not prime
+    // HINTS `segmented_sieve`
+    // 1: The first multiple of p at or after start is
+    //    `start.div_ceil(p) * p`; take the max with p * p.
+    // 2: Work in indices into the segment: from `(first - start) as usize`
+    //    to the window's length, with `.step_by(p as usize)`.
+    todo!()
+}
+
+/// TODO: The number of primes up to and including n
+fn count_primes_up_to(n: u64) -> u64 {
+    todo!()
+}
+
+/// TODO: A factor of the composite n other than 1 and n, by Pollard's rho.
+///
+/// The sequence x -> x^2 + c mod n, taken mod an unknown prime factor p,
+/// repeats after about sqrt(p) steps. Floyd's tortoise and hare find the
+/// repeat: once x = y mod p, p divides gcd(|x - y|, n).
+fn pollard_rho(n: u64) -> u64 {
+    // TODO: 2 divides every even n
+    // TODO: For c = 1, 2, ...: start x = y = 2, then step x once and y
+    //   twice until gcd(|x - y|, n) is not 1
+    // TODO: A gcd of n means the sequence cycled mod n itself: try the
+    //   next c; anything else is a factor
+    // HINTS `pollard_rho`
+    // 1: x < n < 2^64, so x * x + c fits a u128: compute it there, then
+    //    reduce mod n.
+    // 2: `x.abs_diff(y)` is |x - y| without going negative.
+    todo!()
+}
+
+/// TODO: The prime factors of n with their multiplicity, smallest first;
+/// none for 0 and 1
+fn factorize(n: u64) -> Vec<u64> {
+    // TODO: Divide out the small primes first: rho is slow to find them
+    //   and cannot split powers of 2
+    // TODO: Keep a stack of numbers still to split: a prime goes into the
+    //   result, a composite m splits into d = pollard_rho(m) and m / d
+    // TODO: Sort the result
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_mod_past_u64() {
+        assert_eq!(mul_mod(u64::MAX, 2, u64::MAX), 0);
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+        assert_eq!(modular_power(2, 64, u64::MAX), 1);
+        assert_eq!(modular_power(3, 200, 13), 9);
+    }
+
+    #[test]
+    fn test_small_primes() {
+        let primes: Vec<u64> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]);
+    }
+
+    #[test]
+    fn test_strong_pseudoprime_to_base_2() {
+        // 2047 = 23 * 89 passes the base-2 test alone
+        assert!(!is_prime(2047));
+        assert!(is_prime(1_000_000_007));
+    }
+
+    #[test]
+    fn test_segmented_sieve_matches_simple_sieve() {
+        let n = 2 * SEGMENT_SIZE + 100;
+        let mut primes = Vec::new();
+        segmented_sieve(0, n as u64, |p| primes.push(p as usize));
+        assert_eq!(primes, simple_sieve(n));
+        assert_eq!(count_primes_up_to(100), 25);
+    }
+
+    #[test]
+    fn test_window() {
+        let mut primes = Vec::new();
+        segmented_sieve(90, 110, |p| primes.push(p));
+        assert_eq!(primes, vec![97, 101, 103, 107, 109]);
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![2, 2, 2, 3, 3, 5]);
+        assert_eq!(factorize(600_851_475_143), vec![71, 839, 1_471, 6_857]);
+        assert_eq!(factorize(1_000_000_007), vec![1_000_000_007]);
+    }
+
+    #[test]
+    fn test_pollard_rho() {
+        let n = 1_000_003 * 1_000_033;
+        let d = pollard_rho(n);
+        assert!(d == 1_000_003 || d == 1_000_033);
+    }
+}
@@ -0,0 +1,169 @@
+//! Hidden tests for exercise 325: Primes - Miller-Rabin and Pollard's Rho.
+//!
+//! Miller-Rabin and the segmented sieve must agree with the simple sieve
+//! of exercise 111 wherever it can reach, including the segment edges.
+//! Past that: strong pseudoprimes that fool a single base, the largest
+//! primes below 2^64, windows of the sieve at 10^10 and 10^12, and
+//! factorizations that trial division could not finish.
+
+use super::*;
+
+/// Deterministic pseudo-random numbers.
+fn lcg(seed: u64) -> impl FnMut() -> u64 {
+    let mut x = seed;
+    move || {
+        x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        x >> 11
+    }
+}
+
+fn primes_in(lo: u64, hi: u64) -> Vec<u64> {
+    let mut primes = Vec::new();
+    segmented_sieve(lo, hi, |p| primes.push(p));
+    primes
+}
+
+#[test]
+fn miller_rabin_agrees_with_the_sieve() {
+    let n = 200_000;
+    let primes = simple_sieve(n);
+    let mut expected = vec![false; n + 1];
+    for &p in &primes {
+        expected[p] = true;
+    }
+    for (i, &prime) in expected.iter().enumerate() {
+        assert_eq!(is_prime(i as u64), prime, "is_prime({i})");
+    }
+}
+
+#[test]
+fn pseudoprimes_are_composite() {
+    // Fermat liars (Carmichael numbers) and strong pseudoprimes to base 2,
+    // to bases 2 and 3, and so on up to every prime base through 23
+    for n in [
+        561,
+        41_041,
+        825_265,
+        2_047,
+        1_373_653,
+        25_326_001,
+        3_215_031_751,
+        2_152_302_898_747,
+        3_474_749_660_383,
+        341_550_071_728_321,
+        3_825_123_056_546_413_051,
+    ] {
+        assert!(!is_prime(n), "{n} is composite");
+    }
+}
+
+#[test]
+fn primes_near_the_top_of_u64() {
+    let top: Vec<u64> = (u64::MAX - 99..=u64::MAX).filter(|&n| is_prime(n)).collect();
+    assert_eq!(top, [18_446_744_073_709_551_521, 18_446_744_073_709_551_533, 18_446_744_073_709_551_557]);
+    assert!(is_prime(4_294_967_291));
+    assert!(!is_prime(4_294_967_291 * 4_294_967_291));
+    assert!(is_prime(1_000_000_007));
+    assert!(!is_prime(1_000_000_007 * 998_244_353));
+}
+
+#[test]
+fn modular_power_does_not_overflow() {
+    let m = u64::MAX - 58; // the largest prime below 2^64
+    assert_eq!(modular_power(2, m - 1, m), 1, "Fermat's little theorem");
+    assert_eq!(modular_power(m - 1, 2, m), 1);
+    assert_eq!(modular_power(u64::MAX, u64::MAX, 1), 0);
+    assert_eq!(modular_power(0, 0, 7), 1);
+    assert_eq!(mul_mod(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+    let mut next = lcg(325);
+    for _ in 0..1_000 {
+        let (a, b, m) = (next() << 11 | next() & 2047, next(), next().max(1));
+        assert_eq!(mul_mod(a, b, m) as u128, a as u128 * b as u128 % m as u128);
+    }
+}
+
+#[test]
+fn segmented_sieve_agrees_with_the_simple_sieve() {
+    let n = 3 * SEGMENT_SIZE as u64 + 17;
+    let expected: Vec<u64> = simple_sieve(n as usize).into_iter().map(|p| p as u64).collect();
+    let count = |lo: u64, hi: u64| expected.iter().filter(|&&p| lo <= p && p <= hi).count();
+    assert_eq!(primes_in(0, n), expected);
+    assert_eq!(count_primes_up_to(n), expected.len() as u64);
+
+    // Windows that start and end on and around the segment edges
+    let s = SEGMENT_SIZE as u64;
+    let mut next = lcg(7);
+    let mut edges = vec![0, 1, 2, 3, 4, s - 1, s, s + 1, 2 * s, 2 * s + 1, n];
+    edges.extend((0..20).map(|_| next() % n));
+    for &lo in &edges {
+        for &hi in &edges {
+            let window = primes_in(lo, hi);
+            assert_eq!(window.len(), count(lo, hi), "primes in {lo}..={hi}");
+            assert!(window.iter().all(|&p| lo <= p && p <= hi && expected.binary_search(&p).is_ok()));
+        }
+    }
+}
+
+#[test]
+fn segmented_sieve_counts() {
+    assert_eq!(count_primes_up_to(0), 0);
+    assert_eq!(count_primes_up_to(1), 0);
+    assert_eq!(count_primes_up_to(2), 1);
+    assert_eq!(count_primes_up_to(10_000_000), 664_579);
+}
+
+#[test]
+fn segmented_sieve_far_from_zero() {
+    assert_eq!(
+        primes_in(10_000_000_000, 10_000_000_100),
+        [10_000_000_019, 10_000_000_033, 10_000_000_061, 10_000_000_069, 10_000_000_097]
+    );
+    assert_eq!(primes_in(10_000_000_000, 10_001_000_000).len(), 43_427);
+    let window = primes_in(1_000_000_000_000, 1_000_000_100_000);
+    assert_eq!(window.len(), 3_614);
+    assert!(window.iter().all(|&p| is_prime(p)));
+    assert_eq!(primes_in(4_294_867_296, 4_295_067_296).len(), 8_938);
+}
+
+/// Takes most of a minute even with optimizations; run it with
+/// `cargo test --release -- --ignored` on a crate holding the solution.
+#[test]
+#[ignore]
+fn counts_primes_up_to_ten_billion() {
+    assert_eq!(count_primes_up_to(1_000_000_000), 50_847_534);
+    assert_eq!(count_primes_up_to(10_000_000_000), 455_052_511);
+}
+
+#[test]
+fn factorizations_multiply_back() {
+    let mut next = lcg(11);
+    for n in (0..2_000).chain((0..300).map(|_| next() << 11 | next() & 2047)) {
+        let factors = factorize(n);
+        if n <= 1 {
+            assert!(factors.is_empty(), "factorize({n})");
+            continue;
+        }
+        assert!(factors.windows(2).all(|w| w[0] <= w[1]), "{factors:?} is not sorted");
+        assert!(factors.iter().all(|&p| is_prime(p)), "{factors:?} are not all prime");
+        assert_eq!(factors.iter().product::<u64>(), n, "{factors:?}");
+    }
+}
+
+#[test]
+fn hard_factorizations() {
+    assert_eq!(factorize(u64::MAX), [3, 5, 17, 257, 641, 65_537, 6_700_417]);
+    assert_eq!(factorize(600_851_475_143), [71, 839, 1_471, 6_857]);
+    assert_eq!(factorize(998_244_359_987_710_471), [998_244_353, 1_000_000_007]);
+    assert_eq!(factorize(18_446_744_030_759_878_681), [4_294_967_291, 4_294_967_291]);
+    assert_eq!(factorize(3_825_123_056_546_413_051), [149_491, 747_451, 34_233_211]);
+    assert_eq!(factorize(1 << 63), vec![2; 63]);
+    assert_eq!(factorize(18_446_744_073_709_551_557), [18_446_744_073_709_551_557]);
+}
+
+#[test]
+fn pollard_rho_finds_a_proper_factor() {
+    for n in [4, 9, 15, 25, 561, 1_000_000_007 * 3, 998_244_359_987_710_471, 18_446_744_030_759_878_681] {
+        let d = pollard_rho(n);
+        assert!(1 < d && d < n && n % d == 0, "pollard_rho({n}) = {d}");
+    }
+}
@@ -51,7 +51,7 @@ ROOT = Path(__file__).parent.resolve()
 PORT = int(os.getenv("RUST_LEARNING_PORT", "54321"))
 PROFILE_PATH = ROOT / "profile" / "user_stats.json"
 PROBLEMS_DIR = ROOT / "problems"
-TOTAL_PROBLEMS = 320
+TOTAL_PROBLEMS = 325
 
 # Security settings - THESE MUST BE SET IN PRODUCTION
 MAX_CODE_SIZE = 100_000  # 100KB max code size
@@ -21,27 +21,14 @@ for i in $(seq 1 150); do
             FAILED=$((FAILED + 1))
             printf "❌ %03d (compile error)\n" $i
         fi
-    else
-        FAILED=$((FAILED + 1))
-        printf "❌ %03d (missing)\n" $i
-    fi
-done
-
-# Clean up
-rm -f /tmp/check_bin
-
-# Check projects (151-170)
-echo ""
-echo "Checking projects (151-170)..."
-for i in $(seq 151 170); do
-    DIR=$(printf "problems/%03d_project" $i)
-    if [ -f "$DIR/Cargo.toml" ]; then
-        if cargo test --quiet --manifest-path "$DIR/Cargo.toml" 2>/dev/null; then
+    elif [ -f "$DIR/Cargo.toml" ]; then
+        # The module exercises are multi-file crates
+        if cargo build --quiet --manifest-path "$DIR/Cargo.toml" 2>/dev/null; then
             PASSED=$((PASSED + 1))
             printf "✅ %03d\n" $i
         else
             FAILED=$((FAILED + 1))
-            printf "❌ %03d (test failed)\n" $i
+            printf "❌ %03d (compile error)\n" $i
         fi
     else
         FAILED=$((FAILED + 1))
@@ -49,6 +36,18 @@ for i in $(seq 151 170); do
     fi
 done
 
+# Clean up
+rm -f /tmp/check_bin
+
+# Check projects (151-170, 291-320), built together in problems/Cargo.toml
+echo ""
+echo "Checking projects..."
+if cargo xtask projects; then
+    PASSED=$((PASSED + 1))
+else
+    FAILED=$((FAILED + 1))
+fi
+
 echo ""
 echo "=========================================="
 echo "Results: $PASSED passed, $FAILED failed"
@@ -0,0 +1,110 @@
+#!/bin/bash
+# Check the reference solutions in solutions/ against the exercise templates.
+#
+# Exercises with a test module are graded with the template's own tests
+# spliced in, plus the hidden problems/NNN_exercise/tests.rs if there is
+# one; a solution only passes if every test passes. Exercises without
+# tests must compile warning-free and run to completion. The module
+# exercises are Cargo crates: their solution is a solutions/NNN/src/ tree,
+# built with cargo and graded against the tests in the exercise's crate.
+# Exercise 100's doc tests are graded too, and every public item needs one.
+# Exercises with a problems/NNN_exercise/properties.rs must hold its
+# properties as well. Exercises with a problems/NNN_exercise/expected.toml
+# must also print what it allows when their program runs.
+#
+# Usage: scripts/check_solutions.sh [NNN ...]
+
+set -u
+
+cd "$(dirname "$0")/.."
+
+echo "🦀 Checking reference solutions..."
+echo ""
+
+cargo build --quiet --release -p grader -p outcheck || exit 1
+GRADER=target/release/grader
+OUTCHECK=target/release/outcheck
+
+if [ $# -gt 0 ]; then
+    EXERCISES="$*"
+else
+    EXERCISES="$(seq -f "%03g" 1 150) $(seq 321 325)"
+fi
+
+TMP=$(mktemp -d)
+trap 'rm -rf "$TMP"' EXIT
+
+FAILED=0
+PASSED=0
+
+# Runs the single-file solution $1 and checks its output against the rules
+# file $2, printing the diff if it does not match.
+output_matches() {
+    rustc --edition 2021 -C opt-level=0 "$1" -o "$TMP/bin" 2> "$TMP/err" \
+        && timeout 10 "$TMP/bin" < /dev/null > "$TMP/out" || return 1
+    "$OUTCHECK" "$2" "$TMP/out" > "$TMP/check.json" && return 0
+    grep '"diff"' "$TMP/check.json" | sed 's/^/    /'
+    return 1
+}
+
+for N in $EXERCISES; do
+    TEMPLATE="problems/${N}_exercise/template.rs"
+    SOLUTION="solutions/${N}.rs"
+    if [ -d "solutions/${N}" ]; then
+        TEMPLATE="problems/${N}_exercise"
+        SOLUTION="solutions/${N}"
+    fi
+    if [ ! -e "$SOLUTION" ]; then
+        FAILED=$((FAILED + 1))
+        echo "❌ $N (missing solution)"
+        continue
+    fi
+
+    HIDDEN="problems/${N}_exercise/tests.rs"
+    EXTRA=()
+    PROPS="problems/${N}_exercise/properties.rs"
+    [ -f "$HIDDEN" ] && EXTRA=(--hidden-tests "$HIDDEN")
+    [ -f "$PROPS" ] && EXTRA+=(--property-tests "$PROPS")
+    [ "$N" = "100" ] && EXTRA+=(--doc-tests)
+    EXPECTED="problems/${N}_exercise/expected.toml"
+
+    if grep -rq '^#\[cfg(test)\]' "$TEMPLATE" || [ -f "$HIDDEN" ]; then
+        if ! $GRADER --exercise "$N" --tests-from "$TEMPLATE" "${EXTRA[@]}" "$SOLUTION" > "$TMP/report.json"; then
+            FAILED=$((FAILED + 1))
+            echo "❌ $N (tests failed)"
+            grep -E '"(name|status|panic_message|compile_error)"' "$TMP/report.json" \
+                | grep -v '"passed"' | sed 's/^/    /'
+        elif [ -f "$EXPECTED" ] && ! output_matches "$SOLUTION" "$EXPECTED"; then
+            FAILED=$((FAILED + 1))
+            echo "❌ $N (unexpected output)"
+        else
+            PASSED=$((PASSED + 1))
+            echo "✅ $N"
+        fi
+    elif [ -f "$EXPECTED" ]; then
+        if rustc --edition 2021 -C opt-level=0 -D warnings "$SOLUTION" -o "$TMP/bin" 2> "$TMP/err" \
+            && output_matches "$SOLUTION" "$EXPECTED"; then
+            PASSED=$((PASSED + 1))
+            echo "✅ $N"
+        else
+            FAILED=$((FAILED + 1))
+            echo "❌ $N (compile, run or output error)"
+            sed 's/^/    /' "$TMP/err"
+        fi
+    elif rustc --edition 2021 -C opt-level=0 -D warnings "$SOLUTION" -o "$TMP/bin" 2> "$TMP/err" \
+        && timeout 10 "$TMP/bin" < /dev/null > /dev/null; then
+        PASSED=$((PASSED + 1))
+        echo "✅ $N"
+    else
+        FAILED=$((FAILED + 1))
+        echo "❌ $N (compile or run error)"
+        sed 's/^/    /' "$TMP/err"
+    fi
+done
+
+echo ""
+echo "=========================================="
+echo "Results: $PASSED passed, $FAILED failed"
+echo "=========================================="
+
+[ $FAILED -eq 0 ]
@@ -8,15 +8,18 @@ Each run = ephemeral jail, born and destroyed in milliseconds.
 No shared state. No network. No persistence.
 
 Execution Modes (auto-detected, highest security first):
-  1. nsjail   -- Full namespace + seccomp isolation (~5ms startup)
-  2. Docker   -- Container sandbox with --network=none
-  3. Subprocess -- Restricted fallback for development only
+  1. sandbox  -- tools/sandbox: rlimits + seccomp + namespaces, no root or daemon
+  2. nsjail   -- Full namespace + seccomp isolation (~5ms startup)
+  3. Docker   -- Container sandbox with --network=none
+  4. sandbox with seccomp only -- no namespaces anywhere; development only
+  5. Subprocess -- Restricted fallback for development only
 
-In production, ONLY nsjail or Docker modes should be used.
+In production, ONLY the sandbox, nsjail or Docker modes should be used.
 """
 
 import asyncio
 import hashlib
+import json
 import logging
 import os
 import re
@@ -25,7 +28,7 @@ import tempfile
 import uuid
 from dataclasses import dataclass
 from pathlib import Path
-from typing import Optional, Dict, Any, Tuple
+from typing import Optional, Dict, Any, List, Tuple, Union
 
 logger = logging.getLogger(__name__)
 
@@ -34,6 +37,71 @@ NSJAIL_BIN = "/usr/sbin/nsjail"
 NSJAIL_CFG = "/etc/nsjail/rust_exec.cfg"
 RUSTC_PATH = "/rust/bin/rustc"  # read-only mount inside jail
 CARGO_PATH = "/rust/bin/cargo"
+RUSTDOC_PATH = "/rust/bin/rustdoc"
+
+# Test grader (tools/grader) -- compiles with --test, reports per-test JSON
+GRADER_BIN = os.environ.get("GRADER_BIN", "/usr/local/bin/grader")
+GRADE_TIMEOUT = 60  # seconds: compile + every test of one submission
+# Pointer exercises graded with a counting allocator: a test that ends with
+# more live allocations than it started with fails (grader --check-leaks)
+LEAK_CHECKED_EXERCISES = {"094", "119", "138"}
+# Library crates graded on their doc tests too: one result per example, and
+# a failure for each public item without one (grader --doc-tests)
+DOC_TESTED_EXERCISES = {"100"}
+
+# Sandbox runner (tools/sandbox) -- compiles and runs, reports a JSON outcome
+SANDBOX_BIN = os.environ.get("SANDBOX_BIN", "/usr/local/bin/sandbox")
+
+# Diagnostics explainer (tools/explain) -- rustc JSON errors linked to exercises
+EXPLAIN_BIN = os.environ.get("EXPLAIN_BIN", "/usr/local/bin/explain")
+
+# Output checker (tools/outcheck) -- matches a program's output against an
+# exercise's expected.toml: exact and regex lines, unordered line sets and
+# numeric tolerance. Runs no learner code, so it needs no sandbox
+OUTCHECK_BIN = os.environ.get("OUTCHECK_BIN", "/usr/local/bin/outcheck")
+OUTCHECK_TIMEOUT = 5  # seconds
+
+# Learner lint pack (tools/lint) -- flags the habits an exercise is meant to
+# break, such as .clone() in the borrowing exercises or unwrap() in the error
+# propagation ones. Only parses the submission, so it needs no sandbox
+LINT_BIN = os.environ.get("LINT_BIN", "/usr/local/bin/lint")
+LINT_TIMEOUT = 5  # seconds
+
+# Per-learner test generator (tools/variant) -- seeded cases for exercises
+# whose fixed tests can be hard-coded; reads VARIANT_SECRET (or the file
+# VARIANT_SECRET_FILE names) from the env and refuses to run without it
+VARIANT_BIN = os.environ.get("VARIANT_BIN", "/usr/local/bin/variant")
+VARIANT_TIMEOUT = 5  # seconds
+
+# Complexity checker (tools/complexity) -- growth of comparison counts and
+# forbidden std APIs for the sorting and searching exercises
+COMPLEXITY_BIN = os.environ.get("COMPLEXITY_BIN", "/usr/local/bin/complexity")
+COMPLEXITY_EXERCISES = {str(n) for n in (*range(101, 106), *range(126, 130))}
+COMPLEXITY_TIMEOUT = 90  # seconds: up to two harness builds + every size
+
+# Interleaving model checker (tools/interleave) -- runs the concurrency
+# exercises' scenarios under every thread schedule within a bound
+INTERLEAVE_BIN = os.environ.get("INTERLEAVE_BIN", "/usr/local/bin/interleave")
+INTERLEAVE_EXERCISES = {f"{n:03d}" for n in range(80, 86)}
+INTERLEAVE_TIMEOUT = 90  # seconds: harness build + every scenario
+
+# Mutation tester (tools/mutate) -- for the exercises where learners write
+# the tests, the share of mutants of the reference code those tests catch
+MUTATE_BIN = os.environ.get("MUTATE_BIN", "/usr/local/bin/mutate")
+MUTATE_EXERCISES = {"098", "099", "100"}
+MUTATE_TIMEOUT = 120  # seconds: one build and test run per mutant
+
+# Differential fuzzer (tools/fuzz) -- the string-parsing exercises, on
+# mutated inputs, against a reference; any panic or disagreement fails
+FUZZ_BIN = os.environ.get("FUZZ_BIN", "/usr/local/bin/fuzz")
+FUZZ_EXERCISES = {"120", "146", "148"}
+FUZZ_TIMEOUT = 60  # seconds: harness build + the fuzz run
+
+# Multi-file exercises are Cargo crates graded with cargo (grader DIR). A
+# submission is the .rs files under src/, keyed by path; the grader writes
+# the manifest itself
+CRATE_PATH_PATTERN = re.compile(r"src/(?:[A-Za-z_]\w*/)*[A-Za-z_]\w*\.rs")
+MAX_CRATE_FILES = 32
 
 COMPILE_TIMEOUT = 12  # seconds
 RUN_TIMEOUT = 5  # seconds
@@ -48,13 +116,20 @@ SANDBOX_BASE = Path(os.environ.get("SANDBOX_BASE", "/var/sandboxes"))
 # Docker fallback
 DOCKER_IMAGE = os.environ.get("SANDBOX_DOCKER_IMAGE", "rust-sandbox:latest")
 
+# `mod name;` would load another file; only multi-file submissions may
+MOD_DECLARATION_PATTERN = r"\bmod\s+(?:r#)?\w+\s*;"
+# `unsafe` blocks, allowed only in the exercises about raw pointers (094's
+# handle, 323's NonNull list), as in the lint pack
+UNSAFE_PATTERN = r"unsafe\s*\{"
+UNSAFE_EXERCISES = {"094", "323"}
+
 # Prohibited patterns for code validation (defense in depth)
 PROHIBITED_PATTERNS = [
     r"std\s*::\s*process\s*::\s*Command",
     r"std\s*::\s*fs\s*::",
     r"std\s*::\s*net\s*::",
     r"std\s*::\s*os\s*::",
-    r"unsafe\s*\{",
+    UNSAFE_PATTERN,
     r"#\s*!\s*\[\s*feature",
     r"include\s*!",
     r"include_str\s*!",
@@ -64,7 +139,7 @@ PROHIBITED_PATTERNS = [
     r"#![\s]*no_std",
     r"#![\s]*no_main",
     r"extern\s+crate",
-    r"mod\s+[^;]+;",
+    MOD_DECLARATION_PATTERN,
     r"use\s+std::process",
     r"use\s+std::fs",
     r"use\s+std::net",
@@ -102,13 +177,16 @@ class ExecutionResult:
     timed_out: bool = False
     memory_exceeded: bool = False
     security_violation: bool = False
+    # Structured compile errors from tools/explain, when compilation failed
+    diagnostics: Optional[List[Dict[str, Any]]] = None
 
 
-def validate_code(code: str) -> Tuple[bool, Optional[str]]:
+def validate_code(code: str, exercise_id: str = "") -> Tuple[bool, Optional[str]]:
     """
     Validate code before execution (defense in depth).
     The sandbox itself is the primary security boundary.
     """
+    allow_unsafe = exercise_id.zfill(3) in UNSAFE_EXERCISES
     if len(code.encode()) > MAX_CODE_BYTES:
         return False, "Code exceeds 64KB limit."
 
@@ -116,6 +194,8 @@ def validate_code(code: str) -> Tuple[bool, Optional[str]]:
         return False, f"Code exceeds maximum size of {MAX_CODE_SIZE} bytes"
 
     for pattern in PROHIBITED_PATTERNS:
+        if pattern == UNSAFE_PATTERN and allow_unsafe:
+            continue
         if re.search(pattern, code, re.IGNORECASE | re.MULTILINE):
             return False, "Security violation: Prohibited pattern detected"
 
@@ -125,6 +205,33 @@ def validate_code(code: str) -> Tuple[bool, Optional[str]]:
     return True, None
 
 
+def validate_files(files: Dict[str, str]) -> Tuple[bool, Optional[str]]:
+    """
+    Validate a multi-file submission the way validate_code does a single
+    file. `mod name;` may load a sibling file here, but `#[path]` may not
+    point one anywhere else, and only src/lib.rs is required.
+    """
+    if not files or len(files) > MAX_CRATE_FILES:
+        return False, f"A submission has between 1 and {MAX_CRATE_FILES} files."
+
+    if "src/lib.rs" not in files:
+        return False, "A multi-file submission needs src/lib.rs."
+
+    if sum(len(code.encode()) for code in files.values()) > MAX_CODE_BYTES:
+        return False, "Code exceeds 64KB limit."
+
+    for path, code in files.items():
+        if not CRATE_PATH_PATTERN.fullmatch(path):
+            return False, "Files must be .rs files under src/."
+        for pattern in (*PROHIBITED_PATTERNS, r"#\s*\[\s*path\b"):
+            if pattern == MOD_DECLARATION_PATTERN:
+                continue
+            if re.search(pattern, code, re.IGNORECASE | re.MULTILINE):
+                return False, "Security violation: Prohibited pattern detected"
+
+    return True, None
+
+
 def sanitize_error_message(error: str) -> str:
     """Sanitize error messages to prevent information disclosure."""
     if not error:
@@ -135,6 +242,14 @@ def sanitize_error_message(error: str) -> str:
     return error
 
 
+def _development_mode() -> bool:
+    """True only when ENVIRONMENT is development, or DEBUG is set outside
+    production; the weaker fallbacks are refused otherwise."""
+    env = os.environ.get("ENVIRONMENT", "production").lower()
+    debug = os.environ.get("DEBUG", "false").lower() == "true"
+    return env == "development" or (env != "production" and debug)
+
+
 class DisposableExecutor:
     """
     Each instance represents one ephemeral execution domain.
@@ -150,7 +265,7 @@ class DisposableExecutor:
     async def execute(self, code: str, exercise_id: str = "") -> ExecutionResult:
         """Run untrusted code in a disposable sandbox. Returns result."""
         # Validate first (defense in depth)
-        is_valid, validation_error = validate_code(code)
+        is_valid, validation_error = validate_code(code, exercise_id)
         if not is_valid:
             return ExecutionResult(
                 success=False,
@@ -168,13 +283,26 @@ class DisposableExecutor:
 
         async with self._semaphore:
             try:
-                return await self._run_sandboxed(code, exercise_id)
+                result = await self._run_sandboxed(code, exercise_id)
+                if result.compilation_error:
+                    result.diagnostics = await self._explain(code)
+                return result
             finally:
                 self._cleanup()
 
     async def _run_sandboxed(self, code: str, exercise_id: str) -> ExecutionResult:
         """Execute in the best available sandbox."""
-        # Try nsjail first (sovereign mode)
+        # Our own runner needs neither root nor a container daemon, but
+        # without user namespaces it only has seccomp between the program
+        # and the host: a jail with namespaces comes first in that case
+        sandbox = os.path.isfile(SANDBOX_BIN)
+        if sandbox:
+            result = await self._run_sandbox_bin(code, require_namespaces=True)
+            if result is not None:
+                return result
+            logger.warning("Sandbox cannot set up namespaces, trying nsjail")
+
+        # Try nsjail next (sovereign mode)
         if os.path.isfile(NSJAIL_BIN) and os.path.isfile(NSJAIL_CFG):
             return await self._run_nsjail(code, exercise_id)
 
@@ -183,13 +311,125 @@ class DisposableExecutor:
             logger.warning("nsjail not available, falling back to Docker sandbox")
             return await self._run_docker(code, exercise_id)
 
+        # Seccomp and rlimits still beat the bare subprocess, but neither
+        # is a jail: development only, like the subprocess
+        if sandbox and _development_mode():
+            logger.warning(
+                "No namespace jail available! Using the sandbox with seccomp only"
+            )
+            return await self._run_sandbox_bin(code, require_namespaces=False)
+
         # Fallback to restricted subprocess (development only)
         logger.warning(
             "No sandbox available! Using restricted subprocess (UNSAFE for production)"
         )
         return await self._run_subprocess(code, exercise_id)
 
-    # ── nsjail execution (primary, sovereign mode) ──────────────────────────
+    # ── Sandbox runner (tools/sandbox, primary) ──────────────────────────────
+
+    async def _run_sandbox_bin(
+        self, code: str, require_namespaces: bool
+    ) -> Optional[ExecutionResult]:
+        """Compile and run with tools/sandbox; the source goes in on stdin.
+
+        With ``require_namespaces``, returns None when the sandbox cannot
+        set up user namespaces instead of running with seccomp only.
+        """
+        args = ["--compile-timeout", str(COMPILE_TIMEOUT)]
+        args += ["--run-timeout", str(RUN_TIMEOUT)]
+        if require_namespaces:
+            args.append("--require-namespaces")
+        proc = await asyncio.create_subprocess_exec(
+            SANDBOX_BIN,
+            *args,
+            "-",
+            stdin=asyncio.subprocess.PIPE,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+        )
+        try:
+            stdout, stderr = await asyncio.wait_for(
+                proc.communicate(code.encode()),
+                timeout=COMPILE_TIMEOUT + RUN_TIMEOUT + 5,
+            )
+        except asyncio.TimeoutError:
+            proc.kill()
+            return self._sandbox_error("Sandbox did not finish in time")
+
+        # Exit 0/1 carry an outcome and 3 means no namespaces; anything
+        # else is a sandbox failure
+        if proc.returncode == 3 and require_namespaces:
+            return None
+        if proc.returncode not in (0, 1):
+            logger.error(f"Sandbox failed: {stderr.decode(errors='replace')[:500]}")
+            return self._sandbox_error("Sandbox execution failed")
+        try:
+            outcome = json.loads(stdout)
+        except json.JSONDecodeError:
+            return self._sandbox_error("Sandbox returned an invalid outcome")
+        return self._from_outcome(outcome)
+
+    def _from_outcome(self, outcome: Dict[str, Any]) -> ExecutionResult:
+        """Translate a tools/sandbox outcome into an ExecutionResult."""
+        status = outcome["status"]
+        compile_ms = outcome["compile_time_ms"]
+        run_ms = outcome["run_time_ms"]
+        stdout = outcome["stdout"]
+        stderr = sanitize_error_message(outcome["stderr"])
+        signal = outcome["signal"]
+        exit_code = outcome["exit_code"]
+        if exit_code is None:
+            exit_code = 128 + signal if signal else 1
+
+        if status == "compile_error":
+            stderr = sanitize_error_message(outcome["compile_error"] or "")
+            error = f"Compilation error:\n{stderr}"
+        elif status == "runtime_error":
+            error = f"Runtime error (exit code {exit_code}):\n{stderr}"
+        elif status == "timeout":
+            exit_code = 124
+            error = f"Execution timed out after {RUN_TIMEOUT}s"
+        elif status == "oom":
+            error = "Memory limit exceeded"
+        elif status == "signal":
+            error = f"Program killed by signal {signal}:\n{stderr}"
+        elif status == "syscall_violation":
+            error = "Security violation: blocked system call"
+        else:
+            error = stderr or None
+
+        return ExecutionResult(
+            success=status == "ok",
+            stdout=stdout,
+            stderr=stderr,
+            compile_time_ms=compile_ms,
+            run_time_ms=run_ms,
+            exit_code=exit_code,
+            sandbox_id=self.sandbox_id,
+            output=stdout,
+            error=error,
+            execution_time_ms=compile_ms + run_ms,
+            compilation_error=status == "compile_error",
+            timed_out=status == "timeout",
+            memory_exceeded=status == "oom",
+            security_violation=status == "syscall_violation",
+        )
+
+    def _sandbox_error(self, message: str) -> ExecutionResult:
+        return ExecutionResult(
+            success=False,
+            stdout="",
+            stderr=message,
+            compile_time_ms=0,
+            run_time_ms=0,
+            exit_code=1,
+            sandbox_id=self.sandbox_id,
+            output="",
+            error=message,
+            execution_time_ms=0,
+        )
+
+    # ── nsjail execution (sovereign mode) ────────────────────────────────────
 
     async def _run_nsjail(self, code: str, exercise_id: str) -> ExecutionResult:
         """Full nsjail-based disposable execution."""
@@ -271,6 +511,8 @@ class DisposableExecutor:
         bind_dst: str,
         timeout: int,
         output_bind: Optional[str] = None,
+        extra_ro_binds: Optional[list] = None,
+        env: Optional[Dict[str, str]] = None,
     ) -> dict:
         """Invoke nsjail with config file + dynamic bind mounts."""
         nsjail_cmd = [
@@ -287,6 +529,12 @@ class DisposableExecutor:
             Path(output_bind).touch()
             nsjail_cmd += ["--bindmount", f"{output_bind}:/home/user/main"]
 
+        for src, dst in extra_ro_binds or []:
+            nsjail_cmd += ["--bindmount_ro", f"{src}:{dst}"]
+
+        for key, value in (env or {}).items():
+            nsjail_cmd += ["--env", f"{key}={value}"]
+
         nsjail_cmd += ["--", *cmd]
 
         try:
@@ -318,7 +566,7 @@ class DisposableExecutor:
                 "exit_code": 124,
             }
 
-    # ── Docker execution (secondary fallback) ────────────────────────────────
+    # ── Docker execution (fallback) ──────────────────────────────────────────
 
     async def _check_docker(self) -> bool:
         """Check if Docker/Podman is available."""
@@ -445,10 +693,7 @@ class DisposableExecutor:
         import os
 
         # SECURITY FIX: Gate subprocess fallback behind explicit environment check (C-05)
-        env = os.environ.get("ENVIRONMENT", "production").lower()
-        debug = os.environ.get("DEBUG", "false").lower() == "true"
-
-        if env == "production" or (env != "development" and not debug):
+        if not _development_mode():
             logger.error(
                 "Subprocess fallback attempted in production environment - BLOCKED"
             )
@@ -628,6 +873,447 @@ class DisposableExecutor:
                 execution_time_ms=total_ms,
             )
 
+    # ── Test grading (tools/grader) ──────────────────────────────────────────
+
+    async def grade(
+        self,
+        code: str,
+        exercise_id: str = "",
+        tests_from: Optional[Union[str, Dict[str, str]]] = None,
+        hidden_tests: Optional[str] = None,
+        learner_id: Optional[str] = None,
+        files: Optional[Dict[str, str]] = None,
+        property_tests: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """Compile with --test and run every #[test]; returns the grader report.
+
+        tests_from is the trusted template source whose test modules replace
+        the submission's; hidden_tests is appended as `mod hidden_tests`,
+        and property_tests (an exercise's properties.rs) as
+        `mod property_tests`, whose failures report a shrunk counterexample.
+        Multi-file exercises pass files instead of code, and the template's
+        files as tests_from; the grader builds those with cargo.
+        With a learner_id, exercises that have a generator also get that
+        learner's own cases as `mod variant_tests`. Pointer exercises fail
+        any test that leaks heap allocations. Sorting and searching
+        exercises that pass their tests are also checked for complexity, and
+        concurrency exercises under every thread interleaving within a bound;
+        either checker's rejection fails the submission, as does any input on
+        which the fuzzer makes a string-parsing exercise panic or disagree
+        with the reference. For the testing exercises the score is the
+        mutation score of the learner's own tests, and exercise 100 also
+        needs a passing doc example on every public item. Single-file
+        submissions that compile are linted as well, and a lint error fails
+        them.
+        """
+        if files is None:
+            is_valid, validation_error = validate_code(code, exercise_id)
+        else:
+            is_valid, validation_error = validate_files(files)
+        if not is_valid:
+            return self._grade_error(validation_error or "Validation failed", True)
+
+        if not os.path.isfile(GRADER_BIN):
+            logger.error(f"Grader binary not found at {GRADER_BIN}")
+            return self._grade_error("Test grader not available")
+
+        variant_tests = None
+        if learner_id is not None and exercise_id:
+            variant_tests = await self._variant_tests(exercise_id, learner_id)
+
+        async with self._semaphore:
+            try:
+                report = await self._run_grader(
+                    code,
+                    exercise_id,
+                    tests_from,
+                    hidden_tests,
+                    variant_tests,
+                    files,
+                    property_tests,
+                )
+                if report.get("compile_error") and files is None:
+                    report["diagnostics"] = await self._explain(code)
+                elif report.get("success") and exercise_id in COMPLEXITY_EXERCISES:
+                    complexity = await self._complexity(code, exercise_id)
+                    report["complexity"] = complexity
+                    if complexity is not None and not complexity["accepted"]:
+                        report["success"] = False
+                elif report.get("success") and exercise_id in INTERLEAVE_EXERCISES:
+                    interleavings = await self._interleave(code, exercise_id)
+                    report["interleavings"] = interleavings
+                    if interleavings is not None and not interleavings["passed"]:
+                        report["success"] = False
+                elif report.get("success") and exercise_id in MUTATE_EXERCISES:
+                    # A library crate's tests all live in its src/lib.rs
+                    source = code if files is None else files.get("src/lib.rs", "")
+                    mutation = await self._mutate(source, exercise_id)
+                    report["mutation"] = mutation
+                    if mutation is not None:
+                        # The learner's tests are the work; their score is the grade
+                        report["score"] = mutation["score"]
+                        if not mutation["passed"]:
+                            report["success"] = False
+                elif report.get("success") and exercise_id in FUZZ_EXERCISES:
+                    fuzz = await self._fuzz(code, exercise_id)
+                    report["fuzz"] = fuzz
+                    # A submission the fuzzer could not run is not cleared
+                    if fuzz is not None and (not fuzz["passed"] or fuzz.get("unchecked")):
+                        report["success"] = False
+                if not report.get("compile_error") and files is None:
+                    lint = await CodeRunner.lint(code, exercise_id)
+                    report["lint"] = lint
+                    if lint is not None and not lint["passed"]:
+                        report["success"] = False
+                return report
+            finally:
+                self._cleanup()
+
+    async def _run_grader(
+        self,
+        code: str,
+        exercise_id: str,
+        tests_from: Optional[Union[str, Dict[str, str]]] = None,
+        hidden_tests: Optional[str] = None,
+        variant_tests: Optional[str] = None,
+        files: Optional[Dict[str, str]] = None,
+        property_tests: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """Run the grader inside nsjail, or bare in development only."""
+        self.sandbox_dir.mkdir(parents=True, exist_ok=True)
+        if files is None:
+            src_path = self.sandbox_dir / "main.rs"
+            src_path.write_text(code)
+        else:
+            # A multi-file submission goes in as a crate directory
+            src_path = self.sandbox_dir / "crate"
+            self._write_crate(src_path, files)
+        src_dst = f"/home/user/{src_path.name}"
+        args = ["--exercise", exercise_id] if exercise_id else []
+        if exercise_id in LEAK_CHECKED_EXERCISES:
+            args.append("--check-leaks")
+        if exercise_id in DOC_TESTED_EXERCISES and files is not None:
+            args.append("--doc-tests")
+
+        # Trusted test sources go in as separate read-only files
+        jail_args = list(args)
+        ro_binds = [(GRADER_BIN, "/home/user/grader")]
+        for flag, name, text in (
+            ("--tests-from", "template.rs", tests_from),
+            ("--hidden-tests", "hidden_tests.rs", hidden_tests),
+            ("--variant-tests", "variant_tests.rs", variant_tests),
+            ("--property-tests", "property_tests.rs", property_tests),
+        ):
+            if text is None:
+                continue
+            path = self.sandbox_dir / name
+            if isinstance(text, dict):
+                # The template of a multi-file exercise is a crate too
+                path = path.with_suffix("")
+                name = path.name
+                self._write_crate(path, text)
+            else:
+                path.write_text(text)
+            args += [flag, str(path)]
+            jail_args += [flag, f"/home/user/{name}"]
+            ro_binds.append((str(path), f"/home/user/{name}"))
+
+        if os.path.isfile(NSJAIL_BIN) and os.path.isfile(NSJAIL_CFG):
+            result = await self._nsjail_run(
+                cmd=["/home/user/grader", *jail_args, src_dst],
+                bind_src=str(src_path),
+                bind_dst=src_dst,
+                timeout=GRADE_TIMEOUT,
+                extra_ro_binds=ro_binds,
+                # /tmp is noexec inside the jail; the harness binary must run
+                env={
+                    "RUSTC": RUSTC_PATH,
+                    "CARGO": CARGO_PATH,
+                    "RUSTDOC": RUSTDOC_PATH,
+                    "CARGO_HOME": "/home/user/.cargo",
+                    "TMPDIR": "/home/user",
+                },
+            )
+        else:
+            env = os.environ.get("ENVIRONMENT", "production").lower()
+            debug = os.environ.get("DEBUG", "false").lower() == "true"
+            if env == "production" or (env != "development" and not debug):
+                logger.error("Unsandboxed grading attempted in production - BLOCKED")
+                return self._grade_error(
+                    "Sandbox not available. Code execution disabled in production.",
+                    True,
+                )
+            proc = await asyncio.create_subprocess_exec(
+                GRADER_BIN,
+                *args,
+                str(src_path),
+                stdout=asyncio.subprocess.PIPE,
+                stderr=asyncio.subprocess.PIPE,
+            )
+            try:
+                stdout, stderr = await asyncio.wait_for(
+                    proc.communicate(), timeout=GRADE_TIMEOUT
+                )
+            except asyncio.TimeoutError:
+                proc.kill()
+                return self._grade_error(f"Grading timed out after {GRADE_TIMEOUT}s")
+            result = {
+                "stdout": stdout.decode(errors="replace"),
+                "stderr": stderr.decode(errors="replace"),
+                "exit_code": proc.returncode or 0,
+            }
+
+        # Exit 0/1 carry a report; anything else is a grader failure
+        if result["exit_code"] not in (0, 1):
+            logger.error(f"Grader failed: {result['stderr'][:500]}")
+            return self._grade_error("Test grader failed")
+        try:
+            report = json.loads(result["stdout"])
+        except json.JSONDecodeError:
+            return self._grade_error("Test grader returned an invalid report")
+
+        if report.get("compile_error"):
+            report["compile_error"] = sanitize_error_message(report["compile_error"])
+        # Hidden and generated tests report pass/fail only; their assertions
+        # stay private, or a failed run would leak the expected values.
+        # Property tests keep theirs: the counterexample is the feedback.
+        for test in report.get("tests", []):
+            if test["name"].startswith(("hidden_tests::", "variant_tests::")):
+                test["panic_message"] = None
+        report["success"] = result["exit_code"] == 0
+        report["sandbox_id"] = self.sandbox_id
+        return report
+
+    # ── Per-learner tests (tools/variant) ────────────────────────────────────
+
+    async def _variant_tests(
+        self, exercise_id: str, learner_id: str
+    ) -> Optional[str]:
+        """The learner's generated test module, or None if there is none.
+
+        The generator only runs trusted code, so it needs no sandbox. Exit 1
+        means the exercise has no generator.
+        """
+        if not os.path.isfile(VARIANT_BIN):
+            return None
+        proc = await asyncio.create_subprocess_exec(
+            VARIANT_BIN,
+            "--exercise",
+            exercise_id,
+            "--learner",
+            learner_id,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+        )
+        try:
+            stdout, stderr = await asyncio.wait_for(
+                proc.communicate(), timeout=VARIANT_TIMEOUT
+            )
+        except asyncio.TimeoutError:
+            proc.kill()
+            logger.warning(f"Variant generator timed out for {exercise_id}")
+            return None
+        if proc.returncode == 1:
+            return None
+        if proc.returncode != 0:
+            message = stderr.decode(errors="replace")[:500]
+            logger.warning(f"Variant generator failed: {message}")
+            return None
+        return stdout.decode()
+
+    # ── Submission checkers (tools/complexity, interleave, mutate, fuzz) ─────
+
+    async def _complexity(
+        self, code: str, exercise_id: str
+    ) -> Optional[Dict[str, Any]]:
+        """The complexity checker's report on how the submission's cost grows."""
+        report = await self._run_checker(
+            COMPLEXITY_BIN, ["--exercise", exercise_id], code, COMPLEXITY_TIMEOUT
+        )
+        if report is not None:
+            report["problems"] = [
+                sanitize_error_message(p) for p in report["problems"]
+            ]
+        return report
+
+    async def _interleave(
+        self, code: str, exercise_id: str
+    ) -> Optional[Dict[str, Any]]:
+        """The model checker's report on the exercise's thread scenarios."""
+        report = await self._run_checker(
+            INTERLEAVE_BIN, ["--exercise", exercise_id], code, INTERLEAVE_TIMEOUT
+        )
+        if report is not None and report.get("unchecked"):
+            report["unchecked"] = sanitize_error_message(report["unchecked"])
+        return report
+
+    async def _mutate(
+        self, code: str, exercise_id: str
+    ) -> Optional[Dict[str, Any]]:
+        """The mutation tester's report on how well the learner's tests catch bugs."""
+        report = await self._run_checker(
+            MUTATE_BIN, ["--exercise", exercise_id], code, MUTATE_TIMEOUT
+        )
+        if report is not None and report.get("problem"):
+            report["problem"] = sanitize_error_message(report["problem"])
+        return report
+
+    async def _fuzz(self, code: str, exercise_id: str) -> Optional[Dict[str, Any]]:
+        """The fuzzer's report on inputs that break the learner's parsers."""
+        report = await self._run_checker(
+            FUZZ_BIN, ["--exercise", exercise_id], code, FUZZ_TIMEOUT
+        )
+        if report is not None:
+            for failure in report["failures"]:
+                if failure.get("message"):
+                    failure["message"] = sanitize_error_message(failure["message"])
+            if report.get("unchecked"):
+                report["unchecked"] = sanitize_error_message(report["unchecked"])
+        return report
+
+    async def _run_checker(
+        self, binary: str, args: List[str], code: str, timeout: int
+    ) -> Optional[Dict[str, Any]]:
+        """Run a checker that compiles and runs the submission; its JSON report.
+
+        Best effort: returns None if the checker is missing or fails, and the
+        test result stands on its own. Exit 0/1 both carry a report.
+        """
+        if not os.path.isfile(binary):
+            return None
+        name = os.path.basename(binary)
+
+        self.sandbox_dir.mkdir(parents=True, exist_ok=True)
+        src_path = self.sandbox_dir / f"{name}.rs"
+        src_path.write_text(code)
+        if os.path.isfile(NSJAIL_BIN) and os.path.isfile(NSJAIL_CFG):
+            result = await self._nsjail_run(
+                cmd=[f"/home/user/{name}", *args, "/home/user/main.rs"],
+                bind_src=str(src_path),
+                bind_dst="/home/user/main.rs",
+                timeout=timeout,
+                extra_ro_binds=[(binary, f"/home/user/{name}")],
+                # /tmp is noexec inside the jail; the harness binary must run
+                env={"RUSTC": RUSTC_PATH, "TMPDIR": "/home/user"},
+            )
+        else:
+            # grade() already refused to run unsandboxed outside development
+            proc = await asyncio.create_subprocess_exec(
+                binary,
+                *args,
+                str(src_path),
+                stdout=asyncio.subprocess.PIPE,
+                stderr=asyncio.subprocess.PIPE,
+            )
+            try:
+                stdout, stderr = await asyncio.wait_for(
+                    proc.communicate(), timeout=timeout
+                )
+            except asyncio.TimeoutError:
+                proc.kill()
+                return None
+            result = {
+                "stdout": stdout.decode(errors="replace"),
+                "stderr": stderr.decode(errors="replace"),
+                "exit_code": proc.returncode or 0,
+            }
+
+        if result["exit_code"] not in (0, 1):
+            logger.warning(f"{name} failed: {result['stderr'][:500]}")
+            return None
+        try:
+            return json.loads(result["stdout"])
+        except json.JSONDecodeError:
+            return None
+
+    # ── Diagnostics explainer (tools/explain) ────────────────────────────────
+
+    async def _explain(self, code: str) -> Optional[List[Dict[str, Any]]]:
+        """Structured rustc errors, each with the exercise to revisit.
+
+        Best effort: returns None if the explainer is missing or fails, and
+        callers keep the plain compiler output either way.
+        """
+        if not os.path.isfile(EXPLAIN_BIN):
+            return None
+
+        self.sandbox_dir.mkdir(parents=True, exist_ok=True)
+        src_path = self.sandbox_dir / "explain.rs"
+        src_path.write_text(code)
+        if os.path.isfile(NSJAIL_BIN) and os.path.isfile(NSJAIL_CFG):
+            result = await self._nsjail_run(
+                cmd=[
+                    "/home/user/explain",
+                    "--timeout",
+                    str(COMPILE_TIMEOUT),
+                    "/home/user/main.rs",
+                ],
+                bind_src=str(src_path),
+                bind_dst="/home/user/main.rs",
+                timeout=COMPILE_TIMEOUT + 2,
+                extra_ro_binds=[(EXPLAIN_BIN, "/home/user/explain")],
+                env={"RUSTC": RUSTC_PATH, "TMPDIR": "/home/user"},
+            )
+        else:
+            # Only type- and borrow-checks, like the sandbox's own compile step
+            proc = await asyncio.create_subprocess_exec(
+                EXPLAIN_BIN,
+                "--timeout",
+                str(COMPILE_TIMEOUT),
+                str(src_path),
+                stdout=asyncio.subprocess.PIPE,
+                stderr=asyncio.subprocess.PIPE,
+            )
+            try:
+                stdout, stderr = await asyncio.wait_for(
+                    proc.communicate(), timeout=COMPILE_TIMEOUT + 2
+                )
+            except asyncio.TimeoutError:
+                proc.kill()
+                return None
+            result = {
+                "stdout": stdout.decode(errors="replace"),
+                "stderr": stderr.decode(errors="replace"),
+                "exit_code": proc.returncode or 0,
+            }
+
+        # Exit 0/1 carry the diagnostics; anything else is an explainer failure
+        if result["exit_code"] not in (0, 1):
+            logger.warning(f"Explainer failed: {result['stderr'][:500]}")
+            return None
+        try:
+            diagnostics = json.loads(result["stdout"])["diagnostics"]
+        except (json.JSONDecodeError, KeyError, TypeError):
+            return None
+        for diagnostic in diagnostics:
+            diagnostic["message"] = sanitize_error_message(diagnostic["message"])
+            diagnostic["rendered"] = sanitize_error_message(diagnostic["rendered"])
+        return diagnostics
+
+    def _grade_error(self, message: str, security_violation: bool = False) -> dict:
+        return {
+            "success": False,
+            "compiled": False,
+            "compile_error": None,
+            "error": message,
+            "passed": 0,
+            "failed": 0,
+            "ignored": 0,
+            "total": 0,
+            "score": 0.0,
+            "tests": [],
+            "security_violation": security_violation,
+            "sandbox_id": self.sandbox_id,
+        }
+
+    @staticmethod
+    def _write_crate(root: Path, files: Dict[str, str]) -> None:
+        """Write a multi-file submission's paths (already validated) under root."""
+        for rel, text in files.items():
+            path = root / rel
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(text)
+
     def _cleanup(self):
         """Destroy the ephemeral sandbox. No trace left."""
         try:
@@ -683,6 +1369,105 @@ class CodeRunner:
             "sandbox_id": result.sandbox_id,
         }
 
+    @staticmethod
+    async def lint(code: str, exercise_id: Union[int, str]) -> Optional[Dict[str, Any]]:
+        """The lint pack's report on a submission: findings with a line,
+        column, message and a level of "warning" or "error".
+
+        Best effort: returns None if the linter is missing or fails, e.g. on
+        code that does not parse.
+        """
+        if not os.path.isfile(LINT_BIN):
+            return None
+        proc = await asyncio.create_subprocess_exec(
+            LINT_BIN,
+            "--exercise",
+            str(exercise_id or 0),
+            "-",
+            stdin=asyncio.subprocess.PIPE,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+        )
+        try:
+            stdout, stderr = await asyncio.wait_for(
+                proc.communicate(code.encode()), timeout=LINT_TIMEOUT
+            )
+        except asyncio.TimeoutError:
+            proc.kill()
+            logger.warning("Linter timed out")
+            return None
+        if proc.returncode not in (0, 1):
+            message = stderr.decode(errors="replace")[:500]
+            logger.warning(f"Linter failed: {message}")
+            return None
+        try:
+            return json.loads(stdout)
+        except json.JSONDecodeError:
+            logger.warning("Linter printed invalid JSON")
+            return None
+
+    @staticmethod
+    async def check_output(output: str, rules: str) -> Optional[Dict[str, Any]]:
+        """The output checker's report on `output` against an expected.toml.
+
+        Best effort: returns None if the checker is missing or fails, e.g. on
+        a malformed rules file.
+        """
+        if not os.path.isfile(OUTCHECK_BIN):
+            return None
+        with tempfile.NamedTemporaryFile(
+            "w", suffix=".toml", encoding="utf-8"
+        ) as rules_file:
+            rules_file.write(rules)
+            rules_file.flush()
+            proc = await asyncio.create_subprocess_exec(
+                OUTCHECK_BIN,
+                rules_file.name,
+                "-",
+                stdin=asyncio.subprocess.PIPE,
+                stdout=asyncio.subprocess.PIPE,
+                stderr=asyncio.subprocess.PIPE,
+            )
+            try:
+                stdout, stderr = await asyncio.wait_for(
+                    proc.communicate(output.encode()), timeout=OUTCHECK_TIMEOUT
+                )
+            except asyncio.TimeoutError:
+                proc.kill()
+                logger.warning("Output checker timed out")
+                return None
+        if proc.returncode not in (0, 1):
+            message = stderr.decode(errors="replace")[:500]
+            logger.warning(f"Output checker failed: {message}")
+            return None
+        try:
+            return json.loads(stdout)
+        except json.JSONDecodeError:
+            logger.warning("Output checker printed invalid JSON")
+            return None
+
+    @staticmethod
+    async def grade_tests(
+        code: str,
+        exercise_id: Optional[int] = None,
+        tests_from: Optional[Union[str, Dict[str, str]]] = None,
+        hidden_tests: Optional[str] = None,
+        learner_id: Optional[int] = None,
+        files: Optional[Dict[str, str]] = None,
+        property_tests: Optional[str] = None,
+    ) -> Dict[str, Any]:
+        """Grade a solution by its #[test] functions instead of stdout."""
+        executor = DisposableExecutor()
+        return await executor.grade(
+            code,
+            f"{exercise_id:03d}" if exercise_id else "",
+            tests_from=tests_from,
+            hidden_tests=hidden_tests,
+            learner_id=str(learner_id) if learner_id is not None else None,
+            files=files,
+            property_tests=property_tests,
+        )
+
 
 def get_code_runner() -> CodeRunner:
     """Get code runner instance."""
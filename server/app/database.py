@@ -9,6 +9,7 @@ Database configuration and session management.
 import asyncio
 from typing import AsyncGenerator
 
+from sqlalchemy import inspect, text
 from sqlalchemy.ext.asyncio import AsyncSession, create_async_engine, async_sessionmaker
 from sqlalchemy.orm import declarative_base
 from sqlalchemy.pool import NullPool
@@ -74,8 +75,27 @@ async def get_db() -> AsyncGenerator[AsyncSession, None]:
             await session.close()
 
 
+# Columns added to existing tables since their first release, as (table,
+# column, type and default). create_all only creates missing tables, so
+# init_db adds these to a database that predates them.
+ADDED_COLUMNS = [
+    ("user_progress", "hint_usage", "JSON NOT NULL DEFAULT '{}'"),
+]
+
+
+def add_missing_columns(conn) -> None:
+    """ALTER TABLE in each of ADDED_COLUMNS that an existing table lacks."""
+    inspector = inspect(conn)
+    for table, column, definition in ADDED_COLUMNS:
+        if not inspector.has_table(table):
+            continue
+        if column not in {c["name"] for c in inspector.get_columns(table)}:
+            conn.execute(text(f"ALTER TABLE {table} ADD COLUMN {column} {definition}"))
+
+
 async def init_db(max_retries: int = 5, base_delay: float = 1.0) -> None:
-    """Initialize database tables with retry logic.
+    """Initialize database tables with retry logic, and add the columns
+    newer models have to tables an older release created.
 
     Args:
         max_retries: Maximum number of connection attempts
@@ -87,6 +107,7 @@ async def init_db(max_retries: int = 5, base_delay: float = 1.0) -> None:
         try:
             async with engine.begin() as conn:
                 await conn.run_sync(Base.metadata.create_all)
+                await conn.run_sync(add_missing_columns)
             return  # Success
         except Exception as e:
             last_exception = e
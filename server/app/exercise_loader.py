@@ -1,14 +1,17 @@
 """
 Exercise Loader Service
 
-Loads exercises from /problems/ directory, parses metadata from template.rs files,
-and provides caching for fast access.
+Loads exercises from /problems/ directory, parses metadata from template.rs files
+(or src/lib.rs for the exercises that are multi-file Cargo crates), and provides
+caching for fast access.
 """
 
+import json
 import os
 import re
+import subprocess
 from pathlib import Path
-from typing import Optional, List, Dict, Any
+from typing import Optional, List, Dict, Any, Tuple
 from dataclasses import dataclass, field
 import logging
 
@@ -45,6 +48,50 @@ EXERCISE_PATTERN = re.compile(r"//\s*Exercise\s*(\d+):\s*(.+)")
 LEARNING_OBJ_PATTERN = re.compile(r"//\s*Learning\s*[Oo]bjective:?\s*(.+)")
 DIFFICULTY_PATTERN = re.compile(r"//\s*Difficulty:\s*(\d+)")
 
+# Hint splitter (tools/hints) -- takes the tiered hint blocks out of a
+# template, so that learners unlock them one failed submission at a time
+HINTS_BIN = os.environ.get("HINTS_BIN", "/usr/local/bin/hints")
+HINTS_TIMEOUT = 5  # seconds
+HINTS_MARKER = "HINTS `"
+TIER_PATTERN = re.compile(r"(\s*)\d+(?: after \d+)?:")
+
+
+def _strip_hint_blocks(source: str) -> str:
+    """`source` without its hint blocks, for when the splitter fails.
+
+    Follows tools/hints: a ``HINTS `name` `` line, then tier lines
+    (``N: text`` or ``N after K: text``), each followed by the comment
+    lines indented past its number.
+    """
+
+    def comment(line: str) -> Optional[str]:
+        text = line.strip()
+        if not text.startswith("//") or text.startswith(("///", "//!")):
+            return None
+        return line.lstrip()[2:].rstrip("\r\n")
+
+    lines = source.splitlines(keepends=True)
+    kept = []
+    i = 0
+    while i < len(lines):
+        text = comment(lines[i])
+        i += 1
+        if text is None or not text.strip().startswith(HINTS_MARKER):
+            kept.append(lines[i - 1])
+            continue
+        while i < len(lines):
+            tier = TIER_PATTERN.match(comment(lines[i]) or "")
+            if tier is None:
+                break
+            column = len(tier.group(1))
+            i += 1
+            while i < len(lines):
+                body = comment(lines[i])
+                if not body or not body.strip() or len(body) - len(body.lstrip()) <= column:
+                    break
+                i += 1
+    return "".join(kept)
+
 
 @dataclass
 class Exercise:
@@ -56,6 +103,20 @@ class Exercise:
     template_code: str
     concepts: List[str] = field(default_factory=list)
     bonus: Optional[str] = None
+    # Contents of the exercise's tests.rs; graded but never sent to learners
+    hidden_tests: Optional[str] = None
+    # Contents of the exercise's properties.rs, invariants checked on
+    # generated inputs (tools/grader/src/property.rs)
+    property_tests: Optional[str] = None
+    # Contents of the exercise's expected.toml, rules its program's output
+    # must follow (tools/outcheck)
+    expected_rules: Optional[str] = None
+    # Multi-file exercises: every .rs file under src/ by path, e.g.
+    # "src/garden/vegetables.rs"; None for single-file exercises
+    files: Optional[Dict[str, str]] = None
+    # Hint tiers taken out of the template: function, number, after (the
+    # failed submissions that unlock it) and text, one entry per line
+    hints: List[Dict[str, Any]] = field(default_factory=list)
 
     def to_dict(self) -> Dict[str, Any]:
         return {
@@ -94,6 +155,7 @@ class ExerciseLoader:
         except Exception as e:
             logger.error(f"Failed to read {file_path}: {e}")
             return None
+        content, hints = self._split_hints(content)
 
         lines = content.split("\n")
         header_lines = []
@@ -138,6 +200,7 @@ class ExerciseLoader:
             template_code="\n".join(template_code),
             concepts=concepts,
             bonus=bonus,
+            hints=hints,
         )
 
     def _extract_category(self, header: str, title: str) -> str:
@@ -230,14 +293,78 @@ class ExerciseLoader:
                     continue
 
                 template_path = entry / "template.rs"
+                files = None
+                if not template_path.exists() and (entry / "Cargo.toml").exists():
+                    # A Cargo crate; its header is at the top of src/lib.rs
+                    template_path = entry / "src" / "lib.rs"
+                    files = self._read_crate_files(entry)
                 if template_path.exists():
                     exercise = self._parse_exercise_file(exercise_id, template_path)
                     if exercise:
+                        if files is not None:
+                            exercise.hints = []
+                            for path, source in files.items():
+                                files[path], tiers = self._split_hints(source)
+                                exercise.hints.extend(tiers)
+                            exercise.files = files
+                            exercise.template_code = files["src/lib.rs"]
+                        hidden_path = entry / "tests.rs"
+                        if hidden_path.exists():
+                            exercise.hidden_tests = hidden_path.read_text(
+                                encoding="utf-8"
+                            )
+                        properties_path = entry / "properties.rs"
+                        if properties_path.exists():
+                            exercise.property_tests = properties_path.read_text(
+                                encoding="utf-8"
+                            )
+                        expected_path = entry / "expected.toml"
+                        if expected_path.exists():
+                            exercise.expected_rules = expected_path.read_text(
+                                encoding="utf-8"
+                            )
                         self._exercises[exercise_id] = exercise
                         logger.debug(f"Loaded exercise {exercise_id}: {exercise.title}")
 
         logger.info(f"Loaded {len(self._exercises)} exercises")
 
+    @staticmethod
+    def _split_hints(source: str) -> Tuple[str, List[Dict[str, Any]]]:
+        """The template without its hint blocks, and their tiers.
+
+        On failure the blocks are still stripped, and no hints are served.
+        """
+        if HINTS_MARKER not in source:
+            return source, []
+        try:
+            proc = subprocess.run(
+                [HINTS_BIN, "-"],
+                input=source,
+                capture_output=True,
+                text=True,
+                timeout=HINTS_TIMEOUT,
+            )
+        except (OSError, subprocess.TimeoutExpired) as e:
+            logger.error(f"Hint splitter failed to run: {e}")
+            return _strip_hint_blocks(source), []
+        if proc.returncode != 0:
+            logger.error(f"Hint splitter rejected a template: {proc.stderr.strip()}")
+            return _strip_hint_blocks(source), []
+        try:
+            split = json.loads(proc.stdout)
+            return split["template"], split["tiers"]
+        except (json.JSONDecodeError, KeyError, TypeError) as e:
+            logger.error(f"Hint splitter printed no usable split: {e}")
+            return _strip_hint_blocks(source), []
+
+    @staticmethod
+    def _read_crate_files(crate_dir: Path) -> Dict[str, str]:
+        """Every .rs file under the crate's src/, keyed by path from the crate root."""
+        return {
+            path.relative_to(crate_dir).as_posix(): path.read_text(encoding="utf-8")
+            for path in sorted((crate_dir / "src").rglob("*.rs"))
+        }
+
     def get_exercise(self, exercise_id: int) -> Optional[Exercise]:
         return self._exercises.get(exercise_id)
 
@@ -96,6 +96,10 @@ class UserProgress(Base):
     # Problem-specific code storage (optional - for cloud sync)
     saved_code = Column(JSON, default=dict, nullable=False)  # {problem_id: code}
 
+    # Failed submissions, which unlock an exercise's hint tiers, and the
+    # tiers shown, for instructors: {problem_id: {"failed": n, "hints": [...]}}
+    hint_usage = Column(JSON, default=dict, nullable=False)
+
     # Timestamps
     created_at = Column(
         DateTime(timezone=True), server_default=func.now(), nullable=False
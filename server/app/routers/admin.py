@@ -230,6 +230,8 @@ async def get_user_details(
             "solved_problems_count": len(progress.solved_problems) if progress else 0,
             "current_streak": progress.current_streak if progress else 0,
             "longest_streak": progress.longest_streak if progress else 0,
+            # Failed submissions and hint tiers shown, by exercise
+            "hint_usage": progress.hint_usage if progress else {},
         }
         if progress
         else None,
@@ -8,10 +8,11 @@ New v4 features: cursor pagination, advanced filtering, GraphQL preparation.
 from fastapi import APIRouter, Depends, HTTPException, Request, status
 from pydantic import BaseModel
 from sqlalchemy.ext.asyncio import AsyncSession
-from typing import List, Optional
+from typing import Dict, List, Optional
 
 from app.database import get_db
 from app.auth_deps import get_current_user, optional_user
+from app.auth_service import get_user_progress
 from app.models import User
 from app.exercise_loader import get_exercise_loader
 from app.code_runner import get_code_runner
@@ -25,7 +26,9 @@ router = APIRouter(prefix="/v4", tags=["v4"])
 
 
 class RunCodeRequest(BaseModel):
-    code: str
+    code: str = ""
+    # Multi-file exercises: contents by path under src/, e.g. "src/lib.rs"
+    files: Optional[Dict[str, str]] = None
 
 
 class RunCodeResponse(BaseModel):
@@ -33,10 +36,14 @@ class RunCodeResponse(BaseModel):
     output: str
     error: Optional[str] = None
     execution_time_ms: int
+    # Compile errors with span, suggested fix and exercise to revisit
+    diagnostics: Optional[List[dict]] = None
 
 
 class SubmitCodeRequest(BaseModel):
-    code: str
+    code: str = ""
+    # Multi-file exercises: contents by path under src/, e.g. "src/lib.rs"
+    files: Optional[Dict[str, str]] = None
 
 
 class SubmitCodeResponse(BaseModel):
@@ -46,6 +53,9 @@ class SubmitCodeResponse(BaseModel):
     expected_output: Optional[str] = None
     error: Optional[str] = None
     execution_time_ms: int
+    tests: Optional[List[dict]] = None
+    score: Optional[float] = None
+    diagnostics: Optional[List[dict]] = None
 
 
 # =============================================================================
@@ -148,6 +158,8 @@ async def get_exercise_v4(
         "concepts": exercise.concepts,
         "bonus": exercise.bonus,
         "template_code": exercise.template_code,
+        # Multi-file exercises: every file of the crate's src/ by path
+        "files": exercise.files,
     }
 
 
@@ -161,6 +173,13 @@ async def run_exercise_code(
     db: AsyncSession = Depends(get_db),
 ):
     """Run exercise code in sandboxed environment."""
+    if request.files is not None:
+        # The sandbox runs single files; crates are built by the grader
+        raise HTTPException(
+            status_code=400,
+            detail="Multi-file exercises are checked with submit",
+        )
+
     if not request.code or len(request.code.strip()) == 0:
         raise HTTPException(status_code=400, detail="Code is required")
 
@@ -177,6 +196,7 @@ async def run_exercise_code(
         output=result.output,
         error=result.error,
         execution_time_ms=result.execution_time_ms,
+        diagnostics=result.diagnostics,
     )
 
 
@@ -189,36 +209,194 @@ async def submit_exercise(
     current_user: User = Depends(get_current_user),
     db: AsyncSession = Depends(get_db),
 ):
-    """Submit exercise solution for grading."""
-    if not request.code or len(request.code.strip()) == 0:
-        raise HTTPException(status_code=400, detail="Code is required")
+    """Submit exercise solution for grading.
 
+    A failed submission counts towards unlocking the exercise's hints.
+    """
+    response = await _grade_submission(exercise_id, request, current_user)
+    if not response.success:
+        progress = await get_user_progress(db, current_user.id)
+        usage = dict(progress.hint_usage or {})
+        entry = dict(usage.get(str(exercise_id), {}))
+        entry["failed"] = entry.get("failed", 0) + 1
+        usage[str(exercise_id)] = entry
+        progress.hint_usage = usage
+        await db.commit()
+    return response
+
+
+@router.post("/exercises/{exercise_id}/hints")
+@limiter.limit("30/minute")
+async def reveal_hints(
+    exercise_id: int,
+    request_obj: Request,  # Required for rate limiting
+    current_user: User = Depends(get_current_user),
+    db: AsyncSession = Depends(get_db),
+):
+    """The exercise's hint tiers that failed submissions have unlocked.
+
+    Tiers are stripped from the template and unlock one after another as
+    failed submissions add up; each one returned is recorded as shown, so
+    instructors can see how much help a learner needed.
+    """
     loader = get_exercise_loader()
     exercise = loader.get_exercise(exercise_id)
 
     if not exercise:
         raise HTTPException(status_code=404, detail=f"Exercise {exercise_id} not found")
 
+    progress = await get_user_progress(db, current_user.id)
+    usage = dict(progress.hint_usage or {})
+    entry = dict(usage.get(str(exercise_id), {}))
+    failed = entry.get("failed", 0)
+
+    unlocked = [t for t in exercise.hints if t["after"] <= failed]
+    shown = set(entry.get("hints", []))
+    ids = {f"{t['function']}/{t['number']}" for t in unlocked}
+    if not ids <= shown:
+        entry["hints"] = sorted(shown | ids)
+        usage[str(exercise_id)] = entry
+        progress.hint_usage = usage
+        await db.commit()
+
+    locked = [t["after"] for t in exercise.hints if t["after"] > failed]
+    return {
+        "failed_attempts": failed,
+        "hints": [
+            {"function": t["function"], "number": t["number"], "text": t["text"]}
+            for t in unlocked
+        ],
+        # Failed submissions until the next tier unlocks; None once all have
+        "attempts_to_next_hint": min(locked) - failed if locked else None,
+    }
+
+
+async def _grade_submission(
+    exercise_id: int, request: SubmitCodeRequest, current_user: User
+) -> SubmitCodeResponse:
+    """Grades a submission; the response says whether it passed."""
+    loader = get_exercise_loader()
+    exercise = loader.get_exercise(exercise_id)
+
+    if not exercise:
+        raise HTTPException(status_code=404, detail=f"Exercise {exercise_id} not found")
+
+    if exercise.files is not None:
+        if not request.files:
+            raise HTTPException(status_code=400, detail="Files are required")
+    elif not request.code or len(request.code.strip()) == 0:
+        raise HTTPException(status_code=400, detail="Code is required")
+
     runner = get_code_runner()
 
     difficulty = exercise.difficulty
     base_xp = {1: 10, 2: 20, 3: 30, 4: 50, 5: 100}
     xp_earned = base_xp.get(difficulty, 10)
 
+    # Exercises that ship #[test] functions are scored by those tests. The
+    # template's copy replaces whatever tests the learner left in place;
+    # for a multi-file exercise, file by file.
+    if exercise.files is not None:
+        report = await runner.grade_tests(
+            "",
+            exercise_id,
+            tests_from=exercise.files,
+            hidden_tests=exercise.hidden_tests,
+            property_tests=exercise.property_tests,
+            learner_id=current_user.id,
+            files=request.files,
+        )
+        return _graded_response(report, xp_earned)
+
+    if (
+        "#[cfg(test)]" in exercise.template_code
+        or exercise.hidden_tests
+        or exercise.property_tests
+    ):
+        report = await runner.grade_tests(
+            request.code,
+            exercise_id,
+            tests_from=exercise.template_code,
+            hidden_tests=exercise.hidden_tests,
+            property_tests=exercise.property_tests,
+            learner_id=current_user.id,
+        )
+        # Passing tests do not show that main prints what it should
+        if report["success"] and exercise.expected_rules:
+            result = await runner.run_code(request.code, exercise_id)
+            if result.success:
+                error = await _output_error(runner, result, exercise.expected_rules)
+            else:
+                error = result.error
+            if error:
+                report = {**report, "success": False, "error": error}
+        return _graded_response(report, xp_earned)
+
     result = await runner.run_code(request.code, exercise_id)
 
     success = result.success
+    error = result.error
+    if success and exercise.expected_rules:
+        output_error = await _output_error(runner, result, exercise.expected_rules)
+        if output_error:
+            success = False
+            error = output_error
+    if success:
+        lint_error = _lint_error(await runner.lint(request.code, exercise_id))
+        if lint_error:
+            success = False
+            error = lint_error
 
     return SubmitCodeResponse(
         success=success,
         xp_earned=xp_earned if success else 0,
         output=result.output,
         expected_output=None,
-        error=result.error,
+        error=error,
         execution_time_ms=result.execution_time_ms,
+        diagnostics=result.diagnostics,
+    )
+
+
+def _graded_response(report: dict, xp_earned: int) -> SubmitCodeResponse:
+    """The submit response for a grader report."""
+    success = report["success"]
+    failing = [t["name"] for t in report["tests"] if t["status"] != "passed"]
+    error = report.get("compile_error") or report.get("error")
+    if not error and failing:
+        error = "Failing tests: " + ", ".join(failing)
+    if not error:
+        error = _lint_error(report.get("lint"))
+    return SubmitCodeResponse(
+        success=success,
+        xp_earned=xp_earned if success else 0,
+        output=f"{report['passed']}/{report['total']} tests passed",
+        expected_output=None,
+        error=error,
+        execution_time_ms=sum(t["duration_ms"] for t in report["tests"]),
+        tests=report["tests"],
+        score=report["score"],
+        diagnostics=report.get("diagnostics"),
     )
 
 
+async def _output_error(runner, result, rules: str) -> Optional[str]:
+    """Why a run's output breaks an exercise's expected.toml, or None if it
+    matches or the checker is unavailable."""
+    check = await runner.check_output(result.stdout or result.output or "", rules)
+    if check is None or check["passed"]:
+        return None
+    return "Output does not match what the exercise expects:\n" + check["diff"]
+
+
+def _lint_error(lint: Optional[dict]) -> Optional[str]:
+    """The lint pack's errors, one per line, or None if there are none."""
+    errors = [f for f in (lint or {}).get("findings", []) if f["level"] == "error"]
+    if not errors:
+        return None
+    return "\n".join(f"line {f['line']}:{f['column']}: {f['message']}" for f in errors)
+
+
 # =============================================================================
 # User Profile (v4)
 # =============================================================================
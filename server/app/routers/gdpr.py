@@ -95,6 +95,7 @@ async def collect_user_data(db: AsyncSession, user_id: int) -> Dict[str, Any]:
             "current_streak": progress.current_streak if progress else 0,
             "longest_streak": progress.longest_streak if progress else 0,
             "last_solved_date": progress.last_solved_date.isoformat() if progress and progress.last_solved_date else None,
+            "hint_usage": progress.hint_usage if progress else {},
         } if progress else None,
         "school_profile": {
             "role": school_profile.role.value if school_profile else None,
@@ -0,0 +1,5 @@
+// Reference solution for exercise 001: Hello, Rust!
+
+fn main() {
+    println!("Hello, Rust!");
+}
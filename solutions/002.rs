@@ -0,0 +1,10 @@
+// Reference solution for exercise 002: Variables and Mutability
+
+fn main() {
+    let mut score = 10;
+    println!("The initial score is: {}", score);
+
+    score = 20;
+
+    println!("The score is: {}", score);
+}
@@ -0,0 +1,32 @@
+// Reference solution for exercise 003: Data Types
+
+fn main() {
+    println!("🧪 Rust Type System Explorer\n");
+
+    let age: i32 = 25;
+    println!("Age: {} (type: i32)", age);
+
+    let student_count: u32 = 150;
+    println!("Students: {} (type: u32)", student_count);
+
+    let pi: f64 = 3.14159;
+    println!("Pi: {:.5} (type: f64)", pi);
+
+    let temperature: f64 = 72.5;
+    println!("Temperature: {}°F", temperature);
+
+    let is_rust_fun: bool = true;
+    println!("Is Rust fun? {}", is_rust_fun);
+
+    let is_logged_in: bool = false;
+    println!("Logged in: {}", is_logged_in);
+
+    let initial: char = 'R';
+    println!("Initial: {}", initial);
+
+    let emoji: char = '🚀';
+    println!("Favorite emoji: {}", emoji);
+
+    let inferred = 42;
+    println!("\n✨ Bonus: Type inference works too! inferred = {}", inferred);
+}
@@ -0,0 +1,15 @@
+// Reference solution for exercise 004: String vs &str
+
+fn main() {
+    let owned_string = String::from("Hello");
+
+    let string_slice: &str = &owned_string;
+
+    println!("Owned String: {}", owned_string);
+    println!("String slice: {}", string_slice);
+
+    let mut mutable_string = String::from("Hello");
+    mutable_string.push_str(", World!");
+
+    println!("Modified string: {}", mutable_string);
+}
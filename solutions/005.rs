@@ -0,0 +1,14 @@
+// Reference solution for exercise 005: Constants
+
+const MAX_SCORE: i32 = 100;
+const PI: f64 = 3.14159;
+const WELCOME_MESSAGE: &str = "Welcome to Rust!";
+
+fn main() {
+    println!("Maximum score: {}", MAX_SCORE);
+    println!("PI value: {}", PI);
+    println!("Message: {}", WELCOME_MESSAGE);
+
+    let percentage = (75.0 / MAX_SCORE as f64) * 100.0;
+    println!("75 is {:.1}% of {}", percentage, MAX_SCORE);
+}
@@ -0,0 +1,21 @@
+// Reference solution for exercise 006: Arithmetic Operations
+
+fn main() {
+    let a = 15;
+    let b = 4;
+
+    let sum = a + b;
+    println!("{} + {} = {}", a, b, sum);
+
+    let difference = a - b;
+    println!("{} - {} = {}", a, b, difference);
+
+    let product = a * b;
+    println!("{} * {} = {}", a, b, product);
+
+    let quotient = a / b;
+    println!("{} / {} = {}", a, b, quotient);
+
+    let remainder = a % b;
+    println!("{} % {} = {}", a, b, remainder);
+}
@@ -0,0 +1,24 @@
+// Reference solution for exercise 007: Comparison Operators
+
+fn main() {
+    let x = 10;
+    let y = 20;
+
+    let is_equal = x == y;
+    println!("{} == {} ? {}", x, y, is_equal);
+
+    let is_not_equal = x != y;
+    println!("{} != {} ? {}", x, y, is_not_equal);
+
+    let is_less = x < y;
+    println!("{} < {} ? {}", x, y, is_less);
+
+    let is_greater = x > y;
+    println!("{} > {} ? {}", x, y, is_greater);
+
+    let is_less_or_equal = x <= 10;
+    println!("{} <= 10 ? {}", x, is_less_or_equal);
+
+    let is_greater_or_equal = y >= 20;
+    println!("{} >= 20 ? {}", y, is_greater_or_equal);
+}
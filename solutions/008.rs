@@ -0,0 +1,22 @@
+// Reference solution for exercise 008: Boolean Logic
+
+fn main() {
+    let sunny = true;
+    let warm = true;
+    let weekend = false;
+
+    let beach_weather = sunny && warm;
+    println!("Good beach weather? {}", beach_weather);
+
+    let nice_day = sunny || warm;
+    println!("Nice day? {}", nice_day);
+
+    let can_relax = weekend || warm;
+    println!("Can relax? {}", can_relax);
+
+    let is_weekday = !weekend;
+    println!("Is it a weekday? {}", is_weekday);
+
+    let perfect_workday = sunny && warm && !weekend;
+    println!("Perfect workday weather? {}", perfect_workday);
+}
@@ -0,0 +1,25 @@
+// Reference solution for exercise 009: Simple if/else
+
+fn main() {
+    let number = 7;
+
+    if number > 0 {
+        println!("Positive number");
+    } else {
+        println!("Not positive");
+    }
+
+    if number % 2 == 0 {
+        println!("Even");
+    } else {
+        println!("Odd");
+    }
+
+    let temperature = 85;
+
+    if temperature > 80 {
+        println!("It's hot!");
+    } else {
+        println!("Comfortable");
+    }
+}
@@ -0,0 +1,30 @@
+// Reference solution for exercise 010: if/else if/else Chains
+
+fn main() {
+    let score = 75;
+
+    let grade = if score >= 90 {
+        "A"
+    } else if score >= 80 {
+        "B"
+    } else if score >= 70 {
+        "C"
+    } else if score >= 60 {
+        "D"
+    } else {
+        "F"
+    };
+    println!("Score {} is grade {}", score, grade);
+
+    let hour = 14;
+
+    if (5..=11).contains(&hour) {
+        println!("Good morning!");
+    } else if (12..=17).contains(&hour) {
+        println!("Good afternoon!");
+    } else if (18..=21).contains(&hour) {
+        println!("Good evening!");
+    } else {
+        println!("Good night!");
+    }
+}
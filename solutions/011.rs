@@ -0,0 +1,21 @@
+// Reference solution for exercise 011: match Statement Basics
+
+fn main() {
+    let number = 3;
+
+    match number {
+        1 => println!("One"),
+        2 => println!("Two"),
+        3 => println!("Three"),
+        _ => println!("Other"),
+    }
+
+    let color = "red";
+
+    match color {
+        "red" => println!("Stop!"),
+        "yellow" => println!("Caution!"),
+        "green" => println!("Go!"),
+        _ => println!("Unknown signal"),
+    }
+}
@@ -0,0 +1,19 @@
+// Reference solution for exercise 012: while Loop
+
+fn main() {
+    let mut counter = 1;
+
+    while counter <= 5 {
+        println!("{}", counter);
+        counter += 1;
+    }
+
+    println!("---");
+
+    let mut countdown = 5;
+
+    while countdown >= 1 {
+        println!("{}", countdown);
+        countdown -= 1;
+    }
+}
@@ -0,0 +1,21 @@
+// Reference solution for exercise 013: for Loop with Range
+
+fn main() {
+    for number in 1..=5 {
+        println!("{}", number);
+    }
+
+    println!("---");
+
+    for number in (2..=10).step_by(2) {
+        println!("{}", number);
+    }
+
+    println!("---");
+
+    let mut sum = 0;
+    for number in 1..=100 {
+        sum += number;
+    }
+    println!("Sum of 1 to 100: {}", sum);
+}
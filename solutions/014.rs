@@ -0,0 +1,25 @@
+// Reference solution for exercise 014: loop with break
+
+fn main() {
+    let mut count = 0;
+
+    loop {
+        println!("{}", count);
+        if count >= 5 {
+            break;
+        }
+        count += 1;
+    }
+
+    println!("---");
+
+    let mut number = 100;
+
+    loop {
+        if number % 7 == 0 && number % 9 == 0 {
+            println!("First number > 100 divisible by 7 and 9: {}", number);
+            break;
+        }
+        number += 1;
+    }
+}
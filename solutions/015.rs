@@ -0,0 +1,25 @@
+// Reference solution for exercise 015: Ownership Basics
+
+fn main() {
+    let name1 = String::from("Rust");
+    println!("name1 owns: {}", name1);
+
+    let name2 = name1;
+    println!("name2 now owns: {}", name2);
+
+    let my_string = String::from("Hello");
+    takes_ownership(my_string);
+
+    let x = 5;
+    let y = x;
+
+    println!("x = {}, y = {}", x, y);
+
+    let greeting = String::from("Hello, Ownership!");
+    let greeting2 = greeting;
+    println!("{}", greeting2);
+}
+
+fn takes_ownership(s: String) {
+    println!("Function received: {}", s);
+}
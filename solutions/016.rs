@@ -0,0 +1,23 @@
+// Reference solution for exercise 016: Functions - With Parameters
+
+fn greet_person(name: &str) {
+    println!("Hello, {}!", name);
+}
+
+fn print_sum(a: i32, b: i32) {
+    let result = a + b;
+    println!("{} + {} = {}", a, b, result);
+}
+
+fn repeat_message(message: &str, count: i32) {
+    for _ in 0..count {
+        println!("{}", message);
+    }
+}
+
+fn main() {
+    greet_person("Alice");
+    greet_person("Ferris");
+    print_sum(10, 25);
+    repeat_message("Rust is fun!", 3);
+}
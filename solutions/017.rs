@@ -0,0 +1,23 @@
+// Reference solution for exercise 017: Functions - Return Values
+
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn square(n: i32) -> i32 {
+    n * n
+}
+
+fn is_positive(n: i32) -> bool {
+    n > 0
+}
+
+fn main() {
+    let sum = add(5, 3);
+    println!("5 + 3 = {}", sum);
+
+    println!("7 squared = {}", square(7));
+
+    println!("Is -5 positive? {}", is_positive(-5));
+    println!("Is 10 positive? {}", is_positive(10));
+}
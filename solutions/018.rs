@@ -0,0 +1,24 @@
+// Reference solution for exercise 018: Functions - Multiple Returns (Tuples)
+
+fn divide_with_remainder(a: i32, b: i32) -> (i32, i32) {
+    (a / b, a % b)
+}
+
+fn min_max(a: i32, b: i32) -> (i32, i32) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn main() {
+    let (quotient, remainder) = divide_with_remainder(17, 5);
+    println!("17 divided by 5 is {} with remainder {}", quotient, remainder);
+
+    let result = min_max(42, 17);
+    println!("Smaller: {}, Larger: {}", result.0, result.1);
+
+    let (min, max) = min_max(10, 100);
+    println!("Between 10 and 100: min={}, max={}", min, max);
+}
@@ -0,0 +1,32 @@
+// Reference solution for exercise 019: Comments and Documentation
+
+/// Calculates the area of a rectangle.
+///
+/// # Examples
+/// ```ignore
+/// let area = calculate_area(5, 10);
+/// assert_eq!(area, 50);
+/// ```
+fn calculate_area(width: i32, height: i32) -> i32 {
+    // Area of a rectangle is the product of its sides
+    width * height
+}
+
+/// Builds a welcome message for the user called `name`.
+///
+/// # Examples
+/// ```ignore
+/// assert_eq!(greet_user("Alice"), "Hello, Alice! Welcome!");
+/// ```
+fn greet_user(name: &str) -> String {
+    format!("Hello, {}! Welcome!", name)
+}
+
+fn main() {
+    // Compute the area of a 5 x 10 rectangle
+    let area = calculate_area(5, 10);
+    println!("Area: {}", area);
+
+    let greeting = greet_user("Bob");
+    println!("{}", greeting);
+}
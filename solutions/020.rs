@@ -0,0 +1,28 @@
+// Reference solution for exercise 020: Shadowing
+
+fn main() {
+    let x = 5;
+    println!("Original x: {}", x);
+
+    let x = 10;
+    println!("Shadowed x: {}", x);
+
+    let x = x + 5;
+    println!("Shadowed x again: {}", x);
+
+    let name = "Alice";
+    println!("Original name (type &str): {}", name);
+
+    let name = String::from(name);
+    println!("Shadowed name (type String): {}", name);
+
+    let mut y = 10;
+    println!("Initial y: {}", y);
+    y = 20;
+    println!("Mutable y: {}", y);
+
+    let z = "10";
+    println!("z as string: {}", z);
+    let z = z.parse::<i32>().unwrap();
+    println!("z as number: {}", z);
+}
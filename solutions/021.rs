@@ -0,0 +1,21 @@
+// Reference solution for exercise 021: Type Inference
+
+fn main() {
+    let a = 42;
+    let pi = 3.14159;
+    let is_active = true;
+    let message = "Type inference is handy";
+
+    println!("a = {} (inferred as i32)", a);
+    println!("pi = {} (inferred as f64)", pi);
+    println!("is_active = {} (inferred as bool)", is_active);
+    println!("message = {} (inferred as &str)", message);
+
+    let guess: i32 = "42".parse().unwrap();
+    println!("guess = {}", guess);
+
+    let x = 5;
+    let y = 10;
+    let sum = x + y;
+    println!("{} + {} = {}", x, y, sum);
+}
@@ -0,0 +1,20 @@
+// Reference solution for exercise 022: Multiple Variable Declaration
+
+fn main() {
+    let (name, age, score) = ("Alice", 25, 95);
+    println!("Name: {}, Age: {}, Score: {}", name, age, score);
+
+    let (width, height) = (8, 5);
+    let area = width * height;
+    println!("Rectangle: {} x {} = {} square units", width, height, area);
+
+    let mut a = 5;
+    let mut b = 10;
+    println!("Before swap: a = {}, b = {}", a, b);
+
+    (a, b) = (b, a);
+    println!("After swap: a = {}, b = {}", a, b);
+
+    let (first, _, third) = (1, 2, 3);
+    println!("First: {}, Third: {}", first, third);
+}
@@ -0,0 +1,15 @@
+// Reference solution for exercise 023: Simple Array Declaration
+
+fn main() {
+    let numbers: [i32; 5] = [1, 2, 3, 4, 5];
+    let temperatures = [72.5, 68.0, 75.5, 80.0, 65.5];
+    let weekdays = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    let zeros = [0; 10];
+
+    println!("Numbers: {:?}", numbers);
+    println!("Temperatures: {:?}", temperatures);
+    println!("Weekdays: {:?}", weekdays);
+    println!("Zeros: {:?}", zeros);
+
+    println!("Numbers array has {} elements", numbers.len());
+}
@@ -0,0 +1,22 @@
+// Reference solution for exercise 024: Array Access
+
+fn main() {
+    let fruits = ["Apple", "Banana", "Cherry", "Date", "Elderberry"];
+
+    println!("First fruit: {}", fruits[0]);
+    println!("Third fruit: {}", fruits[2]);
+
+    let last_index = fruits.len() - 1;
+    println!("Last fruit: {}", fruits[last_index]);
+
+    let scores = [85, 92, 78, 95, 88];
+
+    let sum = scores[0] + scores[scores.len() - 1];
+    println!("First + Last score: {}", sum);
+
+    let average = (scores[1] + scores[3]) / 2;
+    println!("Average of scores[1] and scores[3]: {}", average);
+
+    const PRIMES: [i32; 5] = [2, 3, 5, 7, 11];
+    println!("The 4th prime number is: {}", PRIMES[3]);
+}
@@ -0,0 +1,40 @@
+// Reference solution for exercise 025: Array Iteration
+
+fn main() {
+    let numbers = [10, 20, 30, 40, 50];
+
+    for number in numbers {
+        println!("{}", number);
+    }
+
+    println!("---");
+
+    let names = ["Alice", "Bob", "Charlie", "Diana"];
+
+    for name in names {
+        println!("Hello, {}!", name);
+    }
+
+    println!("---");
+
+    let scores = [85, 92, 78, 95, 88, 91];
+
+    let mut sum = 0;
+    for score in scores {
+        sum += score;
+    }
+    println!("Sum of scores: {}", sum);
+
+    let average = sum as f64 / scores.len() as f64;
+    println!("Average score: {:.1}", average);
+
+    println!("---");
+
+    let mut highest = scores[0];
+    for &score in &scores {
+        if score > highest {
+            highest = score;
+        }
+    }
+    println!("Highest score: {}", highest);
+}
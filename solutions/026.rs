@@ -0,0 +1,14 @@
+// Reference solution for exercise 026: Ownership - Move Semantics
+
+fn main() {
+    let greeting = String::from("Hello, Rust!");
+
+    take_ownership(greeting);
+
+    let new_greeting = String::from("Hello again, Rust!");
+    println!("I can still use: {}", new_greeting);
+}
+
+fn take_ownership(s: String) {
+    println!("I own: {}", s);
+}
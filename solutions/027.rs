@@ -0,0 +1,22 @@
+// Reference solution for exercise 027: Ownership - Clone
+
+fn main() {
+    let original = String::from("Hello, Clone!");
+    let copy = original.clone();
+
+    consume_string(original);
+
+    println!("Clone still available: {}", copy);
+
+    let data = String::from("Important data");
+    let backup1 = data.clone();
+    let backup2 = data.clone();
+
+    println!("Original: {}", data);
+    println!("Backup 1: {}", backup1);
+    println!("Backup 2: {}", backup2);
+}
+
+fn consume_string(s: String) {
+    println!("Consumed: {}", s);
+}
@@ -0,0 +1,33 @@
+// Reference solution for exercise 028: Ownership - Copy Trait Types
+
+fn main() {
+    let x = 42;
+    let y = x;
+    println!("x = {}, y = {}", x, y);
+
+    let a = 3.14;
+    let b = a;
+    println!("a = {}, b = {}", a, b);
+
+    let flag = true;
+    let flag_copy = flag;
+    println!("flag = {}, flag_copy = {}", flag, flag_copy);
+
+    let point = (10, 20);
+    let point_copy = point;
+    println!("point = {:?}, point_copy = {:?}", point, point_copy);
+
+    let data = (String::from("hello"), 42);
+    let data_clone = (data.0.clone(), data.1);
+    println!("data = {:?}", data);
+    println!("data_clone = {:?}", data_clone);
+
+    let num = 100;
+    use_number(num);
+    use_number(num);
+    println!("num is still: {}", num);
+}
+
+fn use_number(n: i32) {
+    println!("Using number: {}", n);
+}
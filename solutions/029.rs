@@ -0,0 +1,29 @@
+// Reference solution for exercise 029: References - Immutable Borrow
+
+fn main() {
+    let message = String::from("Borrow me!");
+
+    let ref1 = &message;
+    println!("Original: {}", message);
+    println!("Reference: {}", ref1);
+
+    let ref2 = &message;
+    let ref3 = &message;
+    println!("ref1: {}", ref1);
+    println!("ref2: {}", ref2);
+    println!("ref3: {}", ref3);
+
+    print_length(&message);
+
+    println!("Message is still mine: {}", message);
+
+    {
+        let inner_ref = &message;
+        println!("Inside scope: {}", inner_ref);
+    }
+    println!("After scope: {}", message);
+}
+
+fn print_length(s: &String) {
+    println!("Length: {}", s.len());
+}
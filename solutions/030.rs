@@ -0,0 +1,30 @@
+// Reference solution for exercise 030: References - Mutable Borrow
+
+fn main() {
+    let mut greeting = String::from("Hello");
+
+    let ref1 = &mut greeting;
+    ref1.push_str(", World!");
+    println!("Modified: {}", ref1);
+
+    let ref2 = &mut greeting;
+    ref2.push_str("!!!");
+    println!("Final: {}", greeting);
+
+    let mut number = 10;
+    double_value(&mut number);
+    println!("Doubled: {}", number);
+
+    let mut data = String::from("Start");
+    {
+        let data_ref = &mut data;
+        data_ref.push_str(" - Middle");
+        println!("Inside scope: {}", data_ref);
+    }
+    data.push_str(" - End");
+    println!("After scope: {}", data);
+}
+
+fn double_value(n: &mut i32) {
+    *n *= 2;
+}
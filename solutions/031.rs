@@ -0,0 +1,49 @@
+// Reference solution for exercise 031: References - Scope Rules
+
+fn main() {
+    let s1 = String::from("shared");
+    let r1 = &s1;
+    let r2 = &s1;
+    let r3 = &s1;
+    println!("r1: {}, r2: {}, r3: {}", r1, r2, r3);
+
+    let mut s2 = String::from("exclusive");
+    let m1 = &mut s2;
+    println!("m1: {}", m1);
+
+    let m2 = &mut s2;
+    println!("m2: {}", m2);
+
+    let mut s3 = String::from("mixed");
+    let imm1 = &s3;
+    let imm2 = &s3;
+    println!("imm1: {}, imm2: {}", imm1, imm2);
+
+    let mut_ref = &mut s3;
+    mut_ref.push_str(" modified");
+    println!("After mutable: {}", mut_ref);
+
+    let mut text = String::from("scope demo");
+    {
+        let ref_a = &text;
+        let ref_b = &text;
+        println!("Immutable in scope: {} and {}", ref_a, ref_b);
+    }
+
+    let mut_ref = &mut text;
+    mut_ref.push_str(" - changed");
+    println!("After mutable: {}", mut_ref);
+
+    let word1 = String::from("short");
+    let word2 = String::from("verylongwordindeed");
+    let result = longest(&word1, &word2);
+    println!("Longest is: {}", result);
+}
+
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
@@ -0,0 +1,47 @@
+// Reference solution for exercise 032: Slices - String Slices
+
+fn main() {
+    let literal: &str = "Hello, world!";
+    println!("Literal: {}", literal);
+
+    let s = String::from("Hello, Rustaceans!");
+
+    let hello = &s[..5];
+    println!("Slice 1: {}", hello);
+
+    let rust = &s[7..17];
+    println!("Slice 2: {}", rust);
+
+    let rest = &s[7..];
+    println!("Rest: {}", rest);
+
+    let all = &s[..];
+    println!("All: {}", all);
+
+    let sentence = String::from("The quick brown fox");
+    let word = first_word(&sentence);
+    println!("First word: {}", word);
+
+    let mut text = String::from("hello world");
+    let first = first_word(&text);
+    println!("First word: {}", first);
+
+    text.clear();
+    text.push_str("goodbye moon");
+    println!("Modified: {}", text);
+
+    let lit_word = first_word("slice of life");
+    println!("Literal word: {}", lit_word);
+}
+
+fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[0..i];
+        }
+    }
+
+    s
+}
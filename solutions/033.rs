@@ -0,0 +1,57 @@
+// Reference solution for exercise 033: Slices - Array Slices
+
+fn main() {
+    let numbers = [10, 20, 30, 40, 50, 60, 70, 80];
+
+    let all = &numbers[..];
+    println!("All: {:?}", all);
+
+    let first_three = &numbers[..3];
+    println!("First three: {:?}", first_three);
+
+    let middle = &numbers[2..6];
+    println!("Middle: {:?}", middle);
+
+    let last_three = &numbers[5..];
+    println!("Last three: {:?}", last_three);
+
+    let vec = vec![100, 200, 300, 400, 500];
+    let vec_slice = &vec[..3];
+    println!("Vector slice: {:?}", vec_slice);
+
+    let data = [5, 10, 15, 20, 25];
+    let slice = &data[..];
+
+    println!("Length: {}", slice.len());
+    println!("Is empty: {}", slice.is_empty());
+    if let Some(first) = slice.first() {
+        println!("First: {}", first);
+    }
+    if let Some(last) = slice.last() {
+        println!("Last: {}", last);
+    }
+
+    let mut values = [1, 2, 3, 4, 5];
+    let mut_slice = &mut values[1..4];
+    for val in mut_slice {
+        *val *= 2;
+    }
+    println!("Modified: {:?}", values);
+
+    let scores = [85, 92, 78, 95, 88];
+
+    let avg = calculate_average(&scores);
+    println!("Average: {}", avg);
+
+    let avg_first3 = calculate_average(&scores[..3]);
+    println!("Average of first 3: {}", avg_first3);
+}
+
+fn calculate_average(numbers: &[i32]) -> f64 {
+    if numbers.is_empty() {
+        return 0.0;
+    }
+
+    let sum: i32 = numbers.iter().sum();
+    sum as f64 / numbers.len() as f64
+}
@@ -0,0 +1,75 @@
+// Reference solution for exercise 034: Match Expressions
+
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(String),
+}
+
+fn value_in_cents(coin: Coin) -> u32 {
+    match coin {
+        Coin::Penny => {
+            println!("Lucky penny!");
+            1
+        }
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        Coin::Quarter(state) => {
+            println!("Quarter from {:?}!", state);
+            25
+        }
+    }
+}
+
+fn describe(heading: &Direction) -> &'static str {
+    match heading {
+        Direction::North => "Heading North!",
+        Direction::South => "Heading South!",
+        Direction::East => "Heading East!",
+        Direction::West => "Heading West!",
+    }
+}
+
+fn main() {
+    let number = 3;
+
+    match number {
+        1 => println!("One!"),
+        2 => println!("Two!"),
+        3 => println!("Three!"),
+        _ => println!("Something else"),
+    }
+
+    let score = 85;
+
+    let grade = match score {
+        90..=100 => "A",
+        80..=89 => "B",
+        70..=79 => "C",
+        60..=69 => "D",
+        _ => "F",
+    };
+
+    println!("Score {} = Grade {}", score, grade);
+
+    for heading in [Direction::North, Direction::South, Direction::East, Direction::West] {
+        println!("{}", describe(&heading));
+    }
+
+    for coin in [
+        Coin::Penny,
+        Coin::Nickel,
+        Coin::Dime,
+        Coin::Quarter(String::from("Alaska")),
+    ] {
+        println!("Coin value: {} cents", value_in_cents(coin));
+    }
+}
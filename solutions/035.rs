@@ -0,0 +1,29 @@
+// Reference solution for exercise 035: Structs - Tuple Structs
+
+struct Color(u8, u8, u8);
+struct Point(f64, f64, f64);
+struct Meters(f64);
+struct Kilometers(f64);
+struct Wrapper(String);
+
+fn main() {
+    let red = Color(255, 0, 0);
+    let origin = Point(0.0, 0.0, 0.0);
+
+    println!("Red: R={}, G={}, B={}", red.0, red.1, red.2);
+    println!("Origin: x={}, y={}, z={}", origin.0, origin.1, origin.2);
+
+    let Color(r, g, b) = red;
+    println!("Destructured: R={}, G={}, B={}", r, g, b);
+
+    let distance_m = Meters(1000.0);
+    let distance_km = Kilometers(1.0);
+    println!("Distance in meters: {}", distance_m.0);
+    println!("Distance in km: {}", distance_km.0);
+
+    let wrapped = Wrapper(String::from("Hello"));
+    println!("Wrapped: {}", wrapped.0);
+
+    let Wrapper(inner) = wrapped;
+    println!("Inner: {}", inner);
+}
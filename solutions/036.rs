@@ -0,0 +1,35 @@
+// Reference solution for exercise 036: Structs - Unit Structs
+
+struct AlwaysEqual;
+struct Admin;
+struct User;
+struct Idle;
+struct Running;
+struct Paused;
+
+fn admin_function(_admin: Admin) {
+    println!("Admin access granted!");
+}
+
+fn user_function(_user: User) {
+    println!("User access granted!");
+}
+
+fn main() {
+    let _subject = AlwaysEqual;
+    println!("Created an AlwaysEqual instance");
+
+    let admin = Admin;
+    let user = User;
+    admin_function(admin);
+    user_function(user);
+
+    let _state = Idle;
+    let _next = Running;
+    let _later = Paused;
+    println!("Current state: Idle (zero-size type)");
+
+    println!("Size of AlwaysEqual: {} bytes", std::mem::size_of::<AlwaysEqual>());
+    println!("Size of (): {} bytes", std::mem::size_of::<()>());
+    println!("Size of i32: {} bytes", std::mem::size_of::<i32>());
+}
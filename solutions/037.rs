@@ -0,0 +1,60 @@
+// Reference solution for exercise 037: Structs - Methods (impl block)
+
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+impl Rectangle {
+    fn area(&self) -> u32 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> u32 {
+        2 * (self.width + self.height)
+    }
+
+    fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    fn scale(&mut self, factor: u32) {
+        self.width *= factor;
+        self.height *= factor;
+    }
+
+    fn square(size: u32) -> Rectangle {
+        Rectangle {
+            width: size,
+            height: size,
+        }
+    }
+}
+
+fn main() {
+    let rect = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
+    println!("Area: {}", rect.area());
+    println!("Perimeter: {}", rect.perimeter());
+
+    let small = Rectangle {
+        width: 10,
+        height: 20,
+    };
+    println!("Can rect hold small? {}", rect.can_hold(&small));
+
+    let mut square = Rectangle {
+        width: 10,
+        height: 10,
+    };
+    println!("Before: {}x{}", square.width, square.height);
+    square.scale(2);
+    println!("After scaling: {}x{}", square.width, square.height);
+
+    let mut sq = Rectangle::square(5);
+    sq.scale(3);
+    println!("Square area after scaling: {}", sq.area());
+}
@@ -0,0 +1,91 @@
+// Reference solution for exercise 038: Structs - Associated Functions
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+
+    fn origin() -> Point {
+        Point { x: 0.0, y: 0.0 }
+    }
+
+    fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    fn on_x(x: f64) -> Point {
+        Point { x, y: 0.0 }
+    }
+
+    fn distance(a: &Point, b: &Point) -> f64 {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+}
+
+impl Point {
+    fn to_tuple(&self) -> (f64, f64) {
+        (self.x, self.y)
+    }
+}
+
+struct Config {
+    host: String,
+    port: u16,
+    debug: bool,
+}
+
+impl Config {
+    fn new() -> Config {
+        Config {
+            host: String::from("localhost"),
+            port: 80,
+            debug: false,
+        }
+    }
+
+    fn with_host(mut self, host: &str) -> Config {
+        self.host = host.to_string();
+        self
+    }
+
+    fn with_port(mut self, port: u16) -> Config {
+        self.port = port;
+        self
+    }
+
+    fn with_debug(mut self, debug: bool) -> Config {
+        self.debug = debug;
+        self
+    }
+}
+
+fn main() {
+    let origin = Point::origin();
+    println!("Origin: ({}, {})", origin.x, origin.y);
+
+    let p1 = Point::new(3.0, 4.0);
+    println!("p1: ({}, {})", p1.x, p1.y);
+
+    let p2 = Point::on_x(5.0);
+    println!("p2 on x-axis: ({}, {})", p2.x, p2.y);
+
+    let a = Point::new(0.0, 0.0);
+    let b = Point::new(3.0, 4.0);
+    let dist = Point::distance(&a, &b);
+    println!("Distance between a and b: {}", dist);
+
+    println!("p1 as tuple: {:?}", p1.to_tuple());
+
+    println!("ORIGIN: ({}, {})", Point::ORIGIN.x, Point::ORIGIN.y);
+
+    let config = Config::new()
+        .with_host("localhost")
+        .with_port(8080)
+        .with_debug(true);
+    println!("Config: {}:{}, debug={}", config.host, config.port, config.debug);
+}
@@ -0,0 +1,71 @@
+// Reference solution for exercise 039: Enums - Basic Definition
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug)]
+enum GameState {
+    Menu,
+    Playing,
+    Paused,
+    GameOver,
+}
+
+#[derive(Debug)]
+enum MessageType {
+    Text,
+    Image,
+    Video,
+    Audio,
+}
+
+fn describe_direction(dir: Direction) {
+    match dir {
+        Direction::North => println!("Going up!"),
+        Direction::South => println!("Going down!"),
+        Direction::East => println!("Going right!"),
+        Direction::West => println!("Going left!"),
+    }
+}
+
+fn opposite_direction(dir: Direction) -> Direction {
+    match dir {
+        Direction::North => Direction::South,
+        Direction::South => Direction::North,
+        Direction::East => Direction::West,
+        Direction::West => Direction::East,
+    }
+}
+
+fn main() {
+    let heading = Direction::North;
+    let opposite = Direction::South;
+
+    describe_direction(heading);
+    describe_direction(opposite);
+    describe_direction(Direction::East);
+    describe_direction(Direction::West);
+
+    let d = Direction::North;
+    let opp = opposite_direction(d);
+    println!("Opposite of {:?} is {:?}", d, opp);
+
+    for state in [GameState::Menu, GameState::Playing, GameState::Paused, GameState::GameOver] {
+        println!("Game state: {:?}", state);
+    }
+
+    for msg_type in [MessageType::Text, MessageType::Image, MessageType::Video, MessageType::Audio] {
+        println!("Message type: {:?}", msg_type);
+    }
+
+    let a = Direction::North;
+    let b = Direction::North;
+    let c = Direction::South;
+    println!("a == b: {}", a == b);
+    println!("a == c: {}", a == c);
+}
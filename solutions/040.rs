@@ -0,0 +1,82 @@
+// Reference solution for exercise 040: Enums - With Data
+
+#[derive(Debug)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(u8, u8, u8),
+}
+
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+#[derive(Debug)]
+enum IpAddr {
+    V4(u8, u8, u8, u8),
+    V6(String),
+}
+
+fn process_message(msg: Message) {
+    match msg {
+        Message::Quit => println!("Quitting..."),
+        Message::Move { x, y } => println!("Moving to ({}, {})", x, y),
+        Message::Write(text) => println!("Writing: {}", text),
+        Message::ChangeColor(r, g, b) => println!("Changing color to RGB({}, {}, {})", r, g, b),
+    }
+}
+
+fn describe(ip: &IpAddr) -> String {
+    match ip {
+        IpAddr::V4(a, b, c, d) => format!("{}.{}.{}.{}", a, b, c, d),
+        IpAddr::V6(addr) => addr.clone(),
+    }
+}
+
+fn area(shape: &Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Rectangle { width, height } => width * height,
+        Shape::Triangle { base, height } => 0.5 * base * height,
+    }
+}
+
+fn main() {
+    let quit = Message::Quit;
+    let move_msg = Message::Move { x: 10, y: 20 };
+    let write = Message::Write(String::from("Hello"));
+    let color = Message::ChangeColor(255, 0, 0);
+
+    println!("{:?}", quit);
+    println!("{:?}", move_msg);
+    println!("{:?}", write);
+    println!("{:?}", color);
+
+    process_message(quit);
+    process_message(move_msg);
+    process_message(write);
+    process_message(color);
+
+    let circle = Shape::Circle { radius: 5.0 };
+    let rect = Shape::Rectangle {
+        width: 10.0,
+        height: 20.0,
+    };
+    let tri = Shape::Triangle {
+        base: 8.0,
+        height: 4.0,
+    };
+
+    println!("Circle area: {:.2}", area(&circle));
+    println!("Rectangle area: {:.2}", area(&rect));
+    println!("Triangle area: {:.2}", area(&tri));
+
+    let home = IpAddr::V4(127, 0, 0, 1);
+    let loopback = IpAddr::V6(String::from("::1"));
+    println!("Home: {:?}", home);
+    println!("Loopback: {:?}", loopback);
+    println!("Addresses: {} and {}", describe(&home), describe(&loopback));
+}
@@ -0,0 +1,55 @@
+// Reference solution for exercise 041: Enums - Option<T>
+
+fn divide(numerator: f64, denominator: f64) -> Option<f64> {
+    if denominator == 0.0 {
+        None
+    } else {
+        Some(numerator / denominator)
+    }
+}
+
+fn main() {
+    let some_number = Some(5);
+    println!("Some number: {:?}", some_number);
+
+    let absent_number: Option<i32> = None;
+    println!("Absent number: {:?}", absent_number);
+
+    println!("some_number is_some: {}", some_number.is_some());
+    println!("some_number is_none: {}", some_number.is_none());
+    println!("absent_number is_none: {}", absent_number.is_none());
+
+    match some_number {
+        Some(n) => println!("Got number: {}", n),
+        None => println!("No number"),
+    }
+
+    let value = some_number.unwrap();
+    println!("Unwrapped: {}", value);
+
+    let value = some_number.expect("Should have a number");
+    println!("Expected: {}", value);
+
+    let result = absent_number.unwrap_or(0);
+    println!("With default: {}", result);
+
+    let numbers = vec![10, 20, 30, 40, 50];
+
+    let found = numbers.iter().find(|&&x| x == 30);
+    println!("Found 30: {:?}", found);
+
+    let not_found = numbers.iter().find(|&&x| x == 100);
+    println!("Found 100: {:?}", not_found);
+
+    match found {
+        Some(&val) => println!("Found value: {}", val),
+        None => println!("Value not found"),
+    }
+
+    println!("10 / 2 = {:?}", divide(10.0, 2.0));
+    println!("10 / 0 = {:?}", divide(10.0, 0.0));
+
+    if let Some(n) = Some(42) {
+        println!("The number is: {}", n);
+    }
+}
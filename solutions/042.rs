@@ -0,0 +1,76 @@
+// Reference solution for exercise 042: Pattern Matching - match
+
+enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter,
+}
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+fn value_in_cents(coin: &Coin) -> u32 {
+    match coin {
+        Coin::Penny => {
+            println!("Lucky penny!");
+            1
+        }
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        Coin::Quarter => 25,
+    }
+}
+
+fn describe(msg: Message) {
+    match msg {
+        Message::Quit => println!("Quit"),
+        Message::Move { x, y } => println!("Move to ({}, {})", x, y),
+        Message::Write(text) => println!("Text: {}", text),
+        Message::ChangeColor(r, g, b) => println!("RGB({}, {}, {})", r, g, b),
+    }
+}
+
+fn main() {
+    for coin in [Coin::Penny, Coin::Nickel, Coin::Dime, Coin::Quarter] {
+        println!("Coin value: {} cents", value_in_cents(&coin));
+    }
+
+    describe(Message::Write(String::from("Hello")));
+    describe(Message::Quit);
+    describe(Message::ChangeColor(0, 128, 255));
+
+    let number = 7;
+    match number {
+        1 => println!("One"),
+        2 | 3 | 5 | 7 | 11 => println!("Prime"),
+        13..=19 => println!("Teen"),
+        _ => println!("Other"),
+    }
+
+    let msg = Message::Move { x: 10, y: 20 };
+    if let Message::Move { x, y } = msg {
+        println!("Moving x by {}", x);
+        println!("Moving y by {}", y);
+    }
+
+    let some_value = Some(3);
+    match some_value {
+        Some(3) => println!("Three!"),
+        Some(n) => println!("Other number: {}", n),
+        None => println!("No value"),
+    }
+
+    let x = 5;
+    let description = match x {
+        1 => "one",
+        2 => "two",
+        3..=9 => "a few",
+        _ => "many",
+    };
+    println!("{} is {}", x, description);
+}
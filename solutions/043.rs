@@ -0,0 +1,60 @@
+// Reference solution for exercise 043: Pattern Matching - if let
+
+enum Message {
+    Hello { id: i32 },
+    Goodbye,
+}
+
+fn greet(msg: &Message) {
+    if let Message::Hello { id: 3 } = msg {
+        println!("Hello from 3");
+    } else if let Message::Hello { id } = msg {
+        println!("Hello from {}", id);
+    } else {
+        println!("Goodbye!");
+    }
+}
+
+fn main() {
+    let some_value = Some(5);
+    if let Some(n) = some_value {
+        println!("Got: {}", n);
+    }
+
+    let no_value: Option<i32> = None;
+    if let Some(n) = no_value {
+        println!("Got: {}", n);
+    } else {
+        println!("No value");
+    }
+
+    let msg = Message::Hello { id: 5 };
+    if let Message::Hello { id } = msg {
+        println!("Hello with id: {}", id);
+    }
+
+    greet(&Message::Hello { id: 3 });
+    greet(&msg);
+    greet(&Message::Goodbye);
+
+    let values = vec![Some(1), None, Some(3), None, Some(5)];
+
+    for val in &values {
+        if let Some(n) = val {
+            println!("Processing: {}", n);
+        }
+    }
+
+    let mut count = 0;
+    for val in &values {
+        if val.is_some() {
+            count += 1;
+        }
+    }
+    println!("Found {} Some values out of {}", count, values.len());
+
+    let pair = (1, 2);
+    if let (1, y) = pair {
+        println!("First is 1, second is {}", y);
+    }
+}
@@ -0,0 +1,55 @@
+// Reference solution for exercise 044: Pattern Matching - while let
+
+fn main() {
+    let mut stack = vec![1, 2, 3, 4, 5];
+    while let Some(top) = stack.pop() {
+        println!("Popped: {}", top);
+    }
+    println!("Stack is empty: {:?}", stack);
+
+    let mut queue = vec!["first", "second", "third"];
+    while let Some(item) = queue.pop() {
+        println!("Processing: {}", item);
+    }
+
+    let mut values = vec![Some(1), Some(2), Some(3)];
+    let mut iter = values.iter_mut();
+    while let Some(opt) = iter.next() {
+        if let Some(val) = opt {
+            *val *= 2;
+        }
+    }
+    println!("Doubled: {:?}", values);
+
+    let nums = vec![1, 2, 3, 99, 4, 5];
+    let mut iter = nums.into_iter().peekable();
+    while let Some(&n) = iter.peek() {
+        if n == 99 {
+            println!("Found stop value!");
+            break;
+        }
+        println!("Processing: {}", iter.next().unwrap());
+    }
+
+    let mut lines = vec!["Line 1", "Line 2", "", "Line 4"].into_iter();
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            println!("Empty line - stopping");
+            break;
+        }
+        println!("Read: {}", line);
+    }
+
+    let mut v1 = vec![1, 2, 3];
+    loop {
+        match v1.pop() {
+            Some(n) => println!("loop+match: {}", n),
+            None => break,
+        }
+    }
+
+    let mut v2 = vec![1, 2, 3];
+    while let Some(n) = v2.pop() {
+        println!("while let: {}", n);
+    }
+}
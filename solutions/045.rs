@@ -0,0 +1,53 @@
+// Reference solution for exercise 045: Vectors - Creating and Adding
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let mut v1: Vec<i32> = Vec::new();
+    println!("Empty vector: {:?}, length: {}", v1, v1.len());
+
+    let v2 = vec![1, 2, 3];
+    println!("vec! created: {:?}", v2);
+
+    v1.push(10);
+    v1.push(20);
+    v1.push(30);
+    println!("After pushes: {:?}", v1);
+
+    let mut names: Vec<String> = Vec::new();
+    names.push(String::from("Alice"));
+    names.push(String::from("Bob"));
+    names.push(String::from("Charlie"));
+    println!("Names: {:?}", names);
+
+    let mut v3: Vec<i32> = Vec::with_capacity(100);
+    println!("Capacity: {}, Length: {}", v3.capacity(), v3.len());
+    for i in 0..10 {
+        v3.push(i * 10);
+    }
+    println!("After adding: capacity={}, length={}", v3.capacity(), v3.len());
+
+    let mut points: Vec<Point> = Vec::new();
+    points.push(Point { x: 0, y: 0 });
+    points.push(Point { x: 10, y: 20 });
+    points.push(Point { x: -5, y: 15 });
+    println!("Points: {:?}", points);
+    println!("Second point x={}, y={}", points[1].x, points[1].y);
+
+    let mut v4 = vec![1, 2, 3];
+    let more = vec![4, 5, 6];
+    v4.extend(more);
+    println!("Extended: {:?}", v4);
+
+    let mut v5 = vec![1, 3, 4];
+    v5.insert(1, 2);
+    println!("After insert: {:?}", v5);
+
+    let arr = [10, 20, 30];
+    let v6: Vec<i32> = arr.to_vec();
+    println!("From array: {:?}", v6);
+}
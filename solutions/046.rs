@@ -0,0 +1,58 @@
+// Reference solution for exercise 046: Vectors - Accessing Elements
+
+fn safe_divide(v: &[f64], idx1: usize, idx2: usize) -> Option<f64> {
+    let a = v.get(idx1)?;
+    let b = v.get(idx2)?;
+    if *b == 0.0 {
+        None
+    } else {
+        Some(*a / *b)
+    }
+}
+
+fn main() {
+    let numbers = vec![10, 20, 30, 40, 50];
+
+    let first = numbers[0];
+    println!("First: {}", first);
+
+    let third = numbers[2];
+    println!("Third: {}", third);
+
+    match numbers.get(0) {
+        Some(n) => println!("Got: {}", n),
+        None => println!("Not found"),
+    }
+
+    match numbers.get(100) {
+        Some(n) => println!("Got: {}", n),
+        None => println!("Index 100 is out of bounds"),
+    }
+
+    if let Some(n) = numbers.first() {
+        println!("First: {}", n);
+    }
+    if let Some(n) = numbers.last() {
+        println!("Last: {}", n);
+    }
+
+    let mut scores = vec![85, 92, 78, 95];
+    scores[2] = 88;
+    println!("Updated scores: {:?}", scores);
+
+    if let Some(score) = scores.get_mut(0) {
+        *score += 5;
+    }
+    println!("After bonus: {:?}", scores);
+
+    let empty: Vec<i32> = vec![];
+    match empty.first() {
+        Some(_) => println!("Has elements"),
+        None => println!("Empty vector!"),
+    }
+
+    let values = vec![10.0, 2.0, 0.0];
+    println!("10/2 = {:?}", safe_divide(&values, 0, 1));
+    println!("10/0 = {:?}", safe_divide(&values, 0, 2));
+    println!("out of bounds = {:?}", safe_divide(&values, 0, 10));
+}
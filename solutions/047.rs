@@ -0,0 +1,62 @@
+// Reference solution for exercise 047: Vectors - Iterating
+
+fn main() {
+    let numbers = vec![1, 2, 3, 4, 5];
+
+    for num in numbers.iter() {
+        println!("Value: {}", num);
+    }
+
+    for num in &numbers {
+        println!("Got: {}", num);
+    }
+
+    let mut scores = vec![10, 20, 30, 40];
+    for score in scores.iter_mut() {
+        *score *= 2;
+    }
+    println!("Doubled: {:?}", scores);
+
+    let names = vec!["Alice", "Bob", "Charlie"];
+    for (index, name) in names.iter().enumerate() {
+        println!("{}: {}", index, name);
+    }
+
+    let squares: Vec<i32> = numbers.iter().map(|n| n * n).collect();
+    println!("Squares: {:?}", squares);
+
+    let evens: Vec<i32> = numbers.iter().filter(|&&n| n % 2 == 0).copied().collect();
+    println!("Evens: {:?}", evens);
+
+    let even_squares: Vec<i32> = numbers
+        .iter()
+        .filter(|&&n| n % 2 == 0)
+        .map(|n| n * n)
+        .collect();
+    println!("Even squares: {:?}", even_squares);
+
+    let total: i32 = numbers.iter().sum();
+    println!("Total: {}", total);
+
+    let count = numbers.iter().filter(|&&n| n > 2).count();
+    println!("Count > 2: {}", count);
+
+    if let Some(&first_even) = numbers.iter().find(|&&n| n % 2 == 0) {
+        println!("First even: {}", first_even);
+    }
+
+    if let Some(pos) = numbers.iter().position(|&n| n == 3) {
+        println!("3 is at index {}", pos);
+    }
+
+    let any_big = numbers.iter().any(|&n| n > 4);
+    println!("Any > 4: {}", any_big);
+
+    let all_positive = numbers.iter().all(|&n| n > 0);
+    println!("All positive: {}", all_positive);
+
+    let owned = vec!["a", "b", "c"];
+    for item in owned.into_iter() {
+        println!("Owned: {}", item);
+    }
+}
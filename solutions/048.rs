@@ -0,0 +1,59 @@
+// Reference solution for exercise 048: Strings - Creating and Modifying
+
+fn main() {
+    let s1 = String::new();
+    println!("Empty string: '{}'", s1);
+
+    let s2 = String::from("Hello");
+    println!("From literal: '{}'", s2);
+
+    let s3 = "World".to_string();
+    println!("to_string: '{}'", s3);
+
+    let mut s4 = String::from("H");
+    s4.push('e');
+    s4.push('l');
+    s4.push('l');
+    s4.push('o');
+    println!("After pushes: '{}'", s4);
+
+    let mut s5 = String::from("Hello");
+    s5.push_str(", World!");
+    println!("After push_str: '{}'", s5);
+
+    let addon = String::from(" Have a nice day.");
+    s5.push_str(&addon);
+    println!("Final: '{}'", s5);
+
+    let mut s6 = String::with_capacity(100);
+    println!("Empty: length={}, capacity={}", s6.len(), s6.capacity());
+    s6.push_str("Short text");
+    println!("After text: length={}, capacity={}", s6.len(), s6.capacity());
+
+    let mut s7 = String::from("Temporary");
+    println!("Before clear: '{}'", s7);
+    s7.clear();
+    println!("After clear: '{}' (length: {})", s7, s7.len());
+
+    let mut s8 = String::from("Hello, World!");
+    s8.truncate(5);
+    println!("After truncate: '{}'", s8);
+
+    let mut s9 = String::from("ABC");
+    while let Some(ch) = s9.pop() {
+        println!("Popped: '{}'", ch);
+    }
+    println!("After pops: '{}'", s9);
+
+    let mut s10 = String::from("Hello");
+    let removed = s10.remove(1);
+    println!("Removed: '{}', Remaining: '{}'", removed, s10);
+
+    let mut s11 = String::from("Hllo");
+    s11.insert(1, 'e');
+    println!("After insert: '{}'", s11);
+
+    let mut s12 = String::from("Hello World");
+    s12.insert_str(5, ",");
+    println!("After insert_str: '{}'", s12);
+}
@@ -0,0 +1,61 @@
+// Reference solution for exercise 049: Strings - Concatenation
+
+fn main() {
+    let s1 = String::from("Hello, ");
+    let s2 = String::from("World!");
+    let s3 = s1 + &s2;
+    println!("Result: '{}'", s3);
+    println!("s2 still valid: '{}'", s2);
+
+    let s1 = String::from("tic");
+    let s2 = String::from("tac");
+    let s3 = String::from("toe");
+    let result = s1 + "-" + &s2 + "-" + &s3;
+    println!("Chained: '{}'", result);
+
+    let s1 = String::from("tic");
+    let s2 = String::from("tac");
+    let s3 = String::from("toe");
+    let result = format!("{}-{}-{}", s1, s2, s3);
+    println!("Formatted: '{}'", result);
+    println!("All originals still valid: {}, {}, {}", s1, s2, s3);
+
+    let items = vec!["apple", "banana", "cherry"];
+    let mut result = String::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            result.push_str(", ");
+        }
+        result.push_str(item);
+    }
+    println!("List: '{}'", result);
+
+    let items = vec!["red", "green", "blue"];
+    let result = items.join(", ");
+    println!("Joined: '{}'", result);
+
+    let parts = ["Hello", " ", "World"];
+    let result: String = parts.concat();
+    println!("Concatenated: '{}'", result);
+
+    let parts = ["2024", "02", "07"];
+    let date = parts.join("-");
+    println!("Date: '{}'", date);
+
+    let s = String::from("Hello, World!");
+    let first = &s[0..5];
+    let second = &s[7..12];
+    let result = format!("{} {}", first, second);
+    println!("Combined slices: '{}'", result);
+
+    let words = vec!["The", "quick", "brown", "fox"];
+    let mut sentence = String::new();
+    for word in &words {
+        if !sentence.is_empty() {
+            sentence.push(' ');
+        }
+        sentence.push_str(word);
+    }
+    sentence.push('.');
+    println!("Sentence: '{}'", sentence);
+}
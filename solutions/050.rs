@@ -0,0 +1,68 @@
+// Reference solution for exercise 050: HashMap - Basic Operations
+
+use std::collections::HashMap;
+
+fn main() {
+    let mut scores: HashMap<String, i32> = HashMap::new();
+
+    scores.insert(String::from("Alice"), 95);
+    scores.insert(String::from("Bob"), 87);
+    scores.insert(String::from("Charlie"), 92);
+    println!("Scores: {:?}", scores);
+
+    match scores.get("Alice") {
+        Some(score) => println!("Alice's score: {}", score),
+        None => println!("Alice not found"),
+    }
+
+    match scores.get("David") {
+        Some(score) => println!("David's score: {}", score),
+        None => println!("David not in map"),
+    }
+
+    if let Some(score) = scores.get("Bob") {
+        println!("Bob scored {}", score);
+    }
+
+    if scores.contains_key("Charlie") {
+        println!("Charlie is in the map");
+    }
+
+    scores.insert(String::from("Bob"), 90);
+    println!("Bob's updated score: {:?}", scores.get("Bob"));
+
+    scores.entry(String::from("David")).or_insert(88);
+    scores.entry(String::from("Alice")).or_insert(100);
+    println!("After entry ops: {:?}", scores);
+
+    let count = scores.entry(String::from("Eve")).or_insert(0);
+    *count += 1;
+    println!("Eve's count: {:?}", scores.get("Eve"));
+
+    for (name, score) in &scores {
+        println!("{} => {}", name, score);
+    }
+
+    let teams = vec![("Blue", 10), ("Red", 5), ("Green", 15)];
+    let team_scores: HashMap<_, _> = teams.into_iter().collect();
+    println!("Team scores: {:?}", team_scores);
+
+    let text = "hello world";
+    let mut char_counts = HashMap::new();
+    for ch in text.chars() {
+        if ch != ' ' {
+            *char_counts.entry(ch).or_insert(0) += 1;
+        }
+    }
+    println!("Character counts: {:?}", char_counts);
+
+    scores.remove("Bob");
+    println!("After removing Bob: {:?}", scores);
+    println!("Bob exists? {}", scores.contains_key("Bob"));
+
+    println!("Number of entries: {}", scores.len());
+    println!("Is empty? {}", scores.is_empty());
+
+    scores.clear();
+    println!("After clear: {:?}, len: {}", scores, scores.len());
+}
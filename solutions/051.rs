@@ -0,0 +1,27 @@
+// Reference solution for exercise 051: Result Type - Basic Error Handling
+
+/// Parses a string to a positive integer.
+/// Returns Ok(number) if the string can be parsed and the number is positive.
+/// Returns Err with an appropriate error message otherwise.
+fn parse_positive_number(input: &str) -> Result<i32, String> {
+    let number = match input.parse::<i32>() {
+        Ok(n) => n,
+        Err(_) => return Err(String::from("Invalid number format")),
+    };
+    if number > 0 {
+        Ok(number)
+    } else {
+        Err(String::from("Number must be positive"))
+    }
+}
+
+fn main() {
+    let test_cases = vec!["42", "-5", "0", "abc", "100"];
+
+    for case in test_cases {
+        match parse_positive_number(case) {
+            Ok(n) => println!("✓ '{}' parsed successfully: {}", case, n),
+            Err(e) => println!("✗ '{}' failed: {}", case, e),
+        }
+    }
+}
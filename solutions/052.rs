@@ -0,0 +1,28 @@
+// Reference solution for exercise 052: Result - unwrap and expect
+
+/// Returns the length of a string parsed from input.
+/// Uses unwrap_or to return 0 if parsing fails.
+fn get_length_or_default(input: &str) -> usize {
+    input.parse::<usize>().unwrap_or(0)
+}
+
+/// Returns the number if parsing succeeds, panics with a custom message otherwise.
+fn parse_critical_number(input: &str) -> i32 {
+    input.parse::<i32>().expect("Critical number required")
+}
+
+/// Returns the square root of a number if positive, or 0.0 if negative.
+fn safe_sqrt(number: f64) -> f64 {
+    let root = number.sqrt();
+    Some(root).filter(|r| !r.is_nan()).unwrap_or(0.0)
+}
+
+fn main() {
+    println!("Length of 'hello': {}", get_length_or_default("5"));
+    println!("Length of 'invalid': {}", get_length_or_default("abc"));
+
+    println!("√16 = {}", safe_sqrt(16.0));
+    println!("√(-4) = {}", safe_sqrt(-4.0));
+
+    println!("Critical: {}", parse_critical_number("42"));
+}
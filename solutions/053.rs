@@ -0,0 +1,66 @@
+// Reference solution for exercise 053: Result - match handling
+
+/// Divides two numbers, returning a Result with proper error messages.
+fn safe_divide(a: f64, b: f64) -> Result<f64, String> {
+    match b {
+        b if b == 0.0 => Err(String::from("Division by zero")),
+        b => Ok(a / b),
+    }
+}
+
+/// Parses two strings as numbers and adds them.
+/// Returns detailed error messages for each parsing failure.
+fn add_strings(a: &str, b: &str) -> Result<i32, String> {
+    let x = match a.parse::<i32>() {
+        Ok(n) => n,
+        Err(e) => return Err(format!("Cannot parse '{}': {}", a, e)),
+    };
+    let y = match b.parse::<i32>() {
+        Ok(n) => n,
+        Err(e) => return Err(format!("Cannot parse '{}': {}", b, e)),
+    };
+    Ok(x + y)
+}
+
+/// Attempts to open a "file" (simulated with input validation).
+/// Returns Ok(filename) if valid, Err otherwise.
+fn validate_filename(filename: &str) -> Result<&str, String> {
+    if filename.is_empty() {
+        return Err(String::from("Filename cannot be empty"));
+    }
+    if !filename.contains('.') {
+        return Err(String::from("Filename needs extension"));
+    }
+    Ok(filename)
+}
+
+fn main() {
+    match safe_divide(10.0, 2.0) {
+        Ok(result) => println!("10 / 2 = {}", result),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match safe_divide(10.0, 0.0) {
+        Ok(result) => println!("10 / 0 = {}", result),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    println!("\nString addition:");
+    match add_strings("10", "20") {
+        Ok(sum) => println!("10 + 20 = {}", sum),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match add_strings("10", "abc") {
+        Ok(sum) => println!("Sum = {}", sum),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    println!("\nFilename validation:");
+    for filename in &["document.txt", "", "readme"] {
+        match validate_filename(filename) {
+            Ok(f) => println!("✓ Valid: {}", f),
+            Err(e) => println!("✗ Invalid '{}': {}", filename, e),
+        }
+    }
+}
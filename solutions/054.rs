@@ -0,0 +1,67 @@
+// Reference solution for exercise 054: Result - ? operator
+
+/// A custom error type for our operations.
+#[derive(Debug, PartialEq)]
+enum MathError {
+    DivisionByZero,
+    InvalidNumber(String),
+    NegativeResult,
+}
+
+impl std::fmt::Display for MathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MathError::DivisionByZero => write!(f, "Cannot divide by zero"),
+            MathError::InvalidNumber(s) => write!(f, "Invalid number: {}", s),
+            MathError::NegativeResult => write!(f, "Result would be negative"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
+
+/// Parses a string to i32, returning a MathError.
+fn parse_number(input: &str) -> Result<i32, MathError> {
+    input
+        .parse::<i32>()
+        .map_err(|_| MathError::InvalidNumber(input.to_string()))
+}
+
+/// Divides two numbers represented as strings.
+/// Uses ? operator to propagate errors.
+fn divide_strings(a: &str, b: &str) -> Result<i32, MathError> {
+    let dividend = parse_number(a)?;
+    let divisor = parse_number(b)?;
+    if divisor == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    Ok(dividend / divisor)
+}
+
+/// Calculates (a / b) - c, all represented as strings.
+/// Uses ? operator to chain multiple fallible operations.
+fn complex_calculation(a: &str, b: &str, c: &str) -> Result<i32, MathError> {
+    let quotient = divide_strings(a, b)?;
+    let c = parse_number(c)?;
+    let result = quotient - c;
+    if result < 0 {
+        return Err(MathError::NegativeResult);
+    }
+    Ok(result)
+}
+
+fn main() {
+    let cases = vec![
+        ("10", "2", "3"),
+        ("10", "0", "3"),
+        ("10", "2", "10"),
+        ("abc", "2", "3"),
+    ];
+
+    for (a, b, c) in cases {
+        match complex_calculation(a, b, c) {
+            Ok(result) => println!("({}/{}) - {} = {}", a, b, c, result),
+            Err(e) => println!("({}/{}) - {} failed: {}", a, b, c, e),
+        }
+    }
+}
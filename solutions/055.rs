@@ -0,0 +1,36 @@
+// Reference solution for exercise 055: Option - unwrap and expect
+
+/// Returns the first element of a slice, or 0 if empty.
+fn first_or_zero(numbers: &[i32]) -> i32 {
+    *numbers.first().unwrap_or(&0)
+}
+
+/// Returns the first character of a string, or '?' if empty.
+fn first_char_or_default(text: &str) -> char {
+    text.chars().next().unwrap_or('?')
+}
+
+/// Returns the last element of a vector, panicking with "Empty vector" if empty.
+fn last_element_required(numbers: &[i32]) -> i32 {
+    *numbers.last().expect("Empty vector")
+}
+
+/// Returns the length of the longest string, or 0 if the vector is empty.
+fn max_length(strings: &[&str]) -> usize {
+    strings.iter().map(|s| s.len()).max().unwrap_or(0)
+}
+
+fn main() {
+    let numbers = vec![10, 20, 30];
+    println!("First of [10, 20, 30]: {}", first_or_zero(&numbers));
+    println!("First of []: {}", first_or_zero(&[]));
+
+    println!("\nFirst char of 'Hello': {}", first_char_or_default("Hello"));
+    println!("First char of '': {}", first_char_or_default(""));
+
+    let words = vec!["apple", "banana", "cherry"];
+    println!("\nMax length in {:?}: {}", words, max_length(&words));
+    println!("Max length in []: {}", max_length(&[]));
+
+    println!("\nLast element: {}", last_element_required(&numbers));
+}
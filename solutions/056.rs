@@ -0,0 +1,73 @@
+// Reference solution for exercise 056: Option - map and and_then
+
+/// Parses a string to i32 and doubles it.
+/// Returns Some(doubled) if parsing succeeds, None otherwise.
+fn parse_and_double(input: &str) -> Option<i32> {
+    input.parse::<i32>().ok().map(|n| n * 2)
+}
+
+/// Parses a string, doubles it, then converts back to string.
+fn parse_double_string(input: &str) -> Option<String> {
+    parse_and_double(input).map(|n| n.to_string())
+}
+
+/// Divides a by b, then adds c to the result.
+/// All inputs are Options, and the function returns Option<i32>.
+fn divide_and_add(a: Option<i32>, b: Option<i32>, c: Option<i32>) -> Option<i32> {
+    a.and_then(|a_value| {
+        b.and_then(|b_value| a_value.checked_div(b_value))
+    })
+    .and_then(|quotient| c.map(|c_value| quotient + c_value))
+}
+
+/// Finds a user by id, then finds their manager.
+/// Returns the manager's name if both exist.
+fn find_manager_name(user_id: i32) -> Option<String> {
+    fn find_user(id: i32) -> Option<User> {
+        let users = vec![
+            User { id: 1, name: "Alice".to_string(), manager_id: Some(2) },
+            User { id: 2, name: "Bob".to_string(), manager_id: None },
+            User { id: 3, name: "Carol".to_string(), manager_id: Some(1) },
+        ];
+        users.into_iter().find(|u| u.id == id)
+    }
+
+    fn find_user_name(id: i32) -> Option<String> {
+        find_user(id).map(|u| u.name)
+    }
+
+    find_user(user_id)
+        .and_then(|user| user.manager_id)
+        .and_then(find_user_name)
+}
+
+#[derive(Debug, Clone)]
+struct User {
+    id: i32,
+    name: String,
+    manager_id: Option<i32>,
+}
+
+fn main() {
+    println!("Parsing and doubling:");
+    for input in &["10", "25", "abc"] {
+        match parse_and_double(input) {
+            Some(n) => println!("  {} * 2 = {}", input, n),
+            None => println!("  {}: Invalid", input),
+        }
+    }
+    println!("  As string: {:?}", parse_double_string("21"));
+
+    println!("\nDivide and add:");
+    println!("  10 / 2 + 5 = {:?}", divide_and_add(Some(10), Some(2), Some(5)));
+    println!("  10 / 0 + 5 = {:?}", divide_and_add(Some(10), Some(0), Some(5)));
+    println!("  None / 2 + 5 = {:?}", divide_and_add(None, Some(2), Some(5)));
+
+    println!("\nFinding managers:");
+    for id in &[1, 2, 3, 99] {
+        match find_manager_name(*id) {
+            Some(name) => println!("  User {}'s manager: {}", id, name),
+            None => println!("  User {} has no manager", id),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Reference solution for exercise 057: Option - unwrap_or and unwrap_or_else
+
+/// Returns the config value or "default" if None.
+fn get_config_or_default(config: Option<&str>) -> String {
+    config.unwrap_or("default").to_string()
+}
+
+/// Returns the number or computes a default based on input.
+fn get_number_or_compute(input: Option<i32>, multiplier: i32) -> i32 {
+    input.unwrap_or_else(|| multiplier * 10)
+}
+
+/// Returns the username or fetches from environment (simulated).
+fn get_username(config: Option<String>) -> String {
+    config.unwrap_or_else(|| String::from("guest"))
+}
+
+/// Returns the max value or 0 if the iterator is empty.
+fn find_max_or_default(numbers: &[i32]) -> i32 {
+    *numbers.iter().max().unwrap_or(&0)
+}
+
+/// Simulates a cache lookup with expensive fallback computation.
+struct Cache {
+    data: std::collections::HashMap<String, i32>,
+}
+
+impl Cache {
+    fn new() -> Self {
+        let mut data = std::collections::HashMap::new();
+        data.insert("key1".to_string(), 100);
+        Self { data }
+    }
+
+    fn get(&self, key: &str) -> Option<i32> {
+        self.data.get(key).copied()
+    }
+
+    /// Gets value from cache or computes it (simulated expensive operation).
+    fn get_or_compute(&self, key: &str) -> i32 {
+        self.get(key).unwrap_or_else(|| key.len() as i32 * 10)
+    }
+}
+
+fn main() {
+    println!("Config values:");
+    println!("  Some('production'): {}", get_config_or_default(Some("production")));
+    println!("  None: {}", get_config_or_default(None));
+
+    println!("\nNumber with compute fallback:");
+    println!("  Some(5) with mult 3: {}", get_number_or_compute(Some(5), 3));
+    println!("  None with mult 3: {}", get_number_or_compute(None, 3));
+
+    println!("\nUsernames:");
+    println!("  Some('ferris'): {}", get_username(Some(String::from("ferris"))));
+    println!("  None: {}", get_username(None));
+
+    let numbers = vec![3, 1, 4, 1, 5, 9];
+    println!("\nMax values:");
+    println!("  Max of {:?}: {}", numbers, find_max_or_default(&numbers));
+    println!("  Max of []: {}", find_max_or_default(&[]));
+
+    let cache = Cache::new();
+    println!("\nCache lookups:");
+    println!("  key1: {}", cache.get_or_compute("key1"));
+    println!("  key2 (computed as {} * 10): {}", "key2".len(), cache.get_or_compute("key2"));
+}
@@ -0,0 +1,106 @@
+// Reference solution for exercise 058: Error Propagation
+
+use std::collections::HashMap;
+
+/// A custom error type for file operations.
+#[derive(Debug, PartialEq)]
+enum FileError {
+    NotFound(String),
+    PermissionDenied(String),
+    InvalidFormat(String),
+    EmptyFile,
+}
+
+impl std::fmt::Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FileError::NotFound(s) => write!(f, "File not found: {}", s),
+            FileError::PermissionDenied(s) => write!(f, "Permission denied: {}", s),
+            FileError::InvalidFormat(s) => write!(f, "Invalid format: {}", s),
+            FileError::EmptyFile => write!(f, "File is empty"),
+        }
+    }
+}
+
+/// Simulates reading a file from disk.
+fn read_file(filename: &str) -> Result<String, FileError> {
+    let files: HashMap<&str, &str> = [
+        ("data.txt", "10\n20\n30"),
+        ("empty.txt", ""),
+        ("bad.txt", "10\nabc\n30"),
+    ]
+    .into_iter()
+    .collect();
+    
+    match filename {
+        "secret.txt" => Err(FileError::PermissionDenied(filename.to_string())),
+        name => files
+            .get(name)
+            .map(|&content| content.to_string())
+            .ok_or_else(|| FileError::NotFound(filename.to_string())),
+    }
+}
+
+/// Parses a line as a number, converting errors to FileError.
+fn parse_line(line: &str, line_num: usize) -> Result<i32, FileError> {
+    line.trim()
+        .parse::<i32>()
+        .map_err(|_| FileError::InvalidFormat(format!("line {}: '{}' is not a number", line_num, line)))
+}
+
+/// Reads and sums all numbers in a file.
+/// Propagates errors using ? operator.
+fn sum_file_numbers(filename: &str) -> Result<i32, FileError> {
+    let content = read_file(filename)?;
+    if content.is_empty() {
+        return Err(FileError::EmptyFile);
+    }
+    let mut sum = 0;
+    for (i, line) in content.lines().enumerate() {
+        sum += parse_line(line, i + 1)?;
+    }
+    Ok(sum)
+}
+
+/// Processes multiple files, collecting results.
+fn process_files(filenames: &[&str]) -> Vec<Result<i32, FileError>> {
+    filenames.iter().map(|&f| sum_file_numbers(f)).collect()
+}
+
+/// Attempts to sum all valid files, reporting errors for invalid ones.
+fn sum_all_valid_files(filenames: &[&str]) -> (i32, Vec<String>) {
+    let results = process_files(filenames);
+    let mut total = 0;
+    let mut errors = Vec::new();
+    
+    for result in results {
+        match result {
+            Ok(sum) => total += sum,
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    
+    (total, errors)
+}
+
+fn main() {
+    let files = vec!["data.txt", "empty.txt", "bad.txt", "secret.txt", "missing.txt"];
+    
+    println!("Processing files individually:\n");
+    for filename in &files {
+        match sum_file_numbers(filename) {
+            Ok(sum) => println!("✓ {}: sum = {}", filename, sum),
+            Err(e) => println!("✗ {}: {}", filename, e),
+        }
+    }
+    
+    println!("\n---\n");
+    
+    let (total, errors) = sum_all_valid_files(&files);
+    println!("Batch processing:");
+    println!("  Total sum: {}", total);
+    println!("  Errors ({}):", errors.len());
+    for error in errors {
+        println!("    - {}", error);
+    }
+}
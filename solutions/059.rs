@@ -0,0 +1,73 @@
+// Reference solution for exercise 059: Generic Functions
+
+/// Returns the first element of a slice, regardless of type.
+fn first<T>(items: &[T]) -> Option<&T> {
+    items.first()
+}
+
+/// Swaps two values of the same type.
+fn swap<T>(a: T, b: T) -> (T, T) {
+    (b, a)
+}
+
+/// Returns the larger of two values (works for any comparable type).
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a >= b { a } else { b }
+}
+
+/// Prints debug information for any type that implements Debug.
+fn debug_info<T: std::fmt::Debug>(value: &T) {
+    println!("Debug: {:?}", value);
+}
+
+/// Combines two values into a tuple.
+fn pair<T, U>(a: T, b: U) -> (T, U) {
+    (a, b)
+}
+
+/// Compares two values and returns true if they are equal.
+/// Works for any type that implements PartialEq.
+fn are_equal<T: PartialEq>(a: &T, b: &T) -> bool {
+    a == b
+}
+
+fn main() {
+    // Test first with different types
+    let numbers = vec![10, 20, 30];
+    let words = vec!["hello", "world"];
+
+    println!("First of numbers: {:?}", first(&numbers));
+    println!("First of words: {:?}", first(&words));
+    println!("First of empty: {:?}", first(&[] as &[i32]));
+
+    // Test swap
+    println!("\nSwapping:");
+    let (a, b) = swap(1, 2);
+    println!("  swap(1, 2) = ({}, {})", a, b);
+    let (x, y) = swap("hello", "world");
+    println!("  swap('hello', 'world') = ({}, {})", x, y);
+
+    // Test max
+    println!("\nMax values:");
+    println!("  max(5, 10) = {}", max(5, 10));
+    println!("  max(3.14, 2.71) = {}", max(3.14, 2.71));
+    println!("  max('a', 'z') = {}", max('a', 'z'));
+
+    // Test debug_info
+    println!("\nDebug info:");
+    debug_info(&42);
+    debug_info(&"hello");
+    debug_info(&vec![1, 2, 3]);
+
+    // Test pair
+    println!("\nPairs:");
+    let p1 = pair(1, "one");
+    println!("  pair(1, 'one') = {:?}", p1);
+    let p2 = pair(true, 3.14);
+    println!("  pair(true, 3.14) = {:?}", p2);
+
+    // Test are_equal
+    println!("\nEquality checks:");
+    println!("  are_equal(&5, &5) = {}", are_equal(&5, &5));
+    println!("  are_equal(&'a', &'b') = {}", are_equal(&'a', &'b'));
+}
@@ -0,0 +1,124 @@
+// Reference solution for exercise 060: Generic Structs
+
+/// A generic container that holds a single value.
+struct Container<T> {
+    value: T,
+}
+
+impl<T> Container<T> {
+    /// Creates a new Container with the given value.
+    fn new(value: T) -> Self {
+        Container { value }
+    }
+
+    /// Returns a reference to the contained value.
+    fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Replaces the contained value and returns the old one.
+    fn set(&mut self, value: T) -> T {
+        std::mem::replace(&mut self.value, value)
+    }
+}
+
+/// A generic Key-Value pair.
+struct KeyValue<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K, V> KeyValue<K, V> {
+    /// Creates a new KeyValue pair.
+    fn new(key: K, value: V) -> Self {
+        KeyValue { key, value }
+    }
+
+    /// Returns references to both key and value.
+    fn get(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+
+    /// Swaps the key and value (only works when K and V are the same type).
+    fn swap(self) -> KeyValue<V, K> {
+        KeyValue {
+            key: self.value,
+            value: self.key,
+        }
+    }
+}
+
+/// A generic stack data structure.
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates a new empty stack.
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// Pushes an item onto the stack.
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Pops an item from the stack.
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns true if the stack is empty.
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of items in the stack.
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns a reference to the top item without removing it.
+    fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+}
+
+fn main() {
+    // Test Container
+    let mut container = Container::new(42);
+    println!("Container value: {}", container.get());
+    let old = container.set(100);
+    println!("Old value: {}, New value: {}", old, container.get());
+
+    // Test Container with different types
+    let string_container = Container::new("hello");
+    println!("String container: {}", string_container.get());
+
+    // Test KeyValue
+    let kv = KeyValue::new("name", "Alice");
+    let (k, v) = kv.get();
+    println!("\nKeyValue: {} = {}", k, v);
+
+    let number_kv = KeyValue::new(1, 100);
+    let swapped = number_kv.swap();
+    let (new_k, new_v) = swapped.get();
+    println!("Swapped: {} = {}", new_k, new_v);
+
+    // Test Stack
+    let mut stack = Stack::new();
+    println!("\nStack operations:");
+    println!("  Is empty? {}", stack.is_empty());
+
+    stack.push(10);
+    stack.push(20);
+    stack.push(30);
+    println!("  Pushed 10, 20, 30. Length: {}", stack.len());
+    println!("  Peek: {:?}", stack.peek());
+
+    while let Some(item) = stack.pop() {
+        println!("  Popped: {}", item);
+    }
+    println!("  Is empty? {}", stack.is_empty());
+}
@@ -0,0 +1,176 @@
+// Reference solution for exercise 061: Generic Enums
+
+/// A generic Result-like enum for operations that can succeed or fail.
+enum OperationResult<T, E> {
+    Success(T),
+    Failure(E),
+}
+
+impl<T, E> OperationResult<T, E> {
+    /// Returns true if this is a Success.
+    fn is_success(&self) -> bool {
+        matches!(self, OperationResult::Success(_))
+    }
+
+    /// Returns true if this is a Failure.
+    fn is_failure(&self) -> bool {
+        matches!(self, OperationResult::Failure(_))
+    }
+
+    /// Maps a Success value using the provided function.
+    fn map<U, F: FnOnce(T) -> U>(self, f: F) -> OperationResult<U, E> {
+        match self {
+            OperationResult::Success(value) => OperationResult::Success(f(value)),
+            OperationResult::Failure(err) => OperationResult::Failure(err),
+        }
+    }
+
+    /// Returns the contained Success value or a default.
+    fn unwrap_or(self, default: T) -> T {
+        match self {
+            OperationResult::Success(value) => value,
+            OperationResult::Failure(_) => default,
+        }
+    }
+}
+
+/// An enum representing a value that may or may not be present.
+enum Maybe<T> {
+    Just(T),
+    Nothing,
+}
+
+impl<T> Maybe<T> {
+    /// Creates a new Just value.
+    fn just(value: T) -> Self {
+        Maybe::Just(value)
+    }
+
+    /// Creates a Nothing value.
+    fn nothing() -> Self {
+        Maybe::Nothing
+    }
+
+    /// Returns true if this is Just.
+    fn is_just(&self) -> bool {
+        matches!(self, Maybe::Just(_))
+    }
+
+    /// Maps the contained value if present.
+    fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Maybe<U> {
+        match self {
+            Maybe::Just(value) => Maybe::Just(f(value)),
+            Maybe::Nothing => Maybe::Nothing,
+        }
+    }
+
+    /// Returns the contained value or a default.
+    fn unwrap_or(self, default: T) -> T {
+        match self {
+            Maybe::Just(value) => value,
+            Maybe::Nothing => default,
+        }
+    }
+}
+
+/// A state machine enum with generic data for each state.
+enum State<S, R, F> {
+    Starting(S),
+    Running(R),
+    Finished(F),
+}
+
+impl<S, R, F> State<S, R, F> {
+    /// Returns the name of the current state as a string.
+    fn state_name(&self) -> &'static str {
+        match self {
+            State::Starting(_) => "Starting",
+            State::Running(_) => "Running",
+            State::Finished(_) => "Finished",
+        }
+    }
+
+    /// Transitions from Starting to Running.
+    fn start(self, running_data: R) -> Self {
+        match self {
+            State::Starting(_) => State::Running(running_data),
+            other => other,
+        }
+    }
+
+    /// Transitions from Running to Finished.
+    fn finish(self, finished_data: F) -> Self {
+        match self {
+            State::Running(_) => State::Finished(finished_data),
+            other => other,
+        }
+    }
+}
+
+/// Either type - holds one of two possible values.
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns true if this is Left.
+    fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns true if this is Right.
+    fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns the Left value or a default.
+    fn left_or(self, default: L) -> L {
+        match self {
+            Either::Left(value) => value,
+            Either::Right(_) => default,
+        }
+    }
+}
+
+fn main() {
+    // Test OperationResult
+    let success: OperationResult<i32, &str> = OperationResult::Success(42);
+    let failure: OperationResult<i32, &str> = OperationResult::Failure("error");
+
+    println!("OperationResult tests:");
+    println!("  Success is_success: {}", success.is_success());
+    println!("  Failure is_failure: {}", failure.is_failure());
+    let doubled = success.map(|x| x * 2);
+    println!("  Success doubled: {:?}", doubled.is_success());
+    println!("  Success doubled unwrap_or 0: {}", doubled.unwrap_or(0));
+    println!("  Failure unwrap_or 0: {}", failure.unwrap_or(0));
+
+    // Test Maybe
+    println!("\nMaybe tests:");
+    let just_five = Maybe::just(5);
+    let nothing: Maybe<i32> = Maybe::nothing();
+
+    println!("  Just(5) is_just: {}", just_five.is_just());
+    println!("  Nothing is_just: {}", nothing.is_just());
+    println!("  Just(5) * 2: {:?}", just_five.map(|x| x * 2).unwrap_or(0));
+    println!("  Nothing or 10: {}", nothing.unwrap_or(10));
+
+    // Test State
+    println!("\nState tests:");
+    let starting: State<&str, i32, bool> = State::Starting("initializing");
+    println!("  Initial state: {}", starting.state_name());
+    let running = starting.start(42);
+    println!("  After start: {}", running.state_name());
+    let finished = running.finish(true);
+    println!("  After finish: {}", finished.state_name());
+
+    // Test Either
+    println!("\nEither tests:");
+    let left: Either<i32, &str> = Either::Left(42);
+    let right: Either<i32, &str> = Either::Right("hello");
+    println!("  Left is_left: {}, is_right: {}", left.is_left(), left.is_right());
+    println!("  Right is_left: {}, is_right: {}", right.is_left(), right.is_right());
+    println!("  Left or 0: {}", left.left_or(0));
+    println!("  Right or 0: {}", right.left_or(0));
+}
@@ -0,0 +1,164 @@
+// Reference solution for exercise 062: Traits - Defining
+
+/// A trait for types that can be summarized with a short description.
+trait Summarizable {
+    /// Returns a brief summary of the object.
+    fn summary(&self) -> String;
+
+    /// Returns a detailed description (has default implementation).
+    fn details(&self) -> String {
+        format!("Details: {}", self.summary())
+    }
+}
+
+/// A trait for measuring the size of something.
+trait Measurable {
+    /// Returns the size as a f64 value.
+    fn size(&self) -> f64;
+
+    /// Returns the unit of measurement.
+    fn unit(&self) -> &'static str;
+
+    /// Formats the size with unit.
+    fn format_size(&self) -> String {
+        format!("{:.2} {}", self.size(), self.unit())
+    }
+}
+
+/// A trait for types that can be validated.
+trait Validatable {
+    /// Returns true if the object is valid.
+    fn is_valid(&self) -> bool;
+
+    /// Returns a list of validation errors.
+    fn errors(&self) -> Vec<String>;
+}
+
+/// A trait for objects that can be serialized to string.
+trait Serializable {
+    /// Serializes the object to a string.
+    fn serialize(&self) -> String;
+
+    /// Returns the format name (JSON, XML, etc.).
+    fn format() -> &'static str where Self: Sized;
+}
+
+/// A trait for objects that can be compared for equality in a specific way.
+trait Equivalent<Rhs = Self> {
+    /// Returns true if self is equivalent to other.
+    fn is_equivalent(&self, other: &Rhs) -> bool;
+}
+
+/// A trait for objects that have a unique identifier.
+trait Identifiable {
+    /// The type of the identifier.
+    type Id: std::fmt::Display;
+
+    /// Returns the unique identifier.
+    fn id(&self) -> Self::Id;
+}
+
+// Example implementations for primitive types
+impl Summarizable for i32 {
+    fn summary(&self) -> String {
+        format!("Integer: {}", self)
+    }
+}
+
+impl Summarizable for String {
+    fn summary(&self) -> String {
+        if self.len() > 20 {
+            format!("\"{}...\" ({} chars)", &self[..20], self.len())
+        } else {
+            format!("\"{}\"", self)
+        }
+    }
+}
+
+impl Measurable for f64 {
+    fn size(&self) -> f64 {
+        *self
+    }
+
+    fn unit(&self) -> &'static str {
+        "meters"
+    }
+}
+
+impl Validatable for String {
+    fn is_valid(&self) -> bool {
+        self.errors().is_empty()
+    }
+
+    fn errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.is_empty() {
+            errors.push(String::from("must not be empty"));
+        }
+        if self.len() > 100 {
+            errors.push(format!("must be at most 100 characters, got {}", self.len()));
+        }
+        errors
+    }
+}
+
+impl Serializable for i32 {
+    fn serialize(&self) -> String {
+        self.to_string()
+    }
+
+    fn format() -> &'static str {
+        "JSON"
+    }
+}
+
+impl Equivalent<String> for &str {
+    fn is_equivalent(&self, other: &String) -> bool {
+        self.to_lowercase() == other.to_lowercase()
+    }
+}
+
+/// A user account identified by a numeric id.
+struct Account {
+    number: u32,
+    owner: String,
+}
+
+impl Identifiable for Account {
+    type Id = u32;
+
+    fn id(&self) -> Self::Id {
+        self.number
+    }
+}
+
+fn main() {
+    // Test Summarizable
+    let num = 42i32;
+    let text = String::from("Hello, World!");
+    let long_text = String::from("This is a very long string that exceeds twenty characters");
+
+    println!("Summarizable examples:");
+    println!("  Number: {}", num.summary());
+    println!("  Text: {}", text.summary());
+    println!("  Long text: {}", long_text.summary());
+    println!("  Long text details: {}", long_text.details());
+
+    let length = 5.5f64;
+    println!("\nMeasurable: {}", length.format_size());
+
+    let empty = String::new();
+    let valid = String::from("Hello");
+    println!("\nValidatable:");
+    println!("  Empty is valid: {} {:?}", empty.is_valid(), empty.errors());
+    println!("  Valid is valid: {}", valid.is_valid());
+
+    let num = 42i32;
+    println!("\nSerializable: {} format: {}", num.serialize(), i32::format());
+
+    let s = "HELLO";
+    println!("\nEquivalent: 'HELLO' == 'hello': {}", s.is_equivalent(&"hello".to_string()));
+
+    let account = Account { number: 1001, owner: String::from("Ferris") };
+    println!("\nIdentifiable: {} has id {}", account.owner, account.id());
+}
@@ -0,0 +1,136 @@
+// Reference solution for exercise 063: Traits - Implementing
+
+/// A trait for objects that have an area.
+trait Area {
+    fn area(&self) -> f64;
+}
+
+/// A trait for objects that have a perimeter.
+trait Perimeter {
+    fn perimeter(&self) -> f64;
+}
+
+/// A trait for displayable objects.
+trait Displayable {
+    fn display(&self) -> String;
+}
+
+// Shape definitions
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+struct Circle {
+    radius: f64,
+}
+
+struct Triangle {
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Area for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+impl Perimeter for Rectangle {
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+}
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+}
+
+impl Perimeter for Circle {
+    fn perimeter(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+}
+
+impl Area for Triangle {
+    /// Heron's formula.
+    fn area(&self) -> f64 {
+        let s = (self.a + self.b + self.c) / 2.0;
+        (s * (s - self.a) * (s - self.b) * (s - self.c)).sqrt()
+    }
+}
+
+impl Perimeter for Triangle {
+    fn perimeter(&self) -> f64 {
+        self.a + self.b + self.c
+    }
+}
+
+impl Displayable for Rectangle {
+    fn display(&self) -> String {
+        format!("Rectangle: {} x {}", self.width, self.height)
+    }
+}
+
+impl Displayable for Circle {
+    fn display(&self) -> String {
+        format!("Circle: radius = {}", self.radius)
+    }
+}
+
+impl Displayable for Triangle {
+    fn display(&self) -> String {
+        format!("Triangle: sides = {}, {}, {}", self.a, self.b, self.c)
+    }
+}
+
+// Generic wrapper struct
+struct Measured<T> {
+    value: T,
+    unit: String,
+}
+
+impl<T> Measured<T> {
+    fn new(value: T, unit: &str) -> Self {
+        Self {
+            value,
+            unit: unit.to_string(),
+        }
+    }
+}
+
+impl<T: Displayable> Displayable for Measured<T> {
+    fn display(&self) -> String {
+        format!("{} ({})", self.value.display(), self.unit)
+    }
+}
+
+fn main() {
+    let rect = Rectangle { width: 10.0, height: 5.0 };
+    let circle = Circle { radius: 3.0 };
+    let triangle = Triangle { a: 3.0, b: 4.0, c: 5.0 };
+
+    println!("Shapes and their properties:\n");
+
+    println!("Rectangle:");
+    println!("  Display: {}", rect.display());
+    println!("  Area: {:.2}", rect.area());
+    println!("  Perimeter: {:.2}", rect.perimeter());
+
+    println!("\nCircle:");
+    println!("  Display: {}", circle.display());
+    println!("  Area: {:.2}", circle.area());
+    println!("  Perimeter (Circumference): {:.2}", circle.perimeter());
+
+    println!("\nTriangle:");
+    println!("  Display: {}", triangle.display());
+    println!("  Area: {:.2}", triangle.area());
+    println!("  Perimeter: {:.2}", triangle.perimeter());
+
+    println!("\nMeasured values:");
+    let measured_rect = Measured::new(rect, "cm");
+    println!("  {}", measured_rect.display());
+}
@@ -0,0 +1,134 @@
+// Reference solution for exercise 065: Traits as Parameters
+
+/// A trait for objects that can make noise.
+trait SoundMaker {
+    fn make_sound(&self) -> String;
+    fn volume(&self) -> u8 {
+        50 // default volume
+    }
+}
+
+// Concrete types
+struct Dog;
+struct Cat;
+struct Car {
+    model: String,
+}
+
+impl SoundMaker for Dog {
+    fn make_sound(&self) -> String {
+        "Woof!".to_string()
+    }
+    fn volume(&self) -> u8 {
+        80
+    }
+}
+
+impl SoundMaker for Cat {
+    fn make_sound(&self) -> String {
+        "Meow!".to_string()
+    }
+    fn volume(&self) -> u8 {
+        40
+    }
+}
+
+impl SoundMaker for Car {
+    fn make_sound(&self) -> String {
+        "Vroom!".to_string()
+    }
+    fn volume(&self) -> u8 {
+        90
+    }
+}
+
+/// References to sound makers make the same sound, so slices of
+/// `&dyn SoundMaker` can be passed where `impl SoundMaker` is expected.
+impl<T: SoundMaker + ?Sized> SoundMaker for &T {
+    fn make_sound(&self) -> String {
+        (**self).make_sound()
+    }
+    fn volume(&self) -> u8 {
+        (**self).volume()
+    }
+}
+
+/// Takes anything that makes sound and prints its sound.
+fn announce(sound_maker: &impl SoundMaker) {
+    println!("Making sound: {}", sound_maker.make_sound());
+    println!("Volume: {}", sound_maker.volume());
+}
+
+/// Takes two sound makers and returns the louder one.
+fn loudest<'a>(a: &'a impl SoundMaker, b: &'a impl SoundMaker) -> &'a dyn SoundMaker {
+    if a.volume() >= b.volume() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Takes a slice of sound makers and returns their sounds.
+fn collect_sounds(items: &[impl SoundMaker]) -> Vec<String> {
+    items.iter().map(|item| item.make_sound()).collect()
+}
+
+/// Creates a sound maker based on input (simplified factory).
+fn create_sound_maker(animal: &str) -> Box<dyn SoundMaker> {
+    match animal {
+        "dog" => Box::new(Dog),
+        "cat" => Box::new(Cat),
+        _ => Box::new(Car { model: animal.to_string() }),
+    }
+}
+
+/// Filters sound makers by minimum volume.
+fn loud_sounds<'a>(
+    items: &'a [Box<dyn SoundMaker>],
+    min_volume: u8,
+) -> Vec<&'a dyn SoundMaker> {
+    items
+        .iter()
+        .filter(|item| item.volume() >= min_volume)
+        .map(|item| item.as_ref())
+        .collect()
+}
+
+fn main() {
+    let dog = Dog;
+    let cat = Cat;
+    let car = Car { model: "Ferrari".to_string() };
+
+    println!("Individual announcements:");
+    announce(&dog);
+    announce(&cat);
+    println!("The {}:", car.model);
+    announce(&car);
+
+    println!("\nLoudest:");
+    let louder = loudest(&dog, &cat);
+    println!("Louder: {} (volume {})", louder.make_sound(), louder.volume());
+
+    println!("\nCollecting sounds:");
+    let animals: Vec<&dyn SoundMaker> = vec![&dog, &cat, &car];
+    let sounds = collect_sounds(&animals);
+    for sound in sounds {
+        println!("  {}", sound);
+    }
+
+    println!("\nFactory pattern:");
+    let makers = vec![
+        create_sound_maker("dog"),
+        create_sound_maker("cat"),
+        create_sound_maker("truck"),
+    ];
+    for maker in &makers {
+        println!("  {} (volume: {})", maker.make_sound(), maker.volume());
+    }
+
+    println!("\nLoud sounds only (min 70):");
+    let loud = loud_sounds(&makers, 70);
+    for maker in loud {
+        println!("  {} (volume: {})", maker.make_sound(), maker.volume());
+    }
+}
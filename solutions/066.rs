@@ -0,0 +1,125 @@
+// Reference solution for exercise 066: Trait Bounds
+
+use std::fmt::Debug;
+
+/// Returns the maximum of two values.
+fn generic_max<T: PartialOrd>(a: T, b: T) -> T {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Finds the largest element in a slice.
+fn find_largest<T: PartialOrd>(items: &[T]) -> Option<&T> {
+    let mut largest = items.first()?;
+    for item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+/// Sorts a slice of items in place.
+fn sort_items<T: Ord>(items: &mut [T]) {
+    items.sort();
+}
+
+/// Compares two items and returns their ordering.
+fn compare_items<T: PartialOrd>(a: &T, b: &T) -> std::cmp::Ordering {
+    a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+}
+
+/// Formats a collection of items for display.
+fn format_collection<T: Debug>(items: &[T]) -> String {
+    format!("{:?}", items)
+}
+
+/// Clones a vector of items.
+fn clone_vec<T: Clone>(items: &[T]) -> Vec<T> {
+    items.to_vec()
+}
+
+/// A function requiring multiple trait bounds.
+fn process_and_display<T>(items: &[T]) -> String
+where
+    T: PartialOrd + Debug + Clone,
+{
+    match find_largest(items) {
+        Some(largest) => format!("Largest: {:?}", largest.clone()),
+        None => String::from("Largest: none"),
+    }
+}
+
+/// Combines two values into a pair if they're equal.
+fn pair_if_equal<T: PartialEq>(a: T, b: T) -> Option<(T, T)> {
+    if a == b {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// Sums a collection of numeric values.
+fn generic_sum<T>(items: &[T]) -> T
+where
+    T: std::ops::Add<Output = T> + Clone + Default,
+{
+    items.iter().cloned().fold(T::default(), |acc, item| acc + item)
+}
+
+/// A generic wrapper that requires Display.
+struct DisplayWrapper<T: std::fmt::Display> {
+    value: T,
+}
+
+impl<T: std::fmt::Display> DisplayWrapper<T> {
+    fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    fn display(&self) -> String {
+        format!("Wrapped: {}", self.value)
+    }
+}
+
+fn main() {
+    println!("Generic max:");
+    println!("  max(5, 10) = {}", generic_max(5, 10));
+    println!("  max(3.14, 2.71) = {}", generic_max(3.14, 2.71));
+    println!("  max('a', 'z') = {}", generic_max('a', 'z'));
+
+    let numbers = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    println!("\nFind largest in {:?}: {:?}", numbers, find_largest(&numbers));
+    println!("Find largest in []: {:?}", find_largest(&[] as &[i32]));
+
+    let mut to_sort = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("\nSorting {:?}", to_sort);
+    sort_items(&mut to_sort);
+    println!("Sorted: {:?}", to_sort);
+
+    println!("\nComparisons:");
+    println!("  compare(5, 10) = {:?}", compare_items(&5, &10));
+    println!("  compare(10, 5) = {:?}", compare_items(&10, &5));
+    println!("  compare(5, 5) = {:?}", compare_items(&5, &5));
+
+    let items = vec![1, 2, 3];
+    println!("\nFormat collection: {}", format_collection(&items));
+
+    let cloned = clone_vec(&items);
+    println!("Cloned: {:?}", cloned);
+
+    println!("\nProcess and display: {}", process_and_display(&numbers));
+
+    println!("\nPair if equal:");
+    println!("  pair_if_equal(5, 5) = {:?}", pair_if_equal(5, 5));
+    println!("  pair_if_equal(5, 6) = {:?}", pair_if_equal(5, 6));
+
+    let nums = vec![1, 2, 3, 4, 5];
+    println!("\nGeneric sum of {:?} = {}", nums, generic_sum(&nums));
+
+    let wrapper = DisplayWrapper::new(42);
+    println!("\nDisplay wrapper: {}", wrapper.display());
+}
@@ -0,0 +1,69 @@
+// Reference solution for exercise 067: Lifetimes - Basic Annotation
+
+/// Returns the longer of two string slices.
+/// The returned reference must not outlive either input.
+fn longer_str<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() >= b.len() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the first element of a slice.
+fn first_elem<'a, T>(items: &'a [T]) -> &'a T {
+    &items[0]
+}
+
+/// A function that takes two references and returns one of them.
+fn choose_reference<'a>(use_first: bool, first: &'a str, second: &'a str) -> &'a str {
+    if use_first {
+        first
+    } else {
+        second
+    }
+}
+
+/// Returns a reference to the value with the greater length.
+fn longer_by_value<'a, T: std::fmt::Display>(a: &'a T, b: &'a T) -> &'a T {
+    if a.to_string().len() >= b.to_string().len() {
+        a
+    } else {
+        b
+    }
+}
+
+/// A struct that holds a reference to a string.
+struct StringHolder<'a> {
+    text: &'a str,
+}
+
+impl<'a> StringHolder<'a> {
+    /// Creates a new StringHolder.
+    fn new(text: &'a str) -> StringHolder<'a> {
+        StringHolder { text }
+    }
+
+    /// Returns the held string.
+    fn get(&self) -> &'a str {
+        self.text
+    }
+}
+
+fn main() {
+    let s1 = String::from("short");
+    let s2 = String::from("much longer string");
+
+    println!("Longer string: {}", longer_str(&s1, &s2));
+
+    let items = vec![1, 2, 3, 4, 5];
+    println!("First element: {}", first_elem(&items));
+
+    let chosen = choose_reference(true, "first", "second");
+    println!("Chosen: {}", chosen);
+
+    let holder = StringHolder::new("hello world");
+    println!("Holder contains: {}", holder.get());
+
+    println!("Longer by value: {}", longer_by_value(&1000, &42));
+}
@@ -0,0 +1,80 @@
+// Reference solution for exercise 068: Lifetimes - In Functions
+
+/// Returns the first string if it's longer, otherwise the second.
+fn first_if_longer<'a>(first: &'a str, second: &'a str) -> &'a str {
+    if first.len() > second.len() {
+        first
+    } else {
+        second
+    }
+}
+
+/// Returns a substring from start to end index.
+fn substring(text: &str, start: usize, end: usize) -> &str {
+    &text[start..end.min(text.len())]
+}
+
+/// Returns the string that comes first alphabetically.
+fn earliest_alphabetically<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Finds a word in a sentence and returns it.
+fn find_word<'a>(sentence: &'a str, word: &str) -> Option<&'a str> {
+    sentence.find(word).map(|start| &sentence[start..start + word.len()])
+}
+
+/// Splits a string at the first occurrence of a delimiter.
+fn split_at_delimiter(text: &str, delimiter: char) -> (&str, &str) {
+    match text.find(delimiter) {
+        Some(i) => (&text[..i], &text[i + delimiter.len_utf8()..]),
+        None => (text, ""),
+    }
+}
+
+/// Returns all lines from text that contain the pattern.
+fn lines_containing<'a>(text: &'a str, pattern: &str) -> Vec<&'a str> {
+    text.lines().filter(|line| line.contains(pattern)).collect()
+}
+
+/// Trims whitespace from both ends.
+fn trim_both_ends(text: &str) -> &str {
+    text.trim()
+}
+
+fn main() {
+    let text = "The quick brown fox jumps over the lazy dog";
+
+    println!("Text: {}", text);
+    println!("First if longer (vs 'hello world'): {}",
+        first_if_longer(text, "hello world"));
+
+    println!("\nSubstring (0-10): {}", substring(text, 0, 10));
+    println!("Substring (20-30): {}", substring(text, 20, 30));
+
+    let word1 = "apple";
+    let word2 = "banana";
+    println!("\nEarliest alphabetically: {}",
+        earliest_alphabetically(word1, word2));
+
+    match find_word(text, "fox") {
+        Some(found) => println!("\nFound word: {}", found),
+        None => println!("\nWord not found"),
+    }
+
+    let (before, after) = split_at_delimiter(text, 'j');
+    println!("\nSplit at 'j':");
+    println!("  Before: {}", before);
+    println!("  After: {}", after);
+
+    let lines = "Line one\nLine two with fox\nLine three";
+    let matching = lines_containing(lines, "fox");
+    println!("\nLines containing 'fox': {:?}", matching);
+
+    let spaced = "   hello world   ";
+    println!("\nTrimmed: '{}'", trim_both_ends(spaced));
+}
@@ -0,0 +1,137 @@
+// Reference solution for exercise 069: Lifetimes - In Structs
+
+/// A struct that holds a reference to text.
+struct TextHolder<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl<'a> TextHolder<'a> {
+    /// Creates a new TextHolder with the given text range.
+    fn new(text: &'a str, start: usize, end: usize) -> TextHolder<'a> {
+        let end = end.min(text.len());
+        let start = start.min(end);
+        TextHolder { text, start, end }
+    }
+
+    /// Returns the held text slice.
+    fn get(&self) -> &'a str {
+        &self.text[self.start..self.end]
+    }
+
+    /// Returns the length of the held text.
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if the holder is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A struct that holds two references with potentially different lifetimes.
+struct PairHolder<'a> {
+    first: &'a str,
+    second: &'a str,
+}
+
+impl<'a> PairHolder<'a> {
+    /// Creates a new PairHolder.
+    fn new(first: &'a str, second: &'a str) -> PairHolder<'a> {
+        PairHolder { first, second }
+    }
+
+    /// Returns the longer of the two strings.
+    fn longer(&self) -> &'a str {
+        if self.first.len() >= self.second.len() {
+            self.first
+        } else {
+            self.second
+        }
+    }
+
+    /// Returns a concatenated view (conceptually, just for demonstration).
+    fn describe(&self) -> String {
+        format!("First: '{}', Second: '{}'", self.first, self.second)
+    }
+}
+
+/// A generic wrapper that holds a reference to any type.
+struct RefBox<'a, T> {
+    value: &'a T,
+}
+
+impl<'a, T> RefBox<'a, T> {
+    /// Creates a new RefBox.
+    fn new(value: &'a T) -> RefBox<'a, T> {
+        RefBox { value }
+    }
+
+    /// Returns a reference to the contained value.
+    fn get(&self) -> &'a T {
+        self.value
+    }
+
+    /// Maps the contained value using a function.
+    fn map<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        f(self.value)
+    }
+}
+
+/// A parser that holds a reference to input text.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new Parser.
+    fn new(input: &'a str) -> Parser<'a> {
+        Parser { input, position: 0 }
+    }
+
+    /// Returns the remaining unparsed text.
+    fn remaining(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Advances the position by n characters.
+    fn advance(&mut self, n: usize) {
+        self.position = (self.position + n).min(self.input.len());
+    }
+
+    /// Parses the next word (alphanumeric characters).
+    fn next_word(&mut self) -> Option<&'a str> {
+        let rest = self.remaining();
+        let start = rest.find(|c: char| c.is_alphanumeric())?;
+        let len = rest[start..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len() - start);
+        let word = &rest[start..start + len];
+        self.advance(start + len);
+        Some(word)
+    }
+}
+
+fn main() {
+    let text = "Hello, World!";
+    let holder = TextHolder::new(text, 0, 5);
+    println!("TextHolder: '{}' (len: {}, empty: {})", holder.get(), holder.len(), holder.is_empty());
+
+    let pair = PairHolder::new("first", "second longer");
+    println!("\nPairHolder: {}", pair.describe());
+    println!("Longer: '{}'", pair.longer());
+
+    let num = 42;
+    let ref_box = RefBox::new(&num);
+    println!("\nRefBox value: {}", ref_box.get());
+    println!("RefBox doubled: {}", ref_box.map(|n| n * 2));
+
+    let mut parser = Parser::new("Hello World 123");
+    println!("\nParsing:");
+    while let Some(word) = parser.next_word() {
+        println!("  Word: '{}'", word);
+    }
+}
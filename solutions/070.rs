@@ -0,0 +1,120 @@
+// Reference solution for exercise 070: Static Lifetime
+
+use std::any::Any;
+
+/// Returns a static string literal.
+fn get_static_string() -> &'static str {
+    "Hello from the data segment"
+}
+
+/// Returns a static error message based on an error code.
+fn error_message(code: u32) -> &'static str {
+    match code {
+        404 => "Not found",
+        500 => "Server error",
+        _ => "Unknown error",
+    }
+}
+
+/// A trait for objects that can provide a static description.
+trait StaticallyDescribable {
+    fn static_description() -> &'static str;
+}
+
+// Types to implement the trait for
+struct User;
+struct Admin;
+struct Guest;
+
+impl StaticallyDescribable for User {
+    fn static_description() -> &'static str {
+        "A regular user account"
+    }
+}
+
+impl StaticallyDescribable for Admin {
+    fn static_description() -> &'static str {
+        "An administrator with full privileges"
+    }
+}
+
+impl StaticallyDescribable for Guest {
+    fn static_description() -> &'static str {
+        "A guest with limited access"
+    }
+}
+
+/// A registry of static strings for lookup.
+static STATIC_STRINGS: &[&str] = &[
+    "Rust",
+    "is",
+    "awesome",
+];
+
+/// Returns a reference to the static strings array.
+fn get_static_strings() -> &'static [&'static str] {
+    STATIC_STRINGS
+}
+
+/// A function that accepts only 'static data.
+fn accept_static<T: 'static>(_value: T) {}
+
+/// A trait object type that requires 'static lifetime.
+type StaticAny = Box<dyn Any + 'static>;
+
+/// Creates a boxed 'static value.
+fn box_static<T: 'static>(value: T) -> StaticAny {
+    Box::new(value)
+}
+
+/// A configuration struct with static strings.
+struct StaticConfig {
+    name: &'static str,
+    version: &'static str,
+    author: &'static str,
+}
+
+impl StaticConfig {
+    /// Creates a default configuration.
+    fn default() -> Self {
+        StaticConfig {
+            name: "Rust Learning Ground",
+            version: "1.0.0",
+            author: "Ferris",
+        }
+    }
+
+    /// Returns a formatted description.
+    fn description(&self) -> String {
+        format!("{} v{} by {}", self.name, self.version, self.author)
+    }
+}
+
+fn main() {
+    println!("Static string: {}", get_static_string());
+
+    println!("\nError messages:");
+    for code in &[404, 500, 999] {
+        println!("  Code {}: {}", code, error_message(*code));
+    }
+
+    println!("\nStatic descriptions:");
+    println!("  User: {}", User::static_description());
+    println!("  Admin: {}", Admin::static_description());
+    println!("  Guest: {}", Guest::static_description());
+
+    println!("\nStatic strings: {:?}", get_static_strings());
+
+    // These all have 'static lifetime
+    accept_static(42i32);
+    accept_static("hello");
+    accept_static(vec![1, 2, 3]);
+
+    let boxed: StaticAny = box_static(42);
+    if let Some(num) = boxed.downcast_ref::<i32>() {
+        println!("\nBoxed value: {}", num);
+    }
+
+    let config = StaticConfig::default();
+    println!("\nConfig: {}", config.description());
+}
@@ -0,0 +1,94 @@
+// Reference solution for exercise 071: Closures - Basic Syntax
+
+/// Returns a closure that adds a constant to its input.
+fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+/// Returns a closure that multiplies its input by a constant.
+fn make_multiplier(factor: i32) -> impl Fn(i32) -> i32 {
+    move |x| x * factor
+}
+
+/// Returns a closure that checks if a number is greater than a threshold.
+fn make_threshold_checker(threshold: i32) -> impl Fn(i32) -> bool {
+    move |x| x > threshold
+}
+
+/// Applies a function to each element of a vector and returns the results.
+fn map_vector<F, T, U>(items: Vec<T>, f: F) -> Vec<U>
+where
+    F: Fn(T) -> U,
+{
+    items.into_iter().map(f).collect()
+}
+
+/// Returns a closure that formats a greeting.
+fn make_greeter(greeting: &str) -> impl Fn(&str) -> String + '_ {
+    move |name| format!("{}, {}!", greeting, name)
+}
+
+/// Creates a closure that concatenates two strings with a separator.
+fn make_concatenator(separator: &str) -> impl Fn(&str, &str) -> String + '_ {
+    move |a, b| format!("{}{}{}", a, separator, b)
+}
+
+/// Returns a closure that counts how many times it's been called.
+fn make_counter() -> impl FnMut() -> i32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+/// Returns a closure that accumulates values.
+fn make_accumulator() -> impl FnMut(i32) -> i32 {
+    let mut total = 0;
+    move |x| {
+        total += x;
+        total
+    }
+}
+
+fn main() {
+    println!("Adder closures:");
+    let add_five = make_adder(5);
+    println!("  add_five(10) = {}", add_five(10));
+    println!("  add_five(3) = {}", add_five(3));
+
+    let double = make_multiplier(2);
+    println!("\nMultiplier closures:");
+    println!("  double(7) = {}", double(7));
+    println!("  double(15) = {}", double(15));
+
+    let is_adult = make_threshold_checker(18);
+    println!("\nThreshold checker:");
+    println!("  is_adult(21) = {}", is_adult(21));
+    println!("  is_adult(16) = {}", is_adult(16));
+
+    let numbers = vec![1, 2, 3, 4, 5];
+    let doubled = map_vector(numbers.clone(), |x| x * 2);
+    println!("\nMap vector: {:?} -> {:?}", numbers, doubled);
+
+    let square_strings = map_vector(numbers, |x| format!("{}² = {}", x, x * x));
+    println!("As strings: {:?}", square_strings);
+
+    let greeter = make_greeter("Hello");
+    println!("\nGreeting: {}", greeter("World"));
+
+    let join_with_comma = make_concatenator(", ");
+    println!("Concatenation: '{}'", join_with_comma("apple", "banana"));
+
+    let mut counter = make_counter();
+    println!("\nCounter:");
+    println!("  Call 1: {}", counter());
+    println!("  Call 2: {}", counter());
+    println!("  Call 3: {}", counter());
+
+    let mut acc = make_accumulator();
+    println!("\nAccumulator:");
+    println!("  Add 5: {}", acc(5));
+    println!("  Add 3: {}", acc(3));
+    println!("  Add 10: {}", acc(10));
+}
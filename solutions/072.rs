@@ -0,0 +1,111 @@
+// Reference solution for exercise 072: Closures - Capturing Environment
+
+/// Creates a closure that captures by immutable reference.
+fn capture_by_reference(items: &Vec<i32>) -> impl Fn() -> usize + '_ {
+    move || items.len()
+}
+
+/// Creates a closure that captures by mutable reference.
+fn capture_by_mutable_reference(count: &mut i32) -> impl FnMut() + '_ {
+    move || {
+        *count += 1;
+        println!("  Count: {}", count);
+    }
+}
+
+/// Creates a closure that captures by value (takes ownership).
+fn capture_by_value(name: String) -> impl FnOnce() -> String {
+    move || name
+}
+
+/// Creates a closure that sorts a vector in place.
+fn make_sorter<T: Ord>(ascending: bool) -> impl FnMut(&mut Vec<T>) {
+    move |items: &mut Vec<T>| {
+        if ascending {
+            items.sort();
+        } else {
+            items.sort_by(|a, b| b.cmp(a));
+        }
+    }
+}
+
+/// Creates a filter function based on a threshold.
+fn make_filter(min_value: i32) -> impl Fn(&i32) -> bool {
+    move |&value| value >= min_value
+}
+
+/// Creates a closure that formats strings with a prefix and suffix.
+fn make_formatter(prefix: String, suffix: String) -> impl Fn(&str) -> String {
+    move |text| format!("{}{}{}", prefix, text, suffix)
+}
+
+/// Demonstrates FnOnce by consuming a value.
+fn create_consumer<T>(data: Vec<T>) -> impl FnOnce() -> Vec<T> {
+    move || data
+}
+
+/// Creates a closure that modifies captured state on each call.
+fn make_stateful_processor() -> impl FnMut(i32) -> i32 {
+    let mut sum = 0;
+    let mut count = 0;
+    move |value| {
+        sum += value;
+        count += 1;
+        sum / count
+    }
+}
+
+fn main() {
+    println!("Capture by reference:");
+    let items = vec![1, 2, 3, 4, 5];
+    let get_len = capture_by_reference(&items);
+    println!("  Length: {}", get_len());
+    println!("  Length again: {}", get_len());
+    // items is still usable here!
+    println!("  Items still available: {:?}", items);
+
+    println!("\nCapture by mutable reference:");
+    let mut count = 0;
+    {
+        let mut increment = capture_by_mutable_reference(&mut count);
+        increment();
+        increment();
+        increment();
+    }
+    println!("  Final count: {}", count);
+
+    println!("\nCapture by value:");
+    let name = String::from("Alice");
+    let consume = capture_by_value(name);
+    // name is no longer usable here!
+    println!("  Consumed: {}", consume());
+
+    println!("\nSorter closure:");
+    let mut sorter: Box<dyn FnMut(&mut Vec<i32>)> = Box::new(make_sorter(true));
+    let mut nums = vec![3, 1, 4, 1, 5];
+    sorter(&mut nums);
+    println!("  Ascending: {:?}", nums);
+    let mut reverse_sorter = make_sorter(false);
+    reverse_sorter(&mut nums);
+    println!("  Descending: {:?}", nums);
+
+    println!("\nFilter closure:");
+    let is_positive = make_filter(0);
+    let numbers = vec![-5, 3, -2, 8, 0, 10];
+    let positive: Vec<_> = numbers.iter().filter(|&&n| is_positive(&n)).collect();
+    println!("  Positive numbers: {:?}", positive);
+
+    println!("\nConsumer closure:");
+    let consumer = create_consumer(vec!["a", "b"]);
+    println!("  Returned: {:?}", consumer());
+
+    println!("\nFormatter closure:");
+    let bracket_formatter = make_formatter("[".to_string(), "]".to_string());
+    println!("  {}", bracket_formatter("hello"));
+
+    println!("\nStateful processor:");
+    let mut processor = make_stateful_processor();
+    println!("  Process 10: {}", processor(10));
+    println!("  Process 20: {}", processor(20));
+    println!("  Process 30: {}", processor(30));
+}
@@ -0,0 +1,70 @@
+// Reference solution for exercise 073: Iterators - map and filter
+
+/// Doubles all numbers in a vector.
+fn double_all(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().map(|n| n * 2).collect()
+}
+
+/// Returns only the even numbers from a vector.
+fn get_evens(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().filter(|&&n| n % 2 == 0).copied().collect()
+}
+
+/// Returns the lengths of all strings in a vector.
+fn string_lengths(strings: &[&str]) -> Vec<usize> {
+    strings.iter().map(|s| s.len()).collect()
+}
+
+/// Returns only strings longer than a given length.
+fn long_strings<'a>(strings: &[&'a str], min_length: usize) -> Vec<&'a str> {
+    strings.iter().filter(|s| s.len() > min_length).copied().collect()
+}
+
+/// Squares all odd numbers and returns them.
+fn square_odds(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().filter(|&&n| n % 2 != 0).map(|n| n * n).collect()
+}
+
+/// Converts a list of optional numbers to their values, filtering out None.
+fn filter_map_optionals(optionals: &[Option<i32>]) -> Vec<i32> {
+    optionals.iter().filter_map(|&opt| opt).collect()
+}
+
+/// Returns the first letter of each name, uppercased.
+fn first_letters(names: &[&str]) -> Vec<char> {
+    names
+        .iter()
+        .filter_map(|name| name.chars().next())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Doubles positive numbers, filters out non-positive.
+fn double_positives(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().filter(|&&n| n > 0).map(|n| n * 2).collect()
+}
+
+fn main() {
+    let numbers = vec![1, 2, 3, 4, 5, 6];
+    println!("Original: {:?}", numbers);
+    println!("Doubled: {:?}", double_all(&numbers));
+    println!("Evens: {:?}", get_evens(&numbers));
+    println!("Square odds: {:?}", square_odds(&numbers));
+
+    let words = vec!["hello", "hi", "world", "x", "rust"];
+    println!("\nWords: {:?}", words);
+    println!("Lengths: {:?}", string_lengths(&words));
+    println!("Long strings (>2): {:?}", long_strings(&words, 2));
+
+    let maybe_numbers = vec![Some(1), None, Some(2), None, Some(3)];
+    println!("\nOptionals: {:?}", maybe_numbers);
+    println!("Filtered: {:?}", filter_map_optionals(&maybe_numbers));
+
+    let names = vec!["alice", "bob", "charlie"];
+    println!("\nNames: {:?}", names);
+    println!("First letters: {:?}", first_letters(&names));
+
+    let mixed = vec![-2, -1, 0, 1, 2, 3];
+    println!("\nMixed numbers: {:?}", mixed);
+    println!("Double positives: {:?}", double_positives(&mixed));
+}
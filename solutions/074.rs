@@ -0,0 +1,97 @@
+// Reference solution for exercise 074: Iterators - collect
+
+use std::collections::{HashMap, HashSet};
+
+/// Collects numbers into a vector.
+fn to_vector(numbers: &[i32]) -> Vec<i32> {
+    numbers.iter().copied().collect()
+}
+
+/// Collects unique numbers into a HashSet.
+fn to_hashset(numbers: &[i32]) -> HashSet<i32> {
+    numbers.iter().copied().collect()
+}
+
+/// Creates a HashMap from pairs of keys and values.
+fn pairs_to_map(keys: &[&str], values: &[i32]) -> HashMap<String, i32> {
+    keys.iter()
+        .map(|k| k.to_string())
+        .zip(values.iter().copied())
+        .collect()
+}
+
+/// Collects iterator results into a Result<Vec<T>, E>.
+fn try_collect(numbers: &[&str]) -> Result<Vec<i32>, std::num::ParseIntError> {
+    numbers.iter().map(|s| s.parse::<i32>()).collect()
+}
+
+/// Collects valid parses only, filtering out errors.
+fn collect_valid(numbers: &[&str]) -> Vec<i32> {
+    numbers.iter().filter_map(|s| s.parse::<i32>().ok()).collect()
+}
+
+/// Joins strings into a single string separated by commas.
+fn join_with_comma(strings: &[&str]) -> String {
+    strings.join(",")
+}
+
+/// Creates a frequency map of characters.
+fn char_frequency(text: &str) -> HashMap<char, usize> {
+    text.chars().fold(HashMap::new(), |mut freq, c| {
+        *freq.entry(c).or_insert(0) += 1;
+        freq
+    })
+}
+
+/// Groups numbers by even/odd into a HashMap.
+fn group_by_parity(numbers: &[i32]) -> HashMap<bool, Vec<i32>> {
+    numbers.iter().fold(HashMap::new(), |mut groups, &n| {
+        groups.entry(n % 2 == 0).or_insert_with(Vec::new).push(n);
+        groups
+    })
+}
+
+/// Collects iterator of Results, partitioning into Ok and Err values.
+fn partition_results(strings: &[&str]) -> (Vec<i32>, Vec<String>) {
+    let (oks, errs): (Vec<_>, Vec<_>) = strings
+        .iter()
+        .map(|s| s.parse::<i32>().map_err(|e| format!("{}: {}", s, e)))
+        .partition(|r| r.is_ok());
+    let oks = oks.into_iter().filter_map(Result::ok).collect();
+    let errs = errs.into_iter().filter_map(Result::err).collect();
+    (oks, errs)
+}
+
+fn main() {
+    let numbers = vec![1, 2, 3, 2, 1];
+    println!("To vector: {:?}", to_vector(&numbers));
+    println!("To hashset: {:?}", to_hashset(&numbers));
+
+    let keys = vec!["one", "two", "three"];
+    let values = vec![1, 2, 3];
+    println!("\nPairs to map: {:?}", pairs_to_map(&keys, &values));
+
+    let parse_attempts = vec!["10", "20", "30"];
+    match try_collect(&parse_attempts) {
+        Ok(nums) => println!("\nParsed: {:?}", nums),
+        Err(e) => println!("\nParse error: {}", e),
+    }
+
+    let mixed = vec!["1", "abc", "2", "xyz", "3"];
+    println!("Valid parses: {:?}", collect_valid(&mixed));
+
+    let words = vec!["apple", "banana", "cherry"];
+    println!("\nJoined: {}", join_with_comma(&words));
+
+    let text = "hello world";
+    println!("\nChar frequency in '{}': {:?}", text, char_frequency(text));
+
+    let nums = vec![1, 2, 3, 4, 5, 6];
+    println!("\nGrouped by parity: {:?}", group_by_parity(&nums));
+
+    let to_parse = vec!["10", "20", "abc", "30", "xyz"];
+    let (successes, failures) = partition_results(&to_parse);
+    println!("\nPartitioned results:");
+    println!("  Successes: {:?}", successes);
+    println!("  Failures: {:?}", failures);
+}
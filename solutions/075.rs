@@ -0,0 +1,117 @@
+// Reference solution for exercise 075: Iterators - fold and reduce
+
+/// Calculates the sum of all numbers using fold.
+fn fold_sum(numbers: &[i32]) -> i32 {
+    numbers.iter().fold(0, |acc, n| acc + n)
+}
+
+/// Calculates the product of all numbers using fold.
+fn fold_product(numbers: &[i32]) -> i32 {
+    numbers.iter().fold(1, |acc, n| acc * n)
+}
+
+/// Finds the maximum value using fold.
+fn fold_max(numbers: &[i32]) -> Option<i32> {
+    let (first, rest) = numbers.split_first()?;
+    Some(rest.iter().fold(*first, |max, &n| if n > max { n } else { max }))
+}
+
+/// Concatenates all strings using fold.
+fn fold_concatenate(strings: &[&str]) -> String {
+    strings.iter().fold(String::new(), |mut acc, s| {
+        acc.push_str(s);
+        acc
+    })
+}
+
+/// Counts how many elements satisfy a predicate using fold.
+fn fold_count_if<F>(numbers: &[i32], predicate: F) -> usize
+where
+    F: Fn(i32) -> bool,
+{
+    numbers
+        .iter()
+        .fold(0, |count, &n| if predicate(n) { count + 1 } else { count })
+}
+
+/// Reverses a vector using fold.
+fn fold_reverse<T: Clone>(items: &[T]) -> Vec<T> {
+    items.iter().fold(Vec::with_capacity(items.len()), |mut acc, item| {
+        acc.insert(0, item.clone());
+        acc
+    })
+}
+
+/// Finds the longest string using reduce.
+fn reduce_longest<'a>(strings: &[&'a str]) -> Option<&'a str> {
+    strings
+        .iter()
+        .copied()
+        .reduce(|longest, s| if s.len() > longest.len() { s } else { longest })
+}
+
+/// Calculates the average using fold (returns 0.0 for empty).
+fn fold_average(numbers: &[f64]) -> f64 {
+    let (sum, count) = numbers
+        .iter()
+        .fold((0.0, 0usize), |(sum, count), &n| (sum + n, count + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+/// Builds a frequency map using fold.
+fn fold_frequency(items: &[char]) -> std::collections::HashMap<char, usize> {
+    items
+        .iter()
+        .fold(std::collections::HashMap::new(), |mut freq, &c| {
+            *freq.entry(c).or_insert(0) += 1;
+            freq
+        })
+}
+
+/// Flattens a nested vector using fold.
+fn fold_flatten<T: Clone>(nested: &[Vec<T>]) -> Vec<T> {
+    nested.iter().fold(Vec::new(), |mut acc, inner| {
+        acc.extend_from_slice(inner);
+        acc
+    })
+}
+
+fn main() {
+    let numbers = vec![1, 2, 3, 4, 5];
+    println!("Numbers: {:?}", numbers);
+    println!("Sum (fold): {}", fold_sum(&numbers));
+    println!("Product (fold): {}", fold_product(&numbers));
+    println!("Max (fold): {:?}", fold_max(&numbers));
+
+    let words = vec!["Hello", " ", "World", "!"];
+    println!("\nWords: {:?}", words);
+    println!("Concatenated: '{}'", fold_concatenate(&words));
+
+    let evens_count = fold_count_if(&numbers, |n| n % 2 == 0);
+    println!("\nEven numbers count: {}", evens_count);
+
+    let reversed = fold_reverse(&numbers);
+    println!("Reversed: {:?}", reversed);
+
+    let fruits = vec!["apple", "banana", "cherry", "date"];
+    match reduce_longest(&fruits) {
+        Some(longest) => println!("\nLongest fruit: '{}'", longest),
+        None => println!("\nNo fruits"),
+    }
+
+    let floats = vec![1.0, 2.0, 3.0, 4.0];
+    println!("\nFloats: {:?}", floats);
+    println!("Average: {:.2}", fold_average(&floats));
+
+    let chars = vec!['a', 'b', 'a', 'c', 'b', 'a'];
+    println!("\nChars: {:?}", chars);
+    println!("Frequency: {:?}", fold_frequency(&chars));
+
+    let nested = vec![vec![1, 2], vec![3, 4], vec![5, 6]];
+    println!("\nNested: {:?}", nested);
+    println!("Flattened: {:?}", fold_flatten(&nested));
+}
@@ -0,0 +1,21 @@
+// Reference solution for exercise 076: Box<T> - Heap Allocation
+
+fn main() {
+    let boxed_value: Box<i32> = Box::new(42);
+
+    println!("The boxed value is: {}", *boxed_value);
+
+    let inner_value = unbox_value(boxed_value);
+    println!("Inner value: {}", inner_value);
+
+    // Only the pointer moves; the 4000-byte array stays where it is.
+    let large_array: Box<[i32; 1000]> = Box::new([7; 1000]);
+    println!("Large array created on heap, first element: {}", large_array[0]);
+
+    let first_element = (*large_array)[0];
+    println!("First element via dereference: {}", first_element);
+}
+
+fn unbox_value(b: Box<i32>) -> i32 {
+    *b
+}
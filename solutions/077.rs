@@ -0,0 +1,50 @@
+// Reference solution for exercise 077: Box<T> - Recursive Types
+
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    fn new() -> List {
+        Nil
+    }
+
+    fn prepend(self, elem: i32) -> List {
+        Cons(elem, Box::new(self))
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Cons(_, tail) => 1 + tail.len(),
+            Nil => 0,
+        }
+    }
+
+    fn stringify(&self) -> String {
+        match self {
+            Cons(head, tail) => format!("{}, {}", head, tail.stringify()),
+            Nil => String::from("Nil"),
+        }
+    }
+}
+
+fn main() {
+    let list = List::new();
+    println!("Empty list length: {}", list.len());
+
+    let list = list.prepend(3).prepend(2).prepend(1);
+    println!("List: {}", list.stringify());
+    println!("List length: {}", list.len());
+
+    let long_list = List::new()
+        .prepend(10)
+        .prepend(20)
+        .prepend(30)
+        .prepend(40)
+        .prepend(50);
+    println!("Long list: {}", long_list.stringify());
+    println!("Long list length: {}", long_list.len());
+}
@@ -0,0 +1,42 @@
+// Reference solution for exercise 078: Rc<T> - Reference Counting
+
+use std::rc::Rc;
+
+fn main() {
+    let data: Rc<String> = Rc::new(String::from("shared data"));
+    println!("Reference count after creation: {}", Rc::strong_count(&data));
+
+    let data2: Rc<String> = Rc::clone(&data);
+    println!("Reference count after first clone: {}", Rc::strong_count(&data));
+
+    let data3: Rc<String> = Rc::clone(&data);
+    println!("Reference count after second clone: {}", Rc::strong_count(&data));
+
+    println!("data: {}", data);
+    println!("data2: {}", data2);
+    println!("data3: {}", data3);
+
+    {
+        let data4 = Rc::clone(&data);
+        println!("Reference count inside scope: {}", Rc::strong_count(&data4));
+    }
+    println!("Reference count after scope: {}", Rc::strong_count(&data));
+
+    print_shared(Rc::clone(&data));
+    print_shared(Rc::clone(&data2));
+    print_shared(Rc::clone(&data3));
+    println!("Reference count after calls: {}", Rc::strong_count(&data));
+
+    println!("data and data2 point to same data: {}", Rc::ptr_eq(&data, &data2));
+
+    let hello = create_shared_hello();
+    println!("data and hello point to same data: {}", Rc::ptr_eq(&data, &hello));
+}
+
+fn print_shared(data: Rc<String>) {
+    println!("Shared: {} (count: {})", data, Rc::strong_count(&data));
+}
+
+fn create_shared_hello() -> Rc<String> {
+    Rc::new(String::from("hello"))
+}
@@ -0,0 +1,63 @@
+// Reference solution for exercise 079: RefCell<T> - Interior Mutability
+
+use std::cell::RefCell;
+
+fn main() {
+    let data: RefCell<Vec<i32>> = RefCell::new(vec![1, 2, 3]);
+
+    data.borrow_mut().push(4);
+
+    println!("Vector contents: {:?}", data.borrow());
+
+    append_to_vec(&data, 5);
+
+    println!("Final vector: {:?}", data.borrow());
+
+    let borrow1 = data.borrow();
+    let borrow2 = data.borrow();
+    println!("Two immutable borrows work: {:?}, {:?}", borrow1, borrow2);
+    drop(borrow1);
+    drop(borrow2);
+
+    // A live mutable borrow makes any other borrow fail at runtime.
+    let mut_borrow = data.borrow_mut();
+    println!("Borrow while mutably borrowed fails: {}", data.try_borrow().is_err());
+    drop(mut_borrow);
+
+    let counter = RefCell::new(0);
+    increment_counter(&counter);
+    increment_counter(&counter);
+    println!("Counter after increments: {}", counter.borrow());
+
+    let account = BankAccount::new(100);
+    account.deposit(25);
+    println!("Account balance: {}", account.get_balance());
+}
+
+fn increment_counter(counter: &RefCell<i32>) {
+    *counter.borrow_mut() += 1;
+}
+
+fn append_to_vec(vec: &RefCell<Vec<i32>>, value: i32) {
+    vec.borrow_mut().push(value);
+}
+
+struct BankAccount {
+    balance: RefCell<i32>,
+}
+
+impl BankAccount {
+    fn new(initial_balance: i32) -> Self {
+        BankAccount {
+            balance: RefCell::new(initial_balance),
+        }
+    }
+
+    fn deposit(&self, amount: i32) {
+        *self.balance.borrow_mut() += amount;
+    }
+
+    fn get_balance(&self) -> i32 {
+        *self.balance.borrow()
+    }
+}
@@ -0,0 +1,64 @@
+// Reference solution for exercise 080: Arc<T> - Atomic Reference Counting
+
+use std::sync::Arc;
+use std::thread;
+
+fn main() {
+    let data: Arc<String> = Arc::new(String::from("shared across threads"));
+    println!("Initial reference count: {}", Arc::strong_count(&data));
+
+    let mut handles = vec![];
+
+    for i in 0..5 {
+        let thread_data: Arc<String> = Arc::clone(&data);
+
+        println!("Before spawn {}: reference count = {}", i, Arc::strong_count(&data));
+
+        let handle = thread::spawn(move || {
+            println!("Thread {} sees: {}", i, thread_data);
+            println!("Thread {} reference count: {}", i, Arc::strong_count(&thread_data));
+        });
+
+        handles.push(handle);
+    }
+
+    println!("After spawns: reference count = {}", Arc::strong_count(&data));
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("After joins: reference count = {}", Arc::strong_count(&data));
+
+    let numbers: Arc<Vec<i32>> = make_shared(vec![1, 2, 3, 4, 5]);
+
+    let mut sum_handles = vec![];
+
+    for thread_id in 0..3 {
+        let nums = Arc::clone(&numbers);
+        let handle = thread::spawn(move || {
+            let sum: i32 = nums.iter().sum();
+            println!("Thread {} calculated sum: {}", thread_id, sum);
+        });
+        sum_handles.push(handle);
+    }
+
+    for handle in sum_handles {
+        handle.join().unwrap();
+    }
+
+    spawn_with_data(numbers).join().unwrap();
+}
+
+fn make_shared<T>(value: T) -> Arc<T> {
+    Arc::new(value)
+}
+
+fn spawn_with_data<T>(data: Arc<T>) -> thread::JoinHandle<()>
+where
+    T: Send + Sync + 'static + std::fmt::Debug,
+{
+    thread::spawn(move || {
+        println!("Data: {:?}", data);
+    })
+}
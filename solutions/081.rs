@@ -0,0 +1,119 @@
+// Reference solution for exercise 081: Mutex<T> - Mutual Exclusion
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn main() {
+    let counter = Arc::new(Mutex::new(0));
+
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter_clone = Arc::clone(&counter);
+
+        let handle = thread::spawn(move || {
+            for _ in 0..10 {
+                increment_shared_counter(&counter_clone);
+            }
+        });
+
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Final counter: {}", *counter.lock().unwrap());
+
+    let shared_vec = Arc::new(Mutex::new(Vec::new()));
+    let mut vec_handles = vec![];
+
+    for i in 0..5 {
+        let vec_clone = Arc::clone(&shared_vec);
+        let handle = thread::spawn(move || {
+            vec_clone.lock().unwrap().push(i);
+        });
+        vec_handles.push(handle);
+    }
+
+    for handle in vec_handles {
+        handle.join().unwrap();
+    }
+    let mut pushed = shared_vec.lock().unwrap().clone();
+    pushed.sort();
+    println!("Shared vector (sorted): {:?}", pushed);
+
+    let tally = ThreadSafeCounter::new();
+    tally.increment();
+    tally.increment();
+    println!("ThreadSafeCounter: {}", tally.get());
+
+    let account = ThreadSafeAccount::new(100);
+    println!("Deposit 50: {:?}", account.deposit(50));
+    println!("Withdraw 500: {:?}", account.withdraw(500));
+    println!("Withdraw 30: {:?}", account.withdraw(30));
+    println!("Balance: {}", account.balance());
+}
+
+fn increment_shared_counter(counter: &Arc<Mutex<i32>>) {
+    let mut num = counter.lock().unwrap();
+    *num += 1;
+}
+
+struct ThreadSafeCounter {
+    count: Mutex<i32>,
+}
+
+impl ThreadSafeCounter {
+    fn new() -> Self {
+        ThreadSafeCounter {
+            count: Mutex::new(0),
+        }
+    }
+
+    fn increment(&self) {
+        *self.count.lock().unwrap() += 1;
+    }
+
+    fn get(&self) -> i32 {
+        *self.count.lock().unwrap()
+    }
+}
+
+struct ThreadSafeAccount {
+    balance: Mutex<i64>,
+}
+
+impl ThreadSafeAccount {
+    fn new(initial_balance: i64) -> Self {
+        ThreadSafeAccount {
+            balance: Mutex::new(initial_balance),
+        }
+    }
+
+    fn deposit(&self, amount: i64) -> Result<(), &'static str> {
+        if amount < 0 {
+            return Err("Deposit amount must not be negative");
+        }
+        *self.balance.lock().unwrap() += amount;
+        Ok(())
+    }
+
+    fn withdraw(&self, amount: i64) -> Result<(), &'static str> {
+        if amount < 0 {
+            return Err("Withdrawal amount must not be negative");
+        }
+        // Check and update under one lock so no other thread can slip in between.
+        let mut balance = self.balance.lock().unwrap();
+        if amount > *balance {
+            return Err("Insufficient funds");
+        }
+        *balance -= amount;
+        Ok(())
+    }
+
+    fn balance(&self) -> i64 {
+        *self.balance.lock().unwrap()
+    }
+}
@@ -0,0 +1,86 @@
+// Reference solution for exercise 082: Threads - Spawn and Join
+
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let handle = thread::spawn(|| {
+        println!("Hello from spawned thread!");
+    });
+
+    println!("Hello from main thread!");
+
+    handle.join().unwrap();
+
+    let mut handles = vec![];
+
+    for i in 1..=5 {
+        let handle = thread::spawn(move || {
+            println!("Thread {} starting", i);
+            thread::sleep(Duration::from_millis(100));
+            println!("Thread {} finished", i);
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("All threads completed!");
+
+    let mut handles = vec![];
+
+    for i in 1..=3 {
+        let handle = thread::spawn(move || {
+            let duration = Duration::from_millis((4 - i) * 100);
+            println!("Thread {} sleeping for {:?}", i, duration);
+            thread::sleep(duration);
+            println!("Thread {} done!", i);
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let answer = spawn_computation(|| 6 * 7);
+    println!("Computed in thread: {}", answer.join().unwrap());
+
+    for handle in spawn_n_threads(3) {
+        handle.join().unwrap();
+    }
+
+    let data: Vec<i32> = (1..=100).collect();
+    println!("Parallel sum: {}", parallel_sum(data, 4));
+}
+
+fn spawn_computation<F>(f: F) -> thread::JoinHandle<i32>
+where
+    F: FnOnce() -> i32 + Send + 'static,
+{
+    thread::spawn(f)
+}
+
+fn spawn_n_threads(n: usize) -> Vec<thread::JoinHandle<()>> {
+    let mut handles = vec![];
+    for i in 0..n {
+        handles.push(thread::spawn(move || {
+            println!("Worker {}", i);
+        }));
+    }
+    handles
+}
+
+fn parallel_sum(data: Vec<i32>, num_threads: usize) -> i32 {
+    let chunk_size = data.len().div_ceil(num_threads.max(1)).max(1);
+    let handles: Vec<_> = data
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let chunk = chunk.to_vec();
+            thread::spawn(move || chunk.iter().sum::<i32>())
+        })
+        .collect();
+    handles.into_iter().map(|h| h.join().unwrap()).sum()
+}
@@ -0,0 +1,90 @@
+// Reference solution for exercise 083: Threads - Move Closures
+
+use std::thread;
+
+fn main() {
+    let message = String::from("Hello from thread!");
+
+    let handle = thread::spawn(move || {
+        println!("{}", message);
+    });
+
+    handle.join().unwrap();
+
+    let numbers = vec![1, 2, 3, 4, 5];
+
+    let handle = thread::spawn(move || numbers.iter().sum::<i32>());
+
+    let result = handle.join().unwrap();
+    println!("Sum from thread: {}", result);
+
+    let name = String::from("Alice");
+    let age = 30;
+    let city = String::from("New York");
+
+    let handle = thread::spawn(move || {
+        println!("Name: {}, Age: {}, City: {}", name, age, city);
+    });
+    handle.join().unwrap();
+
+    let data = String::from("temporary");
+    let handle = thread::spawn(move || {
+        println!("{}", data);
+    });
+    handle.join().unwrap();
+
+    let shared_message = String::from("Shared");
+    let mut handles = vec![];
+
+    for i in 0..3 {
+        let msg = shared_message.clone();
+        let handle = thread::spawn(move || {
+            println!("Thread {}: {}", i, msg);
+        });
+        handles.push(handle);
+    }
+
+    for h in handles {
+        h.join().unwrap();
+    }
+    println!("Original message still available: {}", shared_message);
+
+    spawn_with_data(vec![1, 2, 3], |v| println!("Got {} items", v.len()))
+        .join()
+        .unwrap();
+
+    let doubled = process_vector_in_thread(vec![1, 2, 3], |v| v.into_iter().map(|x| x * 2).collect());
+    println!("Processed: {:?}", doubled.join().unwrap());
+
+    let worker = Worker::new(String::from("report.txt"));
+    println!("Worker returned: {}", worker.spawn_work().join().unwrap());
+}
+
+fn spawn_with_data<T, F>(data: T, f: F) -> thread::JoinHandle<()>
+where
+    T: Send + 'static,
+    F: FnOnce(T) + Send + 'static,
+{
+    thread::spawn(move || f(data))
+}
+
+fn process_vector_in_thread<F>(vec: Vec<i32>, processor: F) -> thread::JoinHandle<Vec<i32>>
+where
+    F: FnOnce(Vec<i32>) -> Vec<i32> + Send + 'static,
+{
+    thread::spawn(move || processor(vec))
+}
+
+struct Worker {
+    data: String,
+}
+
+impl Worker {
+    fn new(data: String) -> Self {
+        Worker { data }
+    }
+
+    fn spawn_work(self) -> thread::JoinHandle<String> {
+        thread::spawn(move || self.data)
+    }
+}
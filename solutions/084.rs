@@ -0,0 +1,93 @@
+// Reference solution for exercise 084: Channels - mpsc Basics
+
+use std::sync::mpsc;
+use std::thread;
+
+fn main() {
+    let (tx, rx): (mpsc::Sender<String>, mpsc::Receiver<String>) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        let message = String::from("Hello from sender!");
+        tx.send(message).unwrap();
+        println!("Message sent!");
+    });
+
+    let received = rx.recv().unwrap();
+    println!("Received: {}", received);
+
+    handle.join().unwrap();
+
+    let (tx, rx) = mpsc::channel::<i32>();
+
+    thread::spawn(move || {
+        for i in 1..=5 {
+            tx.send(i).unwrap();
+            println!("Sent: {}", i);
+        }
+    });
+
+    for _ in 1..=5 {
+        let received = rx.recv().unwrap();
+        println!("Received: {}", received);
+    }
+
+    let (tx, rx) = mpsc::channel::<String>();
+
+    let tx2 = tx.clone();
+
+    thread::spawn(move || {
+        tx.send(String::from("From thread 1")).unwrap();
+    });
+
+    thread::spawn(move || {
+        tx2.send(String::from("From thread 2")).unwrap();
+    });
+
+    // Order is not guaranteed.
+    for _ in 0..2 {
+        println!("Received: {}", rx.recv().unwrap());
+    }
+
+    println!("{}", spawn_worker("job 7").recv().unwrap());
+
+    let orphan = create_i32_channel();
+    println!("Send without a receiver fails: {}", orphan.send(1).is_err());
+
+    let (system, inbox) = MessageSystem::new();
+    system.send(String::from("ping"));
+    println!("MessageSystem delivered: {}", inbox.recv().unwrap());
+}
+
+fn spawn_worker<T>(data: T) -> mpsc::Receiver<String>
+where
+    T: Send + 'static + std::fmt::Display,
+{
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let msg = format!("Processed: {}", data);
+        tx.send(msg).unwrap();
+    });
+
+    rx
+}
+
+fn create_i32_channel() -> mpsc::Sender<i32> {
+    let (tx, _rx) = mpsc::channel();
+    tx
+}
+
+struct MessageSystem {
+    sender: mpsc::Sender<String>,
+}
+
+impl MessageSystem {
+    fn new() -> (Self, mpsc::Receiver<String>) {
+        let (sender, receiver) = mpsc::channel();
+        (MessageSystem { sender }, receiver)
+    }
+
+    fn send(&self, msg: String) {
+        self.sender.send(msg).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+// Reference solution for exercise 085: Channels - Sending Multiple Values
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let (tx, rx) = mpsc::channel::<i32>();
+
+    thread::spawn(move || {
+        let values = vec![1, 2, 3, 4, 5];
+
+        for val in values {
+            tx.send(val).unwrap();
+            println!("Sent: {}", val);
+            thread::sleep(Duration::from_millis(100));
+        }
+        // tx is dropped when this closure returns, which closes the channel.
+        println!("Sender dropped, channel closed");
+    });
+
+    println!("Receiving values:");
+    for received in rx {
+        println!("Got: {}", received);
+    }
+    println!("Channel closed, no more values");
+
+    let (tx, rx) = mpsc::channel::<String>();
+
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(500));
+        tx.send(String::from("Delayed message")).unwrap();
+    });
+
+    loop {
+        match rx.try_recv() {
+            Ok(msg) => {
+                println!("Received: {}", msg);
+                break;
+            }
+            Err(mpsc::TryRecvError::Empty) => {
+                println!("No message yet, doing other work...");
+                thread::sleep(Duration::from_millis(100));
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                println!("Sender disconnected!");
+                break;
+            }
+        }
+    }
+
+    let (tx, rx) = mpsc::channel();
+
+    for i in 1..=5 {
+        let tx_clone = tx.clone();
+        thread::spawn(move || {
+            let result = i * i;
+            tx_clone.send((i, result)).unwrap();
+        });
+    }
+
+    // Drop the original tx so the channel closes when all clones are done.
+    drop(tx);
+
+    let mut results = vec![];
+    for (input, square) in rx {
+        results.push((input, square));
+        println!("{}^2 = {}", input, square);
+    }
+
+    results.sort_by_key(|(n, _)| *n);
+    println!("All results: {:?}", results);
+
+    println!("Parallel squares: {:?}", parallel_squares(vec![3, 1, 2]));
+
+    let (tx, rx) = create_bounded_channel::<&str>(1);
+    tx.send("bounded").unwrap();
+    println!("From bounded channel: {}", rx.recv().unwrap());
+}
+
+fn parallel_squares(numbers: Vec<i32>) -> Vec<i32> {
+    let (tx, rx) = mpsc::channel();
+    let mut handles = vec![];
+
+    for n in numbers {
+        let tx_clone = tx.clone();
+        let handle = thread::spawn(move || {
+            tx_clone.send(n * n).unwrap();
+        });
+        handles.push(handle);
+    }
+
+    drop(tx);
+
+    let mut results: Vec<i32> = rx.iter().collect();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    results.sort();
+    results
+}
+
+/// The standard library's bounded channel is `sync_channel`: once
+/// `capacity` messages are queued, `send` blocks until one is received.
+fn create_bounded_channel<T>(capacity: usize) -> (mpsc::SyncSender<T>, mpsc::Receiver<T>) {
+    mpsc::sync_channel(capacity)
+}
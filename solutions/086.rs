@@ -0,0 +1,91 @@
+// Reference solution for exercise 086: Pattern Matching - Destructuring Structs
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+struct User {
+    name: String,
+    email: String,
+    age: u32,
+}
+
+struct Rectangle {
+    top_left: Point,
+    bottom_right: Point,
+}
+
+fn main() {
+    let point = Point { x: 10.0, y: 20.0 };
+
+    let Point { x, y } = point;
+    println!("Point: x = {}, y = {}", x, y);
+
+    let Point { x: x_coord, y: y_coord } = point;
+    println!("Coordinates: ({}, {})", x_coord, y_coord);
+
+    let Point { x, .. } = point;
+    println!("Just x: {}", x);
+
+    let user = User {
+        name: String::from("Alice"),
+        email: String::from("alice@example.com"),
+        age: 30,
+    };
+
+    let User { name, email, age } = user;
+    println!("User: {} ({}), email: {}", name, age, email);
+
+    let other = User {
+        name: String::from("Bob"),
+        email: String::from("bob@example.com"),
+        age: 25,
+    };
+    println!("Name only: {}", get_user_name(other));
+
+    let rect = Rectangle {
+        top_left: Point { x: 0.0, y: 10.0 },
+        bottom_right: Point { x: 10.0, y: 0.0 },
+    };
+
+    let Rectangle {
+        top_left: Point { x: x1, y: y1 },
+        bottom_right: Point { x: x2, y: y2 },
+    } = rect;
+
+    println!("Rectangle: ({}, {}) to ({}, {})", x1, y1, x2, y2);
+    let area = rectangle_area(Rectangle {
+        top_left: Point { x: x1, y: y1 },
+        bottom_right: Point { x: x2, y: y2 },
+    });
+    println!("Rectangle area: {}", area);
+
+    describe_point(point);
+
+    classify_point(point);
+}
+
+fn describe_point(Point { x, y }: Point) {
+    println!("The point is at coordinates ({}, {})", x, y);
+}
+
+fn classify_point(point: Point) {
+    match point {
+        // The origin must come first; the axis arms below would also match it.
+        Point { x: 0.0, y: 0.0 } => println!("At origin!"),
+        Point { x: 0.0, y } => println!("On y-axis at y = {}", y),
+        Point { x, y: 0.0 } => println!("On x-axis at x = {}", x),
+        Point { x, y } => println!("Regular point at ({}, {})", x, y),
+    }
+}
+
+fn rectangle_area(Rectangle { top_left: Point { x: x1, y: y1 }, bottom_right: Point { x: x2, y: y2 } }: Rectangle) -> f64 {
+    ((x2 - x1) * (y1 - y2)).abs()
+}
+
+fn get_user_name(user: User) -> String {
+    let User { name, .. } = user;
+    name
+}
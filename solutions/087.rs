@@ -0,0 +1,114 @@
+// Reference solution for exercise 087: Pattern Matching - Destructuring Enums
+
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(u8, u8, u8),
+}
+
+enum Shape {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+    Triangle { base: f64, height: f64 },
+}
+
+enum Status {
+    Pending,
+    Processing { progress: u8 },
+    Completed { result: String },
+    Failed { error: String, code: u32 },
+}
+
+fn main() {
+    let msg = Message::Move { x: 10, y: 20 };
+    match msg {
+        Message::Move { x, y } => {
+            println!("Move to x: {}, y: {}", x, y);
+        }
+        _ => (),
+    }
+
+    let msg = Message::Write(String::from("Hello"));
+    match msg {
+        Message::Write(text) => println!("Message: {}", text),
+        _ => (),
+    }
+
+    let msg = Message::ChangeColor(255, 0, 128);
+    match msg {
+        Message::ChangeColor(r, g, b) => {
+            println!("RGB({}, {}, {})", r, g, b);
+        }
+        _ => (),
+    }
+
+    let msg = Message::Quit;
+    match msg {
+        Message::Quit => println!("Quit message received"),
+        _ => (),
+    }
+
+    let shapes = vec![
+        Shape::Circle { radius: 5.0 },
+        Shape::Rectangle { width: 10.0, height: 20.0 },
+        Shape::Triangle { base: 10.0, height: 5.0 },
+    ];
+
+    for shape in shapes {
+        let area = match shape {
+            Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+            Shape::Rectangle { width, height } => width * height,
+            Shape::Triangle { base, height } => 0.5 * base * height,
+        };
+        println!("Area: {:.2}", area);
+    }
+
+    let statuses = vec![
+        Status::Pending,
+        Status::Processing { progress: 45 },
+        Status::Completed { result: String::from("Success") },
+        Status::Failed { error: String::from("Network error"), code: 500 },
+    ];
+
+    for status in statuses {
+        describe_status(status);
+    }
+
+    println!("Text: {:?}", extract_message_text(Message::Write(String::from("hi"))));
+    println!("Perimeter: {:.2}", shape_perimeter(Shape::Triangle { base: 3.0, height: 4.0 }));
+}
+
+fn describe_status(status: Status) {
+    match status {
+        Status::Pending => println!("Waiting to start..."),
+        Status::Processing { progress } => {
+            println!("Processing... {}% complete", progress);
+        }
+        Status::Completed { result } => {
+            println!("Completed with result: {}", result);
+        }
+        Status::Failed { error, code } => {
+            println!("Failed with error {} (code {})", error, code);
+        }
+    }
+}
+
+fn extract_message_text(msg: Message) -> Option<String> {
+    match msg {
+        Message::Write(text) => Some(text),
+        _ => None,
+    }
+}
+
+fn shape_perimeter(shape: Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => 2.0 * std::f64::consts::PI * radius,
+        Shape::Rectangle { width, height } => 2.0 * (width + height),
+        Shape::Triangle { base, height } => {
+            // Simplified - assumes right triangle
+            let hypotenuse = (base * base + height * height).sqrt();
+            base + height + hypotenuse
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Reference solution for exercise 088: Pattern Matching - Guards
+
+enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+}
+
+struct Person {
+    age: u32,
+    name: String,
+}
+
+fn main() {
+    let temps = vec![
+        Temperature::Celsius(25.0),
+        Temperature::Celsius(-5.0),
+        Temperature::Fahrenheit(98.6),
+        Temperature::Fahrenheit(32.0),
+        Temperature::Kelvin(273.15),
+    ];
+
+    for temp in temps {
+        describe_temperature(temp);
+    }
+
+    let scores = vec![95, 82, 75, 65, 55, 45];
+
+    for score in scores {
+        let grade = match score {
+            // A: >= 90, B: >= 80, C: >= 70, D: >= 60, F: < 60
+            n if n >= 90 => 'A',
+            n if n >= 80 => 'B',
+            n if n >= 70 => 'C',
+            n if n >= 60 => 'D',
+            _ => 'F',
+        };
+        println!("Score {} => Grade {}", score, grade);
+    }
+
+    let people = vec![
+        Person { age: 5, name: String::from("Tommy") },
+        Person { age: 17, name: String::from("Alex") },
+        Person { age: 25, name: String::from("Sarah") },
+        Person { age: 70, name: String::from("Bob") },
+    ];
+
+    for person in people {
+        categorize_person(person);
+    }
+
+    let pairs = vec![(1, 2), (2, 1), (2, 2), (3, 4), (5, 5)];
+
+    for (x, y) in pairs {
+        let result = match (x, y) {
+            (a, b) if a == b => "equal",
+            (a, b) if a > b => "first is larger",
+            (a, b) if a < b => "second is larger",
+            _ => "other",
+        };
+        println!("({}, {}) => {}", x, y, result);
+    }
+
+    let numbers = vec![Some(5), Some(15), Some(25), None];
+
+    for (start, end) in [(-5, -1), (1, 5), (-3, 3)] {
+        println!("[{}, {}] => {}", start, end, categorize_range(start, end));
+    }
+
+    for num in numbers {
+        match num {
+            Some(n) if n < 10 => println!("Small: {}", n),
+            Some(n) if n >= 10 && n < 20 => println!("Medium: {}", n),
+            Some(n) => println!("Large: {}", n),
+            None => println!("No value"),
+        }
+    }
+}
+
+fn describe_temperature(temp: Temperature) {
+    match temp {
+        // Freezing: <= 0°C, Cold: 0-15°C, Mild: 15-25°C, Hot: > 25°C
+        // For Fahrenheit: <= 32°F, 32-59°F, 59-77°F, > 77°F
+        // For Kelvin: <= 273.15K freezing, etc.
+        Temperature::Celsius(c) if c <= 0.0 => println!("Freezing ({}°C)", c),
+        Temperature::Celsius(c) if c <= 15.0 => println!("Cold ({}°C)", c),
+        Temperature::Celsius(c) if c <= 25.0 => println!("Mild ({}°C)", c),
+        Temperature::Celsius(c) => println!("Hot ({}°C)", c),
+
+        Temperature::Fahrenheit(f) if f <= 32.0 => println!("Freezing ({}°F)", f),
+        Temperature::Fahrenheit(f) if f <= 59.0 => println!("Cold ({}°F)", f),
+        Temperature::Fahrenheit(f) if f <= 77.0 => println!("Mild ({}°F)", f),
+        Temperature::Fahrenheit(f) => println!("Hot ({}°F)", f),
+
+        Temperature::Kelvin(k) if k <= 273.15 => println!("Freezing ({}K)", k),
+        Temperature::Kelvin(k) if k <= 288.15 => println!("Cold ({}K)", k),
+        Temperature::Kelvin(k) if k <= 298.15 => println!("Mild ({}K)", k),
+        Temperature::Kelvin(k) => println!("Hot ({}K)", k),
+    }
+}
+
+fn categorize_person(Person { age, name }: Person) {
+    match age {
+        a if a <= 2 => println!("{} is a baby ({})", name, age),
+        a if a <= 12 => println!("{} is a child ({})", name, age),
+        a if a <= 19 => println!("{} is a teen ({})", name, age),
+        a if a <= 64 => println!("{} is an adult ({})", name, age),
+        _ => println!("{} is a senior ({})", name, age),
+    }
+}
+
+fn categorize_range(start: i32, end: i32) -> &'static str {
+    match (start, end) {
+        // Both negative
+        (s, e) if s < 0 && e < 0 => "both negative",
+        // Both positive
+        (s, e) if s > 0 && e > 0 => "both positive",
+        // Spans zero
+        (s, e) if s <= 0 && e >= 0 => "spans zero",
+        // Otherwise
+        _ => "other",
+    }
+}
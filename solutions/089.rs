@@ -0,0 +1,153 @@
+// Reference solution for exercise 089: Pattern Matching - @ Bindings
+
+#[derive(Debug)]
+enum Message {
+    Hello { id: i32 },
+    Goodbye,
+}
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let x = 5;
+    match x {
+        // Bind the matched value to 'n' while also testing it's in range
+        n @ 1..=10 => println!("Got a value in range: {}", n),
+        n => println!("Got some other value: {}", n),
+    }
+
+    let messages = vec![Message::Hello { id: 5 }, Message::Hello { id: 12 }, Message::Goodbye];
+    for msg in messages {
+        match msg {
+            // Bind the whole Message while also extracting id
+            m @ Message::Hello { id: 3..=7 } => {
+                println!("Found message in range: {:?}", m);
+            }
+            Message::Hello { id } => {
+                println!("Other hello with id: {}", id);
+            }
+            Message::Goodbye => println!("Goodbye!"),
+        }
+    }
+
+    let point = Point { x: 2, y: 3 };
+    match point {
+        // Bind the whole point while also extracting fields
+        p @ Point { x: 0, y } => {
+            println!("Point on y-axis: {:?}, y = {}", p, y);
+        }
+        p @ Point { x, y: 0 } => {
+            println!("Point on x-axis: {:?}, x = {}", p, x);
+        }
+        p => {
+            println!("Regular point: {:?}", p);
+        }
+    }
+
+    let points = vec![
+        Point { x: 0, y: 5 },
+        Point { x: 3, y: 0 },
+        Point { x: 2, y: 2 },
+    ];
+
+    for point in points {
+        classify_point(point);
+    }
+
+    let pairs = vec![(1, 2), (5, 5), (3, 4)];
+
+    for pair in pairs {
+        describe_pair(pair);
+    }
+
+    let values = vec![Some(5), Some(15), None, Some(25)];
+
+    for val in values {
+        process_value(val);
+    }
+
+    for n in [5, 25, 75, 150] {
+        println!("{}", get_range_info(n));
+    }
+    println!("{}", describe_rectangle(Rectangle { width: 16, height: 9 }));
+}
+
+fn classify_point(p @ Point { x, y }: Point) {
+    // Use p (the whole point), x, and y
+    if x == y {
+        println!("Point {} is on diagonal y=x", format_point(&p));
+    } else if x > y {
+        println!("Point {} is below diagonal", format_point(&p));
+    } else {
+        println!("Point {} is above diagonal", format_point(&p));
+    }
+}
+
+fn format_point(p: &Point) -> String {
+    format!("({}, {})", p.x, p.y)
+}
+
+fn describe_pair(pair @ (x, y): (i32, i32)) {
+    match pair {
+        // and bind the whole pair
+        p @ (a, b) if a == b => {
+            println!("Equal pair {:?}: both are {}", p, a);
+        }
+        // Match when sum is even
+        p @ (a, b) if (a + b) % 2 == 0 => {
+            println!("Even sum pair {:?}: sum = {}", p, a + b);
+        }
+        // Everything else
+        _ => {
+            println!("Regular pair ({}, {}): sum = {}", x, y, x + y);
+        }
+    }
+}
+
+fn process_value(val: Option<i32>) {
+    match val {
+        // Bind the option while checking range
+        v @ Some(n) if n < 10 => {
+            println!("Small value {:?}", v);
+        }
+        v @ Some(n) if n >= 10 && n < 20 => {
+            println!("Medium value {:?}", v);
+        }
+        Some(n) => {
+            println!("Large value: {}", n);
+        }
+        None => {
+            println!("No value");
+        }
+    }
+}
+
+fn get_range_info(n: i32) -> String {
+    match n {
+        // Use @ binding to capture the value and classify it
+        v @ 0..=10 => format!("Small ({})", v),
+        v @ 11..=50 => format!("Medium ({})", v),
+        v @ 51..=100 => format!("Large ({})", v),
+        v => format!("Out of range ({})", v),
+    }
+}
+
+struct Rectangle {
+    width: u32,
+    height: u32,
+}
+
+fn describe_rectangle(r @ Rectangle { width, height }: Rectangle) -> String {
+    let orientation = if width == height {
+        "square"
+    } else if width > height {
+        "landscape"
+    } else {
+        "portrait"
+    };
+    format!("Rectangle {}x{} is {}", r.width, r.height, orientation)
+}
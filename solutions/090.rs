@@ -0,0 +1,178 @@
+// Reference solution for exercise 090: Advanced Traits - Associated Types
+
+pub trait MyIterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+struct Counter {
+    count: u32,
+    max: u32,
+}
+
+impl Counter {
+    fn new(max: u32) -> Self {
+        Counter { count: 0, max }
+    }
+}
+
+impl MyIterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < self.max {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+pub trait Container {
+    type Item;
+    type Error;
+
+    fn get(&self, index: usize) -> Result<&Self::Item, Self::Error>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Container for Vec<T> {
+    type Item = T;
+    type Error = &'static str;
+
+    fn get(&self, index: usize) -> Result<&Self::Item, Self::Error> {
+        if index < self.len() {
+            Ok(&self[index])
+        } else {
+            Err("Index out of bounds")
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+pub trait Graph {
+    type Node;
+    type Edge;
+
+    fn nodes(&self) -> Vec<Self::Node>;
+    fn edges(&self) -> Vec<Self::Edge>;
+    fn has_edge(&self, from: &Self::Node, to: &Self::Node) -> bool;
+}
+
+struct SimpleGraph {
+    adjacency_list: Vec<Vec<usize>>,
+}
+
+impl Graph for SimpleGraph {
+    type Node = usize;
+    type Edge = (usize, usize);
+
+    fn nodes(&self) -> Vec<Self::Node> {
+        (0..self.adjacency_list.len()).collect()
+    }
+
+    fn edges(&self) -> Vec<Self::Edge> {
+        let mut edges = vec![];
+        for (from, neighbors) in self.adjacency_list.iter().enumerate() {
+            for &to in neighbors {
+                edges.push((from, to));
+            }
+        }
+        edges
+    }
+
+    fn has_edge(&self, from: &Self::Node, to: &Self::Node) -> bool {
+        self.adjacency_list.get(*from)
+            .map(|neighbors| neighbors.contains(to))
+            .unwrap_or(false)
+    }
+}
+
+fn main() {
+    let mut counter = Counter::new(5);
+    println!("Counter values:");
+    while let Some(val) = counter.next() {
+        println!("  {}", val);
+    }
+
+    let vec = vec![10, 20, 30, 40, 50];
+    println!("\nContainer operations:");
+    println!("Length: {}", vec.len());
+    println!("Item at 2: {:?}", vec.get(2));
+    println!("Item at 10: {:?}", vec.get(10));
+
+    let graph = SimpleGraph {
+        adjacency_list: vec![
+            vec![1, 2],    // 0 -> 1, 2
+            vec![2],       // 1 -> 2
+            vec![],        // 2 ->
+        ],
+    };
+
+    println!("\nGraph operations:");
+    println!("Nodes: {:?}", graph.nodes());
+    println!("Edges: {:?}", graph.edges());
+    println!("Has edge 0->1: {}", graph.has_edge(&0, &1));
+    println!("Has edge 1->0: {}", graph.has_edge(&1, &0));
+
+    // Compare: Iterator<T> (generic) vs Iterator with associated type
+    // With associated types, you don't need to specify T every time
+    process_iterator();
+
+    print_first(&vec);
+    print_first(&Vec::<i32>::new());
+    println!("Is empty: {}", Container::is_empty(&vec));
+
+    let bytes = String::from("hi").convert();
+    println!("\nConverted: {:?} -> {:?}", bytes, bytes.as_slice().convert());
+}
+
+fn print_first<C: Container>(container: &C)
+where
+    C::Item: std::fmt::Display,
+    C::Error: std::fmt::Display,
+{
+    match container.get(0) {
+        Ok(item) => println!("First item: {}", item),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+fn process_iterator() {
+    let mut counter = Counter::new(3);
+
+    // The type of item is known from the associated type
+    // We don't need to specify it explicitly
+    while let Some(item) = counter.next() {
+        let _squared: u32 = item * item; // Type is inferred!
+        println!("Squared: {}", _squared);
+    }
+}
+
+pub trait Convertible {
+    type Output;
+    fn convert(&self) -> Self::Output;
+}
+
+impl Convertible for String {
+    type Output = Vec<u8>;
+
+    fn convert(&self) -> Self::Output {
+        self.bytes().collect()
+    }
+}
+
+impl Convertible for &[u8] {
+    type Output = String;
+
+    fn convert(&self) -> Self::Output {
+        String::from_utf8_lossy(self).to_string()
+    }
+}
@@ -0,0 +1,191 @@
+// Reference solution for exercise 091: Advanced Traits - Operator Overloading
+
+use std::ops::{Add, Sub, Mul, Neg, Index, IndexMut};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    fn new(x: f64, y: f64) -> Self {
+        Point { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self::Output {
+        Point {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+struct Matrix {
+    data: Vec<Vec<f64>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Matrix {
+    fn new(rows: usize, cols: usize) -> Self {
+        Matrix {
+            data: vec![vec![0.0; cols]; rows],
+            rows,
+            cols,
+        }
+    }
+
+    fn from_vec(data: Vec<Vec<f64>>) -> Self {
+        let rows = data.len();
+        let cols = if rows > 0 { data[0].len() } else { 0 };
+        Matrix { data, rows, cols }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row][col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row][col]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Complex {
+    real: f64,
+    imag: f64,
+}
+
+impl Complex {
+    fn new(real: f64, imag: f64) -> Self {
+        Complex { real, imag }
+    }
+
+    fn magnitude(&self) -> f64 {
+        (self.real * self.real + self.imag * self.imag).sqrt()
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Complex {
+            real: self.real + other.real,
+            imag: self.imag + other.imag,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        // (a + bi) * (c + di) = (ac - bd) + (ad + bc)i
+        Complex {
+            real: self.real * other.real - self.imag * other.imag,
+            imag: self.real * other.imag + self.imag * other.real,
+        }
+    }
+}
+
+fn main() {
+    let p1 = Point::new(1.0, 2.0);
+    let p2 = Point::new(3.0, 4.0);
+
+    println!("Point operations:");
+    println!("p1 + p2 = {:?}", p1 + p2);
+    println!("p1 - p2 = {:?}", p1 - p2);
+    println!("-p1 = {:?}", -p1);
+    println!("p1 * 2.0 = {:?}", p1 * 2.0);
+
+    let mut matrix = Matrix::new(3, 3);
+    matrix[(0, 0)] = 1.0;
+    matrix[(1, 1)] = 2.0;
+    matrix[(2, 2)] = 3.0;
+
+    println!("\nMatrix:");
+    for row in 0..3 {
+        for col in 0..3 {
+            print!("{:4.1} ", matrix[(row, col)]);
+        }
+        println!();
+    }
+
+    let c1 = Complex::new(3.0, 4.0);
+    let c2 = Complex::new(1.0, 2.0);
+
+    println!("\nComplex operations:");
+    println!("c1 = {:?}", c1);
+    println!("c2 = {:?}", c2);
+    println!("c1 + c2 = {:?}", c1 + c2);
+    println!("c1 * c2 = {:?}", c1 * c2);
+    println!("|c1| = {}", c1.magnitude());
+
+    let points = vec![
+        Point::new(1.0, 1.0),
+        Point::new(2.0, 2.0),
+        Point::new(3.0, 3.0),
+    ];
+
+    let sum: Point = points.iter().fold(Point::new(0.0, 0.0), |acc, &p| acc + p);
+    println!("\nSum of points: {:?}", sum);
+    println!("Generic add: {}", generic_add(2, 3));
+    println!("Distance p1-p2: {:.3}", distance(p1, p2));
+
+    let identity = Matrix::from_vec(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+    println!("Identity is {}x{}, [1][1] = {}", identity.rows, identity.cols, identity[(1, 1)]);
+}
+
+fn generic_add<T: Add<Output = T>>(a: T, b: T) -> T {
+    a + b
+}
+
+fn distance(p1: Point, p2: Point) -> f64 {
+    let diff = p1 - p2;
+    (diff.x * diff.x + diff.y * diff.y).sqrt()
+}
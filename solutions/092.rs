@@ -0,0 +1,202 @@
+// Reference solution for exercise 092: Advanced Traits - Supertraits
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+}
+
+// Drawable is a subtrait of Shape
+pub trait Drawable: Shape {
+    fn draw(&self);
+    fn describe(&self) {
+        println!("Shape with area {:.2}", self.area());
+    }
+}
+
+pub trait Printable {
+    fn print(&self);
+}
+
+pub trait Document: Drawable + Printable {
+    fn render(&self) {
+        self.draw();
+        self.print();
+    }
+}
+
+#[derive(Debug)]
+struct Circle {
+    radius: f64,
+}
+
+impl Circle {
+    fn new(radius: f64) -> Self {
+        Circle { radius }
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * std::f64::consts::PI * self.radius
+    }
+}
+
+impl Drawable for Circle {
+    fn draw(&self) {
+        println!("Drawing circle with radius {}", self.radius);
+    }
+}
+
+impl Printable for Circle {
+    fn print(&self) {
+        println!("Circle(radius={})", self.radius);
+    }
+}
+
+impl Document for Circle {}
+
+#[derive(Debug)]
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+impl Rectangle {
+    fn new(width: f64, height: f64) -> Self {
+        Rectangle { width, height }
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+}
+
+impl Drawable for Rectangle {
+    fn draw(&self) {
+        println!("Drawing rectangle {}x{}", self.width, self.height);
+    }
+}
+
+impl Printable for Rectangle {
+    fn print(&self) {
+        println!("Rectangle(width={}, height={})", self.width, self.height);
+    }
+}
+
+pub trait Animal {
+    fn name(&self) -> &str;
+    fn make_sound(&self);
+}
+
+pub trait Pet: Animal {
+    fn owner(&self) -> &str;
+    fn pet(&self) {
+        println!("You pet {} the {}", self.name(), self.name());
+    }
+}
+
+pub trait Dog: Pet {
+    fn fetch(&self);
+    fn bark(&self) {
+        println!("Woof! Woof!");
+        self.make_sound();
+    }
+}
+
+struct GoldenRetriever {
+    name: String,
+    owner: String,
+}
+
+impl GoldenRetriever {
+    fn new(name: &str, owner: &str) -> Self {
+        GoldenRetriever {
+            name: name.to_string(),
+            owner: owner.to_string(),
+        }
+    }
+}
+
+impl Animal for GoldenRetriever {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn make_sound(&self) {
+        println!("Bark!");
+    }
+}
+
+impl Pet for GoldenRetriever {
+    fn owner(&self) -> &str {
+        &self.owner
+    }
+}
+
+impl Dog for GoldenRetriever {
+    fn fetch(&self) {
+        println!("{} runs to fetch the ball!", self.name);
+    }
+}
+
+fn main() {
+    let circle = Circle::new(5.0);
+    println!("Circle area: {:.2}", circle.area());
+    circle.draw();
+    circle.print();
+    circle.describe();
+    circle.render();
+
+    println!();
+
+    let rect = Rectangle::new(10.0, 20.0);
+    println!("Rectangle area: {:.2}", rect.area());
+    rect.draw();
+    rect.print();
+
+    println!();
+
+    let dog = GoldenRetriever::new("Buddy", "Alice");
+    println!("Dog name: {}", dog.name());
+    println!("Dog owner: {}", dog.owner());
+    dog.make_sound();
+    dog.pet();
+    dog.bark();
+    dog.fetch();
+
+    process_drawable(&circle);
+    process_shape(&rect);
+    render_document(&circle);
+    play_with_dog(&dog);
+}
+
+fn process_drawable<T: Drawable>(item: &T) {
+    println!("Area: {:.2}", item.area());
+    println!("Perimeter: {:.2}", item.perimeter());
+    item.draw();
+}
+
+fn process_shape<T: Shape>(shape: &T) {
+    println!("Processing shape with area {:.2} and perimeter {:.2}",
+             shape.area(), shape.perimeter());
+}
+
+fn render_document<T: Document>(doc: &T) {
+    doc.render();
+}
+
+fn play_with_dog<T: Dog>(dog: &T) {
+    dog.bark();
+    dog.fetch();
+    dog.pet();
+}
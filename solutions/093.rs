@@ -0,0 +1,157 @@
+// Reference solution for exercise 093: Smart Pointers - Deref Trait
+
+use std::ops::Deref;
+
+struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+struct MyString {
+    data: String,
+}
+
+impl MyString {
+    fn new(s: &str) -> Self {
+        MyString {
+            data: s.to_string(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl Deref for MyString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+struct CountingBox<T> {
+    value: T,
+    deref_count: std::cell::RefCell<usize>,
+}
+
+impl<T> CountingBox<T> {
+    fn new(value: T) -> Self {
+        CountingBox {
+            value,
+            deref_count: std::cell::RefCell::new(0),
+        }
+    }
+
+    fn deref_count(&self) -> usize {
+        *self.deref_count.borrow()
+    }
+}
+
+impl<T> Deref for CountingBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        *self.deref_count.borrow_mut() += 1;
+        &self.value
+    }
+}
+
+struct SafeVec<T> {
+    data: Vec<T>,
+}
+
+impl<T> SafeVec<T> {
+    fn new() -> Self {
+        SafeVec { data: Vec::new() }
+    }
+
+    fn push(&mut self, item: T) {
+        self.data.push(item);
+    }
+}
+
+impl<T> Deref for SafeVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+fn main() {
+    let x = 5;
+    let y = MyBox::new(x);
+
+    println!("MyBox dereferencing:");
+    println!("x = {}", x);
+    println!("*y = {}", *y); // Uses Deref
+    assert_eq!(x, *y);
+
+    let name = MyBox::new(String::from("Rust"));
+    // &MyBox<String> -> &String -> &str via deref coercion
+    hello(&name);
+
+    let my_str = MyString::new("Hello, World!");
+    println!("\nMyString operations:");
+    println!("Length: {}", my_str.len());
+    println!("Uppercase: {}", my_str.to_uppercase()); // Deref to str
+    println!("Contains 'World': {}", my_str.contains("World"));
+
+    let counter = CountingBox::new(42);
+    println!("\nCountingBox:");
+    println!("Deref count before: {}", counter.deref_count());
+    let _val = *counter;
+    let _val = *counter;
+    let _val = *counter;
+    println!("Deref count after 3 derefs: {}", counter.deref_count());
+
+    let mut vec = SafeVec::new();
+    vec.push(1);
+    vec.push(2);
+    vec.push(3);
+
+    println!("\nSafeVec iteration:");
+    // `for` needs IntoIterator, which Deref does not provide; iter() comes from [T].
+    for item in vec.iter() {
+        println!("  {}", item);
+    }
+
+    // Can use slice methods
+    println!("First element: {:?}", vec.first());
+    println!("Contains 2: {}", vec.contains(&2));
+
+    println!("\nLength through Deref: {}", get_length(&MyBox::new(String::from("bytes"))));
+    let inner: &String = process_reference(&name);
+    println!("Reference to inner value: {}", inner);
+}
+
+fn hello(name: &str) {
+    println!("Hello, {}!", name);
+}
+
+fn get_length<T: Deref>(item: &T) -> usize
+where
+    T::Target: AsRef<[u8]>,
+{
+    item.as_ref().len()
+}
+
+fn process_reference<T, U>(item: &T) -> &U
+where
+    T: Deref<Target = U>,
+{
+    &*item
+}
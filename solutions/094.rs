@@ -0,0 +1,215 @@
+// Reference solution for exercise 094: Smart Pointers - Drop Trait
+
+use std::ops::Drop;
+use std::mem;
+
+struct Logger {
+    name: String,
+}
+
+impl Logger {
+    fn new(name: &str) -> Self {
+        println!("Logger '{}' created", name);
+        Logger {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Drop for Logger {
+    fn drop(&mut self) {
+        println!("Logger '{}' dropped", self.name);
+    }
+}
+
+struct Resource {
+    id: u32,
+    data: String,
+}
+
+impl Resource {
+    fn new(id: u32, data: &str) -> Self {
+        println!("Resource {} allocated with data: {}", id, data);
+        Resource {
+            id,
+            data: data.to_string(),
+        }
+    }
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        println!("Resource {} deallocated, data was: {}", self.id, self.data);
+    }
+}
+
+struct FileHandle {
+    filename: String,
+    is_open: bool,
+}
+
+impl FileHandle {
+    fn open(filename: &str) -> Self {
+        println!("Opening file: {}", filename);
+        FileHandle {
+            filename: filename.to_string(),
+            is_open: true,
+        }
+    }
+
+    fn close(&mut self) {
+        if self.is_open {
+            println!("Explicitly closing file: {}", self.filename);
+            self.is_open = false;
+        }
+    }
+
+    fn read(&self) -> Option<String> {
+        if self.is_open {
+            Some(format!("Contents of {}", self.filename))
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for FileHandle {
+    fn drop(&mut self) {
+        if self.is_open {
+            println!("Auto-closing file in Drop: {}", self.filename);
+        }
+    }
+}
+
+struct UniquePtr<T> {
+    ptr: *mut T,
+    is_dropped: bool,
+}
+
+impl<T> UniquePtr<T> {
+    fn new(value: T) -> Self {
+        let ptr = Box::into_raw(Box::new(value));
+        UniquePtr { ptr, is_dropped: false }
+    }
+
+    fn get(&self) -> Option<&T> {
+        if self.is_dropped {
+            None
+        } else {
+            Some(unsafe { &*self.ptr })
+        }
+    }
+}
+
+impl<T> Drop for UniquePtr<T> {
+    fn drop(&mut self) {
+        if !self.is_dropped {
+            unsafe {
+                let _ = Box::from_raw(self.ptr);
+            }
+            println!("UniquePtr dropped, memory freed");
+            self.is_dropped = true;
+        }
+    }
+}
+
+fn main() {
+    println!("=== Creating Logger ===");
+    let logger = Logger::new("main");
+    println!("About to exit block...");
+    drop(logger);
+    println!("After explicit drop");
+
+    println!("\n=== Scope-based Drop ===");
+    {
+        let _inner = Logger::new("inner");
+        println!("Inside block");
+    } // _inner dropped here automatically
+    println!("After block");
+
+    println!("\n=== Multiple Resources ===");
+    let res1 = Resource::new(1, "First");
+    let res2 = Resource::new(2, "Second");
+    drop(res1); // Explicitly drop first
+    println!("After dropping res1");
+    drop(res2);
+    println!("After dropping res2");
+
+    println!("\n=== File Handle ===");
+    {
+        let file = FileHandle::open("data.txt");
+        println!("Reading: {:?}", file.read());
+    }
+
+    println!("\n=== Early Drop with mem::drop ===");
+    let file2 = FileHandle::open("temp.txt");
+    mem::drop(file2);
+    println!("After mem::drop");
+
+    println!("\n=== Explicit Close ===");
+    let mut file3 = FileHandle::open("log.txt");
+    file3.close();
+    println!("Reading after close: {:?}", file3.read());
+    drop(file3);
+
+    println!("\n=== RAII ===");
+    use_resource();
+    conditional_drop(Logger::new("kept"), false);
+    conditional_drop(Logger::new("dropped"), true);
+
+    println!("\n=== UniquePtr and OnDrop ===");
+    let unique = UniquePtr::new(String::from("boxed"));
+    println!("UniquePtr holds: {:?}", unique.get());
+    drop(unique);
+    {
+        let _guard = OnDrop::new(|| println!("OnDrop callback ran"));
+        let mut cancelled = OnDrop::new(|| println!("never printed"));
+        cancelled.cancel();
+    }
+
+    println!("\n=== Drop Order ===");
+    let _a = Logger::new("A");
+    let _b = Logger::new("B");
+    let _c = Logger::new("C");
+    // Dropped in reverse order: C, B, A
+    println!("Exiting main...");
+}
+
+fn use_resource() {
+    let resource = Resource::new(99, "temporary");
+    println!("Using resource {}...", resource.id);
+}
+
+fn conditional_drop<T>(item: T, should_drop: bool) {
+    if should_drop {
+        drop(item);
+        println!("Item was dropped");
+    } else {
+        // Forgetting the item would leak it; letting it go out of scope drops it.
+        println!("Item not dropped yet, it will be dropped at end of scope");
+    }
+}
+
+struct OnDrop<F: FnOnce()> {
+    callback: Option<F>,
+}
+
+impl<F: FnOnce()> OnDrop<F> {
+    fn new(callback: F) -> Self {
+        OnDrop {
+            callback: Some(callback),
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.callback = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for OnDrop<F> {
+    fn drop(&mut self) {
+        if let Some(callback) = self.callback.take() {
+            callback();
+        }
+    }
+}
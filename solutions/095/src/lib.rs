@@ -0,0 +1,94 @@
+// Reference solution for exercise 095: Modules - Visibility Modifiers
+//
+// The src/ of the exercise crate; the grader supplies its Cargo.toml.
+
+mod outer; // src/outer.rs, private to this crate
+pub mod utils; // src/utils.rs, part of the public API
+
+fn private_function() {
+    println!("I'm private to this module");
+}
+
+pub fn public_function() {
+    println!("I'm public");
+    private_function(); // Can call private functions from same module
+}
+
+pub fn crate_tour() {
+    outer::outer_public();
+    outer::access_inner();
+    outer::inner::inner_public();
+    outer::inner::crate_visible(); // pub(crate) crosses files, not crates
+    outer::inner::access_parent();
+    println!("Internal util: {}", utils::internal_util());
+    utils::run_internal();
+
+    let s = MyStruct::new(7, "tour");
+    println!("Crate-visible field: {}", s.crate_visible_field);
+
+    for p in [PrivateEnum::Secret, PrivateEnum::Hidden] {
+        match p {
+            PrivateEnum::Secret => println!("Private variant: Secret"),
+            PrivateEnum::Hidden => println!("Private variant: Hidden"),
+        }
+    }
+}
+
+pub struct MyStruct {
+    pub public_field: i32,
+    private_field: String, // Default: private
+    pub(crate) crate_visible_field: bool,
+}
+
+impl MyStruct {
+    pub fn new(value: i32, name: &str) -> Self {
+        MyStruct {
+            public_field: value,
+            private_field: name.to_string(),
+            crate_visible_field: true,
+        }
+    }
+
+    // Public method
+    pub fn get_private(&self) -> &str {
+        &self.private_field
+    }
+
+    // Private method
+    fn internal_helper(&self) -> i32 {
+        self.public_field * 2
+    }
+
+    // Public method using private helper
+    pub fn calculate(&self) -> i32 {
+        self.internal_helper() + 10
+    }
+}
+
+pub enum PublicEnum {
+    VariantA,
+    VariantB(i32),
+    VariantC { name: String },
+}
+
+// Enum variants are public if the enum is public
+// Private enum - variants are private to the module
+enum PrivateEnum {
+    Secret,
+    Hidden,
+}
+
+pub trait PublicTrait {
+    fn public_method(&self);
+
+    // Trait methods are public if trait is public
+    fn default_implementation(&self) {
+        println!("Default implementation");
+    }
+}
+
+impl PublicTrait for MyStruct {
+    fn public_method(&self) {
+        println!("MyStruct implements PublicTrait: {}", self.public_field);
+    }
+}
@@ -0,0 +1,39 @@
+// The binary crate of exercise 095. It reaches the library through its
+// crate name, like any other crate would, so pub(crate) and private items
+// are out of reach here.
+
+use exercise_095::{MyStruct, PublicEnum, PublicTrait};
+
+fn main() {
+    exercise_095::public_function();
+    // exercise_095::private_function(); // Error: private_function is private
+    // exercise_095::outer::outer_public(); // Error: module `outer` is private
+
+    exercise_095::crate_tour();
+
+    let s = MyStruct::new(42, "test");
+    println!("Public field: {}", s.public_field);
+    println!("Private field via getter: {}", s.get_private());
+    println!("Calculated: {}", s.calculate());
+    // println!("{}", s.private_field); // Error: field is private
+    // println!("{}", s.crate_visible_field); // Error: pub(crate) stops at the library
+
+    let e1 = PublicEnum::VariantA;
+    let e2 = PublicEnum::VariantB(10);
+
+    let e3 = PublicEnum::VariantC { name: String::from("named") };
+
+    for e in [e1, e2, e3] {
+        match e {
+            PublicEnum::VariantA => println!("A"),
+            PublicEnum::VariantB(n) => println!("B with {}", n),
+            PublicEnum::VariantC { name } => println!("C with {}", name),
+        }
+    }
+
+    s.public_method();
+    s.default_implementation();
+
+    println!("Answer: {}", exercise_095::utils::get_answer());
+    exercise_095::utils::internal::implementation_detail();
+}
@@ -0,0 +1,23 @@
+
+// Private helper
+fn helper() -> i32 {
+    42
+}
+
+// Public function
+pub fn get_answer() -> i32 {
+    helper()
+}
+
+// Public to crate
+pub(crate) fn internal_util() -> String {
+    String::from("internal")
+}
+
+// Can reach pub(super) items of its child module
+pub fn run_internal() {
+    internal::utils_only();
+}
+
+// Module for internal implementation details, in utils/internal.rs
+pub mod internal;
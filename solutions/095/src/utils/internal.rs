@@ -0,0 +1,8 @@
+pub fn implementation_detail() {
+    println!("This is public in internal, but internal is public in utils");
+}
+
+// Only visible to utils module
+pub(super) fn utils_only() {
+    println!("Only visible to utils and its descendants");
+}
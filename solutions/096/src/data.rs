@@ -0,0 +1,6 @@
+pub struct Record {
+    pub id: u32,
+    pub name: String,
+}
+
+pub mod storage;
@@ -0,0 +1,9 @@
+pub fn save() {
+    println!("Saved to storage");
+}
+
+pub fn load() {
+    println!("Loaded from storage");
+}
+
+pub mod formats;
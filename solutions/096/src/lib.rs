@@ -0,0 +1,100 @@
+// Reference solution for exercise 096: Modules - use Keyword
+//
+// The src/ of the exercise crate; the grader supplies its Cargo.toml.
+
+pub mod network; // src/network.rs, src/network/{tcp,udp}.rs
+pub mod data; // src/data.rs, src/data/storage.rs, src/data/storage/formats.rs
+mod shadowing;
+pub mod imports_demo;
+pub mod my_prelude;
+
+use network::tcp::Connection;
+use network::udp::Socket as UdpSocket;
+
+use data::storage::{load, save, formats::{json, yaml}};
+
+use std::collections::*;
+
+use data::Record;
+
+pub use network::tcp::Connection as TcpConnection;
+pub use data::storage::formats;
+
+pub fn tour() {
+    println!("=== Basic use ===");
+    let conn = Connection::new();
+    conn.connect();
+
+    let udp = UdpSocket::new();
+    udp.bind();
+
+    println!("\n=== Nested imports ===");
+    save();
+    load();
+    json();
+    yaml();
+
+    println!("\n=== Glob import ===");
+    let _map: HashMap<String, i32> = HashMap::new();
+    let _set: HashSet<i32> = HashSet::new();
+    let _vec: Vec<i32> = Vec::new(); // Vec is in prelude, but HashMap/Set need import
+
+    println!("\n=== Using Record ===");
+    let record = Record {
+        id: 1,
+        name: String::from("Test"),
+    };
+    println!("Record: id={}, name={}", record.id, record.name);
+
+    println!("\n=== Local and module imports ===");
+    formats::xml();
+    local_imports();
+    shadowing_demo();
+    use_prelude();
+    imports_demo_usage();
+}
+
+fn local_imports() {
+    // Can use `use` inside functions too
+    use std::io::{self, Write};
+
+    // io and Write are now available
+    let mut stdout = io::stdout();
+    writeln!(stdout, "Written through io::Write").unwrap();
+}
+
+fn shadowing_demo() {
+    use shadowing::action;
+    action(); // Calls shadowing::action
+}
+
+fn use_prelude() {
+    use my_prelude::*;
+
+    let record = Record { id: 2, name: String::from("prelude") };
+    let conn = Connection::new();
+    conn.connect();
+    println!("Prelude record: id={}, name={}", record.id, record.name);
+}
+
+fn imports_demo_usage() {
+    use imports_demo::{Entry, HashMap, None, Some, StringVec};
+
+    let mut words: StringVec = Vec::new();
+    words.push(String::from("alpha"));
+
+    let mut counts: HashMap<String, i32> = HashMap::new();
+    for word in &words {
+        match counts.entry(word.clone()) {
+            Entry::Occupied(mut e) => *e.get_mut() += 1,
+            Entry::Vacant(e) => {
+                e.insert(1);
+            }
+        }
+    }
+
+    let missing: Option<&i32> = None;
+    if let Some(n) = counts.get("alpha") {
+        println!("alpha appears {} time(s), missing is {:?}", n, missing);
+    }
+}
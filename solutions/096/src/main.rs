@@ -0,0 +1,13 @@
+// The binary crate of exercise 096. Its `use` paths start at the
+// library's crate name, `exercise_096`.
+
+use exercise_096::{formats, TcpConnection};
+
+fn main() {
+    exercise_096::tour();
+
+    println!("\n=== Re-exports ===");
+    let tcp = TcpConnection::new();
+    tcp.connect();
+    formats::json();
+}
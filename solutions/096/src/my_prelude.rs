@@ -0,0 +1,3 @@
+// From another file, paths into this crate start at `crate::`.
+pub use crate::data::Record;
+pub use crate::network::tcp::Connection;
@@ -0,0 +1,11 @@
+pub struct Connection;
+
+impl Connection {
+    pub fn new() -> Self {
+        Connection
+    }
+
+    pub fn connect(&self) {
+        println!("TCP connected");
+    }
+}
@@ -0,0 +1,11 @@
+pub struct Socket;
+
+impl Socket {
+    pub fn new() -> Self {
+        Socket
+    }
+
+    pub fn bind(&self) {
+        println!("UDP bound");
+    }
+}
@@ -0,0 +1,14 @@
+// Configuration and logging live in src/app/
+
+pub mod config;
+pub mod logging;
+
+// Main application logic
+pub fn run() {
+    logging::info("Starting application");
+    let settings = config::Settings::default();
+    if settings.debug {
+        logging::error("Debug mode is not supported");
+    }
+    logging::info(&format!("Application started on port {}", settings.port));
+}
@@ -0,0 +1,13 @@
+pub struct Settings {
+    pub debug: bool,
+    pub port: u16,
+}
+
+impl Settings {
+    pub fn default() -> Self {
+        Settings {
+            debug: false,
+            port: 8080,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub fn info(msg: &str) {
+    println!("[INFO] {}", msg);
+}
+
+pub fn error(msg: &str) {
+    println!("[ERROR] {}", msg);
+}
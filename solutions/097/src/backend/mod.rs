@@ -0,0 +1,11 @@
+// The `backend` module in the older directory layout: this mod.rs is the
+// module itself and its submodules sit next to it.
+
+// Common types used throughout backend
+pub mod types;
+
+// Database operations
+pub mod db;
+
+// HTTP handlers
+pub mod handlers;
@@ -0,0 +1,8 @@
+pub type UserId = u64;
+pub type Timestamp = u64;
+
+#[derive(Debug, Clone)]
+pub struct User {
+    pub id: UserId,
+    pub name: String,
+}
@@ -0,0 +1,7 @@
+#[derive(Debug)]
+pub struct Daisy;
+
+#[derive(Debug)]
+pub struct Sunflower {
+    pub height: f64,
+}
@@ -0,0 +1,27 @@
+#[derive(Debug)]
+pub struct Tomato {
+    pub variety: String,
+    ripe: bool,
+}
+
+impl Tomato {
+    pub fn new(variety: &str) -> Self {
+        Tomato {
+            variety: variety.to_string(),
+            ripe: false,
+        }
+    }
+
+    pub fn ripen(&mut self) {
+        self.ripe = true;
+    }
+
+    pub fn is_ripe(&self) -> bool {
+        self.ripe
+    }
+}
+
+#[derive(Debug)]
+pub struct Carrot {
+    pub length: f64,
+}
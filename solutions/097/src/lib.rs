@@ -0,0 +1,21 @@
+// Reference solution for exercise 097: Modules - Nested Modules
+//
+// The src/ of the exercise crate; the grader supplies its Cargo.toml.
+
+pub mod garden;
+pub mod api;
+pub mod backend;
+pub mod app;
+
+pub fn create_garden_report() {
+    use garden::flowers::Rose;
+    use garden::vegetables::Tomato;
+
+    let tomato = Tomato::new("Beefsteak");
+    let rose = Rose::new("White");
+
+    println!(
+        "Garden contains: {:?} tomatoes and {:?} roses",
+        tomato.variety, rose.color
+    );
+}
@@ -0,0 +1,62 @@
+// The binary crate of exercise 097. Every path into the library starts
+// at its crate name, `exercise_097`.
+
+use exercise_097::{api, app, backend, garden};
+
+fn main() {
+    println!("=== Garden Module ===");
+    let mut plant = garden::Plant::new("Fern", 30.0);
+    println!("Plant: {} is {}cm tall", plant.name, plant.height());
+    plant.grow(5.0);
+    println!("After growing: {}cm", plant.height());
+
+    println!("\n=== Vegetables ===");
+    let mut tomato = garden::vegetables::Tomato::new("Roma");
+    println!("Tomato: {:?}, ripe: {}", tomato.variety, tomato.is_ripe());
+    tomato.ripen();
+    println!("After ripening: {}", tomato.is_ripe());
+
+    let carrot = garden::vegetables::Carrot { length: 15.0 };
+    println!("Carrot: {:?}", carrot);
+
+    println!("\n=== Flowers ===");
+    let rose = garden::flowers::Rose::new("Red");
+    println!("Rose: {:?}, has thorns: {}", rose.color, rose.has_thorns());
+
+    let daisy = garden::flowers::wildflowers::Daisy;
+    let sunflower = garden::flowers::wildflowers::Sunflower { height: 200.0 };
+    println!("Daisy: {:?}, Sunflower: {:?}", daisy, sunflower);
+
+    println!("\n=== Maintenance ===");
+    garden::maintain_garden();
+
+    println!("\n=== API Module ===");
+    api::garden_info();
+    let plant2 = api::Plant::new("Cactus", 10.0);
+    let tomato2 = api::Tomato::new("Cherry");
+    println!("Created: {} and {:?}", plant2.name, tomato2.variety);
+
+    println!("\n=== Backend Module ===");
+    use backend::types::UserId;
+
+    let user_id: UserId = 1;
+    if let Some(user) = backend::handlers::get_user(user_id) {
+        println!("Found user: {:?}", user);
+    }
+
+    let new_user = backend::handlers::create_user("Bob");
+    println!("Created user: {:?}", new_user);
+    println!("User {} is {}", new_user.id, new_user.name);
+
+    let created_at: backend::types::Timestamp = 1_700_000_000;
+    println!("Created at: {}", created_at);
+
+    let carrot2 = api::Carrot { length: 12.5 };
+    let rose2 = api::Rose::new("Pink");
+    println!("Carrot length: {}, rose color: {}", carrot2.length, rose2.color);
+    println!("Sunflower height: {}", sunflower.height);
+
+    println!("\n=== Report and App ===");
+    exercise_097::create_garden_report();
+    app::run();
+}
@@ -0,0 +1,102 @@
+// Reference solution for exercise 098: Tests - Unit Tests
+
+pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// Function with edge cases to test
+pub fn divide(a: f64, b: f64) -> Result<f64, String> {
+    if b == 0.0 {
+        Err(String::from("Cannot divide by zero"))
+    } else {
+        Ok(a / b)
+    }
+}
+
+// Struct with methods to test
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+impl Rectangle {
+    pub fn new(width: f64, height: f64) -> Self {
+        Rectangle { width, height }
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    pub fn is_square(&self) -> bool {
+        (self.width - self.height).abs() < f64::EPSILON
+    }
+
+    pub fn scale(&mut self, factor: f64) {
+        self.width *= factor;
+        self.height *= factor;
+    }
+
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+}
+
+// A more complex struct to test
+#[derive(Debug, PartialEq)]
+pub struct BankAccount {
+    balance: f64,
+    transactions: Vec<f64>,
+}
+
+impl BankAccount {
+    pub fn new(initial: f64) -> Self {
+        let mut account = BankAccount {
+            balance: 0.0,
+            transactions: Vec::new(),
+        };
+        // A negative initial amount is rejected and leaves the account empty.
+        let _ = account.deposit(initial);
+        account
+    }
+
+    pub fn deposit(&mut self, amount: f64) -> Result<(), &'static str> {
+        if amount < 0.0 {
+            return Err("Cannot deposit negative amount");
+        }
+        self.balance += amount;
+        self.transactions.push(amount);
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, amount: f64) -> Result<(), &'static str> {
+        if amount < 0.0 {
+            return Err("Cannot withdraw negative amount");
+        }
+        if amount > self.balance {
+            return Err("Insufficient funds");
+        }
+        self.balance -= amount;
+        self.transactions.push(-amount);
+        Ok(())
+    }
+
+    pub fn balance(&self) -> f64 {
+        self.balance
+    }
+
+    pub fn transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+}
+
+fn main() {
+    println!("Run tests with: cargo test");
+    println!("Run specific test: cargo test test_name");
+    println!("Run tests in this file: cargo test --bin template");
+}
@@ -0,0 +1,72 @@
+// Reference solution for exercise 099: Tests - Assert Macros
+
+pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}
+
+pub fn find_max(numbers: &[i32]) -> Option<&i32> {
+    numbers.iter().max()
+}
+
+pub fn parse_number(s: &str) -> Result<i32, String> {
+    s.parse().map_err(|_| format!("'{}' is not a valid number", s))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Person {
+    name: String,
+    age: u32,
+}
+
+impl Person {
+    pub fn new(name: &str, age: u32) -> Self {
+        Person {
+            name: name.to_string(),
+            age,
+        }
+    }
+
+    pub fn can_vote(&self) -> bool {
+        self.age >= 18
+    }
+
+    pub fn is_senior(&self) -> bool {
+        self.age >= 65
+    }
+}
+
+// A collection type to test
+#[derive(Debug)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+fn main() {
+    println!("Run tests with: cargo test");
+}
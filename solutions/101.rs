@@ -0,0 +1,55 @@
+// Reference solution for exercise 101: Bubble Sort Algorithm
+
+fn main() {
+    // Test data
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90];
+
+    println!("Original array: {:?}", numbers);
+
+    // Call the bubble sort function
+    bubble_sort(&mut numbers);
+
+    println!("Sorted array: {:?}", numbers);
+
+    // Verify the result
+    assert_eq!(numbers, vec![11, 12, 22, 25, 34, 64, 90]);
+    println!("✓ Bubble sort completed successfully!");
+}
+
+/// Bubble Sort Implementation
+///
+/// Algorithm steps:
+/// 1. Compare adjacent elements. If the first is greater than the second, swap them.
+/// 2. Do this for each pair of adjacent elements, from the first pair to the last.
+/// 3. After each pass, the largest unsorted element will "bubble up" to its correct position.
+/// 4. Repeat the process for n-1 passes (where n is the length of the array).
+///
+/// Hint: Use nested loops - outer loop for passes, inner loop for comparisons.
+/// Remember that after each pass, the last i elements are already sorted.
+fn bubble_sort(arr: &mut [i32]) {
+    let n = arr.len();
+
+    // The outer loop runs n-1 times because after n-1 passes,
+    // the array will be completely sorted
+
+    for i in 0..n {
+        // Optional optimization: Track if any swaps occurred in this pass
+        // If no swaps occurred, the array is already sorted
+        let mut swapped = false;
+
+        // The inner loop should go from 0 to n-i-1
+        // (we don't need to check the last i elements as they're already sorted)
+        for j in 0..(n - i - 1) {
+            // If arr[j] > arr[j+1], swap them
+            if arr[j] > arr[j + 1] {
+                arr.swap(j, j + 1);
+                swapped = true;
+            }
+        }
+
+        // Optimization: If no swapping occurred, array is sorted
+        if !swapped {
+            break;
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// Reference solution for exercise 102: Selection Sort Algorithm
+
+fn main() {
+    let mut numbers = vec![64, 25, 12, 22, 11];
+
+    println!("Original array: {:?}", numbers);
+
+    selection_sort(&mut numbers);
+
+    println!("Sorted array: {:?}", numbers);
+
+    assert_eq!(numbers, vec![11, 12, 22, 25, 64]);
+    println!("✓ Selection sort completed successfully!");
+}
+
+/// Selection Sort Implementation
+///
+/// Algorithm steps:
+/// 1. Find the minimum element in the unsorted portion of the array
+/// 2. Swap it with the first element of the unsorted portion
+/// 3. Move the boundary between sorted and unsorted one element to the right
+/// 4. Repeat until the entire array is sorted
+///
+/// Hint: The outer loop tracks where the sorted portion ends.
+/// The inner loop finds the minimum in the remaining unsorted portion.
+fn selection_sort(arr: &mut [i32]) {
+    let n = arr.len();
+
+    // One by one move the boundary of the unsorted subarray
+    // The outer loop should go from 0 to n-1
+    for i in 0..n {
+        // Assume the first element is the minimum
+        let mut min_idx = i;
+
+        for j in (i + 1)..n {
+            if arr[j] < arr[min_idx] {
+                min_idx = j;
+            }
+        }
+
+        // Only swap if min_idx is different from i
+        if min_idx != i {
+            arr.swap(i, min_idx);
+        }
+    }
+}
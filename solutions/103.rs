@@ -0,0 +1,46 @@
+// Reference solution for exercise 103: Insertion Sort Algorithm
+
+fn main() {
+    let mut numbers = vec![12, 11, 13, 5, 6];
+
+    println!("Original array: {:?}", numbers);
+
+    insertion_sort(&mut numbers);
+
+    println!("Sorted array: {:?}", numbers);
+
+    assert_eq!(numbers, vec![5, 6, 11, 12, 13]);
+    println!("✓ Insertion sort completed successfully!");
+}
+
+/// Insertion Sort Implementation
+///
+/// Algorithm steps:
+/// 1. Start from the second element (index 1), assuming the first is sorted
+/// 2. Pick the current element and compare it with elements in the sorted portion
+/// 3. Shift all larger elements one position to the right
+/// 4. Insert the current element in its correct position
+/// 5. Repeat for all elements
+///
+/// Hint: Think of the array as having a sorted left part and unsorted right part.
+/// Pick each element from the unsorted part and insert it into the correct
+/// position in the sorted part.
+fn insertion_sort(arr: &mut [i32]) {
+    let n = arr.len();
+
+    // Start from index 1 (second element)
+    for i in 1..n {
+        let key = arr[i];
+
+        // j will track where we need to insert key
+        let mut j = i;
+
+        // Move backwards through the sorted portion
+        while j > 0 && arr[j - 1] > key {
+            arr[j] = arr[j - 1];
+            j -= 1;
+        }
+
+        arr[j] = key;
+    }
+}
@@ -0,0 +1,37 @@
+// Reference solution for exercise 104: Linear Search Algorithm
+
+fn main() {
+    let numbers = vec![10, 20, 80, 30, 60, 50, 110, 100, 130, 170];
+
+    // Test cases
+    let test_cases = vec![(110, Some(6)), (200, None), (10, Some(0))];
+
+    for (target, expected) in test_cases {
+        let result = linear_search(&numbers, target);
+        println!("Searching for {}: {:?} (expected: {:?})", target, result, expected);
+        assert_eq!(result, expected);
+    }
+
+    println!("✓ Linear search completed successfully!");
+}
+
+/// Linear Search Implementation
+///
+/// Algorithm steps:
+/// 1. Start from the leftmost element of the array
+/// 2. One by one compare the target with each element
+/// 3. If the target matches an element, return its index
+/// 4. If the target doesn't match any element, return None
+///
+/// Hint: Use a simple loop to iterate through all elements.
+/// Return the index as soon as you find a match.
+fn linear_search(arr: &[i32], target: i32) -> Option<usize> {
+    // Iterate through each element with its index
+    for (index, &value) in arr.iter().enumerate() {
+        if value == target {
+            return Some(index);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,84 @@
+// Reference solution for exercise 105: Binary Search Algorithm
+
+fn main() {
+    let numbers = vec![2, 3, 4, 10, 40, 50, 70, 80, 90, 100];
+
+    println!("Sorted array: {:?}", numbers);
+
+    // Test cases
+    let test_cases = vec![(10, Some(3)), (100, Some(9)), (5, None), (2, Some(0))];
+
+    for (target, expected) in test_cases {
+        let result = binary_search(&numbers, target);
+        println!("Searching for {}: {:?} (expected: {:?})", target, result, expected);
+        assert_eq!(result, expected);
+    }
+
+    let found = binary_search_recursive(&numbers, 70, 0, numbers.len() as isize - 1);
+    println!("Recursive search for 70: {:?}", found);
+
+    println!("✓ Binary search completed successfully!");
+}
+
+/// Binary Search Implementation (Iterative)
+///
+/// Algorithm steps:
+/// 1. Compare the target with the middle element
+/// 2. If target matches middle, return the middle index
+/// 3. If target is greater, search in the right half
+/// 4. If target is smaller, search in the left half
+/// 5. Repeat until found or subarray size becomes 0
+///
+/// Hint: Use two pointers (left and right) to track the search range.
+/// Calculate mid as left + (right - left) / 2 to avoid overflow.
+fn binary_search(arr: &[i32], target: i32) -> Option<usize> {
+    let mut left = 0;
+    let mut right = arr.len() as isize - 1;
+
+    // Continue while left pointer is less than or equal to right
+    while left <= right {
+        // Use this formula to prevent overflow: left + (right - left) / 2
+        let mid = left + (right - left) / 2;
+        let mid_idx = mid as usize;
+
+        if arr[mid_idx] == target {
+            return Some(mid_idx);
+        }
+
+        if arr[mid_idx] < target {
+            left = mid + 1;
+        } else {
+            right = mid - 1;
+        }
+    }
+
+    None
+}
+
+/// Binary Search Implementation (Recursive) - Bonus!
+///
+/// This is an optional recursive version of binary search.
+/// Try implementing this after completing the iterative version.
+fn binary_search_recursive(arr: &[i32], target: i32, left: usize, right: isize) -> Option<usize> {
+    // Base case: if left > right, target not found
+    if left as isize > right {
+        return None;
+    }
+
+    let mid = left + ((right - left as isize) / 2) as usize;
+
+    if arr[mid] == target {
+        return Some(mid);
+    }
+
+    if arr[mid] > target {
+        // Search left half
+        if mid == 0 {
+            return None;
+        }
+        return binary_search_recursive(arr, target, left, mid as isize - 1);
+    } else {
+        // Search right half
+        return binary_search_recursive(arr, target, mid + 1, right);
+    }
+}
@@ -0,0 +1,52 @@
+// Reference solution for exercise 106: Factorial (Recursive)
+
+fn main() {
+    // Test cases
+    let test_cases = vec![(0, 1), (1, 1), (5, 120), (7, 5040), (10, 3628800)];
+
+    for (n, expected) in test_cases {
+        let result = factorial(n);
+        println!("{}! = {} (expected: {})", n, result, expected);
+        assert_eq!(result, expected);
+    }
+
+    println!("Iterative 10! = {}", factorial_iterative(10));
+
+    println!("✓ Factorial (recursive) completed successfully!");
+}
+
+/// Recursive Factorial Implementation
+///
+/// The factorial function can be defined recursively:
+/// - Base case: 0! = 1 and 1! = 1
+/// - Recursive case: n! = n × (n-1)!
+///
+/// Example trace for factorial(4):
+/// factorial(4) = 4 × factorial(3)
+///              = 4 × (3 × factorial(2))
+///              = 4 × (3 × (2 × factorial(1)))
+///              = 4 × (3 × (2 × 1))
+///              = 24
+///
+/// Hint: Handle the base case first, then implement the recursive step.
+fn factorial(n: u64) -> u64 {
+    // If n is 0 or 1, return 1
+    if n <= 1 {
+        return 1;
+    }
+
+    // Return n multiplied by factorial of (n-1)
+    n * factorial(n - 1)
+}
+
+/// Iterative Factorial - Bonus implementation
+///
+/// While the exercise focuses on recursion, here's how you'd
+/// implement it iteratively for comparison.
+fn factorial_iterative(n: u64) -> u64 {
+    let mut result = 1;
+    for i in 2..=n {
+        result *= i;
+    }
+    result
+}
@@ -0,0 +1,111 @@
+// Reference solution for exercise 108: Fibonacci (Iterative with Memoization)
+
+fn main() {
+    // Test cases
+    let test_cases = vec![
+        (0, 0),
+        (1, 1),
+        (2, 1),
+        (10, 55),
+        (30, 832040),
+        (50, 12586269025),
+    ];
+
+    println!("=== Iterative Approach ===");
+    for (n, expected) in &test_cases {
+        let result = fibonacci_iterative(*n);
+        println!("F({}) = {} (expected: {})", n, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    println!("\n=== Memoization Approach ===");
+    for (n, expected) in &test_cases {
+        let result = fibonacci_memoized(*n);
+        println!("F({}) = {} (expected: {})", n, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    let mut cache = std::collections::HashMap::new();
+    println!("fib(50) with shared cache = {}", fibonacci_with_cache(50, &mut cache));
+
+    println!("\n✓ Fibonacci (iterative with memoization) completed successfully!");
+}
+
+/// Iterative Fibonacci with O(1) space
+///
+/// This approach uses constant space by only keeping track of
+/// the last two Fibonacci numbers at any time.
+///
+/// Algorithm:
+/// 1. Handle base cases
+/// 2. Initialize variables for the previous two Fibonacci numbers
+/// 3. Iterate from 2 to n, computing each Fibonacci number
+/// 4. Update the previous two numbers after each iteration
+fn fibonacci_iterative(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+
+    let mut prev2 = 0; // F(n-2)
+    let mut prev1 = 1; // F(n-1)
+    let mut current = 0;
+
+    for _ in 2..=n {
+        current = prev1 + prev2;
+
+        prev2 = prev1;
+        prev1 = current;
+    }
+
+    current
+}
+
+/// Memoized Fibonacci with O(n) space
+///
+/// This approach stores all Fibonacci numbers up to n in a vector.
+/// While it uses more space, it allows O(1) lookup for any F(k) where k <= n.
+///
+/// This is also useful if you need to access multiple Fibonacci numbers
+/// and want to avoid recalculating.
+fn fibonacci_memoized(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+
+    // Initialize with base cases: memo[0] = 0, memo[1] = 1
+    let mut memo = vec![0u64; (n + 1) as usize];
+    memo[0] = 0;
+    memo[1] = 1;
+
+    for i in 2..=n as usize {
+        memo[i] = memo[i - 1] + memo[i - 2];
+    }
+
+    memo[n as usize]
+}
+
+/// Dynamic Programming with closure-based memoization (Advanced)
+///
+/// This demonstrates a more functional approach using Rust closures.
+/// This is advanced - try implementing the simpler versions first.
+fn fibonacci_with_cache(n: u64, cache: &mut std::collections::HashMap<u64, u64>) -> u64 {
+    if let Some(&result) = cache.get(&n) {
+        return result;
+    }
+
+    if n <= 1 {
+        return n;
+    }
+
+    let result = fibonacci_with_cache(n - 1, cache) + fibonacci_with_cache(n - 2, cache);
+
+    cache.insert(n, result);
+
+    result
+}
@@ -0,0 +1,97 @@
+// Reference solution for exercise 109: Greatest Common Divisor (Euclidean Algorithm)
+
+fn main() {
+    // Test cases
+    let test_cases = vec![
+        ((48, 18), 6),
+        ((56, 98), 14),
+        ((101, 103), 1), // Coprime numbers
+        ((100, 25), 25),
+        ((0, 5), 5),
+        ((5, 0), 5),
+        ((0, 0), 0),
+    ];
+
+    println!("=== Euclidean Algorithm (Iterative) ===");
+    for ((a, b), expected) in &test_cases {
+        let result = gcd(*a, *b);
+        println!("gcd({}, {}) = {} (expected: {})", a, b, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    println!("\n=== Euclidean Algorithm (Recursive) ===");
+    for ((a, b), expected) in &test_cases {
+        let result = gcd_recursive(*a, *b);
+        println!("gcd({}, {}) = {} (expected: {})", a, b, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    println!("lcm(4, 6) = {}", lcm(4, 6));
+
+    println!("\n✓ GCD (Euclidean algorithm) completed successfully!");
+}
+
+/// GCD using Euclidean Algorithm (Iterative)
+///
+/// Algorithm:
+/// 1. If b = 0, return a
+/// 2. Otherwise, compute gcd(b, a % b) and repeat
+///
+/// Example: gcd(48, 18)
+/// Step 1: gcd(48, 18) → 48 % 18 = 12
+/// Step 2: gcd(18, 12) → 18 % 12 = 6
+/// Step 3: gcd(12, 6)  → 12 % 6 = 0
+/// Step 4: gcd(6, 0)   → return 6
+///
+/// The result is 6.
+fn gcd(a: u64, b: u64) -> u64 {
+    if a == 0 && b == 0 {
+        return 0;
+    }
+
+    let mut a = a;
+    let mut b = b;
+
+    // While b is not 0, continue
+    while b != 0 {
+        let temp = a % b;
+
+        a = b;
+        b = temp;
+    }
+
+    a
+}
+
+/// GCD using Euclidean Algorithm (Recursive)
+///
+/// This is the same algorithm but implemented recursively.
+/// It's more elegant but uses more stack space.
+fn gcd_recursive(a: u64, b: u64) -> u64 {
+    if a == 0 && b == 0 {
+        return 0;
+    }
+
+    // If b is 0, return a
+    if b == 0 {
+        return a;
+    }
+
+    // gcd(a, b) = gcd(b, a % b)
+    gcd_recursive(b, a % b)
+}
+
+/// Least Common Multiple (LCM) - Bonus
+///
+/// The LCM of two numbers can be computed using the GCD:
+/// lcm(a, b) = |a × b| / gcd(a, b)
+fn lcm(a: u64, b: u64) -> u64 {
+    // Handle edge cases
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    // LCM = (a * b) / GCD(a, b)
+    // Be careful of overflow - use division first when possible
+    (a / gcd(a, b)) * b
+}
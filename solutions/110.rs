@@ -0,0 +1,96 @@
+// Reference solution for exercise 110: Prime Number Check
+
+fn main() {
+    // Test cases: (number, is_prime)
+    let test_cases = vec![
+        (0, false),
+        (1, false),
+        (2, true),   // Smallest prime
+        (3, true),
+        (4, false),
+        (17, true),
+        (18, false),
+        (97, true),
+        (100, false),
+        (104729, true), // Large prime
+    ];
+
+    println!("=== Prime Check ===");
+    for (n, expected) in &test_cases {
+        let result = is_prime(*n);
+        let status = if result == *expected { "✓" } else { "✗" };
+        println!("{} is_prime({}) = {} (expected: {})", status, n, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    // Test finding primes in a range
+    println!("\n=== Primes up to 50 ===");
+    let primes: Vec<u64> = (2..=50).filter(|&n| is_prime(n)).collect();
+    println!("{:?}", primes);
+
+    println!("is_prime_naive(97) = {}", is_prime_naive(97));
+    println!("Primes up to 30: {:?}", primes_up_to(30));
+
+    println!("\n✓ Prime number check completed successfully!");
+}
+
+/// Check if a number is prime
+///
+/// A prime number is a natural number greater than 1 that has exactly
+/// two distinct divisors: 1 and itself.
+///
+/// Optimizations used:
+/// 1. Handle numbers less than 2 (not prime)
+/// 2. Handle 2 separately (it's the only even prime)
+/// 3. Eliminate all other even numbers
+/// 4. Only check odd divisors up to √n
+///
+/// Why √n? If n = a × b, at least one of a or b must be ≤ √n
+fn is_prime(n: u64) -> bool {
+    // Numbers less than 2 are not prime
+    if n < 2 {
+        return false;
+    }
+
+    if n == 2 {
+        return true;
+    }
+
+    if n % 2 == 0 {
+        return false;
+    }
+
+    // Start at 3, increment by 2 (only check odd numbers)
+    let limit = (n as f64).sqrt() as u64 + 1;
+
+    for i in (3..limit).step_by(2) {
+        if n % i == 0 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Naive prime check (for comparison)
+///
+/// This is a simpler but much slower implementation that checks
+/// all numbers from 2 to n-1.
+fn is_prime_naive(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    for i in 2..n {
+        if n % i == 0 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Generate all primes up to n using trial division
+fn primes_up_to(n: u64) -> Vec<u64> {
+    (2..=n).filter(|&x| is_prime(x)).collect()
+}
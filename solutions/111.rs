@@ -0,0 +1,86 @@
+// Reference solution for exercise 111: Sieve of Eratosthenes
+
+fn main() {
+    let limit = 50;
+
+    println!("Finding all primes up to {}...", limit);
+
+    let primes = sieve_of_eratosthenes(limit);
+
+    println!("Primes found: {:?}", primes);
+    println!("Count: {}", primes.len());
+
+    // Verify against known primes up to 50
+    let expected = vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+    assert_eq!(primes, expected);
+
+    // Test with larger limit
+    let large_primes = sieve_of_eratosthenes(100);
+    assert_eq!(large_primes.len(), 25); // 25 primes under 100
+
+    println!("Optimized sieve up to 30: {:?}", sieve_optimized(30));
+    println!("Primes below 100: {}", count_primes(100));
+
+    println!("✓ Sieve of Eratosthenes completed successfully!");
+}
+
+/// Sieve of Eratosthenes Implementation
+///
+/// Algorithm:
+/// 1. Create a boolean array "is_prime[0..n]" and initialize all entries as true
+/// 2. Mark 0 and 1 as not prime
+/// 3. For each number p from 2 to √n:
+///    a. If is_prime[p] is true, mark all multiples of p as not prime
+/// 4. Collect all indices that are still marked as prime
+///
+/// Key optimization: Start marking multiples from p*p (not 2*p)
+/// because smaller multiples would have been marked by smaller primes.
+fn sieve_of_eratosthenes(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return vec![];
+    }
+
+    // Initialize all entries as true (assuming prime until proven otherwise)
+    let mut is_prime = vec![true; n + 1];
+
+    is_prime[0] = false;
+    is_prime[1] = false;
+
+    // Iterate from 2 to √n
+    let limit = (n as f64).sqrt() as usize + 1;
+
+    for p in 2..limit {
+        if is_prime[p] {
+            // Start from p*p (optimization: smaller multiples already marked)
+            // Step by p to hit all multiples
+            let mut multiple = p * p;
+            while multiple <= n {
+                is_prime[multiple] = false;
+                multiple += p;
+            }
+        }
+    }
+
+    // Iterate through is_prime and collect indices that are still true
+    let mut primes = Vec::new();
+    for (i, &prime) in is_prime.iter().enumerate() {
+        if prime {
+            primes.push(i);
+        }
+    }
+
+    primes
+}
+
+/// Optimized Sieve - Bit Vector Version (Bonus)
+///
+/// This version uses less memory by packing booleans into bits.
+fn sieve_optimized(n: usize) -> Vec<usize> {
+    // This is more advanced and requires understanding of bit operations
+    sieve_of_eratosthenes(n) // Placeholder - implement optimized version
+}
+
+/// Count primes without storing them all (Bonus)
+fn count_primes(n: usize) -> usize {
+    sieve_of_eratosthenes(n).len()
+}
@@ -0,0 +1,134 @@
+// Reference solution for exercise 112: Palindrome Check
+
+fn main() {
+    // Test string palindromes
+    let string_tests = vec![
+        ("radar", true),
+        ("level", true),
+        ("hello", false),
+        ("A man a plan a canal Panama", true), // With spaces (case-insensitive)
+        ("racecar", true),
+        ("", true),  // Empty string is a palindrome
+        ("a", true), // Single character is a palindrome
+    ];
+
+    println!("=== String Palindrome Check ===");
+    for (s, expected) in &string_tests {
+        let result = is_palindrome_string(s);
+        let status = if result == *expected { "✓" } else { "✗" };
+        println!("{} '{}' -> {} (expected: {})", status, s, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    // Test number palindromes
+    let number_tests = vec![
+        (121, true),
+        (12321, true),
+        (12345, false),
+        (0, true),
+        (5, true),
+        (10, false),
+        (1001, true),
+    ];
+
+    println!("\n=== Number Palindrome Check ===");
+    for (n, expected) in &number_tests {
+        let result = is_palindrome_number(*n);
+        let status = if result == *expected { "✓" } else { "✗" };
+        println!("{} {} -> {} (expected: {})", status, n, result, expected);
+        assert_eq!(result, *expected);
+    }
+
+    println!("12321 via string: {}", is_palindrome_number_string(12321));
+    println!("Recursive \"Race car\": {}", is_palindrome_recursive("Race car"));
+
+    println!("\n✓ Palindrome check completed successfully!");
+}
+
+/// Check if a string is a palindrome (case-insensitive, ignoring spaces)
+///
+/// Algorithm:
+/// 1. Filter out non-alphanumeric characters and convert to lowercase
+/// 2. Compare characters from both ends moving towards center
+/// 3. If any pair doesn't match, it's not a palindrome
+fn is_palindrome_string(s: &str) -> bool {
+    let chars: Vec<char> = s
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect();
+
+    if chars.len() <= 1 {
+        return true;
+    }
+
+    let mut left = 0;
+    let mut right = chars.len() - 1;
+
+    while left < right {
+        if chars[left] != chars[right] {
+            return false;
+        }
+
+        left += 1;
+        right -= 1;
+    }
+
+    true
+}
+
+/// Check if a number is a palindrome
+///
+/// Algorithm:
+/// 1. Handle edge case: negative numbers are not palindromes
+/// 2. Reverse the number by repeatedly extracting the last digit
+/// 3. Compare the reversed number with the original
+///
+/// Alternative approach: Convert to string and use string palindrome check
+fn is_palindrome_number(n: i32) -> bool {
+    // Negative numbers are not palindromes
+    if n < 0 {
+        return false;
+    }
+
+    if n < 10 {
+        return true;
+    }
+
+    // Reverse in i64: the reversal of a large i32 may not fit in an i32
+    let original = n as i64;
+    let mut reversed = 0i64;
+    let mut temp = n as i64;
+
+    while temp > 0 {
+        let digit = temp % 10;
+        reversed = reversed * 10 + digit;
+        temp /= 10;
+    }
+
+    original == reversed
+}
+
+/// Alternative: Check palindrome using string conversion
+fn is_palindrome_number_string(n: i32) -> bool {
+    // Convert number to string, then check if it's a palindrome
+    let s = n.to_string();
+    is_palindrome_string(&s)
+}
+
+/// Recursive palindrome check (Bonus)
+fn is_palindrome_recursive(s: &str) -> bool {
+    let filtered: String = s.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect();
+
+    if filtered.chars().count() <= 1 {
+        return true;
+    }
+
+    let mut chars = filtered.chars();
+    // Peel off whole chars; byte slicing would split multi-byte characters
+    if chars.next() != chars.next_back() {
+        return false;
+    }
+
+    is_palindrome_recursive(chars.as_str())
+}
@@ -0,0 +1,120 @@
+// Reference solution for exercise 115: FizzBuzz Algorithm
+
+fn main() {
+    println!("=== Classic FizzBuzz (1 to 20) ===\n");
+
+    // Print first 20 FizzBuzz results
+    for i in 1..=20 {
+        let result = fizzbuzz(i);
+        print!("{} ", result);
+    }
+    println!("\n");
+
+    // Verify specific cases
+    assert_eq!(fizzbuzz(1), "1");
+    assert_eq!(fizzbuzz(3), "Fizz");
+    assert_eq!(fizzbuzz(5), "Buzz");
+    assert_eq!(fizzbuzz(15), "FizzBuzz");
+    assert_eq!(fizzbuzz(30), "FizzBuzz");
+
+    println!("=== Custom FizzBuzz (with different rules) ===\n");
+
+    // Custom version with different divisors
+    for i in 1..=20 {
+        let result = custom_fizzbuzz(i, 2, 7, "Even", "Lucky");
+        print!("{} ", result);
+    }
+    println!("\n");
+
+    println!("fizzbuzz_match(15) = {}", fizzbuzz_match(15));
+    println!("Sequence to 5: {:?}", fizzbuzz_sequence(5));
+    println!("Extended 105: {}", fizzbuzz_extended(105));
+
+    println!("✓ FizzBuzz completed successfully!");
+}
+
+/// Classic FizzBuzz implementation
+///
+/// Returns the appropriate string for a given number:
+/// - "Fizz" if divisible by 3
+/// - "Buzz" if divisible by 5
+/// - "FizzBuzz" if divisible by both
+/// - The number itself as a string otherwise
+///
+/// Order of conditions matters! Check for both (3 and 5) first,
+/// otherwise "FizzBuzz" will never be returned.
+fn fizzbuzz(n: u32) -> String {
+    if n % 3 == 0 && n % 5 == 0 {
+        return "FizzBuzz".to_string();
+    }
+
+    if n % 3 == 0 {
+        return "Fizz".to_string();
+    }
+
+    if n % 5 == 0 {
+        return "Buzz".to_string();
+    }
+
+    n.to_string()
+}
+
+/// Alternative FizzBuzz using match
+///
+/// This shows a more idiomatic Rust approach using pattern matching.
+fn fizzbuzz_match(n: u32) -> String {
+    // Match on (n % 3 == 0, n % 5 == 0) to cover all cases
+    match (n % 3 == 0, n % 5 == 0) {
+        (true, true) => "FizzBuzz".to_string(),
+        (true, false) => "Fizz".to_string(),
+        (false, true) => "Buzz".to_string(),
+        (false, false) => n.to_string(),
+    }
+}
+
+/// Custom FizzBuzz with configurable parameters
+///
+/// Allows specifying different divisors and replacement words.
+fn custom_fizzbuzz(
+    n: u32,
+    div1: u32,
+    div2: u32,
+    word1: &str,
+    word2: &str,
+) -> String {
+    // Use the provided divisors and words
+
+    match (n % div1 == 0, n % div2 == 0) {
+        (true, true) => format!("{}{}", word1, word2),
+        (true, false) => word1.to_string(),
+        (false, true) => word2.to_string(),
+        (false, false) => n.to_string(),
+    }
+}
+
+/// Generate FizzBuzz sequence as a vector
+fn fizzbuzz_sequence(n: u32) -> Vec<String> {
+    (1..=n).map(|i| fizzbuzz(i)).collect()
+}
+
+/// FizzBuzz with additional rules (Bonus)
+///
+/// Extended version that also prints:
+/// - "Jazz" for multiples of 7
+/// - "FizzBuzzJazz" for multiples of 3, 5, and 7
+fn fizzbuzz_extended(n: u32) -> String {
+    let fizz = n % 3 == 0;
+    let buzz = n % 5 == 0;
+    let jazz = n % 7 == 0;
+
+    match (fizz, buzz, jazz) {
+        (true, true, true) => "FizzBuzzJazz".to_string(),
+        (true, true, false) => "FizzBuzz".to_string(),
+        (true, false, true) => "FizzJazz".to_string(),
+        (false, true, true) => "BuzzJazz".to_string(),
+        (true, false, false) => "Fizz".to_string(),
+        (false, true, false) => "Buzz".to_string(),
+        (false, false, true) => "Jazz".to_string(),
+        (false, false, false) => n.to_string(),
+    }
+}
@@ -0,0 +1,125 @@
+// Reference solution for exercise 117: Power Calculation (Fast Exponentiation)
+
+fn main() {
+    let test_cases = vec![
+        (2, 10, 1024),
+        (3, 5, 243),
+        (5, 0, 1),      // Any number^0 = 1
+        (10, 3, 1000),
+        (2, 20, 1048576),
+    ];
+
+    println!("=== Fast Exponentiation ===\n");
+
+    for (base, exp, expected) in &test_cases {
+        let result = fast_power(*base, *exp);
+        let result_recursive = fast_power_recursive(*base, *exp);
+
+        println!("{}^{} = {} (expected: {})", base, exp, result, expected);
+        assert_eq!(result, *expected);
+        assert_eq!(result_recursive, *expected);
+    }
+
+    // Demonstrate the efficiency with large exponents
+    println!("\n=== Large Exponent Demo ===");
+    let big_result = fast_power(2, 60);
+    println!("2^60 = {}", big_result);
+
+    println!("3^200 mod 13 = {}", modular_power(3, 200, 13));
+    println!("Naive 2^10 = {}", naive_power(2, 10));
+
+    println!("\n✓ Fast exponentiation completed successfully!");
+}
+
+/// Fast Exponentiation (Iterative)
+///
+/// Algorithm (Exponentiation by Squaring):
+/// - If exp is 0, return 1
+/// - While exp > 0:
+///   - If exp is odd: result *= base
+///   - base *= base (square the base)
+///   - exp /= 2 (divide exponent by 2)
+///
+/// Example: 3^13
+/// Iteration 1: exp=13 (odd), result=3, base=9, exp=6
+/// Iteration 2: exp=6  (even), result=3, base=81, exp=3
+/// Iteration 3: exp=3  (odd), result=243, base=6561, exp=1
+/// Iteration 4: exp=1  (odd), result=1594323, base=..., exp=0
+/// Return: 1594323
+fn fast_power(base: u64, exp: u32) -> u64 {
+    if exp == 0 {
+        return 1;
+    }
+
+    let mut result = 1u64;
+    let mut b = base;
+    let mut e = exp;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = result.wrapping_mul(b);
+        }
+
+        b = b.wrapping_mul(b);
+
+        e /= 2;
+    }
+
+    result
+}
+
+/// Fast Exponentiation (Recursive)
+///
+/// Recursive formulation:
+/// - power(x, 0) = 1
+/// - power(x, n) = power(x², n/2)          if n is even
+/// - power(x, n) = x * power(x², n/2)      if n is odd
+fn fast_power_recursive(base: u64, exp: u32) -> u64 {
+    if exp == 0 {
+        return 1;
+    }
+
+    let half = fast_power_recursive(base, exp / 2);
+
+    if exp % 2 == 0 {
+        half.wrapping_mul(half)
+    } else {
+        base.wrapping_mul(half).wrapping_mul(half)
+    }
+}
+
+/// Modular Exponentiation (Bonus)
+///
+/// Computes (base^exp) % modulus efficiently.
+/// Essential for cryptography and competitive programming.
+fn modular_power(base: u64, exp: u32, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+
+    let mut result = 1u64;
+    let mut b = base % modulus;
+    let mut e = exp;
+
+    // Widen the products so moduli above 2^32 cannot overflow
+    let mul_mod = |x: u64, y: u64| (x as u128 * y as u128 % modulus as u128) as u64;
+
+    while e > 0 {
+        if e % 2 == 1 {
+            result = mul_mod(result, b);
+        }
+        b = mul_mod(b, b);
+        e /= 2;
+    }
+
+    result
+}
+
+/// Naive power (for comparison/testing)
+fn naive_power(base: u64, exp: u32) -> u64 {
+    let mut result = 1u64;
+    for _ in 0..exp {
+        result = result.wrapping_mul(base);
+    }
+    result
+}
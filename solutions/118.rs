@@ -0,0 +1,106 @@
+// Reference solution for exercise 118: Tower of Hanoi
+
+fn main() {
+    println!("=== Tower of Hanoi ===\n");
+
+    // Solve for different numbers of disks
+    for num_disks in 1..=4 {
+        println!("Solving for {} disk(s):", num_disks);
+
+        let moves = solve_hanoi(num_disks, 'A', 'C', 'B');
+
+        println!("Total moves: {}", moves);
+        println!("Minimum required: {}\n", (1u64 << num_disks) - 1);
+
+        assert_eq!(moves as u64, (1u64 << num_disks) - 1);
+    }
+
+    println!("\nIterative solution for 3 disks:");
+    hanoi_iterative(3);
+    println!("Minimum moves for 10 disks: {}", min_moves(10));
+
+    println!("\nStarting state for 3 disks:");
+    print_state(&[3, 2, 1], &[], &[]);
+
+    println!("✓ Tower of Hanoi completed successfully!");
+}
+
+/// Tower of Hanoi Solver
+///
+/// Algorithm:
+/// To move n disks from source to destination:
+/// 1. Move n-1 disks from source to auxiliary (using destination as helper)
+/// 2. Move the nth (largest) disk from source to destination
+/// 3. Move n-1 disks from auxiliary to destination (using source as helper)
+///
+/// Base case: If n = 1, just move the disk directly
+///
+/// Parameters:
+/// - n: number of disks
+/// - source: source rod (e.g., 'A')
+/// - destination: destination rod (e.g., 'C')
+/// - auxiliary: auxiliary rod (e.g., 'B')
+///
+/// Returns: total number of moves made
+fn solve_hanoi(n: u32, source: char, destination: char, auxiliary: char) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+
+    // If there's only 1 disk, just move it directly
+    if n == 1 {
+        println!("  Move disk 1 from {} to {}", source, destination);
+        return 1;
+    }
+
+    let mut moves = 0;
+
+    // Use destination as the auxiliary rod
+    moves += solve_hanoi(n - 1, source, auxiliary, destination);
+
+    println!("  Move disk {} from {} to {}", n, source, destination);
+    moves += 1;
+
+    // Use source as the auxiliary rod
+    moves += solve_hanoi(n - 1, auxiliary, destination, source);
+
+    moves
+}
+
+/// Non-recursive move counter (Bonus)
+///
+/// The minimum number of moves for n disks is always 2^n - 1
+/// This can be computed directly without recursion.
+fn min_moves(n: u32) -> u64 {
+    // 2^64 - 1 does not fit the shift, so saturate at u64::MAX
+    1u64.checked_shl(n).map_or(u64::MAX, |p| p - 1)
+}
+
+/// Iterative solution using bit manipulation (Bonus)
+///
+/// For a given move number m (0-indexed):
+/// - Disk number = position of least significant 1-bit in (m + 1)
+/// - Direction depends on parity of n
+fn hanoi_iterative(n: u32) {
+    let total_moves = min_moves(n);
+    // With an even number of disks the cycle runs A -> B -> C, so swap the
+    // roles of B and C to still finish on C.
+    let rods = if n % 2 == 0 { ['A', 'C', 'B'] } else { ['A', 'B', 'C'] };
+
+    for m in 1..=total_moves {
+        // Find which disk to move
+        let disk = m.trailing_zeros() + 1;
+        let from = ((m & (m - 1)) % 3) as usize;
+        let to = (((m | (m - 1)) + 1) % 3) as usize;
+        println!("  Move disk {} from {} to {}", disk, rods[from], rods[to]);
+    }
+}
+
+/// Print state representation (Bonus)
+///
+/// Displays the current state of all three rods.
+fn print_state(rod_a: &[u32], rod_b: &[u32], rod_c: &[u32]) {
+    println!("A: {:?}", rod_a);
+    println!("B: {:?}", rod_b);
+    println!("C: {:?}", rod_c);
+}
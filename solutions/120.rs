@@ -0,0 +1,191 @@
+// Reference solution for exercise 120: Stack Implementation using Vec
+
+fn main() {
+    println!("=== Stack Implementation ===\n");
+
+    let mut stack = Stack::new();
+
+    println!("Pushing: 10, 20, 30");
+    stack.push(10);
+    stack.push(20);
+    stack.push(30);
+
+    println!("Stack size: {}", stack.size());
+    println!("Top element: {:?}", stack.peek());
+
+    println!("\nPopping:");
+    while let Some(val) = stack.pop() {
+        println!("  Popped: {}", val);
+    }
+
+    println!("\nIs empty? {}", stack.is_empty());
+
+    // Demonstrate practical use: balanced parentheses
+    println!("\n=== Balanced Parentheses Check ===");
+    let expressions = vec![
+        "((()))",
+        "()()",
+        "(()",
+        "())(",
+        "",
+        "([{}])",
+    ];
+
+    for expr in expressions {
+        let result = is_balanced(expr);
+        println!("'{}' -> {}", expr, if result { "balanced" } else { "not balanced" });
+    }
+
+    println!("\nReversed with a stack: {}", reverse_with_stack("stack"));
+    println!("3 4 + 2 * = {:?}", evaluate_postfix("3 4 + 2 *"));
+    println!("1 0 / = {:?}", evaluate_postfix("1 0 /"));
+
+    stack.push(1);
+    stack.push(2);
+    stack.clear();
+    println!("Size after clear: {}", stack.size());
+
+    println!("\n✓ Stack implementation completed successfully!");
+}
+
+/// Stack data structure
+///
+/// Uses Vec internally for dynamic array storage.
+/// The top of the stack is the end of the Vec.
+#[derive(Debug)]
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// Create a new empty stack
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    /// Push an item onto the stack
+    ///
+    /// Time Complexity: O(1) amortized
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Pop an item from the stack
+    ///
+    /// Returns None if the stack is empty.
+    /// Time Complexity: O(1)
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Peek at the top item without removing it
+    ///
+    /// Returns None if the stack is empty.
+    /// Time Complexity: O(1)
+    fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Check if the stack is empty
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the size of the stack
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Clear all items from the stack
+    fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+/// Check if parentheses are balanced using a stack
+///
+/// Algorithm:
+/// 1. For each character in the string:
+///    - If it's an opening bracket, push to stack
+///    - If it's a closing bracket:
+///      - If stack is empty or top doesn't match, return false
+///      - Otherwise, pop from stack
+/// 2. Return true if stack is empty (all brackets matched)
+fn is_balanced(s: &str) -> bool {
+    let mut stack = Stack::new();
+
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' => stack.push(c),
+
+            ')' => {
+                if stack.pop() != Some('(') {
+                    return false;
+                }
+            }
+            ']' => {
+                if stack.pop() != Some('[') {
+                    return false;
+                }
+            }
+            '}' => {
+                if stack.pop() != Some('{') {
+                    return false;
+                }
+            }
+            _ => {} // Ignore other characters
+        }
+    }
+
+    stack.is_empty()
+}
+
+/// Reverse a string using a stack (Bonus)
+fn reverse_with_stack(s: &str) -> String {
+    let mut stack = Stack::new();
+
+    // Push all characters
+    for c in s.chars() {
+        stack.push(c);
+    }
+
+    // Pop to get reversed order
+    let mut result = String::new();
+    while let Some(c) = stack.pop() {
+        result.push(c);
+    }
+
+    result
+}
+
+/// Evaluate postfix expression (Bonus)
+///
+/// Example: "3 4 + 2 *" = (3+4)*2 = 14
+fn evaluate_postfix(expr: &str) -> Option<i32> {
+    let mut stack: Stack<i32> = Stack::new();
+
+    for token in expr.split_whitespace() {
+        let op: fn(i32, i32) -> Option<i32> = match token {
+            "+" => i32::checked_add,
+            "-" => i32::checked_sub,
+            "*" => i32::checked_mul,
+            // checked_div also rejects division by zero
+            "/" => i32::checked_div,
+            num => {
+                stack.push(num.parse().ok()?);
+                continue;
+            }
+        };
+        let b = stack.pop()?;
+        let a = stack.pop()?;
+        stack.push(op(a, b)?);
+    }
+
+    // A well-formed expression leaves exactly one value behind
+    let result = stack.pop()?;
+    if stack.is_empty() {
+        Some(result)
+    } else {
+        None
+    }
+}
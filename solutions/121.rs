@@ -0,0 +1,186 @@
+// Reference solution for exercise 121: Queue Implementation
+
+fn main() {
+    println!("=== Queue Implementation ===\n");
+
+    let mut queue = Queue::new();
+
+    println!("Enqueuing: Alice, Bob, Charlie");
+    queue.enqueue("Alice");
+    queue.enqueue("Bob");
+    queue.enqueue("Charlie");
+
+    println!("Queue size: {}", queue.size());
+    println!("Front: {:?}", queue.peek());
+
+    println!("\nDequeuing:");
+    while let Some(person) = queue.dequeue() {
+        println!("  Dequeued: {}", person);
+    }
+
+    println!("\nIs empty? {}", queue.is_empty());
+
+    // Demonstrate practical use: BFS level order
+    println!("\n=== BFS Simulation ===");
+    simulate_bfs();
+
+    println!("\n=== Circular Queue ===");
+    let mut cq = CircularQueue::new(2);
+    cq.enqueue('x');
+    cq.enqueue('y');
+    println!("Full: {}, enqueue z accepted: {}", cq.is_full(), cq.enqueue('z'));
+    while let Some(c) = cq.dequeue() {
+        println!("  Dequeued: {}", c);
+    }
+    println!("Empty: {}", cq.is_empty());
+
+    println!("\n✓ Queue implementation completed successfully!");
+}
+
+/// Queue data structure
+///
+/// Uses VecDeque internally for efficient push/pop at both ends.
+/// The front of the queue is the front of the VecDeque.
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+struct Queue<T> {
+    items: VecDeque<T>,
+}
+
+impl<T> Queue<T> {
+    /// Create a new empty queue
+    fn new() -> Self {
+        Queue {
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Add an item to the rear of the queue
+    ///
+    /// Time Complexity: O(1)
+    fn enqueue(&mut self, item: T) {
+        self.items.push_back(item);
+    }
+
+    /// Remove and return the front item
+    ///
+    /// Returns None if the queue is empty.
+    /// Time Complexity: O(1)
+    fn dequeue(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// View the front item without removing it
+    ///
+    /// Returns None if the queue is empty.
+    /// Time Complexity: O(1)
+    fn peek(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Check if the queue is empty
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Get the size of the queue
+    fn size(&self) -> usize {
+        self.items.len()
+    }
+}
+
+/// Simulate BFS using a queue
+///
+/// This demonstrates a practical use case for queues.
+fn simulate_bfs() {
+    // We'll traverse a simple tree:
+    //       1
+    //      / \
+    //     2   3
+    //    / \
+    //   4   5
+
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        children: Vec<usize>, // Indices of children
+    }
+
+    let tree = vec![
+        Node { value: 1, children: vec![1, 2] },
+        Node { value: 2, children: vec![3, 4] },
+        Node { value: 3, children: vec![] },
+        Node { value: 4, children: vec![] },
+        Node { value: 5, children: vec![] },
+    ];
+
+    let mut queue = Queue::new();
+    queue.enqueue(0); // Start with root node index
+
+    println!("BFS traversal order:");
+    while let Some(node_idx) = queue.dequeue() {
+        let node = &tree[node_idx];
+        println!("  Node: {}", node.value);
+
+        // Enqueue children
+        for &child_idx in &node.children {
+            queue.enqueue(child_idx);
+        }
+    }
+}
+
+/// Circular Queue implementation (Bonus)
+///
+/// A fixed-size queue that reuses space.
+#[derive(Debug)]
+struct CircularQueue<T> {
+    buffer: Vec<Option<T>>,
+    front: usize,
+    rear: usize,
+    size: usize,
+    capacity: usize,
+}
+
+impl<T: Clone> CircularQueue<T> {
+    fn new(capacity: usize) -> Self {
+        CircularQueue {
+            buffer: vec![None; capacity],
+            front: 0,
+            rear: 0,
+            size: 0,
+            capacity,
+        }
+    }
+
+    fn enqueue(&mut self, item: T) -> bool {
+        if self.size == self.capacity {
+            return false; // Queue is full
+        }
+
+        self.buffer[self.rear] = Some(item);
+        self.rear = (self.rear + 1) % self.capacity;
+        self.size += 1;
+        true
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
+        }
+
+        let item = self.buffer[self.front].clone();
+        self.buffer[self.front] = None;
+        self.front = (self.front + 1) % self.capacity;
+        self.size -= 1;
+        item
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn is_full(&self) -> bool {
+        self.size == self.capacity
+    }
+}
@@ -0,0 +1,195 @@
+// Reference solution for exercise 124: Binary Tree - Height Calculation
+
+fn main() {
+    println!("=== Binary Tree Height ===\n");
+
+    // Test with different tree shapes
+    println!("Test 1: Empty tree");
+    let empty_tree: BinaryTree<i32> = BinaryTree::new();
+    println!("Height: {}", empty_tree.height());
+    assert_eq!(empty_tree.height(), 0);
+
+    println!("\nTest 2: Single node");
+    let mut single = BinaryTree::new();
+    single.insert(50);
+    println!("Height: {}", single.height());
+    assert_eq!(single.height(), 1);
+
+    println!("\nTest 3: Balanced tree");
+    //       50
+    //      /  \
+    //     30   70
+    //    / \   / \
+    //   20 40 60 80
+    let mut balanced = BinaryTree::new();
+    for &val in &[50, 30, 70, 20, 40, 60, 80] {
+        balanced.insert(val);
+    }
+    println!("Height: {}", balanced.height());
+    assert_eq!(balanced.height(), 3);
+
+    println!("\nTest 4: Skewed tree (left)");
+    //   50
+    //  /
+    // 40
+    // /
+    //30
+    let mut left_skewed = BinaryTree::new();
+    for &val in &[50, 40, 30, 20, 10] {
+        left_skewed.insert(val);
+    }
+    println!("Height: {}", left_skewed.height());
+    assert_eq!(left_skewed.height(), 5);
+
+    println!("\nTest 5: Calculate diameter");
+    println!("Diameter of balanced tree: {}", balanced.diameter());
+
+    println!("Balanced tree is balanced: {}", balanced.is_balanced());
+    println!("Skewed tree is balanced: {}", left_skewed.is_balanced());
+
+    println!("\n✓ Binary tree height calculation completed successfully!");
+}
+
+#[derive(Debug)]
+struct TreeNode<T> {
+    value: T,
+    left: Option<Box<TreeNode<T>>>,
+    right: Option<Box<TreeNode<T>>>,
+}
+
+impl<T: Ord> TreeNode<T> {
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn insert(&mut self, value: T) {
+        if value < self.value {
+            match self.left {
+                Some(ref mut left) => left.insert(value),
+                None => self.left = Some(Box::new(TreeNode::new(value))),
+            }
+        } else if value > self.value {
+            match self.right {
+                Some(ref mut right) => right.insert(value),
+                None => self.right = Some(Box::new(TreeNode::new(value))),
+            }
+        }
+    }
+
+    /// Calculate height of this node
+    ///
+    /// Height = 1 + max(height of left subtree, height of right subtree)
+    /// Base case: empty tree has height 0
+    fn height(&self) -> usize {
+        let left_height = self.left.as_ref().map_or(0, |n| n.height());
+        let right_height = self.right.as_ref().map_or(0, |n| n.height());
+
+        1 + left_height.max(right_height)
+    }
+
+    /// Calculate diameter and height together (Bonus)
+    ///
+    /// Diameter is the longest path between any two nodes in the tree.
+    /// It may or may not pass through the root.
+    fn diameter_and_height(&self) -> (usize, usize) {
+        // Returns (diameter, height)
+        let (left_dia, left_height) = self.left.as_ref()
+            .map_or((0, 0), |n| n.diameter_and_height());
+
+        let (right_dia, right_height) = self.right.as_ref()
+            .map_or((0, 0), |n| n.diameter_and_height());
+
+        // Height of current node
+        let height = 1 + left_height.max(right_height);
+
+        // Diameter passing through root
+        let through_root = left_height + right_height + 1;
+
+        // Maximum diameter
+        let diameter = through_root.max(left_dia.max(right_dia));
+
+        (diameter, height)
+    }
+}
+
+#[derive(Debug)]
+struct BinaryTree<T> {
+    root: Option<Box<TreeNode<T>>>,
+}
+
+impl<T: Ord> BinaryTree<T> {
+    fn new() -> Self {
+        BinaryTree { root: None }
+    }
+
+    fn insert(&mut self, value: T) {
+        match self.root {
+            Some(ref mut root) => root.insert(value),
+            None => self.root = Some(Box::new(TreeNode::new(value))),
+        }
+    }
+
+    /// Calculate height of the tree
+    ///
+    /// Height is the number of nodes on the longest path from root to leaf.
+    /// Empty tree has height 0.
+    /// Single node tree has height 1.
+    fn height(&self) -> usize {
+        match &self.root {
+            Some(root) => root.height(),
+            None => 0,
+        }
+    }
+
+    /// Check if the tree is balanced (Bonus)
+    ///
+    /// A tree is balanced if the height difference between left and right
+    /// subtrees is at most 1 for all nodes.
+    fn is_balanced(&self) -> bool {
+        // An empty tree is trivially balanced
+        self.root.is_none() || self.check_balanced().is_some()
+    }
+
+    fn check_balanced(&self) -> Option<usize> {
+        // Helper that returns height if balanced, None otherwise.
+        // An empty tree is balanced, with height 0.
+        match &self.root {
+            Some(root) => root.check_balanced_helper(),
+            None => Some(0),
+        }
+    }
+
+    /// Calculate diameter of the tree (Bonus)
+    fn diameter(&self) -> usize {
+        match &self.root {
+            Some(root) => root.diameter_and_height().0,
+            None => 0,
+        }
+    }
+}
+
+impl<T: Ord> TreeNode<T> {
+    fn check_balanced_helper(&self) -> Option<usize> {
+        // An unbalanced subtree poisons every ancestor, so propagate None
+        let left_height = match &self.left {
+            Some(n) => n.check_balanced_helper()?,
+            None => 0,
+        };
+
+        let right_height = match &self.right {
+            Some(n) => n.check_balanced_helper()?,
+            None => 0,
+        };
+
+        // Check if current node is balanced
+        if left_height.abs_diff(right_height) > 1 {
+            return None;
+        }
+
+        Some(1 + left_height.max(right_height))
+    }
+}
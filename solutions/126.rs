@@ -0,0 +1,68 @@
+// Reference solution for exercise 126: Merge Sort Algorithm
+
+/// This function should sort a vector of i32 using the merge sort algorithm
+///
+/// Algorithm:
+/// 1. If the array has 0 or 1 elements, it's already sorted (base case)
+/// 2. Divide the array into two halves
+/// 3. Recursively sort both halves
+/// 4. Merge the two sorted halves into a single sorted array
+fn merge_sort(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mid = arr.len() / 2;
+    merge_sort(&mut arr[..mid]);
+    merge_sort(&mut arr[mid..]);
+    merge(arr, mid);
+}
+
+/// Merges two sorted subarrays: arr[left..mid] and arr[mid..right]
+///
+/// Hint: Create temporary vectors to hold the left and right halves,
+/// then merge them back into the original array in sorted order
+fn merge(arr: &mut [i32], mid: usize) {
+    let left = arr[..mid].to_vec();
+    let right = arr[mid..].to_vec();
+
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    while i < left.len() && j < right.len() {
+        // Taking from the left on ties keeps the sort stable
+        if left[i] <= right[j] {
+            arr[k] = left[i];
+            i += 1;
+        } else {
+            arr[k] = right[j];
+            j += 1;
+        }
+        k += 1;
+    }
+
+    for &x in &left[i..] {
+        arr[k] = x;
+        k += 1;
+    }
+    for &x in &right[j..] {
+        arr[k] = x;
+        k += 1;
+    }
+}
+
+fn main() {
+    // Test cases
+    let mut arr1 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("Original array: {:?}", arr1);
+    merge_sort(&mut arr1);
+    println!("Sorted array: {:?}", arr1);
+
+    let mut arr2 = vec![3, -1, 0, 5, -2];
+    println!("\nOriginal array: {:?}", arr2);
+    merge_sort(&mut arr2);
+    println!("Sorted array: {:?}", arr2);
+
+    let mut arr3: Vec<i32> = vec![];
+    println!("\nOriginal array: {:?}", arr3);
+    merge_sort(&mut arr3);
+    println!("Sorted array: {:?}", arr3);
+}
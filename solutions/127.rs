@@ -0,0 +1,61 @@
+// Reference solution for exercise 127: Quick Sort Algorithm
+
+/// Sorts a slice of i32 in-place using the quick sort algorithm
+///
+/// Algorithm:
+/// 1. If the array has 0 or 1 elements, it's already sorted (base case)
+/// 2. Select a pivot element
+/// 3. Partition the array such that elements < pivot are on the left,
+///    elements > pivot are on the right
+/// 4. Recursively sort the left and right partitions
+fn quick_sort(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let pivot = partition(arr);
+    quick_sort(&mut arr[..pivot]);
+    quick_sort(&mut arr[pivot + 1..]);
+}
+
+///
+/// Rearranges elements in arr such that:
+/// - All elements less than pivot come before it
+/// - All elements greater than pivot come after it
+///
+/// Returns the final index of the pivot element
+///
+/// Hint: Use the last element as pivot (Lomuto partition scheme)
+fn partition(arr: &mut [i32]) -> usize {
+    let last = arr.len() - 1;
+    let pivot = arr[last];
+
+    let mut store = 0;
+    for j in 0..last {
+        if arr[j] <= pivot {
+            arr.swap(store, j);
+            store += 1;
+        }
+    }
+
+    arr.swap(store, last);
+    store
+}
+
+fn main() {
+    // Test cases
+    let mut arr1 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("Original array: {:?}", arr1);
+    quick_sort(&mut arr1);
+    println!("Sorted array: {:?}", arr1);
+
+    let mut arr2 = vec![3, -1, 0, 5, -2];
+    println!("\nOriginal array: {:?}", arr2);
+    quick_sort(&mut arr2);
+    println!("Sorted array: {:?}", arr2);
+
+    let mut arr3: Vec<i32> = vec![];
+    println!("\nOriginal array: {:?}", arr3);
+    quick_sort(&mut arr3);
+    println!("Sorted array: {:?}", arr3);
+}
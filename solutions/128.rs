@@ -0,0 +1,70 @@
+// Reference solution for exercise 128: Heap Sort Algorithm
+
+/// Sorts a vector of i32 using heap sort algorithm
+///
+/// Algorithm:
+/// 1. Build a max heap from the input data
+/// 2. At this point, the largest item is stored at the root
+/// 3. Replace the root with the last item, reduce heap size by 1, and heapify
+/// 4. Repeat step 3 while heap size is greater than 1
+fn heap_sort(arr: &mut [i32]) {
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // Build max heap, starting from the last non-leaf node
+    for i in (0..n / 2).rev() {
+        heapify(arr, n, i);
+    }
+
+    // Move the current root to the end, then restore the reduced heap
+    for end in (1..n).rev() {
+        arr.swap(0, end);
+        heapify(arr, end, 0);
+    }
+}
+
+///
+/// Maintains the max-heap property for a subtree rooted at index i
+/// Assumes the binary trees rooted at left(i) and right(i) are max-heaps
+///
+/// Parameters:
+/// - arr: the array representing the heap
+/// - n: size of the heap
+/// - i: index of the root to heapify
+fn heapify(arr: &mut [i32], n: usize, i: usize) {
+    let mut largest = i;
+    let left = 2 * i + 1;
+    let right = 2 * i + 2;
+
+    if left < n && arr[left] > arr[largest] {
+        largest = left;
+    }
+    if right < n && arr[right] > arr[largest] {
+        largest = right;
+    }
+
+    if largest != i {
+        arr.swap(i, largest);
+        heapify(arr, n, largest);
+    }
+}
+
+fn main() {
+    // Test cases
+    let mut arr1 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("Original array: {:?}", arr1);
+    heap_sort(&mut arr1);
+    println!("Sorted array: {:?}", arr1);
+
+    let mut arr2 = vec![3, -1, 0, 5, -2];
+    println!("\nOriginal array: {:?}", arr2);
+    heap_sort(&mut arr2);
+    println!("Sorted array: {:?}", arr2);
+
+    let mut arr3 = vec![5, 4, 3, 2, 1];
+    println!("\nOriginal array: {:?}", arr3);
+    heap_sort(&mut arr3);
+    println!("Sorted array: {:?}", arr3);
+}
@@ -0,0 +1,86 @@
+// Reference solution for exercise 129: Counting Sort Algorithm
+
+/// Sorts a vector of non-negative i32 using counting sort
+///
+/// Algorithm:
+/// 1. Find the maximum element to determine the count array size
+/// 2. Initialize a count array with zeros
+/// 3. Store the count of each element
+/// 4. Modify count array to store actual positions
+/// 5. Build the output array using the count array
+///
+/// Note: For this exercise, assume all input values are non-negative
+fn counting_sort(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    // Offsetting by the minimum also copes with negative values
+    let min = *arr.iter().min().unwrap();
+    let max = *arr.iter().max().unwrap();
+    let mut count = vec![0usize; (max - min) as usize + 1];
+
+    for &x in arr.iter() {
+        count[(x - min) as usize] += 1;
+    }
+
+    // Cumulative counts give each value's end position in the output
+    for i in 1..count.len() {
+        count[i] += count[i - 1];
+    }
+
+    let mut output = vec![0; arr.len()];
+    for &x in arr.iter().rev() {
+        let slot = &mut count[(x - min) as usize];
+        *slot -= 1;
+        output[*slot] = x;
+    }
+
+    arr.copy_from_slice(&output);
+}
+
+/// Alternative: In-place counting sort that modifies the original array directly
+/// This version modifies the array in-place without extra output array
+fn counting_sort_in_place(arr: &mut [i32]) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let min = *arr.iter().min().unwrap();
+    let max = *arr.iter().max().unwrap();
+    let mut count = vec![0usize; (max - min) as usize + 1];
+    for &x in arr.iter() {
+        count[(x - min) as usize] += 1;
+    }
+
+    let mut k = 0;
+    for (offset, &times) in count.iter().enumerate() {
+        for _ in 0..times {
+            arr[k] = min + offset as i32;
+            k += 1;
+        }
+    }
+}
+
+fn main() {
+    // Test cases
+    let mut arr1 = vec![4, 2, 2, 8, 3, 3, 1];
+    println!("Original array: {:?}", arr1);
+    counting_sort(&mut arr1);
+    println!("Sorted array: {:?}", arr1);
+
+    let mut arr2 = vec![1, 4, 1, 2, 7, 5, 2];
+    println!("\nOriginal array: {:?}", arr2);
+    counting_sort(&mut arr2);
+    println!("Sorted array: {:?}", arr2);
+
+    let mut arr3 = vec![5, 5, 5, 1, 1, 1, 3];
+    println!("\nOriginal array: {:?}", arr3);
+    counting_sort(&mut arr3);
+    println!("Sorted array: {:?}", arr3);
+
+    let mut arr4 = vec![9, 0, 6, 0, 3];
+    println!("\nOriginal array: {:?}", arr4);
+    counting_sort_in_place(&mut arr4);
+    println!("Sorted in place: {:?}", arr4);
+}
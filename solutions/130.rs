@@ -0,0 +1,115 @@
+// Reference solution for exercise 130: Depth First Search (DFS) on Graph
+
+use std::collections::{HashSet, VecDeque};
+
+/// Graph represented using adjacency list
+#[derive(Debug, Clone)]
+struct Graph {
+    /// Number of vertices
+    vertices: usize,
+    /// Adjacency list: graph[vertex] = list of neighbors
+    adj: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Creates a new graph with given number of vertices
+    fn new(vertices: usize) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+        }
+    }
+
+    /// Adds an undirected edge between u and v
+    fn add_edge(&mut self, u: usize, v: usize) {
+        if u >= self.vertices || v >= self.vertices {
+            return;
+        }
+        self.adj[u].push(v);
+        self.adj[v].push(u);
+    }
+
+    /// Implement recursive DFS
+    /// Performs DFS starting from the given vertex
+    /// Returns a vector containing vertices in DFS order
+    fn dfs_recursive(&self, start: usize) -> Vec<usize> {
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+
+        if start < self.vertices {
+            self.dfs_helper(start, &mut visited, &mut result);
+        }
+
+        result
+    }
+
+    /// Helper function for recursive DFS
+    fn dfs_helper(&self, vertex: usize, visited: &mut HashSet<usize>, result: &mut Vec<usize>) {
+        visited.insert(vertex);
+        result.push(vertex);
+
+        for &next in &self.adj[vertex] {
+            if !visited.contains(&next) {
+                self.dfs_helper(next, visited, result);
+            }
+        }
+    }
+
+    /// Implement iterative DFS using an explicit stack
+    /// Performs DFS starting from the given vertex using a stack
+    fn dfs_iterative(&self, start: usize) -> Vec<usize> {
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+        let mut stack = VecDeque::new();
+
+        if start < self.vertices {
+            stack.push_back(start);
+        }
+
+        while let Some(vertex) = stack.pop_back() {
+            // A vertex can be pushed more than once before it is visited
+            if !visited.insert(vertex) {
+                continue;
+            }
+            result.push(vertex);
+
+            // Push in reverse so neighbors are visited in the same order
+            // as the recursive version
+            for &next in self.adj[vertex].iter().rev() {
+                if !visited.contains(&next) {
+                    stack.push_back(next);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+fn main() {
+    // Create a sample graph
+    //      0
+    //     / \
+    //    1   2
+    //   /   / \
+    //  3   4   5
+    let mut graph = Graph::new(6);
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 3);
+    graph.add_edge(2, 4);
+    graph.add_edge(2, 5);
+
+    println!("Graph adjacency list: {:?}", graph.adj);
+
+    println!("\nDFS Recursive from vertex 0: {:?}", graph.dfs_recursive(0));
+    println!("DFS Iterative from vertex 0: {:?}", graph.dfs_iterative(0));
+
+    // Test with disconnected graph
+    let mut graph2 = Graph::new(5);
+    graph2.add_edge(0, 1);
+    graph2.add_edge(2, 3); // Disconnected component
+
+    println!("\nDisconnected graph - DFS from 0: {:?}", graph2.dfs_recursive(0));
+    println!("Disconnected graph - DFS from 2: {:?}", graph2.dfs_recursive(2));
+}
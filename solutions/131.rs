@@ -0,0 +1,135 @@
+// Reference solution for exercise 131: Breadth First Search (BFS) on Graph
+
+use std::collections::{HashSet, VecDeque};
+
+/// Graph represented using adjacency list
+#[derive(Debug, Clone)]
+struct Graph {
+    vertices: usize,
+    adj: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Creates a new graph with given number of vertices
+    fn new(vertices: usize) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+        }
+    }
+
+    /// Adds an undirected edge between u and v
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.adj[u].push(v);
+        self.adj[v].push(u);
+    }
+
+    /// Implement BFS traversal
+    /// Performs BFS starting from the given vertex
+    /// Returns a vector containing vertices in BFS order
+    fn bfs(&self, start: usize) -> Vec<usize> {
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(start);
+        queue.push_back(start);
+
+        while let Some(vertex) = queue.pop_front() {
+            result.push(vertex);
+            for &next in &self.adj[vertex] {
+                if visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Implement BFS that returns distances from start
+    /// Returns a vector where distances[i] is the shortest distance
+    /// from start to vertex i (usize::MAX if unreachable)
+    fn bfs_with_distance(&self, start: usize) -> Vec<usize> {
+        let mut distances = vec![usize::MAX; self.vertices];
+        let mut queue = VecDeque::new();
+
+        distances[start] = 0;
+        queue.push_back(start);
+
+        while let Some(vertex) = queue.pop_front() {
+            for &next in &self.adj[vertex] {
+                if distances[next] == usize::MAX {
+                    distances[next] = distances[vertex] + 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Find shortest path between two vertices using BFS
+    /// Returns the path as a vector of vertices, or None if no path exists
+    fn shortest_path(&self, start: usize, end: usize) -> Option<Vec<usize>> {
+        let mut parent: Vec<Option<usize>> = vec![None; self.vertices];
+        let mut visited = vec![false; self.vertices];
+        let mut queue = VecDeque::new();
+
+        visited[start] = true;
+        queue.push_back(start);
+
+        while let Some(vertex) = queue.pop_front() {
+            if vertex == end {
+                break;
+            }
+            for &next in &self.adj[vertex] {
+                if !visited[next] {
+                    visited[next] = true;
+                    parent[next] = Some(vertex);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if !visited[end] {
+            return None;
+        }
+
+        // Walk the parent links back from end, then reverse
+        let mut path = vec![end];
+        let mut current = end;
+        while let Some(p) = parent[current] {
+            path.push(p);
+            current = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+fn main() {
+    // Create a sample graph
+    //      0
+    //     / \
+    //    1   2
+    //   /   / \
+    //  3   4   5
+    let mut graph = Graph::new(6);
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 3);
+    graph.add_edge(2, 4);
+    graph.add_edge(2, 5);
+
+    println!("Graph adjacency list: {:?}", graph.adj);
+
+    println!("\nBFS from vertex 0: {:?}", graph.bfs(0));
+
+    let distances = graph.bfs_with_distance(0);
+    println!("\nDistances from vertex 0: {:?}", distances);
+
+    if let Some(path) = graph.shortest_path(3, 5) {
+        println!("\nShortest path from 3 to 5: {:?}", path);
+    }
+}
@@ -0,0 +1,407 @@
+// Reference solution for exercise 132: Dijkstra's Algorithm (Shortest Path)
+
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+/// Represents a weighted edge in the graph
+#[derive(Debug, Clone, Copy)]
+struct Edge<W = u32> {
+    to: usize,
+    weight: W,
+}
+
+/// Graph represented using adjacency list
+/// Weights are u32 unless said otherwise; Bellman-Ford takes a Graph<i64>
+#[derive(Debug, Clone)]
+struct Graph<W = u32> {
+    vertices: usize,
+    adj: Vec<Vec<Edge<W>>>,
+}
+
+impl<W: Copy> Graph<W> {
+    fn new(vertices: usize) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+        }
+    }
+
+    /// Adds a directed edge from u to v with given weight
+    fn add_edge(&mut self, u: usize, v: usize, weight: W) {
+        self.adj[u].push(Edge { to: v, weight });
+    }
+
+    /// Adds an undirected edge between u and v
+    fn add_undirected_edge(&mut self, u: usize, v: usize, weight: W) {
+        self.add_edge(u, v, weight);
+        self.add_edge(v, u, weight);
+    }
+
+    /// The same graph with every edge pointing the other way
+    fn reversed(&self) -> Graph<W> {
+        let mut reversed = Graph::new(self.vertices);
+        for (u, edges) in self.adj.iter().enumerate() {
+            for edge in edges {
+                reversed.add_edge(edge.to, u, edge.weight);
+            }
+        }
+        reversed
+    }
+}
+
+/// State for priority queue: (distance, vertex)
+/// Implements Ord so that BinaryHeap becomes a min-heap
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct State {
+    distance: u32,
+    vertex: usize,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse the ordering for min-heap; break ties on the vertex so
+        // the ordering agrees with the derived Eq
+        other
+            .distance
+            .cmp(&self.distance)
+            .then_with(|| self.vertex.cmp(&other.vertex))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implement Dijkstra's algorithm
+/// Returns the shortest distances from start to all vertices
+/// Unreachable vertices have distance u32::MAX
+fn dijkstra(graph: &Graph, start: usize) -> Vec<u32> {
+    dijkstra_with_path(graph, start).0
+}
+
+/// Implement Dijkstra's algorithm with path reconstruction
+/// Returns (distances, parents) where parents can be used to reconstruct paths
+fn dijkstra_with_path(graph: &Graph, start: usize) -> (Vec<u32>, Vec<Option<usize>>) {
+    let mut distances = vec![u32::MAX; graph.vertices];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.vertices];
+    let mut heap = BinaryHeap::new();
+
+    distances[start] = 0;
+    heap.push(State { distance: 0, vertex: start });
+
+    while let Some(State { distance, vertex }) = heap.pop() {
+        // Stale entry: a shorter path to this vertex was already settled
+        if distance > distances[vertex] {
+            continue;
+        }
+
+        for edge in &graph.adj[vertex] {
+            let candidate = distance.saturating_add(edge.weight);
+            if candidate < distances[edge.to] {
+                distances[edge.to] = candidate;
+                parents[edge.to] = Some(vertex);
+                heap.push(State { distance: candidate, vertex: edge.to });
+            }
+        }
+    }
+
+    (distances, parents)
+}
+
+/// Reconstructs path from start to end using parent array
+fn reconstruct_path(parents: &[Option<usize>], start: usize, end: usize) -> Option<Vec<usize>> {
+    let mut path = vec![end];
+    let mut current = end;
+
+    while current != start {
+        current = parents[current]?;
+        path.push(current);
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+// Stage 2: Bellman-Ford, for graphs with negative edges
+
+/// A cycle of negative total weight reachable from the start, listed in
+/// edge order: cycle[i] -> cycle[i + 1], and the last vertex back to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NegativeCycle {
+    cycle: Vec<usize>,
+}
+
+/// Implement the Bellman-Ford algorithm
+/// Returns the shortest distances from start (None where unreachable), or
+/// the negative cycle that leaves some of them without a shortest path
+fn bellman_ford(graph: &Graph<i64>, start: usize) -> Result<Vec<Option<i64>>, NegativeCycle> {
+    let mut distances: Vec<Option<i64>> = vec![None; graph.vertices];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.vertices];
+    distances[start] = Some(0);
+
+    // V - 1 rounds settle every shortest path; an edge that still relaxes
+    // in round V lies behind a negative cycle
+    for round in 1..=graph.vertices {
+        let mut relaxed = None;
+        for u in 0..graph.vertices {
+            let Some(distance) = distances[u] else {
+                continue;
+            };
+            for edge in &graph.adj[u] {
+                let candidate = distance.saturating_add(edge.weight);
+                if distances[edge.to].is_none_or(|known| candidate < known) {
+                    distances[edge.to] = Some(candidate);
+                    parents[edge.to] = Some(u);
+                    relaxed = Some(edge.to);
+                }
+            }
+        }
+        match relaxed {
+            None => break,
+            Some(vertex) if round == graph.vertices => {
+                return Err(NegativeCycle { cycle: cycle_behind(&parents, vertex) });
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(distances)
+}
+
+/// The cycle that following parents back from vertex runs into
+fn cycle_behind(parents: &[Option<usize>], vertex: usize) -> Vec<usize> {
+    // Within V steps back the walk is on the cycle
+    let mut on_cycle = vertex;
+    for _ in 0..parents.len() {
+        if let Some(parent) = parents[on_cycle] {
+            on_cycle = parent;
+        }
+    }
+
+    let mut cycle = vec![on_cycle];
+    let mut current = on_cycle;
+    while let Some(parent) = parents[current] {
+        if parent == on_cycle {
+            break;
+        }
+        cycle.push(parent);
+        current = parent;
+    }
+    cycle.reverse();
+    cycle
+}
+
+// Stage 3: A* on a grid map
+
+/// A map read from text, one row per line: '.' is open ground costing 1
+/// to enter, '1' to '9' rough ground costing that much, '#' a wall, and
+/// 'S' and 'G' the start and the goal on open ground
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Grid {
+    width: usize,
+    height: usize,
+    /// Cost of entering each cell, row by row; None for walls
+    cells: Vec<Option<u32>>,
+    start: usize,
+    goal: usize,
+}
+
+impl Grid {
+    /// Parses a map; every row must be as wide as the first
+    fn parse(text: &str) -> Result<Grid, String> {
+        let rows: Vec<&str> = text.lines().map(str::trim).filter(|row| !row.is_empty()).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let (mut start, mut goal) = (None, None);
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for (r, row) in rows.iter().enumerate() {
+            if row.chars().count() != width {
+                return Err(format!("row {} is not {width} cells wide", r + 1));
+            }
+            for c in row.chars() {
+                cells.push(match c {
+                    '.' => Some(1),
+                    '#' => None,
+                    'S' | 'G' => {
+                        let marker = if c == 'S' { &mut start } else { &mut goal };
+                        if marker.replace(cells.len()).is_some() {
+                            return Err(format!("more than one '{c}'"));
+                        }
+                        Some(1)
+                    }
+                    '1'..='9' => c.to_digit(10),
+                    _ => return Err(format!("unknown cell '{c}' in row {}", r + 1)),
+                });
+            }
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+            start: start.ok_or("no start 'S'")?,
+            goal: goal.ok_or("no goal 'G'")?,
+        })
+    }
+
+    /// The map as a graph: every open cell has an edge to each open cell
+    /// above, below, left and right of it, weighted with that cell's cost
+    fn to_graph(&self) -> Graph {
+        let mut graph = Graph::new(self.cells.len());
+        for v in 0..self.cells.len() {
+            if self.cells[v].is_none() {
+                continue;
+            }
+            let (row, col) = (v / self.width, v % self.width);
+            let mut neighbours = Vec::new();
+            if row > 0 {
+                neighbours.push(v - self.width);
+            }
+            if row + 1 < self.height {
+                neighbours.push(v + self.width);
+            }
+            if col > 0 {
+                neighbours.push(v - 1);
+            }
+            if col + 1 < self.width {
+                neighbours.push(v + 1);
+            }
+            for n in neighbours {
+                if let Some(cost) = self.cells[n] {
+                    graph.add_edge(v, n, cost);
+                }
+            }
+        }
+        graph
+    }
+
+    /// Implement the Manhattan distance between two cells
+    /// Every step costs at least 1, so it never overestimates
+    fn manhattan(&self, from: usize, to: usize) -> u32 {
+        let (from_row, from_col) = (from / self.width, from % self.width);
+        let (to_row, to_col) = (to / self.width, to % self.width);
+        (from_row.abs_diff(to_row) + from_col.abs_diff(to_col)) as u32
+    }
+}
+
+/// Implement A* search
+/// Returns the distance and a shortest path from start to goal, or None
+/// if goal is unreachable. heuristic(v) estimates the distance from v to
+/// goal and must never overestimate it
+fn astar(
+    graph: &Graph,
+    start: usize,
+    goal: usize,
+    heuristic: impl Fn(usize) -> u32,
+) -> Option<(u32, Vec<usize>)> {
+    let mut distances = vec![u32::MAX; graph.vertices];
+    let mut parents: Vec<Option<usize>> = vec![None; graph.vertices];
+    let mut heap = BinaryHeap::new();
+
+    // The heap is ordered by distance so far plus the estimate of the rest
+    distances[start] = 0;
+    heap.push(State { distance: heuristic(start), vertex: start });
+
+    while let Some(State { distance: estimate, vertex }) = heap.pop() {
+        // Stale entry: the vertex has been reached more cheaply since
+        if estimate > distances[vertex].saturating_add(heuristic(vertex)) {
+            continue;
+        }
+        if vertex == goal {
+            return Some((distances[goal], reconstruct_path(&parents, start, goal)?));
+        }
+
+        for edge in &graph.adj[vertex] {
+            let candidate = distances[vertex].saturating_add(edge.weight);
+            if candidate < distances[edge.to] {
+                distances[edge.to] = candidate;
+                parents[edge.to] = Some(vertex);
+                heap.push(State {
+                    distance: candidate.saturating_add(heuristic(edge.to)),
+                    vertex: edge.to,
+                });
+            }
+        }
+    }
+    None
+}
+
+// Stage 4: Bidirectional Dijkstra
+
+/// Implement bidirectional Dijkstra
+/// Returns the shortest distance from start to goal, or None if goal is
+/// unreachable
+fn bidirectional_dijkstra(graph: &Graph, start: usize, goal: usize) -> Option<u32> {
+    if start == goal {
+        return Some(0);
+    }
+    // Side 0 searches forward from start, side 1 backward from goal
+    let reversed = graph.reversed();
+    let graphs = [graph, &reversed];
+    let mut distances = [vec![u32::MAX; graph.vertices], vec![u32::MAX; graph.vertices]];
+    let mut heaps = [BinaryHeap::new(), BinaryHeap::new()];
+    distances[0][start] = 0;
+    distances[1][goal] = 0;
+    heaps[0].push(State { distance: 0, vertex: start });
+    heaps[1].push(State { distance: 0, vertex: goal });
+
+    // Shortest start -> goal path seen so far, in u64 so two halves fit
+    let mut best = u64::MAX;
+    loop {
+        let front = |heap: &BinaryHeap<State>| heap.peek().map(|s| u64::from(s.distance));
+        let side = match (front(&heaps[0]), front(&heaps[1])) {
+            (None, None) => break,
+            // No path through a vertex either side has yet to settle can be shorter
+            (Some(f), Some(b)) if f + b >= best => break,
+            (Some(f), Some(b)) => usize::from(b < f),
+            (Some(_), None) => 0,
+            (None, Some(_)) => 1,
+        };
+        let Some(State { distance, vertex }) = heaps[side].pop() else {
+            break;
+        };
+        if distance > distances[side][vertex] {
+            continue;
+        }
+
+        for edge in &graphs[side].adj[vertex] {
+            let candidate = distance.saturating_add(edge.weight);
+            if candidate < distances[side][edge.to] {
+                distances[side][edge.to] = candidate;
+                heaps[side].push(State { distance: candidate, vertex: edge.to });
+            }
+            let other = distances[1 - side][edge.to];
+            if other != u32::MAX {
+                best = best.min(u64::from(candidate) + u64::from(other));
+            }
+        }
+    }
+    u32::try_from(best).ok().filter(|&d| d != u32::MAX)
+}
+
+fn main() {
+    // Create a sample weighted graph
+    //       4
+    //   0 ---- 1
+    //   |      |
+    // 1 |      | 2
+    //   |      |
+    //   2 ---- 3
+    //       1
+    let mut graph = Graph::new(4);
+    graph.add_undirected_edge(0, 1, 4);
+    graph.add_undirected_edge(0, 2, 1);
+    graph.add_undirected_edge(1, 3, 2);
+    graph.add_undirected_edge(2, 3, 1);
+
+    println!("Graph edges: {:?}", graph.adj);
+
+    let distances = dijkstra(&graph, 0);
+    println!("\nShortest distances from vertex 0: {:?}", distances);
+
+    let (distances, parents) = dijkstra_with_path(&graph, 0);
+    println!("Distances: {:?}", distances);
+
+    if let Some(path) = reconstruct_path(&parents, 0, 3) {
+        println!("Shortest path from 0 to 3: {:?}", path);
+    }
+}
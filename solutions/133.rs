@@ -0,0 +1,126 @@
+// Reference solution for exercise 133: 0/1 Knapsack Problem
+
+/// Represents an item with weight and value
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    weight: usize,
+    value: usize,
+}
+
+impl Item {
+    fn new(weight: usize, value: usize) -> Self {
+        Item { weight, value }
+    }
+}
+
+/// Implement 0/1 Knapsack using dynamic programming (2D table)
+/// Returns the maximum value that can be obtained
+///
+/// dp[i][w] = maximum value using first i items with capacity w
+fn knapsack_01(items: &[Item], capacity: usize) -> usize {
+    knapsack_table(items, capacity)[items.len()][capacity]
+}
+
+/// Implement space-optimized 0/1 Knapsack (1D array)
+/// Uses only O(W) space instead of O(n * W)
+fn knapsack_01_optimized(items: &[Item], capacity: usize) -> usize {
+    let mut dp = vec![0; capacity + 1];
+
+    for item in items {
+        // Walking capacities downwards means dp[w - weight] still holds the
+        // value from before this item, so each item is used at most once
+        for w in (item.weight..=capacity).rev() {
+            dp[w] = dp[w].max(dp[w - item.weight] + item.value);
+        }
+    }
+
+    dp[capacity]
+}
+
+/// Implement knapsack that also returns which items were selected
+/// Returns (max_value, selected_items_indices)
+fn knapsack_with_selection(items: &[Item], capacity: usize) -> (usize, Vec<usize>) {
+    let n = items.len();
+    let dp = knapsack_table(items, capacity);
+
+    let mut selected = Vec::new();
+    let mut w = capacity;
+    for i in (1..=n).rev() {
+        if dp[i][w] != dp[i - 1][w] {
+            selected.push(i - 1);
+            w -= items[i - 1].weight;
+        }
+    }
+    selected.reverse();
+
+    (dp[n][capacity], selected)
+}
+
+/// Builds the full table: dp[i][w] = max value using the first i items
+/// with capacity w
+fn knapsack_table(items: &[Item], capacity: usize) -> Vec<Vec<usize>> {
+    let n = items.len();
+    let mut dp = vec![vec![0; capacity + 1]; n + 1];
+
+    for i in 1..=n {
+        let item = items[i - 1];
+        for w in 0..=capacity {
+            dp[i][w] = dp[i - 1][w];
+            if item.weight <= w {
+                dp[i][w] = dp[i][w].max(dp[i - 1][w - item.weight] + item.value);
+            }
+        }
+    }
+
+    dp
+}
+
+/// Recursive approach with memoization (top-down)
+fn knapsack_recursive(items: &[Item], capacity: usize) -> usize {
+    fn best(items: &[Item], i: usize, remaining: usize, memo: &mut [Vec<Option<usize>>]) -> usize {
+        if i == items.len() {
+            return 0;
+        }
+        if let Some(known) = memo[i][remaining] {
+            return known;
+        }
+
+        let skip = best(items, i + 1, remaining, memo);
+        let take = if items[i].weight <= remaining {
+            items[i].value + best(items, i + 1, remaining - items[i].weight, memo)
+        } else {
+            0
+        };
+
+        let result = skip.max(take);
+        memo[i][remaining] = Some(result);
+        result
+    }
+
+    let mut memo = vec![vec![None; capacity + 1]; items.len()];
+    best(items, 0, capacity, &mut memo)
+}
+
+fn main() {
+    let items = vec![
+        Item::new(2, 3),
+        Item::new(3, 4),
+        Item::new(4, 5),
+        Item::new(5, 6),
+    ];
+    let capacity = 5;
+
+    println!("Items: {:?}", items);
+    println!("Capacity: {}", capacity);
+
+    let max_value = knapsack_01(&items, capacity);
+    println!("\nMax value (2D DP): {}", max_value);
+
+    let max_value_opt = knapsack_01_optimized(&items, capacity);
+    println!("Max value (optimized): {}", max_value_opt);
+
+    let (value, selected) = knapsack_with_selection(&items, capacity);
+    println!("Selected items: {:?} with total value: {}", selected, value);
+
+    println!("Max value (memoized recursion): {}", knapsack_recursive(&items, capacity));
+}
@@ -0,0 +1,161 @@
+// Reference solution for exercise 134: Longest Common Subsequence (LCS)
+
+/// Find the length of the longest common subsequence
+/// Returns just the length (not the sequence itself)
+///
+/// Algorithm:
+/// Create a DP table where dp[i][j] = LCS length of s1[0..i] and s2[0..j]
+/// If s1[i-1] == s2[j-1]: dp[i][j] = dp[i-1][j-1] + 1
+/// Else: dp[i][j] = max(dp[i-1][j], dp[i][j-1])
+fn lcs_length(s1: &str, s2: &str) -> usize {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    lcs_table(&a, &b)[a.len()][b.len()]
+}
+
+/// Find and return the actual longest common subsequence string
+fn lcs(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let dp = lcs_table(&a, &b);
+
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut result = Vec::new();
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if dp[i - 1][j] >= dp[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    result.iter().rev().collect()
+}
+
+/// Space-optimized version that uses O(min(m,n)) space
+/// Returns only the length
+fn lcs_length_optimized(s1: &str, s2: &str) -> usize {
+    let (mut long, mut short): (Vec<char>, Vec<char>) = (s1.chars().collect(), s2.chars().collect());
+    if short.len() > long.len() {
+        std::mem::swap(&mut long, &mut short);
+    }
+
+    let mut prev = vec![0; short.len() + 1];
+    let mut curr = vec![0; short.len() + 1];
+    for &x in &long {
+        for (j, &y) in short.iter().enumerate() {
+            curr[j + 1] = if x == y {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(curr[j])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[short.len()]
+}
+
+/// Find all longest common subsequences
+/// Returns a set of all LCS strings (there may be multiple)
+fn all_lcs(s1: &str, s2: &str) -> Vec<String> {
+    use std::collections::{BTreeSet, HashMap};
+
+    fn collect(
+        a: &[char],
+        b: &[char],
+        dp: &[Vec<usize>],
+        i: usize,
+        j: usize,
+        memo: &mut HashMap<(usize, usize), BTreeSet<String>>,
+    ) -> BTreeSet<String> {
+        if i == 0 || j == 0 {
+            return BTreeSet::from([String::new()]);
+        }
+        if let Some(known) = memo.get(&(i, j)) {
+            return known.clone();
+        }
+
+        let mut found = BTreeSet::new();
+        if a[i - 1] == b[j - 1] {
+            for mut s in collect(a, b, dp, i - 1, j - 1, memo) {
+                s.push(a[i - 1]);
+                found.insert(s);
+            }
+        } else {
+            // Follow every direction that keeps the optimal length
+            if dp[i - 1][j] == dp[i][j] {
+                found.extend(collect(a, b, dp, i - 1, j, memo));
+            }
+            if dp[i][j - 1] == dp[i][j] {
+                found.extend(collect(a, b, dp, i, j - 1, memo));
+            }
+        }
+
+        memo.insert((i, j), found.clone());
+        found
+    }
+
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let dp = lcs_table(&a, &b);
+    if dp[a.len()][b.len()] == 0 {
+        return vec![];
+    }
+
+    collect(&a, &b, &dp, a.len(), b.len(), &mut HashMap::new())
+        .into_iter()
+        .collect()
+}
+
+/// dp[i][j] = LCS length of a[..i] and b[..j]
+fn lcs_table(a: &[char], b: &[char]) -> Vec<Vec<usize>> {
+    let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1] + 1
+            } else {
+                dp[i - 1][j].max(dp[i][j - 1])
+            };
+        }
+    }
+
+    dp
+}
+
+fn main() {
+    let s1 = "ABCDGH";
+    let s2 = "AEDFHR";
+
+    println!("String 1: {}", s1);
+    println!("String 2: {}", s2);
+
+    let length = lcs_length(s1, s2);
+    println!("\nLCS Length: {}", length);
+
+    let sequence = lcs(s1, s2);
+    println!("LCS: {}", sequence);
+
+    // More examples
+    let test_cases = vec![
+        ("AGGTAB", "GXTXAYB"),
+        ("ABC", "AC"),
+        ("XMJYAUZ", "MZJAWXU"),
+    ];
+
+    println!("\n--- Additional Test Cases ---");
+    for (a, b) in test_cases {
+        println!("\n'{a}' vs '{b}'");
+        println!("  LCS Length: {}", lcs_length(a, b));
+        println!("  LCS: '{}'", lcs(a, b));
+    }
+
+    println!("\nOptimized length for ABCBDAB/BDCABA: {}", lcs_length_optimized("ABCBDAB", "BDCABA"));
+    println!("All LCS of ABCBDAB/BDCABA: {:?}", all_lcs("ABCBDAB", "BDCABA"));
+}
@@ -0,0 +1,159 @@
+// Reference solution for exercise 135: Levenshtein Distance (Edit Distance)
+
+/// Calculate Levenshtein distance between two strings
+/// Returns the minimum number of edits (insert, delete, replace) needed
+///
+/// Algorithm (Wagner-Fischer):
+/// dp[i][j] = edit distance between s1[0..i] and s2[0..j]
+/// dp[0][j] = j (insert j characters)
+/// dp[i][0] = i (delete i characters)
+/// If s1[i-1] == s2[j-1]: dp[i][j] = dp[i-1][j-1] (no edit needed)
+/// Else: dp[i][j] = 1 + min(dp[i-1][j],    // delete
+///                          dp[i][j-1],    // insert
+///                          dp[i-1][j-1])  // replace
+fn levenshtein_distance(s1: &str, s2: &str) -> usize {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    edit_table(&a, &b)[a.len()][b.len()]
+}
+
+/// Space-optimized version using O(min(m,n)) space
+fn levenshtein_distance_optimized(s1: &str, s2: &str) -> usize {
+    let (mut long, mut short): (Vec<char>, Vec<char>) = (s1.chars().collect(), s2.chars().collect());
+    if short.len() > long.len() {
+        std::mem::swap(&mut long, &mut short);
+    }
+
+    let mut prev: Vec<usize> = (0..=short.len()).collect();
+    let mut curr = vec![0; short.len() + 1];
+    for (i, &x) in long.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &y) in short.iter().enumerate() {
+            curr[j + 1] = if x == y {
+                prev[j]
+            } else {
+                1 + prev[j].min(prev[j + 1]).min(curr[j])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[short.len()]
+}
+
+/// Calculate distance and return the sequence of operations
+/// Returns (distance, operations) where operations is a Vec of strings
+/// describing each edit operation
+fn levenshtein_with_operations(s1: &str, s2: &str) -> (usize, Vec<String>) {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let dp = edit_table(&a, &b);
+
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut ops = Vec::new();
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] {
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && dp[i][j] == dp[i - 1][j - 1] + 1 {
+            ops.push(format!("Replace '{}' with '{}' at position {}", a[i - 1], b[j - 1], i - 1));
+            i -= 1;
+            j -= 1;
+        } else if j > 0 && dp[i][j] == dp[i][j - 1] + 1 {
+            ops.push(format!("Insert '{}' at position {}", b[j - 1], i));
+            j -= 1;
+        } else {
+            ops.push(format!("Delete '{}' at position {}", a[i - 1], i - 1));
+            i -= 1;
+        }
+    }
+    ops.reverse();
+
+    (dp[a.len()][b.len()], ops)
+}
+
+/// Calculate Damerau-Levenshtein distance
+/// Same as Levenshtein but also allows transposition of adjacent characters
+/// This allows "AB" -> "BA" in one operation instead of two
+fn damerau_levenshtein(s1: &str, s2: &str) -> usize {
+    // Optimal string alignment: no substring is edited more than once
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let mut dp = edit_table(&a, &b);
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            dp[i][j] = (dp[i - 1][j] + 1)
+                .min(dp[i][j - 1] + 1)
+                .min(dp[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                dp[i][j] = dp[i][j].min(dp[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    dp[a.len()][b.len()]
+}
+
+/// dp[i][j] = edit distance between a[..i] and b[..j]
+fn edit_table(a: &[char], b: &[char]) -> Vec<Vec<usize>> {
+    let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    dp[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1]
+            } else {
+                1 + dp[i - 1][j].min(dp[i][j - 1]).min(dp[i - 1][j - 1])
+            };
+        }
+    }
+
+    dp
+}
+
+/// Calculate similarity ratio (0.0 to 1.0) based on edit distance
+fn similarity(s1: &str, s2: &str) -> f64 {
+    let max_len = s1.chars().count().max(s2.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    let distance = levenshtein_distance(s1, s2);
+    1.0 - (distance as f64 / max_len as f64)
+}
+
+fn main() {
+    let test_cases = vec![
+        ("kitten", "sitting"),
+        ("saturday", "sunday"),
+        ("intention", "execution"),
+        ("", "abc"),
+        ("same", "same"),
+    ];
+
+    println!("Levenshtein Distance Examples:\n");
+
+    for (s1, s2) in test_cases {
+        let dist = levenshtein_distance(s1, s2);
+        let sim = similarity(s1, s2);
+        println!("'{}' -> '{}'", s1, s2);
+        println!("  Distance: {}", dist);
+        println!("  Similarity: {:.2}%\n", sim * 100.0);
+    }
+
+    // Show operations
+    let (dist, ops) = levenshtein_with_operations("kitten", "sitting");
+    println!("Operations to transform 'kitten' to 'sitting':");
+    for op in ops {
+        println!("  - {}", op);
+    }
+    println!("  Total: {} operations", dist);
+
+    println!("\nOptimized distance: {}", levenshtein_distance_optimized("kitten", "sitting"));
+    println!("Damerau-Levenshtein 'ca' -> 'ac': {}", damerau_levenshtein("ca", "ac"));
+}
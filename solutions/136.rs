@@ -0,0 +1,134 @@
+// Reference solution for exercise 136: Two Sum Problem
+
+use std::collections::HashMap;
+
+/// Find two indices whose values sum to target
+/// Returns Some((i, j)) where nums[i] + nums[j] == target and i < j
+/// Returns None if no such pair exists
+///
+/// Approach: Hash Map
+/// For each element, check if (target - current) exists in the map
+/// Store each element's index as we iterate
+fn two_sum(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i32, usize> = HashMap::new();
+
+    for (j, &num) in nums.iter().enumerate() {
+        if let Some(&i) = target.checked_sub(num).and_then(|c| seen.get(&c)) {
+            return Some((i, j));
+        }
+        // Keep the earliest index for repeated values
+        seen.entry(num).or_insert(j);
+    }
+
+    None
+}
+
+/// Find all unique pairs that sum to target
+/// Returns a vector of (i, j) tuples where i < j
+fn two_sum_all(nums: &[i32], target: i32) -> Vec<(usize, usize)> {
+    let mut seen: HashMap<i32, Vec<usize>> = HashMap::new();
+    let mut pairs = Vec::new();
+
+    for (j, &num) in nums.iter().enumerate() {
+        if let Some(earlier) = target.checked_sub(num).and_then(|c| seen.get(&c)) {
+            pairs.extend(earlier.iter().map(|&i| (i, j)));
+        }
+        seen.entry(num).or_default().push(j);
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Two sum using two-pointer approach (requires sorted array)
+/// Returns Some((i, j)) where nums[i] + nums[j] == target
+///
+/// Note: This returns indices in the sorted array, not original indices
+/// Time: O(n log n) due to sorting, Space: O(1) extra
+fn two_sum_sorted(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut sorted = nums.to_vec();
+    sorted.sort_unstable();
+    if sorted.len() < 2 {
+        return None;
+    }
+
+    let (mut left, mut right) = (0, sorted.len() - 1);
+    while left < right {
+        let sum = sorted[left] as i64 + sorted[right] as i64;
+        match sum.cmp(&(target as i64)) {
+            std::cmp::Ordering::Less => left += 1,
+            std::cmp::Ordering::Greater => right -= 1,
+            std::cmp::Ordering::Equal => return Some((left, right)),
+        }
+    }
+
+    None
+}
+
+/// Three sum - find three numbers that sum to target
+/// Returns a vector of tuples (i, j, k) where nums[i] + nums[j] + nums[k] == target
+fn three_sum(nums: &[i32], target: i32) -> Vec<(usize, usize, usize)> {
+    let mut triples = Vec::new();
+
+    for (i, &first) in nums.iter().enumerate() {
+        let Some(rest_target) = target.checked_sub(first) else {
+            continue;
+        };
+        let offset = i + 1;
+        for (j, k) in two_sum_all(&nums[offset..], rest_target) {
+            triples.push((i, j + offset, k + offset));
+        }
+    }
+
+    triples
+}
+
+/// Check if array has a contiguous subarray summing to target
+fn has_subarray_sum(nums: &[i32], target: i32) -> bool {
+    use std::collections::HashSet;
+
+    // Widen to i64 so long runs of large values cannot overflow
+    let target = target as i64;
+    let mut prefixes = HashSet::from([0i64]);
+    let mut prefix = 0i64;
+
+    for &num in nums {
+        prefix += num as i64;
+        if prefixes.contains(&(prefix - target)) {
+            return true;
+        }
+        prefixes.insert(prefix);
+    }
+
+    false
+}
+
+fn main() {
+    let nums = vec![2, 7, 11, 15];
+    let target = 9;
+
+    println!("Array: {:?}", nums);
+    println!("Target: {}", target);
+
+    if let Some((i, j)) = two_sum(&nums, target) {
+        println!("\nTwo sum found: indices ({}, {})", i, j);
+        println!("Values: {} + {} = {}", nums[i], nums[j], target);
+    } else {
+        println!("\nNo solution found");
+    }
+
+    // Test with duplicates
+    let nums2 = vec![3, 2, 4, 3, 3];
+    let all_pairs = two_sum_all(&nums2, 6);
+    println!("\nArray: {:?}", nums2);
+    println!("All pairs summing to 6: {:?}", all_pairs);
+
+    // Test sorted approach
+    let nums3 = vec![1, 3, 4, 8, 10];
+    if let Some((i, j)) = two_sum_sorted(&nums3, 11) {
+        println!("\nTwo sum in sorted array: indices ({}, {})", i, j);
+    }
+
+    println!("\nThree sum to 0 in [-1, 0, 1, 2, -1]: {:?}", three_sum(&[-1, 0, 1, 2, -1], 0));
+    println!("Has subarray summing to 7 in [1, 2, 3, 4]: {}", has_subarray_sum(&[1, 2, 3, 4], 7));
+}
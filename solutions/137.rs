@@ -0,0 +1,132 @@
+// Reference solution for exercise 137: Maximum Subarray (Kadane's Algorithm)
+
+/// Find the maximum sum of any contiguous subarray
+/// Returns the maximum sum value
+///
+/// Kadane's Algorithm:
+/// Initialize: max_so_far = nums[0], max_ending_here = nums[0]
+/// For each element:
+///   max_ending_here = max(nums[i], max_ending_here + nums[i])
+///   max_so_far = max(max_so_far, max_ending_here)
+fn max_subarray_sum(nums: &[i32]) -> i32 {
+    max_subarray_with_indices(nums).0
+}
+
+/// Find maximum subarray sum and return the subarray indices
+/// Returns (max_sum, start_index, end_index)
+fn max_subarray_with_indices(nums: &[i32]) -> (i32, usize, usize) {
+    if nums.is_empty() {
+        return (0, 0, 0);
+    }
+
+    let mut best = (nums[0], 0, 0);
+    let mut max_ending_here = nums[0];
+    let mut start = 0;
+
+    for (i, &x) in nums.iter().enumerate().skip(1) {
+        // Starting fresh beats extending a negative run
+        if max_ending_here < 0 {
+            max_ending_here = x;
+            start = i;
+        } else {
+            max_ending_here += x;
+        }
+        if max_ending_here > best.0 {
+            best = (max_ending_here, start, i);
+        }
+    }
+
+    best
+}
+
+/// Find the actual subarray with maximum sum
+/// Returns a vector containing the subarray elements
+fn max_subarray(nums: &[i32]) -> Vec<i32> {
+    if nums.is_empty() {
+        return vec![];
+    }
+    let (_, start, end) = max_subarray_with_indices(nums);
+    nums[start..=end].to_vec()
+}
+
+/// Maximum circular subarray sum
+/// The subarray can wrap around the end to the beginning
+///
+/// Approach:
+/// 1. Find max subarray sum using Kadane's (non-circular)
+/// 2. Find total sum - min subarray sum (circular case)
+/// 3. Return max of the two (handle all-negative case specially)
+fn max_circular_subarray_sum(nums: &[i32]) -> i32 {
+    if nums.is_empty() {
+        return 0;
+    }
+
+    let straight = max_subarray_sum(nums);
+    // Every element negative: wrapping would select the empty subarray
+    if straight < 0 {
+        return straight;
+    }
+
+    let total: i32 = nums.iter().sum();
+    let mut min_ending_here = nums[0];
+    let mut min_so_far = nums[0];
+    for &x in &nums[1..] {
+        min_ending_here = x.min(min_ending_here + x);
+        min_so_far = min_so_far.min(min_ending_here);
+    }
+
+    straight.max(total - min_so_far)
+}
+
+/// Maximum product subarray
+/// Find contiguous subarray with maximum product
+///
+/// Challenge: Need to track both max and min because
+/// multiplying by a negative flips the sign
+fn max_product_subarray(nums: &[i32]) -> i32 {
+    if nums.is_empty() {
+        return 0;
+    }
+
+    let mut max_here = nums[0];
+    let mut min_here = nums[0];
+    let mut best = nums[0];
+
+    for &x in &nums[1..] {
+        // A negative factor turns the smallest product into the largest
+        if x < 0 {
+            std::mem::swap(&mut max_here, &mut min_here);
+        }
+        max_here = x.max(max_here * x);
+        min_here = x.min(min_here * x);
+        best = best.max(max_here);
+    }
+
+    best
+}
+
+fn main() {
+    let nums = vec![-2, 1, -3, 4, -1, 2, 1, -5, 4];
+
+    println!("Array: {:?}", nums);
+
+    let max_sum = max_subarray_sum(&nums);
+    println!("\nMaximum subarray sum: {}", max_sum);
+
+    let (sum, start, end) = max_subarray_with_indices(&nums);
+    println!("Subarray indices: [{}, {}]", start, end);
+    println!("Subarray: {:?}", &nums[start..=end]);
+    println!("Sum: {}", sum);
+
+    // Circular example
+    let circular = vec![5, -3, 5];
+    println!("\nCircular array: {:?}", circular);
+    println!("Max circular sum: {}", max_circular_subarray_sum(&circular));
+
+    // Product example
+    let product_nums = vec![2, 3, -2, 4];
+    println!("\nArray: {:?}", product_nums);
+    println!("Max product: {}", max_product_subarray(&product_nums));
+
+    println!("Max subarray of {:?}: {:?}", nums, max_subarray(&nums));
+}
@@ -0,0 +1,225 @@
+// Reference solution for exercise 138: LRU Cache Implementation
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+/// Node in the doubly linked list
+#[derive(Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Option<Rc<RefCell<Node<K, V>>>>,
+    next: Option<Rc<RefCell<Node<K, V>>>>,
+}
+
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V) -> Self {
+        Node {
+            key,
+            value,
+            prev: None,
+            next: None,
+        }
+    }
+}
+
+/// LRU Cache implementation
+#[derive(Debug)]
+struct LRUCache<K, V> {
+    capacity: usize,
+    cache: HashMap<K, Rc<RefCell<Node<K, V>>>>,
+    head: Option<Rc<RefCell<Node<K, V>>>>,
+    tail: Option<Rc<RefCell<Node<K, V>>>>,
+}
+
+impl<K, V> LRUCache<K, V>
+where
+    K: std::hash::Hash + Eq + Clone,
+    V: Clone,
+{
+    /// Create a new LRU Cache with given capacity
+    fn new(capacity: usize) -> Self {
+        LRUCache {
+            capacity,
+            cache: HashMap::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// Get value by key
+    /// Returns Some(value) if found, None otherwise
+    /// Marks the accessed item as most recently used
+    fn get(&mut self, key: &K) -> Option<V> {
+        let node = self.cache.get(key)?.clone();
+        self.move_to_front(node.clone());
+        let value = node.borrow().value.clone();
+        Some(value)
+    }
+
+    /// Insert or update key-value pair
+    /// If key exists, update value and mark as most recent
+    /// If key doesn't exist, insert and evict LRU if at capacity
+    fn put(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some(node) = self.cache.get(&key).cloned() {
+            node.borrow_mut().value = value;
+            self.move_to_front(node);
+            return;
+        }
+
+        let node = Rc::new(RefCell::new(Node::new(key.clone(), value)));
+        self.add_to_front(node.clone());
+        self.cache.insert(key, node);
+
+        if self.cache.len() > self.capacity {
+            if let Some(lru) = self.remove_tail() {
+                self.cache.remove(&lru.borrow().key);
+            }
+        }
+    }
+
+    /// Move a node to the front (most recently used)
+    fn move_to_front(&mut self, node: Rc<RefCell<Node<K, V>>>) {
+        self.remove_node(&node);
+        self.add_to_front(node);
+    }
+
+    /// Add a new node to the front
+    fn add_to_front(&mut self, node: Rc<RefCell<Node<K, V>>>) {
+        match self.head.take() {
+            Some(old_head) => {
+                old_head.borrow_mut().prev = Some(node.clone());
+                node.borrow_mut().next = Some(old_head);
+            }
+            None => self.tail = Some(node.clone()),
+        }
+        self.head = Some(node);
+    }
+
+    /// Remove the tail node (least recently used)
+    fn remove_tail(&mut self) -> Option<Rc<RefCell<Node<K, V>>>> {
+        let tail = self.tail.clone()?;
+        self.remove_node(&tail);
+        Some(tail)
+    }
+
+    /// Remove a node from its current position in the list
+    fn remove_node(&mut self, node: &Rc<RefCell<Node<K, V>>>) {
+        // Clearing the node's own links matters: prev and next are both
+        // strong references, so a stale link would keep a cycle alive
+        let prev = node.borrow_mut().prev.take();
+        let next = node.borrow_mut().next.take();
+
+        match &prev {
+            Some(p) => p.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
+        }
+        match &next {
+            Some(n) => n.borrow_mut().prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    /// Get current size of cache
+    fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Check if cache is empty
+    fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+/// Nodes link to each other with strong references in both directions,
+/// so unlink them explicitly or the whole list would leak.
+impl<K, V> Drop for LRUCache<K, V> {
+    fn drop(&mut self) {
+        self.tail = None;
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            let mut node = node.borrow_mut();
+            node.prev = None;
+            current = node.next.take();
+        }
+    }
+}
+
+/// Alternative implementation using VecDeque for simplicity
+/// Note: This has O(n) operations but is simpler to understand
+#[derive(Debug)]
+struct SimpleLRUCache<K, V> {
+    capacity: usize,
+    items: Vec<(K, V)>,
+}
+
+impl<K: PartialEq + Clone, V: Clone> SimpleLRUCache<K, V> {
+    fn new(capacity: usize) -> Self {
+        SimpleLRUCache {
+            capacity,
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        let pos = self.items.iter().position(|(k, _)| k == key)?;
+        let entry = self.items.remove(pos);
+        let value = entry.1.clone();
+        self.items.insert(0, entry);
+        Some(value)
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn put(&mut self, key: K, value: V) {
+        if let Some(pos) = self.items.iter().position(|(k, _)| *k == key) {
+            self.items.remove(pos);
+        }
+        self.items.insert(0, (key, value));
+        self.items.truncate(self.capacity);
+    }
+}
+
+fn main() {
+    let mut cache = SimpleLRUCache::new(3);
+
+    println!("Testing Simple LRU Cache with capacity 3\n");
+
+    cache.put(1, "one");
+    cache.put(2, "two");
+    cache.put(3, "three");
+    println!("After inserting 1, 2, 3: {:?}", cache);
+
+    cache.put(4, "four"); // Should evict 1
+    println!("After inserting 4 (evicts 1): {:?}", cache);
+
+    if let Some(val) = cache.get(&2) {
+        println!("Get 2: {} (moves to front)", val);
+    }
+    println!("Cache after get(2): {:?}", cache);
+
+    cache.put(5, "five"); // Should evict 3 (not 2, since 2 was accessed)
+    println!("After inserting 5 (evicts 3): {:?}", cache);
+
+    println!("\nTesting linked-list LRU Cache with capacity 2\n");
+    let mut lru = LRUCache::new(2);
+    println!("Empty: {}", lru.is_empty());
+    lru.put("a", 1);
+    lru.put("b", 2);
+    println!("Get a: {:?}", lru.get(&"a"));
+    lru.put("c", 3); // Evicts b
+    println!("Get b after inserting c: {:?}", lru.get(&"b"));
+    println!("Len: {}, simple cache len: {}", lru.len(), cache.len());
+    println!("Simple cache empty: {}", cache.is_empty());
+}
@@ -0,0 +1,166 @@
+// Reference solution for exercise 139: Trie (Prefix Tree)
+
+use std::collections::HashMap;
+
+/// Node in the Trie
+#[derive(Debug, Default)]
+struct TrieNode {
+    /// Whether this node marks the end of a word
+    is_end_of_word: bool,
+    /// Children nodes: character -> node
+    children: HashMap<char, TrieNode>,
+    /// Optional: store the complete word at end nodes for retrieval
+    word: Option<String>,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        TrieNode {
+            is_end_of_word: false,
+            children: HashMap::new(),
+            word: None,
+        }
+    }
+}
+
+/// Trie data structure
+#[derive(Debug)]
+struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    /// Create a new empty Trie
+    fn new() -> Self {
+        Trie { root: TrieNode::new() }
+    }
+
+    /// Insert a word into the Trie
+    fn insert(&mut self, word: &str) {
+        // For each character in word:
+        //   Create child if doesn't exist
+        //   Move to child
+        // Mark final node as end_of_word
+
+        let mut current = &mut self.root;
+        for ch in word.chars() {
+            current = current.children.entry(ch).or_insert_with(TrieNode::new);
+        }
+        current.is_end_of_word = true;
+        current.word = Some(word.to_string());
+    }
+
+    /// Search for a word in the Trie
+    /// Returns true if the exact word exists
+    fn search(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_end_of_word)
+    }
+
+    /// Check if any word starts with given prefix
+    fn starts_with(&self, prefix: &str) -> bool {
+        self.find_node(prefix).is_some()
+    }
+
+    /// Find the node corresponding to a prefix
+    /// Returns Some(node) if prefix exists, None otherwise
+    fn find_node(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut current = &self.root;
+        for ch in prefix.chars() {
+            current = current.children.get(&ch)?;
+        }
+        Some(current)
+    }
+
+    /// Get all words with given prefix
+    fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        if let Some(node) = self.find_node(prefix) {
+            self.collect_words(node, &mut result);
+        }
+        // HashMap iteration order is arbitrary; sort for stable output
+        result.sort();
+        result
+    }
+
+    /// Helper: collect all words from a node
+    fn collect_words(&self, node: &TrieNode, result: &mut Vec<String>) {
+        if node.is_end_of_word {
+            if let Some(word) = &node.word {
+                result.push(word.clone());
+            }
+        }
+        for child in node.children.values() {
+            self.collect_words(child, result);
+        }
+    }
+
+    /// Delete a word from the Trie
+    /// Returns true if word was found and deleted
+    fn delete(&mut self, word: &str) -> bool {
+        /// Returns (found, prune) where prune tells the parent to drop this node
+        fn remove(node: &mut TrieNode, chars: &[char]) -> (bool, bool) {
+            let found = match chars.split_first() {
+                None => {
+                    let found = node.is_end_of_word;
+                    node.is_end_of_word = false;
+                    node.word = None;
+                    found
+                }
+                Some((ch, rest)) => {
+                    let Some(child) = node.children.get_mut(ch) else {
+                        return (false, false);
+                    };
+                    let (found, prune) = remove(child, rest);
+                    if prune {
+                        node.children.remove(ch);
+                    }
+                    found
+                }
+            };
+            (found, !node.is_end_of_word && node.children.is_empty())
+        }
+
+        let chars: Vec<char> = word.chars().collect();
+        remove(&mut self.root, &chars).0
+    }
+
+    /// Count words in Trie
+    fn count(&self) -> usize {
+        fn count_from(node: &TrieNode) -> usize {
+            usize::from(node.is_end_of_word) + node.children.values().map(count_from).sum::<usize>()
+        }
+
+        count_from(&self.root)
+    }
+}
+
+fn main() {
+    let mut trie = Trie::new();
+
+    let words = vec!["cat", "car", "card", "care", "careful", "dog", "dodge"];
+
+    println!("Inserting words: {:?}\n", words);
+
+    for word in &words {
+        trie.insert(word);
+    }
+
+    println!("Search 'cat': {}", trie.search("cat"));
+    println!("Search 'car': {}", trie.search("car"));
+    println!("Search 'ca': {}", trie.search("ca")); // false, it's a prefix not a word
+    println!("Search 'catz': {}", trie.search("catz"));
+
+    println!("\nStarts with 'ca': {}", trie.starts_with("ca"));
+    println!("Starts with 'dog': {}", trie.starts_with("dog"));
+    println!("Starts with 'z': {}", trie.starts_with("z"));
+
+    println!("\nWords with prefix 'car': {:?}", trie.words_with_prefix("car"));
+    println!("Words with prefix 'do': {:?}", trie.words_with_prefix("do"));
+    println!("Words with prefix 'care': {:?}", trie.words_with_prefix("care"));
+
+    println!("\nWord count: {}", trie.count());
+    println!("Delete 'card': {}", trie.delete("card"));
+    println!("Delete 'card' again: {}", trie.delete("card"));
+    println!("Words with prefix 'car' after delete: {:?}", trie.words_with_prefix("car"));
+    println!("Word count: {}", trie.count());
+}
@@ -0,0 +1,282 @@
+// Reference solution for exercise 140: Graph Representation - Adjacency List
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A graph using adjacency list representation
+/// The graph can be either directed or undirected
+#[derive(Debug, Clone)]
+struct Graph {
+    /// Number of vertices
+    vertices: usize,
+    /// For directed graph: adj[u] contains all v where edge u->v exists
+    /// For undirected graph: adj[u] contains all v where edge u-v exists
+    adj: Vec<Vec<usize>>,
+    /// Optional: track if graph is directed
+    directed: bool,
+    /// Optional: edge weights (for weighted graph extension)
+    /// weights[u][i] = weight of edge from u to adj[u][i]
+    weights: Option<Vec<Vec<i32>>>,
+}
+
+impl Graph {
+    /// Create a new graph with given number of vertices
+    fn new(vertices: usize, directed: bool) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+            directed,
+            weights: None,
+        }
+    }
+
+    /// Add an edge to the graph
+    fn add_edge(&mut self, u: usize, v: usize) {
+        if u >= self.vertices || v >= self.vertices {
+            panic!("Vertex out of bounds");
+        }
+
+        self.adj[u].push(v);
+        if let Some(weights) = &mut self.weights {
+            weights[u].push(1);
+        }
+        if !self.directed {
+            self.adj[v].push(u);
+            if let Some(weights) = &mut self.weights {
+                weights[v].push(1);
+            }
+        }
+    }
+
+    /// Remove an edge from the graph
+    fn remove_edge(&mut self, u: usize, v: usize) -> bool {
+        if !self.remove_directed(u, v) {
+            return false;
+        }
+        // An undirected self-loop is stored twice in adj[u], so this
+        // removes its second copy
+        if !self.directed {
+            self.remove_directed(v, u);
+        }
+        true
+    }
+
+    /// Check if edge exists
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        u < self.vertices && self.adj[u].contains(&v)
+    }
+
+    /// Get all neighbors of a vertex
+    fn neighbors(&self, u: usize) -> &[usize] {
+        self.adj.get(u).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get degree of a vertex
+    /// For undirected: number of edges incident to vertex
+    /// For directed: out-degree (number of outgoing edges)
+    fn degree(&self, u: usize) -> usize {
+        self.neighbors(u).len()
+    }
+
+    /// Get in-degree of a vertex (for directed graphs)
+    fn in_degree(&self, u: usize) -> usize {
+        self.adj
+            .iter()
+            .map(|list| list.iter().filter(|&&v| v == u).count())
+            .sum()
+    }
+
+    /// Count total number of edges
+    fn edge_count(&self) -> usize {
+        let stored: usize = self.adj.iter().map(Vec::len).sum();
+        if self.directed {
+            stored
+        } else {
+            // Each undirected edge is stored twice (a self-loop pushes u twice too)
+            stored / 2
+        }
+    }
+
+    /// Transpose the graph (reverse all edges)
+    /// Returns a new graph with all edges reversed
+    fn transpose(&self) -> Graph {
+        let mut transposed = Graph::new(self.vertices, self.directed);
+        if let Some(weights) = &self.weights {
+            for u in 0..self.vertices {
+                for (&v, &w) in self.adj[u].iter().zip(&weights[u]) {
+                    transposed.add_directed(v, u, w);
+                }
+            }
+        } else {
+            for u in 0..self.vertices {
+                for &v in &self.adj[u] {
+                    transposed.adj[v].push(u);
+                }
+            }
+        }
+        transposed
+    }
+
+    /// Add a weighted edge; existing unweighted edges get weight 1
+    fn add_weighted_edge(&mut self, u: usize, v: usize, weight: i32) {
+        if u >= self.vertices || v >= self.vertices {
+            panic!("Vertex out of bounds");
+        }
+        self.add_directed(u, v, weight);
+        if !self.directed {
+            self.add_directed(v, u, weight);
+        }
+    }
+
+    /// Weight of the edge u -> v, if the graph is weighted and the edge exists
+    fn edge_weight(&self, u: usize, v: usize) -> Option<i32> {
+        let weights = self.weights.as_ref()?;
+        let i = self.adj.get(u)?.iter().position(|&x| x == v)?;
+        Some(weights[u][i])
+    }
+
+    fn add_directed(&mut self, u: usize, v: usize, weight: i32) {
+        let adj = &self.adj;
+        let weights = self
+            .weights
+            .get_or_insert_with(|| adj.iter().map(|list| vec![1; list.len()]).collect());
+        weights[u].push(weight);
+        self.adj[u].push(v);
+    }
+
+    fn remove_directed(&mut self, u: usize, v: usize) -> bool {
+        let Some(i) = self.adj.get(u).and_then(|list| list.iter().position(|&x| x == v)) else {
+            return false;
+        };
+        self.adj[u].remove(i);
+        if let Some(weights) = &mut self.weights {
+            weights[u].remove(i);
+        }
+        true
+    }
+
+    /// Perform BFS and return traversal order
+    fn bfs(&self, start: usize) -> Vec<usize> {
+        if start >= self.vertices {
+            return vec![];
+        }
+
+        let mut visited = HashSet::from([start]);
+        let mut queue = VecDeque::from([start]);
+        let mut order = Vec::new();
+
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+            for &v in &self.adj[u] {
+                if visited.insert(v) {
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Perform DFS and return traversal order
+    fn dfs(&self, start: usize) -> Vec<usize> {
+        if start >= self.vertices {
+            return vec![];
+        }
+
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        let mut order = Vec::new();
+
+        while let Some(u) = stack.pop() {
+            if !visited.insert(u) {
+                continue;
+            }
+            order.push(u);
+            // Reverse so the first neighbor is explored first
+            for &v in self.adj[u].iter().rev() {
+                if !visited.contains(&v) {
+                    stack.push(v);
+                }
+            }
+        }
+
+        order
+    }
+}
+
+/// Alternative: Graph using HashMap for sparse/dynamic graphs
+#[derive(Debug)]
+struct HashGraph<T: std::hash::Hash + Eq + Clone> {
+    adj: HashMap<T, Vec<T>>,
+    directed: bool,
+}
+
+impl<T: std::hash::Hash + Eq + Clone> HashGraph<T> {
+    fn new(directed: bool) -> Self {
+        HashGraph {
+            adj: HashMap::new(),
+            directed,
+        }
+    }
+
+    fn add_vertex(&mut self, v: T) {
+        self.adj.entry(v).or_insert_with(Vec::new);
+    }
+
+    fn add_edge(&mut self, u: T, v: T) {
+        self.adj.entry(u.clone()).or_insert_with(Vec::new).push(v.clone());
+        self.adj.entry(v.clone()).or_insert_with(Vec::new);
+        if !self.directed {
+            self.adj.entry(v).or_insert_with(Vec::new).push(u);
+        }
+    }
+}
+
+fn main() {
+    // Create an undirected graph
+    let mut graph = Graph::new(5, false);
+
+    // Create graph: 0 -- 1 -- 2
+    //               |    |
+    //               3 -- 4
+    graph.add_edge(0, 1);
+    graph.add_edge(1, 2);
+    graph.add_edge(0, 3);
+    graph.add_edge(1, 4);
+    graph.add_edge(3, 4);
+
+    println!("Undirected Graph:");
+    println!("Adjacency list: {:?}", graph.adj);
+    println!("Degree of vertex 1: {}", graph.degree(1));
+    println!("Neighbors of vertex 1: {:?}", graph.neighbors(1));
+    println!("Total edges: {}", graph.edge_count());
+
+    println!("\nBFS from 0: {:?}", graph.bfs(0));
+    println!("DFS from 0: {:?}", graph.dfs(0));
+
+    // Create a directed graph
+    let mut digraph = Graph::new(4, true);
+    digraph.add_edge(0, 1);
+    digraph.add_edge(0, 2);
+    digraph.add_edge(1, 2);
+    digraph.add_edge(2, 3);
+
+    println!("\nDirected Graph:");
+    println!("Adjacency list: {:?}", digraph.adj);
+    println!("Out-degree of 0: {}", digraph.degree(0));
+    println!("In-degree of 2: {}", digraph.in_degree(2));
+
+    let transposed = digraph.transpose();
+    println!("Transposed graph: {:?}", transposed.adj);
+
+    let mut weighted = Graph::new(3, false);
+    weighted.add_edge(0, 1);
+    weighted.add_weighted_edge(1, 2, 7);
+    println!("\nWeight of 0-1: {:?}, weight of 2-1: {:?}", weighted.edge_weight(0, 1), weighted.edge_weight(2, 1));
+    println!("Removed 1-2: {}, edges left: {}", weighted.remove_edge(1, 2), weighted.edge_count());
+    println!("Has edge 2-1: {}", weighted.has_edge(2, 1));
+
+    let mut cities = HashGraph::new(false);
+    cities.add_vertex("Lisbon");
+    cities.add_edge("Paris", "Berlin");
+    println!("\nHashGraph: {} vertices, directed: {}", cities.adj.len(), cities.directed);
+}
@@ -0,0 +1,200 @@
+// Reference solution for exercise 141: Graph Representation - Adjacency Matrix
+
+/// A graph using adjacency matrix representation
+#[derive(Debug, Clone)]
+struct Graph {
+    /// Number of vertices
+    vertices: usize,
+    /// Adjacency matrix: matrix[u][v] = true if edge u->v exists
+    matrix: Vec<Vec<bool>>,
+    /// Whether the graph is directed
+    directed: bool,
+    /// Optional: for weighted graphs, store weights instead of bool
+    /// Use Option<i32> where None means no edge
+    weighted: Option<Vec<Vec<Option<i32>>>>,
+}
+
+impl Graph {
+    /// Create a new graph with given number of vertices
+    fn new(vertices: usize, directed: bool) -> Self {
+        Graph {
+            vertices,
+            matrix: vec![vec![false; vertices]; vertices],
+            directed,
+            weighted: None,
+        }
+    }
+
+    /// Create a weighted graph
+    fn new_weighted(vertices: usize, directed: bool) -> Self {
+        Graph {
+            vertices,
+            matrix: vec![vec![false; vertices]; vertices],
+            directed,
+            weighted: Some(vec![vec![None; vertices]; vertices]),
+        }
+    }
+
+    /// Add an edge to the graph
+    fn add_edge(&mut self, u: usize, v: usize) {
+        // Set matrix[u][v] = true
+        // If undirected, set matrix[v][u] = true
+
+        if u >= self.vertices || v >= self.vertices {
+            panic!("Vertex out of bounds");
+        }
+
+        self.matrix[u][v] = true;
+        if !self.directed {
+            self.matrix[v][u] = true;
+        }
+    }
+
+    /// Add a weighted edge
+    fn add_weighted_edge(&mut self, u: usize, v: usize, weight: i32) {
+        // Update both matrix and weighted
+
+        self.add_edge(u, v);
+        if let Some(ref mut w) = self.weighted {
+            w[u][v] = Some(weight);
+            if !self.directed {
+                w[v][u] = Some(weight);
+            }
+        }
+    }
+
+    /// Remove an edge
+    fn remove_edge(&mut self, u: usize, v: usize) {
+        // Also clear weight if weighted
+
+        if u < self.vertices && v < self.vertices {
+            self.matrix[u][v] = false;
+            if !self.directed {
+                self.matrix[v][u] = false;
+            }
+            if let Some(ref mut w) = self.weighted {
+                w[u][v] = None;
+                if !self.directed {
+                    w[v][u] = None;
+                }
+            }
+        }
+    }
+
+    /// Check if edge exists
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        if u < self.vertices && v < self.vertices {
+            self.matrix[u][v]
+        } else {
+            false
+        }
+    }
+
+    /// Get edge weight
+    fn get_weight(&self, u: usize, v: usize) -> Option<i32> {
+        if !self.has_edge(u, v) {
+            return None;
+        }
+        self.weighted.as_ref()?[u][v]
+    }
+
+    /// Get all neighbors of a vertex
+    fn neighbors(&self, u: usize) -> Vec<usize> {
+        if u >= self.vertices {
+            return vec![];
+        }
+        (0..self.vertices).filter(|&v| self.matrix[u][v]).collect()
+    }
+
+    /// Get degree (out-degree for directed)
+    fn degree(&self, u: usize) -> usize {
+        self.neighbors(u).len()
+    }
+
+    /// Get in-degree
+    fn in_degree(&self, u: usize) -> usize {
+        if u >= self.vertices {
+            return 0;
+        }
+        self.matrix.iter().filter(|row| row[u]).count()
+    }
+
+    /// Count total edges
+    fn edge_count(&self) -> usize {
+        self.get_edges().len()
+    }
+
+    /// Get all edges as vector of tuples
+    fn get_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for u in 0..self.vertices {
+            // An undirected edge is stored in both halves; keep the upper one
+            let from = if self.directed { 0 } else { u };
+            for v in from..self.vertices {
+                if self.matrix[u][v] {
+                    edges.push((u, v));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Convert to adjacency list representation
+    fn to_adjacency_list(&self) -> Vec<Vec<usize>> {
+        (0..self.vertices).map(|u| self.neighbors(u)).collect()
+    }
+
+    /// Check if graph is complete
+    fn is_complete(&self) -> bool {
+        (0..self.vertices).all(|u| (0..self.vertices).all(|v| u == v || self.matrix[u][v]))
+    }
+}
+
+fn main() {
+    // Create undirected graph
+    let mut graph = Graph::new(4, false);
+
+    // Create: 0 -- 1
+    //         |  / |
+    //         2 -- 3
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 2);
+    graph.add_edge(1, 3);
+    graph.add_edge(2, 3);
+
+    println!("Undirected Graph:");
+    println!("Adjacency Matrix:");
+    for row in &graph.matrix {
+        println!("  {:?}", row.iter().map(|&b| if b { 1 } else { 0 }).collect::<Vec<_>>());
+    }
+
+    println!("\nNeighbors of 1: {:?}", graph.neighbors(1));
+    println!("Degree of 1: {}", graph.degree(1));
+    println!("Total edges: {}", graph.edge_count());
+    println!("Is complete: {}", graph.is_complete());
+
+    // Create weighted directed graph
+    let mut wgraph = Graph::new_weighted(4, true);
+    wgraph.add_weighted_edge(0, 1, 5);
+    wgraph.add_weighted_edge(0, 2, 3);
+    wgraph.add_weighted_edge(1, 2, 1);
+    wgraph.add_weighted_edge(2, 3, 7);
+
+    println!("\nWeighted Directed Graph:");
+    if let Some(ref weights) = wgraph.weighted {
+        for row in weights {
+            println!("  {:?}", row);
+        }
+    }
+    println!("Weight of edge 0->1: {:?}", wgraph.get_weight(0, 1));
+
+    // Convert to adjacency list
+    let adj_list = graph.to_adjacency_list();
+    println!("\nAs adjacency list: {:?}", adj_list);
+
+    println!("Edges: {:?}", graph.get_edges());
+    println!("In-degree of 2 in weighted graph: {}", wgraph.in_degree(2));
+    wgraph.remove_edge(0, 1);
+    println!("Weight of 0->1 after removal: {:?}", wgraph.get_weight(0, 1));
+}
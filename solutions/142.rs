@@ -0,0 +1,263 @@
+// Reference solution for exercise 142: Cycle Detection in Undirected Graph
+
+use std::collections::{HashSet, VecDeque};
+
+/// Graph using adjacency list
+#[derive(Debug, Clone)]
+struct Graph {
+    vertices: usize,
+    adj: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(vertices: usize) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.adj[u].push(v);
+        self.adj[v].push(u);
+    }
+}
+
+/// Detect cycle using DFS
+/// Returns true if the graph contains any cycle
+///
+/// Approach:
+/// For each unvisited vertex, do DFS
+/// If we encounter a visited vertex that is not the parent, there's a cycle
+fn has_cycle_dfs(graph: &Graph) -> bool {
+    let mut visited = HashSet::new();
+
+    (0..graph.vertices).any(|u| !visited.contains(&u) && dfs_cycle_helper(graph, u, None, &mut visited))
+}
+
+/// DFS helper for cycle detection
+/// Returns true if a cycle is found starting from vertex u
+/// parent is the vertex we came from (to avoid false positive)
+fn dfs_cycle_helper(graph: &Graph, u: usize, parent: Option<usize>, visited: &mut HashSet<usize>) -> bool {
+    visited.insert(u);
+
+    // Skip the edge back to the parent once; a second copy of it is a
+    // parallel edge, which is a cycle of length two
+    let mut parent_edge_skipped = false;
+    for &v in &graph.adj[u] {
+        if Some(v) == parent && !parent_edge_skipped {
+            parent_edge_skipped = true;
+            continue;
+        }
+        if visited.contains(&v) || dfs_cycle_helper(graph, v, Some(u), visited) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Detect cycle using BFS
+/// Alternative approach using BFS with parent tracking
+fn has_cycle_bfs(graph: &Graph) -> bool {
+    let mut visited = HashSet::new();
+
+    for start in 0..graph.vertices {
+        if !visited.insert(start) {
+            continue;
+        }
+
+        let mut queue = VecDeque::from([(start, None)]);
+        while let Some((u, parent)) = queue.pop_front() {
+            let mut parent_edge_skipped = false;
+            for &v in &graph.adj[u] {
+                if Some(v) == parent && !parent_edge_skipped {
+                    parent_edge_skipped = true;
+                    continue;
+                }
+                if !visited.insert(v) {
+                    return true;
+                }
+                queue.push_back((v, Some(u)));
+            }
+        }
+    }
+
+    false
+}
+
+/// Union-Find data structure for cycle detection
+#[derive(Debug)]
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    /// Find with path compression
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            let root = self.find(self.parent[x]);
+            self.parent[x] = root;
+        }
+        self.parent[x]
+    }
+
+    /// Union by rank
+    /// Returns true if union was performed (they were in different sets)
+    /// Returns false if they were already in the same set (cycle detected!)
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let (rx, ry) = (self.find(x), self.find(y));
+        if rx == ry {
+            return false;
+        }
+
+        match self.rank[rx].cmp(&self.rank[ry]) {
+            std::cmp::Ordering::Less => self.parent[rx] = ry,
+            std::cmp::Ordering::Greater => self.parent[ry] = rx,
+            std::cmp::Ordering::Equal => {
+                self.parent[ry] = rx;
+                self.rank[rx] += 1;
+            }
+        }
+        true
+    }
+}
+
+/// Detect cycle using Union-Find
+fn has_cycle_union_find(graph: &Graph) -> bool {
+    let mut uf = UnionFind::new(graph.vertices);
+
+    for (u, neighbors) in graph.adj.iter().enumerate() {
+        for &v in neighbors {
+            // Each undirected edge appears as u -> v and v -> u; take it once.
+            // A self-loop shows up twice in adj[u], so check only one copy.
+            if u < v && !uf.union(u, v) {
+                return true;
+            }
+        }
+        if neighbors.contains(&u) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Find a specific cycle and return its vertices
+/// Returns Some(cycle_vertices) if cycle exists, None otherwise
+fn find_cycle(graph: &Graph) -> Option<Vec<usize>> {
+    fn dfs(
+        graph: &Graph,
+        u: usize,
+        parent: &mut [Option<usize>],
+        visited: &mut [bool],
+    ) -> Option<Vec<usize>> {
+        visited[u] = true;
+
+        let mut parent_edge_skipped = false;
+        for &v in &graph.adj[u] {
+            if Some(v) == parent[u] && !parent_edge_skipped {
+                parent_edge_skipped = true;
+                continue;
+            }
+            if visited[v] {
+                // Back edge u -> v: walk the tree from u up to v
+                let mut cycle = vec![u];
+                let mut current = u;
+                while current != v {
+                    current = parent[current]?;
+                    cycle.push(current);
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            parent[v] = Some(u);
+            if let Some(cycle) = dfs(graph, v, parent, visited) {
+                return Some(cycle);
+            }
+        }
+
+        None
+    }
+
+    let mut parent = vec![None; graph.vertices];
+    let mut visited = vec![false; graph.vertices];
+    for start in 0..graph.vertices {
+        if !visited[start] {
+            if let Some(cycle) = dfs(graph, start, &mut parent, &mut visited) {
+                return Some(cycle);
+            }
+        }
+    }
+
+    None
+}
+
+/// Check if graph is a tree (connected and acyclic)
+fn is_tree(graph: &Graph) -> bool {
+    // A tree has exactly n-1 edges and is connected
+    let edge_count: usize = graph.adj.iter().map(|v| v.len()).sum::<usize>() / 2;
+    if graph.vertices.checked_sub(1) != Some(edge_count) {
+        return false;
+    }
+
+    // Check connectivity
+    let mut visited = HashSet::new();
+    dfs_connectivity(graph, 0, &mut visited);
+    visited.len() == graph.vertices
+}
+
+fn dfs_connectivity(graph: &Graph, u: usize, visited: &mut HashSet<usize>) {
+    visited.insert(u);
+    for &v in &graph.adj[u] {
+        if !visited.contains(&v) {
+            dfs_connectivity(graph, v, visited);
+        }
+    }
+}
+
+fn main() {
+    // Graph with cycle: 0 -- 1 -- 2 -- 0
+    let mut graph1 = Graph::new(3);
+    graph1.add_edge(0, 1);
+    graph1.add_edge(1, 2);
+    graph1.add_edge(2, 0);
+
+    println!("Graph 1 (has cycle):");
+    println!("  DFS: {}", has_cycle_dfs(&graph1));
+    println!("  BFS: {}", has_cycle_bfs(&graph1));
+    println!("  Union-Find: {}", has_cycle_union_find(&graph1));
+
+    // Tree (no cycle): 0 -- 1 -- 2
+    let mut graph2 = Graph::new(3);
+    graph2.add_edge(0, 1);
+    graph2.add_edge(1, 2);
+
+    println!("\nGraph 2 (tree, no cycle):");
+    println!("  DFS: {}", has_cycle_dfs(&graph2));
+    println!("  BFS: {}", has_cycle_bfs(&graph2));
+    println!("  Union-Find: {}", has_cycle_union_find(&graph2));
+    println!("  Is tree: {}", is_tree(&graph2));
+
+    // Disconnected graph with cycle in one component
+    let mut graph3 = Graph::new(5);
+    graph3.add_edge(0, 1);
+    graph3.add_edge(1, 2);
+    graph3.add_edge(2, 0); // Cycle in component 1
+    graph3.add_edge(3, 4); // Component 2 (no cycle)
+
+    println!("\nGraph 3 (disconnected, has cycle):");
+    println!("  DFS: {}", has_cycle_dfs(&graph3));
+    println!("  Is tree: {}", is_tree(&graph3));
+
+    println!("  Cycle found: {:?}", find_cycle(&graph3));
+    println!("  Cycle in tree: {:?}", find_cycle(&graph2));
+}
@@ -0,0 +1,218 @@
+// Reference solution for exercise 143: Topological Sort
+
+use std::collections::{HashSet, VecDeque};
+
+/// Directed Graph
+#[derive(Debug, Clone)]
+struct Graph {
+    vertices: usize,
+    adj: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    fn new(vertices: usize) -> Self {
+        Graph {
+            vertices,
+            adj: vec![Vec::new(); vertices],
+        }
+    }
+
+    fn add_edge(&mut self, u: usize, v: usize) {
+        self.adj[u].push(v);
+    }
+
+    /// Calculate in-degree of all vertices
+    fn calculate_in_degrees(&self) -> Vec<usize> {
+        let mut in_degrees = vec![0; self.vertices];
+        for &v in self.adj.iter().flatten() {
+            in_degrees[v] += 1;
+        }
+        in_degrees
+    }
+}
+
+/// Topological sort using Kahn's algorithm (BFS)
+///
+/// Algorithm:
+/// 1. Calculate in-degree of all vertices
+/// 2. Enqueue all vertices with in-degree 0
+/// 3. While queue not empty:
+///    - Dequeue vertex u, add to result
+///    - For each neighbor v of u: decrease in-degree, if 0 enqueue
+/// 4. If result size != vertices, graph has cycle
+fn topological_sort_kahn(graph: &Graph) -> Option<Vec<usize>> {
+    let mut in_degrees = graph.calculate_in_degrees();
+    let mut queue: VecDeque<usize> = (0..graph.vertices).filter(|&u| in_degrees[u] == 0).collect();
+    let mut result = Vec::with_capacity(graph.vertices);
+
+    while let Some(u) = queue.pop_front() {
+        result.push(u);
+        for &v in &graph.adj[u] {
+            in_degrees[v] -= 1;
+            if in_degrees[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    // Vertices on a cycle never reach in-degree 0
+    if result.len() == graph.vertices {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Topological sort using DFS
+///
+/// Algorithm:
+/// 1. Mark all vertices as unvisited
+/// 2. For each unvisited vertex, call DFS helper
+/// 3. DFS helper: mark as visiting, recurse on neighbors,
+///    then push to result when done
+/// 4. Reverse the result
+fn topological_sort_dfs(graph: &Graph) -> Option<Vec<usize>> {
+    let mut visited = vec![0; graph.vertices];
+    let mut result = Vec::with_capacity(graph.vertices);
+
+    for u in 0..graph.vertices {
+        if visited[u] == 0 && !dfs_topological(graph, u, &mut visited, &mut result) {
+            return None;
+        }
+    }
+
+    result.reverse();
+    Some(result)
+}
+
+/// DFS helper for topological sort
+/// Returns false if cycle detected
+fn dfs_topological(
+    graph: &Graph,
+    u: usize,
+    visited: &mut [i32], // 0=white, 1=gray, 2=black
+    result: &mut Vec<usize>,
+) -> bool {
+    visited[u] = 1;
+
+    for &v in &graph.adj[u] {
+        // A grey vertex on the current path means a back edge
+        if visited[v] == 1 || (visited[v] == 0 && !dfs_topological(graph, v, visited, result)) {
+            return false;
+        }
+    }
+
+    visited[u] = 2;
+    result.push(u);
+    true
+}
+
+/// Check if a given ordering is a valid topological sort
+fn is_valid_topological_order(graph: &Graph, order: &[usize]) -> bool {
+    if order.len() != graph.vertices {
+        return false;
+    }
+
+    let mut position = vec![usize::MAX; graph.vertices];
+    let mut seen = HashSet::new();
+    for (i, &u) in order.iter().enumerate() {
+        if u >= graph.vertices || !seen.insert(u) {
+            return false;
+        }
+        position[u] = i;
+    }
+
+    (0..graph.vertices).all(|u| graph.adj[u].iter().all(|&v| position[u] < position[v]))
+}
+
+/// Find all topological orderings
+/// Returns vector of all valid topological sorts
+fn all_topological_sorts(graph: &Graph) -> Vec<Vec<usize>> {
+    fn backtrack(
+        graph: &Graph,
+        in_degrees: &mut [usize],
+        used: &mut [bool],
+        current: &mut Vec<usize>,
+        all: &mut Vec<Vec<usize>>,
+    ) {
+        if current.len() == graph.vertices {
+            all.push(current.clone());
+            return;
+        }
+
+        for u in 0..graph.vertices {
+            if used[u] || in_degrees[u] != 0 {
+                continue;
+            }
+            used[u] = true;
+            current.push(u);
+            for &v in &graph.adj[u] {
+                in_degrees[v] -= 1;
+            }
+
+            backtrack(graph, in_degrees, used, current, all);
+
+            for &v in &graph.adj[u] {
+                in_degrees[v] += 1;
+            }
+            current.pop();
+            used[u] = false;
+        }
+    }
+
+    let mut in_degrees = graph.calculate_in_degrees();
+    let mut used = vec![false; graph.vertices];
+    let mut all = Vec::new();
+    backtrack(graph, &mut in_degrees, &mut used, &mut Vec::new(), &mut all);
+    all
+}
+
+/// Check if graph is a DAG (Directed Acyclic Graph)
+fn is_dag(graph: &Graph) -> bool {
+    topological_sort_kahn(graph).is_some()
+}
+
+fn main() {
+    // Example: Course prerequisites
+    // 0: Introduction to Programming
+    // 1: Data Structures (requires 0)
+    // 2: Algorithms (requires 1)
+    // 3: Databases (requires 0)
+    // 4: Web Development (requires 0, 3)
+    let mut graph = Graph::new(5);
+    graph.add_edge(0, 1); // Intro -> Data Structures
+    graph.add_edge(1, 2); // Data Structures -> Algorithms
+    graph.add_edge(0, 3); // Intro -> Databases
+    graph.add_edge(0, 4); // Intro -> Web Dev
+    graph.add_edge(3, 4); // Databases -> Web Dev
+
+    println!("Course Prerequisites DAG:");
+    println!("Adjacency list: {:?}\n", graph.adj);
+
+    if let Some(order) = topological_sort_kahn(&graph) {
+        println!("Kahn's topological order: {:?}", order);
+        println!("Valid: {}", is_valid_topological_order(&graph, &order));
+    }
+
+    if let Some(order) = topological_sort_dfs(&graph) {
+        println!("DFS topological order: {:?}", order);
+        println!("Valid: {}", is_valid_topological_order(&graph, &order));
+    }
+
+    // Graph with cycle
+    let mut cyclic = Graph::new(3);
+    cyclic.add_edge(0, 1);
+    cyclic.add_edge(1, 2);
+    cyclic.add_edge(2, 0);
+
+    println!("\nCyclic graph:");
+    println!("Is DAG: {}", is_dag(&cyclic));
+    println!("Kahn's result: {:?}", topological_sort_kahn(&cyclic));
+
+    let mut diamond = Graph::new(4);
+    diamond.add_edge(0, 1);
+    diamond.add_edge(0, 2);
+    diamond.add_edge(1, 3);
+    diamond.add_edge(2, 3);
+    println!("\nAll orderings of the diamond: {:?}", all_topological_sorts(&diamond));
+}
@@ -0,0 +1,96 @@
+// Reference solution for exercise 144: Union-Find (Disjoint Set Union - DSU)
+
+/// Union-Find (Disjoint Set Union) implementation
+#[derive(Debug)]
+struct UnionFind {
+    /// Parent of each element
+    parent: Vec<usize>,
+    /// Rank (approximate tree height) for union by rank
+    rank: Vec<usize>,
+    /// Size of each set (for union by size)
+    size: Vec<usize>,
+    /// Number of disjoint sets
+    count: usize,
+}
+
+impl UnionFind {
+    /// Create a new Union-Find with n elements (0 to n-1)
+    fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Find the representative (root) of the set containing x
+    /// Implements path compression: flatten the structure during find
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            let root = self.find(self.parent[x]);
+            self.parent[x] = root;
+        }
+        self.parent[x]
+    }
+
+    /// Union by rank
+    fn union(&mut self, x: usize, y: usize) -> bool {
+        let (rx, ry) = (self.find(x), self.find(y));
+        if rx == ry {
+            return false;
+        }
+
+        // Hang the shallower tree under the deeper one
+        let (root, child) = if self.rank[rx] < self.rank[ry] { (ry, rx) } else { (rx, ry) };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+
+        self.count -= 1;
+        true
+    }
+
+    /// Check if x and y are in the same set
+    fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Number of elements in the set containing x
+    fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Get the number of disjoint sets
+    fn set_count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Count number of connected components
+fn count_components(n: usize, edges: &[(usize, usize)]) -> usize {
+    let mut uf = UnionFind::new(n);
+    for &(u, v) in edges {
+        uf.union(u, v);
+    }
+    uf.set_count()
+}
+
+fn main() {
+    let mut uf = UnionFind::new(5);
+
+    println!("Initial sets: {}", uf.set_count());
+
+    uf.union(0, 1);
+    uf.union(2, 3);
+
+    println!("After unions: {}", uf.set_count());
+    println!("0 and 1 connected: {}", uf.connected(0, 1));
+    println!("0 and 2 connected: {}", uf.connected(0, 2));
+
+    println!("Size of the set containing 3: {}", uf.set_size(3));
+    println!("Components of 0-1, 1-2, 3-4 on 6 nodes: {}", count_components(6, &[(0, 1), (1, 2), (3, 4)]));
+}
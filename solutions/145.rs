@@ -0,0 +1,137 @@
+// Reference solution for exercise 145: KMP String Matching Algorithm
+
+/// Build the LPS (Longest Prefix Suffix) array for the pattern
+/// lps[i] = length of longest proper prefix of pattern[0..=i]
+///          which is also a suffix of pattern[0..=i]
+///
+/// Algorithm:
+/// 1. lps[0] = 0 (single char has no proper prefix)
+/// 2. Use two pointers: len (length of previous longest prefix suffix)
+///    and i (current position)
+/// 3. If pattern[i] == pattern[len]: len++, lps[i] = len, i++
+/// 4. Else if len != 0: len = lps[len-1] (try shorter prefix)
+/// 5. Else: lps[i] = 0, i++
+fn build_lps(pattern: &str) -> Vec<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let m = pattern.len();
+    let mut lps = vec![0; m];
+
+    let mut len = 0;
+    let mut i = 1;
+    while i < m {
+        if pattern[i] == pattern[len] {
+            len += 1;
+            lps[i] = len;
+            i += 1;
+        } else if len > 0 {
+            // Fall back to the next shorter border; i stays put
+            len = lps[len - 1];
+        } else {
+            lps[i] = 0;
+            i += 1;
+        }
+    }
+
+    lps
+}
+
+/// Find all occurrences of pattern in text using KMP
+/// Returns vector of starting indices where pattern is found
+fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    if pattern.is_empty() || text.len() < pattern.len() {
+        return vec![];
+    }
+
+    let lps = build_lps(pattern);
+    let text: Vec<char> = text.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    let n = text.len();
+    let m = pattern.len();
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n {
+        if text[i] == pattern[j] {
+            i += 1;
+            j += 1;
+            if j == m {
+                result.push(i - j);
+                j = lps[j - 1];
+            }
+        } else if j > 0 {
+            j = lps[j - 1];
+        } else {
+            i += 1;
+        }
+    }
+
+    result
+}
+
+/// Find first occurrence of pattern in text
+fn kmp_find_first(text: &str, pattern: &str) -> Option<usize> {
+    if pattern.is_empty() {
+        return None;
+    }
+
+    let lps = build_lps(pattern);
+    let pattern: Vec<char> = pattern.chars().collect();
+    let mut j = 0;
+    for (i, c) in text.chars().enumerate() {
+        while j > 0 && c != pattern[j] {
+            j = lps[j - 1];
+        }
+        if c == pattern[j] {
+            j += 1;
+        }
+        // Stop at the first full match instead of scanning the whole text
+        if j == pattern.len() {
+            return Some(i + 1 - j);
+        }
+    }
+
+    None
+}
+
+/// Count occurrences of pattern in text
+fn kmp_count(text: &str, pattern: &str) -> usize {
+    kmp_search(text, pattern).len()
+}
+
+/// Check if pattern is a substring of text
+fn contains(text: &str, pattern: &str) -> bool {
+    kmp_find_first(text, pattern).is_some()
+}
+
+fn main() {
+    let text = "ABABDABACDABABCABAB";
+    let pattern = "ABABCABAB";
+
+    println!("Text: {}", text);
+    println!("Pattern: {}", pattern);
+
+    let lps = build_lps(pattern);
+    println!("\nLPS array: {:?}", lps);
+
+    let matches = kmp_search(text, pattern);
+    println!("Pattern found at indices: {:?}", matches);
+
+    // More examples
+    let text2 = "AAAAABAAABA";
+    let pattern2 = "AAAB";
+    println!("\nText: {}", text2);
+    println!("Pattern: {}", pattern2);
+    println!("LPS: {:?}", build_lps(pattern2));
+    println!("Matches: {:?}", kmp_search(text2, pattern2));
+
+    // Overlapping matches
+    let text3 = "AAAAA";
+    let pattern3 = "AAA";
+    println!("\nText: {}", text3);
+    println!("Pattern: {}", pattern3);
+    println!("Overlapping matches: {:?}", kmp_search(text3, pattern3));
+
+    println!("First match: {:?}", kmp_find_first(text3, pattern3));
+    println!("Match count: {}", kmp_count(text3, pattern3));
+    println!("Contains \"AAB\": {}", contains(text2, "AAB"));
+}
@@ -0,0 +1,147 @@
+// Reference solution for exercise 146: atoi Implementation (String to Integer)
+
+///
+/// Requirements:
+/// 1. Discard leading whitespace
+/// 2. Handle optional '+' or '-' sign
+/// 3. Read digits until non-digit or end of string
+/// 4. Convert digits to integer
+/// 5. Handle overflow: clamp to i32::MAX or i32::MIN
+/// 6. Return 0 for invalid input
+///
+/// Algorithm:
+/// 1. Skip leading whitespace
+/// 2. Check for sign
+/// 3. Process digits, building result
+/// 4. Check for overflow at each step
+/// 5. Apply sign and return
+fn my_atoi(s: &str) -> i32 {
+    let bytes = s.as_bytes();
+    let n = bytes.len();
+    let mut i = 0;
+
+    while i < n && is_whitespace(bytes[i]) {
+        i += 1;
+    }
+
+    let negative = i < n && bytes[i] == b'-';
+    if i < n && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+
+    // Accumulate as a negative number so i32::MIN fits without overflow
+    let mut result: i32 = 0;
+    while i < n && is_digit(bytes[i]) {
+        let digit = to_digit(bytes[i]);
+        result = match result.checked_mul(10).and_then(|r| r.checked_sub(digit)) {
+            Some(r) => r,
+            None => return if negative { i32::MIN } else { i32::MAX },
+        };
+        i += 1;
+    }
+
+    if negative {
+        result
+    } else {
+        result.checked_neg().unwrap_or(i32::MAX)
+    }
+}
+
+/// Helper function to check if a character is whitespace
+fn is_whitespace(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}
+
+/// Helper function to check if a character is a digit
+fn is_digit(c: u8) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Convert character to digit
+fn to_digit(c: u8) -> i32 {
+    (c - b'0') as i32
+}
+
+/// Parse string to i64 first, then clamp to i32
+/// Alternative implementation that avoids manual overflow checking
+fn my_atoi_i64(s: &str) -> i32 {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() && is_whitespace(bytes[i]) {
+        i += 1;
+    }
+
+    let mut sign = 1i64;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        if bytes[i] == b'-' {
+            sign = -1;
+        }
+        i += 1;
+    }
+
+    let limit = i32::MAX as i64 + 1;
+    let mut result = 0i64;
+    while i < bytes.len() && is_digit(bytes[i]) {
+        // Saturate so long digit runs cannot overflow the i64
+        result = (result * 10 + to_digit(bytes[i]) as i64).min(limit);
+        i += 1;
+    }
+
+    (sign * result).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Handle floating point numbers and return (integer_part, fractional_part)
+fn parse_float(s: &str) -> Option<(i32, u32)> {
+    let s = s.trim();
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (int_str, frac_str) = match rest.split_once('.') {
+        Some((int_str, frac_str)) if !frac_str.is_empty() => (int_str, frac_str),
+        Some(_) => return None,
+        None => (rest, "0"),
+    };
+    if int_str.is_empty() || !int_str.bytes().chain(frac_str.bytes()).all(is_digit) {
+        return None;
+    }
+
+    let mut int_part: i32 = 0;
+    for b in int_str.bytes() {
+        int_part = int_part.checked_mul(10)?.checked_add(to_digit(b))?;
+    }
+    let mut frac_part: u32 = 0;
+    for b in frac_str.bytes() {
+        frac_part = frac_part.checked_mul(10)?.checked_add(to_digit(b) as u32)?;
+    }
+
+    Some((if negative { -int_part } else { int_part }, frac_part))
+}
+
+fn main() {
+    let test_cases = vec![
+        "42",
+        "   -42",
+        "4193 with words",
+        "words and 987",
+        "-91283472332", // Overflow
+        "91283472332",  // Overflow
+        "+1",
+        "+-12",
+        "00000-42a1234",
+        "  0000000000012345678",
+    ];
+
+    println!("atoi Results:\n");
+    for s in test_cases {
+        let result = my_atoi(s);
+        println!("'{}' -> {}", s, result);
+    }
+
+    println!("\nWith i64 intermediate: '{}' -> {}", "-91283472332", my_atoi_i64("-91283472332"));
+    for s in ["123.456", "-7.25", "42", "1.2.3"] {
+        println!("parse_float('{}') -> {:?}", s, parse_float(s));
+    }
+}
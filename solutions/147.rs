@@ -0,0 +1,127 @@
+// Reference solution for exercise 147: Reverse Integer
+
+/// Reverse a 32-bit signed integer
+///
+/// Requirements:
+/// - Reverse digits: 123 -> 321, -123 -> -321
+/// - Return 0 if reversed integer overflows i32
+/// - Handle trailing zeros: 1200 -> 21
+///
+/// Algorithm (Mathematical):
+/// 1. Handle negative numbers
+/// 2. While x != 0:
+///    - Extract last digit: digit = x % 10
+///    - Remove last digit: x /= 10
+///    - Check for overflow before adding digit
+///    - result = result * 10 + digit
+fn reverse(x: i32) -> i32 {
+    let mut x = x;
+    let mut result: i32 = 0;
+    while x != 0 {
+        // `%` keeps the sign of x, so negative inputs build a negative result
+        let digit = x % 10;
+        x /= 10;
+        result = match result.checked_mul(10).and_then(|r| r.checked_add(digit)) {
+            Some(r) => r,
+            None => return 0,
+        };
+    }
+    result
+}
+
+/// Reverse using string conversion (easier but less efficient)
+fn reverse_string(x: i32) -> i32 {
+    let digits: String = x.unsigned_abs().to_string().chars().rev().collect();
+    match digits.parse::<i32>() {
+        Ok(r) if x < 0 => -r,
+        Ok(r) => r,
+        Err(_) => 0,
+    }
+}
+
+/// Check if reversed number would overflow
+fn would_overflow(x: i32) -> bool {
+    let mut x = x as i64;
+    let mut result = 0i64;
+    while x != 0 {
+        result = result * 10 + x % 10;
+        x /= 10;
+    }
+    result > i32::MAX as i64 || result < i32::MIN as i64
+}
+
+/// Reverse digits of a positive number only
+fn reverse_positive(mut x: i32) -> i32 {
+    let mut result: i32 = 0;
+    while x > 0 {
+        result = match result.checked_mul(10).and_then(|r| r.checked_add(x % 10)) {
+            Some(r) => r,
+            None => return 0,
+        };
+        x /= 10;
+    }
+    result
+}
+
+/// Check if a number is a palindrome
+fn is_palindrome(x: i32) -> bool {
+    if x < 0 || (x % 10 == 0 && x != 0) {
+        return false;
+    }
+
+    // Reverse only the back half so the result can never overflow
+    let mut x = x;
+    let mut half = 0;
+    while x > half {
+        half = half * 10 + x % 10;
+        x /= 10;
+    }
+    x == half || x == half / 10
+}
+
+/// BONUS: Reverse digits in a given base
+fn reverse_in_base(x: i32, base: i32) -> i32 {
+    if base < 2 {
+        return 0;
+    }
+
+    let (base, mut x) = (base as i64, x as i64);
+    let mut result = 0i64;
+    while x != 0 {
+        result = result * base + x % base;
+        if result > i32::MAX as i64 || result < i32::MIN as i64 {
+            return 0;
+        }
+        x /= base;
+    }
+    result as i32
+}
+
+fn main() {
+    let test_cases = vec![
+        123,
+        -123,
+        120,
+        0,
+        1534236469, // This overflows when reversed
+        -2147483412,
+        1463847412, // This overflows
+    ];
+
+    println!("Reverse Integer Results:\n");
+    for x in test_cases {
+        let result = reverse(x);
+        println!("{} -> {}", x, result);
+    }
+
+    // Palindrome check
+    println!("\nPalindrome Check:");
+    for x in vec![121, -121, 10, 12321, 123456] {
+        println!("{} is palindrome: {}", x, is_palindrome(x));
+    }
+
+    println!("\nString reverse of -456: {}", reverse_string(-456));
+    println!("Reversing 1534236469 overflows: {}", would_overflow(1534236469));
+    println!("reverse_positive(1200): {}", reverse_positive(1200));
+    println!("reverse_in_base(13, 2): {}", reverse_in_base(13, 2));
+}
@@ -0,0 +1,151 @@
+// Reference solution for exercise 148: Valid Parentheses (Stack)
+
+/// Check if string of brackets is valid
+///
+/// Valid if:
+/// - Open brackets are closed by the same type
+/// - Open brackets are closed in the correct order
+/// - Every close bracket has a corresponding open bracket
+///
+/// Pairs: () [] {}
+///
+/// Algorithm:
+/// 1. Use a stack to track open brackets
+/// 2. For each character:
+///    - If open bracket: push to stack
+///    - If close bracket: check if stack top matches, if not return false
+/// 3. Return true if stack is empty at end
+fn is_valid(s: &str) -> bool {
+    find_invalid_position(s).is_none()
+}
+
+/// Get the matching opening bracket for a closing bracket
+fn matching_open(close: char) -> Option<char> {
+    match close {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        _ => None,
+    }
+}
+
+/// Check if character is an opening bracket
+fn is_open(c: char) -> bool {
+    matches!(c, '(' | '[' | '{')
+}
+
+/// Check if character is a closing bracket
+fn is_close(c: char) -> bool {
+    matching_open(c).is_some()
+}
+
+/// Find the position of first invalid character
+/// Returns Some(index) if invalid, None if valid
+fn find_invalid_position(s: &str) -> Option<usize> {
+    let mut stack = Vec::new();
+    let mut len = 0;
+
+    for (i, c) in s.chars().enumerate() {
+        len = i + 1;
+        if is_open(c) {
+            stack.push(c);
+        } else if let Some(open) = matching_open(c) {
+            if stack.pop() != Some(open) {
+                return Some(i);
+            }
+        }
+    }
+
+    // Unclosed brackets are reported at the end of the string
+    if stack.is_empty() {
+        None
+    } else {
+        Some(len)
+    }
+}
+
+/// Minimum number of brackets to add to make string valid
+fn min_add_to_make_valid(s: &str) -> usize {
+    let mut open = 0;
+    let mut needed = 0;
+    for c in s.chars() {
+        match c {
+            '(' => open += 1,
+            ')' if open > 0 => open -= 1,
+            ')' => needed += 1,
+            _ => {}
+        }
+    }
+    needed + open
+}
+
+/// Check if string can be valid by removing exactly one bracket
+fn can_be_valid_by_removing_one(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    (0..chars.len()).filter(|&i| is_open(chars[i]) || is_close(chars[i])).any(|i| {
+        let rest: String = chars[..i].iter().chain(&chars[i + 1..]).collect();
+        is_valid(&rest)
+    })
+}
+
+/// Longest valid parentheses substring length
+fn longest_valid_parentheses(s: &str) -> usize {
+    // The stack bottom is the index just before the current valid run
+    let mut stack: Vec<isize> = vec![-1];
+    let mut best = 0;
+
+    for (i, c) in s.chars().enumerate() {
+        let i = i as isize;
+        if c == '(' {
+            stack.push(i);
+            continue;
+        }
+
+        stack.pop();
+        match stack.last() {
+            Some(&start) if c == ')' => best = best.max((i - start) as usize),
+            // An unmatched ')' or any other character starts a new run
+            _ => {
+                stack.clear();
+                stack.push(i);
+            }
+        }
+    }
+
+    best
+}
+
+fn main() {
+    let test_cases = vec![
+        "()",
+        "()[]{}",
+        "(]",
+        "([)]",
+        "{[]}",
+        "",
+        "((",
+        "))",
+        "([{}])",
+    ];
+
+    println!("Valid Parentheses Check:\n");
+    for s in test_cases {
+        let result = is_valid(s);
+        let status = if result { "VALID" } else { "INVALID" };
+        println!("'{}' -> {}", s, status);
+    }
+
+    // Additional examples
+    println!("\nMinimum brackets to add:");
+    for s in vec!["())", "(((", ")", "()"] {
+        println!("'{}' needs {} brackets", s, min_add_to_make_valid(s));
+    }
+
+    println!("\nLongest valid substring:");
+    for s in vec!["(()", ")()())", ""] {
+        println!("'{}' longest valid: {}", s, longest_valid_parentheses(s));
+    }
+
+    println!("\nFirst invalid position in '([)]': {:?}", find_invalid_position("([)]"));
+    println!("'(()))' valid after removing one: {}", can_be_valid_by_removing_one("(()))"));
+}
@@ -0,0 +1,186 @@
+// Reference solution for exercise 149: Merge k Sorted Lists Concept
+
+/// A node in a linked list (for conceptual understanding)
+#[derive(Debug, Clone)]
+struct ListNode {
+    val: i32,
+    next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    fn new(val: i32) -> Self {
+        ListNode { val, next: None }
+    }
+}
+
+/// Build a linked list holding `values` in order
+fn to_linked_list(values: &[i32]) -> Option<Box<ListNode>> {
+    let mut head = None;
+    for &val in values.iter().rev() {
+        let mut node = Box::new(ListNode::new(val));
+        node.next = head;
+        head = Some(node);
+    }
+    head
+}
+
+/// Collect the values of a linked list into a Vec
+fn from_linked_list(mut head: &Option<Box<ListNode>>) -> Vec<i32> {
+    let mut values = Vec::new();
+    while let Some(node) = head {
+        values.push(node.val);
+        head = &node.next;
+    }
+    values
+}
+
+/// For this exercise, we'll work with Vec<Vec<i32>> to represent k sorted lists
+/// This is more practical for testing and understanding
+
+/// Merge k sorted lists using brute force approach
+/// Concatenate all, then sort
+/// Time: O(N log N), Space: O(N)
+fn merge_k_lists_brute_force(lists: Vec<Vec<i32>>) -> Vec<i32> {
+    let mut result: Vec<i32> = lists.into_iter().flatten().collect();
+    result.sort();
+    result
+}
+
+/// Merge k sorted lists using min-heap (priority queue)
+/// Time: O(N log k), Space: O(k)
+///
+/// Algorithm:
+/// 1. Insert first element of each list into min-heap
+/// 2. While heap not empty:
+///    - Extract min element, add to result
+///    - Insert next element from same list (if exists)
+fn merge_k_lists_heap(lists: Vec<Vec<i32>>) -> Vec<i32> {
+    use std::collections::BinaryHeap;
+    use std::cmp::Reverse;
+
+    let mut heap = BinaryHeap::new();
+    for (i, list) in lists.iter().enumerate() {
+        if let Some(&first) = list.first() {
+            heap.push(Reverse((first, i, 0)));
+        }
+    }
+
+    let mut result = Vec::with_capacity(lists.iter().map(Vec::len).sum());
+    while let Some(Reverse((val, i, j))) = heap.pop() {
+        result.push(val);
+        if let Some(&next) = lists[i].get(j + 1) {
+            heap.push(Reverse((next, i, j + 1)));
+        }
+    }
+
+    result
+}
+
+/// Merge k sorted lists using divide and conquer
+/// Recursively merge pairs of lists
+/// Time: O(N log k), Space: O(log k) for recursion
+fn merge_k_lists_divide_conquer(lists: Vec<Vec<i32>>) -> Vec<i32> {
+    fn merge_range(lists: &[Vec<i32>]) -> Vec<i32> {
+        match lists.len() {
+            0 => vec![],
+            1 => lists[0].clone(),
+            n => {
+                let (left, right) = lists.split_at(n / 2);
+                merge_two_lists(&merge_range(left), &merge_range(right))
+            }
+        }
+    }
+
+    merge_range(&lists)
+}
+
+/// Merge two sorted lists (helper function)
+/// Time: O(n + m), Space: O(n + m)
+fn merge_two_lists(list1: &[i32], list2: &[i32]) -> Vec<i32> {
+    let mut result = Vec::with_capacity(list1.len() + list2.len());
+    let (mut i, mut j) = (0, 0);
+
+    while i < list1.len() && j < list2.len() {
+        // `<=` keeps the merge stable
+        if list1[i] <= list2[j] {
+            result.push(list1[i]);
+            i += 1;
+        } else {
+            result.push(list2[j]);
+            j += 1;
+        }
+    }
+
+    result.extend_from_slice(&list1[i..]);
+    result.extend_from_slice(&list2[j..]);
+    result
+}
+
+/// Merge k sorted lists using sequential merging
+/// Merge lists one by one
+/// Time: O(k * N), Space: O(N)
+fn merge_k_lists_sequential(lists: Vec<Vec<i32>>) -> Vec<i32> {
+    lists.iter().fold(Vec::new(), |acc, list| merge_two_lists(&acc, list))
+}
+
+/// Find the kth smallest element in merged array without fully merging
+fn find_kth_smallest(lists: &[Vec<i32>], k: usize) -> Option<i32> {
+    // k is 1-based: the 1st smallest is the minimum
+    let total: usize = lists.iter().map(Vec::len).sum();
+    if k == 0 || k > total {
+        return None;
+    }
+
+    let mut lo = lists.iter().filter_map(|l| l.first()).copied().min()? as i64;
+    let mut hi = lists.iter().filter_map(|l| l.last()).copied().max()? as i64;
+
+    // Smallest value v with at least k elements <= v
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let count: usize = lists
+            .iter()
+            .map(|l| l.partition_point(|&x| x as i64 <= mid))
+            .sum();
+        if count >= k {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    Some(lo as i32)
+}
+
+fn main() {
+    let lists = vec![
+        vec![1, 4, 5],
+        vec![1, 3, 4],
+        vec![2, 6],
+    ];
+
+    println!("Input lists: {:?}\n", lists);
+
+    println!("Brute force: {:?}", merge_k_lists_brute_force(lists.clone()));
+    println!("Min heap:    {:?}", merge_k_lists_heap(lists.clone()));
+    println!("Divide & Conquer: {:?}", merge_k_lists_divide_conquer(lists.clone()));
+    println!("Sequential:  {:?}", merge_k_lists_sequential(lists.clone()));
+
+    // Test with empty lists
+    let lists2 = vec![
+        vec![],
+        vec![1],
+        vec![],
+    ];
+    println!("\nWith empty lists: {:?}", merge_k_lists_heap(lists2));
+
+    // Test merge two lists
+    println!("\nMerge [1,3,5] and [2,4,6]: {:?}",
+        merge_two_lists(&[1, 3, 5], &[2, 4, 6]));
+
+    println!("\n3rd smallest: {:?}", find_kth_smallest(&lists, 3));
+
+    // The same merge works on linked lists by converting at the edges
+    let linked: Vec<_> = lists.iter().map(|l| to_linked_list(l)).collect();
+    let merged = merge_k_lists_heap(linked.iter().map(from_linked_list).collect());
+    println!("Merged linked lists: {:?}", from_linked_list(&to_linked_list(&merged)));
+}
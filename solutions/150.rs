@@ -0,0 +1,166 @@
+// Reference solution for exercise 150: Matrix Rotation (90 degrees)
+
+/// Rotate matrix 90 degrees clockwise IN-PLACE
+///
+/// Algorithm:
+/// 1. Transpose the matrix (swap elements across diagonal)
+/// 2. Reverse each row
+///
+/// Example:
+/// 1 2 3     1 4 7     7 4 1
+/// 4 5 6  -> 2 5 8  -> 8 5 2
+/// 7 8 9     3 6 9     9 6 3
+fn rotate_clockwise(matrix: &mut [Vec<i32>]) {
+    if matrix.is_empty() || matrix.len() == 1 {
+        return;
+    }
+
+    let n = matrix.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let tmp = matrix[i][j];
+            matrix[i][j] = matrix[j][i];
+            matrix[j][i] = tmp;
+        }
+    }
+
+    for row in matrix.iter_mut() {
+        row.reverse();
+    }
+}
+
+/// Rotate matrix 90 degrees counter-clockwise IN-PLACE
+///
+/// Algorithm:
+/// 1. Transpose the matrix
+/// 2. Reverse each column
+fn rotate_counter_clockwise(matrix: &mut [Vec<i32>]) {
+    if matrix.is_empty() || matrix.len() == 1 {
+        return;
+    }
+
+    let n = matrix.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let tmp = matrix[i][j];
+            matrix[i][j] = matrix[j][i];
+            matrix[j][i] = tmp;
+        }
+    }
+
+    // Reversing every column is the same as reversing the row order
+    matrix.reverse();
+}
+
+/// Rotate matrix 180 degrees
+fn rotate_180(matrix: &mut [Vec<i32>]) {
+    matrix.reverse();
+    for row in matrix.iter_mut() {
+        row.reverse();
+    }
+}
+
+/// Rotate matrix clockwise and return new matrix
+/// Does not modify original, creates new matrix
+fn rotate_clockwise_new(matrix: &[Vec<i32>]) -> Vec<Vec<i32>> {
+    let n = matrix.len();
+    (0..n).map(|i| (0..n).map(|j| matrix[n - 1 - j][i]).collect()).collect()
+}
+
+/// Rotate matrix layer by layer (alternative approach)
+/// Rotate elements in each "onion layer" of the matrix
+fn rotate_layer_by_layer(matrix: &mut [Vec<i32>]) {
+    let n = matrix.len();
+    for layer in 0..n / 2 {
+        let last = n - 1 - layer;
+        for i in layer..last {
+            let offset = i - layer;
+            let top = matrix[layer][i];
+
+            // left -> top, bottom -> left, right -> bottom, top -> right
+            matrix[layer][i] = matrix[last - offset][layer];
+            matrix[last - offset][layer] = matrix[last][last - offset];
+            matrix[last][last - offset] = matrix[i][last];
+            matrix[i][last] = top;
+        }
+    }
+}
+
+/// Check if matrix is square
+fn is_square(matrix: &[Vec<i32>]) -> bool {
+    let n = matrix.len();
+    matrix.iter().all(|row| row.len() == n)
+}
+
+/// Print matrix in a readable format
+fn print_matrix(matrix: &[Vec<i32>]) {
+    for row in matrix {
+        let cells: Vec<String> = row.iter().map(|x| format!("{:3}", x)).collect();
+        println!("{}", cells.join(" "));
+    }
+}
+
+/// Rotate a rectangular matrix (not square)
+/// Returns a new matrix since rectangular can't be rotated in-place
+fn rotate_rectangle(matrix: &[Vec<i32>]) -> Vec<Vec<i32>> {
+    let m = matrix.len();
+    let n = matrix.first().map_or(0, Vec::len);
+    (0..n).map(|i| (0..m).map(|j| matrix[m - 1 - j][i]).collect()).collect()
+}
+
+fn main() {
+    // Test 3x3 matrix
+    let mut matrix1 = vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 9],
+    ];
+
+    println!("Original matrix:");
+    print_matrix(&matrix1);
+
+    rotate_clockwise(&mut matrix1);
+    println!("\nRotated 90° clockwise:");
+    print_matrix(&matrix1);
+
+    rotate_counter_clockwise(&mut matrix1);
+    println!("\nRotated 90° counter-clockwise (back to original):");
+    print_matrix(&matrix1);
+
+    rotate_180(&mut matrix1);
+    println!("\nRotated 180°:");
+    print_matrix(&matrix1);
+
+    // Test with larger matrix
+    let mut matrix2 = vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+        vec![13, 14, 15, 16],
+    ];
+
+    println!("\n\nOriginal 4x4 matrix:");
+    print_matrix(&matrix2);
+
+    let rotated_new = rotate_clockwise_new(&matrix2);
+    println!("\nRotated 90° clockwise (new matrix):");
+    print_matrix(&rotated_new);
+
+    // Layer by layer rotation
+    let mut matrix3 = vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 9],
+    ];
+    rotate_layer_by_layer(&mut matrix3);
+    println!("\nRotated using layer-by-layer:");
+    print_matrix(&matrix3);
+
+    println!("\n4x4 is square: {}", is_square(&matrix2));
+    rotate_clockwise(&mut matrix2);
+    println!("In-place matches new matrix: {}", matrix2 == rotated_new);
+
+    let rect = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    println!("\n2x3 rotated into 3x2:");
+    print_matrix(&rotate_rectangle(&rect));
+}
@@ -0,0 +1,269 @@
+// Reference solution for exercise 321: Binary Tree - AVL Tree
+
+use std::cmp::Ordering;
+
+fn main() {
+    println!("=== Binary Tree - AVL Tree ===\n");
+
+    // Sorted input is the worst case for a plain BST: every node becomes
+    // the right child of the one before, and the tree is a list
+    let mut tree = AvlTree::new();
+    println!("Inserting 1..=1000 in order");
+    for value in 1..=1000 {
+        tree.insert(value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("(A plain BST would have height 1000)");
+    assert_eq!(tree.height(), 10);
+
+    println!("\nRemoving the even numbers");
+    for value in (2..=1000).step_by(2) {
+        tree.remove(&value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Contains 500: {}", tree.contains(&500));
+    println!("Contains 501: {}", tree.contains(&501));
+
+    println!("\nRotations for each unbalanced shape:");
+    for order in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+        let mut small = AvlTree::new();
+        for value in order {
+            small.insert(value);
+        }
+        println!(
+            "  insert {:?} -> inorder {:?}, height {}",
+            order,
+            small.inorder(),
+            small.height()
+        );
+    }
+
+    println!("\n✓ AVL tree completed successfully!");
+}
+
+type Link<T> = Option<Box<TreeNode<T>>>;
+
+/// AVL Tree Node
+///
+/// Like the nodes of exercises 122-124, plus the height of the subtree
+/// rooted here, kept up to date so balancing never has to walk the tree.
+#[derive(Debug)]
+struct TreeNode<T> {
+    value: T,
+    /// Nodes on the longest path from here down to a leaf; 1 for a leaf.
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Height of a subtree that may be empty.
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+impl<T: Ord> TreeNode<T> {
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Recomputes the height from the children's.
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// Left height minus right height; AVL keeps it within -1..=1.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+
+    /// Lifts the left child into this node's place:
+    ///
+    /// ```text
+    ///       self           pivot
+    ///      /    \         /     \
+    ///   pivot    C  ->   A      self
+    ///   /   \                  /    \
+    ///  A     B                B      C
+    /// ```
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.left.take().expect("rotate_right needs a left child");
+        self.left = pivot.right.take();
+        self.update_height();
+        pivot.right = Some(self);
+        pivot.update_height();
+        pivot
+    }
+
+    /// The mirror image of `rotate_right`.
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.right.take().expect("rotate_left needs a right child");
+        self.right = pivot.left.take();
+        self.update_height();
+        pivot.left = Some(self);
+        pivot.update_height();
+        pivot
+    }
+
+    /// Restores the balance of a node whose children are balanced and
+    /// differ in height by at most 2, and returns the subtree's new root.
+    fn rebalance(mut self: Box<Self>) -> Box<Self> {
+        self.update_height();
+        match self.balance_factor() {
+            2 => {
+                // Left-right: turn it into left-left first
+                if let Some(left) = self.left.take() {
+                    self.left = Some(if left.balance_factor() < 0 {
+                        left.rotate_left()
+                    } else {
+                        left
+                    });
+                }
+                self.rotate_right()
+            }
+            -2 => {
+                if let Some(right) = self.right.take() {
+                    self.right = Some(if right.balance_factor() > 0 {
+                        right.rotate_right()
+                    } else {
+                        right
+                    });
+                }
+                self.rotate_left()
+            }
+            _ => self,
+        }
+    }
+
+    /// Inserts into the subtree rooted at `link`, returning its new root.
+    /// Duplicates are ignored.
+    fn insert(link: Link<T>, value: T) -> Box<Self> {
+        let Some(mut node) = link else {
+            return Box::new(TreeNode::new(value));
+        };
+        match value.cmp(&node.value) {
+            Ordering::Less => node.left = Some(Self::insert(node.left.take(), value)),
+            Ordering::Greater => node.right = Some(Self::insert(node.right.take(), value)),
+            Ordering::Equal => return node,
+        }
+        node.rebalance()
+    }
+
+    /// Removes `value` from the subtree rooted at `link`, returning its new
+    /// root; `removed` says whether it was there.
+    fn remove(link: Link<T>, value: &T, removed: &mut bool) -> Link<T> {
+        let mut node = link?;
+        match value.cmp(&node.value) {
+            Ordering::Less => node.left = Self::remove(node.left.take(), value, removed),
+            Ordering::Greater => node.right = Self::remove(node.right.take(), value, removed),
+            Ordering::Equal => {
+                *removed = true;
+                match (node.left.take(), node.right.take()) {
+                    (None, only) | (only, None) => return only,
+                    (left, Some(right)) => {
+                        // The smallest value on the right takes this node's place
+                        let (rest, successor) = right.take_min();
+                        node.value = successor;
+                        node.left = left;
+                        node.right = rest;
+                    }
+                }
+            }
+        }
+        Some(node.rebalance())
+    }
+
+    /// Removes the smallest value, returning what is left and the value.
+    fn take_min(mut self: Box<Self>) -> (Link<T>, T) {
+        match self.left.take() {
+            None => {
+                let node = *self;
+                (node.right, node.value)
+            }
+            Some(left) => {
+                let (rest, min) = left.take_min();
+                self.left = rest;
+                (Some(self.rebalance()), min)
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        1 + self.left.as_ref().map_or(0, |n| n.size()) + self.right.as_ref().map_or(0, |n| n.size())
+    }
+
+    fn inorder<'a>(&'a self, result: &mut Vec<&'a T>) {
+        if let Some(left) = &self.left {
+            left.inorder(result);
+        }
+        result.push(&self.value);
+        if let Some(right) = &self.right {
+            right.inorder(result);
+        }
+    }
+}
+
+/// AVL Tree
+///
+/// A binary search tree that rotates after every insertion and removal so
+/// that no node's subtrees differ in height by more than one.
+#[derive(Debug)]
+struct AvlTree<T> {
+    root: Link<T>,
+}
+
+impl<T: Ord> AvlTree<T> {
+    fn new() -> Self {
+        AvlTree { root: None }
+    }
+
+    /// Insert a value; duplicates are ignored
+    fn insert(&mut self, value: T) {
+        self.root = Some(TreeNode::insert(self.root.take(), value));
+    }
+
+    /// Remove a value, returning whether it was in the tree
+    fn remove(&mut self, value: &T) -> bool {
+        let mut removed = false;
+        self.root = TreeNode::remove(self.root.take(), value, &mut removed);
+        removed
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    fn size(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.size())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Height of the tree; 0 when empty. Always O(log n).
+    fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// The values in sorted order
+    fn inorder(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        if let Some(root) = &self.root {
+            root.inorder(&mut result);
+        }
+        result
+    }
+}
@@ -0,0 +1,313 @@
+// Reference solution for exercise 322: Binary Tree - Red-Black Tree
+
+use std::cmp::Ordering;
+
+fn main() {
+    println!("=== Binary Tree - Red-Black Tree ===\n");
+
+    let mut tree = RedBlackTree::new();
+    println!("Inserting 1..=1000 in order");
+    for value in 1..=1000 {
+        tree.insert(value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Black height: {}", tree.black_height());
+    println!("(A plain BST would have height 1000)");
+
+    println!("\nRemoving the multiples of 3");
+    for value in (3..=1000).step_by(3) {
+        tree.remove(&value);
+    }
+    println!("Size: {}, height: {}", tree.size(), tree.height());
+    println!("Contains 300: {}", tree.contains(&300));
+    println!("Contains 301: {}", tree.contains(&301));
+
+    println!("\n✓ Red-black tree completed successfully!");
+}
+
+type Link<T> = Option<Box<TreeNode<T>>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Black,
+}
+
+impl Color {
+    fn flip(self) -> Color {
+        match self {
+            Color::Red => Color::Black,
+            Color::Black => Color::Red,
+        }
+    }
+}
+
+/// Red-Black Tree Node
+///
+/// Like the nodes of exercises 122-124, plus the color of the link from
+/// the parent. A red node is glued to its parent: together they stand for
+/// one node of a 2-3 tree, whose leaves are all at the same depth.
+#[derive(Debug)]
+struct TreeNode<T> {
+    value: T,
+    color: Color,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Whether a subtree's root is red; empty subtrees count as black.
+fn is_red<T>(link: &Link<T>) -> bool {
+    link.as_ref().is_some_and(|node| node.color == Color::Red)
+}
+
+/// Whether the left child of a subtree's root is red.
+fn is_left_red<T>(link: &Link<T>) -> bool {
+    link.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+impl<T: Ord> TreeNode<T> {
+    /// New nodes are red: they join the 2-3 node above them.
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            color: Color::Red,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Turns a red right child into a red left child.
+    fn rotate_left(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.right.take().expect("rotate_left needs a right child");
+        self.right = pivot.left.take();
+        pivot.color = self.color;
+        self.color = Color::Red;
+        pivot.left = Some(self);
+        pivot
+    }
+
+    /// Turns a red left child into a red right child.
+    fn rotate_right(mut self: Box<Self>) -> Box<Self> {
+        let mut pivot = self.left.take().expect("rotate_right needs a left child");
+        self.left = pivot.right.take();
+        pivot.color = self.color;
+        self.color = Color::Red;
+        pivot.right = Some(self);
+        pivot
+    }
+
+    /// Flips this node's color and its children's: splits a 4-node in two
+    /// and passes the middle up, or the reverse.
+    fn flip_colors(&mut self) {
+        self.color = self.color.flip();
+        for child in [&mut self.left, &mut self.right].into_iter().flatten() {
+            child.color = child.color.flip();
+        }
+    }
+
+    /// Restores the left-leaning shape on the way back up.
+    fn fix_up(mut self: Box<Self>) -> Box<Self> {
+        if is_red(&self.right) && !is_red(&self.left) {
+            self = self.rotate_left();
+        }
+        if is_red(&self.left) && is_left_red(&self.left) {
+            self = self.rotate_right();
+        }
+        if is_red(&self.left) && is_red(&self.right) {
+            self.flip_colors();
+        }
+        self
+    }
+
+    /// Inserts into the subtree rooted at `link`, returning its new root.
+    /// Duplicates are ignored.
+    fn insert(link: Link<T>, value: T) -> Box<Self> {
+        let Some(mut node) = link else {
+            return Box::new(TreeNode::new(value));
+        };
+        match value.cmp(&node.value) {
+            Ordering::Less => node.left = Some(Self::insert(node.left.take(), value)),
+            Ordering::Greater => node.right = Some(Self::insert(node.right.take(), value)),
+            Ordering::Equal => return node,
+        }
+        node.fix_up()
+    }
+
+    /// Makes the left child or one of its children red, so that a removal
+    /// on the left never takes out a lone black node.
+    fn move_red_left(mut self: Box<Self>) -> Box<Self> {
+        self.flip_colors();
+        if is_left_red(&self.right) {
+            self.right = self.right.take().map(TreeNode::rotate_right);
+            self = self.rotate_left();
+            self.flip_colors();
+        }
+        self
+    }
+
+    /// The mirror image of `move_red_left`.
+    fn move_red_right(mut self: Box<Self>) -> Box<Self> {
+        self.flip_colors();
+        if is_left_red(&self.left) {
+            self = self.rotate_right();
+            self.flip_colors();
+        }
+        self
+    }
+
+    /// Removes the smallest value, returning what is left and the value.
+    /// The node or its left child must be red.
+    fn take_min(mut self: Box<Self>) -> (Link<T>, T) {
+        // In a left-leaning tree a node without a left child is a leaf
+        if self.left.is_none() {
+            return (None, self.value);
+        }
+        if !is_red(&self.left) && !is_left_red(&self.left) {
+            self = self.move_red_left();
+        }
+        let left = self.left.take().expect("the left child is still there");
+        let (rest, min) = left.take_min();
+        self.left = rest;
+        (Some(self.fix_up()), min)
+    }
+
+    /// Removes `value`, which must be in the subtree, returning its new
+    /// root. The node or its left child must be red.
+    fn remove(mut self: Box<Self>, value: &T) -> Link<T> {
+        if *value < self.value {
+            if !is_red(&self.left) && !is_left_red(&self.left) {
+                self = self.move_red_left();
+            }
+            let left = self.left.take().expect("value is in the left subtree");
+            self.left = left.remove(value);
+        } else {
+            if is_red(&self.left) {
+                self = self.rotate_right();
+            }
+            if *value == self.value && self.right.is_none() {
+                return None;
+            }
+            if !is_red(&self.right) && !is_left_red(&self.right) {
+                self = self.move_red_right();
+            }
+            let right = self.right.take().expect("value is in the right subtree");
+            if *value == self.value {
+                // The smallest value on the right takes this node's place
+                let (rest, successor) = right.take_min();
+                self.value = successor;
+                self.right = rest;
+            } else {
+                self.right = right.remove(value);
+            }
+        }
+        Some(self.fix_up())
+    }
+
+    fn size(&self) -> usize {
+        1 + self.left.as_ref().map_or(0, |n| n.size()) + self.right.as_ref().map_or(0, |n| n.size())
+    }
+
+    fn height(&self) -> usize {
+        let left = self.left.as_ref().map_or(0, |n| n.height());
+        let right = self.right.as_ref().map_or(0, |n| n.height());
+        1 + left.max(right)
+    }
+
+    fn inorder<'a>(&'a self, result: &mut Vec<&'a T>) {
+        if let Some(left) = &self.left {
+            left.inorder(result);
+        }
+        result.push(&self.value);
+        if let Some(right) = &self.right {
+            right.inorder(result);
+        }
+    }
+}
+
+/// Red-Black Tree
+///
+/// A left-leaning red-black tree: a binary search tree in which no red
+/// node has a red child and every path from the root down to an empty
+/// subtree passes the same number of black nodes. So no path is more than
+/// twice as long as another, and the height stays below 2 log2(n + 1).
+#[derive(Debug)]
+struct RedBlackTree<T> {
+    root: Link<T>,
+}
+
+impl<T: Ord> RedBlackTree<T> {
+    fn new() -> Self {
+        RedBlackTree { root: None }
+    }
+
+    /// Insert a value; duplicates are ignored
+    fn insert(&mut self, value: T) {
+        let mut root = TreeNode::insert(self.root.take(), value);
+        root.color = Color::Black;
+        self.root = Some(root);
+    }
+
+    /// Remove a value, returning whether it was in the tree
+    fn remove(&mut self, value: &T) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+        let Some(mut root) = self.root.take() else {
+            return false;
+        };
+        // Removal needs the root or one of its children to be red
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = Color::Red;
+        }
+        self.root = root.remove(value);
+        if let Some(root) = &mut self.root {
+            root.color = Color::Black;
+        }
+        true
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    fn size(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.size())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Height of the tree; 0 when empty.
+    fn height(&self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.height())
+    }
+
+    /// Black nodes on the path down the left spine, the same on every path
+    fn black_height(&self) -> usize {
+        let mut count = 0;
+        let mut current = &self.root;
+        while let Some(node) = current {
+            count += usize::from(node.color == Color::Black);
+            current = &node.left;
+        }
+        count
+    }
+
+    /// The values in sorted order
+    fn inorder(&self) -> Vec<&T> {
+        let mut result = Vec::new();
+        if let Some(root) = &self.root {
+            root.inorder(&mut result);
+        }
+        result
+    }
+}
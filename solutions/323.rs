@@ -0,0 +1,1031 @@
+// Reference solution for exercise 323: Linked List - Doubly Linked
+
+fn main() {
+    println!("=== Doubly Linked List ===\n");
+
+    println!("--- Rc<RefCell<Node>> and Weak ---");
+    let mut list: rc::DoublyLinkedList<i32> = (1..=5).collect();
+    list.push_front(0);
+    println!("List: {:?}", list);
+    println!("Backwards: {:?}", list.iter().rev().collect::<Vec<_>>());
+    let tail = list.split_off(3);
+    println!("split_off(3): {:?} and {:?}", list, tail);
+
+    println!("\n--- NonNull raw pointers ---");
+    let mut list: raw::DoublyLinkedList<i32> = (1..=5).collect();
+    let mut cursor = list.cursor_front_mut();
+    while let Some(value) = cursor.current() {
+        if *value % 2 == 0 {
+            cursor.remove_current();
+        } else {
+            *value *= 10;
+            cursor.move_next();
+        }
+    }
+    println!("Odd values, times ten: {:?}", list);
+    let mut more: raw::DoublyLinkedList<i32> = (6..=8).collect();
+    list.append(&mut more);
+    println!("After append: {:?} (other list: {:?})", list, more);
+    println!("Back to front: {:?}", list.iter().rev().collect::<Vec<_>>());
+
+    println!("\n✓ Doubly linked list completed successfully!");
+}
+
+mod rc {
+    //! The list in safe Rust: each node owns the next one through an `Rc` and
+    //! points back at the previous one through a `Weak`, so the two directions
+    //! never form a cycle of strong references that could not be freed.
+    //!
+    //! `RefCell` moves the borrow checks to run time. The price is that a
+    //! borrow of a value cannot outlive the guard that `RefCell::borrow`
+    //! returns, so `front` hands out a `Ref` and `iter` has to clone.
+
+    #![forbid(unsafe_code)]
+
+    use std::cell::{Ref, RefCell, RefMut};
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::rc::{Rc, Weak};
+
+    type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+    struct Node<T> {
+        value: T,
+        next: Link<T>,
+        prev: Option<Weak<RefCell<Node<T>>>>,
+    }
+
+    impl<T> Node<T> {
+        fn new(value: T) -> Rc<RefCell<Node<T>>> {
+            Rc::new(RefCell::new(Node {
+                value,
+                next: None,
+                prev: None,
+            }))
+        }
+    }
+
+    /// Takes the value out of a node nothing else points to any more.
+    fn into_value<T>(node: Rc<RefCell<Node<T>>>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(cell) => cell.into_inner().value,
+            Err(_) => panic!("an unlinked node is still shared"),
+        }
+    }
+
+    pub struct DoublyLinkedList<T> {
+        head: Link<T>,
+        tail: Link<T>,
+        len: usize,
+    }
+
+    impl<T> DoublyLinkedList<T> {
+        pub fn new() -> Self {
+            DoublyLinkedList {
+                head: None,
+                tail: None,
+                len: 0,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        pub fn push_front(&mut self, value: T) {
+            let node = Node::new(value);
+            match self.head.take() {
+                Some(old) => {
+                    old.borrow_mut().prev = Some(Rc::downgrade(&node));
+                    node.borrow_mut().next = Some(old);
+                }
+                None => self.tail = Some(Rc::clone(&node)),
+            }
+            self.head = Some(node);
+            self.len += 1;
+        }
+
+        pub fn push_back(&mut self, value: T) {
+            let node = Node::new(value);
+            match self.tail.take() {
+                Some(old) => {
+                    node.borrow_mut().prev = Some(Rc::downgrade(&old));
+                    old.borrow_mut().next = Some(Rc::clone(&node));
+                }
+                None => self.head = Some(Rc::clone(&node)),
+            }
+            self.tail = Some(node);
+            self.len += 1;
+        }
+
+        pub fn pop_front(&mut self) -> Option<T> {
+            let old = self.head.take()?;
+            match old.borrow_mut().next.take() {
+                Some(next) => {
+                    next.borrow_mut().prev = None;
+                    self.head = Some(next);
+                }
+                None => self.tail = None,
+            }
+            self.len -= 1;
+            Some(into_value(old))
+        }
+
+        pub fn pop_back(&mut self) -> Option<T> {
+            let old = self.tail.take()?;
+            match old.borrow_mut().prev.take().and_then(|prev| prev.upgrade()) {
+                Some(prev) => {
+                    prev.borrow_mut().next = None;
+                    self.tail = Some(prev);
+                }
+                None => self.head = None,
+            }
+            self.len -= 1;
+            Some(into_value(old))
+        }
+
+        pub fn front(&self) -> Option<Ref<'_, T>> {
+            self.head
+                .as_ref()
+                .map(|node| Ref::map(node.borrow(), |node| &node.value))
+        }
+
+        pub fn back(&self) -> Option<Ref<'_, T>> {
+            self.tail
+                .as_ref()
+                .map(|node| Ref::map(node.borrow(), |node| &node.value))
+        }
+
+        pub fn front_mut(&mut self) -> Option<RefMut<'_, T>> {
+            self.head
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        pub fn back_mut(&mut self) -> Option<RefMut<'_, T>> {
+            self.tail
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        /// Moves every element of `other` to the end of this list, in O(1).
+        pub fn append(&mut self, other: &mut Self) {
+            let Some(other_head) = other.head.take() else {
+                return;
+            };
+            match self.tail.take() {
+                Some(tail) => {
+                    other_head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+                    tail.borrow_mut().next = Some(other_head);
+                }
+                None => self.head = Some(other_head),
+            }
+            self.tail = other.tail.take();
+            self.len += std::mem::take(&mut other.len);
+        }
+
+        /// Splits the list in two at `at`: this list keeps the first `at`
+        /// elements and the rest are returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `at > len`.
+        pub fn split_off(&mut self, at: usize) -> Self {
+            assert!(
+                at <= self.len,
+                "cannot split off at {at}, the list has {}",
+                self.len
+            );
+            if at == 0 {
+                return std::mem::take(self);
+            }
+            if at == self.len {
+                return Self::new();
+            }
+            // Walk to the last node that stays
+            let mut last = self.head.clone();
+            for _ in 1..at {
+                last = last.and_then(|node| node.borrow().next.clone());
+            }
+            let last = last.expect("at is within the list");
+            let rest = last.borrow_mut().next.take().expect("at is before the end");
+            rest.borrow_mut().prev = None;
+            let split = DoublyLinkedList {
+                head: Some(rest),
+                tail: self.tail.replace(last),
+                len: self.len - at,
+            };
+            self.len = at;
+            split
+        }
+
+        pub fn clear(&mut self) {
+            while self.pop_front().is_some() {}
+        }
+
+        /// A cursor on the first element, or on the ghost if the list is empty.
+        pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.head.clone(),
+                index: 0,
+                list: self,
+            }
+        }
+
+        /// A cursor on the last element, or on the ghost if the list is empty.
+        pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.tail.clone(),
+                index: self.len.saturating_sub(1),
+                list: self,
+            }
+        }
+    }
+
+    impl<T: Clone> DoublyLinkedList<T> {
+        /// Clones of the values, front to back. A `Ref` cannot outlive the
+        /// `RefCell` guard of the node it came from, so they cannot be borrowed.
+        pub fn iter(&self) -> Iter<'_, T> {
+            Iter {
+                front: self.head.clone(),
+                back: self.tail.clone(),
+                len: self.len,
+                list: PhantomData,
+            }
+        }
+    }
+
+    impl<T> Default for DoublyLinkedList<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for DoublyLinkedList<T> {
+        /// Frees the nodes one at a time; dropping the head would free the
+        /// rest recursively and overflow the stack on a long list.
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T> FromIterator<T> for DoublyLinkedList<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            let mut list = Self::new();
+            list.extend(iter);
+            list
+        }
+    }
+
+    impl<T> Extend<T> for DoublyLinkedList<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for value in iter {
+                self.push_back(value);
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut list = f.debug_list();
+            let mut current = self.head.clone();
+            while let Some(node) = current {
+                list.entry(&node.borrow().value);
+                current = node.borrow().next.clone();
+            }
+            list.finish()
+        }
+    }
+
+    /// Iterator over clones of the values, from either end.
+    pub struct Iter<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end.
+        len: usize,
+        /// The nodes it holds on to must not be unlinked under it.
+        list: PhantomData<&'a DoublyLinkedList<T>>,
+    }
+
+    impl<T: Clone> Iterator for Iter<'_, T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            if self.len == 0 {
+                return None;
+            }
+            let node = self.front.take()?;
+            self.len -= 1;
+            let node = node.borrow();
+            self.front = node.next.clone();
+            Some(node.value.clone())
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T: Clone> DoubleEndedIterator for Iter<'_, T> {
+        fn next_back(&mut self) -> Option<T> {
+            if self.len == 0 {
+                return None;
+            }
+            let node = self.back.take()?;
+            self.len -= 1;
+            let node = node.borrow();
+            self.back = node.prev.as_ref().and_then(Weak::upgrade);
+            Some(node.value.clone())
+        }
+    }
+
+    impl<T: Clone> ExactSizeIterator for Iter<'_, T> {}
+
+    /// Iterator that takes the values out of the list, from either end.
+    pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.0.pop_front()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len, Some(self.0.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            self.0.pop_back()
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> IntoIterator for DoublyLinkedList<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            IntoIter(self)
+        }
+    }
+
+    /// A cursor that can move both ways and edit the list where it stands.
+    ///
+    /// Between the last element and the first sits a "ghost" position that is
+    /// not an element: moving past either end lands there, and moving on from
+    /// it wraps around to the other end.
+    pub struct CursorMut<'a, T> {
+        list: &'a mut DoublyLinkedList<T>,
+        /// None on the ghost.
+        current: Link<T>,
+        /// Position of `current`; meaningless on the ghost.
+        index: usize,
+    }
+
+    impl<T> CursorMut<'_, T> {
+        /// The position of the current element, or None on the ghost.
+        pub fn index(&self) -> Option<usize> {
+            self.current.as_ref().map(|_| self.index)
+        }
+
+        pub fn current(&mut self) -> Option<RefMut<'_, T>> {
+            self.current
+                .as_ref()
+                .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.value))
+        }
+
+        pub fn move_next(&mut self) {
+            match self.current.take() {
+                Some(node) => {
+                    self.current = node.borrow().next.clone();
+                    self.index += 1;
+                }
+                None => {
+                    self.current = self.list.head.clone();
+                    self.index = 0;
+                }
+            }
+        }
+
+        pub fn move_prev(&mut self) {
+            match self.current.take() {
+                Some(node) => {
+                    self.current = node.borrow().prev.as_ref().and_then(Weak::upgrade);
+                    self.index = self.index.wrapping_sub(1);
+                }
+                None => {
+                    self.current = self.list.tail.clone();
+                    self.index = self.list.len.wrapping_sub(1);
+                }
+            }
+        }
+
+        /// Inserts after the current element; on the ghost, at the front.
+        pub fn insert_after(&mut self, value: T) {
+            let Some(current) = &self.current else {
+                self.list.push_front(value);
+                return;
+            };
+            let next = current.borrow_mut().next.take();
+            let Some(next) = next else {
+                self.list.push_back(value);
+                return;
+            };
+            let node = Node::new(value);
+            {
+                let mut new = node.borrow_mut();
+                new.prev = Some(Rc::downgrade(current));
+                next.borrow_mut().prev = Some(Rc::downgrade(&node));
+                new.next = Some(next);
+            }
+            current.borrow_mut().next = Some(node);
+            self.list.len += 1;
+        }
+
+        /// Inserts before the current element; on the ghost, at the back.
+        pub fn insert_before(&mut self, value: T) {
+            let Some(current) = &self.current else {
+                self.list.push_back(value);
+                return;
+            };
+            let prev = current
+                .borrow_mut()
+                .prev
+                .take()
+                .and_then(|prev| prev.upgrade());
+            self.index += 1;
+            let Some(prev) = prev else {
+                self.list.push_front(value);
+                return;
+            };
+            let node = Node::new(value);
+            {
+                let mut new = node.borrow_mut();
+                new.prev = Some(Rc::downgrade(&prev));
+                current.borrow_mut().prev = Some(Rc::downgrade(&node));
+                new.next = prev.borrow_mut().next.take();
+            }
+            prev.borrow_mut().next = Some(node);
+            self.list.len += 1;
+        }
+
+        /// Removes the current element and moves on to the next one; does
+        /// nothing on the ghost.
+        pub fn remove_current(&mut self) -> Option<T> {
+            let node = self.current.take()?;
+            let next = node.borrow_mut().next.take();
+            let prev = node
+                .borrow_mut()
+                .prev
+                .take()
+                .and_then(|prev| prev.upgrade());
+            match &next {
+                Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+                None => self.list.tail = prev.clone(),
+            }
+            match &prev {
+                Some(prev) => prev.borrow_mut().next = next.clone(),
+                None => self.list.head = next.clone(),
+            }
+            self.list.len -= 1;
+            self.current = next;
+            Some(into_value(node))
+        }
+    }
+}
+
+mod raw {
+    //! The list with raw pointers, as the standard library writes it: the list
+    //! owns every node, allocated with `Box` and turned into a `NonNull`, and
+    //! the nodes point at each other without owning anything.
+    //!
+    //! The compiler no longer checks that a pointer is valid or unaliased, so
+    //! each `unsafe` block says why it is. Run the tests under Miri,
+    //! `cargo +nightly miri test`, to have those claims checked: it reports
+    //! use after free, double frees, leaks and aliasing violations.
+
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::ptr::NonNull;
+
+    type Link<T> = Option<NonNull<Node<T>>>;
+
+    struct Node<T> {
+        value: T,
+        next: Link<T>,
+        prev: Link<T>,
+    }
+
+    pub struct DoublyLinkedList<T> {
+        head: Link<T>,
+        tail: Link<T>,
+        len: usize,
+        /// The list owns its nodes and their values, as a `Box<Node<T>>`
+        /// would: this tells the drop checker so.
+        marker: PhantomData<Box<Node<T>>>,
+    }
+
+    // SAFETY: the list owns its values and hands out `&T` only through `&self`
+    // and `&mut T` only through `&mut self`, like a `Vec<T>`.
+    unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+    // SAFETY: as above; `&DoublyLinkedList<T>` only gives out `&T`.
+    unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+    impl<T> DoublyLinkedList<T> {
+        pub fn new() -> Self {
+            DoublyLinkedList {
+                head: None,
+                tail: None,
+                len: 0,
+                marker: PhantomData,
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Allocates a node that the list has to free again.
+        fn allocate(value: T, prev: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+            NonNull::from(Box::leak(Box::new(Node { value, next, prev })))
+        }
+
+        pub fn push_front(&mut self, value: T) {
+            let node = Self::allocate(value, None, self.head);
+            match self.head {
+                // SAFETY: the head is a live node of this list
+                Some(old) => unsafe { (*old.as_ptr()).prev = Some(node) },
+                None => self.tail = Some(node),
+            }
+            self.head = Some(node);
+            self.len += 1;
+        }
+
+        pub fn push_back(&mut self, value: T) {
+            let node = Self::allocate(value, self.tail, None);
+            match self.tail {
+                // SAFETY: the tail is a live node of this list
+                Some(old) => unsafe { (*old.as_ptr()).next = Some(node) },
+                None => self.head = Some(node),
+            }
+            self.tail = Some(node);
+            self.len += 1;
+        }
+
+        pub fn pop_front(&mut self) -> Option<T> {
+            self.head.map(|node| {
+                // SAFETY: the head is a live node allocated by `allocate`, and
+                // once unlinked nothing else points to it
+                let node = unsafe { Box::from_raw(node.as_ptr()) };
+                self.head = node.next;
+                match self.head {
+                    // SAFETY: the new head is a live node of this list
+                    Some(next) => unsafe { (*next.as_ptr()).prev = None },
+                    None => self.tail = None,
+                }
+                self.len -= 1;
+                node.value
+            })
+        }
+
+        pub fn pop_back(&mut self) -> Option<T> {
+            self.tail.map(|node| {
+                // SAFETY: as in pop_front
+                let node = unsafe { Box::from_raw(node.as_ptr()) };
+                self.tail = node.prev;
+                match self.tail {
+                    // SAFETY: the new tail is a live node of this list
+                    Some(prev) => unsafe { (*prev.as_ptr()).next = None },
+                    None => self.head = None,
+                }
+                self.len -= 1;
+                node.value
+            })
+        }
+
+        pub fn front(&self) -> Option<&T> {
+            // SAFETY: the node lives as long as the list, which is borrowed
+            self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+        }
+
+        pub fn back(&self) -> Option<&T> {
+            // SAFETY: as in front
+            self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+        }
+
+        pub fn front_mut(&mut self) -> Option<&mut T> {
+            // SAFETY: the list is borrowed mutably, so this is the only reference
+            self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        pub fn back_mut(&mut self) -> Option<&mut T> {
+            // SAFETY: as in front_mut
+            self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        /// Moves every element of `other` to the end of this list, in O(1).
+        pub fn append(&mut self, other: &mut Self) {
+            let Some(other_head) = other.head.take() else {
+                return;
+            };
+            match self.tail {
+                // SAFETY: both are live nodes, of this list and of `other`
+                Some(tail) => unsafe {
+                    (*tail.as_ptr()).next = Some(other_head);
+                    (*other_head.as_ptr()).prev = Some(tail);
+                },
+                None => self.head = Some(other_head),
+            }
+            self.tail = other.tail.take();
+            self.len += std::mem::take(&mut other.len);
+        }
+
+        /// Splits the list in two at `at`: this list keeps the first `at`
+        /// elements and the rest are returned.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `at > len`.
+        pub fn split_off(&mut self, at: usize) -> Self {
+            assert!(
+                at <= self.len,
+                "cannot split off at {at}, the list has {}",
+                self.len
+            );
+            if at == 0 {
+                return std::mem::take(self);
+            }
+            if at == self.len {
+                return Self::new();
+            }
+            // Walk to the last node that stays
+            let mut last = self.head.expect("at is within the list");
+            for _ in 1..at {
+                // SAFETY: fewer than len steps from the head stay in the list
+                last = unsafe { (*last.as_ptr()).next.expect("at is within the list") };
+            }
+            // SAFETY: `last` and the node after it are live nodes of this list
+            let rest = unsafe {
+                let rest = (*last.as_ptr()).next.take().expect("at is before the end");
+                (*rest.as_ptr()).prev = None;
+                rest
+            };
+            let split = DoublyLinkedList {
+                head: Some(rest),
+                tail: self.tail.replace(last),
+                len: self.len - at,
+                marker: PhantomData,
+            };
+            self.len = at;
+            split
+        }
+
+        pub fn clear(&mut self) {
+            while self.pop_front().is_some() {}
+        }
+
+        pub fn iter(&self) -> Iter<'_, T> {
+            Iter {
+                front: self.head,
+                back: self.tail,
+                len: self.len,
+                marker: PhantomData,
+            }
+        }
+
+        pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+            IterMut {
+                front: self.head,
+                back: self.tail,
+                len: self.len,
+                marker: PhantomData,
+            }
+        }
+
+        /// A cursor on the first element, or on the ghost if the list is empty.
+        pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.head,
+                index: 0,
+                list: self,
+            }
+        }
+
+        /// A cursor on the last element, or on the ghost if the list is empty.
+        pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                current: self.tail,
+                index: self.len.saturating_sub(1),
+                list: self,
+            }
+        }
+    }
+
+    impl<T> Default for DoublyLinkedList<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T> Drop for DoublyLinkedList<T> {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T> FromIterator<T> for DoublyLinkedList<T> {
+        fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+            let mut list = Self::new();
+            list.extend(iter);
+            list
+        }
+    }
+
+    impl<T> Extend<T> for DoublyLinkedList<T> {
+        fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+            for value in iter {
+                self.push_back(value);
+            }
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    /// Iterator over references to the values, from either end.
+    pub struct Iter<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end.
+        len: usize,
+        marker: PhantomData<&'a T>,
+    }
+
+    impl<'a, T> Iterator for Iter<'a, T> {
+        type Item = &'a T;
+
+        fn next(&mut self) -> Option<&'a T> {
+            if self.len == 0 {
+                return None;
+            }
+            self.front.map(|node| {
+                self.len -= 1;
+                // SAFETY: the list is borrowed for 'a, so its nodes live that long
+                let node = unsafe { &*node.as_ptr() };
+                self.front = node.next;
+                &node.value
+            })
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for Iter<'_, T> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                return None;
+            }
+            self.back.map(|node| {
+                self.len -= 1;
+                // SAFETY: as in next
+                let node = unsafe { &*node.as_ptr() };
+                self.back = node.prev;
+                &node.value
+            })
+        }
+    }
+
+    impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+    /// Iterator over mutable references to the values, from either end.
+    pub struct IterMut<'a, T> {
+        front: Link<T>,
+        back: Link<T>,
+        /// Elements not yet yielded from either end; the two ends never meet,
+        /// so no value is handed out twice.
+        len: usize,
+        marker: PhantomData<&'a mut T>,
+    }
+
+    impl<'a, T> Iterator for IterMut<'a, T> {
+        type Item = &'a mut T;
+
+        fn next(&mut self) -> Option<&'a mut T> {
+            if self.len == 0 {
+                return None;
+            }
+            self.front.map(|node| {
+                self.len -= 1;
+                // SAFETY: the list is borrowed mutably for 'a, and each node is
+                // visited once
+                let node = unsafe { &mut *node.as_ptr() };
+                self.front = node.next;
+                &mut node.value
+            })
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len, Some(self.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IterMut<'_, T> {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            if self.len == 0 {
+                return None;
+            }
+            self.back.map(|node| {
+                self.len -= 1;
+                // SAFETY: as in next
+                let node = unsafe { &mut *node.as_ptr() };
+                self.back = node.prev;
+                &mut node.value
+            })
+        }
+    }
+
+    impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+    /// Iterator that takes the values out of the list, from either end.
+    pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+    impl<T> Iterator for IntoIter<T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.0.pop_front()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len, Some(self.0.len))
+        }
+    }
+
+    impl<T> DoubleEndedIterator for IntoIter<T> {
+        fn next_back(&mut self) -> Option<T> {
+            self.0.pop_back()
+        }
+    }
+
+    impl<T> ExactSizeIterator for IntoIter<T> {}
+
+    impl<T> IntoIterator for DoublyLinkedList<T> {
+        type Item = T;
+        type IntoIter = IntoIter<T>;
+
+        fn into_iter(self) -> IntoIter<T> {
+            IntoIter(self)
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+
+        fn into_iter(self) -> Iter<'a, T> {
+            self.iter()
+        }
+    }
+
+    impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+        type Item = &'a mut T;
+        type IntoIter = IterMut<'a, T>;
+
+        fn into_iter(self) -> IterMut<'a, T> {
+            self.iter_mut()
+        }
+    }
+
+    /// A cursor that can move both ways and edit the list where it stands.
+    ///
+    /// Between the last element and the first sits a "ghost" position that is
+    /// not an element: moving past either end lands there, and moving on from
+    /// it wraps around to the other end.
+    pub struct CursorMut<'a, T> {
+        list: &'a mut DoublyLinkedList<T>,
+        /// None on the ghost.
+        current: Link<T>,
+        /// Position of `current`; meaningless on the ghost.
+        index: usize,
+    }
+
+    impl<T> CursorMut<'_, T> {
+        /// The position of the current element, or None on the ghost.
+        pub fn index(&self) -> Option<usize> {
+            self.current.map(|_| self.index)
+        }
+
+        pub fn current(&mut self) -> Option<&mut T> {
+            // SAFETY: the cursor borrows the list mutably, and the reference
+            // borrows the cursor
+            self.current
+                .map(|node| unsafe { &mut (*node.as_ptr()).value })
+        }
+
+        pub fn move_next(&mut self) {
+            match self.current {
+                Some(node) => {
+                    // SAFETY: the current node is a live node of the list
+                    self.current = unsafe { (*node.as_ptr()).next };
+                    self.index += 1;
+                }
+                None => {
+                    self.current = self.list.head;
+                    self.index = 0;
+                }
+            }
+        }
+
+        pub fn move_prev(&mut self) {
+            match self.current {
+                Some(node) => {
+                    // SAFETY: as in move_next
+                    self.current = unsafe { (*node.as_ptr()).prev };
+                    self.index = self.index.wrapping_sub(1);
+                }
+                None => {
+                    self.current = self.list.tail;
+                    self.index = self.list.len.wrapping_sub(1);
+                }
+            }
+        }
+
+        /// Inserts after the current element; on the ghost, at the front.
+        pub fn insert_after(&mut self, value: T) {
+            let Some(current) = self.current else {
+                self.list.push_front(value);
+                return;
+            };
+            // SAFETY: the current node and its successor are live nodes
+            unsafe {
+                let Some(next) = (*current.as_ptr()).next else {
+                    self.list.push_back(value);
+                    return;
+                };
+                let node = DoublyLinkedList::allocate(value, Some(current), Some(next));
+                (*current.as_ptr()).next = Some(node);
+                (*next.as_ptr()).prev = Some(node);
+            }
+            self.list.len += 1;
+        }
+
+        /// Inserts before the current element; on the ghost, at the back.
+        pub fn insert_before(&mut self, value: T) {
+            let Some(current) = self.current else {
+                self.list.push_back(value);
+                return;
+            };
+            self.index += 1;
+            // SAFETY: the current node and its predecessor are live nodes
+            unsafe {
+                let Some(prev) = (*current.as_ptr()).prev else {
+                    self.list.push_front(value);
+                    return;
+                };
+                let node = DoublyLinkedList::allocate(value, Some(prev), Some(current));
+                (*prev.as_ptr()).next = Some(node);
+                (*current.as_ptr()).prev = Some(node);
+            }
+            self.list.len += 1;
+        }
+
+        /// Removes the current element and moves on to the next one; does
+        /// nothing on the ghost.
+        pub fn remove_current(&mut self) -> Option<T> {
+            let current = self.current?;
+            // SAFETY: the current node is live and was allocated by `allocate`;
+            // once its neighbours no longer point to it, nothing else does
+            let node = unsafe { Box::from_raw(current.as_ptr()) };
+            match node.prev {
+                // SAFETY: a neighbour of a live node is live
+                Some(prev) => unsafe { (*prev.as_ptr()).next = node.next },
+                None => self.list.head = node.next,
+            }
+            match node.next {
+                // SAFETY: as above
+                Some(next) => unsafe { (*next.as_ptr()).prev = node.prev },
+                None => self.list.tail = node.prev,
+            }
+            self.list.len -= 1;
+            self.current = node.next;
+            Some(node.value)
+        }
+    }
+}
@@ -0,0 +1,309 @@
+// Reference solution for exercise 324: Big Integers - Arbitrary Precision
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+fn main() {
+    println!("=== Arbitrary-Precision Integers ===\n");
+
+    let big = BigUint::from_u64(u64::MAX);
+    println!("u64::MAX + 1 = {}", &big + &BigUint::from_u64(1));
+    println!("u64::MAX^2 = {}", &big * &big);
+
+    println!("\n25! = {} (u64 stops at 20!)", factorial_big(25));
+    println!("F(100) = {} (u64 stops at F(93))", fibonacci_big(100));
+    println!("3^100 = {}", fast_power_big(&BigUint::from_u64(3), 100));
+
+    let digits = factorial_big(1000).to_string();
+    println!("\n1000! has {} digits, starting {}...", digits.len(), &digits[..20]);
+    let digits = fibonacci_big(10_000).to_string();
+    println!("F(10000) has {} digits, starting {}...", digits.len(), &digits[..20]);
+
+    println!("\n✓ Arbitrary-precision integers completed successfully!");
+}
+
+/// Products of operands this many limbs long or longer use Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// An unsigned integer of any size.
+///
+/// Stored like a number written in base 2^32: `limbs[0]` is the least
+/// significant digit. There are never zero limbs at the end, so every value
+/// has exactly one representation and zero is the empty vector.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct BigUint {
+    limbs: Vec<u32>,
+}
+
+/// Drops the zero limbs at the end.
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+/// a + b, limb by limb with a carry.
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// a - b, limb by limb with a borrow.
+///
+/// # Panics
+///
+/// Panics if b > a.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let b = &b[..b.len() - b.iter().rev().take_while(|&&limb| limb == 0).count()];
+    assert!(b.len() <= a.len(), "subtraction would underflow");
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut diff = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 1 << 32;
+            borrow = 1;
+        }
+        result.push(diff as u32);
+    }
+    assert_eq!(borrow, 0, "subtraction would underflow");
+    trim(result)
+}
+
+/// Adds `x` into `result` starting at limb `shift`, growing it as needed.
+fn add_shifted(result: &mut Vec<u32>, x: &[u32], shift: usize) {
+    if result.len() < shift + x.len() {
+        result.resize(shift + x.len(), 0);
+    }
+    let mut carry = 0u64;
+    let mut i = shift;
+    for &limb in x {
+        let sum = result[i] as u64 + limb as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    while carry > 0 {
+        if i == result.len() {
+            result.push(0);
+        }
+        let sum = result[i] as u64 + carry;
+        result[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+/// a * b the way it is done on paper: every limb of a times every limb of
+/// b, in O(len(a) * len(b)).
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            // At most (2^32 - 1)^2 + 2 * (2^32 - 1), which fits in a u64
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(result)
+}
+
+/// a * b with three half-size products instead of four, in
+/// O(n^log2(3)) = O(n^1.585).
+///
+/// Split each operand at `half` limbs: a = a1 * B + a0 and b = b1 * B + b0
+/// with B = 2^(32 * half). Then
+/// a * b = z2 * B^2 + z1 * B + z0, where z0 = a0 * b0, z2 = a1 * b1 and
+/// z1 = (a0 + a1) * (b0 + b1) - z0 - z2.
+fn mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+    let half = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(half.min(a.len()));
+    let (b0, b1) = b.split_at(half.min(b.len()));
+
+    let z0 = mul_karatsuba(a0, b0);
+    let z2 = mul_karatsuba(a1, b1);
+    let z1 = mul_karatsuba(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    let z1 = sub_limbs(&sub_limbs(&z1, &z0), &z2);
+
+    let mut result = z0;
+    add_shifted(&mut result, &z1, half);
+    add_shifted(&mut result, &z2, 2 * half);
+    trim(result)
+}
+
+impl BigUint {
+    fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    fn from_u64(n: u64) -> Self {
+        BigUint {
+            limbs: trim(vec![n as u32, (n >> 32) as u32]),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// self * m for a single limb m.
+    fn mul_small(&self, m: u32) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 1);
+        let mut carry = 0u64;
+        for &limb in &self.limbs {
+            let product = limb as u64 * m as u64 + carry;
+            limbs.push(product as u32);
+            carry = product >> 32;
+        }
+        limbs.push(carry as u32);
+        BigUint { limbs: trim(limbs) }
+    }
+
+    /// The quotient and remainder of self / d, for a single limb d.
+    ///
+    /// # Panics
+    ///
+    /// Panics if d is 0.
+    fn divmod_small(&self, d: u32) -> (BigUint, u32) {
+        assert!(d != 0, "division by zero");
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut remainder = 0u64;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << 32) | limb as u64;
+            quotient[i] = (current / d as u64) as u32;
+            remainder = current % d as u64;
+        }
+        (BigUint { limbs: trim(quotient) }, remainder as u32)
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: add_limbs(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// # Panics
+    ///
+    /// Panics if other > self.
+    fn sub(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: sub_limbs(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint {
+            limbs: mul_karatsuba(&self.limbs, &other.limbs),
+        }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Without trailing zero limbs, the longer number is the larger one
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    /// Divides by 10^9 repeatedly, which peels off nine decimal digits at a
+    /// time from the right.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.divmod_small(1_000_000_000);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut digits = chunks.pop().map(|chunk| chunk.to_string()).unwrap_or_default();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+/// n! with no upper limit on n (exercise 106 overflows at 21!).
+fn factorial_big(n: u32) -> BigUint {
+    let mut result = BigUint::from_u64(1);
+    for i in 2..=n {
+        result = result.mul_small(i);
+    }
+    result
+}
+
+/// The nth Fibonacci number with no upper limit on n (exercise 108
+/// overflows at F(94)).
+fn fibonacci_big(n: u64) -> BigUint {
+    let mut a = BigUint::zero();
+    let mut b = BigUint::from_u64(1);
+    for _ in 0..n {
+        let next = &a + &b;
+        a = std::mem::replace(&mut b, next);
+    }
+    a
+}
+
+/// base^exp by squaring, as in exercise 117, but without wrapping.
+fn fast_power_big(base: &BigUint, exp: u32) -> BigUint {
+    let mut result = BigUint::from_u64(1);
+    let mut base = base.clone();
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = &result * &base;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = &base * &base;
+        }
+    }
+    result
+}
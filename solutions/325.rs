@@ -0,0 +1,216 @@
+// Reference solution for exercise 325: Primes - Miller-Rabin and Pollard's Rho
+
+fn main() {
+    println!("=== Primes at 64-bit Scale ===\n");
+
+    println!("--- Miller-Rabin ---");
+    for n in [1_000_000_007, 3_215_031_751, 18_446_744_073_709_551_557, u64::MAX] {
+        println!("is_prime({}) = {}", n, is_prime(n));
+    }
+
+    println!("\n--- Segmented sieve ---");
+    println!("Primes up to 10^7: {}", count_primes_up_to(10_000_000));
+    let mut window = Vec::new();
+    segmented_sieve(10_000_000_000, 10_000_000_100, |p| window.push(p));
+    println!("Primes in [10^10, 10^10 + 100]: {:?}", window);
+
+    println!("\n--- Pollard's rho ---");
+    for n in [600_851_475_143, 998_244_359_987_710_471, u64::MAX] {
+        println!("{} = {:?}", n, factorize(n));
+    }
+
+    println!("\n✓ Primes at 64-bit scale completed successfully!");
+}
+
+/// Largest segment the sieve works on at once, in numbers.
+const SEGMENT_SIZE: usize = 1 << 16;
+
+/// The primes up to and including n, with the sieve of exercise 111.
+fn simple_sieve(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return vec![];
+    }
+    let mut is_prime = vec![true; n + 1];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    let mut p = 2;
+    while p * p <= n {
+        if is_prime[p] {
+            for multiple in (p * p..=n).step_by(p) {
+                is_prime[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    (0..=n).filter(|&i| is_prime[i]).collect()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// a * b % m without overflow: the product of two u64 always fits a u128.
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// base^exp % modulus by squaring, as in exercise 117, for any u64 modulus.
+fn modular_power(base: u64, exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut result = 1;
+    let mut base = base % modulus;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Bases that make Miller-Rabin exact for every n below 3.3 * 10^24, so
+/// for every u64.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Whether n is prime, by Miller-Rabin with a fixed set of witnesses.
+///
+/// Write n - 1 = d * 2^s with d odd. For a prime n, every a has a^d = 1 or
+/// a^(d * 2^r) = n - 1 for some r < s, mod n. A base for which neither
+/// holds proves n composite.
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &WITNESSES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in &WITNESSES {
+        let mut x = modular_power(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Calls `visit` on every prime in lo..=hi, in order.
+///
+/// Only the primes up to sqrt(hi) and one segment of SEGMENT_SIZE flags
+/// are held at a time, so counting to 10^10 needs well under a megabyte
+/// where the plain sieve would need ten gigabytes.
+fn segmented_sieve(lo: u64, hi: u64, mut visit: impl FnMut(u64)) {
+    let lo = lo.max(2);
+    if lo > hi {
+        return;
+    }
+    let base = simple_sieve(hi.isqrt() as usize);
+    let mut composite = vec![false; SEGMENT_SIZE];
+    let mut start = lo;
+    loop {
+        let end = hi.min(start.saturating_add(SEGMENT_SIZE as u64 - 1));
+        let len = (end - start + 1) as usize;
+        composite[..len].fill(false);
+        for &p in &base {
+            let p = p as u64;
+            if p * p > end {
+                break;
+            }
+            // The first multiple of p in the segment, but never p itself
+            let first = (p * p).max(start.div_ceil(p) * p);
+            for i in ((first - start) as usize..len).step_by(p as usize) {
+                composite[i] = true;
+            }
+        }
+        for (i, &flag) in composite[..len].iter().enumerate() {
+            if !flag {
+                visit(start + i as u64);
+            }
+        }
+        if end == hi {
+            break;
+        }
+        start = end + 1;
+    }
+}
+
+/// The number of primes up to and including n.
+fn count_primes_up_to(n: u64) -> u64 {
+    let mut count = 0;
+    segmented_sieve(2, n, |_| count += 1);
+    count
+}
+
+/// A factor of the composite n other than 1 and n, by Pollard's rho.
+///
+/// The sequence x -> x^2 + c mod n, taken mod an unknown prime factor p,
+/// repeats after about sqrt(p) steps. Floyd's tortoise and hare find the
+/// repeat: once x = y mod p, p divides gcd(|x - y|, n).
+fn pollard_rho(n: u64) -> u64 {
+    if n % 2 == 0 {
+        return 2;
+    }
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        // d == n means x and y met mod n itself; try another sequence
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!("some c finds a factor of a composite")
+}
+
+/// The prime factors of n with their multiplicity, smallest first; none
+/// for 0 and 1.
+fn factorize(n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    if n == 0 {
+        return factors;
+    }
+    // Trial division takes the small factors cheaply, rho the rest
+    let mut n = n;
+    for p in 2..100 {
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    let mut pending = vec![n];
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            factors.push(m);
+        } else {
+            let d = pollard_rho(m);
+            pending.push(d);
+            pending.push(m / d);
+        }
+    }
+    factors.sort_unstable();
+    factors
+}
@@ -0,0 +1,420 @@
+//! Parsing the comment block at the top of a template.
+//!
+//! Templates use one of two layouts. The banner layout boxes the header in
+//! `═══` rules, with a `🦀 EXERCISE NNN: Title` line and sections such as
+//! `📚 LEARNING OBJECTIVE:` whose bodies are indented below the label:
+//!
+//! ```text
+//! // ═══════════════════════════════
+//! // 🦀 EXERCISE 034: Match Expressions
+//! // ═══════════════════════════════
+//! //
+//! // 📚 LEARNING OBJECTIVE:
+//! //    Master pattern matching with match expressions.
+//! ```
+//!
+//! The plain layout starts with `// Exercise NNN: Title`, optionally
+//! underlined, and labels sections in sentence case, with the text either
+//! after the colon or on the following lines:
+//!
+//! ```text
+//! // Exercise 026: Ownership - Move Semantics
+//! //
+//! // Learning Objective: Understand how ownership and move semantics work.
+//! //
+//! // Topics covered:
+//! // - Ownership transfer (move)
+//! ```
+//!
+//! Either way the header is the run of comment lines at the top of the
+//! file; the first blank or code line ends it.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Style {
+    Banner,
+    Plain,
+}
+
+/// What a section is about, whatever its label says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topic {
+    Objective,
+    Scenario,
+    Concepts,
+    Task,
+    Hints,
+    Bonus,
+    /// Any other labelled section, e.g. `📖 TYPE REFERENCE GUIDE:`.
+    Other,
+}
+
+impl Topic {
+    fn of(label: &str) -> Topic {
+        match label.to_ascii_lowercase().as_str() {
+            "learning objective" | "learning objectives" => Topic::Objective,
+            "real-world scenario" => Topic::Scenario,
+            "concepts covered" | "topics covered" | "key concepts" | "this exercise covers" => {
+                Topic::Concepts
+            }
+            "your task" => Topic::Task,
+            "hint" | "hints" => Topic::Hints,
+            "bonus" | "bonus challenge" => Topic::Bonus,
+            _ => Topic::Other,
+        }
+    }
+}
+
+/// One labelled part of a header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Section {
+    /// The label as written, without emoji or colon.
+    pub label: String,
+    pub topic: Topic,
+    /// 1-based line of the label.
+    pub line: usize,
+    /// Bullet points become one item each; other lines are joined to the
+    /// item before them.
+    pub items: Vec<String>,
+}
+
+impl Section {
+    /// The items as one piece of text, a line per item.
+    pub fn text(&self) -> String {
+        self.items.join("\n")
+    }
+}
+
+/// A parsed template header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Header {
+    pub style: Style,
+    /// The number in the title line, which should match the directory.
+    pub number: u32,
+    pub title: String,
+    /// 1-based line of the title.
+    pub title_line: usize,
+    /// Unlabelled paragraphs, e.g. the introduction some plain headers
+    /// open with.
+    pub description: Vec<String>,
+    /// Sections in file order.
+    pub sections: Vec<Section>,
+    /// 1-based line of the header's last line.
+    pub end_line: usize,
+}
+
+impl Header {
+    /// The first section about `topic`.
+    pub fn section(&self, topic: Topic) -> Option<&Section> {
+        self.sections.iter().find(|s| s.topic == topic)
+    }
+
+    pub fn objective(&self) -> Option<String> {
+        self.section(Topic::Objective).map(Section::text)
+    }
+
+    pub fn scenario(&self) -> Option<String> {
+        self.section(Topic::Scenario).map(Section::text)
+    }
+
+    pub fn task(&self) -> Option<String> {
+        self.section(Topic::Task).map(Section::text)
+    }
+
+    pub fn concepts(&self) -> &[String] {
+        self.section(Topic::Concepts).map_or(&[], |s| &s.items)
+    }
+
+    pub fn bonus(&self) -> Option<String> {
+        self.section(Topic::Bonus).map(Section::text)
+    }
+}
+
+/// Why a header could not be parsed at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The file does not start with a comment.
+    NoHeader,
+    /// None of the first lines reads `Exercise NNN: Title`.
+    NoTitle,
+    /// A banner whose closing `═══` rule is missing.
+    Unterminated,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ParseError::NoHeader => "no comment header at the top of the file",
+            ParseError::NoTitle => "no `Exercise NNN: Title` line in the header",
+            ParseError::Unterminated => "the banner has no closing rule",
+        })
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// How many lines into the header the title may appear.
+const TITLE_WITHIN: usize = 3;
+
+pub fn parse(source: &str) -> Result<Header, ParseError> {
+    let block: Vec<&str> = source.lines().map_while(comment).collect();
+    if block.is_empty() {
+        return Err(ParseError::NoHeader);
+    }
+
+    let (title_index, number, title) = block
+        .iter()
+        .take(TITLE_WITHIN)
+        .enumerate()
+        .find_map(|(i, line)| title_of(line).map(|(n, t)| (i, n, t)))
+        .ok_or(ParseError::NoTitle)?;
+    let style = if is_rule(block[0]) {
+        Style::Banner
+    } else {
+        Style::Plain
+    };
+
+    // A banner ends at the rule after the one under its title; anything
+    // below that is code commentary, not header.
+    let end = match style {
+        Style::Banner => {
+            let mut below = block.iter().enumerate().skip(title_index + 2);
+            below
+                .find(|(_, l)| is_rule(l))
+                .map(|(i, _)| i)
+                .ok_or(ParseError::Unterminated)?
+        }
+        Style::Plain => block.len() - 1,
+    };
+
+    let mut header = Header {
+        style,
+        number,
+        title,
+        title_line: title_index + 1,
+        description: Vec::new(),
+        sections: Vec::new(),
+        end_line: end + 1,
+    };
+    let mut current: Option<Section> = None;
+    let mut paragraph: Vec<String> = Vec::new();
+    for (i, line) in block.iter().enumerate().take(end + 1).skip(title_index + 1) {
+        let text = line.trim();
+        if is_rule(text) {
+            continue;
+        }
+        if text.is_empty() {
+            // A blank line ends a plain section; banner sections run on
+            // to the next label.
+            if style == Style::Plain {
+                header.sections.extend(current.take());
+            }
+            push_paragraph(&mut header.description, &mut paragraph);
+            continue;
+        }
+        if let Some((label, rest)) = label_of(text, style) {
+            header.sections.extend(current.take());
+            push_paragraph(&mut header.description, &mut paragraph);
+            let mut section = Section {
+                topic: Topic::of(&label),
+                label,
+                line: i + 1,
+                items: Vec::new(),
+            };
+            add_line(&mut section.items, rest);
+            current = Some(section);
+            continue;
+        }
+        match current.as_mut() {
+            Some(section) => add_line(&mut section.items, text),
+            None => paragraph.push(text.to_string()),
+        }
+    }
+    header.sections.extend(current);
+    push_paragraph(&mut header.description, &mut paragraph);
+    Ok(header)
+}
+
+/// The text of a `//`, `///` or `//!` comment line, or `None` for code
+/// and blank lines.
+fn comment(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix("//")?;
+    Some(rest.strip_prefix(['/', '!']).unwrap_or(rest))
+}
+
+/// Box-drawing or ASCII rules such as `═══════` and `=======`.
+fn is_rule(text: &str) -> bool {
+    let text = text.trim();
+    text.chars().count() >= 3 && text.chars().all(|c| matches!(c, '═' | '=' | '─' | '-'))
+}
+
+/// `(number, title)` from `🦀 EXERCISE 034: Match Expressions` or
+/// `Exercise 026: Ownership - Move Semantics`.
+fn title_of(line: &str) -> Option<(u32, String)> {
+    let start = line.find(|c: char| c.is_alphanumeric())?;
+    let rest = &line[start..];
+    let rest = rest
+        .get(..9)
+        .filter(|w| w.eq_ignore_ascii_case("exercise "))
+        .map(|_| &rest[9..])?;
+    let (number, title) = rest.split_once(':')?;
+    let number = number.trim().parse().ok()?;
+    Some((number, title.trim().to_string()))
+}
+
+/// `(label, rest of line)` if `text` opens a section: `📚 LEARNING
+/// OBJECTIVE:` in a banner, `Learning objective: ...` in a plain header.
+fn label_of(text: &str, style: Style) -> Option<(String, &str)> {
+    let (before, after) = text.split_once(':')?;
+    let label = before
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .trim();
+    let words = label.split_whitespace().count();
+    if label.is_empty() || words > 4 {
+        return None;
+    }
+    let is_label = match style {
+        // Banner labels are in capitals and stand alone on their line.
+        Style::Banner => {
+            after.trim().is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_uppercase() || " -/&'".contains(c))
+        }
+        // Plain labels are only the known ones; "Rule 1: ..." is prose.
+        Style::Plain => Topic::of(label) != Topic::Other || label.eq_ignore_ascii_case("note"),
+    };
+    is_label.then(|| (label.to_string(), after.trim()))
+}
+
+/// Adds one line of a section body: a bullet starts a new item, anything
+/// else continues the current one.
+fn add_line(items: &mut Vec<String>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let bullet = ["• ", "- ", "* "].iter().find_map(|b| text.strip_prefix(b));
+    match (bullet, items.last_mut()) {
+        (Some(item), _) => items.push(item.trim().to_string()),
+        (None, Some(last)) if !last.is_empty() => {
+            last.push(' ');
+            last.push_str(text);
+        }
+        _ => items.push(text.to_string()),
+    }
+}
+
+fn push_paragraph(description: &mut Vec<String>, paragraph: &mut Vec<String>) {
+    if !paragraph.is_empty() {
+        description.push(paragraph.join(" "));
+        paragraph.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_banner_headers() {
+        let source = "\
+// ═══════════════
+// 🦀 EXERCISE 200: Container Runtime
+// ═══════════════
+//
+// 📚 LEARNING OBJECTIVE:
+//    Linux namespaces, cgroups, chroot
+//
+// 🏢 REAL-WORLD SCENARIO:
+//    Build a minimal container runtime like runc. Use Linux
+//    namespaces for isolation, ...
+//
+// 💡 CONCEPTS COVERED:
+//    • Linux namespaces
+//    • cgroups
+//
+// ═══════════════
+
+fn main() {}
+";
+        let header = parse(source).unwrap();
+        assert_eq!(header.style, Style::Banner);
+        assert_eq!(
+            (header.number, header.title.as_str()),
+            (200, "Container Runtime")
+        );
+        assert_eq!((header.title_line, header.end_line), (2, 16));
+        assert_eq!(
+            header.objective().as_deref(),
+            Some("Linux namespaces, cgroups, chroot")
+        );
+        assert_eq!(
+            header.scenario().as_deref(),
+            Some("Build a minimal container runtime like runc. Use Linux namespaces for isolation, ...")
+        );
+        assert_eq!(header.concepts(), ["Linux namespaces", "cgroups"]);
+        assert_eq!(header.section(Topic::Concepts).unwrap().line, 12);
+        assert!(header.task().is_none());
+    }
+
+    #[test]
+    fn parses_plain_headers() {
+        let source = "\
+// Exercise 051: Result Type - Basic Error Handling
+// ===========
+//
+// The Result<T, E> type is Rust's primary way of handling
+// recoverable errors.
+//
+// Learning Objectives:
+// - Understand the Result<T, E> enum
+// - Learn to create Ok and Err variants
+//
+// Rule 1: not a section.
+// Your task: Implement a function that parses a string.
+/// - Handling edge cases
+
+/// Parses a string.
+fn parse_positive_number() {}
+";
+        let header = parse(source).unwrap();
+        assert_eq!(header.style, Style::Plain);
+        assert_eq!(header.number, 51);
+        assert_eq!(header.end_line, 13);
+        assert_eq!(
+            header.description,
+            [
+                "The Result<T, E> type is Rust's primary way of handling recoverable errors.",
+                "Rule 1: not a section.",
+            ]
+        );
+        assert_eq!(
+            header.objective().as_deref(),
+            Some("Understand the Result<T, E> enum\nLearn to create Ok and Err variants")
+        );
+        let task = header.section(Topic::Task).unwrap();
+        assert_eq!(
+            task.items,
+            [
+                "Implement a function that parses a string.",
+                "Handling edge cases"
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_unparseable_headers() {
+        assert_eq!(parse("fn main() {}\n"), Err(ParseError::NoHeader));
+        assert_eq!(
+            parse("// Just a comment\n// and more\n"),
+            Err(ParseError::NoTitle)
+        );
+        assert_eq!(
+            parse("// ═══\n// 🦀 EXERCISE 001: Hi\n// ═══\n// 📚 LEARNING OBJECTIVE:\n"),
+            Err(ParseError::Unterminated)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! The comment headers at the top of the exercise templates, parsed and
+//! checked.
+//!
+//! Every template opens with a header naming the exercise and describing
+//! it: what it teaches, the scenario, the concepts it covers. The web app
+//! and the learner tools show those sections, so [`header::parse`] turns
+//! both header layouts into one typed [`Header`], and [`lint()`] reports
+//! headers that are missing sections, cut short, or disagree with the
+//! catalog in `web/exercise_data.json`.
+
+pub mod header;
+pub mod lint;
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use rustground::Catalog;
+
+pub use header::{Header, ParseError, Section, Style, Topic};
+pub use lint::{Finding, Problem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// `problems/NNN_exercise/template.rs`, or the `src/lib.rs` of the
+    /// exercises that are multi-file crates.
+    Exercise,
+    /// `problems/NNN_project/src/main.rs`, a Cargo project.
+    Project,
+}
+
+/// One file learners start from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Template {
+    pub number: u32,
+    pub kind: Kind,
+    pub path: PathBuf,
+}
+
+impl Template {
+    /// Three-digit id, e.g. `"042"`, as the catalog keys it.
+    pub fn id(&self) -> String {
+        format!("{:03}", self.number)
+    }
+}
+
+/// Every template under `root/problems`, by number, exercises before
+/// projects of the same number.
+pub fn templates(root: &Path) -> io::Result<Vec<Template>> {
+    let mut templates = Vec::new();
+    for entry in std::fs::read_dir(root.join("problems"))? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some((number, kind)) = name.to_str().and_then(split_dir_name) else {
+            continue;
+        };
+        let path = match kind {
+            Kind::Exercise if entry.path().join("Cargo.toml").is_file() => {
+                entry.path().join("src/lib.rs")
+            }
+            Kind::Exercise => entry.path().join("template.rs"),
+            Kind::Project => entry.path().join("src/main.rs"),
+        };
+        if path.is_file() {
+            templates.push(Template { number, kind, path });
+        }
+    }
+    templates.sort_by_key(|t| (t.number, t.kind));
+    Ok(templates)
+}
+
+/// A template and its parsed header.
+#[derive(Debug, Clone)]
+pub struct Parsed {
+    pub template: Template,
+    pub header: Result<Header, ParseError>,
+}
+
+/// Parses the templates numbered in `only`, or all of them when it is
+/// empty.
+pub fn parse_all(root: &Path, only: &[u32]) -> io::Result<Vec<Parsed>> {
+    let mut parsed = Vec::new();
+    for template in templates(root)? {
+        if !only.is_empty() && !only.contains(&template.number) {
+            continue;
+        }
+        let source = std::fs::read_to_string(&template.path)?;
+        let header = header::parse(&source);
+        parsed.push(Parsed { template, header });
+    }
+    Ok(parsed)
+}
+
+/// The problems in `parsed`, in file order.
+pub fn lint(parsed: &[Parsed], catalog: &Catalog) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for (i, Parsed { template, header }) in parsed.iter().enumerate() {
+        let header = match header {
+            Ok(header) => header,
+            Err(e) => {
+                let problem = Problem::Unparseable(e.clone());
+                findings.push(Finding {
+                    path: template.path.clone(),
+                    line: 1,
+                    problem,
+                });
+                continue;
+            }
+        };
+        findings.extend(lint::check(template, header));
+
+        // `templates` puts a number's project right after its exercise.
+        let project = parsed.get(i + 1).filter(|p| {
+            template.kind == Kind::Exercise
+                && p.template.kind == Kind::Project
+                && p.template.number == template.number
+        });
+        let collision = project.and_then(|p| {
+            let theirs = p.header.as_ref().ok()?;
+            lint::collision((template, header), (&p.template, theirs))
+        });
+        // A colliding exercise is not the one the catalog describes, so
+        // its title mismatch would only repeat the collision.
+        match collision {
+            Some(finding) => findings.push(finding),
+            None => findings.extend(lint::against_catalog(template, header, catalog)),
+        }
+    }
+    findings
+}
+
+/// `(42, Exercise)` from `042_exercise`.
+fn split_dir_name(name: &str) -> Option<(u32, Kind)> {
+    let (number, kind) = name.split_once('_')?;
+    if number.len() != 3 {
+        return None;
+    }
+    let kind = match kind {
+        "exercise" => Kind::Exercise,
+        "project" => Kind::Project,
+        _ => return None,
+    };
+    Some((number.parse().ok()?, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    }
+
+    fn findings(only: &[u32]) -> Vec<String> {
+        let root = root();
+        let catalog = Catalog::load(&root).unwrap();
+        let parsed = parse_all(&root, only).unwrap();
+        lint(&parsed, &catalog)
+            .iter()
+            .map(|f| {
+                let path = f.path.strip_prefix(&root).unwrap();
+                format!("{}:{}: {}", path.display(), f.line, f.problem)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_template_header_parses() {
+        let parsed = parse_all(&root(), &[]).unwrap();
+        assert!(parsed.len() >= 350, "{} templates", parsed.len());
+        for Parsed { template, header } in &parsed {
+            let header = header
+                .as_ref()
+                .unwrap_or_else(|e| panic!("{}: {e}", template.path.display()));
+            assert_eq!(
+                header.number,
+                template.number,
+                "{}",
+                template.path.display()
+            );
+            assert!(header.objective().is_some(), "{}", template.path.display());
+        }
+
+        let style = |n: usize| parsed[n].header.as_ref().unwrap().style;
+        assert_eq!(
+            (style(0), style(3), style(14)),
+            (Style::Banner, Style::Plain, Style::Banner)
+        );
+        let bonus = parsed[0].header.as_ref().unwrap().bonus().unwrap();
+        assert!(bonus.starts_with("Try printing your name"), "{bonus}");
+    }
+
+    #[test]
+    fn reports_the_known_header_problems() {
+        assert_eq!(
+            findings(&[2, 151, 200]),
+            [
+                "problems/002_exercise/template.rs:2: title `Variables and Mutability` disagrees with the catalog's `Mutable Variables`",
+                "problems/151_exercise/template.rs:2: no concepts covered section",
+                "problems/151_exercise/template.rs:2: exercise `Iterator Adapters` shares its number with project `Calculator CLI`",
+                "problems/151_project/src/main.rs:2: no task section",
+                "problems/151_project/src/main.rs:8: CONCEPTS COVERED still has a TBD placeholder",
+                "problems/200_exercise/template.rs:8: REAL-WORLD SCENARIO is cut off with `...`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! What can be wrong with a template header.
+//!
+//! [`check`] looks at one header on its own, [`against_catalog`] compares
+//! its title with `web/exercise_data.json`, and [`collision`] flags an
+//! exercise and a project that share a number but not a title. Each
+//! finding carries the file and line to fix.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use rustground::Catalog;
+
+use crate::header::{Header, ParseError, Style, Topic};
+use crate::{Kind, Template};
+
+/// Sections every header needs, by layout. Plain headers are a title and
+/// an objective; banners also say what to do and what it covers.
+const REQUIRED: &[(Style, &[Topic])] = &[
+    (Style::Plain, &[Topic::Objective]),
+    (
+        Style::Banner,
+        &[Topic::Objective, Topic::Task, Topic::Concepts],
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    Unparseable(ParseError),
+    /// The title line's number is not the directory's.
+    WrongNumber {
+        found: u32,
+    },
+    Missing(Topic),
+    Empty {
+        label: String,
+    },
+    /// Text that stops at `...` instead of finishing its sentence.
+    Truncated {
+        label: String,
+    },
+    /// A `TBD` left where content belongs.
+    Placeholder {
+        label: String,
+    },
+    TitleMismatch {
+        title: String,
+        catalog: String,
+    },
+    NotInCatalog,
+    /// An exercise and a project with the same number teach different
+    /// things, and the catalog can only describe one of them.
+    Collision {
+        title: String,
+        project: String,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Unparseable(e) => write!(f, "{e}"),
+            Problem::WrongNumber { found } => {
+                write!(
+                    f,
+                    "title says exercise {found:03}, the directory says otherwise"
+                )
+            }
+            Problem::Missing(topic) => write!(f, "no {} section", topic_name(*topic)),
+            Problem::Empty { label } => write!(f, "{label} is empty"),
+            Problem::Truncated { label } => write!(f, "{label} is cut off with `...`"),
+            Problem::Placeholder { label } => write!(f, "{label} still has a TBD placeholder"),
+            Problem::TitleMismatch { title, catalog } => {
+                write!(
+                    f,
+                    "title `{title}` disagrees with the catalog's `{catalog}`"
+                )
+            }
+            Problem::NotInCatalog => f.write_str("not in the catalog"),
+            Problem::Collision { title, project } => {
+                write!(
+                    f,
+                    "exercise `{title}` shares its number with project `{project}`"
+                )
+            }
+        }
+    }
+}
+
+fn topic_name(topic: Topic) -> &'static str {
+    match topic {
+        Topic::Objective => "learning objective",
+        Topic::Scenario => "real-world scenario",
+        Topic::Concepts => "concepts covered",
+        Topic::Task => "task",
+        Topic::Hints => "hints",
+        Topic::Bonus => "bonus",
+        Topic::Other => "other",
+    }
+}
+
+/// A problem and where it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub path: PathBuf,
+    /// 1-based.
+    pub line: usize,
+    pub problem: Problem,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line, self.problem)
+    }
+}
+
+/// Problems with one header on its own terms.
+pub fn check(template: &Template, header: &Header) -> Vec<Finding> {
+    let at = |line, problem| Finding {
+        path: template.path.clone(),
+        line,
+        problem,
+    };
+    let mut findings = Vec::new();
+
+    if header.number != template.number {
+        findings.push(at(
+            header.title_line,
+            Problem::WrongNumber {
+                found: header.number,
+            },
+        ));
+    }
+    let required = REQUIRED.iter().find(|(style, _)| *style == header.style);
+    for &topic in required.map_or(&[][..], |(_, topics)| topics) {
+        if header.section(topic).is_none() {
+            findings.push(at(header.title_line, Problem::Missing(topic)));
+        }
+    }
+
+    for section in &header.sections {
+        let label = section.label.clone();
+        if section.items.is_empty() {
+            findings.push(at(section.line, Problem::Empty { label }));
+            continue;
+        }
+        if section.items.iter().any(|item| is_truncated(item)) {
+            findings.push(at(
+                section.line,
+                Problem::Truncated {
+                    label: label.clone(),
+                },
+            ));
+        }
+        if section
+            .items
+            .iter()
+            .any(|item| item.split_whitespace().any(|w| w == "TBD"))
+        {
+            findings.push(at(section.line, Problem::Placeholder { label }));
+        }
+    }
+    findings
+}
+
+/// `Some` when the catalog has no entry for the template or names it
+/// differently. Case and surrounding space are ignored.
+pub fn against_catalog(template: &Template, header: &Header, catalog: &Catalog) -> Option<Finding> {
+    let problem = match catalog.get(&template.id()) {
+        None => Problem::NotInCatalog,
+        Some(entry) if !entry.name.trim().eq_ignore_ascii_case(header.title.trim()) => {
+            Problem::TitleMismatch {
+                title: header.title.clone(),
+                catalog: entry.name.clone(),
+            }
+        }
+        Some(_) => return None,
+    };
+    Some(Finding {
+        path: template.path.clone(),
+        line: header.title_line,
+        problem,
+    })
+}
+
+/// `Some` when an exercise and a project share a number but not a title.
+/// The finding is on the exercise: the catalog follows the projects.
+pub fn collision(exercise: (&Template, &Header), project: (&Template, &Header)) -> Option<Finding> {
+    let ((template, header), (other, theirs)) = (exercise, project);
+    debug_assert_eq!((template.kind, other.kind), (Kind::Exercise, Kind::Project));
+    if template.number != other.number || header.title.eq_ignore_ascii_case(&theirs.title) {
+        return None;
+    }
+    Some(Finding {
+        path: template.path.clone(),
+        line: header.title_line,
+        problem: Problem::Collision {
+            title: header.title.clone(),
+            project: theirs.title.clone(),
+        },
+    })
+}
+
+fn is_truncated(text: &str) -> bool {
+    let text = text.trim_end();
+    text.ends_with("...") || text.ends_with('…')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::parse;
+
+    fn template(number: u32, kind: Kind) -> Template {
+        Template {
+            number,
+            kind,
+            path: PathBuf::from(format!("{number:03}.rs")),
+        }
+    }
+
+    fn problems(findings: Vec<Finding>) -> Vec<Problem> {
+        findings.into_iter().map(|f| f.problem).collect()
+    }
+
+    #[test]
+    fn flags_missing_truncated_and_placeholder_sections() {
+        let header = parse(
+            "// ═══\n// 🦀 EXERCISE 152: Todo List\n// ═══\n\
+             // 📚 LEARNING OBJECTIVE:\n//    Keep a list of todos...\n\
+             // 💡 CONCEPTS COVERED:\n//    • TBD\n\
+             // 🎯 YOUR TASK:\n// ═══\n",
+        )
+        .unwrap();
+        let findings = check(&template(153, Kind::Project), &header);
+        assert_eq!(
+            findings[0].to_string(),
+            "153.rs:2: title says exercise 152, the directory says otherwise"
+        );
+        assert_eq!(
+            problems(findings),
+            [
+                Problem::WrongNumber { found: 152 },
+                Problem::Truncated {
+                    label: "LEARNING OBJECTIVE".into()
+                },
+                Problem::Placeholder {
+                    label: "CONCEPTS COVERED".into()
+                },
+                Problem::Empty {
+                    label: "YOUR TASK".into()
+                },
+            ]
+        );
+
+        let plain = parse("// Exercise 020: Shadowing\n// Topics covered: shadowing\n").unwrap();
+        assert_eq!(
+            problems(check(&template(20, Kind::Exercise), &plain)),
+            [Problem::Missing(Topic::Objective)]
+        );
+    }
+
+    #[test]
+    fn compares_titles_with_the_catalog() {
+        let catalog = Catalog::parse(
+            r#"{"exercises": {"002": {"name": "Mutable Variables", "category": "Basics", "difficulty": 1},
+                              "020": {"name": "shadowing", "category": "Basics", "difficulty": 1}}}"#,
+        )
+        .unwrap();
+        let header =
+            |title: &str| parse(&format!("// {title}\n// Learning objective: x\n")).unwrap();
+
+        let two = header("Exercise 002: Variables and Mutability");
+        assert_eq!(
+            against_catalog(&template(2, Kind::Exercise), &two, &catalog).map(|f| f.problem),
+            Some(Problem::TitleMismatch {
+                title: "Variables and Mutability".into(),
+                catalog: "Mutable Variables".into(),
+            })
+        );
+        let twenty = header("Exercise 020: Shadowing");
+        assert_eq!(
+            against_catalog(&template(20, Kind::Exercise), &twenty, &catalog),
+            None
+        );
+        let missing = header("Exercise 021: Type Inference");
+        assert_eq!(
+            against_catalog(&template(21, Kind::Exercise), &missing, &catalog).map(|f| f.problem),
+            Some(Problem::NotInCatalog)
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! `banner` -- parse the header of every exercise template and report the
+//! ones that are incomplete or disagree with the catalog.
+//!
+//! Usage: banner [--root DIR] [--dump] [NNN ...]
+//!
+//! Without numbers every template under `problems/` is checked. Problems
+//! are printed one per line as `path:line: message`; `--dump` prints the
+//! parsed headers as JSON instead. The repository root is found by walking
+//! up from the current directory unless `--root` is given.
+//!
+//! Exit status is 0 when no problems are found, 1 when some are, and 2 on
+//! usage or internal errors.
+
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use rustground::Catalog;
+use serde::Serialize;
+
+const USAGE: &str = "usage: banner [--root DIR] [--dump] [NNN ...]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("banner: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut root = None;
+    let mut dump = false;
+    let mut only = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--dump" => dump = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ => match arg.parse::<u32>() {
+                Ok(number) => only.push(number),
+                Err(_) => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+            },
+        }
+    }
+
+    let root = match root {
+        Some(root) => root,
+        None => {
+            let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+            rustground::find_root(&cwd)
+                .ok_or("not inside the repository; run from it or pass --root")?
+        }
+    };
+    let parsed = banner::parse_all(&root, &only).map_err(|e| format!("{}: {e}", root.display()))?;
+    if let Some(missing) = only
+        .iter()
+        .find(|n| !parsed.iter().any(|p| p.template.number == **n))
+    {
+        return Err(format!("no template numbered {missing:03}"));
+    }
+
+    if dump {
+        let headers: Vec<Dumped> = parsed
+            .iter()
+            .map(|p| Dumped {
+                template: &p.template,
+                header: p.header.as_ref().ok(),
+                error: p.header.as_ref().err().map(ToString::to_string),
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&headers).map_err(|e| e.to_string())?;
+        println!("{json}");
+        return Ok(true);
+    }
+
+    let catalog = Catalog::load(&root).map_err(|e| format!("{}: {e}", root.display()))?;
+    let findings = banner::lint(&parsed, &catalog);
+    // Paths relative to the root read better and match editors' jump-to.
+    let mut out = String::new();
+    for finding in &findings {
+        let path = finding.path.strip_prefix(&root).unwrap_or(&finding.path);
+        out.push_str(&format!(
+            "{}:{}: {}\n",
+            path.display(),
+            finding.line,
+            finding.problem
+        ));
+    }
+    // A closed pipe (`banner | head`) is not an error worth reporting.
+    let _ = io::stdout().lock().write_all(out.as_bytes());
+
+    let files: BTreeSet<_> = findings.iter().map(|f| &f.path).collect();
+    eprintln!(
+        "{} problem(s) in {} of {} template(s)",
+        findings.len(),
+        files.len(),
+        parsed.len()
+    );
+    Ok(findings.is_empty())
+}
+
+#[derive(Serialize)]
+struct Dumped<'a> {
+    template: &'a banner::Template,
+    header: Option<&'a banner::Header>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
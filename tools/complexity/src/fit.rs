@@ -0,0 +1,161 @@
+//! Which complexity class a set of measurements grows like.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// The usual suspects, slowest-growing first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Class {
+    #[serde(rename = "O(1)")]
+    Constant,
+    #[serde(rename = "O(log n)")]
+    Log,
+    #[serde(rename = "O(n)")]
+    Linear,
+    #[serde(rename = "O(n log n)")]
+    NLogN,
+    #[serde(rename = "O(n^2)")]
+    Quadratic,
+    #[serde(rename = "O(n^3)")]
+    Cubic,
+}
+
+impl Class {
+    pub const ALL: [Class; 6] = [
+        Class::Constant,
+        Class::Log,
+        Class::Linear,
+        Class::NLogN,
+        Class::Quadratic,
+        Class::Cubic,
+    ];
+
+    /// The class's growth function at `n`.
+    fn at(self, n: f64) -> f64 {
+        match self {
+            Class::Constant => 1.0,
+            Class::Log => n.log2(),
+            Class::Linear => n,
+            Class::NLogN => n * n.log2(),
+            Class::Quadratic => n * n,
+            Class::Cubic => n * n * n,
+        }
+    }
+
+    /// The power of `n` the class grows like on a log-log plot, rounding
+    /// logarithms down.
+    pub fn exponent(self) -> f64 {
+        match self {
+            Class::Constant | Class::Log => 0.0,
+            Class::Linear | Class::NLogN => 1.0,
+            Class::Quadratic => 2.0,
+            Class::Cubic => 3.0,
+        }
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Class::Constant => "O(1)",
+            Class::Log => "O(log n)",
+            Class::Linear => "O(n)",
+            Class::NLogN => "O(n log n)",
+            Class::Quadratic => "O(n^2)",
+            Class::Cubic => "O(n^3)",
+        })
+    }
+}
+
+/// The cost measured at one input size.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Point {
+    pub n: usize,
+    pub cost: u64,
+}
+
+/// The class whose growth function tracks `points` best: the one that
+/// leaves `cost / f(n)` flattest across the sizes. `None` with fewer than
+/// two sizes.
+pub fn classify(points: &[Point]) -> Option<Class> {
+    if points.len() < 2 {
+        return None;
+    }
+    let flatness = |class: Class| {
+        let residual: Vec<(f64, f64)> = points
+            .iter()
+            .map(|p| (p.n as f64, cost(p) / class.at(p.n as f64)))
+            .collect();
+        slope(&residual).abs()
+    };
+    Class::ALL
+        .into_iter()
+        .min_by(|a, b| flatness(*a).total_cmp(&flatness(*b)))
+}
+
+/// The exponent `k` in `cost ≈ c·n^k`, fitted on a log-log plot.
+pub fn growth(points: &[Point]) -> Option<f64> {
+    if points.len() < 2 {
+        return None;
+    }
+    let raw: Vec<(f64, f64)> = points.iter().map(|p| (p.n as f64, cost(p))).collect();
+    Some(slope(&raw))
+}
+
+/// Costs of zero (nothing compared) are treated as one so logs stay finite.
+fn cost(point: &Point) -> f64 {
+    point.cost.max(1) as f64
+}
+
+/// Least-squares slope of `ln y` against `ln x`.
+fn slope(points: &[(f64, f64)]) -> f64 {
+    let logs: Vec<(f64, f64)> = points.iter().map(|&(x, y)| (x.ln(), y.ln())).collect();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|p| p.0).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|p| p.1).sum::<f64>() / count;
+    let covariance: f64 = logs.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    covariance / variance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measured(cost: impl Fn(f64) -> f64) -> Vec<Point> {
+        [256, 512, 1024, 2048, 4096]
+            .into_iter()
+            .map(|n| Point {
+                n,
+                cost: cost(n as f64) as u64,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classifies_textbook_comparison_counts() {
+        let bubble = measured(|n| n * (n - 1.0) / 2.0);
+        let insertion = measured(|n| n * n / 4.0 + n);
+        let merge = measured(|n| n * n.log2() - n + 1.0);
+        let heap = measured(|n| 2.0 * n * n.log2() + 3.0 * n);
+        let linear = measured(|n| 64.0 * n / 2.0);
+        let binary = measured(|n| 64.0 * 2.0 * (n.log2() + 1.0));
+        assert_eq!(classify(&bubble), Some(Class::Quadratic));
+        assert_eq!(classify(&insertion), Some(Class::Quadratic));
+        assert_eq!(classify(&merge), Some(Class::NLogN));
+        assert_eq!(classify(&heap), Some(Class::NLogN));
+        assert_eq!(classify(&linear), Some(Class::Linear));
+        assert_eq!(classify(&binary), Some(Class::Log));
+        assert_eq!(classify(&measured(|_| 0.0)), Some(Class::Constant));
+        assert_eq!(classify(&bubble[..1]), None);
+    }
+
+    #[test]
+    fn growth_is_the_log_log_slope() {
+        let quadratic = growth(&measured(|n| 3.0 * n * n)).unwrap();
+        assert!((quadratic - 2.0).abs() < 1e-6, "{quadratic}");
+        let linear = growth(&measured(|n| 10.0 * n)).unwrap();
+        assert!((linear - 1.0).abs() < 1e-6, "{linear}");
+    }
+}
@@ -0,0 +1,409 @@
+//! The program that calls a submission on growing inputs.
+//!
+//! The submission's functions take `i32`s, so the harness is compiled in
+//! the same crate root, after a `type i32 = Counted;` alias that shadows
+//! the primitive there. Every comparison the learner's code makes then
+//! goes through `Counted`'s `PartialOrd` and `Ord` impls, which count it.
+//!
+//! Moving an element runs no code, whether by assignment, `swap` or
+//! `rotate_right`, so moves cannot be counted the same way. Instead
+//! `Counted` carries 124 bytes of ballast, which makes a move cost about
+//! as much as a comparison, and every call is timed as well: a sort that
+//! makes O(n log n) comparisons but O(n^2) moves, such as binary insertion
+//! sort, has comparisons that fit and a running time that does not.
+//!
+//! The alias breaks code that treats elements as numbers (`x as usize`,
+//! `vec![0; n]` filled with elements), so [`build`] falls back to a plain
+//! build that times each call instead.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use grader::compile::{compile_binary, CompileError};
+use grader::process::run_with_timeout;
+use grader::splice::{strip_fn, strip_tests};
+use grader::ScratchDir;
+
+use crate::fit::Point;
+use crate::targets::{Measure, Shape, Target};
+
+/// The harness prints at most this much.
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// Timed calls are repeated and the fastest kept, to shed scheduling noise.
+const TIMED_REPEATS: usize = 5;
+
+/// Searches run this many queries per size.
+const QUERIES: usize = 64;
+
+/// Exit code the harness uses when the function gives a wrong answer.
+const WRONG_ANSWER: i32 = 3;
+
+/// The element type and counters, compiled into every harness.
+const COUNTED: &str = r#"
+mod complexity_counted {
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::fmt;
+
+    thread_local! {
+        static COMPARISONS: Cell<u64> = const { Cell::new(0) };
+    }
+
+    pub fn reset() {
+        COMPARISONS.with(|c| c.set(0));
+    }
+
+    pub fn comparisons() -> u64 {
+        COMPARISONS.with(Cell::get)
+    }
+
+    fn tick() {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+    }
+
+    /// An `i32` that counts every comparison made with it, padded to 128
+    /// bytes so that moving it takes measurable time.
+    #[derive(Clone, Copy, Hash)]
+    pub struct Counted(pub i32, [u32; 31]);
+
+    impl Counted {
+        pub const MAX: Counted = Counted::new(i32::MAX);
+        pub const MIN: Counted = Counted::new(i32::MIN);
+
+        pub const fn new(value: i32) -> Self {
+            Counted(value, [0; 31])
+        }
+    }
+
+    impl Default for Counted {
+        fn default() -> Self {
+            Counted::new(0)
+        }
+    }
+
+    impl PartialEq for Counted {
+        fn eq(&self, other: &Self) -> bool {
+            tick();
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Counted {}
+
+    impl PartialOrd for Counted {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Counted {
+        fn cmp(&self, other: &Self) -> Ordering {
+            tick();
+            self.0.cmp(&other.0)
+        }
+    }
+
+    impl fmt::Debug for Counted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl fmt::Display for Counted {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+}
+"#;
+
+/// A compiled harness.
+#[derive(Debug)]
+pub struct Harness {
+    /// Keeps the binary's directory alive.
+    _scratch: ScratchDir,
+    binary: PathBuf,
+    pub measure: Measure,
+}
+
+/// Why a harness could not be built.
+#[derive(Debug)]
+pub enum BuildError {
+    /// Neither build compiled; carries the plain build's error.
+    Rejected(String),
+    Io(io::Error),
+}
+
+impl From<io::Error> for BuildError {
+    fn from(err: io::Error) -> Self {
+        BuildError::Io(err)
+    }
+}
+
+/// Compiles `submission` into a harness for `target`, instrumented if the
+/// code allows it and timed otherwise.
+pub fn build(target: &Target, submission: &str, timeout: Duration) -> Result<Harness, BuildError> {
+    let mut measures = vec![Measure::Nanoseconds];
+    if target.measure == Measure::Comparisons {
+        measures.insert(0, Measure::Comparisons);
+    }
+
+    let mut last = None;
+    for measure in measures {
+        let scratch = ScratchDir::new("complexity")?;
+        let source = source(target, submission, measure);
+        match compile_binary(&source, scratch.path(), timeout) {
+            Ok(binary) => {
+                return Ok(Harness {
+                    _scratch: scratch,
+                    binary,
+                    measure,
+                })
+            }
+            Err(CompileError::Io(err)) => return Err(err.into()),
+            Err(err) => last = Some(err.to_string()),
+        }
+    }
+    Err(BuildError::Rejected(last.unwrap_or_default()))
+}
+
+/// How a harness run ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Finished,
+    /// The function's answer was wrong at this size.
+    Wrong {
+        n: usize,
+    },
+    /// Killed at the deadline, part-way through the sizes.
+    TimedOut,
+    /// The function panicked or the process died.
+    Crashed {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    /// One point per size that finished, in the harness's measure.
+    pub points: Vec<Point>,
+    /// Nanoseconds for the same sizes, whatever the measure.
+    pub times: Vec<Point>,
+    pub outcome: Outcome,
+}
+
+impl Harness {
+    pub fn run(&self, timeout: Duration) -> io::Result<Run> {
+        let out = run_with_timeout(&mut Command::new(&self.binary), timeout, MAX_OUTPUT_BYTES)?;
+        let mut points = Vec::new();
+        let mut times = Vec::new();
+        let mut wrong = None;
+        for line in out.stdout.lines() {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next(), words.next()) {
+                (Some("wrong"), Some(n), None, None) => wrong = n.parse().ok(),
+                (Some(n), Some(cost), Some(nanos), None) => {
+                    if let (Ok(n), Ok(cost), Ok(nanos)) = (n.parse(), cost.parse(), nanos.parse()) {
+                        points.push(Point { n, cost });
+                        times.push(Point { n, cost: nanos });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let outcome = if out.timed_out() {
+            Outcome::TimedOut
+        } else if let Some(n) = wrong {
+            Outcome::Wrong { n }
+        } else if out.success() {
+            Outcome::Finished
+        } else {
+            let code = out.status.and_then(|s| s.code());
+            let message = panic_message(&out.stderr)
+                .unwrap_or_else(|| format!("exited with status {code:?}"));
+            Outcome::Crashed { message }
+        };
+        Ok(Run {
+            points,
+            times,
+            outcome,
+        })
+    }
+}
+
+/// The first line of a Rust panic report, minus the thread prefix.
+fn panic_message(stderr: &str) -> Option<String> {
+    let mut lines = stderr.lines();
+    lines.find(|l| l.starts_with("thread '"))?;
+    lines.next().map(str::to_string)
+}
+
+/// The harness program: the submission without its `main` and tests, the
+/// element type alias when counting comparisons, and a driver that prints
+/// `n cost nanoseconds` per size (or `wrong n`).
+pub fn source(target: &Target, submission: &str, measure: Measure) -> String {
+    let body = strip_fn(&strip_tests(submission), "main");
+    // Inner attributes must open the file, ahead of the harness's items
+    let (attributes, code): (Vec<&str>, Vec<&str>) = body
+        .lines()
+        .partition(|l| l.trim_start().starts_with("#!["));
+
+    let mut out = String::from("#![allow(dead_code, unused, non_camel_case_types)]\n");
+    for attribute in attributes {
+        out.push_str(attribute);
+        out.push('\n');
+    }
+    out.push_str(&code.join("\n"));
+    out.push('\n');
+    if measure == Measure::Comparisons {
+        out.push_str("\ntype i32 = complexity_counted::Counted;\n");
+    }
+    out.push_str(COUNTED);
+    out.push_str(&driver(target, measure));
+    out.push_str("\nfn main() {\n    complexity_driver::run();\n}\n");
+    out
+}
+
+fn driver(target: &Target, measure: Measure) -> String {
+    let (element, wrap, unwrap) = match measure {
+        Measure::Comparisons => (
+            "crate::complexity_counted::Counted",
+            "crate::complexity_counted::Counted::new(v)",
+            "e.0",
+        ),
+        Measure::Nanoseconds => ("i32", "v", "e"),
+    };
+    let (reset, cost) = match measure {
+        Measure::Comparisons => (
+            "crate::complexity_counted::reset()",
+            "crate::complexity_counted::comparisons()",
+        ),
+        Measure::Nanoseconds => ("()", "nanos"),
+    };
+    let sizes = target
+        .sizes
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        r#"
+mod complexity_driver {{
+    use std::time::Instant;
+
+    type Element = {element};
+
+    fn wrap(v: i32) -> Element {{
+        {wrap}
+    }}
+
+    fn unwrap(e: Element) -> i32 {{
+        {unwrap}
+    }}
+
+    /// Deterministic, so every run sees the same inputs.
+    #[derive(Clone)]
+    struct Lcg(u64);
+
+    impl Lcg {{
+        fn below(&mut self, n: u64) -> u64 {{
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) % n
+        }}
+    }}
+
+    fn wrong(n: usize) -> ! {{
+        println!("wrong {{n}}");
+        std::process::exit({WRONG_ANSWER});
+    }}
+
+    pub fn run() {{
+        for n in [{sizes}] {{
+            // Every repeat gets the same inputs, so counts agree
+            let rng = Lcg(0x5EED ^ n as u64);
+            let (mut cost, mut nanos) = (u64::MAX, u64::MAX);
+            for _ in 0..{TIMED_REPEATS} {{
+                let (c, t) = measure(n, &mut rng.clone());
+                cost = cost.min(c);
+                nanos = nanos.min(t);
+            }}
+            println!("{{n}} {{cost}} {{nanos}}");
+        }}
+    }}
+"#
+    );
+    match target.shape {
+        Shape::Sort { span } => {
+            let _ = write!(
+                out,
+                r#"
+    fn measure(n: usize, rng: &mut Lcg) -> (u64, u64) {{
+        let values: Vec<i32> = (0..n)
+            .map(|_| rng.below(2 * {span} + 1) as i32 - {span})
+            .collect();
+        let mut input: Vec<Element> = values.iter().map(|&v| wrap(v)).collect();
+        {reset};
+        let start = Instant::now();
+        super::{function}(&mut input);
+        let nanos = start.elapsed().as_nanos() as u64;
+        let cost = {cost};
+        let mut expected = values;
+        expected.sort_unstable();
+        if input.len() != n || input.iter().zip(&expected).any(|(&e, &v)| unwrap(e) != v) {{
+            wrong(n);
+        }}
+        (cost, nanos)
+    }}
+}}
+"#,
+                function = target.function,
+            );
+        }
+        Shape::Search { sorted } => {
+            let _ = write!(
+                out,
+                r#"
+    fn measure(n: usize, rng: &mut Lcg) -> (u64, u64) {{
+        // Even values are present, odd ones are not
+        let mut values: Vec<i32> = (0..n as i32).map(|i| 2 * i).collect();
+        if !{sorted} {{
+            for i in (1..n).rev() {{
+                values.swap(i, rng.below(i as u64 + 1) as usize);
+            }}
+        }}
+        let input: Vec<Element> = values.iter().map(|&v| wrap(v)).collect();
+        let (mut total, mut time) = (0, 0);
+        for _ in 0..{QUERIES} {{
+            let target = rng.below(2 * n as u64) as i32;
+            {reset};
+            let start = Instant::now();
+            let found = super::{function}(&input, wrap(target));
+            let nanos = start.elapsed().as_nanos() as u64;
+            total += {cost};
+            time += nanos;
+            let right = match found {{
+                Some(i) => values.get(i) == Some(&target),
+                None => target % 2 == 1,
+            }};
+            if !right {{
+                wrong(n);
+            }}
+        }}
+        (total, time)
+    }}
+}}
+"#,
+                function = target.function,
+            );
+        }
+    }
+    out
+}
@@ -0,0 +1,303 @@
+//! Empirical complexity checks for the sorting and searching exercises.
+//!
+//! Exercises 101-105 and 126-129 ask for a particular algorithm, and their
+//! tests only check answers. [`check`] also checks how the submission
+//! gets there:
+//!
+//! - it calls the learner's function on growing inputs through a harness
+//!   ([`harness`]) that counts comparisons with an instrumented element
+//!   type, or times the calls when the code cannot take that type; sorts
+//!   are timed alongside their comparisons, to catch quadratic moving;
+//! - it fits the costs to a complexity class ([`fit`]) and rejects a
+//!   class other than the exercise's, e.g. an O(n^2) `heap_sort` or a
+//!   `bubble_sort` that is really a merge sort;
+//! - it scans the code for standard-library APIs that would do the work
+//!   ([`scan`]), such as `slice::sort` inside `merge_sort`.
+
+pub mod fit;
+pub mod harness;
+pub mod scan;
+pub mod targets;
+
+use std::io;
+use std::time::Duration;
+
+use serde::Serialize;
+
+pub use fit::{Class, Point};
+pub use scan::Forbidden;
+pub use targets::{Measure, Shape, Target};
+
+use harness::{BuildError, Outcome};
+
+/// Limits for one check.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub compile_timeout: Duration,
+    /// For the whole run over every size.
+    pub run_timeout: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            compile_timeout: Duration::from_secs(30),
+            run_timeout: Duration::from_secs(20),
+        }
+    }
+}
+
+/// Time measurements may grow this much faster than the expected class on
+/// a log-log plot before the submission counts as too slow.
+const TIMING_SLACK: f64 = 0.5;
+
+/// What checking one submission found.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub exercise: String,
+    pub function: String,
+    pub expected: Class,
+    /// `None` if the harness did not compile.
+    pub measure: Option<Measure>,
+    pub points: Vec<Point>,
+    /// The class the costs fit best; `None` with too few sizes measured.
+    pub measured: Option<Class>,
+    /// Fitted exponent of `cost ≈ c·n^k`.
+    pub growth: Option<f64>,
+    /// Fitted exponent of the running time, whatever the measure. Moving
+    /// elements shows here and not in comparisons.
+    pub time_growth: Option<f64>,
+    pub forbidden: Vec<Forbidden>,
+    /// Why the submission is rejected; empty when it is accepted.
+    pub problems: Vec<String>,
+    pub accepted: bool,
+}
+
+/// Checks `source` against the exercise's expected complexity and
+/// forbidden APIs. Errors are failures of the checker itself, including
+/// asking about an exercise it does not know.
+pub fn check(exercise: &str, source: &str, opts: &Options) -> io::Result<Report> {
+    let target = targets::for_exercise(exercise).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("exercise {exercise} has no complexity check"),
+        )
+    })?;
+
+    let code = grader::splice::strip_fn(&grader::splice::strip_tests(source), "main");
+    let forbidden = scan::scan(&code, target.forbidden);
+    let mut problems: Vec<String> = forbidden
+        .iter()
+        .map(|f| format!("line {}: `{}` is not allowed here", f.line, f.api))
+        .collect();
+
+    let mut report = Report {
+        exercise: target.exercise.to_string(),
+        function: target.function.to_string(),
+        expected: target.expected,
+        measure: None,
+        points: Vec::new(),
+        measured: None,
+        growth: None,
+        time_growth: None,
+        forbidden,
+        problems: Vec::new(),
+        accepted: false,
+    };
+
+    let harness = match harness::build(target, source, opts.compile_timeout) {
+        Ok(harness) => harness,
+        Err(BuildError::Io(err)) => return Err(err),
+        Err(BuildError::Rejected(error)) => {
+            problems.push(format!("does not compile: {error}"));
+            report.problems = problems;
+            return Ok(report);
+        }
+    };
+    let run = harness.run(opts.run_timeout)?;
+    report.measure = Some(harness.measure);
+    report.measured = fit::classify(&run.points);
+    report.growth = fit::growth(&run.points);
+    report.time_growth = fit::growth(&run.times);
+    report.points = run.points;
+
+    let reached = report.points.last().map_or(0, |p| p.n);
+    match run.outcome {
+        Outcome::Finished => {}
+        Outcome::Wrong { n } => problems.push(format!(
+            "`{}` gave a wrong answer for n = {n}",
+            target.function
+        )),
+        Outcome::TimedOut => problems.push(format!(
+            "did not finish the larger inputs within {}s (last finished n = {reached})",
+            opts.run_timeout.as_secs()
+        )),
+        Outcome::Crashed { message } => {
+            problems.push(format!("crashed after n = {reached}: {message}"))
+        }
+    }
+
+    if report.points.len() == target.sizes.len() {
+        match harness.measure {
+            Measure::Comparisons => {
+                if report.measured != Some(target.expected) {
+                    problems.push(format!(
+                        "comparisons grow like {}, expected {}",
+                        report.measured.map_or("?".to_string(), |c| c.to_string()),
+                        target.expected
+                    ));
+                }
+                let time_growth = report.time_growth.unwrap_or(f64::INFINITY);
+                if matches!(target.shape, Shape::Sort { .. })
+                    && time_growth > target.expected.exponent() + TIMING_SLACK
+                {
+                    problems.push(format!(
+                        "running time grows like n^{time_growth:.1}, expected {}; \
+                         elements are moved more often than they are compared",
+                        target.expected
+                    ));
+                }
+            }
+            Measure::Nanoseconds => {
+                let growth = report.growth.unwrap_or(f64::INFINITY);
+                if growth > target.expected.exponent() + TIMING_SLACK {
+                    problems.push(format!(
+                        "running time grows like n^{growth:.1}, expected {}",
+                        target.expected
+                    ));
+                }
+            }
+        }
+    }
+
+    report.accepted = problems.is_empty();
+    report.problems = problems;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn checked(exercise: &str, source: &str) -> Report {
+        check(exercise, source, &Options::default()).unwrap()
+    }
+
+    #[test]
+    fn solutions_are_accepted() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        for target in targets::TARGETS {
+            let id = target.exercise;
+            let solution =
+                std::fs::read_to_string(root.join(format!("solutions/{id}.rs"))).unwrap();
+            let report = checked(id, &solution);
+            assert!(report.accepted, "{id}: {report:?}");
+            assert_eq!(report.measure, Some(target.measure), "{id}");
+        }
+    }
+
+    #[test]
+    fn std_sort_is_forbidden() {
+        let source = "fn merge_sort(arr: &mut [i32]) {\n    arr.sort();\n}\n";
+        let report = checked("126", source);
+        assert!(!report.accepted);
+        assert_eq!(
+            report.forbidden,
+            [Forbidden {
+                api: "sort".into(),
+                line: 2
+            }]
+        );
+        // Otherwise it looks like a fine O(n log n) sort
+        assert_eq!(report.measured, Some(Class::NLogN));
+    }
+
+    #[test]
+    fn wrong_class_is_rejected() {
+        let source = "fn heap_sort(arr: &mut [i32]) {\n\
+                      \x20   for i in 0..arr.len() {\n\
+                      \x20       for j in 0..arr.len() - 1 - i {\n\
+                      \x20           if arr[j] > arr[j + 1] {\n\
+                      \x20               arr.swap(j, j + 1);\n\
+                      \x20           }\n\
+                      \x20       }\n\
+                      \x20   }\n\
+                      }\n";
+        let report = checked("128", source);
+        assert!(!report.accepted);
+        assert_eq!(report.measured, Some(Class::Quadratic));
+
+        let source = "fn binary_search(arr: &[i32], target: i32) -> Option<usize> {\n\
+                      \x20   arr.iter().position(|&x| x == target)\n\
+                      }\n";
+        let report = checked("105", source);
+        assert!(!report.accepted);
+        assert_eq!(report.measured, Some(Class::Linear));
+    }
+
+    #[test]
+    fn wrong_answers_and_panics_are_rejected() {
+        let source = "fn insertion_sort(arr: &mut [i32]) {\n\
+                      \x20   if arr.len() > 1 && arr[0] > arr[1] {\n\
+                      \x20       arr.swap(0, 1);\n\
+                      \x20   }\n\
+                      }\n";
+        let report = checked("103", source);
+        assert!(!report.accepted);
+        assert!(report.points.is_empty());
+        assert!(
+            report.problems[0].contains("wrong answer for n = 256"),
+            "{report:?}"
+        );
+
+        let source = "fn bubble_sort(arr: &mut [i32]) {\n    todo!()\n}\n";
+        let report = checked("101", source);
+        assert!(!report.accepted);
+        assert!(
+            report.problems[0].contains("not yet implemented"),
+            "{report:?}"
+        );
+    }
+
+    #[test]
+    fn quadratic_moves_are_rejected() {
+        // Binary insertion sort: O(n log n) comparisons, O(n^2) moves
+        let shifting = "fn merge_sort(arr: &mut [i32]) {\n\
+                        \x20   for i in 1..arr.len() {\n\
+                        \x20       let x = arr[i];\n\
+                        \x20       let (mut lo, mut hi) = (0, i);\n\
+                        \x20       while lo < hi {\n\
+                        \x20           let mid = (lo + hi) / 2;\n\
+                        \x20           if arr[mid] <= x { lo = mid + 1 } else { hi = mid }\n\
+                        \x20       }\n\
+                        \x20       let mut j = i;\n\
+                        \x20       while j > lo {\n\
+                        \x20           arr[j] = arr[j - 1];\n\
+                        \x20           j -= 1;\n\
+                        \x20       }\n\
+                        \x20       arr[lo] = x;\n\
+                        \x20   }\n\
+                        }\n";
+        let rotating = "fn merge_sort(arr: &mut [i32]) {\n\
+                        \x20   for i in 1..arr.len() {\n\
+                        \x20       let x = arr[i];\n\
+                        \x20       let (mut lo, mut hi) = (0, i);\n\
+                        \x20       while lo < hi {\n\
+                        \x20           let mid = (lo + hi) / 2;\n\
+                        \x20           if arr[mid] <= x { lo = mid + 1 } else { hi = mid }\n\
+                        \x20       }\n\
+                        \x20       arr[lo..=i].rotate_right(1);\n\
+                        \x20   }\n\
+                        }\n";
+        for source in [shifting, rotating] {
+            let report = checked("126", source);
+            assert!(!report.accepted, "{report:?}");
+            assert_eq!(report.measured, Some(Class::NLogN));
+            assert!(
+                report.problems[0].contains("moved more often"),
+                "{report:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! `complexity` -- check that a sorting or searching submission has the
+//! expected complexity and does not lean on std, and print a JSON report.
+//!
+//! Usage: complexity --exercise NNN [--compile-timeout SECS] [--run-timeout SECS]
+//!                   <FILE | ->
+//!
+//! Exercises 101-105 and 126-129 are supported.
+//!
+//! Exit status is 0 when the submission is accepted, 1 when it is
+//! rejected, and 2 on usage or internal errors.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use complexity::Options;
+
+const USAGE: &str = "usage: complexity --exercise NNN [--compile-timeout SECS] \
+                     [--run-timeout SECS] <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("complexity: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut opts = Options::default();
+    let mut exercise = None;
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => exercise = Some(value(&mut args, &arg)?),
+            "--compile-timeout" => opts.compile_timeout = seconds(&mut args, &arg)?,
+            "--run-timeout" => opts.run_timeout = seconds(&mut args, &arg)?,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let exercise = exercise.ok_or(USAGE)?;
+    let exercise = match exercise.parse::<u32>() {
+        Ok(n) => format!("{n:03}"),
+        Err(_) => return Err(format!("--exercise: `{exercise}` is not a number")),
+    };
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let report = complexity::check(&exercise, &source, &opts).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.accepted)
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
+
+fn seconds(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<Duration, String> {
+    let raw = value(args, flag)?;
+    raw.parse::<u64>()
+        .map(Duration::from_secs)
+        .map_err(|_| format!("{flag}: `{raw}` is not a number of seconds"))
+}
@@ -0,0 +1,100 @@
+//! Finding standard-library shortcuts in a submission.
+//!
+//! A `merge_sort` that calls `slice::sort` passes every test and every
+//! growth check, so the submission's code (comments and strings aside) is
+//! searched for the APIs an exercise rules out.
+
+use serde::Serialize;
+
+use grader::splice::code_only;
+
+/// One use of a forbidden API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Forbidden {
+    pub api: String,
+    /// 1-based.
+    pub line: usize,
+}
+
+/// Every use of `apis` in `source`, in order. Lowercase names are methods
+/// and only count when called through `.` or `::`, so a learner's own
+/// `fn binary_search` is fine but `arr.binary_search(&x)` is not.
+/// Capitalised names are types and count wherever they appear.
+pub fn scan(source: &str, apis: &[&str]) -> Vec<Forbidden> {
+    let code = code_only(source);
+    let mut found = Vec::new();
+    for (index, line) in code.lines().enumerate() {
+        for (start, word) in identifiers(line) {
+            let Some(api) = apis.iter().find(|api| **api == word) else {
+                continue;
+            };
+            let before = line[..start].trim_end();
+            let is_type = api.starts_with(char::is_uppercase);
+            if is_type || before.ends_with('.') || before.ends_with("::") {
+                found.push(Forbidden {
+                    api: api.to_string(),
+                    line: index + 1,
+                });
+            }
+        }
+    }
+    found
+}
+
+/// `(offset, identifier)` for each identifier in `line`.
+fn identifiers(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut rest = line.char_indices().peekable();
+    std::iter::from_fn(move || loop {
+        let (start, c) = rest.next()?;
+        if !is_ident(c) {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, c)) = rest.peek() {
+            if !is_ident(c) {
+                break;
+            }
+            end = i + c.len_utf8();
+            rest.next();
+        }
+        return Some((start, &line[start..end]));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_method_calls_and_types_outside_comments() {
+        let source = "fn merge_sort(arr: &mut [i32]) {\n\
+                      \x20   // arr.sort() would be cheating\n\
+                      \x20   let s = \"arr.sort()\";\n\
+                      \x20   arr\n\
+                      \x20       .sort_unstable();\n\
+                      \x20   <[i32]>::sort(arr);\n\
+                      \x20   let heap = std::collections::BinaryHeap::from(arr.to_vec());\n\
+                      }\n";
+        let apis = ["sort", "sort_unstable", "BinaryHeap"];
+        let found: Vec<(String, usize)> = scan(source, &apis)
+            .into_iter()
+            .map(|f| (f.api, f.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("sort_unstable".to_string(), 5),
+                ("sort".to_string(), 6),
+                ("BinaryHeap".to_string(), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn own_functions_with_forbidden_names_are_fine() {
+        let source = "fn binary_search(arr: &[i32], t: i32) -> Option<usize> { None }\n\
+                      fn sort(v: &mut [i32]) {}\nfn main() { sort(&mut []); binary_search(&[], 1); }\n";
+        assert!(scan(source, &["binary_search", "sort"]).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+//! The exercises the checker knows and what each one must look like.
+
+use serde::Serialize;
+
+use crate::fit::Class;
+
+/// What the learner's function does, and so how it is called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// `fn(&mut [i32])`, given random values in `-span..=span`.
+    Sort { span: i32 },
+    /// `fn(&[i32], i32) -> Option<usize>` over distinct values, sorted or
+    /// shuffled; half the queries are absent.
+    Search { sorted: bool },
+}
+
+/// How the cost of one call is counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Measure {
+    /// Comparisons made by the instrumented element type. Exact and
+    /// repeatable, so the fitted class must be the expected one.
+    Comparisons,
+    /// Wall-clock time, for code the element type cannot stand in for.
+    /// Noisy, so it only has to grow no faster than expected.
+    Nanoseconds,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    /// Three-digit exercise number, e.g. `"126"`.
+    pub exercise: &'static str,
+    pub function: &'static str,
+    pub shape: Shape,
+    pub expected: Class,
+    pub measure: Measure,
+    pub sizes: &'static [usize],
+    /// Standard-library APIs that would do the exercise's work for it;
+    /// see [`crate::scan::scan`].
+    pub forbidden: &'static [&'static str],
+}
+
+const SORT_SIZES: &[usize] = &[256, 512, 1024, 2048, 4096];
+const SEARCH_SIZES: &[usize] = &[1024, 4096, 16384, 65536];
+const COUNTING_SIZES: &[usize] = &[8192, 16384, 32768, 65536, 131072];
+
+/// Random sort inputs are drawn from `-SPAN..=SPAN`, wide enough that
+/// duplicates are rare.
+const SPAN: i32 = 1_000_000;
+
+const SORTING_APIS: &[&str] = &[
+    "sort",
+    "sort_by",
+    "sort_by_key",
+    "sort_by_cached_key",
+    "sort_unstable",
+    "sort_unstable_by",
+    "sort_unstable_by_key",
+    "select_nth_unstable",
+    "select_nth_unstable_by",
+    "select_nth_unstable_by_key",
+    "into_sorted_vec",
+    "BinaryHeap",
+    "BTreeMap",
+    "BTreeSet",
+];
+
+const SEARCHING_APIS: &[&str] = &[
+    "binary_search",
+    "binary_search_by",
+    "binary_search_by_key",
+    "partition_point",
+];
+
+const fn sort(exercise: &'static str, function: &'static str, expected: Class) -> Target {
+    Target {
+        exercise,
+        function,
+        shape: Shape::Sort { span: SPAN },
+        expected,
+        measure: Measure::Comparisons,
+        sizes: SORT_SIZES,
+        forbidden: SORTING_APIS,
+    }
+}
+
+const fn search(exercise: &'static str, function: &'static str, sorted: bool) -> Target {
+    Target {
+        exercise,
+        function,
+        shape: Shape::Search { sorted },
+        expected: if sorted { Class::Log } else { Class::Linear },
+        measure: Measure::Comparisons,
+        sizes: SEARCH_SIZES,
+        forbidden: SEARCHING_APIS,
+    }
+}
+
+/// Sorted by exercise.
+pub const TARGETS: &[Target] = &[
+    sort("101", "bubble_sort", Class::Quadratic),
+    sort("102", "selection_sort", Class::Quadratic),
+    sort("103", "insertion_sort", Class::Quadratic),
+    search("104", "linear_search", false),
+    search("105", "binary_search", true),
+    sort("126", "merge_sort", Class::NLogN),
+    sort("127", "quick_sort", Class::NLogN),
+    sort("128", "heap_sort", Class::NLogN),
+    // Counting sort compares nothing and indexes by value (`x as usize`),
+    // which the element type cannot support; it is timed instead, over a
+    // small value range so the count array stays cheap.
+    Target {
+        exercise: "129",
+        function: "counting_sort",
+        shape: Shape::Sort { span: 500 },
+        expected: Class::Linear,
+        measure: Measure::Nanoseconds,
+        sizes: COUNTING_SIZES,
+        forbidden: SORTING_APIS,
+    },
+];
+
+pub fn for_exercise(exercise: &str) -> Option<&'static Target> {
+    TARGETS
+        .binary_search_by(|t| t.exercise.cmp(exercise))
+        .ok()
+        .map(|i| &TARGETS[i])
+}
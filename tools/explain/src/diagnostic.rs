@@ -0,0 +1,264 @@
+//! rustc's JSON diagnostics, trimmed down to what the editor shows.
+//!
+//! `rustc --error-format=json` prints one JSON object per diagnostic on
+//! stderr. Each is turned into a [`Diagnostic`] with its primary span,
+//! the other labelled spans, rustc's notes, its first machine-applicable
+//! suggestion (with a preview of the fixed lines) and, where the error
+//! code has one, the exercise that teaches the rule it enforces.
+
+use serde::{Deserialize, Serialize};
+
+use crate::lessons;
+
+/// One compiler error, ready for the editor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// Error code such as `"E0382"`; some errors have none.
+    pub code: Option<String>,
+    pub message: String,
+    /// Where the error is; `None` for errors about the crate as a whole.
+    pub span: Option<Span>,
+    /// Secondary spans rustc labelled, e.g. where a value was moved.
+    pub labels: Vec<Span>,
+    /// rustc's `note:` and `help:` lines that carry no code change.
+    pub notes: Vec<String>,
+    pub suggestion: Option<Suggestion>,
+    pub revisit: Option<Revisit>,
+    /// The diagnostic as rustc prints it on a terminal.
+    pub rendered: String,
+}
+
+/// A range of the submission; lines and columns are 1-based, columns
+/// count characters and `end_column` is exclusive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub label: Option<String>,
+}
+
+/// A code change rustc proposes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Suggestion {
+    pub message: String,
+    pub edits: Vec<Edit>,
+    /// The lines the edits touch, with the edits applied.
+    pub preview: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Edit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// The exercise to go back to for this kind of error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Revisit {
+    pub exercise: String,
+    pub title: String,
+    pub tip: String,
+}
+
+/// The subset of rustc's JSON diagnostic format we read.
+#[derive(Debug, Deserialize)]
+struct Raw {
+    message: String,
+    code: Option<RawCode>,
+    level: String,
+    #[serde(default)]
+    spans: Vec<RawSpan>,
+    #[serde(default)]
+    children: Vec<Raw>,
+    rendered: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawSpan {
+    file_name: String,
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    line_end: usize,
+    column_start: usize,
+    column_end: usize,
+    is_primary: bool,
+    label: Option<String>,
+    suggested_replacement: Option<String>,
+}
+
+impl RawSpan {
+    fn to_span(&self) -> Span {
+        Span {
+            line: self.line_start,
+            column: self.column_start,
+            end_line: self.line_end,
+            end_column: self.column_end,
+            label: self.label.clone().filter(|l| !l.is_empty()),
+        }
+    }
+}
+
+/// The errors in rustc's JSON output for `source`, which was compiled as
+/// `file_name`. Warnings, the closing "aborting due to" summary and any
+/// lines that are not diagnostics are skipped.
+pub fn parse(stderr: &str, source: &str, file_name: &str) -> Vec<Diagnostic> {
+    stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Raw>(line).ok())
+        .filter(|raw| raw.level == "error" && !raw.message.starts_with("aborting due to"))
+        .map(|raw| convert(raw, source, file_name))
+        .collect()
+}
+
+fn convert(raw: Raw, source: &str, file_name: &str) -> Diagnostic {
+    let ours = |s: &&RawSpan| s.file_name == file_name;
+    let span = raw
+        .spans
+        .iter()
+        .filter(ours)
+        .find(|s| s.is_primary)
+        .or_else(|| raw.spans.iter().find(|s| s.is_primary))
+        .map(RawSpan::to_span);
+    let labels = raw
+        .spans
+        .iter()
+        .filter(ours)
+        .filter(|s| !s.is_primary && s.label.as_deref().is_some_and(|l| !l.is_empty()))
+        .map(RawSpan::to_span)
+        .collect();
+
+    let mut notes = Vec::new();
+    let mut suggestion = None;
+    for child in &raw.children {
+        let edits: Vec<&RawSpan> = child
+            .spans
+            .iter()
+            .filter(ours)
+            .filter(|s| s.suggested_replacement.is_some())
+            .collect();
+        if edits.is_empty() {
+            notes.push(format!("{}: {}", child.level, child.message));
+        } else if suggestion.is_none() {
+            suggestion = Some(Suggestion {
+                message: child.message.clone(),
+                preview: preview(source, &edits),
+                edits: edits
+                    .iter()
+                    .map(|s| Edit {
+                        span: Span { label: None, ..s.to_span() },
+                        replacement: s.suggested_replacement.clone().unwrap_or_default(),
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    let code = raw.code.map(|c| c.code);
+    let revisit = code.as_deref().and_then(lessons::for_code).map(|l| Revisit {
+        exercise: l.exercise.to_string(),
+        title: l.title.to_string(),
+        tip: l.tip.to_string(),
+    });
+    Diagnostic {
+        code,
+        message: raw.message,
+        span,
+        labels,
+        notes,
+        suggestion,
+        revisit,
+        rendered: raw.rendered.unwrap_or_default(),
+    }
+}
+
+/// The lines `edits` touch, after applying them to `source`. Empty if
+/// the byte offsets do not fit the source.
+fn preview(source: &str, edits: &[&RawSpan]) -> Vec<String> {
+    let mut sorted = edits.to_vec();
+    sorted.sort_by_key(|s| s.byte_start);
+    if sorted.windows(2).any(|w| w[0].byte_end > w[1].byte_start) {
+        return Vec::new();
+    }
+
+    let mut fixed = source.to_string();
+    for edit in sorted.iter().rev() {
+        let replacement = edit.suggested_replacement.as_deref().unwrap_or_default();
+        if fixed.get(edit.byte_start..edit.byte_end).is_none() {
+            return Vec::new();
+        }
+        fixed.replace_range(edit.byte_start..edit.byte_end, replacement);
+    }
+
+    let first = sorted.iter().map(|s| s.line_start).min().unwrap_or(1);
+    let last = sorted.iter().map(|s| s.line_end).max().unwrap_or(first);
+    let added: usize = sorted
+        .iter()
+        .map(|s| s.suggested_replacement.as_deref().unwrap_or_default().matches('\n').count())
+        .sum();
+    let removed: usize = sorted
+        .iter()
+        .map(|s| source[s.byte_start..s.byte_end].matches('\n').count())
+        .sum();
+    let count = (last + 1 - first + added).saturating_sub(removed);
+    fixed
+        .lines()
+        .skip(first - 1)
+        .take(count)
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(line: usize, start: usize, end: usize, bytes: (usize, usize), replacement: &str) -> String {
+        format!(
+            r#"{{"file_name":"main.rs","byte_start":{},"byte_end":{},"line_start":{line},"line_end":{line},
+                "column_start":{start},"column_end":{end},"is_primary":true,"label":null,
+                "suggested_replacement":{replacement:?}}}"#,
+            bytes.0, bytes.1
+        )
+        .replace('\n', " ")
+    }
+
+    #[test]
+    fn applies_suggestions_to_a_preview() {
+        let source = "fn main() {\n    let t = s;\n}\n";
+        let child = format!(
+            r#"{{"message":"consider cloning the value","code":null,"level":"help","spans":[{}],"children":[],"rendered":null}}"#,
+            span(2, 14, 14, (25, 25), ".clone()")
+        );
+        let line = format!(
+            r#"{{"message":"use of moved value: `s`","code":{{"code":"E0382"}},"level":"error","spans":[],"children":[{child}],"rendered":"error[E0382]"}}"#
+        );
+        let diagnostics = parse(&line, source, "main.rs");
+        let suggestion = diagnostics[0].suggestion.as_ref().unwrap();
+        assert_eq!(suggestion.preview, ["    let t = s.clone();"]);
+        assert_eq!(suggestion.edits[0].replacement, ".clone()");
+        assert_eq!(diagnostics[0].revisit.as_ref().unwrap().exercise, "026");
+    }
+
+    #[test]
+    fn skips_warnings_and_summaries() {
+        let stderr = [
+            r#"{"message":"unused variable: `x`","code":{"code":"unused_variables"},"level":"warning","spans":[],"children":[],"rendered":""}"#,
+            r#"{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[],"rendered":""}"#,
+            "error: internal compiler error",
+            r#"{"message":"expected `;`","code":null,"level":"error","spans":[],"children":[],"rendered":"error: expected `;`"}"#,
+        ]
+        .join("\n");
+        let diagnostics = parse(&stderr, "", "main.rs");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "expected `;`");
+        assert_eq!((diagnostics[0].code.as_deref(), diagnostics[0].revisit.as_ref()), (None, None));
+    }
+}
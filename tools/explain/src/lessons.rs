@@ -0,0 +1,226 @@
+//! Which exercise teaches the rule behind each rustc error code.
+
+/// An exercise to point a learner back to for one error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lesson {
+    pub code: &'static str,
+    /// Three-digit exercise number, e.g. `"026"`.
+    pub exercise: &'static str,
+    /// Title from the exercise's template header.
+    pub title: &'static str,
+    /// One-line reminder of the rule, in the learner's terms.
+    pub tip: &'static str,
+}
+
+const fn lesson(
+    code: &'static str,
+    exercise: &'static str,
+    title: &'static str,
+    tip: &'static str,
+) -> Lesson {
+    Lesson {
+        code,
+        exercise,
+        title,
+        tip,
+    }
+}
+
+/// Sorted by code.
+pub const LESSONS: &[Lesson] = &[
+    lesson(
+        "E0004",
+        "042",
+        "Pattern Matching - match",
+        "A match must cover every possible value; add the missing arms or a `_` wildcard.",
+    ),
+    lesson(
+        "E0046",
+        "063",
+        "Traits - Implementing",
+        "An impl must define every trait method that has no default body.",
+    ),
+    lesson(
+        "E0061",
+        "016",
+        "Functions - With Parameters",
+        "Call a function with exactly the parameters its signature lists.",
+    ),
+    lesson(
+        "E0072",
+        "077",
+        "Box<T> - Recursive Types",
+        "A type cannot contain itself directly; put the recursive field in a Box.",
+    ),
+    lesson(
+        "E0106",
+        "067",
+        "Lifetimes - Basic Annotation",
+        "A returned or stored reference needs a named lifetime when the compiler cannot infer it.",
+    ),
+    lesson(
+        "E0261",
+        "067",
+        "Lifetimes - Basic Annotation",
+        "Declare a lifetime in angle brackets, e.g. `fn f<'a>`, before using it.",
+    ),
+    lesson(
+        "E0277",
+        "066",
+        "Trait Bounds",
+        "A generic type can only be used in ways its trait bounds allow.",
+    ),
+    lesson(
+        "E0282",
+        "021",
+        "Type Inference",
+        "When the compiler cannot infer a type, annotate it, e.g. `let v: Vec<i32>`.",
+    ),
+    lesson(
+        "E0308",
+        "003",
+        "Data Types",
+        "Every value has one type; convert explicitly instead of mixing types.",
+    ),
+    lesson(
+        "E0369",
+        "091",
+        "Advanced Traits - Operator Overloading",
+        "Operators like `+` and `==` only work on types that implement the matching trait.",
+    ),
+    lesson(
+        "E0373",
+        "083",
+        "Threads - Move Closures",
+        "A closure that may outlive the function must take ownership with `move`.",
+    ),
+    lesson(
+        "E0382",
+        "026",
+        "Ownership - Move Semantics",
+        "After a value is moved it can no longer be used; borrow it or clone it instead.",
+    ),
+    lesson(
+        "E0384",
+        "002",
+        "Variables and Mutability",
+        "Variables are immutable by default; declare them with `let mut` to reassign.",
+    ),
+    lesson(
+        "E0432",
+        "096",
+        "Modules - use Keyword",
+        "A `use` path must name an item that exists, starting from the crate or module.",
+    ),
+    lesson(
+        "E0499",
+        "030",
+        "References - Mutable Borrow",
+        "Only one mutable reference to a value may be live at a time.",
+    ),
+    lesson(
+        "E0502",
+        "030",
+        "References - Mutable Borrow",
+        "A value cannot be borrowed mutably while shared references to it are still in use.",
+    ),
+    lesson(
+        "E0505",
+        "031",
+        "References - Scope Rules",
+        "A value cannot be moved while a reference to it is still in use.",
+    ),
+    lesson(
+        "E0506",
+        "031",
+        "References - Scope Rules",
+        "A value cannot be assigned to while a reference to it is still in use.",
+    ),
+    lesson(
+        "E0507",
+        "027",
+        "Ownership - Clone",
+        "You cannot move out of a borrow; clone the value or take a reference to it.",
+    ),
+    lesson(
+        "E0515",
+        "068",
+        "Lifetimes - In Functions",
+        "A function cannot return a reference to one of its own local variables; return the owned value.",
+    ),
+    lesson(
+        "E0596",
+        "030",
+        "References - Mutable Borrow",
+        "Only a variable declared with `mut` can be borrowed as `&mut`.",
+    ),
+    lesson(
+        "E0597",
+        "031",
+        "References - Scope Rules",
+        "A reference cannot outlive the value it points to; keep the value in scope longer.",
+    ),
+    lesson(
+        "E0603",
+        "095",
+        "Modules - Visibility Modifiers",
+        "Items are private to their module unless marked `pub`.",
+    ),
+    lesson(
+        "E0616",
+        "095",
+        "Modules - Visibility Modifiers",
+        "Struct fields are private to their module unless marked `pub`.",
+    ),
+    lesson(
+        "E0716",
+        "031",
+        "References - Scope Rules",
+        "A temporary is dropped at the end of its statement; bind it to a variable to borrow it longer.",
+    ),
+];
+
+/// The lesson for an error code such as `"E0382"`, if one teaches it.
+pub fn for_code(code: &str) -> Option<&'static Lesson> {
+    LESSONS
+        .binary_search_by(|l| l.code.cmp(code))
+        .ok()
+        .map(|i| &LESSONS[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn looks_up_codes() {
+        assert_eq!(for_code("E0382").unwrap().exercise, "026");
+        assert_eq!(for_code("E0499").unwrap().exercise, "030");
+        assert_eq!(for_code("E0502").unwrap().exercise, "030");
+        assert_eq!(for_code("E0106").unwrap().exercise, "067");
+        assert!(for_code("E0425").is_none());
+    }
+
+    #[test]
+    fn table_is_sorted_and_titles_match_the_templates() {
+        assert!(LESSONS.windows(2).all(|w| w[0].code < w[1].code));
+
+        let problems = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../problems");
+        for lesson in LESSONS {
+            let dir = problems.join(format!("{}_exercise", lesson.exercise));
+            let path = if dir.join("Cargo.toml").is_file() {
+                dir.join("src/lib.rs")
+            } else {
+                dir.join("template.rs")
+            };
+            let template = std::fs::read_to_string(&path).unwrap();
+            let header = format!("exercise {}: {}", lesson.exercise, lesson.title);
+            assert!(
+                template.lines().take(3).any(|l| l.to_lowercase().contains(&header.to_lowercase())),
+                "{} does not start with `{header}`",
+                path.display()
+            );
+        }
+    }
+}
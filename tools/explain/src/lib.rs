@@ -0,0 +1,145 @@
+//! Compiler errors explained in terms of the exercises.
+//!
+//! A submission is checked with `rustc --error-format=json --emit=metadata`
+//! (type and borrow checking, no code generation). Each error comes back
+//! as a [`Diagnostic`] with its span, rustc's suggested fix and, for error
+//! codes an exercise covers, which exercise to revisit: E0382 (use after
+//! move) points to 026, E0499 and E0502 (conflicting borrows) to 030,
+//! E0106 (missing lifetime) to 067, and so on through [`lessons::LESSONS`].
+
+pub mod diagnostic;
+pub mod lessons;
+
+use std::io;
+use std::process::Command;
+use std::time::Duration;
+
+use grader::compile::{rustc, RUSTC_FLAGS};
+use grader::process::run_with_timeout;
+use grader::ScratchDir;
+use serde::Serialize;
+
+pub use diagnostic::{Diagnostic, Edit, Revisit, Span, Suggestion};
+
+/// rustc's JSON output is truncated to this many bytes.
+const MAX_DIAGNOSTIC_BYTES: usize = 1 << 20;
+
+/// Name the submission is compiled under, and the file its spans name.
+const SOURCE_FILE: &str = "main.rs";
+
+/// What checking one submission found.
+#[derive(Debug, Clone, Serialize)]
+pub struct Explained {
+    /// True when rustc accepted the code.
+    pub compiled: bool,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Type- and borrow-checks `source` and explains each error.
+///
+/// Only failures to run rustc at all are errors, including running past
+/// `timeout` (`io::ErrorKind::TimedOut`).
+pub fn explain(source: &str, timeout: Duration) -> io::Result<Explained> {
+    let scratch = ScratchDir::new("explain")?;
+    std::fs::write(scratch.path().join(SOURCE_FILE), source)?;
+
+    // Compiling from inside the scratch directory keeps its path out of
+    // the spans and the rendered messages.
+    let mut cmd = Command::new(rustc());
+    cmd.arg(SOURCE_FILE)
+        .args(["--error-format=json", "--emit=metadata", "-o", "main.rmeta"])
+        .args(RUSTC_FLAGS)
+        .current_dir(scratch.path());
+    let out = run_with_timeout(&mut cmd, timeout, MAX_DIAGNOSTIC_BYTES)?;
+    if out.timed_out() {
+        return Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("rustc did not finish within {}s", timeout.as_secs()),
+        ));
+    }
+
+    Ok(Explained {
+        compiled: out.success(),
+        diagnostics: diagnostic::parse(&out.stderr, source, SOURCE_FILE),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain_ok(source: &str) -> Explained {
+        explain(source, Duration::from_secs(30)).unwrap()
+    }
+
+    fn codes(explained: &Explained) -> Vec<(&str, &str)> {
+        explained
+            .diagnostics
+            .iter()
+            .map(|d| {
+                let code = d.code.as_deref().unwrap_or("");
+                let exercise = d.revisit.as_ref().map_or("", |r| r.exercise.as_str());
+                (code, exercise)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn accepts_correct_code() {
+        let explained = explain_ok("fn main() { let v = vec![1]; println!(\"{v:?}\"); }\n");
+        assert!(explained.compiled);
+        assert!(explained.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn use_after_move_points_to_move_semantics() {
+        let source = "fn main() {\n    let s = String::from(\"hi\");\n    let t = s;\n    println!(\"{s} {t}\");\n}\n";
+        let explained = explain_ok(source);
+        assert!(!explained.compiled);
+        assert_eq!(codes(&explained), [("E0382", "026")]);
+
+        let error = &explained.diagnostics[0];
+        let span = error.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (4, 16));
+        assert!(error.labels.iter().any(|l| l.line == 3), "{:?}", error.labels);
+        assert_eq!(error.suggestion.as_ref().unwrap().preview, ["    let t = s.clone();"]);
+        assert!(error.rendered.contains("--> main.rs:4:16"), "{}", error.rendered);
+    }
+
+    #[test]
+    fn borrow_conflicts_point_to_mutable_borrow() {
+        let source = "fn main() {\n\
+                          let mut v = vec![1];\n\
+                          let a = &v;\n\
+                          v.push(2);\n\
+                          println!(\"{a:?}\");\n\
+                          let b = &mut v;\n\
+                          let c = &mut v;\n\
+                          b.push(3);\n\
+                          c.push(4);\n\
+                      }\n";
+        let explained = explain_ok(source);
+        assert_eq!(codes(&explained), [("E0502", "030"), ("E0499", "030")]);
+    }
+
+    #[test]
+    fn missing_lifetimes_point_to_lifetime_annotation() {
+        let source = "fn longest(a: &str, b: &str) -> &str { if a.len() > b.len() { a } else { b } }\n\
+                      fn main() { println!(\"{}\", longest(\"a\", \"bc\")); }\n";
+        let explained = explain_ok(source);
+        assert_eq!(codes(&explained), [("E0106", "067")]);
+        let suggestion = explained.diagnostics[0].suggestion.as_ref().unwrap();
+        assert_eq!(suggestion.edits.len(), 4);
+        assert!(
+            suggestion.preview[0].starts_with("fn longest<'a>(a: &'a str, b: &'a str) -> &'a str"),
+            "{:?}",
+            suggestion.preview
+        );
+    }
+
+    #[test]
+    fn errors_without_a_lesson_have_no_revisit() {
+        let explained = explain_ok("fn main() { println!(\"{}\", missing); }\n");
+        assert_eq!(codes(&explained), [("E0425", "")]);
+    }
+}
@@ -0,0 +1,64 @@
+//! `explain` -- check a submission with rustc and print its errors as JSON,
+//! each linked to the exercise that teaches the rule it breaks.
+//!
+//! Usage: explain [--timeout SECS] <FILE | ->
+//!
+//! Exit status is 0 when the code compiles, 1 when rustc reports errors,
+//! and 2 on usage or internal errors.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::time::Duration;
+
+const USAGE: &str = "usage: explain [--timeout SECS] <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("explain: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut timeout = Duration::from_secs(12);
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--timeout" => timeout = seconds(&mut args, &arg)?,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf).map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let explained = explain::explain(&source, timeout).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&explained).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(explained.compiled)
+}
+
+fn seconds(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<Duration, String> {
+    let raw = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+    raw.parse::<u64>()
+        .map(Duration::from_secs)
+        .map_err(|_| format!("{flag}: `{raw}` is not a number of seconds"))
+}
@@ -0,0 +1,296 @@
+//! The program that fuzzes a submission.
+//!
+//! The submission, without its `main` and tests, is compiled as
+//! `mod submission`, and the target's reference code as `mod reference`,
+//! so that the two can define the same names. Each gets a child module
+//! `fuzz_calls` with one wrapper per function that formats its answer
+//! with `{:?}`; being a child, it can call the parent's private functions.
+//! A driver hands both sets of wrappers to [`run`](crate::runtime::run)
+//! along with [`RUNTIME`] as `mod fuzz`.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use grader::compile::{compile_binary, CompileError};
+use grader::process::run_with_timeout;
+use grader::splice::{strip_fn, strip_tests};
+use grader::ScratchDir;
+
+use crate::runtime::{hex, unhex};
+use crate::targets::Target;
+
+/// The runtime, compiled into every harness.
+pub const RUNTIME: &str = include_str!("runtime.rs");
+
+/// Kept of the harness's own output, which is only the learner's prints.
+const MAX_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// The harness program for `target`. It takes the seeds file, the report
+/// and current-input paths, the number of iterations, the RNG seed and the
+/// time budget in milliseconds as its arguments.
+pub fn source(target: &Target, submission: &str) -> String {
+    let mut out = String::from("#![allow(dead_code, unused, non_snake_case)]\n\n");
+    for (module, code) in [
+        ("submission", strip_fn(&strip_tests(submission), "main")),
+        ("reference", target.reference.to_string()),
+    ] {
+        // Inner attributes must open the module, ahead of its items
+        let (attributes, items): (Vec<&str>, Vec<&str>) = code
+            .lines()
+            .partition(|l| l.trim_start().starts_with("#!["));
+        let _ = writeln!(out, "mod {module} {{");
+        for attribute in attributes {
+            let _ = writeln!(out, "{attribute}");
+        }
+        let _ = writeln!(out, "{}\n\npub mod fuzz_calls {{", items.join("\n"));
+        for function in target.functions {
+            let _ = writeln!(
+                out,
+                "    pub fn {function}(input: &str) -> String {{\n\
+                 \x20       format!(\"{{:?}}\", super::{function}(input))\n\
+                 \x20   }}"
+            );
+        }
+        out.push_str("}\n}\n\n");
+    }
+
+    out.push_str("mod fuzz {\n");
+    out.push_str(RUNTIME);
+    out.push_str("}\n\nfn main() {\n    let functions = [\n");
+    for function in target.functions {
+        let _ = writeln!(
+            out,
+            "        fuzz::Function {{ name: {function:?}, submission: submission::fuzz_calls::{function}, \
+             reference: reference::fuzz_calls::{function} }},"
+        );
+    }
+    let _ = write!(
+        out,
+        "    ];\n\
+         \x20   let mutator = fuzz::Mutator {{ alphabet: &{:?}, dictionary: &{:?} }};\n",
+        target.alphabet, target.dictionary
+    );
+    out.push_str(
+        "    let args: Vec<String> = std::env::args().collect();\n\
+         \x20   let [_, seeds, report, current, iterations, seed, budget] = &args[..] else {\n\
+         \x20       panic!(\"usage: harness SEEDS REPORT CURRENT ITERATIONS SEED BUDGET_MS\");\n\
+         \x20   };\n\
+         \x20   let seeds = std::fs::read_to_string(seeds).expect(\"cannot read the seeds\");\n\
+         \x20   let config = fuzz::Config {\n\
+         \x20       iterations: iterations.parse().unwrap(),\n\
+         \x20       seed: seed.parse().unwrap(),\n\
+         \x20       budget: std::time::Duration::from_millis(budget.parse().unwrap()),\n\
+         \x20       current: current.clone(),\n\
+         \x20       report: report.clone(),\n\
+         \x20   };\n\
+         \x20   fuzz::run(&config, &functions, &mutator, seeds.lines().map(fuzz::unhex).collect());\n\
+         }\n",
+    );
+    out
+}
+
+/// A compiled harness.
+#[derive(Debug)]
+pub struct Harness {
+    scratch: ScratchDir,
+    binary: PathBuf,
+}
+
+/// Why a harness could not be built.
+#[derive(Debug)]
+pub enum BuildError {
+    /// The submission does not compile in the harness, usually because a
+    /// function is missing or has another signature.
+    Rejected(String),
+    Io(io::Error),
+}
+
+pub fn build(target: &Target, submission: &str, timeout: Duration) -> Result<Harness, BuildError> {
+    let scratch = ScratchDir::new("fuzz").map_err(BuildError::Io)?;
+    match compile_binary(&source(target, submission), scratch.path(), timeout) {
+        Ok(binary) => Ok(Harness { scratch, binary }),
+        Err(CompileError::Io(err)) => Err(BuildError::Io(err)),
+        Err(err) => Err(BuildError::Rejected(err.to_string())),
+    }
+}
+
+/// A failure the harness found, as read back from its report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Record {
+    Panicked {
+        function: String,
+        input: Vec<u8>,
+        message: String,
+    },
+    Mismatch {
+        function: String,
+        input: Vec<u8>,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// How a harness run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub records: Vec<Record>,
+    /// Calls made, if the run got to the end.
+    pub executions: Option<usize>,
+    pub corpus: usize,
+    pub timed_out: bool,
+    /// Set if the harness died other than by timing out.
+    pub crashed: Option<String>,
+    /// The function index and input being tried when the run ended, if it
+    /// ended early.
+    pub current: Option<(usize, Vec<u8>)>,
+}
+
+impl Harness {
+    /// Tries every seed, then `iterations` mutated inputs or as many as
+    /// fit in three quarters of `timeout`.
+    pub fn run(
+        &self,
+        seeds: &[Vec<u8>],
+        iterations: usize,
+        seed: u64,
+        timeout: Duration,
+    ) -> io::Result<Run> {
+        let dir = self.scratch.path();
+        let (seeds_path, report, current) = (
+            dir.join("seeds.txt"),
+            dir.join("report.txt"),
+            dir.join("current.txt"),
+        );
+        let hexed: Vec<String> = seeds.iter().map(|s| hex(s)).collect();
+        std::fs::write(&seeds_path, hexed.join("\n"))?;
+        for stale in [&report, &current] {
+            match std::fs::remove_file(stale) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+
+        let mut command = Command::new(&self.binary);
+        command
+            .args([&seeds_path, &report, &current])
+            .arg(iterations.to_string())
+            .arg(seed.to_string())
+            .arg((timeout.as_millis() * 3 / 4).to_string());
+        let out = run_with_timeout(&mut command, timeout, MAX_OUTPUT_BYTES)?;
+
+        let mut run = parse(&read_if_present(&report)?);
+        run.timed_out = out.timed_out();
+        run.crashed =
+            (!out.timed_out() && !out.success()).then(|| match out.status.and_then(|s| s.code()) {
+                Some(code) => format!("the harness exited with status {code}"),
+                None => {
+                    "the harness was killed by a signal, most likely a stack overflow".to_string()
+                }
+            });
+        if run.executions.is_none() {
+            let current = read_if_present(&current)?;
+            run.current = current
+                .trim_end()
+                .split_once('\t')
+                .and_then(|(index, input)| Some((index.parse().ok()?, unhex(input))));
+        }
+        Ok(run)
+    }
+}
+
+fn read_if_present(path: &std::path::Path) -> io::Result<String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(text),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Reads what [`run`](crate::runtime::run) wrote, skipping anything
+/// malformed.
+pub fn parse(report: &str) -> Run {
+    let mut run = Run {
+        records: Vec::new(),
+        executions: None,
+        corpus: 0,
+        timed_out: false,
+        crashed: None,
+        current: None,
+    };
+    let text = |field: &str| String::from_utf8_lossy(&unhex(field)).into_owned();
+    for line in report.lines() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields[..] {
+            ["panic", function, input, message] => run.records.push(Record::Panicked {
+                function: function.to_string(),
+                input: unhex(input),
+                message: text(message),
+            }),
+            ["mismatch", function, input, expected, actual] => run.records.push(Record::Mismatch {
+                function: function.to_string(),
+                input: unhex(input),
+                expected: text(expected),
+                actual: text(actual),
+            }),
+            ["done", executions, corpus] => {
+                run.executions = executions.parse().ok();
+                run.corpus = corpus.parse().unwrap_or(0);
+            }
+            _ => {}
+        }
+    }
+    run
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets;
+
+    #[test]
+    fn reads_back_what_the_runtime_writes() {
+        let report = format!(
+            "panic\tmy_atoi\t{}\t{}\nmismatch\tparse_float\t{}\t{}\t{}\nnoise\ndone\t812\t37\n",
+            hex(b"99999999999"),
+            hex("attempt to multiply with overflow\nat line 3".as_bytes()),
+            hex("-0.5".as_bytes()),
+            hex(b"Some((0, 5))"),
+            hex(b"None"),
+        );
+        let run = parse(&report);
+        assert_eq!(run.executions, Some(812));
+        assert_eq!(run.corpus, 37);
+        assert_eq!(
+            run.records,
+            [
+                Record::Panicked {
+                    function: "my_atoi".into(),
+                    input: b"99999999999".to_vec(),
+                    message: "attempt to multiply with overflow\nat line 3".into(),
+                },
+                Record::Mismatch {
+                    function: "parse_float".into(),
+                    input: b"-0.5".to_vec(),
+                    expected: "Some((0, 5))".into(),
+                    actual: "None".into(),
+                },
+            ]
+        );
+        assert!(parse("done\t1").executions.is_none());
+    }
+
+    #[test]
+    fn submission_and_reference_live_side_by_side() {
+        let target = targets::for_exercise("148").unwrap();
+        let source = source(
+            target,
+            "#![allow(unused)]\nfn is_valid(s: &str) -> bool { true }\nfn main() {}\n",
+        );
+        assert!(source.contains("mod submission {\n#![allow(unused)]\nfn is_valid"));
+        assert!(source.contains("reference: reference::fuzz_calls::longest_valid_parentheses"));
+        assert!(!source.contains("fn main() {}"));
+    }
+}
@@ -0,0 +1,335 @@
+//! Differential fuzzing for the string-parsing exercises.
+//!
+//! `my_atoi`, `parse_float`, `is_valid` and `evaluate_postfix` take
+//! arbitrary strings, and what the exercises are about is the inputs the
+//! fixed tests never try: a number one past `i32::MAX`, a lone sign, a
+//! closing bracket with nothing open. [`check`] compiles the submission
+//! next to the reference versions in [`targets`] and feeds both inputs
+//! made by a byte [`Mutator`](runtime::Mutator) ([`runtime`]). An input
+//! on which the learner's function panics, or answers differently from the
+//! reference, is reported cut down to its shortest failing form, and with
+//! a corpus directory it is saved there to be replayed.
+
+pub mod harness;
+pub mod runtime;
+pub mod targets;
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use harness::{BuildError, Record};
+pub use targets::Target;
+
+/// Limits for one check.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub compile_timeout: Duration,
+    /// For the whole run; mutation stops at three quarters of it, so that
+    /// the failures found so far are still reported.
+    pub run_timeout: Duration,
+    /// Mutated inputs tried after the seeds, at most. Zero replays the
+    /// seeds alone.
+    pub iterations: usize,
+    pub seed: u64,
+    /// Every file in it is a seed, and every failing input is saved to it.
+    pub corpus: Option<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            compile_timeout: Duration::from_secs(30),
+            run_timeout: Duration::from_secs(20),
+            iterations: 10_000,
+            seed: 0,
+            corpus: None,
+        }
+    }
+}
+
+/// How a function failed on an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    Panic,
+    /// It answered differently from the reference.
+    Mismatch,
+    /// The process died, most likely of a stack overflow.
+    Crash,
+    TimedOut,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Failure {
+    pub function: String,
+    pub kind: Kind,
+    /// The input, with any invalid UTF-8 replaced as the function saw it.
+    pub input: String,
+    /// The reference's answer and the learner's, formatted with `{:?}`.
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    /// The panic message, or why the process died.
+    pub message: Option<String>,
+    /// Where the input was saved, if it was.
+    pub saved: Option<PathBuf>,
+}
+
+/// What fuzzing one submission found.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub exercise: String,
+    /// Calls of the learner's and the reference's functions together,
+    /// unless the run was cut short.
+    pub executions: Option<usize>,
+    /// Inputs kept for giving the reference an answer no earlier one did.
+    pub corpus: usize,
+    pub failures: Vec<Failure>,
+    /// Why the submission was not fuzzed, e.g. a function is missing.
+    pub unchecked: Option<String>,
+    /// The submission was fuzzed and no input made a function panic or
+    /// disagree with the reference. An `unchecked` report does not pass.
+    pub passed: bool,
+}
+
+/// Fuzzes `source` against the exercise's reference. Errors are failures
+/// of the checker itself, including asking about an exercise it has no
+/// target for.
+pub fn check(exercise: &str, source: &str, opts: &Options) -> io::Result<Report> {
+    let target = targets::for_exercise(exercise).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("exercise {exercise} has no fuzz target"),
+        )
+    })?;
+    let mut report = Report {
+        exercise: exercise.to_string(),
+        executions: None,
+        corpus: 0,
+        failures: Vec::new(),
+        unchecked: None,
+        passed: true,
+    };
+
+    let mut seeds: Vec<Vec<u8>> = vec![Vec::new()];
+    seeds.extend(target.dictionary.iter().map(|t| t.as_bytes().to_vec()));
+    if let Some(dir) = &opts.corpus {
+        seeds.extend(read_corpus(dir)?);
+    }
+
+    let harness = match harness::build(target, source, opts.compile_timeout) {
+        Ok(harness) => harness,
+        Err(BuildError::Io(err)) => return Err(err),
+        Err(BuildError::Rejected(error)) => {
+            report.unchecked = Some(format!("does not compile in the fuzz harness: {error}"));
+            report.passed = false;
+            return Ok(report);
+        }
+    };
+    let run = harness.run(&seeds, opts.iterations, opts.seed, opts.run_timeout)?;
+    report.executions = run.executions;
+    report.corpus = run.corpus;
+
+    for record in run.records {
+        report.failures.push(match record {
+            Record::Panicked {
+                function,
+                input,
+                message,
+            } => failure(function, Kind::Panic, &input, Some(message)),
+            Record::Mismatch {
+                function,
+                input,
+                expected,
+                actual,
+            } => Failure {
+                expected: Some(expected),
+                actual: Some(actual),
+                ..failure(function, Kind::Mismatch, &input, None)
+            },
+        });
+    }
+    if let Some((index, input)) = run.current {
+        let function = target.functions.get(index).copied().unwrap_or("?");
+        let ended = if run.timed_out {
+            Some((
+                Kind::TimedOut,
+                format!("no answer within {}s", opts.run_timeout.as_secs()),
+            ))
+        } else {
+            run.crashed.map(|why| (Kind::Crash, why))
+        };
+        if let Some((kind, message)) = ended {
+            let function = function.to_string();
+            report
+                .failures
+                .push(failure(function, kind, &input, Some(message)));
+        }
+    }
+
+    if let Some(dir) = &opts.corpus {
+        for failure in &mut report.failures {
+            let path = dir.join(format!("crash-{:016x}", fnv1a(failure.input.as_bytes())));
+            std::fs::write(&path, &failure.input)?;
+            failure.saved = Some(path);
+        }
+    }
+    if report.failures.is_empty() && report.executions.is_none() {
+        report.unchecked = Some(if run.timed_out {
+            format!("timed out after {}s", opts.run_timeout.as_secs())
+        } else {
+            "the harness stopped without a report".to_string()
+        });
+    }
+    report.passed = report.failures.is_empty() && report.unchecked.is_none();
+    Ok(report)
+}
+
+fn failure(function: String, kind: Kind, input: &[u8], message: Option<String>) -> Failure {
+    Failure {
+        function,
+        kind,
+        input: String::from_utf8_lossy(input).into_owned(),
+        expected: None,
+        actual: None,
+        message,
+        saved: None,
+    }
+}
+
+/// Every file in `dir`, in name order.
+pub fn read_corpus(dir: &Path) -> io::Result<Vec<Vec<u8>>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    paths.iter().map(std::fs::read).collect()
+}
+
+/// Names saved inputs, so that finding one again overwrites it.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grader::ScratchDir;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    }
+
+    fn read(path: &str) -> String {
+        std::fs::read_to_string(root().join(path)).unwrap()
+    }
+
+    #[test]
+    fn solutions_pass_and_templates_fail() {
+        for target in targets::TARGETS {
+            let id = target.exercise;
+            let opts = Options {
+                corpus: Some(root().join(format!("tools/fuzz/corpus/{id}"))),
+                ..Options::default()
+            };
+            let report = check(id, &read(&format!("solutions/{id}.rs")), &opts).unwrap();
+            assert!(report.passed, "{id}: {report:?}");
+            assert!(report.corpus > 1, "{id}: {report:?}");
+
+            let template = read(&format!("problems/{id}_exercise/template.rs"));
+            let report = check(id, &template, &Options::default()).unwrap();
+            assert!(!report.passed, "{id}: {report:?}");
+        }
+    }
+
+    #[test]
+    fn an_atoi_that_overflows_is_caught_and_saved_for_replay() {
+        let solution = read("solutions/146.rs");
+        let buggy = solution.replace(
+            "match result.checked_mul(10).and_then(|r| r.checked_sub(digit))",
+            "match Some(result * 10 - digit)",
+        );
+        assert_ne!(buggy, solution);
+
+        let scratch = ScratchDir::new("fuzz-corpus").unwrap();
+        let opts = Options {
+            corpus: Some(scratch.path().to_path_buf()),
+            ..Options::default()
+        };
+        let report = check("146", &buggy, &opts).unwrap();
+        assert!(!report.passed, "{report:?}");
+        let atoi = report
+            .failures
+            .iter()
+            .find(|f| f.function == "my_atoi")
+            .unwrap();
+        // Cut down to the fewest digits that still overflow
+        assert_eq!(atoi.kind, Kind::Panic);
+        assert!(
+            atoi.message.as_deref().unwrap().contains("overflow"),
+            "{atoi:?}"
+        );
+        assert!(
+            atoi.input.len() == 10 && atoi.input.bytes().all(|b| b.is_ascii_digit()),
+            "{atoi:?}"
+        );
+        let saved = atoi.saved.as_ref().unwrap();
+        assert_eq!(std::fs::read_to_string(saved).unwrap(), atoi.input);
+
+        // Replaying the corpus alone finds it again
+        let replay = Options {
+            iterations: 0,
+            ..opts
+        };
+        let report = check("146", &buggy, &replay).unwrap();
+        assert!(
+            report.failures.iter().any(|f| f.function == "my_atoi"),
+            "{report:?}"
+        );
+    }
+
+    #[test]
+    fn a_stack_overflow_names_the_input_it_died_on() {
+        let solution = read("solutions/148.rs");
+        let recursive = solution.replace(
+            "fn is_valid(s: &str) -> bool {\n",
+            "fn is_valid(s: &str) -> bool {\n    if s.starts_with(\"((((\") {\n        return is_valid(s);\n    }\n",
+        );
+        assert_ne!(recursive, solution);
+
+        let report = check("148", &recursive, &Options::default()).unwrap();
+        assert!(!report.passed && report.executions.is_none(), "{report:?}");
+        let crash = report.failures.last().unwrap();
+        assert_eq!(
+            (crash.function.as_str(), crash.kind),
+            ("is_valid", Kind::Crash)
+        );
+        assert!(crash.input.starts_with("(((("), "{crash:?}");
+    }
+
+    #[test]
+    fn unknown_exercises_and_missing_functions() {
+        let err = check("001", "", &Options::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let report = check(
+            "148",
+            "fn is_valid(s: &str) -> bool { s.is_empty() }",
+            &Options::default(),
+        )
+        .unwrap();
+        assert!(!report.passed);
+        assert!(report
+            .unchecked
+            .unwrap()
+            .contains("longest_valid_parentheses"));
+    }
+}
@@ -0,0 +1,105 @@
+//! `fuzz` -- fuzz a string-parsing submission against the reference and
+//! print a JSON report.
+//!
+//! Usage: fuzz --exercise NNN [--corpus DIR] [--replay] [--iterations N]
+//!             [--seed N] [--compile-timeout SECS] [--run-timeout SECS] <FILE | ->
+//!
+//! Exercises 120, 146 and 148 are supported. With `--corpus`, every file
+//! in DIR is tried before any mutated input, and every failing input is
+//! saved to DIR as `crash-<hash>`; `--replay` then tries those files alone.
+//! `tools/fuzz/corpus/NNN` holds seeds for each exercise.
+//!
+//! Exit status is 0 when the submission was fuzzed and no input made a
+//! function panic or disagree with the reference, 1 when one did or the
+//! submission could not be fuzzed (see `unchecked`), and 2 on usage or
+//! internal errors.
+
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use fuzz::Options;
+
+const USAGE: &str = "usage: fuzz --exercise NNN [--corpus DIR] [--replay] [--iterations N] \
+                     [--seed N] [--compile-timeout SECS] [--run-timeout SECS] <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("fuzz: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut opts = Options::default();
+    let mut exercise = None;
+    let mut input = None;
+    let mut replay = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => exercise = Some(value(&mut args, &arg)?),
+            "--corpus" => opts.corpus = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--replay" => replay = true,
+            "--iterations" => opts.iterations = number(&mut args, &arg)?,
+            "--seed" => opts.seed = number(&mut args, &arg)?,
+            "--compile-timeout" => {
+                opts.compile_timeout = Duration::from_secs(number(&mut args, &arg)?)
+            }
+            "--run-timeout" => opts.run_timeout = Duration::from_secs(number(&mut args, &arg)?),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    if replay {
+        if opts.corpus.is_none() {
+            return Err("--replay needs --corpus".to_string());
+        }
+        opts.iterations = 0;
+    }
+    let exercise = exercise.ok_or(USAGE)?;
+    let exercise = match exercise.parse::<u32>() {
+        Ok(n) => format!("{n:03}"),
+        Err(_) => return Err(format!("--exercise: `{exercise}` is not a number")),
+    };
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let report = fuzz::check(&exercise, &source, &opts).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.passed)
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
+
+fn number<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+) -> Result<T, String> {
+    let raw = value(args, flag)?;
+    raw.parse()
+        .map_err(|_| format!("{flag}: `{raw}` is not a number"))
+}
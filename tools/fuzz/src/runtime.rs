@@ -0,0 +1,388 @@
+//! The fuzz loop, compiled into every harness as `mod fuzz`.
+//!
+//! [`run`] starts from the seeds, then repeatedly picks an input from the
+//! corpus, mutates it ([`Mutator`]) and calls every [`Function`] on it,
+//! once as the learner wrote it and once as the reference. A panic or an
+//! answer that differs from the reference is a failure; its input is cut
+//! down to as few bytes as still fail the same way, and written to the
+//! report. There is no coverage feedback: an input joins the corpus when
+//! the reference gives an answer for it that no earlier input got, which
+//! is a cheap stand-in for reaching new code.
+//!
+//! Before each call the input is written to a file, so that the checker
+//! can still name it when the call aborts the process (a stack overflow)
+//! or never returns. This file builds with std alone.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// Longest input the mutator makes, in bytes.
+pub const MAX_LEN: usize = 256;
+
+/// The corpus stops growing at this many inputs.
+pub const MAX_CORPUS: usize = 4096;
+
+/// Candidates tried while cutting a failing input down, at most.
+pub const MAX_MINIMIZE_STEPS: usize = 2048;
+
+/// One function under test, as the learner wrote it and as the reference
+/// does. Both return the answer formatted with `{:?}`.
+pub struct Function {
+    pub name: &'static str,
+    pub submission: fn(&str) -> String,
+    pub reference: fn(&str) -> String,
+}
+
+/// How a run is driven; the harness's `main` fills it in from its
+/// arguments.
+pub struct Config {
+    pub iterations: usize,
+    pub seed: u64,
+    /// Stop mutating after this long, so a slow submission still reports.
+    pub budget: Duration,
+    /// Where each input goes before it is tried.
+    pub current: String,
+    /// Where failures and the final summary are appended.
+    pub report: String,
+}
+
+/// How a call went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The learner's function panicked with this message.
+    Panicked(String),
+    /// It returned `actual` where the reference returns `expected`.
+    Mismatch { expected: String, actual: String },
+}
+
+impl Failure {
+    fn same_kind(&self, other: &Failure) -> bool {
+        matches!(
+            (self, other),
+            (Failure::Panicked(_), Failure::Panicked(_))
+                | (Failure::Mismatch { .. }, Failure::Mismatch { .. })
+        )
+    }
+}
+
+/// Fuzzes `functions`, starting from `seeds`. The seeds are all tried
+/// first, so with no iterations this replays them.
+pub fn run(config: &Config, functions: &[Function], mutator: &Mutator, seeds: Vec<Vec<u8>>) {
+    // Panics are the failures being looked for; the report has them
+    panic::set_hook(Box::new(|_| {}));
+    let started = Instant::now();
+    let mut rng = Rng::new(config.seed);
+    let mut failed = vec![false; functions.len()];
+    let mut seen = HashSet::new();
+    let mut corpus: Vec<Vec<u8>> = Vec::new();
+    let mut executions = 0usize;
+
+    let mut try_input = |input: Vec<u8>, corpus: &mut Vec<Vec<u8>>, failed: &mut [bool]| {
+        let input = String::from_utf8_lossy(&input).into_owned();
+        let mut novel = false;
+        for (index, function) in functions.iter().enumerate() {
+            if failed[index] {
+                continue;
+            }
+            executions += 1;
+            let Some(expected) = call(function.reference, &input) else {
+                // Outside the reference's domain; nothing to compare with
+                continue;
+            };
+            novel |= seen.insert((index, expected.clone()));
+            if let Some(failure) = attempt(config, index, function.submission, &input, expected) {
+                failed[index] = true;
+                let (input, failure) = minimize(config, index, function, input.as_bytes(), failure);
+                write_failure(config, function.name, &input, &failure);
+            }
+        }
+        if novel && corpus.len() < MAX_CORPUS {
+            corpus.push(input.into_bytes());
+        }
+    };
+
+    for seed in seeds {
+        try_input(seed, &mut corpus, &mut failed);
+    }
+    for _ in 0..config.iterations {
+        if failed.iter().all(|&f| f) || started.elapsed() > config.budget {
+            break;
+        }
+        // Skewed towards the front, where the seeds and the first, simplest
+        // finds are, so they are not drowned out as the corpus grows
+        let parent = if corpus.is_empty() {
+            Vec::new()
+        } else {
+            let bound = 1 + rng.below(corpus.len());
+            corpus[rng.below(bound)].clone()
+        };
+        let child = mutator.mutate(&mut rng, &parent, &corpus);
+        try_input(child, &mut corpus, &mut failed);
+    }
+
+    let mut line = String::new();
+    let _ = writeln!(line, "done\t{executions}\t{}", corpus.len());
+    append(&config.report, &line);
+}
+
+/// `f(input)`, or `None` if it panics.
+fn call(f: fn(&str) -> String, input: &str) -> Option<String> {
+    panic::catch_unwind(AssertUnwindSafe(|| f(input))).ok()
+}
+
+/// Calls the learner's function on `input` and compares with `expected`.
+fn attempt(
+    config: &Config,
+    index: usize,
+    f: fn(&str) -> String,
+    input: &str,
+    expected: String,
+) -> Option<Failure> {
+    let _ = std::fs::write(
+        &config.current,
+        format!("{index}\t{}\n", hex(input.as_bytes())),
+    );
+    match panic::catch_unwind(AssertUnwindSafe(|| f(input))) {
+        Ok(actual) if actual == expected => None,
+        Ok(actual) => Some(Failure::Mismatch { expected, actual }),
+        Err(payload) => Some(Failure::Panicked(panic_message(&*payload))),
+    }
+}
+
+/// The shortest input found by deleting runs of characters from `input`
+/// that still fails the way `failure` does, and how it fails.
+fn minimize(
+    config: &Config,
+    index: usize,
+    function: &Function,
+    input: &[u8],
+    failure: Failure,
+) -> (Vec<u8>, Failure) {
+    let mut best: Vec<char> = String::from_utf8_lossy(input).chars().collect();
+    let mut failure = failure;
+    let mut steps = 0;
+    let mut run = best.len();
+    while run > 0 && steps < MAX_MINIMIZE_STEPS {
+        let mut start = 0;
+        let mut shrunk = false;
+        while start + run <= best.len() && steps < MAX_MINIMIZE_STEPS {
+            steps += 1;
+            let candidate: String = best[..start].iter().chain(&best[start + run..]).collect();
+            let verdict = call(function.reference, &candidate).and_then(|expected| {
+                attempt(config, index, function.submission, &candidate, expected)
+            });
+            match verdict {
+                Some(f) if f.same_kind(&failure) => {
+                    best.drain(start..start + run);
+                    failure = f;
+                    shrunk = true;
+                }
+                _ => start += run,
+            }
+        }
+        if !shrunk {
+            run /= 2;
+        }
+    }
+    (best.into_iter().collect::<String>().into_bytes(), failure)
+}
+
+fn write_failure(config: &Config, name: &str, input: &[u8], failure: &Failure) {
+    let line = match failure {
+        Failure::Panicked(message) => format!(
+            "panic\t{name}\t{}\t{}\n",
+            hex(input),
+            hex(message.as_bytes())
+        ),
+        Failure::Mismatch { expected, actual } => format!(
+            "mismatch\t{name}\t{}\t{}\t{}\n",
+            hex(input),
+            hex(expected.as_bytes()),
+            hex(actual.as_bytes())
+        ),
+    };
+    append(&config.report, &line);
+}
+
+fn append(path: &str, text: &str) {
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path);
+    if let Ok(mut file) = file {
+        let _ = file.write_all(text.as_bytes());
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "the function panicked".to_string(),
+    }
+}
+
+/// Lowercase hex, two digits a byte; the report's fields may hold any byte.
+pub fn hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(out, "{b:02x}");
+    }
+    out
+}
+
+/// The bytes [`hex`] encoded; a malformed pair decodes to nothing.
+pub fn unhex(text: &str) -> Vec<u8> {
+    text.as_bytes()
+        .chunks_exact(2)
+        .filter_map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// splitmix64: small, fast and good enough for picking mutations.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Byte-level mutations that stay within an exercise's input alphabet.
+///
+/// Single bytes are only ever drawn from `alphabet`, and longer pieces
+/// from `dictionary` (tokens such as `"2147483648"` or `"-"`) or from
+/// other corpus inputs, so inputs stay in the domain the exercise defines.
+pub struct Mutator {
+    pub alphabet: &'static [u8],
+    pub dictionary: &'static [&'static str],
+}
+
+impl Mutator {
+    /// `input` after one to four mutations, at most [`MAX_LEN`] bytes.
+    pub fn mutate(&self, rng: &mut Rng, input: &[u8], corpus: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = input.to_vec();
+        for _ in 0..1 + rng.below(4) {
+            self.mutate_once(rng, &mut out, corpus);
+        }
+        out.truncate(MAX_LEN);
+        out
+    }
+
+    fn mutate_once(&self, rng: &mut Rng, out: &mut Vec<u8>, corpus: &[Vec<u8>]) {
+        let at = rng.below(out.len() + 1);
+        match rng.below(7) {
+            // Replace a byte
+            0 if !out.is_empty() => {
+                let i = rng.below(out.len());
+                out[i] = self.byte(rng);
+            }
+            // Insert a byte, or a run of one repeated
+            1 => {
+                let count = if rng.below(4) == 0 {
+                    1 + rng.below(16)
+                } else {
+                    1
+                };
+                let byte = self.byte(rng);
+                out.splice(at..at, std::iter::repeat_n(byte, count));
+            }
+            // Delete a run
+            2 if !out.is_empty() => {
+                let len = 1 + rng.below(out.len().min(8));
+                let start = rng.below(out.len() - len + 1);
+                out.drain(start..start + len);
+            }
+            // Insert or overwrite with a dictionary token. Half the inserts
+            // go at either end, where a token such as " 0 /" extends the
+            // expression instead of splitting one of its numbers
+            3 | 4 if !self.dictionary.is_empty() => {
+                let token = self.dictionary[rng.below(self.dictionary.len())].as_bytes();
+                let at = match rng.below(4) {
+                    0 => 0,
+                    1 => out.len(),
+                    _ => at,
+                };
+                if rng.below(2) == 0 || at + token.len() > out.len() {
+                    out.splice(at..at, token.iter().copied());
+                } else {
+                    out[at..at + token.len()].copy_from_slice(token);
+                }
+            }
+            // Duplicate a run in place
+            5 if !out.is_empty() => {
+                let len = 1 + rng.below(out.len().min(16));
+                let start = rng.below(out.len() - len + 1);
+                let run = out[start..start + len].to_vec();
+                out.splice(start..start, run);
+            }
+            // Splice: this input's head onto another's tail
+            6 if !corpus.is_empty() => {
+                let other = &corpus[rng.below(corpus.len())];
+                let from = rng.below(other.len() + 1);
+                out.truncate(at);
+                out.extend_from_slice(&other[from..]);
+            }
+            _ => out.insert(at, self.byte(rng)),
+        }
+    }
+
+    fn byte(&self, rng: &mut Rng) -> u8 {
+        self.alphabet[rng.below(self.alphabet.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutations_stay_in_the_alphabet_and_under_the_limit() {
+        let mutator = Mutator {
+            alphabet: b"()",
+            dictionary: &["[]"],
+        };
+        let mut rng = Rng::new(7);
+        let mut corpus = vec![b"{}".to_vec()];
+        let mut seen = HashSet::new();
+        for _ in 0..5000 {
+            let parent = corpus[rng.below(corpus.len())].clone();
+            let child = mutator.mutate(&mut rng, &parent, &corpus);
+            assert!(child.len() <= MAX_LEN);
+            assert!(child.iter().all(|b| b"()[]{}".contains(b)), "{child:?}");
+            if seen.insert(child.clone()) {
+                corpus.push(child);
+            }
+        }
+        assert!(seen.len() > 1000);
+        // Splicing and duplication grow inputs well past the seeds
+        assert!(seen.iter().any(|c| c.len() > 32));
+    }
+
+    #[test]
+    fn hex_round_trips_any_bytes() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(hex(b"\t-1\n"), "092d310a");
+        assert_eq!(unhex(&hex(&bytes)), bytes);
+        assert_eq!(unhex(""), b"");
+    }
+}
@@ -0,0 +1,187 @@
+//! The string-parsing exercises the fuzzer knows, and their references.
+
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    /// Three-digit exercise number, e.g. `"146"`.
+    pub exercise: &'static str,
+    /// The functions fuzzed, each `fn(&str) -> T` with `T: Debug`.
+    pub functions: &'static [&'static str],
+    /// Reference versions of `functions`, with the helpers they call.
+    /// Compiled in a module of their own, next to the submission's.
+    pub reference: &'static str,
+    /// Every byte the mutator may insert on its own. Leaving bytes out
+    /// keeps inputs inside what the exercise defines, e.g. no vertical tab
+    /// for `my_atoi`, whose whitespace the template leaves open.
+    pub alphabet: &'static [u8],
+    /// Longer pieces worth inserting whole: boundary numbers, operators,
+    /// multi-byte characters.
+    pub dictionary: &'static [&'static str],
+}
+
+/// Sorted by exercise.
+pub const TARGETS: &[Target] = &[
+    Target {
+        exercise: "120",
+        functions: &["evaluate_postfix"],
+        reference: r#"fn evaluate_postfix(expr: &str) -> Option<i32> {
+    let mut stack: Vec<i32> = Vec::new();
+    for token in expr.split_whitespace() {
+        let op: fn(i32, i32) -> Option<i32> = match token {
+            "+" => i32::checked_add,
+            "-" => i32::checked_sub,
+            "*" => i32::checked_mul,
+            "/" => i32::checked_div,
+            num => {
+                stack.push(num.parse().ok()?);
+                continue;
+            }
+        };
+        let b = stack.pop()?;
+        let a = stack.pop()?;
+        stack.push(op(a, b)?);
+    }
+    let result = stack.pop()?;
+    stack.is_empty().then_some(result)
+}"#,
+        alphabet: b"0123456789 +-*/\tx.",
+        dictionary: &[
+            " ",
+            " + ",
+            " - ",
+            " * ",
+            " / ",
+            " 0 /",
+            " -1 /",
+            " 2 *",
+            " 1 +",
+            " 1 -",
+            " 2147483647 +",
+            " -2147483648 -",
+            "0",
+            "-1",
+            "2147483647",
+            "-2147483648",
+            "2147483648",
+            "65536",
+            "+7",
+            "١",
+        ],
+    },
+    Target {
+        exercise: "146",
+        functions: &["my_atoi", "my_atoi_i64", "parse_float"],
+        reference: r#"fn my_atoi(s: &str) -> i32 {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() && is_whitespace(bytes[i]) {
+        i += 1;
+    }
+    let negative = i < bytes.len() && bytes[i] == b'-';
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    let mut result: i64 = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        result = (result * 10 + (bytes[i] - b'0') as i64).min(i32::MAX as i64 + 1);
+        i += 1;
+    }
+    let result = if negative { -result } else { result };
+    result.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+fn my_atoi_i64(s: &str) -> i32 {
+    my_atoi(s)
+}
+
+fn parse_float(s: &str) -> Option<(i32, u32)> {
+    let s = s.trim();
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int_str, frac_str) = match rest.split_once('.') {
+        Some((int_str, frac_str)) if !frac_str.is_empty() => (int_str, frac_str),
+        Some(_) => return None,
+        None => (rest, "0"),
+    };
+    if int_str.is_empty() || !int_str.bytes().chain(frac_str.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let int_part: i32 = int_str.parse().ok()?;
+    let frac_part: u32 = frac_str.parse().ok()?;
+    Some((if negative { -int_part } else { int_part }, frac_part))
+}
+
+fn is_whitespace(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c)
+}"#,
+        alphabet: b"0123456789 +-.\t\n\rax",
+        dictionary: &[
+            "-",
+            "+",
+            ".",
+            "   ",
+            "00000",
+            "2147483647",
+            "2147483648",
+            "-2147483648",
+            "-2147483649",
+            "4294967295",
+            "4294967296",
+            "9223372036854775808",
+            "99999999999999999999",
+            "é",
+            "٣",
+        ],
+    },
+    Target {
+        exercise: "148",
+        functions: &["is_valid", "longest_valid_parentheses"],
+        reference: r#"fn is_valid(s: &str) -> bool {
+    let mut stack = Vec::new();
+    for c in s.chars() {
+        match c {
+            '(' | '[' | '{' => stack.push(c),
+            ')' => if stack.pop() != Some('(') { return false },
+            ']' => if stack.pop() != Some('[') { return false },
+            '}' => if stack.pop() != Some('{') { return false },
+            _ => {}
+        }
+    }
+    stack.is_empty()
+}
+
+fn longest_valid_parentheses(s: &str) -> usize {
+    let mut stack: Vec<isize> = vec![-1];
+    let mut best = 0;
+    for (i, c) in s.chars().enumerate() {
+        let i = i as isize;
+        if c == '(' {
+            stack.push(i);
+            continue;
+        }
+        stack.pop();
+        match stack.last() {
+            Some(&start) if c == ')' => best = best.max((i - start) as usize),
+            _ => {
+                stack.clear();
+                stack.push(i);
+            }
+        }
+    }
+    best
+}"#,
+        alphabet: b"()[]{}()a ",
+        dictionary: &[
+            "()", "((", "))", ")(", "([{}])", "(]", "é", "((((((((", "))))))))",
+        ],
+    },
+];
+
+pub fn for_exercise(exercise: &str) -> Option<&'static Target> {
+    TARGETS
+        .binary_search_by(|t| t.exercise.cmp(exercise))
+        .ok()
+        .map(|i| &TARGETS[i])
+}
@@ -0,0 +1,321 @@
+//! The public items of a library crate, named the way rustdoc names them.
+//!
+//! [`public_items`] walks `src/lib.rs` and the module files it declares
+//! and lists every `pub` item that is neither test-only nor marked
+//! `#[doc(hidden)]`: `add`, `Point`, `Point::new` for a method of an
+//! inherent impl, and `string_utils::reverse` inside a public module.
+//! Items of trait impls, and those only visible inside the crate, are not
+//! part of the list. This is a token scan, not a parser; items a macro
+//! generates are missed.
+
+use std::ops::Range;
+
+use crate::cargo::{Crate, LIB};
+use crate::splice::code_only;
+
+/// Paths of the public items of `krate`'s library, in source order.
+/// Empty when the crate has no `src/lib.rs`.
+pub fn public_items(krate: &Crate) -> Vec<String> {
+    let mut items = Vec::new();
+    if let Some(source) = krate.files.get(LIB) {
+        Walker::new(krate, &code_only(source)).module_file("src", "", &mut items);
+    }
+    items
+}
+
+/// `path` without the generic arguments rustdoc keeps in the names of
+/// methods, e.g. `UniqueCollection::add` for `UniqueCollection<T>::add`.
+pub fn without_generics(path: &str) -> String {
+    let mut depth = 0usize;
+    let mut out = String::with_capacity(path.len());
+    for c in path.chars() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Item kinds that rustdoc documents under their own name.
+const NAMED: &[&str] = &["fn", "struct", "enum", "union", "trait", "type", "const", "static", "mod"];
+
+/// Words that may come between the visibility and the kind of an item.
+const QUALIFIERS: &[&str] = &["unsafe", "async", "extern", "default"];
+
+struct Walker<'a> {
+    krate: &'a Crate,
+    code: String,
+    tokens: Vec<Range<usize>>,
+    /// For each `(`, `[` or `{` token, the index of the token closing it.
+    close: Vec<Option<usize>>,
+}
+
+impl<'a> Walker<'a> {
+    fn new(krate: &'a Crate, code: &str) -> Self {
+        let tokens = tokens(code);
+        let close = closing(code, &tokens);
+        Walker {
+            krate,
+            code: code.to_string(),
+            tokens,
+            close,
+        }
+    }
+
+    fn text(&self, i: usize) -> &str {
+        self.tokens.get(i).map_or("", |r| &self.code[r.clone()])
+    }
+
+    /// Collects the public items of a whole file.
+    fn module_file(&self, dir: &str, prefix: &str, items: &mut Vec<String>) {
+        self.module(0..self.tokens.len(), dir, prefix, items);
+    }
+
+    /// Collects the public items among the tokens in `range`, a module body
+    /// whose files live in `dir` and whose items are named `prefix<name>`.
+    fn module(&self, range: Range<usize>, dir: &str, prefix: &str, items: &mut Vec<String>) {
+        let mut i = range.start;
+        while i < range.end {
+            let mut hidden = false;
+            while self.text(i) == "#" {
+                let open = if self.text(i + 1) == "!" { i + 2 } else { i + 1 };
+                let Some(end) = self.close.get(open).copied().flatten() else {
+                    return;
+                };
+                let attr: String = (open + 1..end).map(|t| self.text(t)).collect();
+                hidden |= attr == "doc(hidden)" || attr == "cfg(test)";
+                i = end + 1;
+            }
+
+            let mut public = self.text(i) == "pub";
+            if public {
+                i += 1;
+                if self.text(i) == "(" {
+                    // pub(crate), pub(super) and pub(in path) stay inside the crate
+                    public = false;
+                    i = self.close[i].map_or(range.end, |end| end + 1);
+                }
+            }
+            // `const fn` is a function; `const NAME` is a constant
+            while QUALIFIERS.contains(&self.text(i)) || (self.text(i) == "const" && !self.is_name(i + 1)) {
+                i += 1;
+            }
+
+            let kind = self.text(i);
+            if kind == "impl" {
+                let end = self.item_end(i, range.end, true);
+                if !hidden {
+                    if let Some((ty, body)) = self.inherent_impl(i, end) {
+                        self.module(body, dir, &format!("{prefix}{ty}::"), items);
+                    }
+                }
+                i = end;
+            } else if NAMED.contains(&kind) {
+                let name = if self.text(i + 1) == "mut" { i + 2 } else { i + 1 };
+                let end = self.item_end(i, range.end, !matches!(kind, "const" | "static" | "type"));
+                if public && !hidden && self.is_name(name) {
+                    let path = format!("{prefix}{}", self.text(name));
+                    items.push(path.clone());
+                    if kind == "mod" {
+                        self.submodule(name, end, dir, &path, items);
+                    }
+                }
+                i = end;
+            } else {
+                i = self.item_end(i, range.end, kind == "macro_rules");
+            }
+        }
+    }
+
+    /// Follows `mod name { ... }` into its body, or `mod name;` into
+    /// `dir/name.rs` or `dir/name/mod.rs`.
+    fn submodule(&self, name: usize, end: usize, dir: &str, path: &str, items: &mut Vec<String>) {
+        let child_dir = format!("{dir}/{}", self.text(name));
+        let prefix = format!("{path}::");
+        if self.text(name + 1) == "{" {
+            self.module(name + 2..end - 1, &child_dir, &prefix, items);
+            return;
+        }
+        let file = [format!("{child_dir}.rs"), format!("{child_dir}/mod.rs")]
+            .into_iter()
+            .find_map(|file| self.krate.files.get(&file));
+        if let Some(source) = file {
+            Walker::new(self.krate, &code_only(source)).module_file(&child_dir, &prefix, items);
+        }
+    }
+
+    /// The self type and body of the `impl` block between tokens `start`
+    /// and `end`, unless it implements a trait.
+    fn inherent_impl(&self, start: usize, end: usize) -> Option<(String, Range<usize>)> {
+        let open = (start..end).find(|&t| self.text(t) == "{")?;
+        let mut i = start + 1;
+        if self.text(i) == "<" {
+            i = self.past_angles(i);
+        }
+        let mut ty = None;
+        let mut depth = 0usize;
+        for t in i..open {
+            match self.text(t) {
+                "<" => depth += 1,
+                ">" => depth = depth.saturating_sub(1),
+                "for" if depth == 0 => return None,
+                "where" if depth == 0 => break,
+                // The last segment of the type's path, e.g. `Point` in `shapes::Point<T>`
+                _ if depth == 0 && self.is_name(t) => ty = Some(self.text(t).to_string()),
+                _ => {}
+            }
+        }
+        Some((ty?, open + 1..self.close[open]?))
+    }
+
+    /// Index just past the `>` matching the `<` at `open`.
+    fn past_angles(&self, open: usize) -> usize {
+        let mut depth = 0usize;
+        for t in open..self.tokens.len() {
+            match self.text(t) {
+                "<" => depth += 1,
+                ">" if self.text(t - 1) != "-" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return t + 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.tokens.len()
+    }
+
+    /// Index just past the item starting at `start`: its top-level `;`, or
+    /// with `block` its first top-level `{ ... }` if that comes first.
+    fn item_end(&self, start: usize, limit: usize, block: bool) -> usize {
+        let mut i = start;
+        while i < limit {
+            match self.text(i) {
+                ";" => return i + 1,
+                "{" if block => return self.close[i].map_or(limit, |end| end + 1),
+                "(" | "[" | "{" => i = self.close[i].map_or(limit, |end| end + 1),
+                _ => i += 1,
+            }
+        }
+        limit
+    }
+
+    fn is_name(&self, i: usize) -> bool {
+        let text = self.text(i);
+        text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') && !NAMED.contains(&text)
+    }
+}
+
+/// Byte ranges of the tokens of `code`: identifiers and numbers whole,
+/// every other character on its own.
+fn tokens(code: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut chars = code.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        if c.is_alphanumeric() || c == '_' {
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+        }
+        tokens.push(start..end);
+    }
+    tokens
+}
+
+/// Pairs up the brackets among the `tokens` of `code`.
+fn closing(code: &str, tokens: &[Range<usize>]) -> Vec<Option<usize>> {
+    let mut close = vec![None; tokens.len()];
+    let mut open = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match &code[token.clone()] {
+            "(" | "[" | "{" => open.push(i),
+            ")" | "]" | "}" => {
+                if let Some(start) = open.pop() {
+                    close[start] = Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    close
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn krate(files: &[(&str, &str)]) -> Crate {
+        Crate {
+            files: files.iter().map(|(p, s)| (p.to_string(), s.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn lists_what_rustdoc_documents() {
+        let lib = r#"
+//! Crate docs with `pub fn not_an_item() {}` in them.
+pub fn add(a: i32, b: i32) -> i32 { a + b }
+fn private() {}
+pub(crate) fn internal() {}
+#[doc(hidden)]
+pub fn helper() -> i32 { 42 }
+pub const LIMIT: u32 = 20;
+pub struct Point { x: f64 }
+impl Point {
+    pub fn new(x: f64) -> Self { Point { x } }
+    fn secret(&self) {}
+}
+impl<T: PartialEq> UniqueCollection<T> where T: Clone {
+    pub const fn len(&self) -> usize { 0 }
+}
+impl std::fmt::Display for Point {
+    pub fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { write!(f, "{") }
+}
+pub trait Shape { fn area(&self) -> f64; }
+pub mod string_utils {
+    pub fn reverse(s: &str) -> String { s.chars().rev().collect() }
+}
+pub mod shapes;
+mod private_mod { pub fn hidden() {} }
+#[cfg(test)]
+mod tests { pub fn t() {} }
+"#;
+        let shapes = "pub enum Kind { A, B }\nimpl Kind { pub unsafe fn raw() {} }\n";
+        let items = public_items(&krate(&[("src/lib.rs", lib), ("src/shapes.rs", shapes)]));
+        assert_eq!(
+            items,
+            [
+                "add",
+                "LIMIT",
+                "Point",
+                "Point::new",
+                "UniqueCollection::len",
+                "Shape",
+                "string_utils",
+                "string_utils::reverse",
+                "shapes",
+                "shapes::Kind",
+                "shapes::Kind::raw",
+            ]
+        );
+    }
+
+    #[test]
+    fn generics_are_dropped_from_rustdoc_names() {
+        assert_eq!(without_generics("UniqueCollection<T>::add"), "UniqueCollection::add");
+        assert_eq!(without_generics("Wrapper<Vec<u8>>::get"), "Wrapper::get");
+        assert_eq!(without_generics("add"), "add");
+    }
+}
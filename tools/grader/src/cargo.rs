@@ -0,0 +1,241 @@
+//! Building a multi-file submission with cargo.
+//!
+//! The module exercises are Cargo crates: a `src/lib.rs` with its module
+//! files and a `src/main.rs` that uses the library. A submission is the
+//! set of `.rs` files under `src/`; the manifest is always the grader's own
+//! (see [`manifest`]), so a submission cannot add dependencies or a build
+//! script. `cargo test --no-run` builds one harness per target, and the
+//! tests in each are run like those of a single-file submission.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::compile::CompileError;
+use crate::process::run_with_timeout;
+use crate::splice;
+
+/// Crate root of the library; hidden and generated test modules are
+/// appended to it.
+pub const LIB: &str = "src/lib.rs";
+
+/// cargo's diagnostics are truncated to this many bytes.
+const MAX_DIAGNOSTIC_BYTES: usize = 65_536;
+
+/// The cargo to invoke: `$CARGO` if set, otherwise `cargo` from `PATH`.
+pub fn cargo() -> PathBuf {
+    std::env::var_os("CARGO")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("cargo"))
+}
+
+/// Package name of an exercise's crate, e.g. `exercise_095`; its
+/// `main.rs` imports the library under this name.
+pub fn package_name(exercise: Option<&str>) -> String {
+    match exercise {
+        Some(exercise) => format!("exercise_{exercise}"),
+        None => "submission".to_string(),
+    }
+}
+
+/// The `Cargo.toml` every submission is built with.
+pub fn manifest(package: &str) -> String {
+    format!(
+        "[package]\nname = \"{package}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n\
+         [dependencies]\n\n[profile.dev]\ndebug = false\n\n[workspace]\n"
+    )
+}
+
+/// Whether `path` may be a file of a submission: a `.rs` file under
+/// `src/`, written with `/` and made of plain module names, so it cannot
+/// escape the crate or name a manifest or build script.
+pub fn is_source_path(path: &str) -> bool {
+    let Some(rest) = path.strip_prefix("src/") else {
+        return false;
+    };
+    let Some(rest) = rest.strip_suffix(".rs") else {
+        return false;
+    };
+    rest.split('/').all(|part| {
+        part.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// The source files of a multi-file submission, keyed by their path from
+/// the crate root, e.g. `src/garden/vegetables.rs`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Crate {
+    pub files: BTreeMap<String, String>,
+}
+
+impl Crate {
+    /// Reads every `.rs` file under `dir/src`. Anything else in `dir`,
+    /// its `Cargo.toml` included, is left out.
+    pub fn read(dir: &Path) -> io::Result<Self> {
+        let mut files = BTreeMap::new();
+        read_sources(dir, Path::new("src"), &mut files)?;
+        if files.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: no .rs files under src/", dir.display()),
+            ));
+        }
+        Ok(Crate { files })
+    }
+
+    /// Replaces the test modules of each file with those of the same file
+    /// in `template`. Test modules in files the template does not have
+    /// are dropped.
+    pub fn with_tests_from(&self, template: &Crate) -> Crate {
+        let files = self
+            .files
+            .iter()
+            .map(|(path, source)| {
+                let rebuilt = match template.files.get(path) {
+                    Some(trusted) => splice::with_tests_from(source, trusted),
+                    None => splice::strip_tests(source),
+                };
+                (path.clone(), rebuilt)
+            })
+            .collect();
+        Crate { files }
+    }
+
+    /// Appends `body` to the library root as `#[cfg(test)] mod <name>`.
+    pub fn append_test_module(&mut self, name: &str, body: &str) {
+        let lib = self.files.entry(LIB.to_string()).or_default();
+        *lib = splice::append_test_module(lib, name, body);
+    }
+}
+
+fn read_sources(root: &Path, dir: &Path, files: &mut BTreeMap<String, String>) -> io::Result<()> {
+    let entries = match fs::read_dir(root.join(dir)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let path = dir.join(entry.file_name());
+        let kind = entry.file_type()?;
+        if kind.is_dir() {
+            read_sources(root, &path, files)?;
+        } else if kind.is_file() {
+            let key = path.to_string_lossy().replace('\\', "/");
+            if is_source_path(&key) {
+                files.insert(key, fs::read_to_string(root.join(&path))?);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes `krate` and its manifest into `dir` and runs `cargo test
+/// --no-run`, returning the harness of every target that has one.
+///
+/// Nothing is fetched: the build is `--offline` and the target directory
+/// lives in `dir`. Warnings are discarded; only a failed build surfaces
+/// cargo's rendered diagnostics, with paths relative to the crate root.
+pub fn compile_crate_tests(
+    krate: &Crate,
+    package: &str,
+    dir: &Path,
+    timeout: Duration,
+) -> Result<Vec<PathBuf>, CompileError> {
+    fs::write(dir.join("Cargo.toml"), manifest(package))?;
+    for (path, source) in &krate.files {
+        if !is_source_path(path) {
+            return Err(CompileError::Rejected(format!("`{path}` is not a source file under src/")));
+        }
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, source)?;
+    }
+
+    let mut cmd = command(dir);
+    cmd.args(["test", "--no-run", "--offline", "--quiet"])
+        .args(["--message-format", "json-render-diagnostics"]);
+
+    let out = run_with_timeout(&mut cmd, timeout, MAX_DIAGNOSTIC_BYTES)?;
+    if out.timed_out() {
+        return Err(CompileError::TimedOut(timeout));
+    }
+    if !out.success() {
+        return Err(CompileError::Rejected(out.stderr));
+    }
+    Ok(test_executables(&out.stdout))
+}
+
+/// A cargo command for the crate written into `dir`, building into
+/// `dir/target` with none of the caller's compiler flags.
+pub fn command(dir: &Path) -> Command {
+    let mut cmd = Command::new(cargo());
+    cmd.env("CARGO_TARGET_DIR", dir.join("target"))
+        .env_remove("RUSTFLAGS")
+        .env_remove("RUSTDOCFLAGS")
+        .current_dir(dir);
+    cmd
+}
+
+/// Paths of the test harnesses in cargo's JSON messages, in build order.
+fn test_executables(messages: &str) -> Vec<PathBuf> {
+    messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|msg| msg["reason"] == "compiler-artifact" && msg["profile"]["test"] == true)
+        .filter_map(|msg| msg["executable"].as_str().map(PathBuf::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_paths_stay_inside_src() {
+        for ok in ["src/lib.rs", "src/main.rs", "src/garden/vegetables.rs", "src/backend/mod.rs"] {
+            assert!(is_source_path(ok), "{ok}");
+        }
+        for bad in ["Cargo.toml", "build.rs", "src/../build.rs", "src//lib.rs", "/src/lib.rs", "src/lib.txt", "src/a b.rs"]
+        {
+            assert!(!is_source_path(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn template_tests_replace_the_submissions_file_by_file() {
+        let krate = |lib: &str, garden: &str| Crate {
+            files: BTreeMap::from([("src/lib.rs".into(), lib.into()), ("src/garden.rs".into(), garden.into())]),
+        };
+        let template = krate("mod garden;\n#[cfg(test)]\nmod tests { fn real() {} }\n", "pub fn grow() {}\n");
+        let submission = krate(
+            "mod garden;\n#[cfg(test)]\nmod tests { fn fake() {} }\n",
+            "pub fn grow() {}\n#[cfg(test)]\nmod tests { fn fake() {} }\n",
+        );
+        let rebuilt = submission.with_tests_from(&template);
+        assert!(rebuilt.files["src/lib.rs"].contains("real") && !rebuilt.files["src/lib.rs"].contains("fake"));
+        assert!(!rebuilt.files["src/garden.rs"].contains("fake"));
+    }
+
+    #[test]
+    fn test_executables_come_from_test_profile_artifacts() {
+        let messages = concat!(
+            r#"{"reason":"compiler-artifact","profile":{"test":false},"executable":null}"#,
+            "\n",
+            r#"{"reason":"compiler-artifact","profile":{"test":true},"executable":"/t/debug/deps/exercise_097-1"}"#,
+            "\n",
+            r#"{"reason":"compiler-artifact","profile":{"test":false},"executable":"/t/debug/exercise_097"}"#,
+            "\n",
+            r#"{"reason":"build-finished","success":true}"#,
+        );
+        assert_eq!(test_executables(messages), [PathBuf::from("/t/debug/deps/exercise_097-1")]);
+    }
+}
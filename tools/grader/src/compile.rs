@@ -0,0 +1,129 @@
+//! Compiling a submission into a libtest harness, a plain binary or a
+//! library for its doc tests to link against.
+
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::process::run_with_timeout;
+
+/// Flags shared with `code_runner.py`, so a submission that runs in the
+/// editor also compiles for grading.
+pub const RUSTC_FLAGS: &[&str] = &["--edition", "2021", "-C", "opt-level=0"];
+
+/// Compiler diagnostics are truncated to this many bytes.
+const MAX_DIAGNOSTIC_BYTES: usize = 65_536;
+
+/// Why a submission could not be turned into a test binary.
+#[derive(Debug)]
+pub enum CompileError {
+    /// rustc rejected the code; carries its stderr.
+    Rejected(String),
+    /// rustc did not finish within the compile timeout.
+    TimedOut(Duration),
+    /// rustc could not be started or the scratch directory was unusable.
+    Io(io::Error),
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompileError::Rejected(stderr) => write!(f, "compilation failed:\n{stderr}"),
+            CompileError::TimedOut(limit) => {
+                write!(f, "compilation timed out after {}s", limit.as_secs())
+            }
+            CompileError::Io(err) => write!(f, "could not run rustc: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+impl From<io::Error> for CompileError {
+    fn from(err: io::Error) -> Self {
+        CompileError::Io(err)
+    }
+}
+
+/// The compiler to invoke: `$RUSTC` if set, otherwise `rustc` from `PATH`.
+pub fn rustc() -> PathBuf {
+    std::env::var_os("RUSTC")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("rustc"))
+}
+
+/// Writes `source` into `dir` and compiles it with `--test`.
+///
+/// Returns the path of the harness binary. Warnings are discarded; only a
+/// failed build surfaces rustc's output.
+pub fn compile_tests(source: &str, dir: &Path, timeout: Duration) -> Result<PathBuf, CompileError> {
+    compile(source, dir, timeout, &["--test"])
+}
+
+/// Writes `source` into `dir` and compiles it as an ordinary program.
+pub fn compile_binary(source: &str, dir: &Path, timeout: Duration) -> Result<PathBuf, CompileError> {
+    compile(source, dir, timeout, &[])
+}
+
+/// Writes `source` into `dir` and compiles it as the library crate
+/// `crate_name`, returning the path of the `.rlib`. A `main` the
+/// submission defines is compiled but never called.
+pub fn compile_library(
+    source: &str,
+    dir: &Path,
+    timeout: Duration,
+    crate_name: &str,
+) -> Result<PathBuf, CompileError> {
+    let rlib = format!("lib{crate_name}.rlib");
+    let built = compile(source, dir, timeout, &["--crate-type", "lib", "--crate-name", crate_name])?;
+    let renamed = dir.join(rlib);
+    fs::rename(built, &renamed)?;
+    Ok(renamed)
+}
+
+fn compile(
+    source: &str,
+    dir: &Path,
+    timeout: Duration,
+    extra: &[&str],
+) -> Result<PathBuf, CompileError> {
+    let mut cmd = Command::new(rustc());
+    cmd.args(rustc_args(dir, extra)).current_dir(dir);
+    compile_with(&mut cmd, source, dir, timeout)
+}
+
+/// rustc's arguments for compiling `dir/main.rs` into `dir/main` with
+/// [`RUSTC_FLAGS`] and `extra`.
+pub fn rustc_args(dir: &Path, extra: &[&str]) -> Vec<OsString> {
+    let mut args = vec![dir.join("main.rs").into_os_string()];
+    args.extend(extra.iter().map(OsString::from));
+    args.push("-o".into());
+    args.push(dir.join("main").into_os_string());
+    args.extend(RUSTC_FLAGS.iter().map(OsString::from));
+    args
+}
+
+/// Writes `source` into `dir` and runs `cmd`, a rustc invocation built
+/// from [`rustc_args`], returning the path of `dir/main`. Lets a caller
+/// confine the compiler.
+pub fn compile_with(
+    cmd: &mut Command,
+    source: &str,
+    dir: &Path,
+    timeout: Duration,
+) -> Result<PathBuf, CompileError> {
+    fs::write(dir.join("main.rs"), source)?;
+
+    let out = run_with_timeout(cmd, timeout, MAX_DIAGNOSTIC_BYTES)?;
+    if out.timed_out() {
+        return Err(CompileError::TimedOut(timeout));
+    }
+    if !out.success() {
+        return Err(CompileError::Rejected(out.stderr));
+    }
+    Ok(dir.join("main"))
+}
@@ -0,0 +1,285 @@
+//! Running the examples in a submission's doc comments.
+//!
+//! Doc tests use the submission the way a downstream crate would, as
+//! `exercise_100::add(2, 3)`, so it is first built as a library under that
+//! name and `rustdoc --test` then compiles each example against that
+//! library and runs it. [`run_doc_tests`] does this for a single file;
+//! [`grade_crate_doc_tests`] has cargo do it for a crate, and also fails
+//! every public item that has no example.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use crate::api::{public_items, without_generics};
+use crate::cargo::{self, Crate};
+use crate::compile::{compile_library, CompileError, RUSTC_FLAGS};
+use crate::harness::{failure_block, parse_failure, Status, TestCase};
+use crate::process::{run_with_timeout, Captured};
+
+/// Exercises graded on their doc tests as well as their unit tests.
+pub const EXERCISES: &[&str] = &["100"];
+
+/// Name of the case that stands for the doc test run as a whole when it
+/// times out or fails before running any example.
+const RUN_NAME: &str = "doc tests";
+
+/// rustdoc's report is truncated to this many bytes.
+const MAX_REPORT_BYTES: usize = 65_536;
+
+/// The rustdoc to invoke: `$RUSTDOC` if set, otherwise the `rustdoc` next to
+/// `$RUSTC`, otherwise `rustdoc` from `PATH`.
+pub fn rustdoc() -> PathBuf {
+    if let Some(rustdoc) = std::env::var_os("RUSTDOC") {
+        return PathBuf::from(rustdoc);
+    }
+    match std::env::var_os("RUSTC").map(PathBuf::from) {
+        Some(rustc) if rustc.parent().is_some_and(|dir| !dir.as_os_str().is_empty()) => {
+            rustc.with_file_name("rustdoc")
+        }
+        _ => PathBuf::from("rustdoc"),
+    }
+}
+
+/// Builds `source` as the library `crate_name` in `dir` and runs its doc
+/// tests.
+///
+/// `timeout` bounds rustdoc as a whole, which compiles every example as
+/// well as running it. A submission whose library does not build is an
+/// error; an example that does not compile is a failed doc test.
+pub fn run_doc_tests(
+    source: &str,
+    crate_name: &str,
+    dir: &Path,
+    compile_timeout: Duration,
+    timeout: Duration,
+) -> Result<Captured, CompileError> {
+    let library = compile_library(source, dir, compile_timeout, crate_name)?;
+    let mut cmd = Command::new(rustdoc());
+    cmd.arg("--test")
+        .arg(dir.join("main.rs"))
+        .args(["--crate-name", crate_name])
+        .args(rustdoc_flags())
+        .arg("--extern")
+        .arg(format!("{crate_name}={}", library.display()))
+        .arg("-L")
+        .arg(dir)
+        .env("RUST_BACKTRACE", "0")
+        .current_dir(dir);
+    run_with_timeout(&mut cmd, timeout, MAX_REPORT_BYTES).map_err(CompileError::Io)
+}
+
+/// Runs the doc tests of `krate`, already built in `dir` by
+/// [`cargo::compile_crate_tests`], and grades them.
+///
+/// Each example is a case named as rustdoc names it, e.g. `src/lib.rs -
+/// add (line 15)`. Each of the [`public_items`] with no example that runs
+/// adds a failed `<item> - no example`: examples marked `ignore`, `no_run`
+/// or `compile_fail` do not count. `cargo test --doc` hands the examples
+/// to `rustdoc --test`; `timeout` bounds it as a whole.
+pub fn grade_crate_doc_tests(krate: &Crate, dir: &Path, timeout: Duration) -> io::Result<Vec<TestCase>> {
+    let mut cmd = cargo::command(dir);
+    cmd.args(["test", "--doc", "--offline"]).env("RUST_BACKTRACE", "0");
+    let out = run_with_timeout(&mut cmd, timeout, MAX_REPORT_BYTES)?;
+
+    let mut cases = doc_test_cases(&out.stdout);
+    let run_failure = |status, message: String| TestCase {
+        name: RUN_NAME.to_string(),
+        status,
+        panic_message: Some(message),
+        duration_ms: out.elapsed.as_millis() as u64,
+    };
+    if out.timed_out() {
+        // Examples that had not finished are missing, not undocumented
+        cases.push(run_failure(Status::TimedOut, format!("doc tests exceeded {}s", timeout.as_secs())));
+        return Ok(cases);
+    }
+    if cases.is_empty() && !out.success() {
+        cases.push(run_failure(Status::Failed, out.stderr.trim().to_string()));
+    }
+    cases.extend(missing_examples(&public_items(krate), &cases));
+    Ok(cases)
+}
+
+/// One case per `test <name> ... <result>` line of a rustdoc report. A
+/// failed example's message is its panic, or rustdoc's whole account of
+/// it when it did not compile.
+fn doc_test_cases(stdout: &str) -> Vec<TestCase> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test ")?.rsplit_once(" ... "))
+        .filter_map(|(name, result)| {
+            let status = match result.trim() {
+                "ok" => Status::Passed,
+                "FAILED" => Status::Failed,
+                "ignored" => Status::Ignored,
+                _ => return None,
+            };
+            let panic_message = match status {
+                Status::Failed => parse_failure(stdout, name)
+                    .or_else(|| failure_block(stdout, name).map(|block| block.trim().to_string())),
+                _ => None,
+            };
+            Some(TestCase {
+                name: name.to_string(),
+                status,
+                panic_message,
+                duration_ms: 0,
+            })
+        })
+        .collect()
+}
+
+/// A failed case for each of `items` that none of the examples in `cases`
+/// both belongs to and runs.
+fn missing_examples(items: &[String], cases: &[TestCase]) -> Vec<TestCase> {
+    let documented: HashSet<String> = cases
+        .iter()
+        .filter(|case| case.status != Status::Ignored)
+        .filter_map(|case| example_item(&case.name))
+        .collect();
+    items
+        .iter()
+        .filter(|item| !documented.contains(*item))
+        .map(|item| TestCase {
+            name: format!("{item} - no example"),
+            status: Status::Failed,
+            panic_message: Some(format!("`{item}` is public but its docs have no example that runs")),
+            duration_ms: 0,
+        })
+        .collect()
+}
+
+/// The item the example `name` documents, generics dropped, e.g.
+/// `UniqueCollection::add` for `src/lib.rs - UniqueCollection<T>::add
+/// (line 198)`; `None` for an example that is only compiled.
+fn example_item(name: &str) -> Option<String> {
+    let (_, rest) = name.split_once(" - ")?;
+    let (item, line) = rest.rsplit_once(" (line ")?;
+    line.ends_with(')').then(|| without_generics(item))
+}
+
+/// The parts of [`RUSTC_FLAGS`] rustdoc accepts; it has no `-C` of its own
+/// for doc tests.
+fn rustdoc_flags() -> impl Iterator<Item = &'static str> {
+    let edition = RUSTC_FLAGS.iter().position(|&f| f == "--edition");
+    edition.into_iter().flat_map(|i| RUSTC_FLAGS[i..i + 2].iter().copied())
+}
+
+/// Names of the tests a libtest or rustdoc report marks as failed, e.g.
+/// `tests::test_add`, or `add (line 15)` for rustdoc's
+/// `main.rs - add (line 15)`.
+pub fn failed_tests(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("test ")?.strip_suffix(" ... FAILED"))
+        .map(|name| test_name(name).to_string())
+        .collect()
+}
+
+/// `name` without the kind libtest and rustdoc append, as in
+/// `tests::overflow - should panic`, and without rustdoc's file prefix.
+fn test_name(name: &str) -> &str {
+    let name = [" - should panic", " - compile fail", " - compile"]
+        .iter()
+        .find_map(|kind| name.strip_suffix(kind))
+        .unwrap_or(name);
+    match name.split_once(" - ") {
+        Some((_, item)) if name.ends_with(')') && name.contains(" (line ") => item,
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScratchDir;
+
+    const SOURCE: &str = r#"
+/// ```
+/// assert_eq!(exercise_100::double(4), 8);
+/// ```
+///
+/// ```
+/// assert_eq!(exercise_100::double(1), 3);
+/// ```
+pub fn double(n: i32) -> i32 {
+    n * 2
+}
+
+fn main() {}
+"#;
+
+    #[test]
+    fn runs_each_example_against_the_library() {
+        let scratch = ScratchDir::new("doctest").unwrap();
+        let timeout = Duration::from_secs(60);
+        let out = run_doc_tests(SOURCE, "exercise_100", scratch.path(), timeout, timeout).unwrap();
+        assert!(!out.success(), "{}", out.stdout);
+        assert!(out.stdout.contains("2 tests"), "{}", out.stdout);
+        assert_eq!(failed_tests(&out.stdout), ["double (line 6)"]);
+    }
+
+    #[test]
+    fn failed_test_names_drop_the_kind_and_file() {
+        let report = "test tests::overflow - should panic ... FAILED\n\
+                      test tests::add ... ok\n\
+                      test src/main.rs - Stack (line 3) - compile fail ... FAILED\n\
+                      test tests::a - b ... FAILED\n";
+        assert_eq!(failed_tests(report), ["tests::overflow", "Stack (line 3)", "tests::a - b"]);
+    }
+
+    #[test]
+    fn public_items_without_an_example_that_runs_fail() {
+        let report = "test src/lib.rs - Point (line 3) ... ok\n\
+                      test src/lib.rs - Stack<T>::push (line 9) ... FAILED\n\
+                      test src/lib.rs - Stack<T>::pop (line 20) ... ignored\n\
+                      test src/lib.rs - add (line 30) - compile ... ok\n";
+        let cases = doc_test_cases(report);
+        let statuses: Vec<Status> = cases.iter().map(|c| c.status).collect();
+        assert_eq!(statuses, [Status::Passed, Status::Failed, Status::Ignored, Status::Passed]);
+
+        let items = ["Point", "Stack::push", "Stack::pop", "add"].map(String::from);
+        let missing: Vec<String> = missing_examples(&items, &cases).into_iter().map(|c| c.name).collect();
+        assert_eq!(missing, ["Stack::pop - no example", "add - no example"]);
+    }
+
+    #[test]
+    fn exercise_100_needs_an_example_on_every_public_item() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let opts = crate::Options {
+            exercise: Some("100".into()),
+            doc_tests: true,
+            ..crate::Options::default()
+        };
+        let template = Crate::read(&root.join("problems/100_exercise")).unwrap();
+        let report = crate::grade_crate(&template, &opts).unwrap();
+        let failed: Vec<&str> = report.tests.iter().filter(|t| t.status != Status::Passed).map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            failed,
+            [
+                "Point::x - no example",
+                "Point::y - no example",
+                "Point::distance_from_origin - no example",
+                "UniqueCollection::new - no example",
+                "UniqueCollection::contains - no example",
+                "UniqueCollection::len - no example",
+            ]
+        );
+        assert!(report.tests.iter().any(|t| t.name.starts_with("src/lib.rs - UniqueCollection<T>::add (line ")));
+
+        let solution = Crate::read(&root.join("solutions/100")).unwrap().with_tests_from(&template);
+        let report = crate::grade_crate(&solution, &opts).unwrap();
+        assert!(report.all_passed(), "{:?}", report.tests);
+    }
+
+    #[test]
+    fn a_library_that_does_not_build_is_rejected() {
+        let scratch = ScratchDir::new("doctest").unwrap();
+        let timeout = Duration::from_secs(60);
+        let err = run_doc_tests("pub fn f() -> u8 { \"no\" }", "f", scratch.path(), timeout, timeout);
+        assert!(matches!(err, Err(CompileError::Rejected(_))));
+    }
+}
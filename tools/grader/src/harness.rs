@@ -0,0 +1,263 @@
+//! Driving a compiled libtest binary one test at a time.
+//!
+//! Each test runs in its own process with `--exact`, which gives every test
+//! its own timeout and duration. The result comes from the exit status and
+//! the line libtest writes to its `--logfile`, kept outside the test's
+//! working directory; stable libtest has no other machine-readable output.
+//! Stdout is only mined for the panic message, since the code under test
+//! can print a forged `test <name> ... ok` line there.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::process::{run_with_timeout, Captured};
+use crate::ScratchDir;
+
+/// Captured test output is truncated to this many bytes.
+const MAX_TEST_OUTPUT: usize = 65_536;
+
+/// What libtest prints on stderr, without a newline, when given `--logfile`.
+const LOGFILE_WARNING: &str = "warning: `--logfile` is deprecated";
+
+/// Outcome of a single test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Passed,
+    Failed,
+    Ignored,
+    TimedOut,
+}
+
+/// Result of one `#[test]` function.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCase {
+    /// Path of the test inside the crate, e.g. `tests::test_lru_basic`.
+    pub name: String,
+    pub status: Status,
+    /// Message of the panic that failed the test, if any.
+    pub panic_message: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl TestCase {
+    /// True if the test failed on a `todo!()` / `unimplemented!()` stub.
+    pub fn hit_todo(&self) -> bool {
+        self.panic_message
+            .as_deref()
+            .is_some_and(|m| m.starts_with("not yet implemented") || m.starts_with("not implemented"))
+    }
+}
+
+/// Lists all tests in the harness binary, ignored ones included.
+pub fn list_tests(binary: &Path) -> io::Result<Vec<String>> {
+    let out = harness(binary)
+        .args(["--list", "--format", "terse"])
+        .output()?;
+    Ok(parse_listing(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Lists only the `#[ignore]`d tests in the harness binary.
+pub fn list_ignored(binary: &Path) -> io::Result<Vec<String>> {
+    let out = harness(binary)
+        .args(["--list", "--format", "terse", "--ignored"])
+        .output()?;
+    Ok(parse_listing(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Runs the single test `name` and classifies the outcome.
+pub fn run_test(binary: &Path, name: &str, timeout: Duration) -> io::Result<TestCase> {
+    let scratch = ScratchDir::new("test")?;
+    let work = scratch.path().join("work");
+    let log = scratch.path().join("log");
+    std::fs::create_dir(&work)?;
+
+    let mut cmd = harness(binary);
+    cmd.args(["--exact", name, "--test-threads=1", "--logfile"])
+        .arg(&log)
+        .current_dir(&work);
+    let mut out = run_with_timeout(&mut cmd, timeout, MAX_TEST_OUTPUT)?;
+    if let Some(rest) = out.stderr.strip_prefix(LOGFILE_WARNING) {
+        out.stderr = rest.to_string();
+    }
+    let duration_ms = out.elapsed.as_millis() as u64;
+
+    if out.timed_out() {
+        return Ok(TestCase {
+            name: name.to_string(),
+            status: Status::TimedOut,
+            panic_message: Some(format!("test exceeded {}s", timeout.as_secs())),
+            duration_ms,
+        });
+    }
+
+    // Passing takes both the log line and a clean exit. Without a log line
+    // the harness never finished the test: it died (abort, stack overflow,
+    // ...) or the code under test called process::exit, which fails the
+    // test even with exit status 0.
+    let logged = std::fs::read_to_string(&log).unwrap_or_default();
+    let status = match parse_log(&logged, name) {
+        Some(status @ (Status::Passed | Status::Ignored)) if out.success() => status,
+        _ => Status::Failed,
+    };
+    let panic_message = match status {
+        Status::Failed => parse_failure(&out.stdout, name)
+            .or_else(|| abnormal_exit(&out.stderr))
+            .or_else(|| Some(exit_without_result(&out))),
+        _ => None,
+    };
+
+    Ok(TestCase {
+        name: name.to_string(),
+        status,
+        panic_message,
+        duration_ms,
+    })
+}
+
+fn harness(binary: &Path) -> Command {
+    let mut cmd = Command::new(binary);
+    // Keep panic reports to the message itself; backtraces are noise here.
+    cmd.env("RUST_BACKTRACE", "0");
+    cmd
+}
+
+/// Parses `--list --format terse` output: one `name: test` line per test.
+pub(crate) fn parse_listing(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Finds the `<result> <name>` line for `name` in a `--logfile`.
+pub(crate) fn parse_log(log: &str, name: &str) -> Option<Status> {
+    log.lines().find_map(|line| {
+        let (result, test) = line.split_once(' ')?;
+        if test != name {
+            return None;
+        }
+        match result {
+            "ok" => Some(Status::Passed),
+            "failed" => Some(Status::Failed),
+            "ignored" => Some(Status::Ignored),
+            _ => None,
+        }
+    })
+}
+
+/// Extracts the failure explanation from the `---- <name> stdout ----` block.
+///
+/// That block holds whatever the test printed followed by the panic report
+/// `thread '<name>' panicked at <file>:<line>:<col>:` and the message lines.
+/// The message is everything up to the `note:` trailer.
+pub(crate) fn parse_failure(stdout: &str, name: &str) -> Option<String> {
+    let block = failure_block(stdout, name)?;
+    let mut lines = block.lines();
+    let mut message = Vec::new();
+    if lines.by_ref().any(|l| l.starts_with("thread '") && l.contains("panicked at")) {
+        message.extend(lines.take_while(|l| !l.starts_with("note: ")));
+    } else {
+        // e.g. `#[should_panic]` tests report "note: test did not panic as expected".
+        message.extend(block.lines().filter_map(|l| l.strip_prefix("note: ")));
+    }
+
+    let message = message.join("\n").trim().to_string();
+    (!message.is_empty()).then_some(message)
+}
+
+/// Everything in the `---- <name> stdout ----` block of a failed test.
+pub(crate) fn failure_block<'a>(stdout: &'a str, name: &str) -> Option<&'a str> {
+    let header = format!("---- {name} stdout ----");
+    let start = stdout.find(&header)? + header.len();
+    let block = &stdout[start..];
+    let block = block.find("\n---- ").map_or(block, |end| &block[..end]);
+    Some(block.find("\nfailures:").map_or(block, |end| &block[..end]))
+}
+
+fn abnormal_exit(stderr: &str) -> Option<String> {
+    let stderr = stderr.trim();
+    (!stderr.is_empty()).then(|| stderr.to_string())
+}
+
+/// Message for a test process that exited without reporting a result.
+fn exit_without_result(out: &Captured) -> String {
+    match out.status.and_then(|status| status.code()) {
+        Some(code) => format!("the test process exited with status {code} before the test finished"),
+        None => "the test process was killed before the test finished".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAILED_RUN: &str = "
+running 1 test
+test tests::bad ... FAILED
+
+failures:
+
+---- tests::bad stdout ----
+learner output
+thread 'tests::bad' (14756) panicked at main.rs:5:20:
+assertion `left == right` failed: nope
+  left: 1
+ right: 2
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::bad
+
+test result: FAILED. 0 passed; 1 failed; 0 ignored; 0 measured; 4 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn listing_keeps_only_tests() {
+        let out = "tests::a: test\ntests::b: test\nsome_bench: bench\n";
+        assert_eq!(parse_listing(out), ["tests::a", "tests::b"]);
+    }
+
+    #[test]
+    fn log_lines() {
+        assert_eq!(parse_log("failed tests::bad\n", "tests::bad"), Some(Status::Failed));
+        assert_eq!(parse_log("ok tests::sp\n", "tests::sp"), Some(Status::Passed));
+        assert_eq!(parse_log("ignored tests::x\n", "tests::x"), Some(Status::Ignored));
+        assert_eq!(parse_log("ok tests::xy\n", "tests::x"), None);
+        assert_eq!(parse_log("test tests::x ... ok\n", "tests::x"), None);
+    }
+
+    #[test]
+    fn panic_message_is_extracted() {
+        assert_eq!(
+            parse_failure(FAILED_RUN, "tests::bad").as_deref(),
+            Some("assertion `left == right` failed: nope\n  left: 1\n right: 2")
+        );
+    }
+
+    #[test]
+    fn should_panic_note_is_the_message() {
+        let out = "---- tests::sp stdout ----\nnote: test did not panic as expected\n\nfailures:\n";
+        assert_eq!(
+            parse_failure(out, "tests::sp").as_deref(),
+            Some("test did not panic as expected")
+        );
+    }
+
+    #[test]
+    fn todo_is_detected() {
+        let case = TestCase {
+            name: "tests::t".into(),
+            status: Status::Failed,
+            panic_message: Some("not yet implemented".into()),
+            duration_ms: 0,
+        };
+        assert!(case.hit_todo());
+    }
+}
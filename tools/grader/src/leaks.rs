@@ -0,0 +1,218 @@
+//! Failing tests that leave memory behind.
+//!
+//! [`instrument`] installs a counting `#[global_allocator]` in the test
+//! build and runs every `#[test]` body through it. A test that returns with
+//! more live allocations on its thread than it started with fails with a
+//! message saying how many it leaked and how many bytes they hold, which
+//! catches `Rc` cycles, `mem::forget` and `Box::into_raw` without a
+//! matching `Box::from_raw` in the pointer exercises.
+//!
+//! The counts are per thread, kept in thread-locals, so two kinds of
+//! test get the wrong answer:
+//!
+//! - allocations made on a thread the test spawns are not counted, so a
+//!   leak there passes;
+//! - memory the test allocates and another thread frees is counted as
+//!   leaked, so handing a `Box` to a worker that drops it fails.
+//!
+//! A process-wide count would fix both, even with one test per process,
+//! but libtest's main thread allocates while the test runs (when it blocks
+//! on the result channel, depending on timing), which would fail tests at
+//! random. The leak-checked exercises (094, 119, 138) are single-threaded.
+
+use crate::splice::test_fn_bodies;
+
+/// Module holding the allocator, appended to the instrumented source.
+pub const MODULE: &str = "leak_check";
+
+/// Source of [`MODULE`]. Its `run` wraps each test body in a closure, so
+/// the body's locals and temporaries are all dropped before counting.
+const ALLOCATOR: &str = r#"
+#[cfg(test)]
+mod leak_check {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Per thread, so that allocations libtest's main thread makes while
+    // the test runs do not count against it
+    thread_local! {
+        static LIVE: Cell<isize> = const { Cell::new(0) };
+        static BYTES: Cell<isize> = const { Cell::new(0) };
+    }
+
+    /// Adds to this thread's counts. The counters are const-initialized
+    /// and need no destructor, so this never allocates; after the thread's
+    /// locals are gone it does nothing.
+    fn count(live: isize, bytes: isize) {
+        let _ = LIVE.try_with(|n| n.set(n.get() + live));
+        let _ = BYTES.try_with(|n| n.set(n.get() + bytes));
+    }
+
+    fn counts() -> (isize, isize) {
+        (LIVE.with(Cell::get), BYTES.with(Cell::get))
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                count(1, layout.size() as isize);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                count(1, layout.size() as isize);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            count(-1, -(layout.size() as isize));
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                count(0, new_size as isize - layout.size() as isize);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static COUNTING: Counting = Counting;
+
+    /// Runs one test body on the current thread. Only that thread's
+    /// allocations count: memory the test hands to another thread to free
+    /// looks leaked, and leaks on threads it spawns go unseen. The leaked
+    /// byte count also takes in buffers the test grew but did not
+    /// allocate, such as captured output.
+    pub fn run<T>(test: impl FnOnce() -> T) -> T {
+        // The test's first print allocates libtest's capture buffer, which
+        // outlives the test; make that happen before counting starts
+        println!();
+        let (live, bytes) = counts();
+        let out = test();
+        let leaked = counts().0 - live;
+        if leaked > 0 {
+            let bytes = counts().1 - bytes;
+            panic!(
+                "leaked {leaked} allocation{} ({bytes} bytes) still live when the test returned; \
+                 look for an Rc cycle, a mem::forget or a Box::into_raw that is never freed",
+                if leaked == 1 { "" } else { "s" }
+            );
+        }
+        out
+    }
+}
+"#;
+
+/// `source` with every `#[test]` body run through the counting allocator.
+/// A submission with its own `#[global_allocator]` no longer compiles.
+pub fn instrument(source: &str) -> String {
+    let mut out = String::with_capacity(source.len() + ALLOCATOR.len());
+    let mut last = 0;
+    for body in test_fn_bodies(source) {
+        out.push_str(&source[last..body.start]);
+        out.push_str(&format!("{{ crate::{MODULE}::run(|| "));
+        out.push_str(&source[body.clone()]);
+        out.push_str(") }");
+        last = body.end;
+    }
+    out.push_str(&source[last..]);
+    out.push_str(ALLOCATOR);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::splice::{append_test_module, with_tests_from, HIDDEN_MODULE};
+    use crate::{grade, Options, Status};
+    use std::path::Path;
+
+    const SUBMISSION: &str = r#"
+use std::rc::Rc;
+use std::cell::RefCell;
+
+struct Node { next: RefCell<Option<Rc<Node>>> }
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn balanced() {
+        let v = vec![String::from("a"); 3];
+        println!("printing is not a leak: {v:?}");
+        assert_eq!(v.len(), 3)
+    }
+
+    #[test]
+    fn cycle() {
+        let a = Rc::new(Node { next: RefCell::new(None) });
+        *a.next.borrow_mut() = Some(Rc::clone(&a));
+    }
+
+    #[test]
+    fn forgotten() {
+        std::mem::forget(Box::new([0u8; 40]));
+    }
+
+    #[test]
+    fn returns_result() -> Result<(), String> {
+        let s = String::from("kept until the test returns");
+        if s.is_empty() {
+            return Err(s);
+        }
+        Ok(())
+    }
+}
+"#;
+
+    #[test]
+    fn leaking_tests_fail_with_the_count() {
+        let opts = Options {
+            check_leaks: true,
+            ..Options::default()
+        };
+        let report = grade(SUBMISSION, &opts).unwrap();
+        assert!(report.compiled, "{:?}", report.compile_error);
+        let by_name = |n: &str| report.tests.iter().find(|t| t.name == n).unwrap();
+
+        assert_eq!(by_name("tests::balanced").status, Status::Passed);
+        assert_eq!(by_name("tests::returns_result").status, Status::Passed);
+        let cycle = by_name("tests::cycle");
+        assert_eq!(cycle.status, Status::Failed);
+        let message = cycle.panic_message.as_deref().unwrap();
+        assert!(message.starts_with("leaked 1 allocation ("), "{message}");
+        let forgotten = by_name("tests::forgotten").panic_message.as_deref().unwrap();
+        assert!(forgotten.starts_with("leaked 1 allocation (40 bytes)"), "{forgotten}");
+    }
+
+    #[test]
+    fn pointer_exercise_solutions_do_not_leak() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let opts = Options {
+            check_leaks: true,
+            ..Options::default()
+        };
+        for id in ["094", "119", "138"] {
+            let read = |path: String| std::fs::read_to_string(root.join(path)).unwrap();
+            let dir = format!("problems/{id}_exercise");
+            let mut source = with_tests_from(&read(format!("solutions/{id}.rs")), &read(format!("{dir}/template.rs")));
+            if root.join(&dir).join("tests.rs").exists() {
+                source = append_test_module(&source, HIDDEN_MODULE, &read(format!("{dir}/tests.rs")));
+            }
+            let report = grade(&source, &opts).unwrap();
+            assert!(report.all_passed(), "{id}: {:?}", report.tests);
+        }
+    }
+}
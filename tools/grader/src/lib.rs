@@ -0,0 +1,291 @@
+//! Test-based grading for exercise submissions.
+//!
+//! A submission is compiled with `rustc --test` using the same flags as
+//! `code_runner.py`, then every `#[test]` in it is run in its own process.
+//! The [`Report`] lists each test with its status, panic message and
+//! duration, and scores the submission by the share of tests that passed.
+//! Multi-file submissions are built with cargo instead (see [`cargo`]),
+//! and can have their doc tests graded as well (see [`doctest`]).
+
+pub mod api;
+pub mod cargo;
+pub mod compile;
+pub mod doctest;
+pub mod harness;
+pub mod leaks;
+pub mod process;
+pub mod property;
+pub mod report;
+pub mod splice;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+pub use cargo::Crate;
+pub use compile::CompileError;
+pub use harness::{Status, TestCase};
+pub use report::Report;
+
+/// Grading limits and labels.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Exercise number echoed back in the report.
+    pub exercise: Option<String>,
+    pub compile_timeout: Duration,
+    /// Wall-clock limit for each individual test.
+    pub test_timeout: Duration,
+    /// Fail tests that leave allocations behind (see [`leaks`]).
+    pub check_leaks: bool,
+    /// Also grade the crate's doc tests, and fail public items without an
+    /// example (see [`doctest::grade_crate_doc_tests`]). Crates only.
+    pub doc_tests: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            exercise: None,
+            compile_timeout: Duration::from_secs(30),
+            test_timeout: Duration::from_secs(5),
+            check_leaks: false,
+            doc_tests: false,
+        }
+    }
+}
+
+/// Compiles `source` as a test harness and runs each of its tests.
+///
+/// A submission that does not compile yields a report with
+/// `compiled: false`; only failures of the grader itself are errors.
+pub fn grade(source: &str, opts: &Options) -> io::Result<Report> {
+    let scratch = ScratchDir::new("grader")?;
+    let instrumented;
+    let source = if opts.check_leaks {
+        instrumented = leaks::instrument(source);
+        &instrumented
+    } else {
+        source
+    };
+    let binary = match compile::compile_tests(source, scratch.path(), opts.compile_timeout) {
+        Ok(binary) => binary,
+        Err(CompileError::Io(err)) => return Err(err),
+        Err(err) => return Ok(Report::compile_failure(opts.exercise.clone(), err.to_string())),
+    };
+    run_all(&binary, opts)
+}
+
+/// Builds `krate` with cargo and runs the tests of each of its targets.
+///
+/// The package is named after the exercise (see [`cargo::package_name`]).
+/// As with [`grade`], a crate that does not build yields a report with
+/// `compiled: false`. Leak checking is not supported here. Doc tests run
+/// after the unit tests, within the compile timeout since building the
+/// examples is most of their cost.
+pub fn grade_crate(krate: &Crate, opts: &Options) -> io::Result<Report> {
+    let scratch = ScratchDir::new("grader")?;
+    let package = cargo::package_name(opts.exercise.as_deref());
+    let binaries = match cargo::compile_crate_tests(krate, &package, scratch.path(), opts.compile_timeout) {
+        Ok(binaries) => binaries,
+        Err(CompileError::Io(err)) => return Err(err),
+        Err(err) => return Ok(Report::compile_failure(opts.exercise.clone(), err.to_string())),
+    };
+    let mut tests = Vec::new();
+    for binary in &binaries {
+        tests.extend(run_tests(binary, opts)?);
+    }
+    if opts.doc_tests {
+        tests.extend(doctest::grade_crate_doc_tests(krate, scratch.path(), opts.compile_timeout)?);
+    }
+    Ok(Report::from_tests(opts.exercise.clone(), tests))
+}
+
+/// Runs every test in an already compiled harness binary.
+pub fn run_all(binary: &Path, opts: &Options) -> io::Result<Report> {
+    Ok(Report::from_tests(opts.exercise.clone(), run_tests(binary, opts)?))
+}
+
+fn run_tests(binary: &Path, opts: &Options) -> io::Result<Vec<TestCase>> {
+    let ignored = harness::list_ignored(binary)?;
+    let mut tests = Vec::new();
+    for name in harness::list_tests(binary)? {
+        let case = if ignored.contains(&name) {
+            TestCase {
+                name,
+                status: Status::Ignored,
+                panic_message: None,
+                duration_ms: 0,
+            }
+        } else {
+            harness::run_test(binary, &name, opts.test_timeout)?
+        };
+        tests.push(case);
+    }
+    Ok(tests)
+}
+
+/// A uniquely named temporary directory, removed on drop.
+#[derive(Debug)]
+pub struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    pub fn new(prefix: &str) -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("{prefix}-{}-{n}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        Ok(ScratchDir(dir))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBMISSION: &str = r#"
+fn add(a: i32, b: i32) -> i32 { a + b }
+fn sub(_a: i32, _b: i32) -> i32 { todo!() }
+
+fn main() { println!("{}", add(1, 2)); }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() { assert_eq!(add(2, 2), 4); }
+
+    #[test]
+    fn test_sub() { assert_eq!(sub(2, 2), 0); }
+
+    #[test]
+    fn test_wrong() { assert_eq!(add(2, 2), 5, "two plus two"); }
+
+    #[test]
+    #[ignore]
+    fn test_slow() {}
+}
+"#;
+
+    #[test]
+    fn grades_each_test() {
+        let report = grade(SUBMISSION, &Options::default()).unwrap();
+        assert!(report.compiled);
+        assert_eq!((report.passed, report.failed, report.ignored), (1, 2, 1));
+
+        let by_name = |n: &str| report.tests.iter().find(|t| t.name == n).unwrap();
+        assert_eq!(by_name("tests::test_add").status, Status::Passed);
+        assert!(by_name("tests::test_sub").hit_todo());
+        let wrong = by_name("tests::test_wrong").panic_message.as_deref().unwrap();
+        assert!(wrong.contains("two plus two"), "{wrong}");
+        assert_eq!(by_name("tests::test_slow").status, Status::Ignored);
+    }
+
+    #[test]
+    fn hidden_tests_run_beside_template_tests() {
+        let hidden = "//! Not shown to learners.\nuse super::*;\n\n\
+                      #[test]\nfn big() { assert_eq!(add(1 << 20, 1), 1_048_577); }\n";
+        let source = splice::append_test_module(SUBMISSION, splice::HIDDEN_MODULE, hidden);
+        let report = grade(&source, &Options::default()).unwrap();
+        let big = report.tests.iter().find(|t| t.name == "hidden_tests::big").unwrap();
+        assert_eq!(big.status, Status::Passed);
+        assert_eq!(report.tests.len(), 5);
+    }
+
+    #[test]
+    fn compile_errors_are_reported() {
+        let report = grade("fn main() { let x: u32 = \"no\"; }", &Options::default()).unwrap();
+        assert!(!report.compiled);
+        assert!(report.compile_error.unwrap().contains("mismatched types"));
+    }
+
+    #[test]
+    fn exiting_early_fails_the_test() {
+        let source = "fn add(_a: i32, _b: i32) -> i32 { std::process::exit(0) }\n\
+                      fn main() {}\n#[test]\nfn test_add() { assert_eq!(add(1, 2), 3); }\n";
+        let report = grade(source, &Options::default()).unwrap();
+        assert_eq!((report.passed, report.failed), (0, 1));
+        let message = report.tests[0].panic_message.as_deref().unwrap();
+        assert!(message.contains("exited with status 0"), "{message}");
+    }
+
+    #[test]
+    fn forged_result_lines_do_not_pass() {
+        let source = "fn add(_a: i32, _b: i32) -> i32 {\n\
+                          println!(\"test tests::test_add ... ok\");\n\
+                          std::process::exit(0)\n\
+                      }\n\
+                      fn main() {}\n\
+                      #[cfg(test)]\nmod tests {\n\
+                          #[test]\nfn test_add() { assert_eq!(super::add(1, 2), 3); }\n\
+                      }\n";
+        let report = grade(source, &Options::default()).unwrap();
+        assert_eq!((report.passed, report.failed), (0, 1));
+    }
+
+    #[test]
+    fn infinite_loops_time_out() {
+        let source = "fn main() {}\n#[test]\nfn spin() { loop { std::hint::spin_loop(); } }\n";
+        let opts = Options {
+            test_timeout: Duration::from_millis(300),
+            ..Options::default()
+        };
+        let report = grade(source, &opts).unwrap();
+        assert_eq!(report.tests[0].status, Status::TimedOut);
+    }
+
+    #[test]
+    fn crates_are_built_with_cargo_across_files() {
+        let krate = Crate {
+            files: [
+                ("src/lib.rs", "pub mod garden;
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn tall() { assert!(crate::garden::height() > 1); }
+}
+"),
+                ("src/garden.rs", "pub(crate) fn height() -> u32 { 2 }
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn short() { assert_eq!(super::height(), 1); }
+}
+"),
+                ("src/main.rs", "fn main() { let _ = exercise_097::garden::height; }
+"),
+            ]
+            .into_iter()
+            .map(|(path, source)| (path.to_string(), source.to_string()))
+            .collect(),
+        };
+        let opts = Options {
+            exercise: Some("097".into()),
+            ..Options::default()
+        };
+        // main.rs is another crate: pub(crate) stops at the library's edge
+        let report = grade_crate(&krate, &opts).unwrap();
+        assert!(!report.compiled);
+        assert!(report.compile_error.unwrap().contains("src/main.rs"));
+
+        let mut krate = krate;
+        krate.files.insert("src/main.rs".into(), "fn main() {}
+".into());
+        let report = grade_crate(&krate, &opts).unwrap();
+        assert!(report.compiled, "{:?}", report.compile_error);
+        let names: Vec<_> = report.tests.iter().map(|t| (t.name.as_str(), t.status)).collect();
+        assert_eq!(names, [("garden::tests::short", Status::Failed), ("tests::tall", Status::Passed)]);
+    }
+}
@@ -0,0 +1,152 @@
+//! `grader` -- compile a submission with `--test` and print a JSON report.
+//!
+//! Usage: grader [--exercise NNN] [--tests-from TEMPLATE] [--hidden-tests FILE]
+//!               [--variant-tests FILE] [--property-tests FILE] [--test-timeout SECS]
+//!               [--compile-timeout SECS] [--check-leaks] [--doc-tests] <FILE | DIR | ->
+//!
+//! With `--tests-from`, the submission's own `#[cfg(test)]` modules are
+//! replaced by those of TEMPLATE before compiling. `--hidden-tests` appends
+//! the items in FILE as an extra `mod hidden_tests`, and `--variant-tests`
+//! as `mod variant_tests` (the learner's generated cases, see tools/variant).
+//! `--property-tests` appends an exercise's `properties.rs` as
+//! `mod property_tests`, together with the `prop` runtime it is written
+//! against; a failing property reports a shrunk counterexample.
+//! `--check-leaks` fails every test that returns with more live heap
+//! allocations than it started with.
+//!
+//! A DIR is a multi-file submission, the `src/` of a Cargo crate: it is
+//! built with cargo as the package `exercise_NNN`, and TEMPLATE is then
+//! the exercise's crate directory, whose tests replace those of the same
+//! files. Hidden, generated and property tests are appended to `src/lib.rs`.
+//! `--doc-tests` also runs the crate's doc tests, one case per example,
+//! and fails each public item of the library that has no example.
+//!
+//! Exit status is 0 when every test passed, 1 when the submission failed
+//! to compile or any test failed, and 2 on usage or internal errors.
+
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use grader::splice::{HIDDEN_MODULE, PROPERTY_MODULE, VARIANT_MODULE};
+use grader::{Crate, Options, Report};
+
+const USAGE: &str = "usage: grader [--exercise NNN] [--tests-from TEMPLATE] \
+                     [--hidden-tests FILE] [--variant-tests FILE] [--property-tests FILE] \
+                     [--test-timeout SECS] [--compile-timeout SECS] [--check-leaks] [--doc-tests] <FILE | DIR | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("grader: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut opts = Options::default();
+    let mut input = None;
+    let mut tests_from = None;
+    let mut hidden_tests = None;
+    let mut variant_tests = None;
+    let mut property_tests = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => opts.exercise = Some(value(&mut args, &arg)?),
+            "--tests-from" => tests_from = Some(value(&mut args, &arg)?),
+            "--hidden-tests" => hidden_tests = Some(value(&mut args, &arg)?),
+            "--variant-tests" => variant_tests = Some(value(&mut args, &arg)?),
+            "--property-tests" => property_tests = Some(value(&mut args, &arg)?),
+            "--test-timeout" => opts.test_timeout = seconds(&mut args, &arg)?,
+            "--compile-timeout" => opts.compile_timeout = seconds(&mut args, &arg)?,
+            "--check-leaks" => opts.check_leaks = true,
+            "--doc-tests" => opts.doc_tests = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let input = input.ok_or(USAGE)?;
+    if Path::new(&input).is_dir() {
+        if opts.check_leaks {
+            return Err("--check-leaks does not support multi-file submissions".to_string());
+        }
+        let mut krate = read_crate(&input)?;
+        if let Some(template) = tests_from {
+            krate = krate.with_tests_from(&read_crate(&template)?);
+        }
+        if let Some(hidden) = hidden_tests {
+            krate.append_test_module(HIDDEN_MODULE, &read(&hidden)?);
+        }
+        if let Some(variant) = variant_tests {
+            krate.append_test_module(VARIANT_MODULE, &read(&variant)?);
+        }
+        if let Some(properties) = property_tests {
+            krate.append_test_module(PROPERTY_MODULE, &grader::property::test_module(&read(&properties)?));
+        }
+        let report = grader::grade_crate(&krate, &opts).map_err(|e| e.to_string())?;
+        return print(&report);
+    }
+    if opts.doc_tests {
+        return Err("--doc-tests needs a crate directory".to_string());
+    }
+
+    let mut source = if input == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf).map_err(|e| e.to_string())?;
+        buf
+    } else {
+        read(&input)?
+    };
+    if let Some(template) = tests_from {
+        source = grader::splice::with_tests_from(&source, &read(&template)?);
+    }
+    if let Some(hidden) = hidden_tests {
+        source = grader::splice::append_test_module(&source, HIDDEN_MODULE, &read(&hidden)?);
+    }
+    if let Some(variant) = variant_tests {
+        source = grader::splice::append_test_module(&source, VARIANT_MODULE, &read(&variant)?);
+    }
+    if let Some(properties) = property_tests {
+        let module = grader::property::test_module(&read(&properties)?);
+        source = grader::splice::append_test_module(&source, PROPERTY_MODULE, &module);
+    }
+
+    let report = grader::grade(&source, &opts).map_err(|e| e.to_string())?;
+    print(&report)
+}
+
+fn print(report: &Report) -> Result<bool, String> {
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.all_passed())
+}
+
+fn read_crate(dir: &str) -> Result<Crate, String> {
+    Crate::read(Path::new(dir)).map_err(|e| e.to_string())
+}
+
+fn read(path: &str) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
+
+fn seconds(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<Duration, String> {
+    let raw = value(args, flag)?;
+    raw.parse::<u64>()
+        .map(Duration::from_secs)
+        .map_err(|_| format!("{flag}: `{raw}` is not a number of seconds"))
+}
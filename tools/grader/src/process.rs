@@ -0,0 +1,130 @@
+//! Child process helpers: run a command to completion or until a deadline.
+
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a running child is polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Captured result of a finished (or killed) child process.
+#[derive(Debug)]
+pub struct Captured {
+    /// Exit status, `None` if the child was killed after the deadline.
+    pub status: Option<ExitStatus>,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+}
+
+impl Captured {
+    pub fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+
+    pub fn success(&self) -> bool {
+        self.status.is_some_and(|s| s.success())
+    }
+}
+
+/// Runs `cmd` with piped output, killing it once `timeout` has elapsed.
+///
+/// Output is drained on background threads so a chatty child cannot block
+/// on a full pipe while we wait for it. At most `max_output` bytes of each
+/// stream are kept.
+pub fn run_with_timeout(
+    cmd: &mut Command,
+    timeout: Duration,
+    max_output: usize,
+) -> io::Result<Captured> {
+    let start = Instant::now();
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = drain(child.stdout.take(), max_output);
+    let stderr = drain(child.stderr.take(), max_output);
+    let status = wait_deadline(&mut child, start + timeout)?;
+    let elapsed = start.elapsed();
+
+    Ok(Captured {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        elapsed,
+    })
+}
+
+fn wait_deadline(child: &mut Child, deadline: Instant) -> io::Result<Option<ExitStatus>> {
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            // The child may exit between try_wait and kill; either way it is gone.
+            let _ = child.kill();
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn drain<R: Read + Send + 'static>(
+    pipe: Option<R>,
+    max_output: usize,
+) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return String::new();
+        };
+        let mut kept = Vec::new();
+        let mut buf = [0u8; 8192];
+        loop {
+            match pipe.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    let room = max_output.saturating_sub(kept.len());
+                    kept.extend_from_slice(&buf[..n.min(room)]);
+                }
+            }
+        }
+        String::from_utf8_lossy(&kept).into_owned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_output_and_status() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo out; echo err >&2; exit 3"]);
+        let captured = run_with_timeout(&mut cmd, Duration::from_secs(5), 1024).unwrap();
+        assert_eq!(captured.stdout, "out\n");
+        assert_eq!(captured.stderr, "err\n");
+        assert_eq!(captured.status.and_then(|s| s.code()), Some(3));
+        assert!(!captured.success());
+    }
+
+    #[test]
+    fn kills_after_deadline() {
+        let mut cmd = Command::new("sleep");
+        cmd.arg("10");
+        let captured = run_with_timeout(&mut cmd, Duration::from_millis(50), 1024).unwrap();
+        assert!(captured.timed_out());
+        assert!(captured.elapsed < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn truncates_output() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "printf 0123456789"]);
+        let captured = run_with_timeout(&mut cmd, Duration::from_secs(5), 4).unwrap();
+        assert_eq!(captured.stdout, "0123");
+    }
+}
@@ -0,0 +1,98 @@
+//! Property tests: invariants checked on many generated inputs.
+//!
+//! An exercise's `properties.rs` holds `#[test]`s that state what must
+//! hold for any input, e.g. that `bubble_sort` leaves a sorted permutation
+//! of its input, and check it with `prop::check`. [`test_module`] bundles
+//! them with [`RUNTIME`], the generators and shrinker, into the body of
+//! `mod property_tests`, which is appended to the submission like its
+//! hidden tests. A failing property reports the smallest input it found
+//! that still fails.
+
+/// Source of the `prop` module the properties use. It needs nothing but
+/// std, so it builds wherever the submission does.
+pub const RUNTIME: &str = include_str!("property/runtime.rs");
+
+/// The body of the test module for `properties`: the properties followed
+/// by `mod prop`, which they import from.
+pub fn test_module(properties: &str) -> String {
+    format!("{properties}\n\nmod prop {{\n{RUNTIME}}}\n")
+}
+
+// Compiled here only so that it is checked and tested with the grader
+#[cfg(test)]
+mod runtime;
+
+#[cfg(test)]
+mod tests {
+    use super::runtime::*;
+    use super::*;
+    use crate::splice::{append_test_module, with_tests_from, PROPERTY_MODULE};
+    use crate::{grade, Options, Status};
+    use std::panic;
+    use std::path::Path;
+
+    /// The message `check` fails with, if it fails.
+    fn failure<S: Strategy>(strategy: S, property: impl Fn(S::Value)) -> Option<String> {
+        let caught = panic::catch_unwind(panic::AssertUnwindSafe(|| check(strategy, property)));
+        caught.err().map(|payload| payload.downcast_ref::<String>().cloned().unwrap_or_default())
+    }
+
+    #[test]
+    fn counterexamples_are_shrunk_to_a_minimum() {
+        let message = failure(vecs(ints(-1000..=1000i32), 0..=32), |v| {
+            assert!(v.iter().sum::<i32>() < 100, "sum of {v:?}");
+        })
+        .unwrap();
+        assert!(message.contains("minimal counterexample: [100]\n"), "{message}");
+        assert!(message.ends_with("sum of [100]"), "{message}");
+
+        let message = failure((strings("ab", 0..=16), ints(0..=u64::MAX)), |(s, n)| {
+            assert!(!(s.contains('b') && n > 5));
+        })
+        .unwrap();
+        assert!(message.contains("minimal counterexample: (\"b\", 6)\n"), "{message}");
+    }
+
+    #[test]
+    fn runs_are_reproducible_and_passing_properties_are_silent() {
+        let run = || failure(ints(0..=u64::MAX), |n| assert!(n < 1000));
+        let first = run().unwrap();
+        assert!(first.contains("minimal counterexample: 1000\n"), "{first}");
+        assert_eq!(run(), Some(first));
+        assert_eq!(failure(vecs(ints(0..=9u8), 2..=4), |v| assert!((2..=4).contains(&v.len()))), None);
+    }
+
+    #[test]
+    fn a_bubble_sort_that_stops_early_is_caught() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        let template = read("problems/101_exercise/template.rs");
+        let buggy = read("solutions/101.rs").replace("for i in 0..n", "for i in 0..n.saturating_sub(2)");
+        assert_ne!(buggy, read("solutions/101.rs"));
+
+        let properties = test_module(&read("problems/101_exercise/properties.rs"));
+        let source = append_test_module(&with_tests_from(&buggy, &template), PROPERTY_MODULE, &properties);
+        let report = grade(&source, &Options::default()).unwrap();
+        let sorted = report.tests.iter().find(|t| t.name == "property_tests::output_is_sorted").unwrap();
+        assert_eq!(sorted.status, Status::Failed);
+        let message = sorted.panic_message.as_deref().unwrap();
+        assert!(message.contains("minimal counterexample: [0, -1]\n"), "{message}");
+    }
+
+    #[test]
+    fn reference_solutions_have_every_property() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        for id in 101..=150 {
+            let dir = root.join(format!("problems/{id}_exercise"));
+            let Ok(properties) = std::fs::read_to_string(dir.join("properties.rs")) else {
+                continue;
+            };
+            let solution = std::fs::read_to_string(root.join(format!("solutions/{id}.rs"))).unwrap();
+            let source = append_test_module(&solution, PROPERTY_MODULE, &test_module(&properties));
+            let report = grade(&source, &Options::default()).unwrap();
+            assert!(report.compiled, "{id}: {}", report.compile_error.unwrap_or_default());
+            let failed: Vec<_> = report.tests.iter().filter(|t| t.status != Status::Passed).collect();
+            assert!(failed.is_empty(), "{id}: {failed:?}");
+        }
+    }
+}
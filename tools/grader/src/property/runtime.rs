@@ -0,0 +1,329 @@
+//! Property testing with shrinking, spliced into a submission as `prop`.
+//!
+//! `check(strategy, property)` runs `property` on [`CASES`] values drawn
+//! from `strategy`, small ones first. The first value it panics on is
+//! shrunk: every simpler value the strategy offers is tried in turn, and
+//! kept if the property still fails on it, until none does. The test then
+//! fails with that minimal counterexample and the property's own panic
+//! message. Each run is seeded from the test's name, so a failure comes
+//! back the same on every run. This file builds with std alone.
+
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Values tried per property.
+pub const CASES: usize = 256;
+
+/// Sizes grow from 0 to this over a run: the length of generated vectors
+/// and strings, and the magnitude of most generated integers.
+pub const MAX_SIZE: usize = 32;
+
+/// Candidates tried while shrinking, at most.
+pub const MAX_SHRINK_STEPS: usize = 4096;
+
+/// A source of values for a property, and of simpler versions of them.
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    /// A value whose length or magnitude is roughly `size`.
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value;
+
+    /// Simpler values than `value`, most promising first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// Runs `property` against generated values and panics with a minimal
+/// counterexample if it panics on any of them.
+pub fn check<S: Strategy>(strategy: S, property: impl Fn(S::Value)) {
+    let name = std::thread::current().name().unwrap_or("property").to_string();
+    let seed = name.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100_0000_01b3));
+    let mut rng = Rng::new(seed);
+
+    // Every attempt while shrinking panics; only the verdict is reported
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let fails = |value: &S::Value| {
+        panic::catch_unwind(AssertUnwindSafe(|| property(value.clone())))
+            .err()
+            .map(|payload| panic_message(&*payload))
+    };
+
+    let mut failure = None;
+    for case in 0..CASES {
+        let value = strategy.generate(&mut rng, case * MAX_SIZE / (CASES - 1));
+        if let Some(message) = fails(&value) {
+            failure = Some((case, value, message));
+            break;
+        }
+    }
+    let report = failure.map(|(case, original, mut message)| {
+        let mut current = original.clone();
+        let mut steps = 0;
+        let mut shrinks = 0;
+        'shrinking: while steps < MAX_SHRINK_STEPS {
+            for candidate in strategy.shrink(&current) {
+                steps += 1;
+                if let Some(m) = fails(&candidate) {
+                    current = candidate;
+                    message = m;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+                if steps == MAX_SHRINK_STEPS {
+                    break 'shrinking;
+                }
+            }
+            break;
+        }
+        format!(
+            "property failed on case {} of {CASES} (seed {seed:#018x})\n\
+             minimal counterexample: {current:?}\n\
+             shrunk {shrinks} times from: {original:?}\n\
+             {message}",
+            case + 1,
+        )
+    });
+    panic::set_hook(hook);
+    if let Some(report) = report {
+        panic!("{report}");
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "the property panicked".to_string(),
+    }
+}
+
+/// splitmix64: small, fast and good enough for test inputs.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn between(&mut self, lo: i128, hi: i128) -> i128 {
+        let span = (hi - lo) as u128 + 1;
+        lo + ((self.next_u64() as u128) % span) as i128
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+}
+
+/// Integer types [`ints`] can generate.
+pub trait Int: Copy + Debug {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! int {
+    ($($t:ty)*) => {$(
+        impl Int for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+int!(i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+
+/// Integers in `range`. Most are within `size` of zero; the rest are
+/// spread over the whole range, its two ends included. They shrink
+/// towards zero, or towards the end of the range nearest to it.
+pub fn ints<T: Int>(range: RangeInclusive<T>) -> Ints<T> {
+    Ints {
+        lo: range.start().to_i128(),
+        hi: range.end().to_i128(),
+        _type: PhantomData,
+    }
+}
+
+pub struct Ints<T> {
+    lo: i128,
+    hi: i128,
+    _type: PhantomData<T>,
+}
+
+impl<T> Ints<T> {
+    fn target(&self) -> i128 {
+        0.clamp(self.lo, self.hi)
+    }
+}
+
+impl<T: Int> Strategy for Ints<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> T {
+        let value = if rng.one_in(16) {
+            if rng.one_in(2) {
+                self.lo
+            } else {
+                self.hi
+            }
+        } else if rng.one_in(4) {
+            rng.between(self.lo, self.hi)
+        } else {
+            let size = size as i128;
+            let target = self.target();
+            rng.between((target - size).max(self.lo), (target + size).min(self.hi))
+        };
+        T::from_i128(value)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let value = value.to_i128();
+        let mut candidates = Vec::new();
+        let mut distance = value - self.target();
+        while distance != 0 {
+            candidates.push(T::from_i128(value - distance));
+            distance /= 2;
+        }
+        candidates
+    }
+}
+
+/// Vectors of `element`s with a length in `len`. They shrink by dropping
+/// runs of elements, then by shrinking single elements.
+pub fn vecs<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vecs<S> {
+    Vecs { element, len }
+}
+
+pub struct Vecs<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        let (min, max) = (*self.len.start(), *self.len.end());
+        let len = rng.between(min as i128, max.min(min + size) as i128) as usize;
+        (0..len).map(|_| self.element.generate(rng, size)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let min = *self.len.start();
+        let mut candidates = Vec::new();
+        let mut run = value.len().saturating_sub(min);
+        while run > 0 {
+            for start in (0..=value.len() - run).step_by(run) {
+                let mut shorter = value[..start].to_vec();
+                shorter.extend_from_slice(&value[start + run..]);
+                candidates.push(shorter);
+            }
+            run /= 2;
+        }
+        for (i, item) in value.iter().enumerate() {
+            for simpler in self.element.shrink(item) {
+                let mut candidate = value.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Characters of `alphabet`, shrinking towards its first one.
+pub fn chars(alphabet: &str) -> Chars {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    assert!(!alphabet.is_empty(), "an alphabet needs at least one character");
+    Chars { alphabet }
+}
+
+pub struct Chars {
+    alphabet: Vec<char>,
+}
+
+impl Strategy for Chars {
+    type Value = char;
+
+    fn generate(&self, rng: &mut Rng, _size: usize) -> char {
+        self.alphabet[rng.between(0, self.alphabet.len() as i128 - 1) as usize]
+    }
+
+    fn shrink(&self, value: &char) -> Vec<char> {
+        match self.alphabet.iter().position(|c| c == value) {
+            Some(i) if i > 0 => vec![self.alphabet[0]],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Strings of characters from `alphabet` with a length in `len`. A small
+/// alphabet makes repeats, and so interesting inputs, likely.
+pub fn strings(alphabet: &str, len: RangeInclusive<usize>) -> Strings {
+    Strings {
+        chars: vecs(chars(alphabet), len),
+    }
+}
+
+pub struct Strings {
+    chars: Vecs<Chars>,
+}
+
+impl Strategy for Strings {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        self.chars.generate(rng, size).into_iter().collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        self.chars.shrink(&chars).into_iter().map(|c| c.into_iter().collect()).collect()
+    }
+}
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        (self.0.generate(rng, size), self.1.generate(rng, size))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let firsts = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let seconds = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<A: Strategy, B: Strategy, C: Strategy> Strategy for (A, B, C) {
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Self::Value {
+        (self.0.generate(rng, size), self.1.generate(rng, size), self.2.generate(rng, size))
+    }
+
+    fn shrink(&self, (a, b, c): &Self::Value) -> Vec<Self::Value> {
+        let firsts = self.0.shrink(a).into_iter().map(|a| (a, b.clone(), c.clone()));
+        let seconds = self.1.shrink(b).into_iter().map(|b| (a.clone(), b, c.clone()));
+        let thirds = self.2.shrink(c).into_iter().map(|c| (a.clone(), b.clone(), c));
+        firsts.chain(seconds).chain(thirds).collect()
+    }
+}
@@ -0,0 +1,101 @@
+//! The JSON document returned to the server.
+
+use serde::Serialize;
+
+use crate::harness::{Status, TestCase};
+
+/// Grading result for one submission.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    /// Exercise number, e.g. `"138"`, when the caller supplied one.
+    pub exercise: Option<String>,
+    pub compiled: bool,
+    /// rustc output when `compiled` is false.
+    pub compile_error: Option<String>,
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    /// Tests that ran, i.e. everything except ignored ones.
+    pub total: usize,
+    /// `passed / total`, or 0 when nothing ran.
+    pub score: f64,
+    pub tests: Vec<TestCase>,
+}
+
+impl Report {
+    pub fn compile_failure(exercise: Option<String>, error: String) -> Self {
+        Report {
+            exercise,
+            compiled: false,
+            compile_error: Some(error),
+            passed: 0,
+            failed: 0,
+            ignored: 0,
+            total: 0,
+            score: 0.0,
+            tests: Vec::new(),
+        }
+    }
+
+    pub fn from_tests(exercise: Option<String>, tests: Vec<TestCase>) -> Self {
+        let count = |s: Status| tests.iter().filter(|t| t.status == s).count();
+        let passed = count(Status::Passed);
+        let ignored = count(Status::Ignored);
+        let total = tests.len() - ignored;
+        Report {
+            exercise,
+            compiled: true,
+            compile_error: None,
+            passed,
+            failed: total - passed,
+            ignored,
+            total,
+            score: if total == 0 { 0.0 } else { passed as f64 / total as f64 },
+            tests,
+        }
+    }
+
+    /// True when the submission compiled, had tests and all of them passed.
+    pub fn all_passed(&self) -> bool {
+        self.compiled && self.total > 0 && self.passed == self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(name: &str, status: Status) -> TestCase {
+        TestCase {
+            name: name.to_string(),
+            status,
+            panic_message: None,
+            duration_ms: 1,
+        }
+    }
+
+    #[test]
+    fn score_excludes_ignored_tests() {
+        let report = Report::from_tests(
+            Some("138".into()),
+            vec![
+                case("a", Status::Passed),
+                case("b", Status::Failed),
+                case("c", Status::TimedOut),
+                case("d", Status::Ignored),
+                case("e", Status::Passed),
+            ],
+        );
+        assert_eq!((report.passed, report.failed, report.ignored), (2, 2, 1));
+        assert_eq!(report.total, 4);
+        assert!((report.score - 0.5).abs() < f64::EPSILON);
+        assert!(!report.all_passed());
+    }
+
+    #[test]
+    fn no_tests_is_not_a_pass() {
+        let report = Report::from_tests(None, Vec::new());
+        assert_eq!(report.score, 0.0);
+        assert!(!report.all_passed());
+    }
+}
@@ -0,0 +1,444 @@
+//! Rebuilding a submission around a trusted test module.
+//!
+//! Learners edit the same file that carries the template's tests, so the
+//! tests in a submission cannot be trusted. These helpers find every
+//! `#[cfg(test)] mod name { ... }` item, strip it from the submission and
+//! append the template's copy instead.
+
+use std::ops::Range;
+
+const TEST_ATTR: &str = "#[cfg(test)]";
+
+/// Module name given to an exercise's hidden `tests.rs` when it is appended.
+/// Test names under it start with `hidden_tests::`.
+pub const HIDDEN_MODULE: &str = "hidden_tests";
+
+/// Module name given to a learner's generated cases (tools/variant) when
+/// they are appended. Test names under it start with `variant_tests::`.
+pub const VARIANT_MODULE: &str = "variant_tests";
+
+/// Module name given to an exercise's `properties.rs` when it is appended
+/// (see [`crate::property`]). Test names under it start with `property_tests::`.
+pub const PROPERTY_MODULE: &str = "property_tests";
+
+/// Byte ranges of every `#[cfg(test)] mod name { ... }` item in `source`,
+/// attribute included.
+pub fn test_modules(source: &str) -> Vec<Range<usize>> {
+    let mut found = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = source[search..].find(TEST_ATTR) {
+        let start = search + offset;
+        search = start + TEST_ATTR.len();
+        if !at_line_start(source, start) || in_comment_or_string(source, start) {
+            continue;
+        }
+        let rest = source[search..].trim_start();
+        let Some(after_mod) = rest.strip_prefix("mod ").or_else(|| rest.strip_prefix("pub mod ")) else {
+            continue;
+        };
+        let Some(open) = after_mod.find(['{', ';']) else {
+            continue;
+        };
+        if after_mod.as_bytes()[open] == b';' {
+            continue;
+        }
+        let open = source.len() - after_mod.len() + open;
+        if let Some(close) = matching_brace(source, open) {
+            found.push(start..close + 1);
+            search = close + 1;
+        }
+    }
+    found
+}
+
+/// `source` with all of its test modules removed.
+pub fn strip_tests(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut last = 0;
+    for range in test_modules(source) {
+        out.push_str(&source[last..range.start]);
+        last = range.end;
+    }
+    out.push_str(&source[last..]);
+    out
+}
+
+/// Replaces the test modules of `submission` with those of `template`.
+pub fn with_tests_from(submission: &str, template: &str) -> String {
+    let mut out = strip_tests(submission);
+    for range in test_modules(template) {
+        out.push_str("\n\n");
+        out.push_str(&template[range]);
+    }
+    out.push('\n');
+    out
+}
+
+/// Appends `body` to `source` as `#[cfg(test)] mod <name> { body }`.
+pub fn append_test_module(source: &str, name: &str, body: &str) -> String {
+    format!("{source}\n\n{TEST_ATTR}\nmod {name} {{\n{body}\n}}\n")
+}
+
+/// Byte ranges of every free function `fn <name>` written at the start of
+/// a line, `pub` or not, from its first keyword to its closing brace.
+pub fn fn_items(source: &str, name: &str) -> Vec<Range<usize>> {
+    let needle = format!("fn {name}");
+    let mut found = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = source[search..].find(&needle) {
+        let start = search + offset;
+        search = start + needle.len();
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let before = source[line_start..start].trim();
+        let item_start = start - source[line_start..start].trim_start().len();
+        let ends_name = source[search..].trim_start().starts_with(['(', '<']);
+        if !matches!(before, "" | "pub" | "pub(crate)") || !ends_name || in_comment_or_string(source, start) {
+            continue;
+        }
+        let Some(open) = source[search..].find(['{', ';']).map(|i| search + i) else {
+            continue;
+        };
+        if source.as_bytes()[open] == b';' {
+            continue;
+        }
+        if let Some(close) = matching_brace(source, open) {
+            found.push(item_start..close + 1);
+            search = close + 1;
+        }
+    }
+    found
+}
+
+/// `source` without its `fn <name>` items, e.g. without `main` so that
+/// its functions can be compiled into another program.
+pub fn strip_fn(source: &str, name: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut last = 0;
+    for range in fn_items(source, name) {
+        out.push_str(&source[last..range.start]);
+        last = range.end;
+    }
+    out.push_str(&source[last..]);
+    out
+}
+
+/// Byte ranges of the `{ ... }` body of every `#[test]` function, braces
+/// included. Other attributes may sit between `#[test]` and the `fn`.
+pub fn test_fn_bodies(source: &str) -> Vec<Range<usize>> {
+    let code = code_only(source);
+    let mut found = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = code[search..].find("#[test]") {
+        let start = search + offset;
+        search = start + "#[test]".len();
+        if !at_line_start(source, start) {
+            continue;
+        }
+        let Some(fn_at) = code[search..].find("fn ").map(|i| search + i) else {
+            continue;
+        };
+        let Some(open) = code[fn_at..].find(['{', ';']).map(|i| fn_at + i) else {
+            continue;
+        };
+        if code.as_bytes()[open] == b';' {
+            continue;
+        }
+        if let Some(close) = matching_brace(source, open) {
+            found.push(open..close + 1);
+            search = close + 1;
+        }
+    }
+    found
+}
+
+/// `source` with comments and string and char literals blanked out. Line
+/// breaks and byte offsets are kept, so positions still point at the
+/// original; only real code can match a search.
+pub fn code_only(source: &str) -> String {
+    let mut code = vec![b' '; source.len()];
+    for (i, &b) in source.as_bytes().iter().enumerate() {
+        if b == b'\n' {
+            code[i] = b'\n';
+        }
+    }
+    let mut scanner = Scanner::new(source);
+    while let Some(i) = scanner.next_code_byte() {
+        code[i] = source.as_bytes()[i];
+    }
+    // Only whole multi-byte characters of code are copied, and comments or
+    // literals become ASCII spaces, so the bytes stay valid UTF-8.
+    String::from_utf8(code).expect("code bytes are copied whole")
+}
+
+fn at_line_start(source: &str, pos: usize) -> bool {
+    source[..pos]
+        .rsplit('\n')
+        .next()
+        .is_some_and(|line| line.trim().is_empty())
+}
+
+/// True if `pos` lies inside a comment or string literal.
+fn in_comment_or_string(source: &str, pos: usize) -> bool {
+    let mut scanner = Scanner::new(source);
+    while let Some(i) = scanner.next_code_byte() {
+        if i >= pos {
+            return i > pos;
+        }
+    }
+    true
+}
+
+/// Index of the `}` closing the `{` at `open`, skipping comments and literals.
+fn matching_brace(source: &str, open: usize) -> Option<usize> {
+    let mut scanner = Scanner::new(source);
+    scanner.pos = open;
+    let mut depth = 0usize;
+    while let Some(i) = scanner.next_code_byte() {
+        match source.as_bytes()[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Walks Rust source yielding the positions of bytes that are real code,
+/// i.e. not inside comments, string, byte-string or char literals.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(source: &'a str) -> Self {
+        Scanner {
+            bytes: source.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self, ahead: usize) -> Option<u8> {
+        self.bytes.get(self.pos + ahead).copied()
+    }
+
+    fn next_code_byte(&mut self) -> Option<usize> {
+        loop {
+            let b = self.peek(0)?;
+            match (b, self.peek(1)) {
+                (b'/', Some(b'/')) => self.skip_line(),
+                (b'/', Some(b'*')) => self.skip_block_comment(),
+                (b'"', _) => self.skip_string(),
+                (b'r', Some(b'"' | b'#')) if self.starts_raw_string() => self.skip_raw_string(),
+                (b'\'', _) if self.starts_char_literal() => self.skip_char_literal(),
+                _ => {
+                    self.pos += 1;
+                    return Some(self.pos - 1);
+                }
+            }
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(b) = self.peek(0) {
+            self.pos += 1;
+            if b == b'\n' {
+                break;
+            }
+        }
+    }
+
+    fn skip_block_comment(&mut self) {
+        self.pos += 2;
+        let mut depth = 1;
+        while depth > 0 {
+            match (self.peek(0), self.peek(1)) {
+                (None, _) => return,
+                (Some(b'/'), Some(b'*')) => {
+                    depth += 1;
+                    self.pos += 2;
+                }
+                (Some(b'*'), Some(b'/')) => {
+                    depth -= 1;
+                    self.pos += 2;
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_string(&mut self) {
+        self.pos += 1;
+        while let Some(b) = self.peek(0) {
+            self.pos += 1;
+            match b {
+                b'\\' => self.pos += 1,
+                b'"' => return,
+                _ => {}
+            }
+        }
+    }
+
+    fn starts_raw_string(&self) -> bool {
+        // `r"`, `r#..#"` or `br"`, but not an identifier ending in r.
+        let before = &self.bytes[..self.pos];
+        let prev_is_ident = match before {
+            [.., p, b'b'] => is_ident_byte(*p),
+            [.., b'b'] => false,
+            [.., p] => is_ident_byte(*p),
+            [] => false,
+        };
+        let hashes = self.bytes[self.pos + 1..].iter().take_while(|&&b| b == b'#').count();
+        !prev_is_ident && self.peek(1 + hashes) == Some(b'"')
+    }
+
+    fn skip_raw_string(&mut self) {
+        self.pos += 1;
+        let hashes = self.bytes[self.pos..].iter().take_while(|&&b| b == b'#').count();
+        self.pos += hashes + 1;
+        while let Some(b) = self.peek(0) {
+            self.pos += 1;
+            let closing = self.bytes.get(self.pos..self.pos + hashes);
+            if b == b'"' && closing.is_some_and(|h| h.iter().all(|&c| c == b'#')) {
+                self.pos += hashes;
+                return;
+            }
+        }
+    }
+
+    /// Distinguishes `'x'` / `'\n'` from lifetimes like `'a`.
+    fn starts_char_literal(&self) -> bool {
+        match self.peek(1) {
+            Some(b'\\') => true,
+            Some(_) => {
+                // The char may be multi-byte UTF-8; find where it ends.
+                let rest = &self.bytes[self.pos + 1..];
+                let len = utf8_len(rest[0]);
+                rest.get(len) == Some(&b'\'')
+            }
+            None => false,
+        }
+    }
+
+    fn skip_char_literal(&mut self) {
+        self.pos += 1;
+        while let Some(b) = self.peek(0) {
+            self.pos += 1;
+            match b {
+                b'\\' => self.pos += 1,
+                b'\'' => return,
+                _ => {}
+            }
+        }
+    }
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0xF0..=0xFF => 4,
+        0xE0..=0xEF => 3,
+        0xC0..=0xDF => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = r#"fn add(a: i32, b: i32) -> i32 { todo!() }
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn braces_in_literals() {
+        let s = "}}}";
+        let c = '}';
+        // }
+        assert_eq!(add(1, 2), 3, "{s}{c}");
+    }
+}
+
+// Not a test: #[cfg(test)] mod fake { }
+pub mod after {
+    pub fn keep<'a>(s: &'a str) -> &'a str { s }
+}
+"#;
+
+    #[test]
+    fn finds_balanced_test_module() {
+        let ranges = test_modules(TEMPLATE);
+        assert_eq!(ranges.len(), 1);
+        let module = &TEMPLATE[ranges[0].clone()];
+        assert!(module.starts_with("#[cfg(test)]\nmod tests {"));
+        assert!(module.ends_with("\"{s}{c}\");\n    }\n}"));
+    }
+
+    #[test]
+    fn strip_keeps_code_after_tests() {
+        let stripped = strip_tests(TEMPLATE);
+        assert!(!stripped.contains("braces_in_literals"));
+        assert!(stripped.contains("pub mod after"));
+    }
+
+    #[test]
+    fn learner_tests_are_replaced() {
+        let submission = "fn add(a: i32, b: i32) -> i32 { a + b }\nfn main() {}\n\
+                          #[cfg(test)]\nmod tests {\n    #[test]\n    fn trivial() { assert!(true); }\n}\n";
+        let graded = with_tests_from(submission, TEMPLATE);
+        assert!(!graded.contains("trivial"));
+        assert!(graded.contains("braces_in_literals"));
+        assert!(graded.contains("a + b"));
+    }
+
+    #[test]
+    fn nested_test_modules_come_out_whole() {
+        let src = "#[cfg(test)]\nmod a {\n    mod b { fn f() { let _ = r#\"}\"#; } }\n}\nfn main() {}\n";
+        assert_eq!(strip_tests(src), "\nfn main() {}\n");
+    }
+
+    #[test]
+    fn main_is_stripped_but_not_lookalikes() {
+        let src = "fn main() {\n    let s = \"}\";\n}\nfn main_menu() {}\n// fn main() {}\npub fn main<T>() { {} }\n";
+        assert_eq!(fn_items(src, "main").len(), 2);
+        assert_eq!(strip_fn(src, "main"), "\nfn main_menu() {}\n// fn main() {}\n\n");
+    }
+
+    #[test]
+    fn test_bodies_are_found_past_other_attributes() {
+        let src = "#[test]\n#[should_panic]\nfn a() -> Result<(), String> { if true { return Ok(()) } Ok(()) }\n\
+                   // #[test]\nfn helper() {}\n    #[test]\n    fn b() { let s = \"}\"; }\n";
+        let bodies: Vec<&str> = test_fn_bodies(src).into_iter().map(|r| &src[r]).collect();
+        assert_eq!(bodies, ["{ if true { return Ok(()) } Ok(()) }", "{ let s = \"}\"; }"]);
+    }
+
+    #[test]
+    fn code_only_blanks_comments_and_literals() {
+        let src = "let v = x.sort(); // .sort()\nlet s = \".sort() é\"; /* sort */ y.sort_by(f);\n";
+        let code = code_only(src);
+        assert_eq!(code.len(), src.len());
+        assert_eq!(code.matches(".sort").count(), 2);
+        assert_eq!(code.lines().count(), 2);
+        assert!(!code.contains('é'));
+    }
+
+    #[test]
+    fn appended_module_is_found_again() {
+        let src = append_test_module("fn main() {}", HIDDEN_MODULE, "    #[test]\n    fn t() {}");
+        assert_eq!(test_modules(&src).len(), 1);
+        assert!(src.contains("mod hidden_tests {"));
+    }
+}
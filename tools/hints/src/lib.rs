@@ -0,0 +1,390 @@
+//! Hints that unlock one tier at a time.
+//!
+//! A template gives the hints for a function in a block of line comments
+//! headed ``HINTS `name` ``, one numbered tier per line; lines indented past
+//! the number continue the tier:
+//!
+//! ```text
+//! // HINTS `parse_number`
+//! // 1: `str::parse` already returns a `Result`; only its error is wrong.
+//! // 2 after 4: The syntax is:
+//! //      input.parse::<i32>().map_err(|_| ...)
+//! ```
+//!
+//! A function with several steps can have a block next to each; its tiers
+//! are numbered on from one block to the next.
+//!
+//! Tier N unlocks after one more failed attempt than the tier before it,
+//! unless it says otherwise with `after K`; no tier unlocks before the one
+//! above it. The blocks are stripped from the copy of the template that
+//! learners work on, so they see a tier only once they ask for it.
+//!
+//! Free-form notes, `// HINT: ...` and the banner's `💡 HINTS:` section,
+//! are read by [`notes`].
+
+pub mod notes;
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// One tier of a function's hints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tier {
+    /// The function the hints are for.
+    pub function: String,
+    /// 1-based, counting on across the function's blocks.
+    pub number: u32,
+    /// How many failed attempts unlock it.
+    pub after: u32,
+    /// 1-based line of the tier in the template.
+    pub line: usize,
+    /// The tier with comment markers removed; one entry per line.
+    pub text: Vec<String>,
+}
+
+impl Tier {
+    /// `function/number`, the name a tier is recorded under once shown.
+    pub fn id(&self) -> String {
+        format!("{}/{}", self.function, self.number)
+    }
+}
+
+/// A template split into its hint tiers and everything else.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Hints {
+    /// Every tier, in file order.
+    pub tiers: Vec<Tier>,
+    /// The template without its hint blocks.
+    pub template: String,
+}
+
+impl Hints {
+    /// The tiers that `failed` attempts have unlocked, in file order.
+    pub fn unlocked(&self, failed: u32) -> impl Iterator<Item = &Tier> {
+        self.tiers.iter().filter(move |tier| tier.after <= failed)
+    }
+
+    /// How many failed attempts unlock the next tier, if any is locked.
+    pub fn next_unlock(&self, failed: u32) -> Option<u32> {
+        self.tiers
+            .iter()
+            .map(|tier| tier.after)
+            .filter(|&after| after > failed)
+            .min()
+    }
+}
+
+/// A malformed hint block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Splits `source` into its hint tiers and the template without them.
+/// Everything outside the blocks is kept byte for byte.
+pub fn parse(source: &str) -> Result<Hints, ParseError> {
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+    let error = |line: usize, message: String| Err(ParseError { line, message });
+
+    let mut tiers: Vec<Tier> = Vec::new();
+    let mut template = String::with_capacity(source.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(function) = comment(lines[i]).and_then(header) else {
+            template.push_str(lines[i]);
+            i += 1;
+            continue;
+        };
+        let header_line = i + 1;
+        if function.is_empty() || !function.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return error(header_line, format!("`{function}` is not a function name"));
+        }
+        if !defines(source, function) {
+            return error(header_line, format!("there is no function `{function}`"));
+        }
+        i += 1;
+
+        // Numbering carries on from the function's earlier blocks
+        let earlier: Vec<&Tier> = tiers.iter().filter(|t| t.function == function).collect();
+        let first = earlier.len() as u32 + 1;
+        let mut number = earlier.len() as u32;
+        let mut previous = earlier.last().map_or(0, |tier| tier.after);
+        while let Some(line) = lines.get(i).and_then(|l| comment(l)).and_then(tier_line) {
+            number += 1;
+            if line.number != number {
+                return error(
+                    i + 1,
+                    format!("expected tier {number}, found {}", line.number),
+                );
+            }
+            let after = match line.after {
+                Some(after) if after < previous => {
+                    return error(
+                        i + 1,
+                        format!("tier {number} unlocks before tier {}", number - 1),
+                    );
+                }
+                Some(after) => after,
+                None => previous + 1,
+            };
+            let tier_line = i + 1;
+            i += 1;
+
+            let mut body = Vec::new();
+            while let Some(text) = lines.get(i).and_then(|l| comment(l)) {
+                if text.trim().is_empty() || indent(text) <= line.column {
+                    break;
+                }
+                body.push(text);
+                i += 1;
+            }
+            let mut text = Vec::new();
+            if !line.text.is_empty() {
+                text.push(line.text.to_string());
+            }
+            text.extend(dedent(&body));
+            tiers.push(Tier {
+                function: function.to_string(),
+                number,
+                after,
+                line: tier_line,
+                text,
+            });
+            previous = after;
+        }
+        if number < first {
+            return error(
+                header_line,
+                format!("the hints for `{function}` have no tiers"),
+            );
+        }
+    }
+    Ok(Hints { tiers, template })
+}
+
+/// The name in a ``HINTS `name` `` line.
+fn header(comment: &str) -> Option<&str> {
+    comment.trim().strip_prefix("HINTS `")?.strip_suffix('`')
+}
+
+struct TierLine<'a> {
+    /// Column of the number within the comment text.
+    column: usize,
+    number: u32,
+    after: Option<u32>,
+    /// Whatever follows the colon.
+    text: &'a str,
+}
+
+/// Recognises `N: text` and `N after K: text`.
+fn tier_line(comment: &str) -> Option<TierLine<'_>> {
+    let column = indent(comment);
+    let (number, rest) = leading_number(comment.trim_start())?;
+    let (after, rest) = match rest.strip_prefix(" after ") {
+        Some(rest) => {
+            let (after, rest) = leading_number(rest)?;
+            (Some(after), rest)
+        }
+        None => (None, rest),
+    };
+    Some(TierLine {
+        column,
+        number,
+        after,
+        text: rest.strip_prefix(':')?.trim(),
+    })
+}
+
+fn leading_number(text: &str) -> Option<(u32, &str)> {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    Some((text[..end].parse().ok()?, &text[end..]))
+}
+
+/// Whether `source` has a `fn name(` or `fn name<`.
+fn defines(source: &str, name: &str) -> bool {
+    let needle = format!("fn {name}");
+    source
+        .match_indices(&needle)
+        .any(|(at, _)| source[at + needle.len()..].starts_with(['(', '<']))
+}
+
+/// The text of a plain `//` line comment; doc comments hold no hints.
+fn comment(line: &str) -> Option<&str> {
+    let rest = line
+        .trim_end_matches(['\n', '\r'])
+        .trim_start()
+        .strip_prefix("//")?;
+    (!rest.starts_with(['/', '!'])).then_some(rest)
+}
+
+fn indent(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+/// Strips the indentation the lines have in common, keeping the rest.
+fn dedent(lines: &[&str]) -> Vec<String> {
+    let common = lines.iter().map(|l| indent(l)).min().unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l[common..].trim_end().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn tiers(hints: &Hints) -> Vec<(String, u32, usize, Vec<&str>)> {
+        hints
+            .tiers
+            .iter()
+            .map(|t| {
+                (
+                    t.id(),
+                    t.after,
+                    t.line,
+                    t.text.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_tiers_from_the_template() {
+        let source = "\
+fn parse_number(input: &str) -> Result<i32, MathError> {
+    // TODO: parse it
+    // HINTS `parse_number`
+    // 1: `str::parse` returns a `Result` already.
+    // 2 after 4: The syntax is:
+    //      input.parse::<i32>()
+    //          .map_err(|_| ...)
+    // 3: Nothing more.
+    //
+    // Not a hint.
+    todo!()
+}
+
+// HINTS `main`
+// 1 after 0: Call it.
+fn main() {
+    // HINTS `main`
+    // 2: Then print it.
+}
+";
+        let hints = parse(source).unwrap();
+        assert_eq!(
+            tiers(&hints),
+            [
+                (
+                    "parse_number/1".to_string(),
+                    1,
+                    4,
+                    vec!["`str::parse` returns a `Result` already."]
+                ),
+                (
+                    "parse_number/2".to_string(),
+                    4,
+                    5,
+                    vec![
+                        "The syntax is:",
+                        "input.parse::<i32>()",
+                        "    .map_err(|_| ...)"
+                    ]
+                ),
+                ("parse_number/3".to_string(), 5, 8, vec!["Nothing more."]),
+                ("main/1".to_string(), 0, 15, vec!["Call it."]),
+                ("main/2".to_string(), 1, 18, vec!["Then print it."]),
+            ]
+        );
+        assert_eq!(
+            hints.template,
+            "\
+fn parse_number(input: &str) -> Result<i32, MathError> {
+    // TODO: parse it
+    //
+    // Not a hint.
+    todo!()
+}
+
+fn main() {
+}
+"
+        );
+
+        assert_eq!(
+            hints.unlocked(0).map(Tier::id).collect::<Vec<_>>(),
+            ["main/1"]
+        );
+        assert_eq!(hints.unlocked(4).count(), 4);
+        assert_eq!(hints.next_unlock(1), Some(4));
+        assert_eq!(hints.next_unlock(5), None);
+    }
+
+    #[test]
+    fn rejects_malformed_blocks() {
+        let message = |source: &str| parse(source).unwrap_err().to_string();
+        assert_eq!(
+            message("// HINTS `f`\n// 2: No.\nfn f() {}\n"),
+            "line 2: expected tier 1, found 2"
+        );
+        assert_eq!(
+            message("// HINTS `f`\n// 1 after 3: A.\n// 2 after 2: B.\nfn f() {}\n"),
+            "line 3: tier 2 unlocks before tier 1"
+        );
+        assert_eq!(
+            message("// HINTS `f`\n// Just text.\nfn f() {}\n"),
+            "line 1: the hints for `f` have no tiers"
+        );
+        assert_eq!(
+            message("// HINTS `g`\n// 1: A.\nfn f() {}\n"),
+            "line 1: there is no function `g`"
+        );
+        assert_eq!(
+            message("// HINTS `f()`\n// 1: A.\nfn f() {}\n"),
+            "line 1: `f()` is not a function name"
+        );
+        assert_eq!(
+            message("// HINTS `f`\n// 1: A.\n// HINTS `f`\n// 1: B.\nfn f() {}\n"),
+            "line 4: expected tier 2, found 1"
+        );
+    }
+
+    #[test]
+    fn repository_templates_have_well_formed_hints() {
+        let problems = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../problems");
+        let mut with_hints = 0;
+        for entry in std::fs::read_dir(&problems).unwrap() {
+            let path = entry.unwrap().path().join("template.rs");
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let hints = parse(&source).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            assert!(!hints.template.contains("HINTS `"), "{}", path.display());
+            with_hints += usize::from(!hints.tiers.is_empty());
+        }
+        assert!(with_hints > 100, "{with_hints}");
+
+        let source = std::fs::read_to_string(problems.join("001_exercise/template.rs")).unwrap();
+        let hello = parse(&source).unwrap();
+        assert_eq!(
+            hello.tiers[0].text,
+            ["The syntax is:", "println!(\"Your text here\");"]
+        );
+        assert!(!hello.template.contains("println!(\"Your text here\")"));
+    }
+}
@@ -0,0 +1,69 @@
+//! `hints` -- split a template into its hint tiers and the copy learners
+//! get, and print both as JSON.
+//!
+//! Usage: hints [--failures N] [FILE | -]
+//!
+//! The template is read from FILE, or from stdin when it is `-` or left
+//! out. The report is `{"tiers": [...], "template": "..."}`, the template
+//! being the input without its hint blocks. With `--failures N` only the
+//! tiers that N failed attempts unlock are listed.
+//!
+//! Exit status is 0 on success and 2 on usage errors or a malformed hint
+//! block.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: hints [--failures N] [FILE | -]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("hints: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut failures = None;
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--failures" => {
+                let value = args.next().ok_or("--failures needs a value")?;
+                let n = value
+                    .parse::<u32>()
+                    .map_err(|_| format!("`{value}` is not a number of failures"))?;
+                failures = Some(n);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+    let source = match input.as_deref() {
+        None | Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let mut hints = hints::parse(&source).map_err(|e| e.to_string())?;
+    if let Some(failed) = failures {
+        hints.tiers.retain(|tier| tier.after <= failed);
+    }
+    let json = serde_json::to_string_pretty(&hints).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(())
+}
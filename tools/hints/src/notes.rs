@@ -0,0 +1,256 @@
+//! Pulling the HINT and BONUS notes out of a template's comments.
+//!
+//! Besides the tiered blocks read by [`parse`](crate::parse), templates
+//! give hints in two shapes, all shown at once: a section of the header
+//! banner (`// 💡 HINTS:` followed by indented bullets) and inline markers
+//! next to the code (`// HINT: ...`, `/// Hint: ...`, `// TODO: Bonus - ...`).
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Hint,
+    Bonus,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Kind::Hint => "HINT",
+            Kind::Bonus => "BONUS",
+        })
+    }
+}
+
+/// One hint block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+    /// 1-based line of the marker in the template.
+    pub line: usize,
+    pub kind: Kind,
+    /// The hint with comment markers removed; one entry per line.
+    pub text: Vec<String>,
+}
+
+/// Every hint block in `source`, in file order.
+pub fn extract(source: &str) -> Vec<Hint> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut hints = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(marker) = comment(lines[i]).and_then(parse_marker) else {
+            i += 1;
+            continue;
+        };
+        let mut text = Vec::new();
+        if !marker.text.is_empty() {
+            text.push(marker.text.to_string());
+        }
+        // A marker with nothing after it, or ending in a colon, introduces
+        // a block running to the next blank comment line. Otherwise only
+        // lines that visibly continue the sentence belong to it.
+        let block = marker.text.is_empty() || marker.text.ends_with(':');
+        let mut body = Vec::new();
+        let mut j = i + 1;
+        while let Some(next) = lines.get(j).and_then(|l| comment(l)) {
+            let trimmed = next.trim();
+            if trimmed.is_empty() || is_rule(trimmed) || parse_marker(next).is_some() {
+                break;
+            }
+            let continues = block
+                || indent(lines[j]) > marker.column
+                || (!ends_sentence(body.last().map_or(marker.text, |l: &&str| l.trim()))
+                    && trimmed.starts_with(char::is_lowercase));
+            if !continues {
+                break;
+            }
+            body.push(next);
+            j += 1;
+        }
+        text.extend(dedent(&body));
+        hints.push(Hint {
+            line: i + 1,
+            kind: marker.kind,
+            text,
+        });
+        i = j;
+    }
+    hints
+}
+
+struct Marker<'a> {
+    kind: Kind,
+    /// Whatever follows the label on the marker line.
+    text: &'a str,
+    /// Column of the label within the comment text.
+    column: usize,
+}
+
+/// Recognises `HINT:`, `Hints:`, `BONUS CHALLENGE:`, `Bonus -` and the
+/// like, after optional leading symbols (emoji, nested `//`) and `TODO:`.
+fn parse_marker(comment: &str) -> Option<Marker<'_>> {
+    let start = comment.find(|c: char| c.is_alphanumeric())?;
+    let mut rest = &comment[start..];
+    if let Some(after) = rest.strip_prefix("TODO:") {
+        rest = after.trim_start();
+    }
+    let column = comment.len() - rest.len();
+
+    // The label is "Hint", "Hints" or "Bonus" in any case, optionally
+    // followed by up to two more words in capitals ("BONUS CHALLENGE").
+    let label_end = rest.find(|c: char| !(c.is_alphabetic() || c == ' '))?;
+    let label = rest[..label_end].trim_end();
+    let mut words = label.split(' ');
+    let kind = match words.next()?.to_ascii_lowercase().as_str() {
+        "hint" | "hints" => Kind::Hint,
+        "bonus" => Kind::Bonus,
+        _ => return None,
+    };
+    let extra: Vec<&str> = words.collect();
+    if extra.len() > 2 || !extra.iter().all(|w| !w.is_empty() && w.chars().all(char::is_uppercase)) {
+        return None;
+    }
+
+    let after = &rest[label.len()..];
+    let text = after
+        .strip_prefix(':')
+        .or_else(|| after.strip_prefix(" -"))
+        .or_else(|| after.strip_prefix('!'))?;
+    Some(Marker {
+        kind,
+        text: text.trim(),
+        column,
+    })
+}
+
+/// The text of a line comment, without its `//`, `///` or `//!`.
+fn comment(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix("//")?;
+    Some(rest.strip_prefix(['/', '!']).unwrap_or(rest))
+}
+
+/// Column of the first non-space character of a comment's text.
+fn indent(line: &str) -> usize {
+    let text = comment(line).unwrap_or(line);
+    text.len() - text.trim_start().len()
+}
+
+/// Banner separators such as `═══════` or `-------`.
+fn is_rule(text: &str) -> bool {
+    let mut chars = text.chars();
+    let first = chars.next().filter(|c| !c.is_alphanumeric());
+    first.is_some() && text.chars().count() >= 3 && chars.all(|c| Some(c) == first)
+}
+
+fn ends_sentence(text: &str) -> bool {
+    text.ends_with(['.', '!', '?', ';', ')'])
+}
+
+/// Strips the indentation the lines have in common, keeping the rest.
+fn dedent(lines: &[&str]) -> Vec<String> {
+    let common = lines
+        .iter()
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines.iter().map(|l| l[common..].trim_end().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn texts(source: &str) -> Vec<(usize, Kind, Vec<String>)> {
+        extract(source)
+            .into_iter()
+            .map(|h| (h.line, h.kind, h.text))
+            .collect()
+    }
+
+    fn strings(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn reads_banner_sections() {
+        let source = "\
+// 🎯 YOUR TASK:
+//    Implement it.
+//
+// 💡 HINTS:
+//    • Think about edge cases
+//    • Use appropriate Rust patterns
+//
+// ✨ BONUS CHALLENGE:
+//    Try it recursively.
+// ═══════════════
+fn main() {}
+";
+        assert_eq!(
+            texts(source),
+            [
+                (4, Kind::Hint, strings(&["• Think about edge cases", "• Use appropriate Rust patterns"])),
+                (8, Kind::Bonus, strings(&["Try it recursively."])),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_inline_markers() {
+        let source = "\
+fn main() {
+    // HINT: The syntax is:
+    //   println!(\"text\");
+    //
+    // Make sure to use a semicolon.
+    // Hint: keep the
+    // counter in a variable.
+    // Next comment.
+    // TODO: Bonus - implement it recursively
+    // Base case: nothing left
+}
+/// Hint: Use match
+fn f() {}
+// HINT: First.
+// HINT: Second.
+// TODO: check if even (hint: n % 2 == 0)
+// Hints are below: nothing
+";
+        assert_eq!(
+            texts(source),
+            [
+                (2, Kind::Hint, strings(&["The syntax is:", "println!(\"text\");"])),
+                (6, Kind::Hint, strings(&["keep the", "counter in a variable."])),
+                (9, Kind::Bonus, strings(&["implement it recursively"])),
+                (12, Kind::Hint, strings(&["Use match"])),
+                (14, Kind::Hint, strings(&["First."])),
+                (15, Kind::Hint, strings(&["Second."])),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_code_samples_after_an_empty_marker() {
+        let source = "    // Hint:\n    // if condition {\n    //     // code\n    // }\n    let x = 1;\n";
+        assert_eq!(
+            texts(source),
+            [(1, Kind::Hint, strings(&["if condition {", "    // code", "}"]))]
+        );
+    }
+
+    #[test]
+    fn finds_hints_in_repository_templates() {
+        let problems = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../problems");
+        let read = |n: &str| std::fs::read_to_string(problems.join(n).join("template.rs")).unwrap();
+
+        let hello = extract(&read("001_exercise"));
+        // Its syntax hint is now a tiered block, which is not a note
+        assert_eq!(hello.iter().map(|h| h.kind).collect::<Vec<_>>(), [Kind::Bonus]);
+        assert_eq!(hello[0].line, 18);
+
+        let smart_pointer = extract(&read("152_exercise"));
+        assert_eq!(smart_pointer.len(), 1);
+        assert_eq!(smart_pointer[0].text.len(), 3);
+    }
+}
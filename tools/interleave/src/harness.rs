@@ -0,0 +1,330 @@
+//! The program that model-checks a submission.
+//!
+//! The submission is compiled together with [`RUNTIME`] as
+//! `mod interleave`, with every `std::` path in its code pointed at
+//! `crate::interleave::std`. That module re-exports the standard library
+//! with `sync` and `thread` replaced by the shims, so the learner's own
+//! `Mutex`, `mpsc` and `thread::spawn` calls go through the scheduler. A
+//! driver then runs each scenario under [`explore`](crate::runtime::explore)
+//! and writes the summaries to a report file; stdout is left to the
+//! learner's `println!`s, which run once per execution.
+
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+use grader::compile::{compile_binary, CompileError};
+use grader::process::run_with_timeout;
+use grader::splice::{code_only, strip_fn, strip_tests};
+use grader::ScratchDir;
+
+use crate::runtime::Config;
+use crate::scenarios::Scenario;
+
+/// The runtime, compiled into every harness.
+pub const RUNTIME: &str = include_str!("runtime.rs");
+
+/// Where the submission's `std` paths are sent.
+const SHIM: &str = "crate::interleave::std";
+
+/// Kept of the harness's own output, which is only the learner's prints.
+const MAX_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// `source` with every path through `std` or `core` (or `::std`,
+/// `::core`) rewritten to start at [`SHIM`], so `core::sync::atomic` gets
+/// the shimmed atomics too. Comments and string literals are left alone.
+pub fn redirect_std(source: &str) -> String {
+    let code = code_only(source);
+    let bytes = code.as_bytes();
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let next_crate = |from: usize| {
+        ["std", "core"]
+            .iter()
+            .filter_map(|name| Some((from + code[from..].find(name)?, name.len())))
+            .min()
+    };
+
+    let mut out = String::with_capacity(source.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while let Some((start, len)) = next_crate(i) {
+        let end = start + len;
+        i = end;
+        if (start > 0 && is_ident(bytes[start - 1])) || bytes.get(end).is_some_and(|&b| is_ident(b))
+        {
+            continue;
+        }
+        if !code[end..].trim_start().starts_with("::") {
+            continue;
+        }
+        // `::std` loses its leading `::`; `a::std` and `x.std` are not the crate
+        let before = code[..start].trim_end();
+        let from = match before.strip_suffix("::") {
+            Some(rest) if follows_path(rest) => continue,
+            Some(rest) => rest.len(),
+            None if before.ends_with('.') => continue,
+            None => start,
+        };
+        out.push_str(&source[copied..from]);
+        out.push_str(SHIM);
+        copied = end;
+    }
+    out.push_str(&source[copied..]);
+    out
+}
+
+/// Whether `::` after `before` continues a path, as in `a::std`, rather
+/// than opening one, as in `as ::std` or `(::std`.
+fn follows_path(before: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "as", "dyn", "else", "for", "impl", "in", "let", "mut", "return", "where",
+    ];
+    let before = before.trim_end();
+    let word = &before[before
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map_or(0, |i| i + 1)..];
+    !word.is_empty() && !KEYWORDS.contains(&word)
+}
+
+/// The harness program for `scenarios`: the redirected submission without
+/// its `main` and tests, the runtime, and a driver taking the report path
+/// as its only argument.
+pub fn source(submission: &str, scenarios: &[Scenario], config: &Config) -> String {
+    let body = redirect_std(&strip_fn(&strip_tests(submission), "main"));
+    // Inner attributes must open the file, ahead of the harness's items
+    let (attributes, code): (Vec<&str>, Vec<&str>) = body
+        .lines()
+        .partition(|l| l.trim_start().starts_with("#!["));
+
+    let mut out = String::from("#![allow(dead_code, unused, non_snake_case)]\n");
+    for attribute in attributes {
+        out.push_str(attribute);
+        out.push('\n');
+    }
+    out.push_str(&code.join("\n"));
+    out.push_str("\n\nmod interleave {\n");
+    out.push_str(RUNTIME);
+    out.push_str("}\n\nmod interleave_driver {\n");
+    out.push_str(
+        "    use super::*;\n\
+         \x20   use crate::interleave::std::sync::{mpsc, Arc, Mutex};\n\
+         \x20   use crate::interleave::std::thread;\n\
+         \x20   use crate::interleave::{explore, write_summary, Config};\n\n\
+         \x20   pub fn run(report: &str) {\n\
+         \x20       // Panics are the failures being looked for; the report has them\n\
+         \x20       std::panic::set_hook(Box::new(|_| {}));\n",
+    );
+    out.push_str(&format!(
+        "        let config = Config {{ preemption_bound: {}, max_executions: {}, max_steps: {} }};\n\
+         \x20       let mut out = String::new();\n",
+        config.preemption_bound, config.max_executions, config.max_steps
+    ));
+    for scenario in scenarios {
+        out.push_str(&format!(
+            "        let summary = explore(&config, || {{{}}});\n\
+             \x20       write_summary(&mut out, {:?}, &summary);\n\
+             \x20       std::fs::write(report, &out).expect(\"cannot write the report\");\n",
+            scenario.body, scenario.name
+        ));
+    }
+    out.push_str(
+        "    }\n}\n\n\
+         fn main() {\n\
+         \x20   let report = std::env::args().nth(1).expect(\"usage: harness REPORT\");\n\
+         \x20   interleave_driver::run(&report);\n\
+         }\n",
+    );
+    out
+}
+
+/// A compiled harness.
+#[derive(Debug)]
+pub struct Harness {
+    scratch: ScratchDir,
+    binary: PathBuf,
+}
+
+/// Why a harness could not be built.
+#[derive(Debug)]
+pub enum BuildError {
+    /// The submission does not compile against the shims, usually because
+    /// it uses something they lack (`RwLock`, `Condvar`, ...).
+    Rejected(String),
+    Io(io::Error),
+}
+
+pub fn build(
+    submission: &str,
+    scenarios: &[Scenario],
+    config: &Config,
+    timeout: Duration,
+) -> Result<Harness, BuildError> {
+    let scratch = ScratchDir::new("interleave").map_err(BuildError::Io)?;
+    let source = source(submission, scenarios, config);
+    match compile_binary(&source, scratch.path(), timeout) {
+        Ok(binary) => Ok(Harness { scratch, binary }),
+        Err(CompileError::Io(err)) => Err(BuildError::Io(err)),
+        Err(err) => Err(BuildError::Rejected(err.to_string())),
+    }
+}
+
+/// One scenario's summary, as read back from the report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub name: String,
+    pub executions: usize,
+    pub exhaustive: bool,
+    pub spun: bool,
+    pub failure: Option<String>,
+    /// `(thread, op)` for each step of the failing execution.
+    pub schedule: Vec<(usize, String)>,
+}
+
+/// How a harness run ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    /// One per scenario that finished, in order.
+    pub records: Vec<Record>,
+    pub timed_out: bool,
+    /// Set if the harness died other than by timing out.
+    pub crashed: Option<String>,
+}
+
+impl Harness {
+    pub fn run(&self, timeout: Duration) -> io::Result<Run> {
+        let report = self.scratch.path().join("report.txt");
+        let mut command = Command::new(&self.binary);
+        command.arg(&report);
+        let out = run_with_timeout(&mut command, timeout, MAX_OUTPUT_BYTES)?;
+        let records = match std::fs::read_to_string(&report) {
+            Ok(text) => parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let crashed = (!out.timed_out() && !out.success()).then(|| {
+            let code = out.status.and_then(|s| s.code());
+            format!("the harness exited with status {code:?}")
+        });
+        Ok(Run {
+            records,
+            timed_out: out.timed_out(),
+            crashed,
+        })
+    }
+}
+
+/// Reads the records [`write_summary`](crate::runtime::write_summary)
+/// wrote, skipping anything malformed.
+pub fn parse(report: &str) -> Vec<Record> {
+    let mut records: Vec<Record> = Vec::new();
+    for line in report.lines() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields[..] {
+            ["scenario", name, executions, exhaustive, spun] => {
+                records.push(Record {
+                    name: name.to_string(),
+                    executions: executions.parse().unwrap_or(0),
+                    exhaustive: exhaustive == "1",
+                    spun: spun == "1",
+                    failure: None,
+                    schedule: Vec::new(),
+                });
+            }
+            ["failure", message] => {
+                if let Some(record) = records.last_mut() {
+                    record.failure = Some(message.to_string());
+                }
+            }
+            ["step", thread, op] => {
+                if let (Some(record), Ok(thread)) = (records.last_mut(), thread.parse()) {
+                    record.schedule.push((thread, op.to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::{write_summary, Counterexample, Failure, Step, Summary};
+
+    #[test]
+    fn redirects_core_paths_too() {
+        let source = "use core::sync::atomic::{AtomicUsize, Ordering};\n\
+                      fn f(score: &::core::cell::Cell<u8>) -> usize { my::core::get() }\n";
+        assert_eq!(
+            redirect_std(source),
+            "use crate::interleave::std::sync::atomic::{AtomicUsize, Ordering};\n\
+             fn f(score: &crate::interleave::std::cell::Cell<u8>) -> usize { my::core::get() }\n"
+        );
+    }
+
+    #[test]
+    fn redirects_std_paths_only() {
+        let source = "use std::sync::{Arc, Mutex};\n\
+                      use std::{thread, time::Duration};\n\
+                      // std::thread stays in comments\n\
+                      fn f() -> ::std::thread::JoinHandle<()> {\n\
+                      \x20   let s = \"std::sync\";\n\
+                      \x20   let v: Vec<i32> = my::std::make();\n\
+                      \x20   let rustd = 1; x.std::<u8>();\n\
+                      \x20   let _ = 1 as ::std::primitive::u8;\n\
+                      \x20   std :: thread::spawn(|| {})\n\
+                      }\n";
+        assert_eq!(
+            redirect_std(source),
+            "use crate::interleave::std::sync::{Arc, Mutex};\n\
+             use crate::interleave::std::{thread, time::Duration};\n\
+             // std::thread stays in comments\n\
+             fn f() -> crate::interleave::std::thread::JoinHandle<()> {\n\
+             \x20   let s = \"std::sync\";\n\
+             \x20   let v: Vec<i32> = my::std::make();\n\
+             \x20   let rustd = 1; x.std::<u8>();\n\
+             \x20   let _ = 1 as crate::interleave::std::primitive::u8;\n\
+             \x20   crate::interleave::std :: thread::spawn(|| {})\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn reads_back_what_the_runtime_writes() {
+        let mut report = String::new();
+        let passed = Summary {
+            executions: 12,
+            exhaustive: true,
+            spun: false,
+            counterexample: None,
+        };
+        let failed = Summary {
+            executions: 3,
+            exhaustive: false,
+            spun: false,
+            counterexample: Some(Counterexample {
+                failure: Failure::Panicked {
+                    thread: 1,
+                    message: "went\tnegative\n-20".into(),
+                },
+                schedule: vec![Step {
+                    thread: 0,
+                    op: "spawn thread 1".into(),
+                }],
+            }),
+        };
+        write_summary(&mut report, "a", &passed);
+        write_summary(&mut report, "b", &failed);
+
+        let records = parse(&report);
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].executions, records[0].exhaustive), (12, true));
+        assert_eq!(records[0].failure, None);
+        assert_eq!(
+            records[1].failure.as_deref(),
+            Some("thread 1 panicked: went negative -20")
+        );
+        assert_eq!(records[1].schedule, [(0, "spawn thread 1".to_string())]);
+    }
+}
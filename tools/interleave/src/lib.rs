@@ -0,0 +1,255 @@
+//! Deterministic thread-interleaving checks for the concurrency exercises.
+//!
+//! Exercises 080-085 are tested by running real threads once, so a race
+//! such as a `withdraw` that checks the balance under one lock and
+//! subtracts under another passes nearly every time. [`check`] instead
+//! compiles the submission against shimmed `Mutex`, `mpsc`, atomics and
+//! `thread::spawn` ([`runtime`]), runs each of the exercise's
+//! [`scenarios`] under every interleaving within a preemption bound, and
+//! reports the first schedule that panics or deadlocks.
+
+pub mod harness;
+pub mod runtime;
+pub mod scenarios;
+
+use std::io;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use harness::BuildError;
+pub use runtime::Config;
+
+/// Limits for one check.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub compile_timeout: Duration,
+    /// For every scenario together.
+    pub run_timeout: Duration,
+    pub config: Config,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            compile_timeout: Duration::from_secs(30),
+            run_timeout: Duration::from_secs(30),
+            config: Config::default(),
+        }
+    }
+}
+
+/// One operation of a failing schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    /// Threads are numbered in spawn order; 0 runs the scenario itself.
+    pub thread: usize,
+    pub op: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub executions: usize,
+    /// Every interleaving within the preemption bound was tried.
+    pub exhaustive: bool,
+    /// Why the search stopped early without a failure, if it did.
+    pub stopped: Option<String>,
+    /// The panic or deadlock the failing schedule ends in.
+    pub failure: Option<String>,
+    pub schedule: Vec<Step>,
+}
+
+/// What checking one submission found.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub exercise: String,
+    pub preemption_bound: usize,
+    pub scenarios: Vec<ScenarioReport>,
+    /// Why some or all scenarios were not checked, e.g. the submission
+    /// uses a primitive the shims lack.
+    pub unchecked: Option<String>,
+    /// Every scenario was checked to the end and no schedule broke it. A
+    /// report that is `unchecked` or has a scenario that `stopped` early
+    /// does not pass.
+    pub passed: bool,
+}
+
+/// Model-checks `source` against the exercise's scenarios. Errors are
+/// failures of the checker itself, including asking about an exercise
+/// without scenarios.
+pub fn check(exercise: &str, source: &str, opts: &Options) -> io::Result<Report> {
+    let scenarios = scenarios::for_exercise(exercise);
+    if scenarios.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("exercise {exercise} has no interleaving scenarios"),
+        ));
+    }
+    let mut report = Report {
+        exercise: exercise.to_string(),
+        preemption_bound: opts.config.preemption_bound,
+        scenarios: Vec::new(),
+        unchecked: None,
+        passed: true,
+    };
+
+    let harness = match harness::build(source, scenarios, &opts.config, opts.compile_timeout) {
+        Ok(harness) => harness,
+        Err(BuildError::Io(err)) => return Err(err),
+        Err(BuildError::Rejected(error)) => {
+            report.unchecked = Some(format!(
+                "does not compile against the thread shims: {error}"
+            ));
+            report.passed = false;
+            return Ok(report);
+        }
+    };
+    let run = harness.run(opts.run_timeout)?;
+
+    for record in run.records {
+        let stopped = if record.spun {
+            Some(format!(
+                "an execution ran past {} steps, most likely spinning",
+                opts.config.max_steps
+            ))
+        } else if !record.exhaustive && record.failure.is_none() {
+            Some(format!("stopped after {} executions", record.executions))
+        } else {
+            None
+        };
+        report.passed &= record.failure.is_none() && stopped.is_none();
+        report.scenarios.push(ScenarioReport {
+            name: record.name,
+            executions: record.executions,
+            exhaustive: record.exhaustive,
+            stopped,
+            failure: record.failure,
+            schedule: record
+                .schedule
+                .into_iter()
+                .map(|(thread, op)| Step { thread, op })
+                .collect(),
+        });
+    }
+    if report.scenarios.len() < scenarios.len() {
+        let next = scenarios[report.scenarios.len()].name;
+        report.unchecked = Some(if run.timed_out {
+            format!(
+                "timed out after {}s while checking {next}",
+                opts.run_timeout.as_secs()
+            )
+        } else {
+            let why = run.crashed.unwrap_or_default();
+            format!("{why} while checking {next}")
+        });
+        report.passed = false;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn checked(exercise: &str, source: &str) -> Report {
+        check(exercise, source, &Options::default()).unwrap()
+    }
+
+    fn failing<'a>(report: &'a Report, name: &str) -> &'a ScenarioReport {
+        let scenario = report.scenarios.iter().find(|s| s.name == name).unwrap();
+        assert!(scenario.failure.is_some(), "{report:?}");
+        scenario
+    }
+
+    #[test]
+    fn solutions_pass_and_templates_fail() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let mut exercises: Vec<&str> = scenarios::SCENARIOS.iter().map(|s| s.exercise).collect();
+        exercises.dedup();
+        for id in exercises {
+            let solution =
+                std::fs::read_to_string(root.join(format!("solutions/{id}.rs"))).unwrap();
+            let report = checked(id, &solution);
+            assert!(report.passed, "{id}: {report:?}");
+            assert!(
+                report.scenarios.iter().all(|s| s.exhaustive),
+                "{id}: {report:?}"
+            );
+
+            let template = root.join(format!("problems/{id}_exercise/template.rs"));
+            // Some templates do not compile yet; the rest panic in todo!()
+            let report = checked(id, &std::fs::read_to_string(template).unwrap());
+            assert!(!report.passed, "{id}: {report:?}");
+        }
+    }
+
+    #[test]
+    fn finds_the_withdraw_race() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let solution = std::fs::read_to_string(root.join("solutions/081.rs")).unwrap();
+        let racy = solution.replace(
+            "let mut balance = self.balance.lock().unwrap();\n        if amount > *balance {\n            return Err(\"Insufficient funds\");\n        }\n        *balance -= amount;",
+            "if amount > self.balance() {\n            return Err(\"Insufficient funds\");\n        }\n        *self.balance.lock().unwrap() -= amount;",
+        );
+        assert_ne!(racy, solution);
+
+        let report = checked("081", &racy);
+        assert!(!report.passed);
+        let withdraw = failing(&report, "ThreadSafeAccount::withdraw");
+        assert_eq!(
+            withdraw.failure.as_deref(),
+            Some("thread 0 panicked: two threads withdrew 60 from 100 and the balance went negative: -20")
+        );
+        // Both threads read the balance before either subtracts
+        let locks: Vec<usize> = withdraw
+            .schedule
+            .iter()
+            .filter(|s| s.op == "lock mutex 0")
+            .map(|s| s.thread)
+            .collect();
+        assert_eq!(locks.len(), 5, "{withdraw:?}");
+        assert_ne!(locks[0], locks[2], "{withdraw:?}");
+    }
+
+    #[test]
+    fn finds_a_receiver_that_never_sees_the_channel_close() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let solution = std::fs::read_to_string(root.join("solutions/085.rs")).unwrap();
+        let hanging = solution.replace(
+            "    drop(tx);\n\n    let mut results: Vec<i32>",
+            "    let mut results: Vec<i32>",
+        );
+        assert_ne!(hanging, solution);
+
+        let report = checked("085", &hanging);
+        let squares = failing(&report, "parallel_squares");
+        assert!(
+            squares
+                .failure
+                .as_deref()
+                .unwrap()
+                .starts_with("deadlock: thread 0 waits on channel 0"),
+            "{squares:?}"
+        );
+    }
+
+    #[test]
+    fn unsupported_primitives_fail_the_submission_unchecked() {
+        let source = "use std::sync::{Arc, RwLock};\n\
+                      use std::thread;\n\
+                      fn parallel_sum(data: Vec<i32>, n: usize) -> i32 {\n\
+                      \x20   let total = Arc::new(RwLock::new(0));\n\
+                      \x20   *total.write().unwrap() += data.iter().sum::<i32>();\n\
+                      \x20   let t = *total.read().unwrap();\n\
+                      \x20   t\n\
+                      }\n\
+                      fn spawn_n_threads(n: usize) -> Vec<thread::JoinHandle<()>> {\n\
+                      \x20   (0..n).map(|_| thread::spawn(|| {})).collect()\n\
+                      }\n";
+        let report = checked("082", source);
+        assert!(!report.passed && report.scenarios.is_empty());
+        assert!(report.unchecked.unwrap().contains("RwLock"));
+    }
+}
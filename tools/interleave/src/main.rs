@@ -0,0 +1,91 @@
+//! `interleave` -- model-check a concurrency submission under every thread
+//! interleaving within a bound, and print a JSON report.
+//!
+//! Usage: interleave --exercise NNN [--preemptions N] [--max-executions N]
+//!                   [--compile-timeout SECS] [--run-timeout SECS] <FILE | ->
+//!
+//! Exercises 080-085 are supported.
+//!
+//! Exit status is 0 when every scenario was checked and no schedule broke
+//! it, 1 when one did or the submission could not be fully checked (see
+//! `unchecked` and `stopped`), and 2 on usage or internal errors.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use interleave::Options;
+
+const USAGE: &str = "usage: interleave --exercise NNN [--preemptions N] [--max-executions N] \
+                     [--compile-timeout SECS] [--run-timeout SECS] <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("interleave: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut opts = Options::default();
+    let mut exercise = None;
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => exercise = Some(value(&mut args, &arg)?),
+            "--preemptions" => opts.config.preemption_bound = number(&mut args, &arg)?,
+            "--max-executions" => opts.config.max_executions = number(&mut args, &arg)?,
+            "--compile-timeout" => {
+                opts.compile_timeout = Duration::from_secs(number(&mut args, &arg)?)
+            }
+            "--run-timeout" => opts.run_timeout = Duration::from_secs(number(&mut args, &arg)?),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let exercise = exercise.ok_or(USAGE)?;
+    let exercise = match exercise.parse::<u32>() {
+        Ok(n) => format!("{n:03}"),
+        Err(_) => return Err(format!("--exercise: `{exercise}` is not a number")),
+    };
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let report = interleave::check(&exercise, &source, &opts).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.passed)
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
+
+fn number<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+) -> Result<T, String> {
+    let raw = value(args, flag)?;
+    raw.parse()
+        .map_err(|_| format!("{flag}: `{raw}` is not a number"))
+}
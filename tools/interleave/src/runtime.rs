@@ -0,0 +1,1534 @@
+//! A small model checker for code written against `std::sync` and
+//! `std::thread`.
+//!
+//! [`explore`] runs a closure over and over, each time under a different
+//! interleaving of the threads it spawns, until every interleaving within
+//! the preemption bound has been tried or one of them panics or deadlocks.
+//! The threads are real OS threads, but only one runs at a time: before any
+//! operation another thread could observe (locking a mutex, sending or
+//! receiving, touching an atomic, joining) a thread asks the scheduler who
+//! goes next, and the choices are enumerated depth-first.
+//!
+//! [`std`] mirrors the standard library with `sync` and `thread` replaced by
+//! shims that do this; code under test is pointed at it instead of `::std`.
+//! Memory orderings are ignored, so every execution is sequentially
+//! consistent, and a rendezvous `sync_channel(0)` buffers one message.
+//!
+//! This file depends only on `::std` and reaches its own items through
+//! `super`, because the checker also compiles it into every harness as
+//! `mod interleave`.
+
+use ::std::any::Any;
+use ::std::cell::RefCell;
+use ::std::collections::HashMap;
+use ::std::fmt;
+use ::std::panic::{self, AssertUnwindSafe};
+use ::std::sync::{Arc as StdArc, Condvar, Mutex as StdMutex, MutexGuard as StdGuard, PoisonError};
+
+/// Bounds on the search.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Times per execution the scheduler may switch away from a thread
+    /// that could have kept running. Most concurrency bugs need one or two.
+    pub preemption_bound: usize,
+    pub max_executions: usize,
+    /// Operations per execution; more means a thread is spinning.
+    pub max_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            preemption_bound: 2,
+            max_executions: 20_000,
+            max_steps: 10_000,
+        }
+    }
+}
+
+/// One operation of a failing execution. Threads are numbered in spawn
+/// order, the closure itself being thread 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub thread: usize,
+    pub op: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Panicked {
+        thread: usize,
+        message: String,
+    },
+    /// Every unfinished thread is blocked; each with what it waits for.
+    Deadlock {
+        waiting: Vec<(usize, String)>,
+    },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panicked { thread, message } => {
+                write!(f, "thread {thread} panicked: {message}")
+            }
+            Failure::Deadlock { waiting } => {
+                f.write_str("deadlock:")?;
+                for (i, (thread, what)) in waiting.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { "; " };
+                    write!(f, "{sep}thread {thread} waits {what}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An execution that failed, and the operations that led there.
+#[derive(Debug, Clone)]
+pub struct Counterexample {
+    pub failure: Failure,
+    pub schedule: Vec<Step>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    pub executions: usize,
+    /// Every interleaving within the preemption bound was tried.
+    pub exhaustive: bool,
+    /// An execution ran past `max_steps` and the search stopped there.
+    pub spun: bool,
+    pub counterexample: Option<Counterexample>,
+}
+
+/// Runs `f` under every interleaving within `config`'s bounds, stopping at
+/// the first that panics or deadlocks.
+pub fn explore<F>(config: &Config, f: F) -> Summary
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let f = StdArc::new(f);
+    let mut schedule = Schedule::default();
+    let mut executions = 0;
+    loop {
+        executions += 1;
+        let exec = StdArc::new(Execution::new(config.clone(), schedule));
+        let body = StdArc::clone(&f);
+        exec.lock().threads.push(Status::Runnable);
+        let os = ::std::thread::spawn({
+            let exec = StdArc::clone(&exec);
+            move || run_thread(exec, 0, move || (*body)())
+        });
+        exec.adopt(os);
+
+        let mut state = exec.lock();
+        while state.threads.iter().any(|s| *s != Status::Finished) {
+            state = exec
+                .turn
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        drop(state);
+        loop {
+            let os = exec
+                .os_threads
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .pop();
+            match os {
+                Some(os) => {
+                    let _ = os.join();
+                }
+                None => break,
+            }
+        }
+
+        let mut state = exec.lock();
+        if let Some(failure) = state.failure.take() {
+            let (failure, schedule) = describe(failure, &state.trace);
+            return Summary {
+                executions,
+                exhaustive: false,
+                spun: false,
+                counterexample: Some(Counterexample { failure, schedule }),
+            };
+        }
+        let spun = state.spun;
+        schedule = ::std::mem::take(&mut state.schedule);
+        let more = !spun && executions < config.max_executions && schedule.advance();
+        if !more {
+            return Summary {
+                executions,
+                exhaustive: !spun && executions < config.max_executions,
+                spun,
+                counterexample: None,
+            };
+        }
+    }
+}
+
+/// Writes `summary` as the line records the checker reads back:
+/// a `scenario` line, then `failure` and `step` lines if one failed.
+pub fn write_summary(out: &mut String, name: &str, summary: &Summary) {
+    use ::std::fmt::Write as _;
+    let flag = |b: bool| if b { 1 } else { 0 };
+    let _ = writeln!(
+        out,
+        "scenario\t{name}\t{}\t{}\t{}",
+        summary.executions,
+        flag(summary.exhaustive),
+        flag(summary.spun)
+    );
+    if let Some(counterexample) = &summary.counterexample {
+        let failure = counterexample
+            .failure
+            .to_string()
+            .replace(['\n', '\t'], " ");
+        let _ = writeln!(out, "failure\t{failure}");
+        for step in &counterexample.schedule {
+            let _ = writeln!(out, "step\t{}\t{}", step.thread, step.op);
+        }
+    }
+}
+
+// ── Scheduler ────────────────────────────────────────────────────────────
+
+/// What a blocked thread waits for. Objects are named by address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Wait {
+    Lock(usize),
+    Join(usize),
+    Channel(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Runnable,
+    Blocked(Wait),
+    Finished,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Spawn(usize),
+    Join(usize),
+    Lock(usize),
+    TryLock(usize),
+    Send(usize),
+    Recv(usize),
+    TryRecv(usize),
+    Atomic(&'static str, usize),
+}
+
+/// A point where more than one thread could go next.
+#[derive(Debug)]
+struct Branch {
+    options: Vec<usize>,
+    taken: usize,
+}
+
+/// The choices of one execution: replayed up to the last branch with
+/// options left, which then takes its next option.
+#[derive(Debug, Default)]
+struct Schedule {
+    branches: Vec<Branch>,
+    pos: usize,
+}
+
+impl Schedule {
+    fn choose(&mut self, options: Vec<usize>) -> usize {
+        if options.len() == 1 {
+            return options[0];
+        }
+        // Different options on replay mean the code is nondeterministic
+        // (a HashMap's order, the clock); explore onwards from here
+        if self.branches.get(self.pos).map(|b| &b.options) != Some(&options) {
+            self.branches.truncate(self.pos);
+            self.branches.push(Branch { options, taken: 0 });
+        }
+        let branch = &self.branches[self.pos];
+        self.pos += 1;
+        branch.options[branch.taken]
+    }
+
+    fn advance(&mut self) -> bool {
+        self.pos = 0;
+        while let Some(branch) = self.branches.last_mut() {
+            if branch.taken + 1 < branch.options.len() {
+                branch.taken += 1;
+                return true;
+            }
+            self.branches.pop();
+        }
+        false
+    }
+}
+
+#[derive(Debug)]
+enum RawFailure {
+    Panicked { thread: usize, message: String },
+    Deadlock(Vec<(usize, Wait)>),
+}
+
+#[derive(Debug)]
+struct State {
+    threads: Vec<Status>,
+    active: usize,
+    schedule: Schedule,
+    preemptions: usize,
+    trace: Vec<(usize, Op)>,
+    failure: Option<RawFailure>,
+    spun: bool,
+    /// Set once the execution is being abandoned; blocked threads unwind.
+    aborting: bool,
+}
+
+struct Execution {
+    config: Config,
+    state: StdMutex<State>,
+    turn: Condvar,
+    os_threads: StdMutex<Vec<::std::thread::JoinHandle<()>>>,
+}
+
+/// Unwinds a thread out of an execution that is being abandoned.
+struct Abort;
+
+thread_local! {
+    static CURRENT: RefCell<Option<(StdArc<Execution>, usize)>> = const { RefCell::new(None) };
+}
+
+/// The execution this thread belongs to and its number there.
+fn current() -> Option<(StdArc<Execution>, usize)> {
+    CURRENT.with(|c| c.borrow().clone())
+}
+
+fn lock<T>(mutex: &StdMutex<T>) -> StdGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// The body of every model thread: wait for the first turn, run, and hand
+/// the turn on.
+fn run_thread(exec: StdArc<Execution>, me: usize, body: impl FnOnce()) {
+    CURRENT.with(|c| *c.borrow_mut() = Some((StdArc::clone(&exec), me)));
+    let mut state = exec.lock();
+    while state.active != me && !state.aborting {
+        state = exec
+            .turn
+            .wait(state)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+    let started = !state.aborting;
+    drop(state);
+    if started {
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(body)) {
+            exec.panicked(me, &*payload);
+        }
+    }
+    exec.finish(me);
+    CURRENT.with(|c| *c.borrow_mut() = None);
+}
+
+impl Execution {
+    fn new(config: Config, schedule: Schedule) -> Self {
+        Execution {
+            config,
+            state: StdMutex::new(State {
+                threads: Vec::new(),
+                active: 0,
+                schedule,
+                preemptions: 0,
+                trace: Vec::new(),
+                failure: None,
+                spun: false,
+                aborting: false,
+            }),
+            turn: Condvar::new(),
+            os_threads: StdMutex::new(Vec::new()),
+        }
+    }
+
+    fn lock(&self) -> StdGuard<'_, State> {
+        lock(&self.state)
+    }
+
+    fn adopt(&self, os: ::std::thread::JoinHandle<()>) {
+        lock(&self.os_threads).push(os);
+    }
+
+    fn runnable(state: &State) -> Vec<usize> {
+        (0..state.threads.len())
+            .filter(|&t| state.threads[t] == Status::Runnable)
+            .collect()
+    }
+
+    /// Hands the turn to `next` and waits until it comes back to `me`.
+    fn switch<'a>(
+        &'a self,
+        mut state: StdGuard<'a, State>,
+        me: usize,
+        next: usize,
+    ) -> StdGuard<'a, State> {
+        state.active = next;
+        self.turn.notify_all();
+        while state.active != me && !state.aborting {
+            state = self
+                .turn
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        if state.aborting {
+            drop(state);
+            panic::resume_unwind(Box::new(Abort));
+        }
+        state
+    }
+
+    /// Marks the execution abandoned and unwinds the calling thread.
+    fn abort(&self, mut state: StdGuard<'_, State>) -> ! {
+        state.aborting = true;
+        self.turn.notify_all();
+        drop(state);
+        panic::resume_unwind(Box::new(Abort))
+    }
+
+    /// Whether `me` is unwinding out of an abandoned execution, in which
+    /// case it must not wait for a turn that will never come.
+    fn leaving(state: &State) -> bool {
+        state.aborting && ::std::thread::panicking()
+    }
+
+    /// A scheduling point before `me` performs `op`.
+    fn step(&self, me: usize, op: Op) {
+        let mut state = self.lock();
+        if Self::leaving(&state) {
+            return;
+        }
+        if state.trace.len() >= self.config.max_steps {
+            state.spun = true;
+            self.abort(state);
+        }
+        let mut options = vec![me];
+        if state.preemptions < self.config.preemption_bound {
+            options.extend(Self::runnable(&state).into_iter().filter(|&t| t != me));
+        }
+        let next = state.schedule.choose(options);
+        if next != me {
+            state.preemptions += 1;
+            state = self.switch(state, me, next);
+        }
+        state.trace.push((me, op));
+    }
+
+    /// Lets another thread run, as `yield_now` and failed `try_` calls do.
+    /// Not a preemption: without it a spin loop would never let go.
+    fn pass(&self, me: usize) {
+        let mut state = self.lock();
+        if Self::leaving(&state) {
+            return;
+        }
+        let others: Vec<usize> = Self::runnable(&state)
+            .into_iter()
+            .filter(|&t| t != me)
+            .collect();
+        if others.is_empty() {
+            return;
+        }
+        let next = state.schedule.choose(others);
+        drop(self.switch(state, me, next));
+    }
+
+    /// Parks `me` until something it waits for changes.
+    fn block(&self, me: usize, wait: Wait) {
+        let mut state = self.lock();
+        if Self::leaving(&state) {
+            drop(state);
+            ::std::thread::yield_now();
+            return;
+        }
+        state.threads[me] = Status::Blocked(wait);
+        let options = Self::runnable(&state);
+        if options.is_empty() {
+            let waiting = (0..state.threads.len())
+                .filter_map(|t| match state.threads[t] {
+                    Status::Blocked(wait) => Some((t, wait)),
+                    _ => None,
+                })
+                .collect();
+            state.failure.get_or_insert(RawFailure::Deadlock(waiting));
+            self.abort(state);
+        }
+        let next = state.schedule.choose(options);
+        drop(self.switch(state, me, next));
+    }
+
+    /// Makes every thread waiting for `wait` runnable again. Never switches,
+    /// so it is safe from `Drop`.
+    fn unblock(&self, wait: Wait) {
+        let mut state = self.lock();
+        for status in &mut state.threads {
+            if *status == Status::Blocked(wait) {
+                *status = Status::Runnable;
+            }
+        }
+    }
+
+    fn spawn(&self, me: usize) -> usize {
+        let mut state = self.lock();
+        let id = state.threads.len();
+        state.threads.push(Status::Runnable);
+        state.trace.push((me, Op::Spawn(id)));
+        id
+    }
+
+    fn join(&self, me: usize, id: usize) {
+        self.step(me, Op::Join(id));
+        while self.lock().threads[id] != Status::Finished {
+            self.block(me, Wait::Join(id));
+        }
+    }
+
+    fn is_finished(&self, id: usize) -> bool {
+        self.lock().threads[id] == Status::Finished
+    }
+
+    /// Records a panic in `me` as the failure, unless it is the unwinding
+    /// of an abandoned execution.
+    fn panicked(&self, me: usize, payload: &(dyn Any + Send)) {
+        if payload.is::<Abort>() {
+            return;
+        }
+        let mut state = self.lock();
+        state.failure.get_or_insert(RawFailure::Panicked {
+            thread: me,
+            message: panic_message(payload),
+        });
+        state.aborting = true;
+        self.turn.notify_all();
+    }
+
+    fn finish(&self, me: usize) {
+        let mut state = self.lock();
+        state.threads[me] = Status::Finished;
+        for status in &mut state.threads {
+            if *status == Status::Blocked(Wait::Join(me)) {
+                *status = Status::Runnable;
+            }
+        }
+        if !state.aborting {
+            let options = Self::runnable(&state);
+            if !options.is_empty() {
+                state.active = state.schedule.choose(options);
+            } else if state.threads.iter().any(|s| *s != Status::Finished) {
+                let waiting = (0..state.threads.len())
+                    .filter_map(|t| match state.threads[t] {
+                        Status::Blocked(wait) => Some((t, wait)),
+                        _ => None,
+                    })
+                    .collect();
+                state.failure.get_or_insert(RawFailure::Deadlock(waiting));
+                state.aborting = true;
+            }
+        }
+        self.turn.notify_all();
+    }
+}
+
+/// Names objects by kind and first appearance, e.g. `mutex 0`.
+#[derive(Default)]
+struct Names(HashMap<(&'static str, usize), usize>);
+
+impl Names {
+    fn name(&mut self, kind: &'static str, addr: usize) -> String {
+        let next = self.0.keys().filter(|(k, _)| *k == kind).count();
+        let n = *self.0.entry((kind, addr)).or_insert(next);
+        format!("{kind} {n}")
+    }
+}
+
+fn describe(failure: RawFailure, trace: &[(usize, Op)]) -> (Failure, Vec<Step>) {
+    let mut names = Names::default();
+    let schedule = trace
+        .iter()
+        .map(|&(thread, op)| {
+            let op = match op {
+                Op::Spawn(t) => format!("spawn thread {t}"),
+                Op::Join(t) => format!("join thread {t}"),
+                Op::Lock(m) => format!("lock {}", names.name("mutex", m)),
+                Op::TryLock(m) => format!("try_lock {}", names.name("mutex", m)),
+                Op::Send(c) => format!("send on {}", names.name("channel", c)),
+                Op::Recv(c) => format!("recv on {}", names.name("channel", c)),
+                Op::TryRecv(c) => format!("try_recv on {}", names.name("channel", c)),
+                Op::Atomic(what, a) => format!("{what} {}", names.name("atomic", a)),
+            };
+            Step { thread, op }
+        })
+        .collect();
+    let failure = match failure {
+        RawFailure::Panicked { thread, message } => Failure::Panicked { thread, message },
+        RawFailure::Deadlock(waiting) => Failure::Deadlock {
+            waiting: waiting
+                .into_iter()
+                .map(|(thread, wait)| {
+                    let what = match wait {
+                        Wait::Lock(m) => format!("to lock {}", names.name("mutex", m)),
+                        Wait::Join(t) => format!("to join thread {t}"),
+                        Wait::Channel(c) => format!("on {}", names.name("channel", c)),
+                    };
+                    (thread, what)
+                })
+                .collect(),
+        },
+    };
+    (failure, schedule)
+}
+
+/// A scheduling point for `op`, if this thread is part of an execution.
+fn visit(op: Op) {
+    if let Some((exec, me)) = current() {
+        exec.step(me, op);
+    }
+}
+
+/// Waits for something outside any execution, where there is no scheduler.
+fn spin() {
+    ::std::thread::yield_now();
+}
+
+// ── Shims ────────────────────────────────────────────────────────────────
+
+/// The standard library, with `sync` and `thread` swapped for shims that
+/// hand every observable operation to the scheduler.
+pub mod std {
+    pub use ::std::*;
+
+    pub mod sync {
+        //! `Arc` and `Weak` are std's own: a reference count is only ever
+        //! read through `strong_count`, never waited on.
+
+        pub use ::std::sync::{Arc, LockResult, PoisonError, TryLockError, TryLockResult, Weak};
+
+        use ::std::cell::UnsafeCell;
+        use ::std::fmt;
+        use ::std::ops::{Deref, DerefMut};
+        use ::std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+
+        use super::super::{current, spin, Op, Wait};
+
+        const UNLOCKED: usize = usize::MAX;
+        /// Owner of a mutex locked outside any execution.
+        const OUTSIDE: usize = usize::MAX - 1;
+
+        pub struct Mutex<T: ?Sized> {
+            owner: AtomicUsize,
+            data: UnsafeCell<T>,
+        }
+
+        // SAFETY: `owner` admits one guard at a time, like std's Mutex
+        unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+        unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+        impl<T> Mutex<T> {
+            pub const fn new(value: T) -> Self {
+                Mutex {
+                    owner: AtomicUsize::new(UNLOCKED),
+                    data: UnsafeCell::new(value),
+                }
+            }
+
+            pub fn into_inner(self) -> LockResult<T> {
+                Ok(self.data.into_inner())
+            }
+        }
+
+        impl<T: ?Sized> Mutex<T> {
+            fn addr(&self) -> usize {
+                self as *const Self as *const () as usize
+            }
+
+            fn acquire(&self, me: usize) -> bool {
+                self.owner
+                    .compare_exchange(UNLOCKED, me, SeqCst, SeqCst)
+                    .is_ok()
+            }
+
+            pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+                match current() {
+                    Some((exec, me)) => {
+                        exec.step(me, Op::Lock(self.addr()));
+                        while !self.acquire(me) {
+                            exec.block(me, Wait::Lock(self.addr()));
+                        }
+                    }
+                    None => {
+                        while !self.acquire(OUTSIDE) {
+                            spin();
+                        }
+                    }
+                }
+                Ok(MutexGuard { mutex: self })
+            }
+
+            pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
+                let (exec, me) = match current() {
+                    Some((exec, me)) => (Some(exec), me),
+                    None => (None, OUTSIDE),
+                };
+                if let Some(exec) = &exec {
+                    exec.step(me, Op::TryLock(self.addr()));
+                }
+                if self.acquire(me) {
+                    return Ok(MutexGuard { mutex: self });
+                }
+                if let Some(exec) = &exec {
+                    exec.pass(me);
+                }
+                Err(TryLockError::WouldBlock)
+            }
+
+            pub fn is_poisoned(&self) -> bool {
+                false
+            }
+
+            pub fn get_mut(&mut self) -> LockResult<&mut T> {
+                Ok(self.data.get_mut())
+            }
+        }
+
+        impl<T: Default> Default for Mutex<T> {
+            fn default() -> Self {
+                Mutex::new(T::default())
+            }
+        }
+
+        impl<T> From<T> for Mutex<T> {
+            fn from(value: T) -> Self {
+                Mutex::new(value)
+            }
+        }
+
+        impl<T: ?Sized> fmt::Debug for Mutex<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("Mutex").finish_non_exhaustive()
+            }
+        }
+
+        pub struct MutexGuard<'a, T: ?Sized> {
+            mutex: &'a Mutex<T>,
+        }
+
+        // SAFETY: like std's guard, shared access only hands out `&T`
+        unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+        impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                // SAFETY: the guard's existence means this thread owns the lock
+                unsafe { &*self.mutex.data.get() }
+            }
+        }
+
+        impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+            fn deref_mut(&mut self) -> &mut T {
+                // SAFETY: as above, and `&mut self` makes this the only borrow
+                unsafe { &mut *self.mutex.data.get() }
+            }
+        }
+
+        impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+            fn drop(&mut self) {
+                self.mutex.owner.store(UNLOCKED, SeqCst);
+                if let Some((exec, _)) = current() {
+                    exec.unblock(Wait::Lock(self.mutex.addr()));
+                }
+            }
+        }
+
+        impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        impl<T: ?Sized + fmt::Display> fmt::Display for MutexGuard<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                (**self).fmt(f)
+            }
+        }
+
+        pub mod atomic {
+            //! Every operation is a scheduling point and sequentially
+            //! consistent, whatever `Ordering` it is given.
+
+            pub use ::std::sync::atomic::Ordering;
+
+            use ::std::fmt;
+
+            use super::super::super::{visit, Op};
+
+            macro_rules! atomic {
+                ($name:ident, $value:ty, { $($extra:tt)* }) => {
+                    #[derive(Default)]
+                    pub struct $name(::std::sync::atomic::$name);
+
+                    impl $name {
+                        pub const fn new(value: $value) -> Self {
+                            $name(::std::sync::atomic::$name::new(value))
+                        }
+
+                        fn visit(&self, what: &'static str) {
+                            visit(Op::Atomic(what, self as *const Self as usize));
+                        }
+
+                        pub fn load(&self, order: Ordering) -> $value {
+                            self.visit("load");
+                            self.0.load(order)
+                        }
+
+                        pub fn store(&self, value: $value, order: Ordering) {
+                            self.visit("store");
+                            self.0.store(value, order)
+                        }
+
+                        pub fn swap(&self, value: $value, order: Ordering) -> $value {
+                            self.visit("swap");
+                            self.0.swap(value, order)
+                        }
+
+                        pub fn compare_exchange(
+                            &self,
+                            current: $value,
+                            new: $value,
+                            success: Ordering,
+                            failure: Ordering,
+                        ) -> Result<$value, $value> {
+                            self.visit("compare_exchange");
+                            self.0.compare_exchange(current, new, success, failure)
+                        }
+
+                        pub fn compare_exchange_weak(
+                            &self,
+                            current: $value,
+                            new: $value,
+                            success: Ordering,
+                            failure: Ordering,
+                        ) -> Result<$value, $value> {
+                            self.visit("compare_exchange");
+                            self.0.compare_exchange(current, new, success, failure)
+                        }
+
+                        pub fn get_mut(&mut self) -> &mut $value {
+                            self.0.get_mut()
+                        }
+
+                        pub fn into_inner(self) -> $value {
+                            self.0.into_inner()
+                        }
+
+                        $($extra)*
+                    }
+
+                    impl From<$value> for $name {
+                        fn from(value: $value) -> Self {
+                            $name::new(value)
+                        }
+                    }
+
+                    impl fmt::Debug for $name {
+                        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            self.0.fmt(f)
+                        }
+                    }
+                };
+            }
+
+            macro_rules! fetch {
+                ($value:ty; $($op:ident),*) => {
+                    $(
+                        pub fn $op(&self, value: $value, order: Ordering) -> $value {
+                            self.visit(stringify!($op));
+                            self.0.$op(value, order)
+                        }
+                    )*
+                };
+            }
+
+            macro_rules! atomic_int {
+                ($($name:ident($value:ty)),*) => {
+                    $(
+                        atomic!($name, $value, {
+                            fetch!($value; fetch_add, fetch_sub, fetch_max, fetch_min, fetch_and, fetch_or, fetch_xor);
+                        });
+                    )*
+                };
+            }
+
+            atomic!(AtomicBool, bool, {
+                fetch!(bool; fetch_and, fetch_or, fetch_xor);
+            });
+
+            atomic_int!(
+                AtomicI8(i8),
+                AtomicI16(i16),
+                AtomicI32(i32),
+                AtomicI64(i64),
+                AtomicIsize(isize),
+                AtomicU8(u8),
+                AtomicU16(u16),
+                AtomicU32(u32),
+                AtomicU64(u64),
+                AtomicUsize(usize)
+            );
+        }
+
+        pub mod mpsc {
+            pub use ::std::sync::mpsc::{
+                RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError,
+            };
+
+            use ::std::collections::VecDeque;
+            use ::std::fmt;
+            use ::std::sync::{Arc as StdArc, Mutex as StdMutex};
+            use ::std::time::Duration;
+
+            use super::super::super::{current, lock, spin, Op, Wait};
+
+            struct Chan<T> {
+                queue: VecDeque<T>,
+                senders: usize,
+                receiving: bool,
+                /// `None` for `channel`, at least 1 for `sync_channel`.
+                bound: Option<usize>,
+            }
+
+            struct Shared<T>(StdMutex<Chan<T>>);
+
+            fn shared<T>(bound: Option<usize>) -> StdArc<Shared<T>> {
+                StdArc::new(Shared(StdMutex::new(Chan {
+                    queue: VecDeque::new(),
+                    senders: 1,
+                    receiving: true,
+                    bound,
+                })))
+            }
+
+            fn addr<T>(shared: &StdArc<Shared<T>>) -> usize {
+                StdArc::as_ptr(shared) as *const () as usize
+            }
+
+            fn wake<T>(shared: &StdArc<Shared<T>>) {
+                if let Some((exec, _)) = current() {
+                    exec.unblock(Wait::Channel(addr(shared)));
+                }
+            }
+
+            /// Waits for the channel to change.
+            fn wait<T>(shared: &StdArc<Shared<T>>) {
+                match current() {
+                    Some((exec, me)) => exec.block(me, Wait::Channel(addr(shared))),
+                    None => spin(),
+                }
+            }
+
+            fn send<T>(shared: &StdArc<Shared<T>>, value: T) -> Result<(), SendError<T>> {
+                super::super::super::visit(Op::Send(addr(shared)));
+                loop {
+                    let mut chan = lock(&shared.0);
+                    if !chan.receiving {
+                        return Err(SendError(value));
+                    }
+                    if chan.bound.is_none_or(|bound| chan.queue.len() < bound) {
+                        chan.queue.push_back(value);
+                        drop(chan);
+                        wake(shared);
+                        return Ok(());
+                    }
+                    drop(chan);
+                    wait(shared);
+                }
+            }
+
+            fn drop_sender<T>(shared: &StdArc<Shared<T>>) {
+                lock(&shared.0).senders -= 1;
+                wake(shared);
+            }
+
+            pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+                let shared = shared(None);
+                (
+                    Sender {
+                        shared: StdArc::clone(&shared),
+                    },
+                    Receiver { shared },
+                )
+            }
+
+            pub fn sync_channel<T>(bound: usize) -> (SyncSender<T>, Receiver<T>) {
+                let shared = shared(Some(bound.max(1)));
+                (
+                    SyncSender {
+                        shared: StdArc::clone(&shared),
+                    },
+                    Receiver { shared },
+                )
+            }
+
+            pub struct Sender<T> {
+                shared: StdArc<Shared<T>>,
+            }
+
+            impl<T> Sender<T> {
+                pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+                    send(&self.shared, value)
+                }
+            }
+
+            impl<T> Clone for Sender<T> {
+                fn clone(&self) -> Self {
+                    lock(&self.shared.0).senders += 1;
+                    Sender {
+                        shared: StdArc::clone(&self.shared),
+                    }
+                }
+            }
+
+            impl<T> Drop for Sender<T> {
+                fn drop(&mut self) {
+                    drop_sender(&self.shared);
+                }
+            }
+
+            impl<T> fmt::Debug for Sender<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct("Sender").finish_non_exhaustive()
+                }
+            }
+
+            pub struct SyncSender<T> {
+                shared: StdArc<Shared<T>>,
+            }
+
+            impl<T> SyncSender<T> {
+                pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+                    send(&self.shared, value)
+                }
+
+                pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+                    super::super::super::visit(Op::Send(addr(&self.shared)));
+                    let mut chan = lock(&self.shared.0);
+                    if !chan.receiving {
+                        return Err(TrySendError::Disconnected(value));
+                    }
+                    if chan.bound.is_some_and(|bound| chan.queue.len() >= bound) {
+                        drop(chan);
+                        if let Some((exec, me)) = current() {
+                            exec.pass(me);
+                        }
+                        return Err(TrySendError::Full(value));
+                    }
+                    chan.queue.push_back(value);
+                    drop(chan);
+                    wake(&self.shared);
+                    Ok(())
+                }
+            }
+
+            impl<T> Clone for SyncSender<T> {
+                fn clone(&self) -> Self {
+                    lock(&self.shared.0).senders += 1;
+                    SyncSender {
+                        shared: StdArc::clone(&self.shared),
+                    }
+                }
+            }
+
+            impl<T> Drop for SyncSender<T> {
+                fn drop(&mut self) {
+                    drop_sender(&self.shared);
+                }
+            }
+
+            impl<T> fmt::Debug for SyncSender<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct("SyncSender").finish_non_exhaustive()
+                }
+            }
+
+            pub struct Receiver<T> {
+                shared: StdArc<Shared<T>>,
+            }
+
+            /// What a receive found without waiting.
+            enum Poll<T> {
+                Value(T),
+                Empty,
+                Disconnected,
+            }
+
+            impl<T> Receiver<T> {
+                fn poll(&self) -> Poll<T> {
+                    let mut chan = lock(&self.shared.0);
+                    let polled = match chan.queue.pop_front() {
+                        Some(value) => Poll::Value(value),
+                        None if chan.senders == 0 => Poll::Disconnected,
+                        None => Poll::Empty,
+                    };
+                    drop(chan);
+                    if let Poll::Value(_) = polled {
+                        // A bounded sender may be waiting for room
+                        wake(&self.shared);
+                    }
+                    polled
+                }
+
+                pub fn recv(&self) -> Result<T, RecvError> {
+                    super::super::super::visit(Op::Recv(addr(&self.shared)));
+                    loop {
+                        match self.poll() {
+                            Poll::Value(value) => return Ok(value),
+                            Poll::Disconnected => return Err(RecvError),
+                            Poll::Empty => wait(&self.shared),
+                        }
+                    }
+                }
+
+                pub fn try_recv(&self) -> Result<T, TryRecvError> {
+                    super::super::super::visit(Op::TryRecv(addr(&self.shared)));
+                    match self.poll() {
+                        Poll::Value(value) => Ok(value),
+                        Poll::Disconnected => Err(TryRecvError::Disconnected),
+                        Poll::Empty => {
+                            if let Some((exec, me)) = current() {
+                                exec.pass(me);
+                            }
+                            Err(TryRecvError::Empty)
+                        }
+                    }
+                }
+
+                /// Any wait may time out, so an empty channel always does.
+                pub fn recv_timeout(&self, _timeout: Duration) -> Result<T, RecvTimeoutError> {
+                    self.try_recv().map_err(|err| match err {
+                        TryRecvError::Empty => RecvTimeoutError::Timeout,
+                        TryRecvError::Disconnected => RecvTimeoutError::Disconnected,
+                    })
+                }
+
+                pub fn iter(&self) -> Iter<'_, T> {
+                    Iter { rx: self }
+                }
+
+                pub fn try_iter(&self) -> TryIter<'_, T> {
+                    TryIter { rx: self }
+                }
+            }
+
+            impl<T> Drop for Receiver<T> {
+                fn drop(&mut self) {
+                    lock(&self.shared.0).receiving = false;
+                    wake(&self.shared);
+                }
+            }
+
+            impl<T> fmt::Debug for Receiver<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_struct("Receiver").finish_non_exhaustive()
+                }
+            }
+
+            pub struct Iter<'a, T> {
+                rx: &'a Receiver<T>,
+            }
+
+            impl<T> Iterator for Iter<'_, T> {
+                type Item = T;
+
+                fn next(&mut self) -> Option<T> {
+                    self.rx.recv().ok()
+                }
+            }
+
+            pub struct TryIter<'a, T> {
+                rx: &'a Receiver<T>,
+            }
+
+            impl<T> Iterator for TryIter<'_, T> {
+                type Item = T;
+
+                fn next(&mut self) -> Option<T> {
+                    self.rx.try_recv().ok()
+                }
+            }
+
+            pub struct IntoIter<T> {
+                rx: Receiver<T>,
+            }
+
+            impl<T> Iterator for IntoIter<T> {
+                type Item = T;
+
+                fn next(&mut self) -> Option<T> {
+                    self.rx.recv().ok()
+                }
+            }
+
+            impl<'a, T> IntoIterator for &'a Receiver<T> {
+                type Item = T;
+                type IntoIter = Iter<'a, T>;
+
+                fn into_iter(self) -> Iter<'a, T> {
+                    self.iter()
+                }
+            }
+
+            impl<T> IntoIterator for Receiver<T> {
+                type Item = T;
+                type IntoIter = IntoIter<T>;
+
+                fn into_iter(self) -> IntoIter<T> {
+                    IntoIter { rx: self }
+                }
+            }
+        }
+    }
+
+    pub mod thread {
+        //! Threads exist only inside [`explore`](super::super::explore);
+        //! `sleep` just lets another thread run.
+
+        pub use ::std::thread::{available_parallelism, panicking, Result};
+
+        use ::std::marker::PhantomData;
+        use ::std::panic::{self, AssertUnwindSafe};
+        use ::std::sync::{Arc as StdArc, Mutex as StdMutex};
+        use ::std::time::Duration;
+
+        use super::super::{current, lock, run_thread, Execution};
+
+        fn context() -> (StdArc<Execution>, usize) {
+            current().expect("interleave: threads can only be used inside explore()")
+        }
+
+        type Slot<T> = StdArc<StdMutex<Option<T>>>;
+
+        fn take<T>(slot: &Slot<T>) -> T {
+            lock(slot)
+                .take()
+                .expect("interleave: joined thread left no result")
+        }
+
+        pub struct JoinHandle<T> {
+            id: usize,
+            slot: Slot<T>,
+        }
+
+        pub fn spawn<F, T>(f: F) -> JoinHandle<T>
+        where
+            F: FnOnce() -> T + Send + 'static,
+            T: Send + 'static,
+        {
+            let (exec, me) = context();
+            let id = exec.spawn(me);
+            let slot: Slot<T> = StdArc::new(StdMutex::new(None));
+            let os = ::std::thread::spawn({
+                let exec = StdArc::clone(&exec);
+                let slot = StdArc::clone(&slot);
+                move || run_thread(exec, id, move || *lock(&slot) = Some(f()))
+            });
+            exec.adopt(os);
+            JoinHandle { id, slot }
+        }
+
+        impl<T> JoinHandle<T> {
+            pub fn join(self) -> Result<T> {
+                let (exec, me) = context();
+                exec.join(me, self.id);
+                Ok(take(&self.slot))
+            }
+
+            pub fn is_finished(&self) -> bool {
+                context().0.is_finished(self.id)
+            }
+        }
+
+        impl<T> ::std::fmt::Debug for JoinHandle<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct("JoinHandle").finish_non_exhaustive()
+            }
+        }
+
+        pub fn sleep(_duration: Duration) {
+            yield_now();
+        }
+
+        pub fn yield_now() {
+            if let Some((exec, me)) = current() {
+                exec.pass(me);
+            }
+        }
+
+        pub struct Scope<'scope, 'env: 'scope> {
+            inner: &'scope ::std::thread::Scope<'scope, 'env>,
+            spawned: StdMutex<Vec<usize>>,
+        }
+
+        pub struct ScopedJoinHandle<'scope, T> {
+            id: usize,
+            slot: Slot<T>,
+            _scope: PhantomData<&'scope ()>,
+        }
+
+        /// Like `std::thread::scope`, whose threads it runs on. Each call
+        /// leaks a `Scope`, which outlives the threads borrowing it.
+        pub fn scope<'env, F, T>(f: F) -> T
+        where
+            F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+        {
+            let (exec, me) = context();
+            ::std::thread::scope(|inner| {
+                let scope: &Scope<'_, 'env> = Box::leak(Box::new(Scope {
+                    inner,
+                    spawned: StdMutex::new(Vec::new()),
+                }));
+                match panic::catch_unwind(AssertUnwindSafe(|| f(scope))) {
+                    Ok(value) => {
+                        let spawned = ::std::mem::take(&mut *lock(&scope.spawned));
+                        for id in spawned {
+                            exec.join(me, id);
+                        }
+                        value
+                    }
+                    Err(payload) => {
+                        // Abandon the execution before std's scope waits on
+                        // threads that would otherwise never get a turn
+                        exec.panicked(me, &*payload);
+                        panic::resume_unwind(payload)
+                    }
+                }
+            })
+        }
+
+        impl<'scope> Scope<'scope, '_> {
+            pub fn spawn<F, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
+            where
+                F: FnOnce() -> T + Send + 'scope,
+                T: Send + 'scope,
+            {
+                let (exec, me) = context();
+                let id = exec.spawn(me);
+                lock(&self.spawned).push(id);
+                let slot: Slot<T> = StdArc::new(StdMutex::new(None));
+                self.inner.spawn({
+                    let slot = StdArc::clone(&slot);
+                    move || run_thread(exec, id, move || *lock(&slot) = Some(f()))
+                });
+                ScopedJoinHandle {
+                    id,
+                    slot,
+                    _scope: PhantomData,
+                }
+            }
+        }
+
+        impl<T> ScopedJoinHandle<'_, T> {
+            pub fn join(self) -> Result<T> {
+                let (exec, me) = context();
+                exec.join(me, self.id);
+                Ok(take(&self.slot))
+            }
+
+            pub fn is_finished(&self) -> bool {
+                context().0.is_finished(self.id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::std::sync::atomic::{AtomicUsize, Ordering};
+    use super::std::sync::{mpsc, Arc, Mutex};
+    use super::std::thread;
+    use super::*;
+
+    fn failure(summary: &Summary) -> String {
+        summary
+            .counterexample
+            .as_ref()
+            .expect("no counterexample")
+            .failure
+            .to_string()
+    }
+
+    /// Withdraws after reading the balance under a separate lock.
+    fn racy_withdraw(balance: &Mutex<i64>, amount: i64) -> bool {
+        let current = *balance.lock().unwrap();
+        if current < amount {
+            return false;
+        }
+        *balance.lock().unwrap() -= amount;
+        true
+    }
+
+    #[test]
+    fn finds_a_check_then_act_race_and_its_schedule() {
+        let summary = explore(&Config::default(), || {
+            let balance = Arc::new(Mutex::new(100));
+            let other = Arc::clone(&balance);
+            let t = thread::spawn(move || racy_withdraw(&other, 60));
+            racy_withdraw(&balance, 60);
+            t.join().unwrap();
+            let left = *balance.lock().unwrap();
+            assert!(left >= 0, "balance went negative: {left}");
+        });
+        assert_eq!(
+            failure(&summary),
+            "thread 0 panicked: balance went negative: -20"
+        );
+        let schedule = &summary.counterexample.unwrap().schedule;
+        assert_eq!(
+            schedule[0],
+            Step {
+                thread: 0,
+                op: "spawn thread 1".into()
+            }
+        );
+        assert!(schedule
+            .iter()
+            .any(|s| s.thread == 1 && s.op == "lock mutex 0"));
+    }
+
+    #[test]
+    fn checks_every_interleaving_of_correct_code() {
+        let summary = explore(&Config::default(), || {
+            let counter = Arc::new(Mutex::new(0));
+            let handles: Vec<_> = (0..3)
+                .map(|_| {
+                    let counter = Arc::clone(&counter);
+                    thread::spawn(move || *counter.lock().unwrap() += 1)
+                })
+                .collect();
+            for h in handles {
+                h.join().unwrap();
+            }
+            assert_eq!(*counter.lock().unwrap(), 3);
+        });
+        assert!(summary.counterexample.is_none(), "{summary:?}");
+        assert!(summary.exhaustive);
+        assert!(summary.executions > 10, "{summary:?}");
+    }
+
+    #[test]
+    fn finds_lost_updates_on_atomics() {
+        let summary = explore(&Config::default(), || {
+            let n = Arc::new(AtomicUsize::new(0));
+            let other = Arc::clone(&n);
+            let bump = |n: &AtomicUsize| n.store(n.load(Ordering::SeqCst) + 1, Ordering::SeqCst);
+            let t = thread::spawn(move || bump(&other));
+            bump(&n);
+            t.join().unwrap();
+            assert_eq!(n.load(Ordering::SeqCst), 2);
+        });
+        assert!(failure(&summary).contains("left: 1"), "{summary:?}");
+    }
+
+    #[test]
+    fn finds_lock_order_deadlocks() {
+        let summary = explore(&Config::default(), || {
+            let a = Arc::new(Mutex::new(()));
+            let b = Arc::new(Mutex::new(()));
+            let (a2, b2) = (Arc::clone(&a), Arc::clone(&b));
+            let t = thread::spawn(move || {
+                let _b = b2.lock().unwrap();
+                let _a = a2.lock().unwrap();
+            });
+            {
+                let _a = a.lock().unwrap();
+                let _b = b.lock().unwrap();
+            }
+            t.join().unwrap();
+        });
+        assert_eq!(
+            failure(&summary),
+            "deadlock: thread 0 waits to lock mutex 1; thread 1 waits to lock mutex 0"
+        );
+    }
+
+    #[test]
+    fn a_receiver_whose_sender_is_never_dropped_deadlocks() {
+        let summary = explore(&Config::default(), || {
+            let (tx, rx) = mpsc::channel();
+            for i in 0..2 {
+                let tx = tx.clone();
+                thread::spawn(move || tx.send(i).unwrap());
+            }
+            let _all: Vec<i32> = rx.iter().collect();
+        });
+        assert!(
+            failure(&summary).starts_with("deadlock: thread 0 waits on channel 0"),
+            "{summary:?}"
+        );
+
+        let summary = explore(&Config::default(), || {
+            let (tx, rx) = mpsc::sync_channel(1);
+            for i in 0..2 {
+                let tx = tx.clone();
+                thread::spawn(move || tx.send(i).unwrap());
+            }
+            drop(tx);
+            let mut all: Vec<i32> = rx.iter().collect();
+            all.sort();
+            assert_eq!(all, [0, 1]);
+        });
+        assert!(
+            summary.counterexample.is_none() && summary.exhaustive,
+            "{summary:?}"
+        );
+    }
+
+    #[test]
+    fn scoped_threads_borrow_and_are_joined() {
+        let summary = explore(&Config::default(), || {
+            let data = [1, 2, 3, 4];
+            let total = Mutex::new(0);
+            thread::scope(|s| {
+                for chunk in data.chunks(2) {
+                    let total = &total;
+                    s.spawn(move || *total.lock().unwrap() += chunk.iter().sum::<i32>());
+                }
+            });
+            assert_eq!(total.into_inner().unwrap(), 10);
+        });
+        assert!(
+            summary.counterexample.is_none() && summary.exhaustive,
+            "{summary:?}"
+        );
+    }
+
+    #[test]
+    fn spin_loops_are_cut_off_without_failing() {
+        let config = Config {
+            max_steps: 200,
+            ..Config::default()
+        };
+        let summary = explore(&config, || {
+            let flag = Arc::new(AtomicUsize::new(0));
+            let other = Arc::clone(&flag);
+            let t = thread::spawn(move || other.store(1, Ordering::SeqCst));
+            while flag.load(Ordering::SeqCst) == 0 {}
+            t.join().unwrap();
+        });
+        assert!(summary.spun && !summary.exhaustive, "{summary:?}");
+        assert!(summary.counterexample.is_none());
+    }
+}
@@ -0,0 +1,161 @@
+//! What must hold for each concurrency exercise under every interleaving.
+//!
+//! A scenario's body runs as the closure given to
+//! [`explore`](crate::runtime::explore), inside the harness, with the
+//! submission's items and the shimmed `Arc`, `Mutex`, `mpsc` and `thread`
+//! in scope. It states its invariant with `assert!`; the message is what a
+//! learner sees when a schedule breaks it.
+
+pub struct Scenario {
+    /// Three-digit exercise number, e.g. `"081"`.
+    pub exercise: &'static str,
+    pub name: &'static str,
+    pub body: &'static str,
+}
+
+/// Sorted by exercise.
+pub const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        exercise: "080",
+        name: "spawn_with_data",
+        body: r#"
+        let data = make_shared(vec![1, 2, 3]);
+        let a = spawn_with_data(Arc::clone(&data));
+        let b = spawn_with_data(Arc::clone(&data));
+        a.join().unwrap();
+        b.join().unwrap();
+        assert_eq!(Arc::strong_count(&data), 1, "a joined thread still holds its clone of the Arc");
+        "#,
+    },
+    Scenario {
+        exercise: "081",
+        name: "increment_shared_counter",
+        body: r#"
+        let counter = Arc::new(Mutex::new(0));
+        let other = Arc::clone(&counter);
+        let t = thread::spawn(move || {
+            increment_shared_counter(&other);
+            increment_shared_counter(&other);
+        });
+        increment_shared_counter(&counter);
+        t.join().unwrap();
+        let total = *counter.lock().unwrap();
+        assert_eq!(total, 3, "three increments from two threads left the counter at {total}");
+        "#,
+    },
+    Scenario {
+        exercise: "081",
+        name: "ThreadSafeCounter::increment",
+        body: r#"
+        let counter = Arc::new(ThreadSafeCounter::new());
+        let other = Arc::clone(&counter);
+        let t = thread::spawn(move || other.increment());
+        counter.increment();
+        t.join().unwrap();
+        let total = counter.get();
+        assert_eq!(total, 2, "two threads incremented once each and get() returned {total}");
+        "#,
+    },
+    Scenario {
+        exercise: "081",
+        name: "ThreadSafeAccount::withdraw",
+        body: r#"
+        let account = Arc::new(ThreadSafeAccount::new(100));
+        let other = Arc::clone(&account);
+        let t = thread::spawn(move || other.withdraw(60).is_ok());
+        let mine = account.withdraw(60).is_ok();
+        let theirs = t.join().unwrap();
+        let balance = account.balance();
+        assert!(balance >= 0, "two threads withdrew 60 from 100 and the balance went negative: {balance}");
+        assert!(mine != theirs, "two threads withdrew 60 from 100 and {} succeeded", if mine { "both" } else { "neither" });
+        assert_eq!(balance, 40, "one withdrawal of 60 from 100 succeeded but the balance is {balance}");
+        "#,
+    },
+    Scenario {
+        exercise: "081",
+        name: "ThreadSafeAccount::deposit",
+        body: r#"
+        let account = Arc::new(ThreadSafeAccount::new(100));
+        let other = Arc::clone(&account);
+        let t = thread::spawn(move || other.deposit(50).is_ok());
+        let withdrew = account.withdraw(120).is_ok();
+        assert!(t.join().unwrap(), "deposit(50) failed");
+        let expected = if withdrew { 30 } else { 150 };
+        let balance = account.balance();
+        assert_eq!(balance, expected, "a deposit of 50 raced a withdrawal of 120 from 100 and one of them was lost");
+        "#,
+    },
+    Scenario {
+        exercise: "082",
+        name: "parallel_sum",
+        body: r#"
+        let sum = parallel_sum((1..=6).collect(), 3);
+        assert_eq!(sum, 21, "parallel_sum of 1..=6 on 3 threads returned {sum}");
+        "#,
+    },
+    Scenario {
+        exercise: "082",
+        name: "spawn_n_threads",
+        body: r#"
+        let handles = spawn_n_threads(3);
+        assert_eq!(handles.len(), 3, "spawn_n_threads(3) returned {} handles", handles.len());
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        "#,
+    },
+    Scenario {
+        exercise: "083",
+        name: "spawn_with_data",
+        body: r#"
+        let (tx, rx) = mpsc::channel();
+        spawn_with_data(vec![1, 2, 3], move |v| tx.send(v.len()).unwrap()).join().unwrap();
+        assert_eq!(rx.try_recv().ok(), Some(3), "the closure had not run by the time its thread was joined");
+        "#,
+    },
+    Scenario {
+        exercise: "083",
+        name: "process_vector_in_thread",
+        body: r#"
+        let handle = process_vector_in_thread(vec![1, 2, 3], |v| v.into_iter().map(|x| x * 2).collect());
+        assert_eq!(handle.join().unwrap(), vec![2, 4, 6]);
+        "#,
+    },
+    Scenario {
+        exercise: "084",
+        name: "spawn_worker",
+        body: r#"
+        let rx = spawn_worker(7);
+        assert_eq!(rx.recv().unwrap(), "Processed: 7");
+        "#,
+    },
+    Scenario {
+        exercise: "084",
+        name: "MessageSystem",
+        body: r#"
+        let (system, rx) = MessageSystem::new();
+        let t = thread::spawn(move || {
+            system.send(String::from("first"));
+            system.send(String::from("second"));
+        });
+        assert_eq!(rx.recv().unwrap(), "first");
+        assert_eq!(rx.recv().unwrap(), "second");
+        t.join().unwrap();
+        "#,
+    },
+    Scenario {
+        exercise: "085",
+        name: "parallel_squares",
+        body: r#"
+        let squares = parallel_squares(vec![3, 1, 2]);
+        assert_eq!(squares, vec![1, 4, 9], "parallel_squares(vec![3, 1, 2]) returned {squares:?}");
+        "#,
+    },
+];
+
+/// The scenarios for `exercise`, empty if it has none.
+pub fn for_exercise(exercise: &str) -> &'static [Scenario] {
+    let start = SCENARIOS.partition_point(|s| s.exercise < exercise);
+    let end = SCENARIOS.partition_point(|s| s.exercise <= exercise);
+    &SCENARIOS[start..end]
+}
@@ -0,0 +1,216 @@
+//! Lints for the habits an exercise is meant to break.
+//!
+//! A submission can pass every test and still miss the point: `.clone()`
+//! everywhere in the borrowing exercises, `unwrap()` in the ones about
+//! propagating errors. Each lint in [`RULES`] applies to the exercises that
+//! teach the alternative, as a warning or as an error that fails the
+//! submission. Submissions are parsed with `syn`, never compiled or run.
+
+pub mod visit;
+
+use std::ops::RangeInclusive;
+
+use serde::Serialize;
+
+/// What a lint looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Lint {
+    /// `.unwrap()` and `.expect(..)`.
+    Unwrap,
+    /// `.clone()` and `Clone::clone(..)`.
+    Clone,
+    /// `for i in 0..v.len()`.
+    IndexLoop,
+    /// `unsafe` blocks, functions, impls and traits.
+    Unsafe,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Warning,
+    /// Fails the submission.
+    Error,
+}
+
+/// Which exercises a rule applies to, by number.
+#[derive(Debug, Clone)]
+pub enum Exercises {
+    Only(&'static [RangeInclusive<u32>]),
+    AllBut(&'static [u32]),
+}
+
+impl Exercises {
+    pub fn contains(&self, exercise: u32) -> bool {
+        match self {
+            Exercises::Only(ranges) => ranges.iter().any(|r| r.contains(&exercise)),
+            Exercises::AllBut(excluded) => !excluded.contains(&exercise),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub lint: Lint,
+    pub level: Level,
+    pub exercises: Exercises,
+    /// What to do instead, appended to each finding's message.
+    pub advice: &'static str,
+}
+
+/// Every rule. At most one rule per lint applies to any exercise.
+pub const RULES: &[Rule] = &[
+    // 054 (the `?` operator) and 058 (error propagation)
+    Rule {
+        lint: Lint::Unwrap,
+        level: Level::Error,
+        exercises: Exercises::Only(&[54..=54, 58..=58]),
+        advice: "propagate the error with `?`, or handle it with `match`",
+    },
+    // 029-033: references and slices
+    Rule {
+        lint: Lint::Clone,
+        level: Level::Error,
+        exercises: Exercises::Only(&[29..=33]),
+        advice: "borrow it with `&` or `&mut` instead",
+    },
+    // 073-075: iterator adapters
+    Rule {
+        lint: Lint::IndexLoop,
+        level: Level::Warning,
+        exercises: Exercises::Only(&[73..=75]),
+        advice: "iterate with `.iter()` and adapters, or `.enumerate()` when the index matters",
+    },
+    // Only 094's raw-pointer handle and 323's NonNull list need it
+    Rule {
+        lint: Lint::Unsafe,
+        level: Level::Error,
+        exercises: Exercises::AllBut(&[94, 323]),
+        advice: "this exercise is solvable in safe Rust",
+    },
+];
+
+/// A lint that fired.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    pub lint: Lint,
+    pub level: Level,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub exercise: String,
+    pub findings: Vec<Finding>,
+    /// No finding is an error.
+    pub passed: bool,
+}
+
+/// Lints `source`, a submission to `exercise` (`"054"`). Exercises with no
+/// rules of their own are still checked for `unsafe`; an `exercise` that
+/// is not a number gets only that. Fails if `source` does not parse.
+pub fn check(exercise: &str, source: &str) -> syn::Result<Report> {
+    let file = syn::parse_file(source)?;
+    let number = exercise.parse::<u32>().unwrap_or(0);
+    let rules: Vec<&Rule> = RULES
+        .iter()
+        .filter(|rule| rule.exercises.contains(number))
+        .collect();
+
+    let findings: Vec<Finding> = visit::hits(&file)
+        .into_iter()
+        .filter_map(|hit| {
+            let rule = rules.iter().find(|rule| rule.lint == hit.lint)?;
+            Some(Finding {
+                lint: hit.lint,
+                level: rule.level,
+                line: hit.line,
+                column: hit.column,
+                message: format!("{}; {}", hit.message, rule.advice),
+            })
+        })
+        .collect();
+    Ok(Report {
+        exercise: exercise.to_string(),
+        passed: findings.iter().all(|f| f.level < Level::Error),
+        findings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn lints(exercise: &str, source: &str) -> Vec<(Lint, Level)> {
+        check(exercise, source)
+            .unwrap()
+            .findings
+            .iter()
+            .map(|f| (f.lint, f.level))
+            .collect()
+    }
+
+    #[test]
+    fn rules_apply_only_to_their_exercises() {
+        let clone = "fn f(s: &String) -> usize { s.clone().len() }";
+        assert_eq!(lints("030", clone), [(Lint::Clone, Level::Error)]);
+        assert!(lints("027", clone).is_empty());
+
+        let unwrap = "fn f(s: &str) -> i32 { s.parse().unwrap() }";
+        assert_eq!(lints("058", unwrap), [(Lint::Unwrap, Level::Error)]);
+        assert!(lints("052", unwrap).is_empty());
+
+        let index =
+            "fn f(v: &[i32]) -> i32 { let mut s = 0; for i in 0..v.len() { s += v[i]; } s }";
+        let report = check("074", index).unwrap();
+        assert!(report.passed);
+        assert_eq!(report.findings[0].level, Level::Warning);
+        assert!(report.findings[0]
+            .message
+            .ends_with("when the index matters"));
+
+        let raw = "fn f(p: *const u8) -> u8 { unsafe { *p } }";
+        assert!(lints("094", raw).is_empty());
+        assert!(lints("323", raw).is_empty());
+        assert!(!check("010", raw).unwrap().passed);
+        assert!(!check("not a number", raw).unwrap().passed);
+        assert!(check("010", "fn f( {").is_err());
+    }
+
+    #[test]
+    fn templates_and_solutions_have_no_errors() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let mut checked = 0;
+        for dir in ["problems", "solutions"] {
+            for entry in std::fs::read_dir(root.join(dir)).unwrap() {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy().into_owned();
+                let (path, exercise) = match name.strip_suffix("_exercise") {
+                    Some(id) => (path.join("template.rs"), id.to_string()),
+                    None => match name.strip_suffix(".rs") {
+                        Some(id) => (path.clone(), id.to_string()),
+                        None => continue,
+                    },
+                };
+                let Ok(source) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                // Some templates are left unfinished on purpose
+                let report = match check(&exercise, &source) {
+                    Ok(report) => report,
+                    Err(_) if dir == "problems" => continue,
+                    Err(e) => panic!("{}: {e}", path.display()),
+                };
+                assert!(report.passed, "{}: {:?}", path.display(), report.findings);
+                checked += 1;
+            }
+        }
+        assert!(checked > 250, "{checked}");
+    }
+}
@@ -0,0 +1,66 @@
+//! `lint` -- lint a submission for the habits its exercise is meant to
+//! break and print a JSON report.
+//!
+//! Usage: lint --exercise NNN <FILE | ->
+//!
+//! Exit status is 0 when no finding is an error (warnings allowed), 1 when
+//! one is, and 2 on usage errors or when the submission does not parse.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: lint --exercise NNN <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("lint: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut exercise = None;
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => exercise = Some(args.next().ok_or("--exercise needs a value")?),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let exercise = exercise.ok_or(USAGE)?;
+    let exercise = match exercise.parse::<u32>() {
+        Ok(n) => format!("{n:03}"),
+        Err(_) => return Err(format!("--exercise: `{exercise}` is not a number")),
+    };
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let report = lint::check(&exercise, &source).map_err(|e| {
+        let start = e.span().start();
+        format!("line {}:{}: {e}", start.line, start.column + 1)
+    })?;
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.passed)
+}
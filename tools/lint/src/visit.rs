@@ -0,0 +1,289 @@
+//! Finding every place a lint could fire, whatever the exercise.
+//!
+//! Test code is skipped: `#[cfg(test)]` modules and `#[test]` functions are
+//! the template's, not the learner's. Macro arguments are linted when they
+//! parse as a comma-separated list of expressions, as those of `println!`,
+//! `vec!` and `assert_eq!` do.
+
+use proc_macro2::Span;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, Token};
+
+use crate::Lint;
+
+/// One place a lint fires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hit {
+    pub lint: Lint,
+    /// 1-based.
+    pub line: usize,
+    /// 1-based, in characters.
+    pub column: usize,
+    pub message: String,
+}
+
+/// Every hit in `file`, in source order.
+pub fn hits(file: &syn::File) -> Vec<Hit> {
+    let mut finder = Finder { hits: Vec::new() };
+    finder.visit_file(file);
+    finder.hits.sort_by_key(|h| (h.line, h.column));
+    finder.hits
+}
+
+struct Finder {
+    hits: Vec<Hit>,
+}
+
+impl Finder {
+    fn hit(&mut self, lint: Lint, span: Span, message: String) {
+        let start = span.start();
+        self.hits.push(Hit {
+            lint,
+            line: start.line,
+            column: start.column + 1,
+            message,
+        });
+    }
+}
+
+fn is_test_code(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("test")
+            || (attr.path().is_ident("cfg")
+                && attr
+                    .parse_args::<syn::Ident>()
+                    .is_ok_and(|arg| arg == "test"))
+    })
+}
+
+impl<'ast> Visit<'ast> for Finder {
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        if !is_test_code(&item.attrs) {
+            visit::visit_item_mod(self, item);
+        }
+    }
+
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        if is_test_code(&item.attrs) {
+            return;
+        }
+        if let Some(token) = item.sig.unsafety {
+            let name = &item.sig.ident;
+            self.hit(Lint::Unsafe, token.span, format!("`unsafe fn {name}`"));
+        }
+        visit::visit_item_fn(self, item);
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        if let Some(token) = item.sig.unsafety {
+            let name = &item.sig.ident;
+            self.hit(Lint::Unsafe, token.span, format!("`unsafe fn {name}`"));
+        }
+        visit::visit_impl_item_fn(self, item);
+    }
+
+    fn visit_trait_item_fn(&mut self, item: &'ast syn::TraitItemFn) {
+        if let Some(token) = item.sig.unsafety {
+            let name = &item.sig.ident;
+            self.hit(Lint::Unsafe, token.span, format!("`unsafe fn {name}`"));
+        }
+        visit::visit_trait_item_fn(self, item);
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        if let Some(token) = item.unsafety {
+            self.hit(Lint::Unsafe, token.span, "`unsafe impl`".to_string());
+        }
+        visit::visit_item_impl(self, item);
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast syn::ItemTrait) {
+        if let Some(token) = item.unsafety {
+            let name = &item.ident;
+            self.hit(Lint::Unsafe, token.span, format!("`unsafe trait {name}`"));
+        }
+        visit::visit_item_trait(self, item);
+    }
+
+    fn visit_expr_unsafe(&mut self, expr: &'ast syn::ExprUnsafe) {
+        self.hit(
+            Lint::Unsafe,
+            expr.unsafe_token.span,
+            "`unsafe` block".to_string(),
+        );
+        visit::visit_expr_unsafe(self, expr);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        let method = call.method.to_string();
+        match method.as_str() {
+            "unwrap" | "expect" => self.hit(
+                Lint::Unwrap,
+                call.method.span(),
+                format!("`.{method}()` panics instead of handing the error back"),
+            ),
+            "clone" if call.args.is_empty() => self.hit(
+                Lint::Clone,
+                call.method.span(),
+                "`.clone()` copies the value".to_string(),
+            ),
+            _ => {}
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        // `Clone::clone(&x)`, the same call spelled as a path
+        if let Expr::Path(path) = &*call.func {
+            let segments: Vec<String> = path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            if segments.ends_with(&["Clone".to_string(), "clone".to_string()]) {
+                self.hit(
+                    Lint::Clone,
+                    path.span(),
+                    "`Clone::clone` copies the value".to_string(),
+                );
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_for_loop(&mut self, for_loop: &'ast syn::ExprForLoop) {
+        if let Some(collection) = index_range(&for_loop.expr) {
+            self.hit(
+                Lint::IndexLoop,
+                for_loop.for_token.span,
+                format!("`for` over the indices `0..{collection}.len()`"),
+            );
+        }
+        visit::visit_expr_for_loop(self, for_loop);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// For `0..v.len()`, `0..=v.len() - 1` and the same in parentheses, how
+/// `v` is spelled.
+fn index_range(expr: &Expr) -> Option<String> {
+    let Expr::Range(range) = strip_parens(expr) else {
+        return None;
+    };
+    if !range.start.as_deref().is_some_and(is_zero) {
+        return None;
+    }
+    let mut end = strip_parens(range.end.as_deref()?);
+    if let (syn::RangeLimits::Closed(_), Expr::Binary(minus)) = (&range.limits, end) {
+        if matches!(minus.op, syn::BinOp::Sub(_)) && is_one(&minus.right) {
+            end = strip_parens(&minus.left);
+        }
+    }
+    let Expr::MethodCall(len) = end else {
+        return None;
+    };
+    if len.method != "len" || !len.args.is_empty() {
+        return None;
+    }
+    Some(match strip_parens(&len.receiver) {
+        Expr::Path(path) if path.path.get_ident().is_some() => path.path.get_ident()?.to_string(),
+        Expr::Field(field) => match &field.member {
+            syn::Member::Named(name) => format!("….{name}"),
+            syn::Member::Unnamed(index) => format!("….{}", index.index),
+        },
+        _ => "…".to_string(),
+    })
+}
+
+fn strip_parens(mut expr: &Expr) -> &Expr {
+    while let Expr::Paren(inner) = expr {
+        expr = &inner.expr;
+    }
+    expr
+}
+
+fn is_zero(expr: &Expr) -> bool {
+    int_literal(expr) == Some(0)
+}
+
+fn is_one(expr: &Expr) -> bool {
+    int_literal(expr) == Some(1)
+}
+
+fn int_literal(expr: &Expr) -> Option<u128> {
+    match strip_parens(expr) {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(source: &str) -> Vec<(Lint, usize, usize)> {
+        hits(&syn::parse_file(source).unwrap())
+            .into_iter()
+            .map(|h| (h.lint, h.line, h.column))
+            .collect()
+    }
+
+    #[test]
+    fn finds_each_habit_with_its_position() {
+        let source = "\
+fn main() {
+    let v = vec![1, 2];
+    let w = v.clone();
+    println!(\"{:?}\", w.first().unwrap());
+    for i in 0..v.len() {}
+    for i in 0..=(self.items.len() - 1) {}
+    for i in 1..v.len() {}
+    let _ = unsafe { Clone::clone(&v) };
+}
+unsafe impl Send for S {}
+";
+        assert_eq!(
+            find(source),
+            [
+                (Lint::Clone, 3, 15),
+                (Lint::Unwrap, 4, 32),
+                (Lint::IndexLoop, 5, 5),
+                (Lint::IndexLoop, 6, 5),
+                (Lint::Unsafe, 8, 13),
+                (Lint::Clone, 8, 22),
+                (Lint::Unsafe, 10, 1),
+            ]
+        );
+        let hits = hits(&syn::parse_file(source).unwrap());
+        assert_eq!(hits[3].message, "`for` over the indices `0..….items.len()`");
+    }
+
+    #[test]
+    fn test_code_is_left_alone() {
+        let source = "\
+fn f(x: Option<u8>) -> u8 { x.expect(\"some\") }
+#[test]
+fn top_level() { Some(1).unwrap(); }
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() { let v = vec![1]; v.clone(); Some(1).unwrap(); }
+}
+";
+        assert_eq!(find(source), [(Lint::Unwrap, 1, 31)]);
+    }
+}
@@ -0,0 +1,489 @@
+//! Mutation testing for the exercises where learners write the tests.
+//!
+//! Exercises 098-100 hand the learner working code and ask for unit tests,
+//! assertions and doc tests; a suite of `assert!(true)` passes them as
+//! well as a thorough one. [`check`] grades the suite instead: it puts the
+//! reference body of each function in [`targets`] into the submission,
+//! makes small wrong versions of them ([`mutation`]), and runs the
+//! learner's own tests against each. The share of mutants some test
+//! fails on is the mutation score.
+
+pub mod mutation;
+pub mod targets;
+
+use std::io;
+use std::ops::Range;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use grader::cargo::package_name;
+use grader::compile::{compile_tests, CompileError};
+use grader::doctest::{failed_tests, run_doc_tests};
+use grader::harness::{list_ignored, list_tests};
+use grader::process::{run_with_timeout, Captured};
+use grader::splice::{code_only, fn_items};
+use grader::ScratchDir;
+
+pub use targets::Target;
+
+/// Limits for one check.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub compile_timeout: Duration,
+    /// For one run of the learner's tests, unit and doc tests each.
+    pub run_timeout: Duration,
+    /// Lowest mutation score that passes.
+    pub min_score: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            compile_timeout: Duration::from_secs(30),
+            run_timeout: Duration::from_secs(10),
+            min_score: 0.8,
+        }
+    }
+}
+
+/// Test output is truncated to this many bytes.
+const MAX_TEST_OUTPUT: usize = 65_536;
+
+/// What became of one mutant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// A test failed on it.
+    Killed,
+    /// The tests ran past the timeout, which counts as killing it.
+    TimedOut,
+    /// Every test passed.
+    Survived,
+    /// The mutant does not compile and is left out of the score.
+    Unviable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MutantReport {
+    /// `add` or `Rectangle::can_hold`.
+    pub function: String,
+    pub mutation: String,
+    /// The mutated line of the reference code.
+    pub code: String,
+    pub outcome: Outcome,
+    /// The learner's tests that failed on the mutant.
+    pub killed_by: Vec<String>,
+}
+
+/// What checking one submission found.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub exercise: String,
+    /// The learner's `#[test]`s that run, ignored ones left out.
+    pub tests: usize,
+    pub doc_tests: usize,
+    pub mutants: Vec<MutantReport>,
+    pub killed: usize,
+    pub survived: usize,
+    /// Killed (or timed-out) mutants over viable ones; 0 when the tests
+    /// could not be run against the reference code.
+    pub score: f64,
+    /// Why the tests could not be scored, e.g. they fail on the reference.
+    pub problem: Option<String>,
+    pub passed: bool,
+}
+
+/// Scores the tests in `source` by the mutants of the exercise's reference
+/// functions they kill. Errors are failures of the checker itself,
+/// including asking about an exercise without targets.
+pub fn check(exercise: &str, source: &str, opts: &Options) -> io::Result<Report> {
+    let targets = targets::for_exercise(exercise);
+    if targets.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("exercise {exercise} has no mutation targets"),
+        ));
+    }
+    let mut report = Report {
+        exercise: exercise.to_string(),
+        tests: 0,
+        doc_tests: 0,
+        mutants: Vec::new(),
+        killed: 0,
+        survived: 0,
+        score: 0.0,
+        problem: None,
+        passed: false,
+    };
+
+    let mut bodies = Vec::new();
+    for target in targets {
+        match locate(source, target.path) {
+            Some(body) => bodies.push(body),
+            None => {
+                report.problem = Some(format!("the submission has no `{}` to test", target.path));
+                return Ok(report);
+            }
+        }
+    }
+    let references: Vec<&str> = targets
+        .iter()
+        .map(|t| mutation::split(t.reference).0)
+        .collect();
+    let doc_crate = targets::DOC_TESTED
+        .contains(&exercise)
+        .then(|| package_name(Some(exercise)));
+    let runner = Runner { opts, doc_crate };
+
+    let baseline = runner.run(&splice(source, &bodies, &references))?;
+    let failed = match baseline {
+        Run::Unviable(error) => {
+            report.problem = Some(format!(
+                "the submission does not compile with the reference code: {error}"
+            ));
+            return Ok(report);
+        }
+        Run::Finished {
+            tests,
+            doc_tests,
+            failed,
+            timed_out,
+        } => {
+            report.tests = tests;
+            report.doc_tests = doc_tests;
+            if timed_out {
+                report.problem = Some("the tests time out on the reference code".into());
+                return Ok(report);
+            }
+            failed
+        }
+    };
+    if !failed.is_empty() {
+        report.problem = Some(format!(
+            "the tests must pass on the reference code, but these fail: {}",
+            failed.join(", ")
+        ));
+        return Ok(report);
+    }
+    if report.tests + report.doc_tests == 0 {
+        report.problem = Some("the submission has no tests".into());
+        return Ok(report);
+    }
+
+    for (index, target) in targets.iter().enumerate() {
+        for mutant in mutation::mutations(target.reference) {
+            let mut mutated = references.clone();
+            mutated[index] = &mutant.body;
+            let (outcome, killed_by) = match runner.run(&splice(source, &bodies, &mutated))? {
+                Run::Unviable(_) => (Outcome::Unviable, Vec::new()),
+                Run::Finished {
+                    timed_out: true,
+                    failed,
+                    ..
+                } => (Outcome::TimedOut, failed),
+                Run::Finished { failed, .. } if failed.is_empty() => (Outcome::Survived, failed),
+                Run::Finished { failed, .. } => (Outcome::Killed, failed),
+            };
+            report.mutants.push(MutantReport {
+                function: target.path.to_string(),
+                mutation: mutant.description,
+                code: mutant.code,
+                outcome,
+                killed_by,
+            });
+        }
+    }
+
+    let count = |outcome: Outcome| {
+        report
+            .mutants
+            .iter()
+            .filter(|m| m.outcome == outcome)
+            .count()
+    };
+    report.killed = count(Outcome::Killed) + count(Outcome::TimedOut);
+    report.survived = count(Outcome::Survived);
+    let viable = report.killed + report.survived;
+    report.score = if viable == 0 {
+        0.0
+    } else {
+        report.killed as f64 / viable as f64
+    };
+    report.passed = report.score >= opts.min_score;
+    Ok(report)
+}
+
+/// How the learner's tests did against one version of the code.
+enum Run {
+    Unviable(String),
+    Finished {
+        tests: usize,
+        doc_tests: usize,
+        failed: Vec<String>,
+        timed_out: bool,
+    },
+}
+
+struct Runner<'a> {
+    opts: &'a Options,
+    /// Crate name the doc tests import the code under, for exercises
+    /// whose learners write them.
+    doc_crate: Option<String>,
+}
+
+impl Runner<'_> {
+    /// Runs the unit tests of `source`, then its doc tests if the exercise
+    /// has them and the unit tests passed.
+    fn run(&self, source: &str) -> io::Result<Run> {
+        let scratch = ScratchDir::new("mutate")?;
+        let binary = match compile_tests(source, scratch.path(), self.opts.compile_timeout) {
+            Ok(binary) => binary,
+            Err(CompileError::Io(err)) => return Err(err),
+            Err(err) => return Ok(Run::Unviable(err.to_string())),
+        };
+        let ignored = list_ignored(&binary)?.len();
+        let tests = list_tests(&binary)?.len().saturating_sub(ignored);
+        let out = run_with_timeout(
+            &mut Command::new(&binary),
+            self.opts.run_timeout,
+            MAX_TEST_OUTPUT,
+        )?;
+        let mut failed = failed_tests(&out.stdout);
+        let mut timed_out = out.timed_out();
+        let mut doc_tests = 0;
+
+        if let Some(crate_name) = self
+            .doc_crate
+            .as_deref()
+            .filter(|_| failed.is_empty() && !timed_out)
+        {
+            let docs = ScratchDir::new("mutate-doc")?;
+            let out = match run_doc_tests(
+                source,
+                crate_name,
+                docs.path(),
+                self.opts.compile_timeout,
+                self.opts.run_timeout,
+            ) {
+                Ok(out) => out,
+                Err(CompileError::Io(err)) => return Err(err),
+                Err(err) => return Ok(Run::Unviable(err.to_string())),
+            };
+            doc_tests = tests_run(&out);
+            failed = failed_tests(&out.stdout);
+            timed_out = out.timed_out();
+        }
+        Ok(Run::Finished {
+            tests,
+            doc_tests,
+            failed,
+            timed_out,
+        })
+    }
+}
+
+/// The `N` of rustdoc's `running N tests` line.
+fn tests_run(out: &Captured) -> usize {
+    out.stdout
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("running ")?
+                .split(' ')
+                .next()?
+                .parse()
+                .ok()
+        })
+        .unwrap_or(0)
+}
+
+/// `source` with each of `bodies` replaced by the matching entry of
+/// `with`. The ranges must be disjoint.
+fn splice(source: &str, bodies: &[Range<usize>], with: &[&str]) -> String {
+    let mut order: Vec<usize> = (0..bodies.len()).collect();
+    order.sort_by_key(|&i| bodies[i].start);
+    let mut out = String::with_capacity(source.len());
+    let mut last = 0;
+    for i in order {
+        out.push_str(&source[last..bodies[i].start]);
+        out.push_str(with[i]);
+        last = bodies[i].end;
+    }
+    out.push_str(&source[last..]);
+    out
+}
+
+/// Byte range of the body of `path` in `source`, braces included: a free
+/// function written at the start of a line, or `Type::name` in an inherent
+/// `impl Type` block.
+pub fn locate(source: &str, path: &str) -> Option<Range<usize>> {
+    let code = code_only(source);
+    let item = match path.split_once("::") {
+        None => fn_items(source, path)
+            .into_iter()
+            .find(|r| r.start == 0 || source.as_bytes()[r.start - 1] == b'\n')?,
+        Some((ty, name)) => impl_blocks(&code, ty).into_iter().find_map(|block| {
+            let range = fn_items(&source[block.clone()], name).into_iter().next()?;
+            Some(block.start + range.start..block.start + range.end)
+        })?,
+    };
+    let open = item.start + code[item.clone()].find('{')?;
+    Some(open..item.end)
+}
+
+/// Byte ranges of the insides of every `impl Type` and `impl<..> Type<..>`
+/// block in `code`, trait impls left out.
+fn impl_blocks(code: &str, ty: &str) -> Vec<Range<usize>> {
+    let mut found = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = code[search..].find("impl") {
+        let start = search + offset;
+        search = start + "impl".len();
+        let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+        if (start > 0 && is_ident(code.as_bytes()[start - 1]))
+            || code.as_bytes().get(search).is_some_and(|&b| is_ident(b))
+        {
+            continue;
+        }
+        let Some(open) = code[search..].find('{').map(|i| search + i) else {
+            continue;
+        };
+        let mut header = code[search..open].trim();
+        if header.starts_with('<') {
+            header = skip_generics(header);
+        }
+        let self_ty = header
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .next();
+        if header.contains(" for ") || self_ty != Some(ty) {
+            continue;
+        }
+        let mut depth = 0usize;
+        for (i, b) in code[open..].bytes().enumerate() {
+            match b {
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        found.push(open + 1..open + i);
+                        search = open + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    found
+}
+
+/// `text` after the `<...>` it starts with.
+fn skip_generics(text: &str) -> &str {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return text[i + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn template(id: &str) -> String {
+        let dir =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../../problems/{id}_exercise"));
+        let file = if dir.join("Cargo.toml").is_file() {
+            "src/lib.rs"
+        } else {
+            "template.rs"
+        };
+        std::fs::read_to_string(dir.join(file)).unwrap()
+    }
+
+    #[test]
+    fn finds_functions_and_methods() {
+        let source = "pub fn add(a: i32) -> i32 { a }\n\
+                      impl<T> Stack<T> {\n    pub fn pop(&mut self) -> Option<T> { self.items.pop() }\n}\n\
+                      impl Display for Stack<u8> {\n    fn pop(&self) {}\n}\n\
+                      mod tests {\n    fn add() {}\n}\n";
+        assert_eq!(&source[locate(source, "add").unwrap()], "{ a }");
+        assert_eq!(
+            &source[locate(source, "Stack::pop").unwrap()],
+            "{ self.items.pop() }"
+        );
+        assert_eq!(locate(source, "Rectangle::can_hold"), None);
+    }
+
+    #[test]
+    fn template_tests_leave_boundary_mutants_alive() {
+        let report = check("098", &template("098"), &Options::default()).unwrap();
+        assert_eq!(report.problem, None, "{report:?}");
+        assert_eq!(report.tests, 18);
+        let survivors: Vec<&str> = report
+            .mutants
+            .iter()
+            .filter(|m| m.outcome == Outcome::Survived)
+            .map(|m| m.code.as_str())
+            .collect();
+        // can_hold is only tested on squares of different sizes
+        assert!(
+            survivors.contains(&"self.width > other.width && self.height >= other.height"),
+            "{survivors:?}"
+        );
+        assert!(report.score > 0.5 && report.score < 1.0, "{report:?}");
+
+        let killer = report.mutants.iter().find(|m| m.code == "a - b").unwrap();
+        assert_eq!(killer.outcome, Outcome::Killed);
+        assert_eq!(killer.killed_by, ["tests::test_add_basic"]);
+    }
+
+    #[test]
+    fn trivial_tests_kill_nothing() {
+        let source = template("099");
+        let start = source.find("#[cfg(test)]").unwrap();
+        let trivial = format!(
+            "{}#[cfg(test)]\nmod tests {{\n    #[test]\n    fn t() {{ assert!(true); }}\n}}\n",
+            &source[..start]
+        );
+        let report = check("099", &trivial, &Options::default()).unwrap();
+        assert_eq!((report.tests, report.killed, report.score), (1, 0, 0.0));
+        assert!(!report.passed);
+    }
+
+    #[test]
+    fn doc_tests_count_for_exercise_100() {
+        let report = check("100", &template("100"), &Options::default()).unwrap();
+        assert_eq!(report.problem, None, "{report:?}");
+        assert!(report.doc_tests > 10, "{report:?}");
+        let overflow = report
+            .mutants
+            .iter()
+            .find(|m| m.code == "if n >= 20 {")
+            .unwrap();
+        assert_eq!(overflow.outcome, Outcome::Survived);
+    }
+
+    #[test]
+    fn tests_that_fail_on_the_reference_are_not_scored() {
+        let source =
+            template("098").replace("assert_eq!(add(2, 3), 5);", "assert_eq!(add(2, 3), 6);");
+        let report = check("098", &source, &Options::default()).unwrap();
+        assert!(report
+            .problem
+            .unwrap()
+            .ends_with("these fail: tests::test_add_basic"));
+        assert!(report.mutants.is_empty() && !report.passed);
+    }
+}
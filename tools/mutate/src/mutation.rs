@@ -0,0 +1,223 @@
+//! Making mutants of one function.
+//!
+//! Each mutant changes the function once: an operator is swapped for a
+//! near miss (`>=` for `>`, `+` for `-`, `&&` for `||`, `..=` for `..`), a
+//! `!` is deleted, or the whole body is replaced with a plain value of the
+//! return type. A test suite that pins the behaviour down fails on each of
+//! them.
+
+use grader::splice::code_only;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mutation {
+    /// What changed, e.g. "replace `>=` with `>`".
+    pub description: String,
+    /// The changed line of the function, trimmed, or the replacement body.
+    pub code: String,
+    /// The mutated body, braces included.
+    pub body: String,
+}
+
+/// Operators in the order they are tried at each position, longer ones
+/// first, with what each may become. Those without replacements are only
+/// listed so their characters are not read as shorter operators.
+const OPERATORS: &[(&str, &[&str])] = &[
+    ("..=", &[".."]),
+    ("&&", &["||"]),
+    ("||", &["&&"]),
+    ("==", &["!="]),
+    ("!=", &["=="]),
+    (">=", &[">", "<"]),
+    ("<=", &["<", ">"]),
+    ("+=", &["-=", "*="]),
+    ("-=", &["+=", "/="]),
+    ("*=", &["+=", "/="]),
+    ("/=", &["%=", "*="]),
+    ("%=", &["/=", "+="]),
+    ("->", &[]),
+    ("=>", &[]),
+    ("::", &[]),
+    ("..", &[]),
+    ("<", &["<=", ">="]),
+    (">", &[">=", "<="]),
+    ("+", &["-", "*"]),
+    ("-", &["+", "/"]),
+    ("*", &["+", "/"]),
+    ("/", &["%", "*"]),
+    ("%", &["/", "+"]),
+    ("!", &[""]),
+];
+
+/// The body of `function`, braces included, and its return type (`()`
+/// when it has none).
+pub fn split(function: &str) -> (&str, &str) {
+    let code = code_only(function);
+    let open = code.find('{').unwrap_or(function.len());
+    let returns = match code[..open].rfind("->") {
+        Some(arrow) => function[arrow + 2..open].trim(),
+        None => "()",
+    };
+    (&function[open..], returns)
+}
+
+/// Every mutant of `function`, a whole `fn` item.
+pub fn mutations(function: &str) -> Vec<Mutation> {
+    let (body, returns) = split(function);
+    let mut found = operator_mutations(body);
+    for value in plain_values(returns) {
+        found.push(Mutation {
+            description: format!("replace the body with `{value}`"),
+            code: value.clone(),
+            body: format!("{{ {value} }}"),
+        });
+    }
+    found
+}
+
+fn operator_mutations(body: &str) -> Vec<Mutation> {
+    let code = code_only(body);
+    let bytes = code.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(&(op, replacements)) = OPERATORS.iter().find(|(op, _)| code[i..].starts_with(op))
+        else {
+            i += 1;
+            continue;
+        };
+        let end = i + op.len();
+        if applies(op, &code, i, end) {
+            for replacement in replacements {
+                let mutated = format!("{}{replacement}{}", &body[..i], &body[end..]);
+                let description = if replacement.is_empty() {
+                    format!("delete `{op}`")
+                } else {
+                    format!("replace `{op}` with `{replacement}`")
+                };
+                found.push(Mutation {
+                    description,
+                    code: line_at(&mutated, i).trim().to_string(),
+                    body: mutated,
+                });
+            }
+        }
+        i = end;
+    }
+    found
+}
+
+/// Whether `op` at `start..end` of `code` is the operator it looks like:
+/// `<` and `>` spaced as comparisons rather than generics, arithmetic
+/// after an operand rather than a deref or a negative literal, and `!`
+/// as a negation rather than a macro call.
+fn applies(op: &str, code: &str, start: usize, end: usize) -> bool {
+    let before = code[..start].chars().next_back();
+    let after = code[end..].chars().next();
+    let spaced = before.is_some_and(char::is_whitespace) && after.is_some_and(char::is_whitespace);
+    let operand = code[..start]
+        .trim_end()
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | ')' | ']'));
+    match op {
+        "<" | ">" | "&&" | "||" => spaced,
+        "+" | "-" | "*" | "/" | "%" => operand,
+        "!" => !before.is_some_and(|c| c.is_alphanumeric() || c == '_'),
+        _ => true,
+    }
+}
+
+fn line_at(text: &str, pos: usize) -> &str {
+    let start = text[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[pos..].find('\n').map_or(text.len(), |i| pos + i);
+    &text[start..end]
+}
+
+/// Simple values of type `ty` to return in place of the real body.
+fn plain_values(ty: &str) -> Vec<String> {
+    let ty = ty.trim();
+    let inner = |prefix: &str| ty.strip_prefix(prefix)?.strip_suffix('>');
+    match ty {
+        "bool" => vec!["true".into(), "false".into()],
+        "f32" | "f64" => vec!["0.0".into(), "1.0".into()],
+        "String" => vec!["String::new()".into()],
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" => {
+            vec!["0".into(), "1".into()]
+        }
+        _ if inner("Option<").is_some() => vec!["None".into()],
+        _ => match inner("Result<").and_then(|args| args.rsplit_once(',')) {
+            Some((ok, err)) => {
+                let mut values: Vec<String> = plain_values(ok)
+                    .into_iter()
+                    .map(|v| format!("Ok({v})"))
+                    .collect();
+                values.extend(plain_values(err).into_iter().map(|v| format!("Err({v})")));
+                values
+            }
+            None => Vec::new(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn described(function: &str) -> Vec<String> {
+        mutations(function)
+            .into_iter()
+            .map(|m| format!("{}: {}", m.description, m.code))
+            .collect()
+    }
+
+    #[test]
+    fn comparisons_and_logic_are_flipped() {
+        let function = "pub fn can_hold(&self, other: &Rectangle) -> bool {\n    \
+                        self.width >= other.width && !self.empty\n}";
+        assert_eq!(
+            described(function),
+            [
+                "replace `>=` with `>`: self.width > other.width && !self.empty",
+                "replace `>=` with `<`: self.width < other.width && !self.empty",
+                "replace `&&` with `||`: self.width >= other.width || !self.empty",
+                "delete `!`: self.width >= other.width && self.empty",
+                "replace the body with `true`: true",
+                "replace the body with `false`: false",
+            ]
+        );
+    }
+
+    #[test]
+    fn arithmetic_but_not_derefs_macros_or_generics() {
+        let function = "fn f(v: &mut Vec<u64>, n: u32) -> Result<f64, String> {\n    \
+                        *v = Vec::<u64>::new();\n    \
+                        assert!(n > 1, \"a - b\");\n    \
+                        Ok((1..=n as u64).product::<u64>() as f64 - -1.0)\n}";
+        let descriptions: Vec<String> = mutations(function)
+            .into_iter()
+            .map(|m| m.description)
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "replace `>` with `>=`",
+                "replace `>` with `<=`",
+                "replace `..=` with `..`",
+                "replace `-` with `+`",
+                "replace `-` with `/`",
+                "replace the body with `Ok(0.0)`",
+                "replace the body with `Ok(1.0)`",
+                "replace the body with `Err(String::new())`",
+            ]
+        );
+    }
+
+    #[test]
+    fn bodies_are_replaced_with_values_of_the_return_type() {
+        let mutants = mutations("pub fn pop(&mut self) -> Option<T> {\n    self.items.pop()\n}");
+        assert_eq!(mutants.len(), 1);
+        assert_eq!(mutants[0].body, "{ None }");
+        assert!(mutations("fn reset(&mut self) { self.n = 0 }").is_empty());
+    }
+}
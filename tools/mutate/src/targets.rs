@@ -0,0 +1,91 @@
+//! The reference code each testing exercise's mutants are made from.
+
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    /// Three-digit exercise number, e.g. `"098"`.
+    pub exercise: &'static str,
+    /// `name` for a free function, `Type::name` for a method.
+    pub path: &'static str,
+    /// The reference function, signature included. Its body replaces the
+    /// learner's before the mutants are made.
+    pub reference: &'static str,
+}
+
+/// Sorted by exercise.
+pub const TARGETS: &[Target] = &[
+    Target {
+        exercise: "098",
+        path: "add",
+        reference: "pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}",
+    },
+    Target {
+        exercise: "098",
+        path: "divide",
+        reference: r#"pub fn divide(a: f64, b: f64) -> Result<f64, String> {
+    if b == 0.0 {
+        Err(String::from("Cannot divide by zero"))
+    } else {
+        Ok(a / b)
+    }
+}"#,
+    },
+    Target {
+        exercise: "098",
+        path: "Rectangle::can_hold",
+        reference: "pub fn can_hold(&self, other: &Rectangle) -> bool {
+    self.width >= other.width && self.height >= other.height
+}",
+    },
+    Target {
+        exercise: "099",
+        path: "is_even",
+        reference: "pub fn is_even(n: i32) -> bool {
+    n % 2 == 0
+}",
+    },
+    Target {
+        exercise: "099",
+        path: "Person::can_vote",
+        reference: "pub fn can_vote(&self) -> bool {
+    self.age >= 18
+}",
+    },
+    Target {
+        exercise: "099",
+        path: "Stack::pop",
+        reference: "pub fn pop(&mut self) -> Option<T> {
+    self.items.pop()
+}",
+    },
+    Target {
+        exercise: "100",
+        path: "add",
+        reference: "pub fn add(a: i32, b: i32) -> i32 {
+    a + b
+}",
+    },
+    Target {
+        exercise: "100",
+        path: "factorial",
+        reference: r#"pub fn factorial(n: u32) -> u64 {
+    if n > 20 {
+        panic!("factorial({}) would overflow", n);
+    }
+    (1..=n as u64).product()
+}"#,
+    },
+];
+
+/// Exercises whose learners write doc tests, the ones the grader grades
+/// with `--doc-tests`; those run against every mutant as well as the unit
+/// tests.
+pub const DOC_TESTED: &[&str] = grader::doctest::EXERCISES;
+
+/// The targets for `exercise`, empty if it has none.
+pub fn for_exercise(exercise: &str) -> &'static [Target] {
+    let start = TARGETS.partition_point(|t| t.exercise < exercise);
+    let end = TARGETS.partition_point(|t| t.exercise <= exercise);
+    &TARGETS[start..end]
+}
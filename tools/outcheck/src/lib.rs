@@ -0,0 +1,265 @@
+//! Checking a program's output against an exercise's `expected.toml`.
+//!
+//! Comparing the whole output with one expected string fails programs
+//! that are right but not deterministic: a `HashMap` printed in iteration
+//! order (050), threads that print as they are scheduled (082, 084), a
+//! float printed with more or fewer digits (075, 151). The [`rules`] say
+//! instead what each line may be, and [`check`] reports every rule that
+//! failed along with a diff of the output against them.
+
+pub mod rules;
+
+use serde::Serialize;
+
+pub use rules::{Line, Rule, Rules};
+
+/// A rule the output broke.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Failure {
+    /// 1-based index of the `[[rule]]` in the file; `None` for output
+    /// past the last rule.
+    pub rule: Option<usize>,
+    /// 1-based line of the output where it failed.
+    pub line: usize,
+    /// The line the rule wanted, if it wanted one.
+    pub expected: Option<String>,
+    /// The line of output, if there was one.
+    pub actual: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub passed: bool,
+    pub failures: Vec<Failure>,
+    /// The output with a leading `  ` on each line that matched, `+ ` on
+    /// each that did not, and `- ` lines for what the rules wanted instead.
+    pub diff: String,
+}
+
+/// Checks `output` against `rules`. Trailing whitespace and trailing blank
+/// lines are ignored.
+pub fn check(rules: &Rules, output: &str) -> Report {
+    let lines: Vec<&str> = output.trim_end().lines().map(str::trim_end).collect();
+    let mut failures = Vec::new();
+    let mut diff = String::new();
+    let mut at = 0;
+
+    for (rule, expected) in &rules.rules {
+        match expected {
+            Rule::Line(line) => match lines.get(at) {
+                None => missing(&mut failures, &mut diff, *rule, at, line),
+                Some(actual) => {
+                    match line.mismatch(actual) {
+                        None => diff.push_str(&format!("  {actual}\n")),
+                        Some(reason) => {
+                            diff.push_str(&format!("- {}\n+ {actual}\n", line.describe()));
+                            failures.push(Failure {
+                                rule: Some(*rule),
+                                line: at + 1,
+                                expected: Some(line.describe()),
+                                actual: Some(actual.to_string()),
+                                reason,
+                            });
+                        }
+                    }
+                    at += 1;
+                }
+            },
+            Rule::Unordered(set) => {
+                let window = &lines[at.min(lines.len())..(at + set.len()).min(lines.len())];
+                let matched = match_lines(set, window);
+                for (i, actual) in window.iter().enumerate() {
+                    if matched.contains(&Some(i)) {
+                        diff.push_str(&format!("  {actual}\n"));
+                        continue;
+                    }
+                    diff.push_str(&format!("+ {actual}\n"));
+                    failures.push(Failure {
+                        rule: Some(*rule),
+                        line: at + i + 1,
+                        expected: None,
+                        actual: Some(actual.to_string()),
+                        reason: "matches none of the unordered lines left".to_string(),
+                    });
+                }
+                for (line, _) in set.iter().zip(&matched).filter(|(_, m)| m.is_none()) {
+                    // Short of lines only because the output ended
+                    if window.len() < set.len() {
+                        missing(&mut failures, &mut diff, *rule, at + window.len(), line);
+                        continue;
+                    }
+                    diff.push_str(&format!("- {}\n", line.describe()));
+                    failures.push(Failure {
+                        rule: Some(*rule),
+                        line: at + 1,
+                        expected: Some(line.describe()),
+                        actual: None,
+                        reason: "no line of this unordered set matches it".to_string(),
+                    });
+                }
+                at += window.len();
+            }
+        }
+    }
+    for (i, actual) in lines.iter().enumerate().skip(at) {
+        diff.push_str(&format!("+ {actual}\n"));
+        failures.push(Failure {
+            rule: None,
+            line: i + 1,
+            expected: None,
+            actual: Some(actual.to_string()),
+            reason: "output past the last rule".to_string(),
+        });
+    }
+
+    Report {
+        passed: failures.is_empty(),
+        failures,
+        diff,
+    }
+}
+
+/// Records that the output ran out before `line`, the `at`th (0-based).
+fn missing(failures: &mut Vec<Failure>, diff: &mut String, rule: usize, at: usize, line: &Line) {
+    diff.push_str(&format!("- {}\n", line.describe()));
+    failures.push(Failure {
+        rule: Some(rule),
+        line: at + 1,
+        expected: Some(line.describe()),
+        actual: None,
+        reason: "the output ends before this line".to_string(),
+    });
+}
+
+/// For each of `set`, the index of the line in `window` it is paired with,
+/// pairing as many as possible. A line can match several patterns, so a
+/// greedy pairing is not enough: this is Kuhn's augmenting-path matching.
+fn match_lines(set: &[Line], window: &[&str]) -> Vec<Option<usize>> {
+    let fits: Vec<Vec<usize>> = set
+        .iter()
+        .map(|line| {
+            (0..window.len())
+                .filter(|&i| line.mismatch(window[i]).is_none())
+                .collect()
+        })
+        .collect();
+    let mut owner: Vec<Option<usize>> = vec![None; window.len()];
+
+    fn augment(
+        rule: usize,
+        fits: &[Vec<usize>],
+        owner: &mut [Option<usize>],
+        seen: &mut [bool],
+    ) -> bool {
+        for &line in &fits[rule] {
+            if seen[line] {
+                continue;
+            }
+            seen[line] = true;
+            if owner[line].is_none_or(|other| augment(other, fits, owner, seen)) {
+                owner[line] = Some(rule);
+                return true;
+            }
+        }
+        false
+    }
+    for rule in 0..set.len() {
+        augment(rule, &fits, &mut owner, &mut vec![false; window.len()]);
+    }
+
+    let mut matched = vec![None; set.len()];
+    for (line, rule) in owner.iter().enumerate() {
+        if let Some(rule) = rule {
+            matched[*rule] = Some(line);
+        }
+    }
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::process::Command;
+
+    fn rules(raw: &str) -> Rules {
+        Rules::parse(raw).unwrap()
+    }
+
+    #[test]
+    fn reports_each_broken_rule_with_a_diff() {
+        let rules = rules(
+            "[[rule]]\nexact = \"start\"\n\
+             [[rule]]\nunordered = [\"a\", \"b\", { regex = 'c\\d' }]\n\
+             [[rule]]\napprox = \"avg 2.5\"\ntolerance = 0.01\n\
+             [[rule]]\nexact = \"end\"\n",
+        );
+        assert!(check(&rules, "start\nc7\nb\na\navg 2.501\nend\n\n").passed);
+
+        let report = check(&rules, "start\nb\nd\na\navg 2.6\nend\nextra");
+        assert!(!report.passed);
+        assert_eq!(
+            report.diff,
+            "  start\n  b\n+ d\n  a\n- /c\\d/\n- avg 2.5  (numbers ± 0.01)\n+ avg 2.6\n  end\n+ extra\n"
+        );
+        let rules_broken: Vec<_> = report.failures.iter().map(|f| (f.rule, f.line)).collect();
+        assert_eq!(
+            rules_broken,
+            [(Some(2), 3), (Some(2), 2), (Some(3), 5), (None, 7)]
+        );
+        assert_eq!(report.failures[2].reason, "2.6 is not within 0.01 of 2.5");
+
+        let report = check(&rules, "start\nb");
+        assert_eq!(
+            report.diff,
+            "  start\n  b\n- a\n- /c\\d/\n- avg 2.5  (numbers ± 0.01)\n- end\n"
+        );
+        assert_eq!(report.failures.len(), 4);
+    }
+
+    #[test]
+    fn unordered_lines_pair_up_even_when_patterns_overlap() {
+        // Greedy would give "x1" to the first pattern and leave "x" unmatched
+        let set = vec![
+            Line::Regex(regex::Regex::new("^(?:x.*)$").unwrap()),
+            Line::Exact("x1".to_string()),
+        ];
+        assert_eq!(match_lines(&set, &["x1", "x2"]), [Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn solutions_print_what_their_expected_toml_allows() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let scratch = std::env::temp_dir().join(format!("outcheck-{}", std::process::id()));
+        std::fs::create_dir_all(&scratch).unwrap();
+        let mut checked = 0;
+        for entry in std::fs::read_dir(root.join("problems")).unwrap() {
+            let dir = entry.unwrap().path();
+            let path = dir.join("expected.toml");
+            if !path.is_file() {
+                continue;
+            }
+            let rules = Rules::load(&path).unwrap();
+            let id = &dir.file_name().unwrap().to_string_lossy()[..3];
+            let solution = root.join(format!("solutions/{id}.rs"));
+            if !solution.is_file() {
+                continue;
+            }
+            let binary = scratch.join(id);
+            let built = Command::new("rustc")
+                .args(["--edition", "2021", "-o"])
+                .arg(&binary)
+                .arg(&solution)
+                .status()
+                .unwrap();
+            assert!(built.success(), "{id}");
+            let run = Command::new(&binary).output().unwrap();
+            let report = check(&rules, &String::from_utf8_lossy(&run.stdout));
+            assert!(report.passed, "{id}:\n{}", report.diff);
+            checked += 1;
+        }
+        std::fs::remove_dir_all(&scratch).unwrap();
+        assert!(checked >= 4, "{checked}");
+    }
+}
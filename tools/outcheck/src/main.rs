@@ -0,0 +1,61 @@
+//! `outcheck` -- check a program's output against an `expected.toml` and
+//! print a JSON report.
+//!
+//! Usage: outcheck RULES [OUTPUT | -]
+//!
+//! RULES is an exercise's `expected.toml`; the output is read from OUTPUT,
+//! or from stdin when it is `-` or left out.
+//!
+//! Exit status is 0 when the output follows every rule, 1 when it breaks
+//! any, and 2 on usage errors or a malformed RULES file.
+
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
+
+use outcheck::Rules;
+
+const USAGE: &str = "usage: outcheck RULES [OUTPUT | -]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("outcheck: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut args = std::env::args().skip(1);
+    let rules = match args.next().as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return Ok(true);
+        }
+        Some(path) => Rules::load(Path::new(path)).map_err(|e| e.to_string())?,
+    };
+    let output = match args.next().as_deref() {
+        None | Some("-") => {
+            let mut buf = Vec::new();
+            io::stdin()
+                .read_to_end(&mut buf)
+                .map_err(|e| e.to_string())?;
+            String::from_utf8_lossy(&buf).into_owned()
+        }
+        Some(path) => std::fs::read(path)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .map_err(|e| format!("{path}: {e}"))?,
+    };
+    if let Some(arg) = args.next() {
+        return Err(format!("unexpected argument `{arg}`\n{USAGE}"));
+    }
+
+    let report = outcheck::check(&rules, &output);
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(report.passed)
+}
@@ -0,0 +1,287 @@
+//! `expected.toml`: what an exercise's program must print.
+//!
+//! The file is a list of `[[rule]]` tables, matched against the output in
+//! order. Each has one of:
+//!
+//! - `exact`: the line itself. A multi-line string is one rule per line.
+//! - `regex`: a pattern the whole line must match.
+//! - `approx`: the line with every number allowed to be off by up to
+//!   `tolerance`; the text around the numbers must be exact. Multi-line,
+//!   like `exact`.
+//! - `unordered`: as many lines as the list has entries, in any order.
+//!   An entry is a string for an exact line, or an inline table with a
+//!   `regex` or `approx` key.
+//!
+//! A top-level `tolerance` sets the default for `approx` (1e-6 otherwise).
+//! Trailing whitespace is ignored on both sides.
+
+use std::io;
+use std::path::Path;
+
+use regex::Regex;
+use serde::Deserialize;
+
+/// Allowed error of an `approx` number when neither the rule nor the file
+/// gives one.
+pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
+/// Matches one line of output.
+#[derive(Debug, Clone)]
+pub enum Line {
+    Exact(String),
+    /// Anchored at both ends.
+    Regex(Regex),
+    Approx {
+        text: String,
+        tolerance: f64,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Line(Line),
+    Unordered(Vec<Line>),
+}
+
+/// The rules of one file, in output order.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    /// Each rule with the 1-based index of the `[[rule]]` it came from.
+    pub rules: Vec<(usize, Rule)>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRules {
+    tolerance: Option<f64>,
+    #[serde(default)]
+    rule: Vec<RawRule>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRule {
+    exact: Option<String>,
+    regex: Option<String>,
+    approx: Option<String>,
+    unordered: Option<Vec<RawEntry>>,
+    tolerance: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawEntry {
+    Exact(String),
+    Rule(RawRule),
+}
+
+impl Rules {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let raw = std::fs::read_to_string(path)?;
+        Rules::parse(&raw).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {e}", path.display()),
+            )
+        })
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let file: RawRules = toml::from_str(raw).map_err(|e| e.to_string())?;
+        let default = file.tolerance.unwrap_or(DEFAULT_TOLERANCE);
+        let mut rules = Vec::new();
+        for (i, raw) in file.rule.into_iter().enumerate() {
+            let index = i + 1;
+            let fail = |e: String| format!("rule {index}: {e}");
+            match raw.unordered {
+                Some(entries) => {
+                    if raw.exact.is_some() || raw.regex.is_some() || raw.approx.is_some() {
+                        return Err(fail(
+                            "`unordered` cannot be combined with another rule".into(),
+                        ));
+                    }
+                    let tolerance = raw.tolerance.unwrap_or(default);
+                    let mut lines = Vec::new();
+                    for entry in entries {
+                        let mut expanded = match entry {
+                            RawEntry::Exact(line) => lines_of(&line, Line::Exact),
+                            RawEntry::Rule(rule) if rule.unordered.is_some() => {
+                                return Err(fail("`unordered` entries cannot nest".into()))
+                            }
+                            RawEntry::Rule(rule) => line_rules(rule, tolerance).map_err(fail)?,
+                        };
+                        if expanded.len() != 1 {
+                            return Err(fail("each `unordered` entry is a single line".into()));
+                        }
+                        lines.append(&mut expanded);
+                    }
+                    rules.push((index, Rule::Unordered(lines)));
+                }
+                None => {
+                    let lines = line_rules(raw, default).map_err(fail)?;
+                    rules.extend(lines.into_iter().map(|line| (index, Rule::Line(line))));
+                }
+            }
+        }
+        Ok(Rules { rules })
+    }
+}
+
+/// The lines an `exact`, `regex` or `approx` rule expands to.
+fn line_rules(raw: RawRule, default: f64) -> Result<Vec<Line>, String> {
+    let tolerance = raw.tolerance.unwrap_or(default);
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(format!(
+            "`tolerance` must be a non-negative number, not {tolerance}"
+        ));
+    }
+    match (raw.exact, raw.regex, raw.approx) {
+        (Some(text), None, None) => Ok(lines_of(&text, Line::Exact)),
+        (None, Some(pattern), None) => {
+            if pattern.contains('\n') {
+                return Err("a `regex` matches a single line".to_string());
+            }
+            let regex = Regex::new(&format!("^(?:{pattern})$")).map_err(|e| e.to_string())?;
+            Ok(vec![Line::Regex(regex)])
+        }
+        (None, None, Some(text)) => Ok(lines_of(&text, |text| Line::Approx { text, tolerance })),
+        (None, None, None) => {
+            Err("needs one of `exact`, `regex`, `approx` or `unordered`".to_string())
+        }
+        _ => Err("has more than one of `exact`, `regex` and `approx`".to_string()),
+    }
+}
+
+/// One rule per line of `text`, without its trailing whitespace. A
+/// trailing newline, as TOML's multi-line strings tend to end with, does
+/// not add an empty line.
+fn lines_of(text: &str, rule: impl Fn(String) -> Line) -> Vec<Line> {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    text.split('\n')
+        .map(|l| rule(l.trim_end().to_string()))
+        .collect()
+}
+
+impl Line {
+    /// Why `actual` does not match, or `None` if it does.
+    pub fn mismatch(&self, actual: &str) -> Option<String> {
+        match self {
+            Line::Exact(text) if text == actual => None,
+            Line::Exact(_) => Some("differs".to_string()),
+            Line::Regex(regex) if regex.is_match(actual) => None,
+            Line::Regex(_) => Some("does not match the pattern".to_string()),
+            Line::Approx { text, tolerance } => approx_mismatch(text, actual, *tolerance),
+        }
+    }
+
+    /// The line as a diff shows it.
+    pub fn describe(&self) -> String {
+        match self {
+            Line::Exact(text) => text.clone(),
+            Line::Regex(regex) => {
+                let anchored = regex.as_str();
+                format!("/{}/", &anchored[4..anchored.len() - 2])
+            }
+            Line::Approx { text, tolerance } => format!("{text}  (numbers ± {tolerance})"),
+        }
+    }
+}
+
+fn approx_mismatch(expected: &str, actual: &str, tolerance: f64) -> Option<String> {
+    let (expected_text, expected_numbers) = split_numbers(expected);
+    let (actual_text, actual_numbers) = split_numbers(actual);
+    if expected_text != actual_text || expected_numbers.len() != actual_numbers.len() {
+        return Some("differs apart from the numbers".to_string());
+    }
+    expected_numbers
+        .iter()
+        .zip(&actual_numbers)
+        .find(|((_, e), (_, a))| {
+            let error = (a - e).abs();
+            error.is_nan() || error > tolerance
+        })
+        .map(|((e, _), (a, _))| format!("{a} is not within {tolerance} of {e}"))
+}
+
+/// `line` with each number replaced by a placeholder, and the numbers.
+fn split_numbers(line: &str) -> (String, Vec<(&str, f64)>) {
+    let number = number_pattern();
+    let mut text = String::new();
+    let mut numbers = Vec::new();
+    let mut last = 0;
+    for m in number.find_iter(line) {
+        // A sign right after a digit or letter is an operator, as in `2-1`
+        let (start, raw) = match m.as_str().as_bytes()[0] {
+            b'-' | b'+' if line[..m.start()].ends_with(|c: char| c.is_alphanumeric()) => {
+                (m.start() + 1, &m.as_str()[1..])
+            }
+            _ => (m.start(), m.as_str()),
+        };
+        text.push_str(&line[last..start]);
+        text.push('\u{0}');
+        numbers.push((raw, raw.parse().unwrap_or(f64::NAN)));
+        last = m.end();
+    }
+    text.push_str(&line[last..]);
+    (text, numbers)
+}
+
+fn number_pattern() -> &'static Regex {
+    static NUMBER: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
+    NUMBER.get_or_init(|| Regex::new(r"[-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?").unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_match_within_the_tolerance_and_text_exactly() {
+        let line = |text: &str| Line::Approx {
+            text: text.to_string(),
+            tolerance: 0.001,
+        };
+        let tax = line("With tax: [108.0, -54.0, 216.0]");
+        assert_eq!(
+            tax.mismatch("With tax: [108.00000000000001, -54.0, 216.00000000000003]"),
+            None
+        );
+        assert_eq!(
+            tax.mismatch("With tax: [108.0, -54.5, 216.0]").as_deref(),
+            Some("-54.5 is not within 0.001 of -54.0")
+        );
+        assert!(tax.mismatch("With tax: [108.0, -54.0]").is_some());
+        assert!(tax.mismatch("Tax: [108.0, -54.0, 216.0]").is_some());
+        assert_eq!(line("Average: 2.50").mismatch("Average: 2.5"), None);
+        assert_eq!(line("1e3 and 3-1").mismatch("1000 and 3-1.0005"), None);
+        assert!(line("x").mismatch("x NaN").is_some());
+    }
+
+    #[test]
+    fn rules_expand_and_bad_files_are_rejected() {
+        let rules = Rules::parse(
+            "tolerance = 0.5\n\
+             [[rule]]\nexact = \"\"\"\na\nb  \n\"\"\"\n\
+             [[rule]]\nunordered = [\"x\", { regex = 'y\\d' }, { approx = \"z 1\" }]\n",
+        )
+        .unwrap();
+        assert_eq!(rules.rules.len(), 3);
+        assert!(matches!(&rules.rules[1], (1, Rule::Line(Line::Exact(b))) if b == "b"));
+        let (2, Rule::Unordered(set)) = &rules.rules[2] else {
+            panic!("{rules:?}");
+        };
+        assert_eq!(set[1].describe(), "/y\\d/");
+        assert!(matches!(set[2], Line::Approx { tolerance, .. } if tolerance == 0.5));
+
+        for bad in [
+            "[[rule]]\nexact = \"a\"\nregex = \"b\"",
+            "[[rule]]\ntolerance = 1.0",
+            "[[rule]]\nregex = \"(\"",
+            "[[rule]]\nunordered = [\"a\\nb\"]",
+            "[[rule]]\nexcat = \"a\"",
+            "[[rule]]\napprox = \"1\"\ntolerance = -1.0",
+        ] {
+            assert!(Rules::parse(bad).is_err(), "{bad}");
+        }
+    }
+}
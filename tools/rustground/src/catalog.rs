@@ -0,0 +1,201 @@
+//! Exercise metadata from `web/exercise_data.json`.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Catalog file, relative to the repository root.
+pub const CATALOG_FILE: &str = "web/exercise_data.json";
+
+/// One exercise as the web app describes it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Exercise {
+    /// Three-digit number, e.g. `"042"`. Filled in from the catalog key.
+    #[serde(skip)]
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    /// 1 (easiest) to 5.
+    pub difficulty: u8,
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub time_estimate: String,
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+}
+
+impl Exercise {
+    /// The file learners start from: `NNN_exercise/template.rs`, the
+    /// `src/lib.rs` of exercises that are multi-file crates, or the
+    /// `src/main.rs` of exercises that only exist as a Cargo project.
+    pub fn template(&self, root: &Path) -> PathBuf {
+        let template = self.dir(root).join("template.rs");
+        if template.is_file() {
+            return template;
+        }
+        if let Some(dir) = self.crate_dir(root) {
+            return dir.join("src/lib.rs");
+        }
+        root.join("problems").join(format!("{}_project/src/main.rs", self.id))
+    }
+
+    /// `NNN_exercise` when the exercise is a multi-file Cargo crate.
+    pub fn crate_dir(&self, root: &Path) -> Option<PathBuf> {
+        Some(self.dir(root)).filter(|dir| dir.join("Cargo.toml").is_file())
+    }
+
+    /// The extra test module graded alongside the template's, if any.
+    pub fn hidden_tests(&self, root: &Path) -> Option<PathBuf> {
+        Some(self.dir(root).join("tests.rs")).filter(|p| p.is_file())
+    }
+
+    /// The exercise's property tests (see `grader::property`), if any.
+    pub fn property_tests(&self, root: &Path) -> Option<PathBuf> {
+        Some(self.dir(root).join("properties.rs")).filter(|p| p.is_file())
+    }
+
+    /// The rules its program's output must follow (see `outcheck`), if any.
+    pub fn expected_output(&self, root: &Path) -> Option<PathBuf> {
+        Some(self.dir(root).join("expected.toml")).filter(|p| p.is_file())
+    }
+
+    fn dir(&self, root: &Path) -> PathBuf {
+        root.join("problems").join(format!("{}_exercise", self.id))
+    }
+}
+
+/// All exercises, sorted by number.
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    exercises: Vec<Exercise>,
+}
+
+impl Catalog {
+    pub fn load(root: &Path) -> io::Result<Self> {
+        let raw = std::fs::read_to_string(root.join(CATALOG_FILE))?;
+        Self::parse(&raw).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Parses the catalog: top-level sections (`exercises`, `advanced`,
+    /// ...) each mapping exercise numbers to their metadata.
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        let sections: BTreeMap<String, BTreeMap<String, Exercise>> = serde_json::from_str(json)?;
+        let mut exercises: Vec<Exercise> = sections
+            .into_values()
+            .flatten()
+            .map(|(id, exercise)| Exercise { id, ..exercise })
+            .collect();
+        exercises.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(Catalog { exercises })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Exercise> {
+        self.exercises
+            .binary_search_by(|e| e.id.as_str().cmp(id))
+            .ok()
+            .map(|i| &self.exercises[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Exercise> {
+        self.exercises.iter()
+    }
+
+    /// Every exercise after all of its prerequisites, lowest number first
+    /// among those that are ready. Unknown prerequisites are ignored; any
+    /// exercises caught in a cycle come last, by number.
+    pub fn in_prerequisite_order(&self) -> Vec<&Exercise> {
+        let mut waiting_on: HashMap<&str, usize> = HashMap::new();
+        let mut unlocks: HashMap<&str, Vec<&Exercise>> = HashMap::new();
+        for exercise in &self.exercises {
+            let known = exercise.prerequisites.iter().filter(|p| self.get(p).is_some());
+            for prerequisite in known {
+                *waiting_on.entry(&exercise.id).or_default() += 1;
+                unlocks.entry(prerequisite).or_default().push(exercise);
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<&str>> = self
+            .exercises
+            .iter()
+            .filter(|e| !waiting_on.contains_key(e.id.as_str()))
+            .map(|e| Reverse(e.id.as_str()))
+            .collect();
+        let mut order = Vec::with_capacity(self.exercises.len());
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(self.get(id).expect("ids come from the catalog"));
+            for next in unlocks.get(id).into_iter().flatten() {
+                let count = waiting_on.get_mut(next.id.as_str()).expect("counted above");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(Reverse(&next.id));
+                }
+            }
+        }
+
+        if order.len() < self.exercises.len() {
+            let placed: Vec<&str> = order.iter().map(|e| e.id.as_str()).collect();
+            let stuck = self.exercises.iter().filter(|e| !placed.contains(&e.id.as_str()));
+            order.extend(stuck.collect::<Vec<_>>());
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, prerequisites: &[&str]) -> String {
+        format!(
+            r#"{{"name": "{name}", "category": "Basics", "difficulty": 1,
+                "prerequisites": {prerequisites:?}}}"#
+        )
+    }
+
+    #[test]
+    fn flattens_sections_and_sorts_by_number() {
+        let json = format!(
+            r#"{{"advanced": {{"101": {}}}, "exercises": {{"002": {}, "001": {}}}}}"#,
+            entry("Bubble Sort", &["002"]),
+            entry("Mutable Variables", &["001"]),
+            entry("Hello", &[]),
+        );
+        let catalog = Catalog::parse(&json).unwrap();
+        let ids: Vec<_> = catalog.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["001", "002", "101"]);
+        assert_eq!(catalog.get("101").unwrap().name, "Bubble Sort");
+        assert!(catalog.get("003").is_none());
+    }
+
+    #[test]
+    fn prerequisites_come_first() {
+        let json = format!(
+            r#"{{"exercises": {{"001": {}, "002": {}, "003": {}, "004": {}, "005": {}}}}}"#,
+            entry("a", &["004"]),
+            entry("b", &[]),
+            entry("c", &["002", "999"]),
+            entry("d", &[]),
+            entry("e", &["005"]),
+        );
+        let catalog = Catalog::parse(&json).unwrap();
+        let order: Vec<_> = catalog.in_prerequisite_order().iter().map(|e| e.id.as_str()).collect();
+        // 005 depends on itself and can never be ready.
+        assert_eq!(order, ["002", "003", "004", "001", "005"]);
+    }
+
+    #[test]
+    fn repository_catalog_covers_every_exercise() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let catalog = Catalog::load(&root).unwrap();
+        assert_eq!(catalog.iter().count(), 325);
+        for exercise in catalog.iter() {
+            assert!(exercise.template(&root).is_file(), "{}", exercise.id);
+            assert!((1..=5).contains(&exercise.difficulty), "{}", exercise.id);
+        }
+        assert_eq!(catalog.in_prerequisite_order().len(), 325);
+    }
+}
@@ -0,0 +1,269 @@
+//! Compiling and testing a learner's working copy.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use grader::compile::{compile_binary, compile_tests};
+use grader::{doctest, property};
+use grader::splice::{self, HIDDEN_MODULE, PROPERTY_MODULE};
+use grader::{CompileError, Crate, Options, Report, ScratchDir};
+
+use crate::catalog::Exercise;
+
+/// Exercises without tests pass when their program exits successfully
+/// within this long.
+const RUN_TIMEOUT: Duration = Duration::from_secs(10);
+/// Program output is truncated to this many bytes per stream.
+const MAX_OUTPUT: usize = 65_536;
+
+/// What checking a working copy found.
+#[derive(Debug)]
+pub enum Verdict {
+    /// The copy is still the template as handed out, or as it was before
+    /// hint blocks were stripped from copies.
+    Untouched,
+    /// rustc rejected the copy; diagnostics point at the working copy.
+    CompileError(String),
+    /// The template's tests (and any hidden ones) ran against the copy.
+    Tests(Report),
+    /// The exercise has no tests, so its program was run instead.
+    Run {
+        success: bool,
+        timed_out: bool,
+        stdout: String,
+        stderr: String,
+        /// How the output fared against the exercise's `expected.toml`,
+        /// if it has one and the program finished.
+        output: Option<outcheck::Report>,
+    },
+}
+
+impl Verdict {
+    pub fn passed(&self) -> bool {
+        match self {
+            Verdict::Untouched | Verdict::CompileError(_) => false,
+            Verdict::Tests(report) => report.all_passed(),
+            Verdict::Run { success, .. } => *success,
+        }
+    }
+}
+
+/// Checks the working copy at `copy` of `exercise`.
+///
+/// Exercises whose template has test modules are graded against those
+/// tests, plus the hidden and property tests if the exercise has any; the
+/// learner's own test modules are replaced. Other exercises only need to
+/// build and run successfully, printing what their `expected.toml` allows
+/// if they have one. A multi-file exercise's copy is a crate
+/// directory, built with cargo and graded the same way; exercise 100 also
+/// grades its doc tests.
+pub fn check(root: &Path, exercise: &Exercise, copy: &Path) -> io::Result<Verdict> {
+    if let Some(dir) = exercise.crate_dir(root) {
+        return check_crate(root, exercise, &dir, copy);
+    }
+    let template = std::fs::read_to_string(exercise.template(root))?;
+    let source = std::fs::read_to_string(copy)?;
+    if source == template || source == crate::without_hints(&template)? {
+        return Ok(Verdict::Untouched);
+    }
+
+    let scratch = ScratchDir::new("rustground")?;
+    let relabel = |diagnostics: String| {
+        let compiled = scratch.path().join("main.rs");
+        diagnostics.replace(&*compiled.to_string_lossy(), &copy.to_string_lossy())
+    };
+    let timeout = Options::default().compile_timeout;
+
+    if splice::test_modules(&template).is_empty() {
+        let binary = match compile_binary(&source, scratch.path(), timeout) {
+            Ok(binary) => binary,
+            Err(CompileError::Io(err)) => return Err(err),
+            Err(err) => return Ok(Verdict::CompileError(relabel(err.to_string()))),
+        };
+        let run = grader::process::run_with_timeout(
+            Command::new(binary).current_dir(scratch.path()),
+            RUN_TIMEOUT,
+            MAX_OUTPUT,
+        )?;
+        let output = match exercise.expected_output(root) {
+            Some(rules) if run.success() => {
+                Some(outcheck::check(&outcheck::Rules::load(&rules)?, &run.stdout))
+            }
+            _ => None,
+        };
+        return Ok(Verdict::Run {
+            success: run.success() && output.as_ref().is_none_or(|o| o.passed),
+            timed_out: run.timed_out(),
+            stdout: run.stdout,
+            stderr: run.stderr,
+            output,
+        });
+    }
+
+    let mut graded = splice::with_tests_from(&source, &template);
+    if let Some(hidden) = exercise.hidden_tests(root) {
+        let hidden = std::fs::read_to_string(hidden)?;
+        graded = splice::append_test_module(&graded, HIDDEN_MODULE, &hidden);
+    }
+    if let Some(properties) = exercise.property_tests(root) {
+        let properties = property::test_module(&std::fs::read_to_string(properties)?);
+        graded = splice::append_test_module(&graded, PROPERTY_MODULE, &properties);
+    }
+    let opts = Options {
+        exercise: Some(exercise.id.clone()),
+        ..Options::default()
+    };
+    let binary = match compile_tests(&graded, scratch.path(), timeout) {
+        Ok(binary) => binary,
+        Err(CompileError::Io(err)) => return Err(err),
+        Err(err) => return Ok(Verdict::CompileError(relabel(err.to_string()))),
+    };
+    grader::run_all(&binary, &opts).map(Verdict::Tests)
+}
+
+fn check_crate(root: &Path, exercise: &Exercise, dir: &Path, copy: &Path) -> io::Result<Verdict> {
+    let template = Crate::read(dir)?;
+    let source = Crate::read(copy)?;
+    let handed_out = template
+        .files
+        .iter()
+        .map(|(path, file)| Ok((path.clone(), crate::without_hints(file)?)))
+        .collect::<io::Result<_>>()?;
+    if source == template || source == (Crate { files: handed_out }) {
+        return Ok(Verdict::Untouched);
+    }
+
+    let mut graded = source.with_tests_from(&template);
+    if let Some(hidden) = exercise.hidden_tests(root) {
+        graded.append_test_module(HIDDEN_MODULE, &std::fs::read_to_string(hidden)?);
+    }
+    if let Some(properties) = exercise.property_tests(root) {
+        graded.append_test_module(PROPERTY_MODULE, &property::test_module(&std::fs::read_to_string(properties)?));
+    }
+    let opts = Options {
+        exercise: Some(exercise.id.clone()),
+        doc_tests: doctest::EXERCISES.contains(&exercise.id.as_str()),
+        ..Options::default()
+    };
+    let report = grader::grade_crate(&graded, &opts)?;
+    match report.compile_error {
+        // cargo names files from the crate root
+        Some(diagnostics) => {
+            let src = format!("{}/src/", copy.display());
+            Ok(Verdict::CompileError(diagnostics.replace("--> src/", &format!("--> {src}"))))
+        }
+        None => Ok(Verdict::Tests(report)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::Catalog;
+    use std::path::PathBuf;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    }
+
+    fn check_source(id: &str, source: &str) -> Verdict {
+        let root = root();
+        let catalog = Catalog::load(&root).unwrap();
+        let scratch = ScratchDir::new("rustground-check").unwrap();
+        let copy = scratch.path().join(format!("{id}.rs"));
+        std::fs::write(&copy, source).unwrap();
+        check(&root, catalog.get(id).unwrap(), &copy).unwrap()
+    }
+
+    fn template(id: &str) -> String {
+        std::fs::read_to_string(root().join(format!("problems/{id}_exercise/template.rs"))).unwrap()
+    }
+
+    #[test]
+    fn untouched_templates_do_not_pass() {
+        let verdict = check_source("001", &template("001"));
+        assert!(matches!(verdict, Verdict::Untouched));
+        assert!(!verdict.passed());
+        let handed_out = crate::without_hints(&template("001")).unwrap();
+        assert!(matches!(check_source("001", &handed_out), Verdict::Untouched));
+    }
+
+    #[test]
+    fn programs_without_tests_pass_when_they_run() {
+        let verdict = check_source("001", "fn main() { println!(\"Hello, Rust!\"); }\n");
+        assert!(verdict.passed(), "{verdict:?}");
+
+        let verdict = check_source("001", "fn main() { panic!(\"no\"); }\n");
+        assert!(matches!(&verdict, Verdict::Run { success: false, timed_out: false, .. }));
+    }
+
+    #[test]
+    fn programs_must_print_what_their_expected_toml_allows() {
+        let solution = std::fs::read_to_string(root().join("solutions/050.rs")).unwrap();
+        let verdict = check_source("050", &solution);
+        assert!(verdict.passed(), "{verdict:?}");
+
+        let verdict = check_source("050", &solution.replacen("println!", "print!", 1));
+        let Verdict::Run { success: false, output: Some(report), .. } = &verdict else {
+            panic!("expected an output mismatch: {verdict:?}");
+        };
+        assert!(report.diff.contains("\n+ "), "{}", report.diff);
+    }
+
+    #[test]
+    fn compile_errors_point_at_the_working_copy() {
+        let Verdict::CompileError(diagnostics) = check_source("001", "fn main() { let x: u8 = \"\"; }\n")
+        else {
+            panic!("expected a compile error");
+        };
+        assert!(diagnostics.contains("001.rs:1:"), "{diagnostics}");
+        assert!(!diagnostics.contains("main.rs"), "{diagnostics}");
+    }
+
+    #[test]
+    fn exercises_with_tests_are_graded_against_the_template() {
+        // The learner's own (empty) test module is replaced by the
+        // template's, and the hidden and property tests for 101 come along too.
+        let solution = std::fs::read_to_string(root().join("solutions/101.rs")).unwrap();
+        let Verdict::Tests(report) = check_source("101", &splice::strip_tests(&solution)) else {
+            panic!("expected a test report");
+        };
+        assert!(report.all_passed(), "{report:?}");
+        assert!(report.tests.iter().any(|t| t.name.starts_with("hidden_tests::")));
+        assert!(report.tests.iter().any(|t| t.name.starts_with("property_tests::")));
+        assert!(report.tests.iter().any(|t| t.name.starts_with("tests::")));
+    }
+
+    #[test]
+    fn crate_exercises_are_copied_and_graded_as_crates() {
+        let root = root();
+        let catalog = Catalog::load(&root).unwrap();
+        let exercise = catalog.get("097").unwrap();
+        let dir = exercise.crate_dir(&root).unwrap();
+        let scratch = ScratchDir::new("rustground-crate").unwrap();
+        let copy = scratch.path().join("097");
+        let write = |path: &str, source: &str| {
+            let path = copy.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, source).unwrap();
+        };
+        for (path, source) in Crate::read(&dir).unwrap().files {
+            write(&path, &source);
+        }
+        assert!(matches!(check(&root, exercise, &copy).unwrap(), Verdict::Untouched));
+
+        write("src/garden/vegetables.rs", "pub struct Tomato;\n");
+        let Verdict::CompileError(diagnostics) = check(&root, exercise, &copy).unwrap() else {
+            panic!("expected a compile error");
+        };
+        assert!(diagnostics.contains(&format!("--> {}/src/", copy.display())), "{diagnostics}");
+
+        for (path, source) in Crate::read(&root.join("solutions/097")).unwrap().files {
+            write(&path, &source);
+        }
+        let verdict = check(&root, exercise, &copy).unwrap();
+        assert!(verdict.passed(), "{verdict:?}");
+    }
+}
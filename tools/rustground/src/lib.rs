@@ -0,0 +1,35 @@
+//! An offline, rustlings-style way through the exercises.
+//!
+//! Learners work on copies of the templates under `learn/`, so the
+//! templates themselves stay pristine. Each copy is graded against its
+//! template's tests (and the hidden tests, where an exercise has them),
+//! or simply built and run when the template has no tests. Which
+//! exercises pass is recorded in `learn/progress.toml`, along with the
+//! failed attempts that unlock the templates' hints and the hints shown.
+
+pub mod catalog;
+pub mod check;
+pub mod progress;
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub use catalog::{Catalog, Exercise};
+pub use check::{check, Verdict};
+pub use progress::Progress;
+
+/// The repository root at or above `start`: the first directory holding
+/// both the exercise catalog and `problems/`.
+pub fn find_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join(catalog::CATALOG_FILE).is_file() && dir.join("problems").is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// `template` as learners get it: without its hint blocks.
+pub fn without_hints(template: &str) -> io::Result<String> {
+    hints::parse(template)
+        .map(|hints| hints.template)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
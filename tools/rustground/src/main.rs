@@ -0,0 +1,439 @@
+//! `rustground` -- work through the exercises offline, rustlings-style.
+//!
+//! Usage: rustground [--root DIR] <COMMAND>
+//!
+//!   list          every exercise with its difficulty and your progress
+//!   watch [NNN]   re-check an exercise on every save, moving on when it passes
+//!   verify        check your exercises in prerequisite order
+//!   hint [NNN]    print the hints your failed attempts have unlocked, and
+//!                 the HINT and BONUS notes of an exercise's template
+//!
+//! Working copies live in `learn/NNN.rs`, or in a `learn/NNN/` crate for
+//! the multi-file exercises, and progress in `learn/progress.toml`. The
+//! repository root is found by walking up from the current directory
+//! unless `--root` is given.
+//!
+//! Copies are made without the template's tiered hint blocks. Each save
+//! that fails its check counts as a failed attempt and may unlock the next
+//! tier; the progress file records the attempts and the tiers shown.
+//!
+//! Exit status is 0 on success, 1 when `verify` finds a failing exercise,
+//! and 2 on usage or internal errors.
+
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use grader::Status;
+use rustground::progress::{working_copy, LEARN_DIR};
+use rustground::{Catalog, Exercise, Progress, Verdict};
+
+const USAGE: &str = "usage: rustground [--root DIR] <list | watch [NNN] | verify | hint [NNN]>";
+
+/// How often `watch` looks for a new save.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("rustground: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut root = None;
+    let mut words = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ => words.push(arg),
+        }
+    }
+
+    let root = match root {
+        Some(root) => root,
+        None => {
+            let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+            rustground::find_root(&cwd)
+                .ok_or("not inside the repository; run from it or pass --root")?
+        }
+    };
+    let catalog = Catalog::load(&root).map_err(|e| format!("{}: {e}", root.display()))?;
+    let mut session = Session {
+        progress: Progress::load(&root).map_err(|e| e.to_string())?,
+        root,
+        catalog,
+    };
+
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    match words.as_slice() {
+        ["list"] => session.list(),
+        ["watch"] => session.watch(None),
+        ["watch", id] => session.watch(Some(id)),
+        ["verify"] => session.verify(),
+        ["hint"] => session.hint(None),
+        ["hint", id] => session.hint(Some(id)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+struct Session {
+    root: PathBuf,
+    catalog: Catalog,
+    progress: Progress,
+}
+
+impl Session {
+    fn list(&self) -> Result<bool, String> {
+        // Built up front so that `rustground list | head` does not panic
+        // on a closed pipe halfway through.
+        let mut out = format!("   {:<4} {:<40} {:<24} {:<6} STATUS\n", "#", "NAME", "CATEGORY", "LEVEL");
+        for exercise in self.catalog.iter() {
+            let current = self.progress.current.as_deref() == Some(exercise.id.as_str());
+            let status = if self.progress.is_done(&exercise.id) {
+                "done"
+            } else if working_copy(&self.root, exercise).exists() {
+                "started"
+            } else {
+                ""
+            };
+            out += &format!(
+                "{} {:<4} {:<40} {:<24} {:<6} {status}\n",
+                if current { ">" } else { " " },
+                exercise.id,
+                truncate(&exercise.name, 40),
+                truncate(&exercise.category, 24),
+                "★".repeat(exercise.difficulty.into()),
+            );
+        }
+        out += &format!("\n{}\n", self.tally());
+        let _ = io::stdout().lock().write_all(out.as_bytes());
+        Ok(true)
+    }
+
+    fn watch(&mut self, id: Option<&str>) -> Result<bool, String> {
+        let mut exercise = match id {
+            Some(id) => self.exercise(id)?.clone(),
+            None => match self.progress.current.as_deref().or_else(|| self.next_id()) {
+                Some(id) => self.exercise(id)?.clone(),
+                None => {
+                    println!("Every exercise is done.");
+                    return Ok(true);
+                }
+            },
+        };
+
+        // Commands typed while watching: h(int), q(uit), anything else re-checks.
+        let (tx, commands) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if tx.send(line.trim().to_string()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        loop {
+            let copy = self.start(&exercise)?;
+            println!("\n━━ {} {} ━━  editing {}", exercise.id, exercise.name, copy.display());
+            let verdict = rustground::check(&self.root, &exercise, &copy).map_err(|e| e.to_string())?;
+            print_verdict(&exercise, &verdict);
+            if verdict.passed() {
+                self.progress.set_done(&exercise.id, true);
+                let next = self.next_id().map(str::to_string);
+                self.progress.current = next.clone();
+                self.save()?;
+                match next {
+                    Some(id) => {
+                        println!("✓ {} passes. Next up: {id}.", exercise.id);
+                        exercise = self.exercise(&id)?.clone();
+                        continue;
+                    }
+                    None => {
+                        println!("✓ {} passes. That was the last exercise!", exercise.id);
+                        return Ok(true);
+                    }
+                }
+            }
+            self.record_failure(&exercise, &copy, &verdict)?;
+            println!("Save to re-check · h + Enter for hints · q + Enter to quit");
+
+            let seen = modified(&copy);
+            loop {
+                match commands.recv_timeout(POLL_INTERVAL) {
+                    Ok(cmd) if cmd == "q" => return Ok(true),
+                    Ok(cmd) if cmd == "h" => self.print_hints(&exercise)?,
+                    Ok(_) => break,
+                    // stdin is closed; keep watching the file alone.
+                    Err(RecvTimeoutError::Disconnected) => std::thread::sleep(POLL_INTERVAL),
+                    Err(RecvTimeoutError::Timeout) => {}
+                }
+                if modified(&copy) != seen {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn verify(&mut self) -> Result<bool, String> {
+        let order: Vec<Exercise> = self.catalog.in_prerequisite_order().into_iter().cloned().collect();
+        for exercise in &order {
+            let copy = working_copy(&self.root, exercise);
+            if !copy.exists() {
+                println!("{}. Next up: {} {}", self.tally(), exercise.id, exercise.name);
+                println!("Start it with `rustground watch {}`.", exercise.id);
+                self.progress.current = Some(exercise.id.clone());
+                self.save()?;
+                return Ok(true);
+            }
+            let verdict = rustground::check(&self.root, exercise, &copy).map_err(|e| e.to_string())?;
+            let passed = verdict.passed();
+            self.progress.set_done(&exercise.id, passed);
+            if !passed {
+                println!("━━ {} {} ━━", exercise.id, exercise.name);
+                print_verdict(exercise, &verdict);
+                self.record_failure(exercise, &copy, &verdict)?;
+                self.progress.current = Some(exercise.id.clone());
+                self.save()?;
+                return Ok(false);
+            }
+            println!("✓ {} {}", exercise.id, exercise.name);
+        }
+        self.progress.current = None;
+        self.save()?;
+        println!("Every exercise passes.");
+        Ok(true)
+    }
+
+    fn hint(&mut self, id: Option<&str>) -> Result<bool, String> {
+        let id = id
+            .or(self.progress.current.as_deref())
+            .ok_or("no current exercise; name one, e.g. `rustground hint 001`")?;
+        let exercise = self.exercise(id)?.clone();
+        self.print_hints(&exercise)?;
+        Ok(true)
+    }
+
+    /// Prints the hint tiers unlocked so far, recording them as shown, and
+    /// then the template's notes.
+    fn print_hints(&mut self, exercise: &Exercise) -> Result<(), String> {
+        let (template, tiered) = read_template(&self.root, exercise)?;
+        let notes = hints::notes::extract(&template);
+        if tiered.tiers.is_empty() && notes.is_empty() {
+            println!("{} has no hints.", exercise.id);
+        }
+
+        let failed = self.progress.failed(&exercise.id);
+        let mut shown = false;
+        for tier in tiered.unlocked(failed) {
+            println!("HINT {} for `{}`:", tier.number, tier.function);
+            for line in &tier.text {
+                println!("    {line}");
+            }
+            shown |= self.progress.record_hint(&exercise.id, &tier.id());
+        }
+        if let Some(after) = tiered.next_unlock(failed) {
+            let more = after - failed;
+            println!(
+                "Another hint unlocks after {more} more failed attempt{}.",
+                if more == 1 { "" } else { "s" }
+            );
+        }
+        if shown {
+            self.save()?;
+        }
+
+        for hint in notes {
+            println!("{} (line {}):", hint.kind, hint.line);
+            for line in &hint.text {
+                println!("    {line}");
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts a failed check of `copy` as an attempt at `exercise`, unless
+    /// it is untouched or the same save was counted already, and says so
+    /// when that unlocks a hint.
+    fn record_failure(&mut self, exercise: &Exercise, copy: &Path, verdict: &Verdict) -> Result<(), String> {
+        let saved = modified(copy).and_then(|time| time.duration_since(UNIX_EPOCH).ok());
+        let (Some(saved), false) = (saved, matches!(verdict, Verdict::Untouched)) else {
+            return Ok(());
+        };
+        let before = self.progress.failed(&exercise.id);
+        if !self.progress.record_failure(&exercise.id, saved.as_secs()) {
+            return Ok(());
+        }
+        self.save()?;
+
+        let (_, tiered) = read_template(&self.root, exercise)?;
+        if tiered.unlocked(before + 1).count() > tiered.unlocked(before).count() {
+            println!("A new hint is unlocked; `rustground hint {}` shows it.", exercise.id);
+        }
+        Ok(())
+    }
+
+    fn exercise(&self, id: &str) -> Result<&Exercise, String> {
+        // Accept `7` as well as `007`.
+        let id = id.parse::<u32>().map(|n| format!("{n:03}")).unwrap_or_else(|_| id.to_string());
+        self.catalog
+            .get(&id)
+            .ok_or_else(|| format!("there is no exercise {id}"))
+    }
+
+    fn tally(&self) -> String {
+        format!("{}/{} exercises done", self.progress.done.len(), self.catalog.iter().count())
+    }
+
+    /// The first exercise in prerequisite order that is not done yet.
+    fn next_id(&self) -> Option<&str> {
+        self.catalog
+            .in_prerequisite_order()
+            .into_iter()
+            .find(|e| !self.progress.is_done(&e.id))
+            .map(|e| e.id.as_str())
+    }
+
+    /// Makes `exercise` the current one, copying its template into the
+    /// learner's directory unless a working copy already exists.
+    fn start(&mut self, exercise: &Exercise) -> Result<PathBuf, String> {
+        let copy = working_copy(&self.root, exercise);
+        if !copy.exists() {
+            std::fs::create_dir_all(self.root.join(LEARN_DIR)).map_err(|e| e.to_string())?;
+            let copied = match exercise.crate_dir(&self.root) {
+                Some(dir) => copy_crate(&dir, &copy),
+                None => copy_source(&exercise.template(&self.root), &copy),
+            };
+            copied.map_err(|e| format!("{}: {e}", copy.display()))?;
+        }
+        if self.progress.current.as_deref() != Some(exercise.id.as_str()) {
+            self.progress.current = Some(exercise.id.clone());
+            self.save()?;
+        }
+        Ok(copy)
+    }
+
+    fn save(&self) -> Result<(), String> {
+        self.progress.save(&self.root).map_err(|e| e.to_string())
+    }
+}
+
+fn print_verdict(exercise: &Exercise, verdict: &Verdict) {
+    match verdict {
+        Verdict::Untouched => {
+            println!("Not started yet: edit the file above and save to check it.");
+        }
+        Verdict::CompileError(diagnostics) => println!("{diagnostics}"),
+        Verdict::Tests(report) => {
+            for test in &report.tests {
+                match test.status {
+                    Status::Passed => println!("  ✓ {}", test.name),
+                    Status::Ignored => println!("  - {} (ignored)", test.name),
+                    Status::TimedOut => println!("  ✗ {} (timed out)", test.name),
+                    Status::Failed if test.hit_todo() => println!("  … {} (still todo!())", test.name),
+                    Status::Failed => match &test.panic_message {
+                        Some(message) => println!("  ✗ {}: {message}", test.name),
+                        None => println!("  ✗ {}", test.name),
+                    },
+                }
+            }
+            println!("{}/{} tests pass", report.passed, report.total);
+        }
+        Verdict::Run {
+            success,
+            timed_out,
+            stdout,
+            stderr,
+            output,
+        } => {
+            print!("{stdout}");
+            eprint!("{stderr}");
+            if *timed_out {
+                println!("{} did not finish in time", exercise.id);
+            } else if let Some(report) = output.as_ref().filter(|o| !o.passed) {
+                println!("{} printed something else than expected:", exercise.id);
+                print!("{}", report.diff);
+            } else if !success {
+                println!("{} exited with an error", exercise.id);
+            }
+        }
+    }
+}
+
+/// An exercise's template, and its hint tiers.
+fn read_template(root: &Path, exercise: &Exercise) -> Result<(String, hints::Hints), String> {
+    let path = exercise.template(root);
+    let template = std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    let tiered = hints::parse(&template).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok((template, tiered))
+}
+
+/// Copies a template's source without its hint blocks.
+fn copy_source(from: &Path, to: &Path) -> io::Result<()> {
+    let source = rustground::without_hints(&std::fs::read_to_string(from)?)?;
+    std::fs::write(to, source)
+}
+
+/// Copies an exercise crate's manifest and sources, but not its build
+/// output or hint blocks.
+fn copy_crate(from: &Path, to: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    std::fs::copy(from.join("Cargo.toml"), to.join("Cargo.toml"))?;
+    copy_dir(&from.join("src"), &to.join("src"))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+        } else if entry.path().extension().is_some_and(|ext| ext == "rs") {
+            copy_source(&entry.path(), &to.join(entry.file_name()))?;
+        } else {
+            std::fs::copy(entry.path(), to.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Modification time of `path`, or `None` while it is missing. For a
+/// working crate it is the latest of its source files.
+fn modified(path: &Path) -> Option<SystemTime> {
+    let meta = std::fs::metadata(path).ok()?;
+    if !meta.is_dir() {
+        return meta.modified().ok();
+    }
+    std::fs::read_dir(path)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.file_name() != "target")
+        .filter_map(|entry| modified(&entry.path()))
+        .max()
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(width - 1).collect();
+    short.push('…');
+    short
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
@@ -0,0 +1,174 @@
+//! The learner's progress, kept next to their working copies.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::Exercise;
+
+/// Directory under the repository root holding the learner's copies of
+/// the templates and their progress file. Ignored by git.
+pub const LEARN_DIR: &str = "learn";
+const PROGRESS_FILE: &str = "progress.toml";
+
+/// Which exercises are done and which one `watch` picks up next.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+    #[serde(default)]
+    pub done: BTreeSet<String>,
+    /// By exercise, for those with a failed attempt or a hint shown.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attempts: BTreeMap<String, Attempts>,
+}
+
+/// How much trying, and how much help, an exercise has taken so far.
+/// Instructors read these from the progress file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempts {
+    /// Failed checks, each save counting once however often it is checked.
+    #[serde(default)]
+    pub failed: u32,
+    /// Modification time of the last save counted, in seconds since the
+    /// Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_save: Option<u64>,
+    /// The hint tiers shown, as `function/tier`.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub hints: BTreeSet<String>,
+}
+
+impl Progress {
+    /// Reads the progress file, or starts afresh if there is none yet.
+    pub fn load(root: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path(root)) {
+            Ok(raw) => toml::from_str(&raw).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{PROGRESS_FILE}: {e}"))
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> io::Result<()> {
+        let raw = toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::create_dir_all(root.join(LEARN_DIR))?;
+        std::fs::write(path(root), raw)
+    }
+
+    pub fn is_done(&self, id: &str) -> bool {
+        self.done.contains(id)
+    }
+
+    /// Records whether `id` passes; returns whether that changed anything.
+    pub fn set_done(&mut self, id: &str, done: bool) -> bool {
+        if done {
+            self.done.insert(id.to_string())
+        } else {
+            self.done.remove(id)
+        }
+    }
+
+    /// How many failed attempts `id` has had.
+    pub fn failed(&self, id: &str) -> u32 {
+        self.attempts.get(id).map_or(0, |a| a.failed)
+    }
+
+    /// Records a failed check of the save of `id` made at `saved`, in
+    /// seconds since the Unix epoch; returns whether it counted, which it
+    /// does unless that save was already counted.
+    pub fn record_failure(&mut self, id: &str, saved: u64) -> bool {
+        let attempts = self.attempts.entry(id.to_string()).or_default();
+        if attempts.last_save == Some(saved) {
+            return false;
+        }
+        attempts.failed += 1;
+        attempts.last_save = Some(saved);
+        true
+    }
+
+    /// Records that the hint tier `hint` of `id` was shown; returns
+    /// whether it was the first time.
+    pub fn record_hint(&mut self, id: &str, hint: &str) -> bool {
+        self.attempts
+            .entry(id.to_string())
+            .or_default()
+            .hints
+            .insert(hint.to_string())
+    }
+}
+
+/// The learner's working copy of an exercise: `learn/NNN.rs`, or a
+/// `learn/NNN/` crate for the exercises that are multi-file crates.
+pub fn working_copy(root: &Path, exercise: &Exercise) -> PathBuf {
+    let name = match exercise.crate_dir(root) {
+        Some(_) => exercise.id.clone(),
+        None => format!("{}.rs", exercise.id),
+    };
+    root.join(LEARN_DIR).join(name)
+}
+
+fn path(root: &Path) -> PathBuf {
+    root.join(LEARN_DIR).join(PROGRESS_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_the_progress_file() {
+        let scratch = grader::ScratchDir::new("rustground-progress").unwrap();
+        let root = scratch.path();
+        assert_eq!(Progress::load(root).unwrap(), Progress::default());
+
+        let mut progress = Progress {
+            current: Some("003".into()),
+            ..Progress::default()
+        };
+        assert!(progress.set_done("002", true));
+        assert!(progress.set_done("001", true));
+        assert!(!progress.set_done("001", true));
+        progress.save(root).unwrap();
+
+        let raw = std::fs::read_to_string(root.join("learn/progress.toml")).unwrap();
+        assert_eq!(raw, "current = \"003\"\ndone = [\"001\", \"002\"]\n");
+        let mut loaded = Progress::load(root).unwrap();
+        assert_eq!(loaded, progress);
+        assert!(loaded.set_done("002", false));
+        assert!(!loaded.is_done("002") && loaded.is_done("001"));
+    }
+
+    #[test]
+    fn counts_each_failed_save_once_and_records_hints() {
+        let scratch = grader::ScratchDir::new("rustground-progress").unwrap();
+        let root = scratch.path();
+        let mut progress = Progress::default();
+        assert!(progress.record_failure("001", 100));
+        assert!(!progress.record_failure("001", 100));
+        assert!(progress.record_failure("001", 160));
+        assert!(progress.record_hint("001", "main/1"));
+        assert!(!progress.record_hint("001", "main/1"));
+        assert_eq!((progress.failed("001"), progress.failed("002")), (2, 0));
+        progress.save(root).unwrap();
+
+        let raw = std::fs::read_to_string(root.join("learn/progress.toml")).unwrap();
+        assert_eq!(
+            raw,
+            "done = []\n\n[attempts.001]\nfailed = 2\nlast_save = 160\nhints = [\"main/1\"]\n"
+        );
+        assert_eq!(Progress::load(root).unwrap(), progress);
+    }
+
+    #[test]
+    fn rejects_a_malformed_progress_file() {
+        let scratch = grader::ScratchDir::new("rustground-progress").unwrap();
+        std::fs::create_dir(scratch.path().join(LEARN_DIR)).unwrap();
+        std::fs::write(path(scratch.path()), "done = 3").unwrap();
+        let err = Progress::load(scratch.path()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,400 @@
+//! Confining the child between fork and exec.
+//!
+//! [`Jail::run`] and [`Jail::compile`] prepare every path, map and filter
+//! up front. The `pre_exec` hook runs in a forked copy of a possibly
+//! multi-threaded parent, so it only makes raw syscalls and never
+//! allocates.
+
+use std::ffi::{c_char, CStr, CString, OsString};
+use std::io;
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::Command;
+use std::ptr;
+
+use crate::outcome::Isolation;
+use crate::{seccomp, Limits};
+
+/// Uid and gid the program runs as: `nobody` inside its user namespace,
+/// or on the host when the sandbox itself was started as root.
+const NOBODY: u32 = 65534;
+
+/// Host directories bound read-only into the new root so the dynamically
+/// linked program can find its loader and libc.
+const SYSTEM_DIRS: &[&str] = &["/usr", "/lib", "/lib64"];
+
+/// Where the build directory with the binary is bound inside the new root:
+/// read-only for the run, writable for the compile.
+pub(crate) const BINARY_DIR: &str = "/sandbox";
+/// Writable, tmpfs-backed working directory inside the new root.
+const WORK_DIR: &str = "/work";
+
+/// Largest file rustc and the linker may write.
+const COMPILE_FILE_SIZE: u64 = 256 << 20;
+/// Most arguments a jailed program can take, since `enter` cannot allocate
+/// its argv.
+const MAX_ARGS: usize = 24;
+/// Most environment variables a jailed program can get.
+const MAX_ENV: usize = 4;
+
+const NAMESPACES: libc::c_int = libc::CLONE_NEWUSER
+    | libc::CLONE_NEWNS
+    | libc::CLONE_NEWNET
+    | libc::CLONE_NEWIPC
+    | libc::CLONE_NEWUTS;
+
+#[cfg(target_env = "gnu")]
+type Resource = libc::__rlimit_resource_t;
+#[cfg(not(target_env = "gnu"))]
+type Resource = libc::c_int;
+
+/// Everything the child needs to lock itself down and exec the program.
+pub(crate) struct Jail {
+    namespaces: Option<Namespaces>,
+    workdir: CString,
+    exec_path: CString,
+    /// The arguments after the program name.
+    args: Vec<CString>,
+    /// The whole environment, as `KEY=value`.
+    env: Vec<CString>,
+    rlimits: Vec<(Resource, libc::rlimit)>,
+    /// Switch to `nobody` before exec; only without a user namespace.
+    drop_root: bool,
+    /// None for the compiler, which has to fork and exec the linker.
+    filter: Option<Vec<libc::sock_filter>>,
+}
+
+struct Namespaces {
+    uid_map: Vec<u8>,
+    gid_map: Vec<u8>,
+    /// Empty host directory the tmpfs root is mounted on.
+    root: CString,
+    tmpfs_options: CString,
+    /// Mount points created inside the tmpfs, as host paths, parents first.
+    dirs: Vec<CString>,
+    binds: Vec<Bind>,
+}
+
+/// A bind mount. `remount` makes it read-only, repeating the source's
+/// locked flags, which an unprivileged remount may not clear; a writable
+/// bind has none.
+struct Bind {
+    source: CString,
+    target: CString,
+    remount: Option<libc::c_ulong>,
+}
+
+impl Jail {
+    /// Prepares a jail that runs the program at `binary`, using `scratch`
+    /// for the mount point of the new root or, without namespaces, as
+    /// working directory.
+    pub(crate) fn run(
+        binary: &Path,
+        scratch: &Path,
+        limits: &Limits,
+        isolation: Isolation,
+    ) -> io::Result<Self> {
+        let build_dir = binary.parent().unwrap_or(Path::new("/"));
+        let (namespaces, workdir, exec_path) = match isolation {
+            Isolation::Namespaces => {
+                let name = binary.file_name().unwrap_or_default();
+                let exec_path = Path::new(BINARY_DIR).join(name);
+                let binds = [(build_dir, Path::new(BINARY_DIR), false)];
+                let ns = Namespaces::new(scratch, &binds, limits)?;
+                (Some(ns), cstring(Path::new(WORK_DIR))?, cstring(&exec_path)?)
+            }
+            Isolation::SeccompOnly => {
+                let work = scratch.join("work");
+                std::fs::create_dir_all(&work)?;
+                (None, cstring(&work)?, cstring(binary)?)
+            }
+        };
+
+        let cpu = limits.run_timeout.as_secs().max(1);
+        let rlimits = vec![
+            (libc::RLIMIT_CPU, rlimit(cpu, cpu + 1)),
+            (libc::RLIMIT_AS, rlimit(limits.memory, limits.memory)),
+            (libc::RLIMIT_FSIZE, rlimit(limits.file_size, limits.file_size)),
+            (libc::RLIMIT_NPROC, rlimit(limits.processes, limits.processes)),
+            (libc::RLIMIT_NOFILE, rlimit(64, 64)),
+            (libc::RLIMIT_CORE, rlimit(0, 0)),
+        ];
+        let filter = seccomp::filter(exec_path.as_ptr(), namespaces.is_none());
+        Ok(Jail {
+            drop_root: namespaces.is_none() && unsafe { libc::geteuid() } == 0,
+            namespaces,
+            workdir,
+            exec_path,
+            args: Vec::new(),
+            env: Vec::new(),
+            rlimits,
+            filter: Some(filter),
+        })
+    }
+
+    /// Prepares a jail that runs `rustc` from `sysroot` on the source in
+    /// `build_dir`, which it writes the binary to. `args(dir)` gives the
+    /// compiler's arguments for the build directory at `dir`.
+    ///
+    /// With namespaces, only the system directories, the read-only
+    /// sysroot and the build directory are visible. Either way the
+    /// compiler gets an empty environment apart from `PATH`, `TMPDIR` and
+    /// `LD_LIBRARY_PATH`,
+    /// the build directory as working directory, and rlimits.
+    pub(crate) fn compile(
+        sysroot: &Path,
+        build_dir: &Path,
+        args: impl Fn(&Path) -> Vec<OsString>,
+        scratch: &Path,
+        limits: &Limits,
+        isolation: Isolation,
+    ) -> io::Result<Self> {
+        let rustc = sysroot.join("bin/rustc");
+        let (namespaces, dir) = match isolation {
+            Isolation::Namespaces => {
+                let mut binds = vec![(build_dir, Path::new(BINARY_DIR), true)];
+                // A sysroot under /usr is visible already
+                if !SYSTEM_DIRS.iter().any(|dir| sysroot.starts_with(dir)) {
+                    binds.push((sysroot, sysroot, false));
+                }
+                let ns = Namespaces::new(scratch, &binds, limits)?;
+                (Some(ns), Path::new(BINARY_DIR))
+            }
+            Isolation::SeccompOnly => (None, build_dir),
+        };
+        let drop_root = namespaces.is_none() && unsafe { libc::geteuid() } == 0;
+        if drop_root {
+            // nobody has to write the binary
+            std::os::unix::fs::chown(build_dir, Some(NOBODY), Some(NOBODY))?;
+        }
+
+        let args = args(dir)
+            .into_iter()
+            .map(|arg| CString::new(arg.into_vec()))
+            .collect::<Result<Vec<_>, _>>()?;
+        // The loader resolves rustc's `$ORIGIN` runpath through /proc,
+        // which the jail lacks, so point it at the libraries as rustup does
+        let env = [
+            "PATH=/usr/bin:/bin".to_string(),
+            format!("TMPDIR={}", dir.display()),
+            format!("LD_LIBRARY_PATH={}", sysroot.join("lib").display()),
+        ];
+        let cpu = limits.compile_timeout.as_secs().max(1);
+        let memory = limits.compile_memory;
+        let rlimits = vec![
+            (libc::RLIMIT_CPU, rlimit(cpu, cpu + 1)),
+            (libc::RLIMIT_AS, rlimit(memory, memory)),
+            (libc::RLIMIT_FSIZE, rlimit(COMPILE_FILE_SIZE, COMPILE_FILE_SIZE)),
+            (libc::RLIMIT_NPROC, rlimit(limits.processes, limits.processes)),
+            (libc::RLIMIT_NOFILE, rlimit(1024, 1024)),
+            (libc::RLIMIT_CORE, rlimit(0, 0)),
+        ];
+        if args.len() > MAX_ARGS {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many compiler arguments"));
+        }
+        Ok(Jail {
+            namespaces,
+            workdir: cstring(dir)?,
+            exec_path: cstring(&rustc)?,
+            args,
+            env: env.into_iter().map(CString::new).collect::<Result<_, _>>()?,
+            rlimits,
+            drop_root,
+            filter: None,
+        })
+    }
+
+    /// Makes `cmd` enter the jail after forking. The jail execs the
+    /// program itself, so the program `cmd` names is never run.
+    pub(crate) fn install(mut self, cmd: &mut Command) {
+        // SAFETY: `enter` only makes async-signal-safe syscalls on data
+        // prepared before the fork.
+        unsafe {
+            cmd.pre_exec(move || self.enter());
+        }
+    }
+
+    fn enter(&mut self) -> io::Result<()> {
+        unsafe {
+            if let Some(ns) = &self.namespaces {
+                ns.enter()?;
+            }
+            check(libc::chdir(self.workdir.as_ptr()))?;
+            if self.drop_root {
+                check(libc::setgroups(0, ptr::null()))?;
+                check(libc::setgid(NOBODY))?;
+                check(libc::setuid(NOBODY))?;
+            }
+            for (resource, limit) in &self.rlimits {
+                check(libc::setrlimit(*resource, limit))?;
+            }
+
+            check(libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0))?;
+            if let Some(filter) = &mut self.filter {
+                seccomp::set_pid(filter, libc::getpid() as u32);
+                let prog = libc::sock_fprog {
+                    len: filter.len() as libc::c_ushort,
+                    filter: filter.as_ptr() as *mut libc::sock_filter,
+                };
+                check(libc::prctl(
+                    libc::PR_SET_SECCOMP,
+                    libc::SECCOMP_MODE_FILTER,
+                    &prog as *const libc::sock_fprog,
+                ))?;
+            }
+
+            // Null-terminated on the stack: the forked child must not allocate
+            let mut argv: [*const c_char; MAX_ARGS + 2] = [ptr::null(); MAX_ARGS + 2];
+            argv[0] = self.exec_path.as_ptr();
+            for (slot, arg) in argv[1..].iter_mut().zip(&self.args) {
+                *slot = arg.as_ptr();
+            }
+            let mut envp: [*const c_char; MAX_ENV + 1] = [ptr::null(); MAX_ENV + 1];
+            for (slot, var) in envp.iter_mut().zip(&self.env) {
+                *slot = var.as_ptr();
+            }
+            libc::execve(self.exec_path.as_ptr(), argv.as_ptr(), envp.as_ptr());
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
+impl Namespaces {
+    /// Namespaces with a tmpfs root mounted on `scratch/root`, holding the
+    /// system directories and `binds`, as (host source, target inside,
+    /// writable).
+    fn new(scratch: &Path, binds: &[(&Path, &Path, bool)], limits: &Limits) -> io::Result<Self> {
+        let root = scratch.join("root");
+        std::fs::create_dir_all(&root)?;
+        let (uid, gid) = unsafe { (libc::geteuid(), libc::getegid()) };
+        let mut ns = Namespaces {
+            uid_map: format!("{NOBODY} {uid} 1").into_bytes(),
+            gid_map: format!("{NOBODY} {gid} 1").into_bytes(),
+            root: cstring(&root)?,
+            tmpfs_options: CString::new(format!(
+                "size={},nr_inodes=256,mode=0755",
+                limits.file_size
+            ))?,
+            dirs: Vec::new(),
+            binds: Vec::new(),
+        };
+
+        let system = SYSTEM_DIRS.iter().map(Path::new).filter(|dir| dir.exists());
+        for (source, target, writable) in system
+            .map(|dir| (dir, dir, false))
+            .chain(binds.iter().copied())
+        {
+            let inside = root.join(target.strip_prefix("/").unwrap_or(target));
+            // Parents first, each once: a bind may be nested, e.g. a sysroot
+            let new_dirs: Vec<_> = inside
+                .ancestors()
+                .take_while(|dir| *dir != root)
+                .map(cstring)
+                .collect::<io::Result<_>>()?;
+            for dir in new_dirs.into_iter().rev() {
+                if !ns.dirs.contains(&dir) {
+                    ns.dirs.push(dir);
+                }
+            }
+            ns.binds.push(Bind {
+                source: cstring(source)?,
+                target: cstring(&inside)?,
+                remount: if writable { None } else { Some(remount_flags(source)?) },
+            });
+        }
+        ns.dirs.push(cstring(&root.join(&WORK_DIR[1..]))?);
+        Ok(ns)
+    }
+
+    /// Unshares, maps the caller to `nobody` and pivots into a tmpfs root.
+    unsafe fn enter(&self) -> io::Result<()> {
+        check(libc::unshare(NAMESPACES))?;
+        write_file(c"/proc/self/setgroups", b"deny")?;
+        write_file(c"/proc/self/uid_map", &self.uid_map)?;
+        write_file(c"/proc/self/gid_map", &self.gid_map)?;
+
+        check(libc::mount(
+            ptr::null(),
+            c"/".as_ptr(),
+            ptr::null(),
+            libc::MS_REC | libc::MS_PRIVATE,
+            ptr::null(),
+        ))?;
+        check(libc::mount(
+            c"tmpfs".as_ptr(),
+            self.root.as_ptr(),
+            c"tmpfs".as_ptr(),
+            libc::MS_NOSUID | libc::MS_NODEV,
+            self.tmpfs_options.as_ptr().cast(),
+        ))?;
+        for dir in &self.dirs {
+            check(libc::mkdir(dir.as_ptr(), 0o755))?;
+        }
+        for bind in &self.binds {
+            let target = bind.target.as_ptr();
+            check(libc::mount(
+                bind.source.as_ptr(),
+                target,
+                ptr::null(),
+                libc::MS_BIND | libc::MS_REC,
+                ptr::null(),
+            ))?;
+            if let Some(flags) = bind.remount {
+                check(libc::mount(ptr::null(), target, ptr::null(), flags, ptr::null()))?;
+            }
+        }
+
+        // pivot_root(".", ".") stacks the old root on the new one; detaching
+        // it leaves only the tmpfs and its binds visible.
+        check(libc::chdir(self.root.as_ptr()))?;
+        check(libc::syscall(libc::SYS_pivot_root, c".".as_ptr(), c".".as_ptr()) as libc::c_int)?;
+        check(libc::umount2(c".".as_ptr(), libc::MNT_DETACH))?;
+        check(libc::chdir(c"/".as_ptr()))
+    }
+}
+
+fn remount_flags(source: &Path) -> io::Result<libc::c_ulong> {
+    const LOCKED: libc::c_ulong = libc::ST_NOSUID
+        | libc::ST_NODEV
+        | libc::ST_NOEXEC
+        | libc::ST_NOATIME
+        | libc::ST_NODIRATIME
+        | libc::ST_RELATIME;
+    let path = cstring(source)?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    check(unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) })?;
+    // The ST_* bits share their values with the matching MS_* mount flags.
+    let locked = unsafe { stat.assume_init() }.f_flag & LOCKED;
+    Ok(libc::MS_BIND | libc::MS_REMOUNT | libc::MS_RDONLY | locked)
+}
+
+unsafe fn write_file(path: &CStr, contents: &[u8]) -> io::Result<()> {
+    let fd = libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+    check(fd)?;
+    let written = libc::write(fd, contents.as_ptr().cast(), contents.len());
+    libc::close(fd);
+    if written != contents.len() as isize {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn rlimit(soft: u64, hard: u64) -> libc::rlimit {
+    libc::rlimit {
+        rlim_cur: soft as libc::rlim_t,
+        rlim_max: hard as libc::rlim_t,
+    }
+}
+
+fn cstring(path: &Path) -> io::Result<CString> {
+    CString::new(path.as_os_str().as_bytes()).map_err(io::Error::from)
+}
+
+fn check(ret: libc::c_int) -> io::Result<()> {
+    if ret == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+//! Compiling and running one untrusted program in a throwaway jail.
+//!
+//! The submission is compiled with the grader's rustc flags, then run with
+//! rlimits on CPU time, address space, file size and process count, under
+//! a seccomp filter that kills it on any syscall outside a std-only
+//! allowlist (sockets, fork, ptrace, mount, ...).
+//!
+//! Where unprivileged user namespaces are available the program also gets
+//! fresh user, mount, network, IPC and UTS namespaces: it runs as `nobody`
+//! with no network devices, inside a tmpfs root holding only read-only
+//! binds of the system libraries and its own binary. Where they are not
+//! (some hardened kernels and containers), it runs in a private scratch
+//! directory and may not open files for writing. The [`Outcome`] says
+//! which [`Isolation`] the run got.
+//!
+//! rustc runs in the same kind of jail, without the seccomp filter since
+//! it runs the linker: an empty environment, rlimits, and a root holding
+//! only the system directories, the toolchain and the build directory, so
+//! `include_str!` and `env!` cannot pull the server's files or environment
+//! into the binary.
+
+#[cfg(target_os = "linux")]
+mod jail;
+pub mod outcome;
+#[cfg(target_os = "linux")]
+mod seccomp;
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use grader::process::Captured;
+use grader::CompileError;
+
+pub use outcome::{Isolation, Outcome, Status};
+
+/// Program output is truncated to this many bytes per stream.
+const MAX_OUTPUT: usize = 65_536;
+
+/// Resource limits for one compile and run.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    pub compile_timeout: Duration,
+    /// Address-space limit for rustc and the linker, in bytes.
+    pub compile_memory: u64,
+    /// Wall-clock limit for the run; CPU time is capped to the same
+    /// number of whole seconds.
+    pub run_timeout: Duration,
+    /// Address-space limit in bytes.
+    pub memory: u64,
+    /// Largest file the program may write, and the size of its tmpfs.
+    pub file_size: u64,
+    /// Processes and threads the program's user may have.
+    pub processes: u64,
+    /// Fail with [`io::ErrorKind::Unsupported`] instead of falling back to
+    /// seccomp only when user namespaces are unavailable.
+    pub require_namespaces: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            compile_timeout: Duration::from_secs(12),
+            compile_memory: 2 << 30,
+            run_timeout: Duration::from_secs(5),
+            memory: 512 << 20,
+            file_size: 4 << 20,
+            processes: 64,
+            require_namespaces: false,
+        }
+    }
+}
+
+/// Compiles `source` and runs it in the jail.
+///
+/// A submission that does not compile, crashes or breaks a limit yields an
+/// [`Outcome`] saying so; only failures of the sandbox itself are errors.
+pub fn run(source: &str, limits: &Limits) -> io::Result<Outcome> {
+    let scratch = grader::ScratchDir::new("sandbox")?;
+    let build = scratch.path().join("build");
+    std::fs::create_dir(&build)?;
+
+    let started = std::time::Instant::now();
+    let compiled = compile_jailed(source, &build, scratch.path(), limits)?;
+    let compile_time_ms = started.elapsed().as_millis() as u64;
+    let binary = match compiled {
+        Ok(binary) => binary,
+        Err(CompileError::Io(err)) => return Err(err),
+        Err(err) => return Ok(Outcome::compile_failure(err.to_string(), compile_time_ms)),
+    };
+
+    let (run, isolation) = run_jailed(&binary, scratch.path(), limits)?;
+    Ok(Outcome::from_run(run, isolation, compile_time_ms))
+}
+
+/// The toolchain's sysroot, which holds the real compiler behind a rustup
+/// proxy and the standard library it links against.
+fn sysroot() -> io::Result<PathBuf> {
+    let out = std::process::Command::new(grader::compile::rustc())
+        .args(["--print", "sysroot"])
+        .output()?;
+    if !out.status.success() {
+        return Err(io::Error::other("rustc --print sysroot failed"));
+    }
+    Ok(PathBuf::from(String::from_utf8_lossy(&out.stdout).trim()))
+}
+
+/// The linker rustc should call, with symlinks resolved: `cc` usually
+/// points through /etc/alternatives, which the jail does not hold.
+fn linker() -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path).find_map(|dir| std::fs::canonicalize(dir.join("cc")).ok())
+}
+
+/// Compiles `source` in `build` inside the jail, with the same fallback
+/// rules as [`run_jailed`]. The outer error is a failure of the jail, the
+/// inner one of the compile.
+#[cfg(target_os = "linux")]
+fn compile_jailed(
+    source: &str,
+    build: &Path,
+    scratch: &Path,
+    limits: &Limits,
+) -> io::Result<Result<PathBuf, CompileError>> {
+    let sysroot = sysroot()?;
+    let linker = linker();
+    let spawn = |isolation| -> io::Result<Result<PathBuf, CompileError>> {
+        let args = |dir: &Path| {
+            let mut args = grader::compile::rustc_args(dir, &[]);
+            args.extend(["--sysroot".into(), sysroot.clone().into_os_string()]);
+            if let Some(linker) = &linker {
+                let mut flag = std::ffi::OsString::from("linker=");
+                flag.push(linker);
+                args.extend(["-C".into(), flag]);
+            }
+            args
+        };
+        let jail = jail::Jail::compile(&sysroot, build, args, scratch, limits, isolation)?;
+        let mut cmd = std::process::Command::new(sysroot.join("bin/rustc"));
+        jail.install(&mut cmd);
+        match grader::compile::compile_with(&mut cmd, source, build, limits.compile_timeout) {
+            Err(CompileError::Io(err)) => Err(err),
+            compiled => Ok(compiled),
+        }
+    };
+    match spawn(Isolation::Namespaces) {
+        Ok(compiled) => Ok(compiled),
+        Err(err) if limits.require_namespaces => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("cannot set up namespaces: {err}"),
+        )),
+        Err(_) => spawn(Isolation::SeccompOnly).map_err(|err| {
+            io::Error::new(err.kind(), format!("cannot set up the compile jail: {err}"))
+        }),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn compile_jailed(
+    _source: &str,
+    _build: &Path,
+    _scratch: &Path,
+    _limits: &Limits,
+) -> io::Result<Result<PathBuf, CompileError>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the sandbox needs Linux namespaces and seccomp",
+    ))
+}
+
+/// Runs the binary in namespaces, falling back to seccomp only if the
+/// jail cannot be set up and `limits` allow it. When they do not, the
+/// error is [`io::ErrorKind::Unsupported`].
+#[cfg(target_os = "linux")]
+fn run_jailed(
+    binary: &Path,
+    scratch: &Path,
+    limits: &Limits,
+) -> io::Result<(Captured, Isolation)> {
+    let spawn = |isolation| -> io::Result<Captured> {
+        let mut cmd = std::process::Command::new(binary);
+        jail::Jail::run(binary, scratch, limits, isolation)?.install(&mut cmd);
+        grader::process::run_with_timeout(&mut cmd, limits.run_timeout, MAX_OUTPUT)
+    };
+    match spawn(Isolation::Namespaces) {
+        Ok(run) => Ok((run, Isolation::Namespaces)),
+        Err(err) if limits.require_namespaces => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("cannot set up namespaces: {err}"),
+        )),
+        Err(_) => {
+            let run = spawn(Isolation::SeccompOnly).map_err(|err| {
+                io::Error::new(err.kind(), format!("cannot set up the jail: {err}"))
+            })?;
+            Ok((run, Isolation::SeccompOnly))
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn run_jailed(
+    _binary: &Path,
+    _scratch: &Path,
+    _limits: &Limits,
+) -> io::Result<(Captured, Isolation)> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the sandbox needs Linux namespaces and seccomp",
+    ))
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    fn run_ok(source: &str) -> Outcome {
+        run(source, &Limits::default()).unwrap()
+    }
+
+    #[test]
+    fn runs_programs_with_threads() {
+        let outcome = run_ok(
+            "fn main() {\n\
+                 let h: Vec<_> = (1..=4).map(|i| std::thread::spawn(move || i * i)).collect();\n\
+                 let sum: i32 = h.into_iter().map(|h| h.join().unwrap()).sum();\n\
+                 println!(\"{sum}\");\n\
+                 eprintln!(\"done\");\n\
+             }",
+        );
+        assert_eq!(outcome.status, Status::Ok, "{outcome:?}");
+        assert_eq!((outcome.stdout.as_str(), outcome.stderr.as_str()), ("30\n", "done\n"));
+        assert_eq!(outcome.exit_code, Some(0));
+    }
+
+    #[test]
+    fn compile_errors_are_reported() {
+        let outcome = run_ok("fn main() { let x: u32 = \"no\"; }");
+        assert_eq!(outcome.status, Status::CompileError);
+        assert!(outcome.compile_error.unwrap().contains("mismatched types"));
+        assert_eq!(outcome.isolation, None);
+    }
+
+    #[test]
+    fn panics_are_runtime_errors() {
+        let outcome = run_ok("fn main() { let v: Vec<i32> = Vec::new(); println!(\"{}\", v[3]); }");
+        assert_eq!(outcome.status, Status::RuntimeError);
+        assert_eq!(outcome.exit_code, Some(101));
+        assert!(outcome.stderr.contains("index out of bounds"), "{}", outcome.stderr);
+    }
+
+    #[test]
+    fn spinning_threads_hit_the_cpu_limit() {
+        let limits = Limits {
+            run_timeout: Duration::from_secs(1),
+            ..Limits::default()
+        };
+        let source = "fn main() {\n\
+                          for _ in 0..3 { std::thread::spawn(|| loop { std::hint::spin_loop() }); }\n\
+                          loop { std::hint::spin_loop() }\n\
+                      }";
+        let outcome = run(source, &limits).unwrap();
+        assert_eq!(outcome.status, Status::Timeout, "{outcome:?}");
+        assert!(outcome.run_time_ms < 3_000);
+    }
+
+    #[test]
+    fn allocations_past_the_memory_limit_are_oom() {
+        let limits = Limits {
+            memory: 128 << 20,
+            ..Limits::default()
+        };
+        let source = "fn main() { let v: Vec<u8> = vec![1; 256 << 20]; println!(\"{}\", v.len()); }";
+        let outcome = run(source, &limits).unwrap();
+        assert_eq!(outcome.status, Status::Oom, "{outcome:?}");
+        assert_eq!(outcome.signal, Some(libc::SIGABRT));
+    }
+
+    #[test]
+    fn network_and_processes_are_syscall_violations() {
+        for body in [
+            "let _ = std::net::TcpStream::connect(\"127.0.0.1:80\");",
+            "let _ = std::process::Command::new(\"/bin/true\").status();",
+        ] {
+            let outcome = run_ok(&format!("fn main() {{ {body} println!(\"escaped\"); }}"));
+            assert_eq!(outcome.status, Status::SyscallViolation, "{body}");
+            assert_eq!(outcome.signal, Some(libc::SIGSYS));
+            assert_eq!(outcome.stdout, "");
+        }
+    }
+
+    #[test]
+    fn files_stay_inside_the_jail() {
+        let source = "fn main() {\n\
+                          let wrote = std::fs::write(\"out.txt\", \"hi\").is_ok();\n\
+                          let home = std::fs::read_dir(\"/root\").is_ok();\n\
+                          println!(\"{wrote} {home}\");\n\
+                      }";
+        let outcome = run_ok(source);
+        assert_eq!(outcome.status, Status::Ok, "{outcome:?}");
+        // The tmpfs root takes writes; without namespaces writes are refused.
+        let expected = match outcome.isolation {
+            Some(Isolation::Namespaces) => "true false\n",
+            _ => "false false\n",
+        };
+        assert_eq!(outcome.stdout, expected);
+    }
+
+    #[test]
+    fn the_compiler_sees_no_environment() {
+        let outcome = run_ok("fn main() { println!(\"{:?}\", option_env!(\"HOME\")); }");
+        assert_eq!(outcome.status, Status::Ok, "{outcome:?}");
+        assert_eq!(outcome.stdout, "None\n");
+    }
+
+    #[test]
+    fn the_compiler_cannot_read_server_files() {
+        let source = "macro_rules! file { () => { \"/proc/self/environ\" } }\n\
+                      const ENV: &str = include_str!(file!());\n\
+                      fn main() { println!(\"{}\", ENV.len()); }";
+        let outcome = run_ok(source);
+        assert_eq!(outcome.status, Status::CompileError, "{outcome:?}");
+    }
+}
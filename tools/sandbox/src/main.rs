@@ -0,0 +1,85 @@
+//! `sandbox` -- compile a submission, run it in a jail and print a JSON outcome.
+//!
+//! Usage: sandbox [--run-timeout SECS] [--compile-timeout SECS]
+//!                [--memory-mb MB] [--file-size-kb KB] [--require-namespaces]
+//!                <FILE | ->
+//!
+//! Exit status is 0 when the program compiled and exited successfully, 1
+//! for any other outcome, 2 on usage or internal errors, and 3 when
+//! `--require-namespaces` is given but user namespaces are unavailable.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use sandbox::{Limits, Status};
+
+const USAGE: &str = "usage: sandbox [--run-timeout SECS] [--compile-timeout SECS] \
+                     [--memory-mb MB] [--file-size-kb KB] [--require-namespaces] <FILE | ->";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(msg) => {
+            eprintln!("sandbox: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<ExitCode, String> {
+    let mut limits = Limits::default();
+    let mut input = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--run-timeout" => limits.run_timeout = Duration::from_secs(number(&mut args, &arg)?),
+            "--compile-timeout" => {
+                limits.compile_timeout = Duration::from_secs(number(&mut args, &arg)?)
+            }
+            "--memory-mb" => limits.memory = number(&mut args, &arg)? << 20,
+            "--file-size-kb" => limits.file_size = number(&mut args, &arg)? << 10,
+            "--require-namespaces" => limits.require_namespaces = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(ExitCode::SUCCESS);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let source = match input.as_deref() {
+        None => return Err(USAGE.to_string()),
+        Some("-") => {
+            let mut buf = String::new();
+            io::stdin().read_to_string(&mut buf).map_err(|e| e.to_string())?;
+            buf
+        }
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?,
+    };
+
+    let outcome = match sandbox::run(&source, &limits) {
+        Ok(outcome) => outcome,
+        // Distinct from other failures so the caller can pick another jail
+        Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+            eprintln!("sandbox: {e}");
+            return Ok(ExitCode::from(3));
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+    let json = serde_json::to_string_pretty(&outcome).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(if outcome.status == Status::Ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    })
+}
+
+fn number(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<u64, String> {
+    let raw = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+    raw.parse()
+        .map_err(|_| format!("{flag}: `{raw}` is not a whole number"))
+}
@@ -0,0 +1,98 @@
+//! The JSON document describing how a sandboxed run ended.
+
+use std::os::unix::process::ExitStatusExt;
+
+use grader::process::Captured;
+use serde::Serialize;
+
+/// How the submission's run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Compiled, ran and exited with status 0.
+    Ok,
+    /// rustc rejected the code or did not finish in time.
+    CompileError,
+    /// Exited with a non-zero status, e.g. 101 after a panic.
+    RuntimeError,
+    /// Hit the wall-clock deadline or the CPU time limit.
+    Timeout,
+    /// An allocation failed against the address-space limit.
+    Oom,
+    /// Killed by any other signal, e.g. `SIGSEGV` or `SIGXFSZ`.
+    Signal,
+    /// Made a syscall outside the seccomp allowlist.
+    SyscallViolation,
+}
+
+/// Which layers of isolation the run actually got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Isolation {
+    /// Fresh user, mount, network, IPC and UTS namespaces with a tmpfs root,
+    /// plus rlimits and seccomp.
+    Namespaces,
+    /// rlimits and seccomp only; used where user namespaces are disabled.
+    SeccompOnly,
+}
+
+/// Result of one sandboxed compile and run.
+#[derive(Debug, Clone, Serialize)]
+pub struct Outcome {
+    pub status: Status,
+    /// rustc output when `status` is `compile_error`.
+    pub compile_error: Option<String>,
+    pub exit_code: Option<i32>,
+    /// Number of the signal that ended the program, if one did.
+    pub signal: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub compile_time_ms: u64,
+    pub run_time_ms: u64,
+    /// `None` when nothing ran because compilation failed.
+    pub isolation: Option<Isolation>,
+}
+
+impl Outcome {
+    pub fn compile_failure(error: String, compile_time_ms: u64) -> Self {
+        Outcome {
+            status: Status::CompileError,
+            compile_error: Some(error),
+            exit_code: None,
+            signal: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            compile_time_ms,
+            run_time_ms: 0,
+            isolation: None,
+        }
+    }
+
+    /// Classifies a finished run of the compiled program.
+    pub fn from_run(run: Captured, isolation: Isolation, compile_time_ms: u64) -> Self {
+        let exit_code = run.status.and_then(|s| s.code());
+        let signal = run.status.and_then(|s| s.signal());
+        let status = match (exit_code, signal) {
+            _ if run.timed_out() => Status::Timeout,
+            (Some(0), _) => Status::Ok,
+            (Some(_), _) => Status::RuntimeError,
+            // SIGXCPU at the soft CPU limit, SIGKILL at the hard one.
+            (_, Some(libc::SIGXCPU | libc::SIGKILL)) => Status::Timeout,
+            (_, Some(libc::SIGSYS)) => Status::SyscallViolation,
+            // std's allocation error handler prints this, then aborts.
+            (_, Some(libc::SIGABRT)) if run.stderr.contains("memory allocation of") => Status::Oom,
+            _ => Status::Signal,
+        };
+        Outcome {
+            status,
+            compile_error: None,
+            exit_code,
+            signal,
+            stdout: run.stdout,
+            stderr: run.stderr,
+            compile_time_ms,
+            run_time_ms: run.elapsed.as_millis() as u64,
+            isolation: Some(isolation),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! The syscall allowlist, compiled to a classic BPF program.
+//!
+//! The list covers what a std-only Rust program needs to start, allocate,
+//! print, spawn threads, sleep and panic. Anything else kills the process
+//! with `SIGSYS`, which the runner reports as a syscall violation. A few
+//! calls get a closer look at their arguments:
+//!
+//! - `tgkill` may only target the program's own pid, which the jail fills
+//!   in with [`set_pid`] after forking, and `prlimit64` only pid 0 (self).
+//!   Outside a PID namespace the program shares its host uid with the
+//!   server, so it must not be able to signal or limit anyone else.
+//! - `clone` is allowed only with `CLONE_THREAD`, so threads work but
+//!   `fork` does not; `clone3` fails with `ENOSYS` so libc falls back to it.
+//! - `execve` is allowed only with the exact pointer the jail passes for
+//!   the submission's path. Once the new image is loaded that pointer is
+//!   meaningless, so the program itself cannot exec anything.
+//! - With `deny_writes`, `openat` fails with `EACCES` for any flag that
+//!   could create or modify a file.
+
+use std::ffi::c_char;
+
+use libc::sock_filter;
+
+/// `AUDIT_ARCH_*` value the filter expects in `seccomp_data.arch`.
+#[cfg(target_arch = "x86_64")]
+const AUDIT_ARCH: u32 = 0xC000_003E;
+#[cfg(target_arch = "aarch64")]
+const AUDIT_ARCH: u32 = 0xC000_00B7;
+
+// Offsets into `struct seccomp_data`.
+const OFFSET_NR: u32 = 0;
+const OFFSET_ARCH: u32 = 4;
+const OFFSET_ARGS: u32 = 16;
+
+/// Syscalls allowed with any arguments.
+const ALLOWED: &[libc::c_long] = &[
+    // I/O on the descriptors the program was given or opened.
+    libc::SYS_read,
+    libc::SYS_write,
+    libc::SYS_readv,
+    libc::SYS_writev,
+    libc::SYS_pread64,
+    libc::SYS_lseek,
+    libc::SYS_close,
+    libc::SYS_fcntl,
+    libc::SYS_fstat,
+    libc::SYS_newfstatat,
+    libc::SYS_statx,
+    libc::SYS_getdents64,
+    libc::SYS_readlinkat,
+    libc::SYS_faccessat,
+    libc::SYS_getcwd,
+    libc::SYS_ppoll,
+    // Memory.
+    libc::SYS_brk,
+    libc::SYS_mmap,
+    libc::SYS_munmap,
+    libc::SYS_mremap,
+    libc::SYS_mprotect,
+    libc::SYS_madvise,
+    // Signals. tgkill, which abort() raises SIGABRT with, is handled below.
+    libc::SYS_rt_sigaction,
+    libc::SYS_rt_sigprocmask,
+    libc::SYS_rt_sigreturn,
+    libc::SYS_sigaltstack,
+    // Threads and synchronisation.
+    libc::SYS_futex,
+    libc::SYS_set_robust_list,
+    libc::SYS_set_tid_address,
+    libc::SYS_rseq,
+    libc::SYS_membarrier,
+    libc::SYS_sched_yield,
+    libc::SYS_sched_getaffinity,
+    libc::SYS_gettid,
+    libc::SYS_getpid,
+    libc::SYS_getuid,
+    libc::SYS_geteuid,
+    libc::SYS_getgid,
+    libc::SYS_getegid,
+    libc::SYS_uname,
+    // Time.
+    libc::SYS_clock_gettime,
+    libc::SYS_clock_getres,
+    libc::SYS_clock_nanosleep,
+    libc::SYS_nanosleep,
+    libc::SYS_gettimeofday,
+    libc::SYS_getrandom,
+    libc::SYS_exit,
+    libc::SYS_exit_group,
+];
+
+/// Legacy x86-64 spellings of calls listed above.
+#[cfg(target_arch = "x86_64")]
+const ALLOWED_ARCH: &[libc::c_long] = &[
+    libc::SYS_arch_prctl,
+    libc::SYS_access,
+    libc::SYS_readlink,
+    libc::SYS_poll,
+    libc::SYS_getrlimit,
+];
+#[cfg(target_arch = "aarch64")]
+const ALLOWED_ARCH: &[libc::c_long] = &[];
+
+/// `openat` flags that make a descriptor able to change the filesystem.
+const WRITE_FLAGS: u32 =
+    (libc::O_WRONLY | libc::O_RDWR | libc::O_CREAT | libc::O_TRUNC | libc::O_APPEND) as u32;
+
+/// Index of the instruction comparing `tgkill`'s pid argument.
+const PID_SLOT: usize = 6;
+
+/// Builds the filter. `exec_path` is the pointer the jail will pass to
+/// `execve`; it must stay valid until then.
+pub(crate) fn filter(exec_path: *const c_char, deny_writes: bool) -> Vec<sock_filter> {
+    let mut prog = vec![
+        load(OFFSET_ARCH),
+        jump_eq(AUDIT_ARCH, 1, 0),
+        ret(libc::SECCOMP_RET_KILL_PROCESS),
+        load(OFFSET_NR),
+        // tgkill(pid, tid, sig): own process only; see `set_pid`.
+        jump_eq(libc::SYS_tgkill as u32, 0, 4),
+        load(arg_low(0)),
+        jump_eq(0, 0, 1),
+        ret(libc::SECCOMP_RET_ALLOW),
+        ret(libc::SECCOMP_RET_KILL_PROCESS),
+        // prlimit64(pid, ...): pid 0 is the caller.
+        jump_eq(libc::SYS_prlimit64 as u32, 0, 4),
+        load(arg_low(0)),
+        jump_eq(0, 0, 1),
+        ret(libc::SECCOMP_RET_ALLOW),
+        ret(libc::SECCOMP_RET_KILL_PROCESS),
+    ];
+
+    for &nr in ALLOWED.iter().chain(ALLOWED_ARCH) {
+        prog.push(jump_eq(nr as u32, 0, 1));
+        prog.push(ret(libc::SECCOMP_RET_ALLOW));
+    }
+
+    // clone(flags, ...): threads only.
+    prog.extend([
+        jump_eq(libc::SYS_clone as u32, 0, 4),
+        load(arg_low(0)),
+        jump_set(libc::CLONE_THREAD as u32, 0, 1),
+        ret(libc::SECCOMP_RET_ALLOW),
+        ret(libc::SECCOMP_RET_KILL_PROCESS),
+    ]);
+    prog.extend([
+        jump_eq(libc::SYS_clone3 as u32, 0, 1),
+        ret(errno(libc::ENOSYS)),
+    ]);
+
+    // execve(path, ...): only the jail's own call.
+    let addr = exec_path as u64;
+    prog.extend([
+        jump_eq(libc::SYS_execve as u32, 0, 6),
+        load(arg_low(0)),
+        jump_eq(addr as u32, 0, 3),
+        load(arg_low(0) + 4),
+        jump_eq((addr >> 32) as u32, 0, 1),
+        ret(libc::SECCOMP_RET_ALLOW),
+        ret(libc::SECCOMP_RET_KILL_PROCESS),
+    ]);
+
+    // openat(dirfd, path, flags, mode)
+    let on_write = if deny_writes {
+        errno(libc::EACCES)
+    } else {
+        libc::SECCOMP_RET_ALLOW
+    };
+    prog.extend([
+        jump_eq(libc::SYS_openat as u32, 0, 4),
+        load(arg_low(2)),
+        jump_set(WRITE_FLAGS, 0, 1),
+        ret(on_write),
+        ret(libc::SECCOMP_RET_ALLOW),
+    ]);
+
+    prog.push(ret(libc::SECCOMP_RET_KILL_PROCESS));
+    prog
+}
+
+/// Lets `tgkill` target `pid`. pid_t is 32 bits, so the kernel ignores
+/// the upper half of the argument and so may the filter.
+pub(crate) fn set_pid(prog: &mut [sock_filter], pid: u32) {
+    prog[PID_SLOT].k = pid;
+}
+
+/// Offset of the low 32 bits of syscall argument `n` (little-endian).
+fn arg_low(n: u32) -> u32 {
+    OFFSET_ARGS + 8 * n
+}
+
+fn errno(code: i32) -> u32 {
+    libc::SECCOMP_RET_ERRNO | (code as u32 & libc::SECCOMP_RET_DATA)
+}
+
+fn load(offset: u32) -> sock_filter {
+    insn(libc::BPF_LD | libc::BPF_W | libc::BPF_ABS, 0, 0, offset)
+}
+
+fn jump_eq(k: u32, jt: u8, jf: u8) -> sock_filter {
+    insn(libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K, jt, jf, k)
+}
+
+fn jump_set(k: u32, jt: u8, jf: u8) -> sock_filter {
+    insn(libc::BPF_JMP | libc::BPF_JSET | libc::BPF_K, jt, jf, k)
+}
+
+fn ret(k: u32) -> sock_filter {
+    insn(libc::BPF_RET | libc::BPF_K, 0, 0, k)
+}
+
+fn insn(code: u32, jt: u8, jf: u8, k: u32) -> sock_filter {
+    sock_filter {
+        code: code as u16,
+        jt,
+        jf,
+        k,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs the program over a syscall the way the kernel would.
+    fn eval(prog: &[sock_filter], nr: libc::c_long, args: [u64; 6]) -> u32 {
+        let mut data = [0u32; 16];
+        data[(OFFSET_NR / 4) as usize] = nr as u32;
+        data[(OFFSET_ARCH / 4) as usize] = AUDIT_ARCH;
+        for (i, arg) in args.iter().enumerate() {
+            data[4 + 2 * i] = *arg as u32;
+            data[5 + 2 * i] = (*arg >> 32) as u32;
+        }
+
+        let (mut pc, mut acc) = (0usize, 0u32);
+        loop {
+            let insn = prog[pc];
+            let code = insn.code as u32;
+            pc += 1;
+            if code == libc::BPF_LD | libc::BPF_W | libc::BPF_ABS {
+                acc = data[(insn.k / 4) as usize];
+            } else if code == libc::BPF_RET | libc::BPF_K {
+                return insn.k;
+            } else {
+                let taken = match code & 0xf0 {
+                    c if c == libc::BPF_JEQ => acc == insn.k,
+                    c if c == libc::BPF_JSET => acc & insn.k != 0,
+                    _ => panic!("unexpected opcode {code:#x}"),
+                };
+                pc += if taken { insn.jt } else { insn.jf } as usize;
+            }
+        }
+    }
+
+    fn prog(deny_writes: bool) -> (Vec<sock_filter>, u64) {
+        let path = c"/sandbox/main";
+        (filter(path.as_ptr(), deny_writes), path.as_ptr() as u64)
+    }
+
+    #[test]
+    fn allows_listed_calls_and_kills_the_rest() {
+        let (prog, _) = prog(false);
+        assert_eq!(eval(&prog, libc::SYS_write, [1, 0, 5, 0, 0, 0]), libc::SECCOMP_RET_ALLOW);
+        assert_eq!(eval(&prog, libc::SYS_mmap, [0; 6]), libc::SECCOMP_RET_ALLOW);
+        for nr in [libc::SYS_socket, libc::SYS_ptrace, libc::SYS_mount, libc::SYS_unshare, libc::SYS_kill] {
+            assert_eq!(eval(&prog, nr, [0; 6]), libc::SECCOMP_RET_KILL_PROCESS, "syscall {nr}");
+        }
+    }
+
+    #[test]
+    fn signals_and_limits_only_reach_the_program_itself() {
+        let (mut prog, _) = prog(false);
+        set_pid(&mut prog, 4242);
+        let abort = [4242, 4243, libc::SIGABRT as u64, 0, 0, 0];
+        assert_eq!(eval(&prog, libc::SYS_tgkill, abort), libc::SECCOMP_RET_ALLOW);
+        let other = [1, 1, libc::SIGKILL as u64, 0, 0, 0];
+        assert_eq!(eval(&prog, libc::SYS_tgkill, other), libc::SECCOMP_RET_KILL_PROCESS);
+
+        assert_eq!(eval(&prog, libc::SYS_prlimit64, [0; 6]), libc::SECCOMP_RET_ALLOW);
+        let server = [4000, libc::RLIMIT_NOFILE as u64, 1, 0, 0, 0];
+        assert_eq!(eval(&prog, libc::SYS_prlimit64, server), libc::SECCOMP_RET_KILL_PROCESS);
+    }
+
+    #[test]
+    fn clone_is_limited_to_threads() {
+        let (prog, _) = prog(false);
+        let thread = (libc::CLONE_VM | libc::CLONE_THREAD | libc::CLONE_SIGHAND) as u64;
+        assert_eq!(eval(&prog, libc::SYS_clone, [thread, 0, 0, 0, 0, 0]), libc::SECCOMP_RET_ALLOW);
+        let fork = libc::SIGCHLD as u64;
+        assert_eq!(eval(&prog, libc::SYS_clone, [fork, 0, 0, 0, 0, 0]), libc::SECCOMP_RET_KILL_PROCESS);
+        assert_eq!(eval(&prog, libc::SYS_clone3, [0; 6]), errno(libc::ENOSYS));
+    }
+
+    #[test]
+    fn execve_only_accepts_the_pinned_path() {
+        let (prog, addr) = prog(false);
+        assert_eq!(eval(&prog, libc::SYS_execve, [addr, 0, 0, 0, 0, 0]), libc::SECCOMP_RET_ALLOW);
+        for other in [addr + 1, addr ^ (1 << 32), 0] {
+            assert_eq!(
+                eval(&prog, libc::SYS_execve, [other, 0, 0, 0, 0, 0]),
+                libc::SECCOMP_RET_KILL_PROCESS
+            );
+        }
+    }
+
+    #[test]
+    fn write_opens_fail_only_when_denied() {
+        let read_only = libc::O_RDONLY as u64;
+        let create = (libc::O_WRONLY | libc::O_CREAT) as u64;
+        let (open, _) = prog(false);
+        assert_eq!(eval(&open, libc::SYS_openat, [0, 0, create, 0, 0, 0]), libc::SECCOMP_RET_ALLOW);
+
+        let (locked, _) = prog(true);
+        assert_eq!(eval(&locked, libc::SYS_openat, [0, 0, read_only, 0, 0, 0]), libc::SECCOMP_RET_ALLOW);
+        assert_eq!(eval(&locked, libc::SYS_openat, [0, 0, create, 0, 0, 0]), errno(libc::EACCES));
+    }
+}
@@ -0,0 +1,62 @@
+//! Exercise 133: 0/1 Knapsack Problem.
+
+use crate::rng::Rng;
+
+/// Tries every subset of `(weight, value)` items; fine for a dozen items.
+fn exhaustive(items: &[(usize, usize)], capacity: usize) -> usize {
+    (0u32..1 << items.len())
+        .filter_map(|mask| {
+            let chosen = items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0);
+            let (weight, value) = chosen.fold((0, 0), |(w, v), (_, item)| (w + item.0, v + item.1));
+            (weight <= capacity).then_some(value)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn case(rng: &mut Rng) -> String {
+    let items: Vec<(usize, usize)> = (0..rng.usize(4..=12))
+        .map(|_| (rng.usize(1..=15), rng.usize(1..=40)))
+        .collect();
+    let total: usize = items.iter().map(|item| item.0).sum();
+    // Tight enough that not everything fits
+    let capacity = rng.usize(0..=total * 2 / 3);
+    let best = exhaustive(&items, capacity);
+
+    let list: Vec<String> = items
+        .iter()
+        .map(|(weight, value)| format!("Item::new({weight}, {value})"))
+        .collect();
+    format!(
+        "    let items = [{}];\n\
+         \x20   assert_eq!(knapsack_01(&items, {capacity}), {best});\n\
+         \x20   assert_eq!(knapsack_01_optimized(&items, {capacity}), {best});\n\
+         \x20   assert_eq!(knapsack_recursive(&items, {capacity}), {best});\n\
+         \x20   let (value, selected) = knapsack_with_selection(&items, {capacity});\n\
+         \x20   assert_eq!(value, {best});\n\
+         \x20   let mut distinct = selected.clone();\n\
+         \x20   distinct.sort();\n\
+         \x20   distinct.dedup();\n\
+         \x20   assert_eq!(distinct.len(), selected.len(), \"{{selected:?}} picks an item twice\");\n\
+         \x20   let weight: usize = selected.iter().map(|&i| items[i].weight).sum();\n\
+         \x20   assert!(weight <= {capacity}, \"{{selected:?}} weighs {{weight}}\");\n\
+         \x20   assert_eq!(selected.iter().map(|&i| items[i].value).sum::<usize>(), {best});\n",
+        list.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_beats_greedy_by_ratio() {
+        let items = [(5, 10), (4, 40), (6, 30), (3, 50)];
+        assert_eq!(exhaustive(&items, 10), 90);
+        assert_eq!(exhaustive(&items, 0), 0);
+        assert_eq!(exhaustive(&[], 10), 0);
+    }
+}
@@ -0,0 +1,174 @@
+//! Per-learner test cases for exercises whose fixed tests can be passed by
+//! hard-coding the answers.
+//!
+//! Each supported exercise has a generator that draws random inputs from
+//! a seed and computes the expected outputs with a trusted reference
+//! implementation (brute force, so it is easy to trust). [`generate`]
+//! writes the cases out, inputs and expected values as literals, as a test
+//! module that `grader --variant-tests` appends to a submission. The same
+//! seed always yields the same module, so an instructor can replay exactly
+//! what a learner was graded against.
+//!
+//! The grading service derives each learner's seed with [`seed_for`] from
+//! the learner, the exercise and a server-side secret, so learners get
+//! different cases and cannot compute their own ahead of time.
+
+mod knapsack;
+mod max_subarray;
+pub mod rng;
+mod two_sum;
+
+use rng::Rng;
+
+/// How many cases a module holds.
+pub const CASES: usize = 5;
+
+/// One exercise's case generator.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// Three-digit exercise number, e.g. `"136"`.
+    pub exercise: &'static str,
+    /// The function the fixed tests leave open to hard-coding.
+    pub function: &'static str,
+    /// The body of one `#[test]` function.
+    case: fn(&mut Rng) -> String,
+}
+
+/// Sorted by exercise.
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        exercise: "133",
+        function: "knapsack_01",
+        case: knapsack::case,
+    },
+    Generator {
+        exercise: "136",
+        function: "two_sum",
+        case: two_sum::case,
+    },
+    Generator {
+        exercise: "137",
+        function: "max_subarray_sum",
+        case: max_subarray::case,
+    },
+];
+
+pub fn for_exercise(exercise: &str) -> Option<&'static Generator> {
+    GENERATORS
+        .binary_search_by(|g| g.exercise.cmp(exercise))
+        .ok()
+        .map(|i| &GENERATORS[i])
+}
+
+/// The seed for one learner on one exercise.
+///
+/// FNV-1a over the three strings, then SplitMix64's finalizer. This is not
+/// a MAC: it keeps learners from computing each other's cases, not from a
+/// determined attack on a leaked secret.
+pub fn seed_for(secret: &str, learner: &str, exercise: &str) -> u64 {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+    for part in [secret, learner, exercise] {
+        // A separator keeps ("ab", "c") and ("a", "bc") apart
+        for byte in part.bytes().chain([0xFF]) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+    rng::mix(hash)
+}
+
+/// The test module for `exercise` drawn from `seed`, or `None` if the
+/// exercise has no generator.
+pub fn generate(exercise: &str, seed: u64) -> Option<String> {
+    let generator = for_exercise(exercise)?;
+    let mut rng = Rng::new(seed);
+    let mut module = format!(
+        "//! Generated tests for exercise {exercise} (`{}`), seed {seed}.\n\
+         //! Replay with `variant --exercise {exercise} --seed {seed}`.\n\n\
+         use super::*;\n",
+        generator.function
+    );
+    for n in 1..=CASES {
+        let body = (generator.case)(&mut rng);
+        module.push_str(&format!("\n#[test]\nfn case_{n}() {{\n{body}}}\n"));
+    }
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use grader::splice::{append_test_module, VARIANT_MODULE};
+    use grader::{Options, Status};
+
+    fn graded(source: &str, module: &str) -> Vec<(String, Status)> {
+        let source = append_test_module(source, VARIANT_MODULE, module);
+        let opts = Options {
+            test_timeout: Duration::from_secs(10),
+            ..Options::default()
+        };
+        let report = grader::grade(&source, &opts).unwrap();
+        assert!(
+            report.compiled,
+            "{}",
+            report.compile_error.unwrap_or_default()
+        );
+        report
+            .tests
+            .into_iter()
+            .filter(|t| t.name.starts_with(VARIANT_MODULE))
+            .map(|t| (t.name, t.status))
+            .collect()
+    }
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(GENERATORS.windows(2).all(|w| w[0].exercise < w[1].exercise));
+        assert!(for_exercise("136").is_some());
+        assert!(for_exercise("001").is_none());
+        assert!(generate("001", 7).is_none());
+    }
+
+    #[test]
+    fn seeds_replay_and_differ_between_learners() {
+        assert_eq!(seed_for("s", "42", "136"), seed_for("s", "42", "136"));
+        assert_ne!(seed_for("s", "42", "136"), seed_for("s", "43", "136"));
+        assert_ne!(seed_for("s", "42", "136"), seed_for("s", "42", "137"));
+        assert_ne!(seed_for("s", "42", "136"), seed_for("t", "42", "136"));
+        assert_ne!(seed_for("", "ab", "c"), seed_for("", "a", "bc"));
+
+        for generator in GENERATORS {
+            let first = generate(generator.exercise, 1).unwrap();
+            assert_eq!(first, generate(generator.exercise, 1).unwrap());
+            assert_ne!(first, generate(generator.exercise, 2).unwrap());
+            assert!(first.contains("seed 1."), "{first}");
+        }
+    }
+
+    #[test]
+    fn solutions_pass_and_templates_fail() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        for generator in GENERATORS {
+            let id = generator.exercise;
+            let module = generate(id, seed_for("test", "learner", id)).unwrap();
+            let solution =
+                std::fs::read_to_string(root.join(format!("solutions/{id}.rs"))).unwrap();
+            let results = graded(&solution, &module);
+            assert_eq!(results.len(), CASES, "{id}");
+            assert!(
+                results.iter().all(|(_, s)| *s == Status::Passed),
+                "{id}: {results:?}"
+            );
+
+            let template = root.join(format!("problems/{id}_exercise/template.rs"));
+            let results = graded(&std::fs::read_to_string(template).unwrap(), &module);
+            assert!(
+                results.iter().any(|(_, s)| *s == Status::Failed),
+                "{id}: {results:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! `variant` -- print a learner's generated test module for an exercise.
+//!
+//! Usage: variant --exercise NNN (--seed N | --learner ID)
+//!
+//! With `--learner`, the seed is derived from the learner, the exercise and
+//! the `VARIANT_SECRET` environment variable (or the file named by
+//! `VARIANT_SECRET_FILE`), as the grading service does; an instructor with
+//! the same secret gets the same cases back. The module starts with the
+//! seed it was drawn from.
+//!
+//! Exit status is 0 when a module was printed, 1 when the exercise has no
+//! generator, and 2 on usage errors or when `--learner` is given without a
+//! secret.
+
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: variant --exercise NNN (--seed N | --learner ID)";
+
+/// Environment variable holding the server-side part of every seed.
+const SECRET_VAR: &str = "VARIANT_SECRET";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("variant: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut exercise = None;
+    let mut seed = None;
+    let mut learner = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--exercise" => exercise = Some(value(&mut args, &arg)?),
+            "--seed" => {
+                let raw = value(&mut args, &arg)?;
+                let parsed = raw.parse::<u64>();
+                seed = Some(parsed.map_err(|_| format!("--seed: `{raw}` is not a number"))?);
+            }
+            "--learner" => learner = Some(value(&mut args, &arg)?),
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(true);
+            }
+            _ => return Err(format!("unexpected argument `{arg}`\n{USAGE}")),
+        }
+    }
+
+    let exercise = exercise.ok_or(USAGE)?;
+    let exercise = match exercise.parse::<u32>() {
+        Ok(n) => format!("{n:03}"),
+        Err(_) => return Err(format!("--exercise: `{exercise}` is not a number")),
+    };
+    let seed = match (seed, learner) {
+        (Some(seed), None) => seed,
+        (None, Some(learner)) => {
+            variant::seed_for(&secret()?, &learner, &exercise)
+        }
+        _ => return Err(USAGE.to_string()),
+    };
+
+    match variant::generate(&exercise, seed) {
+        Some(module) => {
+            print!("{module}");
+            Ok(true)
+        }
+        None => {
+            eprintln!("variant: exercise {exercise} has no generator");
+            Ok(false)
+        }
+    }
+}
+
+/// The secret from `VARIANT_SECRET`, or from the file `VARIANT_SECRET_FILE`
+/// names. An empty one is an error: anyone could recompute the seeds.
+fn secret() -> Result<String, String> {
+    let secret = match std::env::var(SECRET_VAR) {
+        Ok(secret) => secret,
+        Err(_) => match std::env::var_os(format!("{SECRET_VAR}_FILE")) {
+            Some(path) => std::fs::read_to_string(&path)
+                .map_err(|e| format!("{SECRET_VAR}_FILE: {}: {e}", path.to_string_lossy()))?
+                .trim()
+                .to_string(),
+            None => String::new(),
+        },
+    };
+    if secret.trim().is_empty() {
+        return Err(format!("{SECRET_VAR} is unset or empty; --learner needs it"));
+    }
+    Ok(secret)
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{flag} needs a value"))
+}
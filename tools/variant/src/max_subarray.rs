@@ -0,0 +1,73 @@
+//! Exercise 137: Maximum Subarray (Kadane's Algorithm).
+
+use crate::rng::Rng;
+
+/// The best `value` over every non-empty contiguous subarray.
+fn best_over(nums: &[i32], value: impl Fn(&[i32]) -> i64) -> i64 {
+    let mut best = i64::MIN;
+    for i in 0..nums.len() {
+        for j in i..nums.len() {
+            best = best.max(value(&nums[i..=j]));
+        }
+    }
+    best
+}
+
+fn max_sum(nums: &[i32]) -> i64 {
+    best_over(nums, |s| s.iter().map(|&x| x as i64).sum())
+}
+
+/// Every window of length 1 to `n` starting anywhere and wrapping around.
+fn max_circular_sum(nums: &[i32]) -> i64 {
+    let n = nums.len();
+    let mut best = i64::MIN;
+    for start in 0..n {
+        let mut sum = 0;
+        for len in 1..=n {
+            sum += nums[(start + len - 1) % n] as i64;
+            best = best.max(sum);
+        }
+    }
+    best
+}
+
+fn max_product(nums: &[i32]) -> i64 {
+    best_over(nums, |s| s.iter().map(|&x| x as i64).product())
+}
+
+pub fn case(rng: &mut Rng) -> String {
+    let nums = rng.values(1..=15, -20..=20);
+    // Short runs of small factors keep every product well inside i32
+    let factors = rng.values(1..=9, -6..=6);
+    let (sum, circular, product) = (
+        max_sum(&nums),
+        max_circular_sum(&nums),
+        max_product(&factors),
+    );
+    format!(
+        "    let nums = {nums:?};\n\
+         \x20   assert_eq!(max_subarray_sum(&nums), {sum});\n\
+         \x20   let (best, start, end) = max_subarray_with_indices(&nums);\n\
+         \x20   assert_eq!(best, {sum});\n\
+         \x20   assert_eq!(nums[start..=end].iter().sum::<i32>(), {sum}, \"({{start}}, {{end}})\");\n\
+         \x20   assert_eq!(max_subarray(&nums).iter().sum::<i32>(), {sum});\n\
+         \x20   assert_eq!(max_circular_subarray_sum(&nums), {circular});\n\
+         \x20   assert_eq!(max_product_subarray(&{factors:?}), {product});\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_handle_negatives_and_wrapping() {
+        assert_eq!(max_sum(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), 6);
+        assert_eq!(max_sum(&[-3, -1, -2]), -1);
+        assert_eq!(max_circular_sum(&[5, -3, 5]), 10);
+        assert_eq!(max_circular_sum(&[-3, -1, -2]), -1);
+        assert_eq!(max_product(&[2, 3, -2, 4]), 6);
+        assert_eq!(max_product(&[-2, 0, -1]), 0);
+        assert_eq!(max_product(&[-2, 3, -4]), 24);
+    }
+}
@@ -0,0 +1,55 @@
+//! A small deterministic generator, so a seed replays the same cases on
+//! every machine and toolchain.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64. Not for anything secret; it only needs to be fast, well
+/// mixed and stable.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        mix(self.0)
+    }
+
+    /// A value in `range`; the bias of taking a remainder is negligible
+    /// for the small ranges the generators use.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {low}..={high}");
+        let span = high.abs_diff(low) + 1;
+        low.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// Between `len.start()` and `len.end()` values drawn from `range`.
+    pub fn values(&mut self, len: RangeInclusive<usize>, range: RangeInclusive<i32>) -> Vec<i32> {
+        let len = self.usize(len);
+        let (low, high) = range.into_inner();
+        (0..len)
+            .map(|_| self.range(low as i64..=high as i64) as i32)
+            .collect()
+    }
+
+    pub fn usize(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (low, high) = range.into_inner();
+        self.range(low as i64..=high as i64) as usize
+    }
+
+    /// True `percent` times in a hundred.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+}
+
+/// SplitMix64's finalizer.
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
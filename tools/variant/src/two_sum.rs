@@ -0,0 +1,64 @@
+//! Exercise 136: Two Sum Problem.
+
+use crate::rng::Rng;
+
+fn all_pairs(nums: &[i32], target: i32) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for j in 1..nums.len() {
+        for i in 0..j {
+            if nums[i] as i64 + nums[j] as i64 == target as i64 {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+pub fn case(rng: &mut Rng) -> String {
+    let nums = rng.values(6..=16, -50..=50);
+    // Mostly targets with an answer; the rest are random and may have none
+    let target = if rng.chance(75) {
+        let i = rng.usize(0..=nums.len() - 2);
+        let j = rng.usize(i + 1..=nums.len() - 1);
+        nums[i] + nums[j]
+    } else {
+        rng.range(-100..=100) as i32
+    };
+
+    // The template only asks for some pair with i < j, so any valid pair
+    // passes, not just the one the reference finds
+    let all = all_pairs(&nums, target);
+    let mut body = format!("    let nums = {nums:?};\n");
+    if all.is_empty() {
+        body.push_str(&format!(
+            "    assert_eq!(two_sum(&nums, {target}), None);\n\
+             \x20   assert!(two_sum_all(&nums, {target}).is_empty());\n"
+        ));
+    } else {
+        body.push_str(&format!(
+            "    let (i, j) = two_sum(&nums, {target}).expect(\"a pair sums to {target}\");\n\
+             \x20   assert!(i < j && nums[i] + nums[j] == {target}, \"two_sum returned ({{i}}, {{j}})\");\n\
+             \x20   let mut all = two_sum_all(&nums, {target});\n\
+             \x20   all.sort();\n\
+             \x20   assert_eq!(all, {all:?});\n"
+        ));
+    }
+    body.push_str(&format!(
+        "    assert_eq!(two_sum_sorted(&nums, {target}).is_some(), {});\n",
+        !all.is_empty()
+    ));
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_finds_every_pair() {
+        assert_eq!(all_pairs(&[1, 4, 5, 8], 9), [(0, 3), (1, 2)]);
+        assert_eq!(all_pairs(&[i32::MAX, i32::MAX], -2), []);
+        assert_eq!(all_pairs(&[2, 2, 2], 4), [(0, 1), (0, 2), (1, 2)]);
+    }
+}
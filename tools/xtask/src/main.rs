@@ -0,0 +1,56 @@
+//! `cargo xtask` -- repository chores, written in Rust rather than shell.
+//!
+//! Usage: cargo xtask projects [NNN ...]
+//!
+//! `projects` builds the Cargo projects under `problems/` in one go and
+//! runs each one's tests, then prints a table of passing, failing and
+//! `todo!()` tests per project, marking the projects that are still stubs.
+//! Without numbers it takes all of them. Compiler errors follow the table.
+//!
+//! Exit status is 0 when every project builds and no test fails other than
+//! on a `todo!()`, 1 otherwise, and 2 on usage or internal errors.
+
+mod projects;
+
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: cargo xtask projects [NNN ...]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("xtask: {msg}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("projects") => {}
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return Ok(true);
+        }
+        Some(other) => return Err(format!("unknown task `{other}`\n{USAGE}")),
+        None => return Err(USAGE.to_string()),
+    }
+    let only = args
+        .map(|arg| {
+            arg.parse::<u32>()
+                .map_err(|_| format!("`{arg}` is not a project number\n{USAGE}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let (rows, errors) = projects::run(&root, &only).map_err(|e| e.to_string())?;
+    print!("{}", projects::table(&rows));
+    for (number, error) in &errors {
+        println!("\n{number:03} does not build:\n{error}");
+    }
+    Ok(!rows.iter().any(projects::Row::failed))
+}
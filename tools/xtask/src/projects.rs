@@ -0,0 +1,423 @@
+//! `cargo xtask projects`: the Cargo projects under `problems/`, built once
+//! and tested one by one.
+//!
+//! The projects form the workspace `problems/Cargo.toml`, so a single
+//! `cargo build --tests` builds all of them into one target directory.
+//! Each project's test harness is then run by the grader, test by test,
+//! which tells a test that hit `todo!()` apart from one that failed. A
+//! project is a stub while its only test is the empty `test_basic` it was
+//! generated with.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use grader::{Crate, Report, Status};
+use serde_json::Value;
+
+/// One `problems/NNN_project`.
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub number: u32,
+    pub dir: PathBuf,
+}
+
+impl Project {
+    /// Its package name, as every project's manifest spells it.
+    pub fn package(&self) -> String {
+        format!("exercise_{:03}", self.number)
+    }
+}
+
+/// The projects under `problems`, by number; only those numbered in `only`
+/// unless it is empty.
+pub fn find(problems: &Path, only: &[u32]) -> io::Result<Vec<Project>> {
+    let mut projects = Vec::new();
+    for entry in std::fs::read_dir(problems)? {
+        let dir = entry?.path();
+        let number = dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_suffix("_project")?.parse().ok());
+        match number {
+            Some(number) if only.is_empty() || only.contains(&number) => {
+                projects.push(Project { number, dir })
+            }
+            _ => {}
+        }
+    }
+    projects.sort_by_key(|p| p.number);
+    Ok(projects)
+}
+
+/// What building the workspace produced.
+#[derive(Debug, Default)]
+pub struct Build {
+    /// Test harness of each package that built, by package name.
+    pub harnesses: BTreeMap<String, PathBuf>,
+    /// Rendered compiler errors of each package that did not.
+    pub errors: BTreeMap<String, String>,
+}
+
+/// Builds the tests of `projects` in the workspace at `problems`. Packages
+/// that fail to build are reported in [`Build::errors`], not as an error.
+pub fn build(problems: &Path, projects: &[Project]) -> io::Result<Build> {
+    let mut cmd = Command::new(grader::cargo::cargo());
+    // `cargo test --no-run` would stop at the first project that fails
+    cmd.current_dir(problems).args([
+        "build",
+        "--tests",
+        "--keep-going",
+        "--message-format",
+        "json",
+    ]);
+    for project in projects {
+        cmd.args(["--package", &project.package()]);
+    }
+    let out = cmd.output()?;
+    let build = read_messages(&String::from_utf8_lossy(&out.stdout));
+    if !out.status.success() && build.errors.is_empty() {
+        // cargo itself failed, e.g. on a broken manifest
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&out.stderr).into_owned(),
+        ));
+    }
+    Ok(build)
+}
+
+/// Reads cargo's `--message-format json` output.
+fn read_messages(messages: &str) -> Build {
+    let mut build = Build::default();
+    for msg in messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+    {
+        let Some(package) = msg["target"]["name"].as_str() else {
+            continue;
+        };
+        match msg["reason"].as_str() {
+            Some("compiler-artifact") if msg["profile"]["test"] == true => {
+                if let Some(harness) = msg["executable"].as_str() {
+                    build
+                        .harnesses
+                        .insert(package.to_string(), PathBuf::from(harness));
+                }
+            }
+            Some("compiler-message") if msg["message"]["level"] == "error" => {
+                let rendered = msg["message"]["rendered"].as_str().unwrap_or_default();
+                build
+                    .errors
+                    .entry(package.to_string())
+                    .or_default()
+                    .push_str(rendered);
+            }
+            _ => {}
+        }
+    }
+    build
+}
+
+/// Whether the tests in `krate` are still just the generated empty
+/// `test_basic`.
+pub fn is_stub(krate: &Crate) -> bool {
+    let Ok(files) = krate
+        .files
+        .values()
+        .map(|source| syn::parse_file(source))
+        .collect::<syn::Result<Vec<_>>>()
+    else {
+        return false;
+    };
+    let mut tests = Vec::new();
+    for file in &files {
+        collect_tests(&file.items, &mut tests);
+    }
+    matches!(&tests[..], [test] if test.sig.ident == "test_basic" && test.block.stmts.is_empty())
+}
+
+fn collect_tests<'a>(items: &'a [syn::Item], tests: &mut Vec<&'a syn::ItemFn>) {
+    for item in items {
+        match item {
+            syn::Item::Fn(f) if f.attrs.iter().any(|a| a.path().is_ident("test")) => tests.push(f),
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => collect_tests(items, tests),
+            _ => {}
+        }
+    }
+}
+
+/// How one project fared.
+#[derive(Debug)]
+pub struct Row {
+    pub number: u32,
+    pub title: String,
+    pub stub: bool,
+    /// `None` if it did not build.
+    pub report: Option<Report>,
+}
+
+/// Test counts of a report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub total: usize,
+    pub passed: usize,
+    /// Failed or timed out, other than on a `todo!()`.
+    pub failed: usize,
+    /// Failed on a `todo!()` or `unimplemented!()`.
+    pub todo: usize,
+}
+
+impl Counts {
+    pub fn of(report: &Report) -> Self {
+        let mut counts = Counts {
+            total: report.tests.len(),
+            ..Counts::default()
+        };
+        for test in &report.tests {
+            match test.status {
+                Status::Passed => counts.passed += 1,
+                Status::Ignored => {}
+                Status::Failed if test.hit_todo() => counts.todo += 1,
+                Status::Failed | Status::TimedOut => counts.failed += 1,
+            }
+        }
+        counts
+    }
+}
+
+impl Row {
+    /// Did not build, or has a failing test. Tests still at `todo!()` are
+    /// work in progress, not failures.
+    pub fn failed(&self) -> bool {
+        self.report
+            .as_ref()
+            .is_none_or(|report| Counts::of(report).failed > 0)
+    }
+
+    fn status(&self) -> &'static str {
+        let Some(report) = &self.report else {
+            return "does not build";
+        };
+        let counts = Counts::of(report);
+        if counts.failed > 0 {
+            "failing"
+        } else if counts.todo > 0 {
+            "todo"
+        } else if self.stub {
+            "stub"
+        } else {
+            "ok"
+        }
+    }
+}
+
+/// The rows as a table, with a summary line.
+pub fn table(rows: &[Row]) -> String {
+    let width = rows
+        .iter()
+        .map(|r| r.title.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("project".len());
+    let mut out = format!(
+        "{:<3}  {:<width$}  {:>5}  {:>4}  {:>4}  {:>4}  status\n",
+        "#", "project", "tests", "pass", "fail", "todo"
+    );
+    for row in rows {
+        let counts = row.report.as_ref().map(Counts::of).unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "{:03}  {:<width$}  {:>5}  {:>4}  {:>4}  {:>4}  {}",
+            row.number,
+            row.title,
+            counts.total,
+            counts.passed,
+            counts.failed,
+            counts.todo,
+            row.status()
+        );
+    }
+    let count = |status: &str| rows.iter().filter(|r| r.status() == status).count();
+    let _ = writeln!(
+        out,
+        "\n{} projects: {} ok, {} stubs, {} with todo!() left, {} failing, {} not building",
+        rows.len(),
+        count("ok"),
+        count("stub"),
+        count("todo"),
+        count("failing"),
+        count("does not build")
+    );
+    out
+}
+
+/// Builds and tests the projects numbered in `only`, or all of them, and
+/// returns their rows with the compiler errors of those that did not build.
+pub fn run(root: &Path, only: &[u32]) -> io::Result<(Vec<Row>, BTreeMap<u32, String>)> {
+    let problems = root.join("problems");
+    let projects = find(&problems, only)?;
+    let missing: BTreeSet<u32> = only
+        .iter()
+        .filter(|n| !projects.iter().any(|p| p.number == **n))
+        .copied()
+        .collect();
+    if !missing.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no such project: {missing:?}"),
+        ));
+    }
+
+    let mut build = build(&problems, &projects)?;
+    let mut rows = Vec::new();
+    let mut errors = BTreeMap::new();
+    for project in &projects {
+        let krate = Crate::read(&project.dir)?;
+        let title = krate
+            .files
+            .get("src/main.rs")
+            .and_then(|main| banner::header::parse(main).ok())
+            .map_or_else(|| "?".to_string(), |header| header.title);
+        let report = match build.harnesses.get(&project.package()) {
+            Some(harness) => {
+                let opts = grader::Options {
+                    exercise: Some(format!("{:03}", project.number)),
+                    ..grader::Options::default()
+                };
+                Some(grader::run_all(harness, &opts)?)
+            }
+            None => {
+                let error = build.errors.remove(&project.package());
+                errors.insert(project.number, error.unwrap_or_default());
+                None
+            }
+        };
+        rows.push(Row {
+            number: project.number,
+            title,
+            stub: is_stub(&krate),
+            report,
+        });
+    }
+    Ok((rows, errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grader::TestCase;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+    }
+
+    fn main_rs(source: &str) -> Crate {
+        Crate {
+            files: BTreeMap::from([("src/main.rs".to_string(), source.to_string())]),
+        }
+    }
+
+    #[test]
+    fn stubs_have_only_the_empty_test_basic() {
+        let projects = find(&root().join("problems"), &[]).unwrap();
+        assert_eq!(projects.len(), 50);
+        assert_eq!(projects[0].package(), "exercise_151");
+        assert!(is_stub(&Crate::read(&projects[0].dir).unwrap()));
+
+        let stub = "fn main() {}\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn test_basic() {\n        // Add your test here\n    }\n}\n";
+        assert!(is_stub(&main_rs(stub)));
+        assert!(!is_stub(&main_rs(
+            &stub.replace("// Add", "assert!(true); // Add")
+        )));
+        assert!(!is_stub(&main_rs(&format!(
+            "{stub}#[test]\nfn parses() {{}}\n"
+        ))));
+        assert!(!is_stub(&main_rs("fn main() {}\n")));
+    }
+
+    #[test]
+    fn harnesses_and_errors_are_read_per_package() {
+        let messages = r#"{"reason":"compiler-artifact","target":{"name":"exercise_151"},"profile":{"test":false},"executable":"/t/exercise_151"}
+{"reason":"compiler-artifact","target":{"name":"exercise_151"},"profile":{"test":true},"executable":"/t/deps/exercise_151-1"}
+{"reason":"compiler-message","target":{"name":"exercise_152"},"message":{"level":"warning","rendered":"warning: unused\n"}}
+{"reason":"compiler-message","target":{"name":"exercise_152"},"message":{"level":"error","rendered":"error[E0308]: mismatched types\n"}}
+{"reason":"build-finished","success":false}"#;
+        let build = read_messages(messages);
+        assert_eq!(
+            build.harnesses,
+            BTreeMap::from([(
+                "exercise_151".into(),
+                PathBuf::from("/t/deps/exercise_151-1")
+            )])
+        );
+        assert_eq!(
+            build.errors["exercise_152"],
+            "error[E0308]: mismatched types\n"
+        );
+    }
+
+    #[test]
+    fn the_table_counts_todo_apart_from_failures() {
+        let test = |name: &str, status, panic: Option<&str>| TestCase {
+            name: name.to_string(),
+            status,
+            panic_message: panic.map(str::to_string),
+            duration_ms: 0,
+        };
+        let report = |tests| Some(Report::from_tests(None, tests));
+        let row = |number, stub, report| Row {
+            number,
+            title: format!("Project {number}"),
+            stub,
+            report,
+        };
+        let rows = [
+            row(
+                151,
+                true,
+                report(vec![test("tests::test_basic", Status::Passed, None)]),
+            ),
+            row(
+                152,
+                false,
+                report(vec![
+                    test("tests::parses", Status::Passed, None),
+                    test("tests::adds", Status::Failed, Some("not yet implemented")),
+                ]),
+            ),
+            row(
+                153,
+                false,
+                report(vec![test(
+                    "tests::adds",
+                    Status::Failed,
+                    Some("assertion failed"),
+                )]),
+            ),
+            row(154, false, None),
+        ];
+        assert_eq!(
+            rows.iter().map(Row::failed).collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+        let table = table(&rows);
+        assert!(
+            table.starts_with("#    project      tests  pass  fail  todo  status\n"),
+            "{table}"
+        );
+        assert!(
+            table.contains("\n152  Project 152      2     1     0     1  todo\n"),
+            "{table}"
+        );
+        assert!(
+            table.contains("\n154  Project 154      0     0     0     0  does not build\n"),
+            "{table}"
+        );
+        assert!(table.ends_with(
+            "4 projects: 0 ok, 1 stubs, 1 with todo!() left, 1 failing, 1 not building\n"
+        ));
+    }
+}
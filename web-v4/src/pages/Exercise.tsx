@@ -24,11 +24,30 @@ import {
   Clock,
   Zap,
   Volume2,
+  FileCode2,
 } from "lucide-react";
-import type { ExerciseDetail, ConsoleEntry } from "../types";
+import type { ExerciseDetail, ConsoleEntry, SourceFiles } from "../types";
 import { XPPopup } from "../components/engagement/XPPopup";
 import { AudioGuide } from "../components/audio/AudioGuide";
 
+/** Saved work of a multi-file exercise, if it is still a set of its files. */
+function parseSavedFiles(
+  saved: string | undefined,
+  template: SourceFiles,
+): SourceFiles | null {
+  if (!saved) return null;
+  try {
+    const files = JSON.parse(saved);
+    const same =
+      files &&
+      typeof files === "object" &&
+      Object.keys(template).every((path) => typeof files[path] === "string");
+    return same ? (files as SourceFiles) : null;
+  } catch {
+    return null;
+  }
+}
+
 const difficultyColors = {
   1: "from-green-500 to-emerald-500",
   2: "from-lime-500 to-yellow-500",
@@ -55,6 +74,10 @@ export default function ExercisePage() {
     code,
     setCode,
     resetCode,
+    files,
+    setFiles,
+    activeFile,
+    setActiveFile,
     runCode,
     submitCode,
     isRunning,
@@ -92,11 +115,19 @@ export default function ExercisePage() {
         const exercise = await exerciseApi.get(exerciseId);
         // Load saved code or use template
         const savedCode = progressStore.getProgress(exerciseId)?.savedCode;
-        setCode(
-          savedCode ||
-            exercise.template_code ||
-            "fn main() {\n    // Your code here\n}",
-        );
+        if (exercise.files) {
+          // Multi-file exercises save every file as one JSON object
+          setFiles(
+            parseSavedFiles(savedCode, exercise.files) || exercise.files,
+          );
+        } else {
+          setFiles(null);
+          setCode(
+            savedCode ||
+              exercise.template_code ||
+              "fn main() {\n    // Your code here\n}",
+          );
+        }
         // Track that user started this exercise
         progressStore.startExercise(exerciseId);
       } catch (error) {
@@ -104,7 +135,14 @@ export default function ExercisePage() {
       }
     };
     loadExercise();
-  }, [exerciseId, clearCurrent, clearConsole, setCode, progressStore]);
+  }, [
+    exerciseId,
+    clearCurrent,
+    clearConsole,
+    setCode,
+    setFiles,
+    progressStore,
+  ]);
 
   const handleRun = async () => {
     await runCode(exerciseId);
@@ -175,7 +213,9 @@ export default function ExercisePage() {
   };
 
   const handleReset = () => {
-    if (currentExercise) {
+    if (currentExercise?.files) {
+      setFiles(currentExercise.files);
+    } else if (currentExercise) {
       setCode(
         currentExercise.template_code ||
           "fn main() {\n    // Your code here\n}",
@@ -187,13 +227,15 @@ export default function ExercisePage() {
   // Debounced code save
   useEffect(() => {
     const timeoutId = setTimeout(() => {
-      if (code && code.trim()) {
+      if (files) {
+        progressStore.saveCode(exerciseId, JSON.stringify(files));
+      } else if (code && code.trim()) {
         progressStore.saveCode(exerciseId, code);
       }
     }, 1000);
 
     return () => clearTimeout(timeoutId);
-  }, [code, exerciseId, progressStore]);
+  }, [code, files, exerciseId, progressStore]);
 
   const getConsoleColor = (type: ConsoleEntry["type"]) => {
     switch (type) {
@@ -367,10 +409,31 @@ export default function ExercisePage() {
         </div>
 
         <div className="flex flex-col h-full">
+          {files && (
+            <div className="flex items-center gap-1 mb-2 overflow-x-auto scrollbar-thin">
+              {Object.keys(files)
+                .sort()
+                .map((path) => (
+                  <button
+                    key={path}
+                    onClick={() => setActiveFile(path)}
+                    className={`flex items-center gap-1.5 px-3 py-1.5 rounded-lg text-xs font-mono whitespace-nowrap transition-all ${
+                      path === activeFile
+                        ? "bg-primary/10 text-primary"
+                        : "text-text-secondary hover:text-text-primary"
+                    }`}
+                  >
+                    <FileCode2 className="w-3.5 h-3.5" />
+                    {path.replace(/^src\//, "")}
+                  </button>
+                ))}
+            </div>
+          )}
           <div className="flex-1 rounded-xl overflow-hidden border border-border-subtle bg-canvas">
             <Editor
               height="100%"
               defaultLanguage="rust"
+              path={files ? activeFile : undefined}
               value={code}
               onChange={(value) => setCode(value || "")}
               theme="vs-dark"
@@ -421,7 +484,12 @@ export default function ExercisePage() {
             <div className="flex items-center gap-3 px-4 py-3 border-t border-border-subtle bg-surface-hover">
               <button
                 onClick={handleRun}
-                disabled={isRunning || isSubmitting}
+                disabled={isRunning || isSubmitting || files !== null}
+                title={
+                  files
+                    ? "Multi-file exercises are built with cargo: use Submit to run the tests"
+                    : undefined
+                }
                 className="flex-1 flex items-center justify-center gap-2 px-4 py-2 rounded-lg bg-success text-white font-medium hover:bg-success-hover transition-all disabled:opacity-50 disabled:cursor-not-allowed"
               >
                 {isRunning ? (
@@ -3,6 +3,7 @@ import type {
   Exercise,
   ExerciseDetail,
   RunResult,
+  SourceFiles,
   SubmitResult,
   PaginatedResponse,
   Category,
@@ -48,6 +49,13 @@ export const exerciseApi = {
     return api.post<SubmitResult>(`/exercises/${id}/submit`, { code });
   },
 
+  submitFiles: async (
+    id: number,
+    files: SourceFiles,
+  ): Promise<SubmitResult> => {
+    return api.post<SubmitResult>(`/exercises/${id}/submit`, { files });
+  },
+
   getCategories: async (): Promise<{
     categories: Category[];
     total: number;
@@ -1,10 +1,19 @@
 import { create } from "zustand";
 import { exerciseApi } from "../services";
-import type { ConsoleEntry, RunResult, SubmitResult } from "../types";
+import type {
+  ConsoleEntry,
+  RunResult,
+  SourceFiles,
+  SubmitResult,
+} from "../types";
 
 interface EditorState {
+  /** The text in the editor: the whole program, or the active file. */
   code: string;
   originalCode: string;
+  /** Every file of a multi-file exercise, or null for a single file. */
+  files: SourceFiles | null;
+  activeFile: string;
   isRunning: boolean;
   isSubmitting: boolean;
   consoleEntries: ConsoleEntry[];
@@ -12,6 +21,8 @@ interface EditorState {
   lastSubmitResult: SubmitResult | null;
 
   setCode: (code: string) => void;
+  setFiles: (files: SourceFiles | null) => void;
+  setActiveFile: (path: string) => void;
   resetCode: () => void;
   runCode: (exerciseId: number) => Promise<void>;
   submitCode: (exerciseId: number) => Promise<void>;
@@ -34,6 +45,8 @@ const DEFAULT_CODE = `fn main() {
 export const useEditorStore = create<EditorState>((set, get) => ({
   code: DEFAULT_CODE,
   originalCode: DEFAULT_CODE,
+  files: null,
+  activeFile: "",
   isRunning: false,
   isSubmitting: false,
   consoleEntries: [],
@@ -41,7 +54,26 @@ export const useEditorStore = create<EditorState>((set, get) => ({
   lastSubmitResult: null,
 
   setCode: (code: string) => {
-    set({ code });
+    const { files, activeFile } = get();
+    set({ code, files: files && { ...files, [activeFile]: code } });
+  },
+
+  setFiles: (files: SourceFiles | null) => {
+    if (!files) {
+      set({ files: null, activeFile: "" });
+      return;
+    }
+    // Open the library root first; it declares every other module
+    const activeFile =
+      "src/lib.rs" in files ? "src/lib.rs" : Object.keys(files)[0] || "";
+    set({ files, activeFile, code: files[activeFile] ?? "" });
+  },
+
+  setActiveFile: (path: string) => {
+    const { files } = get();
+    if (files && path in files) {
+      set({ activeFile: path, code: files[path] });
+    }
   },
 
   resetCode: () => {
@@ -93,7 +125,7 @@ export const useEditorStore = create<EditorState>((set, get) => ({
   },
 
   submitCode: async (exerciseId: number) => {
-    const { code } = get();
+    const { code, files } = get();
 
     get().addConsoleEntry({
       type: "info",
@@ -103,7 +135,9 @@ export const useEditorStore = create<EditorState>((set, get) => ({
     set({ isSubmitting: true });
 
     try {
-      const result = await exerciseApi.submit(exerciseId, code);
+      const result = files
+        ? await exerciseApi.submitFiles(exerciseId, files)
+        : await exerciseApi.submit(exerciseId, code);
 
       set({ lastSubmitResult: result });
 
@@ -51,10 +51,15 @@ export interface Exercise {
   bonus?: string;
 }
 
+/** Source files of a multi-file exercise by path, e.g. `src/lib.rs`. */
+export type SourceFiles = Record<string, string>;
+
 export interface ExerciseDetail extends Exercise {
   template_code: string;
   hints: string[];
   prerequisites?: number[];
+  /** Set for exercises that are Cargo crates rather than one file. */
+  files?: SourceFiles | null;
 }
 
 export interface ExerciseProgress {